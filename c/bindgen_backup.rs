@@ -204,6 +204,17 @@ pub const cFOPEN_MAX: u32 = 16;
 pub const ctrue: u32 = 1;
 pub const cfalse: u32 = 0;
 pub const c__bool_true_false_are_defined: u32 = 1;
+pub const cBW_MODIFIER_CTRL: u32 = 1;
+pub const cBW_MODIFIER_SHIFT: u32 = 2;
+pub const cBW_MODIFIER_ALT: u32 = 4;
+pub const cBW_MODIFIER_SUPER: u32 = 8;
+pub const cBW_MODIFIER_LEFT_BUTTON: u32 = 16;
+pub const cBW_MODIFIER_MIDDLE_BUTTON: u32 = 32;
+pub const cBW_MODIFIER_RIGHT_BUTTON: u32 = 64;
+pub const cBW_PERMISSION_COUNT: u32 = 5;
+pub const cBW_THEME_UNKNOWN: u32 = 0;
+pub const cBW_THEME_LIGHT: u32 = 1;
+pub const cBW_THEME_DARK: u32 = 2;
 pub type cBOOL = ::std::os::raw::c_int;
 pub type csize_t = ::std::os::raw::c_ulong;
 pub type cwchar_t = ::std::os::raw::c_int;
@@ -354,6 +365,26 @@ pub type cbw_ApplicationDispatchFn = ::std::option::Option<
     unsafe extern "C" fn(app: *mut cbw_Application, data: *mut ::std::os::raw::c_void),
 >;
 pub type cbw_ApplicationReadyFn = cbw_ApplicationDispatchFn;
+#[doc = " Invoked when a notification has gone away, with whether or not the user has clicked on it."]
+pub type cbw_ApplicationNotificationFn = ::std::option::Option<
+    unsafe extern "C" fn(app: *mut cbw_Application, clicked: cBOOL, data: *mut ::std::os::raw::c_void),
+>;
+#[doc = " Invoked when a global hotkey has been pressed."]
+pub type cbw_ApplicationHotkeyFn = ::std::option::Option<
+    unsafe extern "C" fn(
+        app: *mut cbw_Application,
+        hotkey_id: u32,
+        data: *mut ::std::os::raw::c_void,
+    ),
+>;
+#[doc = " Invoked when the system has switched between its light and dark theme, with the `BW_THEME_*` value of the new one."]
+pub type cbw_ApplicationThemeFn = ::std::option::Option<
+    unsafe extern "C" fn(
+        app: *mut cbw_Application,
+        theme: u8,
+        data: *mut ::std::os::raw::c_void,
+    ),
+>;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct cbw_ApplicationImpl {}
@@ -2997,6 +3028,20 @@ extern "C" {
         __n: ::std::os::raw::c_ulong,
     ) -> *mut ::std::os::raw::c_char;
 }
+#[doc = " The details of an exception that JavaScript code has thrown, which are the data of the errors that `bw_Err_newJsException` creates."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cbw_JsException {
+    #[doc = " The name of the error, like `TypeError`, or empty if it is unknown"]
+    pub name: *const ::std::os::raw::c_char,
+    pub message: *const ::std::os::raw::c_char,
+    #[doc = " The stack trace, or empty if the browser engine doesn't give it"]
+    pub stack: *const ::std::os::raw::c_char,
+    #[doc = " Starts at 1, or is 0 if it is unknown"]
+    pub line: ::std::os::raw::c_uint,
+    #[doc = " Starts at 1, or is 0 if it is unknown"]
+    pub column: ::std::os::raw::c_uint,
+}
 pub type cbw_ErrCode = ::std::os::raw::c_uint;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -3067,6 +3112,13 @@ extern "C" {
         arg2: *const ::std::os::raw::c_void,
     ) -> *mut ::std::os::raw::c_char;
 }
+extern "C" {
+    #[link_name = "\u{1}bw_Err_msg_jsException"]
+    pub fn cbw_Err_msg_jsException(
+        arg1: cbw_ErrCode,
+        arg2: *const ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_char;
+}
 extern "C" {
     #[link_name = "\u{1}bw_Err_free"]
     pub fn cbw_Err_free(err: *mut cbw_Err);
@@ -3075,6 +3127,16 @@ extern "C" {
     #[link_name = "\u{1}bw_Err_new_with_msg"]
     pub fn cbw_Err_new_with_msg(code: cbw_ErrCode, msg: *const ::std::os::raw::c_char) -> cbw_Err;
 }
+extern "C" {
+    #[link_name = "\u{1}bw_Err_newJsException"]
+    pub fn cbw_Err_newJsException(
+        name: *const ::std::os::raw::c_char,
+        message: *const ::std::os::raw::c_char,
+        stack: *const ::std::os::raw::c_char,
+        line: ::std::os::raw::c_uint,
+        column: ::std::os::raw::c_uint,
+    ) -> cbw_Err;
+}
 extern "C" {
     #[link_name = "\u{1}bw_Err_message"]
     pub fn cbw_Err_message(error: *const cbw_Err) -> *mut ::std::os::raw::c_char;
@@ -3103,14 +3165,17 @@ pub struct cbw_Application {
     pub windows_alive: ::std::os::raw::c_uint,
     pub is_running: cBOOL,
     pub is_done: cBOOL,
+    #[doc = " Windows are never shown when set."]
+    pub headless: cBOOL,
     pub impl_: cbw_ApplicationImpl,
+    #[doc = " Can be set by the implementation of a browser engine"]
     pub engine_impl: cbw_ApplicationEngineImpl,
 }
 #[test]
 fn bindgen_test_layout_cbw_Application() {
     assert_eq!(
         ::std::mem::size_of::<cbw_Application>(),
-        12usize,
+        16usize,
         concat!("Size of: ", stringify!(cbw_Application))
     );
     assert_eq!(
@@ -3149,8 +3214,18 @@ fn bindgen_test_layout_cbw_Application() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_Application>())).impl_ as *const _ as usize },
+        unsafe { &(*(::std::ptr::null::<cbw_Application>())).headless as *const _ as usize },
         12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_Application),
+            "::",
+            stringify!(headless)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_Application>())).impl_ as *const _ as usize },
+        16usize,
         concat!(
             "Offset of field: ",
             stringify!(cbw_Application),
@@ -3160,7 +3235,7 @@ fn bindgen_test_layout_cbw_Application() {
     );
     assert_eq!(
         unsafe { &(*(::std::ptr::null::<cbw_Application>())).engine_impl as *const _ as usize },
-        12usize,
+        16usize,
         concat!(
             "Offset of field: ",
             stringify!(cbw_Application),
@@ -3222,12 +3297,42 @@ fn bindgen_test_layout_cbw_ApplicationDispatchData() {
 pub struct cbw_ApplicationSettings {
     pub engine_seperate_executable_path: cbw_CStrSlice,
     pub resource_dir: cbw_CStrSlice,
+    #[doc = " Runs the browser engine without ever showing any windows."]
+    pub headless: cBOOL,
+    #[doc = " Empty for the engine's default."]
+    pub cache_path: cbw_CStrSlice,
+    #[doc = " Empty for the engine's default."]
+    pub user_data_dir: cbw_CStrSlice,
+    #[doc = " 0 for the engine's default, otherwise from 1 (verbose) up to 5 (fatal), or 6 to disable logging."]
+    pub log_severity: ::std::os::raw::c_int,
+    #[doc = " Empty for the engine's default."]
+    pub log_file: cbw_CStrSlice,
+    #[doc = " Empty for the system's locale."]
+    pub locale: cbw_CStrSlice,
+    #[doc = " 0 to disable remote debugging."]
+    pub remote_debugging_port: ::std::os::raw::c_int,
+    #[doc = " Extra command-line switches for the browser engine, like `--disable-gpu`."]
+    pub engine_switches: *const cbw_CStrSlice,
+    pub engine_switch_count: csize_t,
+    #[doc = " Keeps the cookies that expire with the session across restarts, which needs `cache_path` to be set."]
+    pub persist_session_cookies: cBOOL,
+    #[doc = " The schemes of which browser windows answer the requests themselves, with their scheme handler."]
+    pub custom_schemes: *const cbw_CStrSlice,
+    pub custom_scheme_count: csize_t,
+    #[doc = " Renders everything without the GPU."]
+    pub disable_gpu: cBOOL,
+    #[doc = " Rasterizes the pages without the GPU, while still compositing them with it."]
+    pub software_rasterization: cBOOL,
+    #[doc = " The maximum number of frames per second that offscreen browsers paint, or 0 for the engine's default."]
+    pub max_frame_rate: ::std::os::raw::c_uint,
+    #[doc = " Lets pages play media with sound before the user has interacted with them."]
+    pub autoplay_without_gesture: cBOOL,
 }
 #[test]
 fn bindgen_test_layout_cbw_ApplicationSettings() {
     assert_eq!(
         ::std::mem::size_of::<cbw_ApplicationSettings>(),
-        32usize,
+        176usize,
         concat!("Size of: ", stringify!(cbw_ApplicationSettings))
     );
     assert_eq!(
@@ -3236,10 +3341,7 @@ fn bindgen_test_layout_cbw_ApplicationSettings() {
         concat!("Alignment of ", stringify!(cbw_ApplicationSettings))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<cbw_ApplicationSettings>())).engine_seperate_executable_path
-                as *const _ as usize
-        },
+        unsafe { &(*(::std::ptr::null::<cbw_ApplicationSettings>())).engine_seperate_executable_path as *const _ as usize },
         0usize,
         concat!(
             "Offset of field: ",
@@ -3249,9 +3351,7 @@ fn bindgen_test_layout_cbw_ApplicationSettings() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<cbw_ApplicationSettings>())).resource_dir as *const _ as usize
-        },
+        unsafe { &(*(::std::ptr::null::<cbw_ApplicationSettings>())).resource_dir as *const _ as usize },
         16usize,
         concat!(
             "Offset of field: ",
@@ -3260,1805 +3360,3634 @@ fn bindgen_test_layout_cbw_ApplicationSettings() {
             stringify!(resource_dir)
         )
     );
-}
-extern "C" {
-    #[doc = " Safety check that makes sure the given application handle is used on the correct thread."]
-    #[doc = " Does nothing in release mode."]
-    #[link_name = "\u{1}bw_Application_assertCorrectThread"]
-    pub fn cbw_Application_assertCorrectThread(arg1: *const cbw_Application);
-}
-extern "C" {
-    #[doc = " Exits the main loop, returning execution to the function that invoked the run call."]
-    #[doc = " The exit_code will be returned by bw_Application_run."]
-    #[link_name = "\u{1}bw_Application_exit"]
-    pub fn cbw_Application_exit(app: *mut cbw_Application, exit_code: ::std::os::raw::c_int);
-}
-extern "C" {
-    #[doc = " Same as bw_Application_exit, but guaranteed to be thread-safe"]
-    #[doc = " The exit_code will be returned by bw_Application_run."]
-    #[link_name = "\u{1}bw_Application_exitAsync"]
-    pub fn cbw_Application_exitAsync(app: *mut cbw_Application, exit_code: ::std::os::raw::c_int);
-}
-extern "C" {
-    #[doc = " Dispatches the given function to be executed on the thread this application instance has been created on,"]
-    #[doc = "     and passes the given data to it."]
-    #[doc = " This function is thread safe."]
-    #[doc = ""]
-    #[doc = " # Returns"]
-    #[doc = " An indication of whether or not the function was able to be dispatched."]
-    #[doc = " Dispatching a function fails when the application has already been terminated."]
-    #[link_name = "\u{1}bw_Application_dispatch"]
-    pub fn cbw_Application_dispatch(
-        app: *mut cbw_Application,
-        func: cbw_ApplicationDispatchFn,
-        data: *mut ::std::os::raw::c_void,
-    ) -> cBOOL;
-}
-extern "C" {
-    #[doc = " Shuts down all application processes and performs necessary clean-up code."]
-    #[link_name = "\u{1}bw_Application_finish"]
-    pub fn cbw_Application_finish(app: *mut cbw_Application);
-}
-extern "C" {
-    #[doc = " Frees memory for the application handle."]
-    #[doc = " Call `bw_Application_finish` before you call this function."]
-    #[doc = " Freeing the application handle is generally not necessary, as all memory in use by the process gets released anyway after shutdown."]
-    #[link_name = "\u{1}bw_Application_free"]
-    pub fn cbw_Application_free(app: *mut cbw_Application);
-}
-extern "C" {
-    #[doc = " Initializes browser window."]
-    #[doc = " Starts up browser engine process(es)."]
-    #[doc = " Returns an application handle."]
-    #[link_name = "\u{1}bw_Application_initialize"]
-    pub fn cbw_Application_initialize(
-        application: *mut *mut cbw_Application,
-        argc: ::std::os::raw::c_int,
-        argv: *mut *mut ::std::os::raw::c_char,
-        settings: *const cbw_ApplicationSettings,
-    ) -> cbw_Err;
-}
-extern "C" {
-    #[link_name = "\u{1}bw_Application_isRunning"]
-    pub fn cbw_Application_isRunning(app: *const cbw_Application) -> cBOOL;
-}
-extern "C" {
-    #[link_name = "\u{1}bw_Application_markAsDone"]
-    pub fn cbw_Application_markAsDone(app: *mut cbw_Application);
-}
-extern "C" {
-    #[doc = " Runs the event loop."]
-    #[doc = " Calls the `on_ready` callback when `app` can be used."]
-    #[link_name = "\u{1}bw_Application_run"]
-    pub fn cbw_Application_run(
-        app: *mut cbw_Application,
-        on_ready: cbw_ApplicationReadyFn,
-        user_data: *mut ::std::os::raw::c_void,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    #[doc = " Executes the given closure after the specified delay."]
-    #[link_name = "\u{1}bw_Application_dispatchDelayed"]
-    pub fn cbw_Application_dispatchDelayed(
-        app: *mut cbw_Application,
-        func: cbw_ApplicationDispatchFn,
-        user_data: *mut ::std::os::raw::c_void,
-        milliseconds: u64,
-    ) -> cBOOL;
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct cbw_BrowserWindowImpl {
-    pub cef_ptr: *mut ::std::os::raw::c_void,
-    pub resource_path: *mut ::std::os::raw::c_char,
-}
-#[test]
-fn bindgen_test_layout_cbw_BrowserWindowImpl() {
-    assert_eq!(
-        ::std::mem::size_of::<cbw_BrowserWindowImpl>(),
-        16usize,
-        concat!("Size of: ", stringify!(cbw_BrowserWindowImpl))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<cbw_BrowserWindowImpl>(),
-        8usize,
-        concat!("Alignment of ", stringify!(cbw_BrowserWindowImpl))
-    );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowImpl>())).cef_ptr as *const _ as usize },
-        0usize,
+        unsafe { &(*(::std::ptr::null::<cbw_ApplicationSettings>())).headless as *const _ as usize },
+        32usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_BrowserWindowImpl),
+            stringify!(cbw_ApplicationSettings),
             "::",
-            stringify!(cef_ptr)
+            stringify!(headless)
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<cbw_BrowserWindowImpl>())).resource_path as *const _ as usize
-        },
-        8usize,
+        unsafe { &(*(::std::ptr::null::<cbw_ApplicationSettings>())).cache_path as *const _ as usize },
+        40usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_BrowserWindowImpl),
+            stringify!(cbw_ApplicationSettings),
             "::",
-            stringify!(resource_path)
+            stringify!(cache_path)
         )
     );
-}
-extern "C" {
-    #[link_name = "\u{1}__assert_fail"]
-    pub fn c__assert_fail(
-        __assertion: *const ::std::os::raw::c_char,
-        __file: *const ::std::os::raw::c_char,
-        __line: ::std::os::raw::c_uint,
-        __function: *const ::std::os::raw::c_char,
-    );
-}
-extern "C" {
-    #[link_name = "\u{1}__assert_perror_fail"]
-    pub fn c__assert_perror_fail(
-        __errnum: ::std::os::raw::c_int,
-        __file: *const ::std::os::raw::c_char,
-        __line: ::std::os::raw::c_uint,
-        __function: *const ::std::os::raw::c_char,
-    );
-}
-extern "C" {
-    #[link_name = "\u{1}__assert"]
-    pub fn c__assert(
-        __assertion: *const ::std::os::raw::c_char,
-        __file: *const ::std::os::raw::c_char,
-        __line: ::std::os::raw::c_int,
-    );
-}
-pub type cva_list = c__builtin_va_list;
-pub type c__gnuc_va_list = c__builtin_va_list;
-#[repr(C)]
-#[derive(Copy, Clone)]
-pub struct c__mbstate_t {
-    pub __count: ::std::os::raw::c_int,
-    pub __value: c__mbstate_t__bindgen_ty_1,
-}
-#[repr(C)]
-#[derive(Copy, Clone)]
-pub union c__mbstate_t__bindgen_ty_1 {
-    pub __wch: ::std::os::raw::c_uint,
-    pub __wchb: [::std::os::raw::c_char; 4usize],
-    _bindgen_union_align: u32,
-}
-#[test]
-fn bindgen_test_layout_c__mbstate_t__bindgen_ty_1() {
-    assert_eq!(
-        ::std::mem::size_of::<c__mbstate_t__bindgen_ty_1>(),
-        4usize,
-        concat!("Size of: ", stringify!(c__mbstate_t__bindgen_ty_1))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<c__mbstate_t__bindgen_ty_1>(),
-        4usize,
-        concat!("Alignment of ", stringify!(c__mbstate_t__bindgen_ty_1))
-    );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<c__mbstate_t__bindgen_ty_1>())).__wch as *const _ as usize
-        },
-        0usize,
+        unsafe { &(*(::std::ptr::null::<cbw_ApplicationSettings>())).user_data_dir as *const _ as usize },
+        56usize,
         concat!(
             "Offset of field: ",
-            stringify!(c__mbstate_t__bindgen_ty_1),
+            stringify!(cbw_ApplicationSettings),
             "::",
-            stringify!(__wch)
+            stringify!(user_data_dir)
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<c__mbstate_t__bindgen_ty_1>())).__wchb as *const _ as usize
-        },
-        0usize,
+        unsafe { &(*(::std::ptr::null::<cbw_ApplicationSettings>())).log_severity as *const _ as usize },
+        72usize,
         concat!(
             "Offset of field: ",
-            stringify!(c__mbstate_t__bindgen_ty_1),
+            stringify!(cbw_ApplicationSettings),
             "::",
-            stringify!(__wchb)
+            stringify!(log_severity)
         )
     );
-}
-#[test]
-fn bindgen_test_layout_c__mbstate_t() {
-    assert_eq!(
-        ::std::mem::size_of::<c__mbstate_t>(),
-        8usize,
-        concat!("Size of: ", stringify!(c__mbstate_t))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<c__mbstate_t>(),
-        4usize,
-        concat!("Alignment of ", stringify!(c__mbstate_t))
-    );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c__mbstate_t>())).__count as *const _ as usize },
-        0usize,
+        unsafe { &(*(::std::ptr::null::<cbw_ApplicationSettings>())).log_file as *const _ as usize },
+        80usize,
         concat!(
             "Offset of field: ",
-            stringify!(c__mbstate_t),
+            stringify!(cbw_ApplicationSettings),
             "::",
-            stringify!(__count)
+            stringify!(log_file)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c__mbstate_t>())).__value as *const _ as usize },
-        4usize,
+        unsafe { &(*(::std::ptr::null::<cbw_ApplicationSettings>())).locale as *const _ as usize },
+        96usize,
         concat!(
             "Offset of field: ",
-            stringify!(c__mbstate_t),
+            stringify!(cbw_ApplicationSettings),
             "::",
-            stringify!(__value)
-        )
-    );
-}
-#[repr(C)]
-#[derive(Copy, Clone)]
-pub struct c_G_fpos_t {
-    pub __pos: c__off_t,
-    pub __state: c__mbstate_t,
-}
-#[test]
-fn bindgen_test_layout_c_G_fpos_t() {
-    assert_eq!(
-        ::std::mem::size_of::<c_G_fpos_t>(),
-        16usize,
-        concat!("Size of: ", stringify!(c_G_fpos_t))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<c_G_fpos_t>(),
-        8usize,
-        concat!("Alignment of ", stringify!(c_G_fpos_t))
-    );
-    assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_G_fpos_t>())).__pos as *const _ as usize },
-        0usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(c_G_fpos_t),
-            "::",
-            stringify!(__pos)
+            stringify!(locale)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_G_fpos_t>())).__state as *const _ as usize },
-        8usize,
+        unsafe { &(*(::std::ptr::null::<cbw_ApplicationSettings>())).remote_debugging_port as *const _ as usize },
+        112usize,
         concat!(
             "Offset of field: ",
-            stringify!(c_G_fpos_t),
+            stringify!(cbw_ApplicationSettings),
             "::",
-            stringify!(__state)
+            stringify!(remote_debugging_port)
         )
     );
-}
-pub type c__fpos_t = c_G_fpos_t;
-#[repr(C)]
-#[derive(Copy, Clone)]
-pub struct c_G_fpos64_t {
-    pub __pos: c__off64_t,
-    pub __state: c__mbstate_t,
-}
-#[test]
-fn bindgen_test_layout_c_G_fpos64_t() {
-    assert_eq!(
-        ::std::mem::size_of::<c_G_fpos64_t>(),
-        16usize,
-        concat!("Size of: ", stringify!(c_G_fpos64_t))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<c_G_fpos64_t>(),
-        8usize,
-        concat!("Alignment of ", stringify!(c_G_fpos64_t))
-    );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_G_fpos64_t>())).__pos as *const _ as usize },
-        0usize,
+        unsafe { &(*(::std::ptr::null::<cbw_ApplicationSettings>())).engine_switches as *const _ as usize },
+        120usize,
         concat!(
             "Offset of field: ",
-            stringify!(c_G_fpos64_t),
+            stringify!(cbw_ApplicationSettings),
             "::",
-            stringify!(__pos)
+            stringify!(engine_switches)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_G_fpos64_t>())).__state as *const _ as usize },
-        8usize,
+        unsafe { &(*(::std::ptr::null::<cbw_ApplicationSettings>())).engine_switch_count as *const _ as usize },
+        128usize,
         concat!(
             "Offset of field: ",
-            stringify!(c_G_fpos64_t),
+            stringify!(cbw_ApplicationSettings),
             "::",
-            stringify!(__state)
+            stringify!(engine_switch_count)
         )
     );
-}
-pub type c__fpos64_t = c_G_fpos64_t;
-pub type c__FILE = c_IO_FILE;
-pub type cFILE = c_IO_FILE;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct c_IO_marker {
-    _unused: [u8; 0],
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct c_IO_codecvt {
-    _unused: [u8; 0],
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct c_IO_wide_data {
-    _unused: [u8; 0],
-}
-pub type c_IO_lock_t = ::std::os::raw::c_void;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct c_IO_FILE {
-    pub _flags: ::std::os::raw::c_int,
-    pub _IO_read_ptr: *mut ::std::os::raw::c_char,
-    pub _IO_read_end: *mut ::std::os::raw::c_char,
-    pub _IO_read_base: *mut ::std::os::raw::c_char,
-    pub _IO_write_base: *mut ::std::os::raw::c_char,
-    pub _IO_write_ptr: *mut ::std::os::raw::c_char,
-    pub _IO_write_end: *mut ::std::os::raw::c_char,
-    pub _IO_buf_base: *mut ::std::os::raw::c_char,
-    pub _IO_buf_end: *mut ::std::os::raw::c_char,
-    pub _IO_save_base: *mut ::std::os::raw::c_char,
-    pub _IO_backup_base: *mut ::std::os::raw::c_char,
-    pub _IO_save_end: *mut ::std::os::raw::c_char,
-    pub _markers: *mut c_IO_marker,
-    pub _chain: *mut c_IO_FILE,
-    pub _fileno: ::std::os::raw::c_int,
-    pub _flags2: ::std::os::raw::c_int,
-    pub _old_offset: c__off_t,
-    pub _cur_column: ::std::os::raw::c_ushort,
-    pub _vtable_offset: ::std::os::raw::c_schar,
-    pub _shortbuf: [::std::os::raw::c_char; 1usize],
-    pub _lock: *mut c_IO_lock_t,
-    pub _offset: c__off64_t,
-    pub _codecvt: *mut c_IO_codecvt,
-    pub _wide_data: *mut c_IO_wide_data,
-    pub _freeres_list: *mut c_IO_FILE,
-    pub _freeres_buf: *mut ::std::os::raw::c_void,
-    pub __pad5: csize_t,
-    pub _mode: ::std::os::raw::c_int,
-    pub _unused2: [::std::os::raw::c_char; 20usize],
-}
-#[test]
-fn bindgen_test_layout_c_IO_FILE() {
-    assert_eq!(
-        ::std::mem::size_of::<c_IO_FILE>(),
-        216usize,
-        concat!("Size of: ", stringify!(c_IO_FILE))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<c_IO_FILE>(),
-        8usize,
-        concat!("Alignment of ", stringify!(c_IO_FILE))
-    );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._flags as *const _ as usize },
-        0usize,
+        unsafe { &(*(::std::ptr::null::<cbw_ApplicationSettings>())).persist_session_cookies as *const _ as usize },
+        136usize,
         concat!(
             "Offset of field: ",
-            stringify!(c_IO_FILE),
+            stringify!(cbw_ApplicationSettings),
             "::",
-            stringify!(_flags)
+            stringify!(persist_session_cookies)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._IO_read_ptr as *const _ as usize },
-        8usize,
+        unsafe { &(*(::std::ptr::null::<cbw_ApplicationSettings>())).custom_schemes as *const _ as usize },
+        144usize,
         concat!(
             "Offset of field: ",
-            stringify!(c_IO_FILE),
+            stringify!(cbw_ApplicationSettings),
             "::",
-            stringify!(_IO_read_ptr)
+            stringify!(custom_schemes)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._IO_read_end as *const _ as usize },
-        16usize,
+        unsafe { &(*(::std::ptr::null::<cbw_ApplicationSettings>())).custom_scheme_count as *const _ as usize },
+        152usize,
         concat!(
             "Offset of field: ",
-            stringify!(c_IO_FILE),
+            stringify!(cbw_ApplicationSettings),
             "::",
-            stringify!(_IO_read_end)
+            stringify!(custom_scheme_count)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._IO_read_base as *const _ as usize },
-        24usize,
+        unsafe { &(*(::std::ptr::null::<cbw_ApplicationSettings>())).disable_gpu as *const _ as usize },
+        160usize,
         concat!(
             "Offset of field: ",
-            stringify!(c_IO_FILE),
+            stringify!(cbw_ApplicationSettings),
             "::",
-            stringify!(_IO_read_base)
+            stringify!(disable_gpu)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._IO_write_base as *const _ as usize },
-        32usize,
+        unsafe { &(*(::std::ptr::null::<cbw_ApplicationSettings>())).software_rasterization as *const _ as usize },
+        164usize,
         concat!(
             "Offset of field: ",
-            stringify!(c_IO_FILE),
+            stringify!(cbw_ApplicationSettings),
             "::",
-            stringify!(_IO_write_base)
+            stringify!(software_rasterization)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._IO_write_ptr as *const _ as usize },
-        40usize,
+        unsafe { &(*(::std::ptr::null::<cbw_ApplicationSettings>())).max_frame_rate as *const _ as usize },
+        168usize,
         concat!(
             "Offset of field: ",
-            stringify!(c_IO_FILE),
+            stringify!(cbw_ApplicationSettings),
             "::",
-            stringify!(_IO_write_ptr)
+            stringify!(max_frame_rate)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._IO_write_end as *const _ as usize },
-        48usize,
+        unsafe { &(*(::std::ptr::null::<cbw_ApplicationSettings>())).autoplay_without_gesture as *const _ as usize },
+        172usize,
         concat!(
             "Offset of field: ",
-            stringify!(c_IO_FILE),
+            stringify!(cbw_ApplicationSettings),
             "::",
-            stringify!(_IO_write_end)
+            stringify!(autoplay_without_gesture)
         )
     );
+}
+#[doc = " A display that is connected to the system."]
+#[doc = " Its coordinates are in the same units as the positions of windows."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cbw_Monitor {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    #[doc = " The work area is the part of the display that isn't covered by taskbars, docks or panels."]
+    pub work_x: i32,
+    pub work_y: i32,
+    pub work_width: u32,
+    pub work_height: u32,
+    pub scale_factor: f32,
+    pub is_primary: cBOOL,
+}
+#[test]
+fn bindgen_test_layout_cbw_Monitor() {
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._IO_buf_base as *const _ as usize },
-        56usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(c_IO_FILE),
-            "::",
-            stringify!(_IO_buf_base)
-        )
+        ::std::mem::size_of::<cbw_Monitor>(),
+        40usize,
+        concat!("Size of: ", stringify!(cbw_Monitor))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._IO_buf_end as *const _ as usize },
-        64usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(c_IO_FILE),
-            "::",
-            stringify!(_IO_buf_end)
-        )
+        ::std::mem::align_of::<cbw_Monitor>(),
+        4usize,
+        concat!("Alignment of ", stringify!(cbw_Monitor))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._IO_save_base as *const _ as usize },
-        72usize,
+        unsafe { &(*(::std::ptr::null::<cbw_Monitor>())).x as *const _ as usize },
+        0usize,
         concat!(
             "Offset of field: ",
-            stringify!(c_IO_FILE),
+            stringify!(cbw_Monitor),
             "::",
-            stringify!(_IO_save_base)
+            stringify!(x)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._IO_backup_base as *const _ as usize },
-        80usize,
+        unsafe { &(*(::std::ptr::null::<cbw_Monitor>())).y as *const _ as usize },
+        4usize,
         concat!(
             "Offset of field: ",
-            stringify!(c_IO_FILE),
+            stringify!(cbw_Monitor),
             "::",
-            stringify!(_IO_backup_base)
+            stringify!(y)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._IO_save_end as *const _ as usize },
-        88usize,
+        unsafe { &(*(::std::ptr::null::<cbw_Monitor>())).width as *const _ as usize },
+        8usize,
         concat!(
             "Offset of field: ",
-            stringify!(c_IO_FILE),
+            stringify!(cbw_Monitor),
             "::",
-            stringify!(_IO_save_end)
+            stringify!(width)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._markers as *const _ as usize },
-        96usize,
+        unsafe { &(*(::std::ptr::null::<cbw_Monitor>())).height as *const _ as usize },
+        12usize,
         concat!(
             "Offset of field: ",
-            stringify!(c_IO_FILE),
+            stringify!(cbw_Monitor),
             "::",
-            stringify!(_markers)
+            stringify!(height)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._chain as *const _ as usize },
-        104usize,
+        unsafe { &(*(::std::ptr::null::<cbw_Monitor>())).work_x as *const _ as usize },
+        16usize,
         concat!(
             "Offset of field: ",
-            stringify!(c_IO_FILE),
+            stringify!(cbw_Monitor),
             "::",
-            stringify!(_chain)
+            stringify!(work_x)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._fileno as *const _ as usize },
-        112usize,
+        unsafe { &(*(::std::ptr::null::<cbw_Monitor>())).work_y as *const _ as usize },
+        20usize,
         concat!(
             "Offset of field: ",
-            stringify!(c_IO_FILE),
+            stringify!(cbw_Monitor),
             "::",
-            stringify!(_fileno)
+            stringify!(work_y)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._flags2 as *const _ as usize },
-        116usize,
+        unsafe { &(*(::std::ptr::null::<cbw_Monitor>())).work_width as *const _ as usize },
+        24usize,
         concat!(
             "Offset of field: ",
-            stringify!(c_IO_FILE),
+            stringify!(cbw_Monitor),
             "::",
-            stringify!(_flags2)
+            stringify!(work_width)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._old_offset as *const _ as usize },
-        120usize,
+        unsafe { &(*(::std::ptr::null::<cbw_Monitor>())).work_height as *const _ as usize },
+        28usize,
         concat!(
             "Offset of field: ",
-            stringify!(c_IO_FILE),
+            stringify!(cbw_Monitor),
             "::",
-            stringify!(_old_offset)
+            stringify!(work_height)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._cur_column as *const _ as usize },
-        128usize,
+        unsafe { &(*(::std::ptr::null::<cbw_Monitor>())).scale_factor as *const _ as usize },
+        32usize,
         concat!(
             "Offset of field: ",
-            stringify!(c_IO_FILE),
+            stringify!(cbw_Monitor),
             "::",
-            stringify!(_cur_column)
+            stringify!(scale_factor)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._vtable_offset as *const _ as usize },
-        130usize,
+        unsafe { &(*(::std::ptr::null::<cbw_Monitor>())).is_primary as *const _ as usize },
+        36usize,
         concat!(
             "Offset of field: ",
-            stringify!(c_IO_FILE),
+            stringify!(cbw_Monitor),
             "::",
-            stringify!(_vtable_offset)
+            stringify!(is_primary)
         )
     );
-    assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._shortbuf as *const _ as usize },
-        131usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(c_IO_FILE),
-            "::",
-            stringify!(_shortbuf)
-        )
-    );
-    assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._lock as *const _ as usize },
-        136usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(c_IO_FILE),
-            "::",
-            stringify!(_lock)
-        )
-    );
-    assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._offset as *const _ as usize },
-        144usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(c_IO_FILE),
-            "::",
-            stringify!(_offset)
-        )
-    );
-    assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._codecvt as *const _ as usize },
-        152usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(c_IO_FILE),
-            "::",
-            stringify!(_codecvt)
-        )
-    );
-    assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._wide_data as *const _ as usize },
-        160usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(c_IO_FILE),
-            "::",
-            stringify!(_wide_data)
-        )
-    );
-    assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._freeres_list as *const _ as usize },
-        168usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(c_IO_FILE),
-            "::",
-            stringify!(_freeres_list)
-        )
-    );
-    assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._freeres_buf as *const _ as usize },
-        176usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(c_IO_FILE),
-            "::",
-            stringify!(_freeres_buf)
-        )
-    );
-    assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>())).__pad5 as *const _ as usize },
-        184usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(c_IO_FILE),
-            "::",
-            stringify!(__pad5)
-        )
-    );
-    assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._mode as *const _ as usize },
-        192usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(c_IO_FILE),
-            "::",
-            stringify!(_mode)
-        )
-    );
-    assert_eq!(
-        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._unused2 as *const _ as usize },
-        196usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(c_IO_FILE),
-            "::",
-            stringify!(_unused2)
-        )
-    );
-}
-pub type cfpos_t = c__fpos_t;
-extern "C" {
-    #[link_name = "\u{1}stdin"]
-    pub static mut cstdin: *mut cFILE;
 }
 extern "C" {
-    #[link_name = "\u{1}stdout"]
-    pub static mut cstdout: *mut cFILE;
+    #[doc = " Safety check that makes sure the given application handle is used on the correct thread."]
+    #[doc = " Does nothing in release mode."]
+    #[link_name = "\u{1}bw_Application_assertCorrectThread"]
+    pub fn cbw_Application_assertCorrectThread(arg1: *const cbw_Application);
 }
 extern "C" {
-    #[link_name = "\u{1}stderr"]
-    pub static mut cstderr: *mut cFILE;
+    #[doc = " Exits the main loop, returning execution to the function that invoked the run call."]
+    #[doc = " The exit_code will be returned by bw_Application_run."]
+    #[link_name = "\u{1}bw_Application_exit"]
+    pub fn cbw_Application_exit(app: *mut cbw_Application, exit_code: ::std::os::raw::c_int);
 }
 extern "C" {
-    #[link_name = "\u{1}remove"]
-    pub fn cremove(__filename: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+    #[doc = " Same as bw_Application_exit, but guaranteed to be thread-safe"]
+    #[doc = " The exit_code will be returned by bw_Application_run."]
+    #[link_name = "\u{1}bw_Application_exitAsync"]
+    pub fn cbw_Application_exitAsync(app: *mut cbw_Application, exit_code: ::std::os::raw::c_int);
 }
 extern "C" {
-    #[link_name = "\u{1}rename"]
-    pub fn crename(
-        __old: *const ::std::os::raw::c_char,
-        __new: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+    #[doc = " Dispatches the given function to be executed on the thread this application instance has been created on,"]
+    #[doc = "     and passes the given data to it."]
+    #[doc = " This function is thread safe."]
+    #[doc = ""]
+    #[doc = " # Returns"]
+    #[doc = " An indication of whether or not the function was able to be dispatched."]
+    #[doc = " Dispatching a function fails when the application has already been terminated."]
+    #[link_name = "\u{1}bw_Application_dispatch"]
+    pub fn cbw_Application_dispatch(
+        app: *mut cbw_Application,
+        func: cbw_ApplicationDispatchFn,
+        data: *mut ::std::os::raw::c_void,
+    ) -> cBOOL;
 }
 extern "C" {
-    #[link_name = "\u{1}renameat"]
-    pub fn crenameat(
-        __oldfd: ::std::os::raw::c_int,
-        __old: *const ::std::os::raw::c_char,
-        __newfd: ::std::os::raw::c_int,
-        __new: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+    #[doc = " Prepares the application to have its events processed by `bw_Application_pumpEvents`, instead of by its own event loop with `bw_Application_run`."]
+    #[doc = " Use this when another event loop, like the one of winit, already runs on the main thread."]
+    #[link_name = "\u{1}bw_Application_beginPumping"]
+    pub fn cbw_Application_beginPumping(app: *mut cbw_Application);
 }
 extern "C" {
-    #[link_name = "\u{1}tmpfile"]
-    pub fn ctmpfile() -> *mut cFILE;
+    #[doc = " Stores a newly allocated string with the version of the browser engine in `version`."]
+    #[doc = " Returns false if the version could not be determined."]
+    #[doc = " The string needs to be freed with `bw_string_free`."]
+    #[link_name = "\u{1}bw_Application_getEngineVersion"]
+    pub fn cbw_Application_getEngineVersion(version: *mut cbw_StrSlice) -> cBOOL;
 }
 extern "C" {
-    #[link_name = "\u{1}tmpnam"]
-    pub fn ctmpnam(__s: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+    #[doc = " Shuts down all application processes and performs necessary clean-up code."]
+    #[link_name = "\u{1}bw_Application_finish"]
+    pub fn cbw_Application_finish(app: *mut cbw_Application);
 }
 extern "C" {
-    #[link_name = "\u{1}tmpnam_r"]
-    pub fn ctmpnam_r(__s: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+    #[doc = " Frees memory for the application handle."]
+    #[doc = " Call `bw_Application_finish` before you call this function."]
+    #[doc = " Freeing the application handle is generally not necessary, as all memory in use by the process gets released anyway after shutdown."]
+    #[link_name = "\u{1}bw_Application_free"]
+    pub fn cbw_Application_free(app: *mut cbw_Application);
 }
 extern "C" {
-    #[link_name = "\u{1}tempnam"]
-    pub fn ctempnam(
-        __dir: *const ::std::os::raw::c_char,
-        __pfx: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+    #[doc = " Frees the monitors given by `bw_Application_getMonitors`."]
+    #[link_name = "\u{1}bw_Application_freeMonitors"]
+    pub fn cbw_Application_freeMonitors(monitors: *mut cbw_Monitor);
 }
 extern "C" {
-    #[link_name = "\u{1}fclose"]
-    pub fn cfclose(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+    #[doc = " Frees the pixels given by `bw_Application_readClipboardImage`."]
+    #[link_name = "\u{1}bw_Application_freeClipboardImage"]
+    pub fn cbw_Application_freeClipboardImage(pixels: *mut u8);
 }
 extern "C" {
-    #[link_name = "\u{1}fflush"]
-    pub fn cfflush(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+    #[doc = " Stores a newly allocated array with all displays that are connected to the system in `monitors`, and returns its length."]
+    #[doc = " The array needs to be freed with `bw_Application_freeMonitors`."]
+    #[link_name = "\u{1}bw_Application_getMonitors"]
+    pub fn cbw_Application_getMonitors(
+        app: *mut cbw_Application,
+        monitors: *mut *mut cbw_Monitor,
+    ) -> csize_t;
 }
 extern "C" {
-    #[link_name = "\u{1}fflush_unlocked"]
-    pub fn cfflush_unlocked(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+    #[doc = " Gives whether the system uses a light or a dark theme for applications, as one of the `BW_THEME_*` values."]
+    #[doc = " Returns `BW_THEME_UNKNOWN` if the system doesn't tell."]
+    #[doc = ""]
+    #[doc = " *Note:* On Windows, this is the theme of applications in the personalization settings."]
+    #[doc = " With GTK, this is derived from the GTK theme, which is dark if its name ends with `-dark` or if dark themes are preferred."]
+    #[doc = " The theme is not known when CEF provides the windows itself."]
+    #[link_name = "\u{1}bw_Application_getSystemTheme"]
+    pub fn cbw_Application_getSystemTheme(app: *mut cbw_Application) -> u8;
 }
 extern "C" {
-    #[link_name = "\u{1}fopen"]
-    pub fn cfopen(
-        __filename: *const ::std::os::raw::c_char,
-        __modes: *const ::std::os::raw::c_char,
-    ) -> *mut cFILE;
+    #[doc = " Initializes browser window."]
+    #[doc = " Starts up browser engine process(es)."]
+    #[doc = " Returns an application handle."]
+    #[link_name = "\u{1}bw_Application_initialize"]
+    pub fn cbw_Application_initialize(
+        application: *mut *mut cbw_Application,
+        argc: ::std::os::raw::c_int,
+        argv: *mut *mut ::std::os::raw::c_char,
+        settings: *const cbw_ApplicationSettings,
+    ) -> cbw_Err;
 }
 extern "C" {
-    #[link_name = "\u{1}freopen"]
-    pub fn cfreopen(
-        __filename: *const ::std::os::raw::c_char,
-        __modes: *const ::std::os::raw::c_char,
-        __stream: *mut cFILE,
-    ) -> *mut cFILE;
+    #[link_name = "\u{1}bw_Application_isRunning"]
+    pub fn cbw_Application_isRunning(app: *const cbw_Application) -> cBOOL;
 }
 extern "C" {
-    #[link_name = "\u{1}fdopen"]
-    pub fn cfdopen(
-        __fd: ::std::os::raw::c_int,
-        __modes: *const ::std::os::raw::c_char,
-    ) -> *mut cFILE;
+    #[doc = " Shows a desktop notification."]
+    #[doc = ""]
+    #[doc = " # Arguments"]
+    #[doc = " * `icon_path` - The image file that is shown with the notification, which needs to be an `.ico` file on Windows. May be empty."]
+    #[doc = " * `handler` - Invoked once the notification has been clicked or dismissed. May be NULL."]
+    #[doc = ""]
+    #[doc = " *Note:* GTK doesn't report when notifications are dismissed, so `handler` is only invoked when the notification is clicked."]
+    #[doc = " Notifications are not supported when CEF provides the windows itself, `handler` is invoked with `clicked` set to FALSE right away."]
+    #[link_name = "\u{1}bw_Application_notify"]
+    pub fn cbw_Application_notify(
+        app: *mut cbw_Application,
+        title: cbw_CStrSlice,
+        body: cbw_CStrSlice,
+        icon_path: cbw_CStrSlice,
+        handler: cbw_ApplicationNotificationFn,
+        data: *mut ::std::os::raw::c_void,
+    );
 }
 extern "C" {
-    #[link_name = "\u{1}fmemopen"]
-    pub fn cfmemopen(
-        __s: *mut ::std::os::raw::c_void,
-        __len: csize_t,
-        __modes: *const ::std::os::raw::c_char,
-    ) -> *mut cFILE;
+    #[link_name = "\u{1}bw_Application_markAsDone"]
+    pub fn cbw_Application_markAsDone(app: *mut cbw_Application);
 }
 extern "C" {
-    #[link_name = "\u{1}open_memstream"]
-    pub fn copen_memstream(
-        __bufloc: *mut *mut ::std::os::raw::c_char,
-        __sizeloc: *mut csize_t,
-    ) -> *mut cFILE;
+    #[doc = " Gives the paths of the files that are on the clipboard, like the ones that have been copied in a file manager."]
+    #[doc = " Every path in `paths` is terminated by a null character."]
+    #[doc = " Returns FALSE if the clipboard doesn't contain any files."]
+    #[doc = " The string needs to be freed with `bw_string_free`."]
+    #[link_name = "\u{1}bw_Application_readClipboardFiles"]
+    pub fn cbw_Application_readClipboardFiles(
+        app: *mut cbw_Application,
+        paths: *mut cbw_StrSlice,
+    ) -> cBOOL;
 }
 extern "C" {
-    #[link_name = "\u{1}setbuf"]
-    pub fn csetbuf(__stream: *mut cFILE, __buf: *mut ::std::os::raw::c_char);
+    #[doc = " Gives the HTML fragment that is on the clipboard."]
+    #[doc = " Returns FALSE if the clipboard doesn't contain any HTML."]
+    #[doc = " The string needs to be freed with `bw_string_free`."]
+    #[link_name = "\u{1}bw_Application_readClipboardHtml"]
+    pub fn cbw_Application_readClipboardHtml(
+        app: *mut cbw_Application,
+        html: *mut cbw_StrSlice,
+    ) -> cBOOL;
 }
 extern "C" {
-    #[link_name = "\u{1}setvbuf"]
-    pub fn csetvbuf(
-        __stream: *mut cFILE,
-        __buf: *mut ::std::os::raw::c_char,
-        __modes: ::std::os::raw::c_int,
-        __n: csize_t,
-    ) -> ::std::os::raw::c_int;
+    #[doc = " Gives the image that is on the clipboard, as RGBA pixels that go row by row from the top, without any padding."]
+    #[doc = " Returns FALSE if the clipboard doesn't contain an image."]
+    #[doc = " The pixels need to be freed with `bw_Application_freeClipboardImage`."]
+    #[doc = ""]
+    #[doc = " *Note:* The clipboard is not supported when CEF provides the windows itself, it always appears to be empty and can't be written to."]
+    #[link_name = "\u{1}bw_Application_readClipboardImage"]
+    pub fn cbw_Application_readClipboardImage(
+        app: *mut cbw_Application,
+        pixels: *mut *mut u8,
+        width: *mut u32,
+        height: *mut u32,
+    ) -> cBOOL;
 }
 extern "C" {
-    #[link_name = "\u{1}setbuffer"]
-    pub fn csetbuffer(__stream: *mut cFILE, __buf: *mut ::std::os::raw::c_char, __size: csize_t);
+    #[doc = " Gives the text that is on the clipboard."]
+    #[doc = " Returns FALSE if the clipboard doesn't contain any text."]
+    #[doc = " The string needs to be freed with `bw_string_free`."]
+    #[link_name = "\u{1}bw_Application_readClipboardText"]
+    pub fn cbw_Application_readClipboardText(
+        app: *mut cbw_Application,
+        text: *mut cbw_StrSlice,
+    ) -> cBOOL;
 }
 extern "C" {
-    #[link_name = "\u{1}setlinebuf"]
-    pub fn csetlinebuf(__stream: *mut cFILE);
+    #[doc = " Registers a key combination, like `Ctrl+Alt+Space`, that invokes `handler` on the GUI thread whenever it is pressed."]
+    #[doc = " This happens even when none of the windows of the application has focus."]
+    #[doc = " `hotkey_id` should not be in use by another hotkey."]
+    #[doc = " Returns FALSE if the key combination is invalid, or if it can't be registered because another application has taken it already."]
+    #[doc = ""]
+    #[doc = " *Note:* Global hotkeys are only supported on Windows and on X11."]
+    #[doc = " Wayland compositors don't allow applications to grab keys, and CEF doesn't provide a way to do so when it provides the windows itself."]
+    #[link_name = "\u{1}bw_Application_registerGlobalHotkey"]
+    pub fn cbw_Application_registerGlobalHotkey(
+        app: *mut cbw_Application,
+        hotkey_id: u32,
+        hotkey: cbw_CStrSlice,
+        handler: cbw_ApplicationHotkeyFn,
+        data: *mut ::std::os::raw::c_void,
+    ) -> cBOOL;
 }
 extern "C" {
-    #[link_name = "\u{1}fprintf"]
-    pub fn cfprintf(
-        __stream: *mut cFILE,
-        __format: *const ::std::os::raw::c_char,
-        ...
-    ) -> ::std::os::raw::c_int;
+    #[doc = " Unregisters the global hotkey with the given ID."]
+    #[doc = " Returns the `data` that it has been registered with, so that it can be freed, or NULL if there is no such hotkey."]
+    #[link_name = "\u{1}bw_Application_unregisterGlobalHotkey"]
+    pub fn cbw_Application_unregisterGlobalHotkey(
+        app: *mut cbw_Application,
+        hotkey_id: u32,
+    ) -> *mut ::std::os::raw::c_void;
 }
 extern "C" {
-    #[link_name = "\u{1}printf"]
-    pub fn cprintf(__format: *const ::std::os::raw::c_char, ...) -> ::std::os::raw::c_int;
+    #[doc = " Processes all events that are pending, without waiting for new ones."]
+    #[doc = " Returns FALSE once the application has exited, in which case the exit code is stored in `exit_code`."]
+    #[link_name = "\u{1}bw_Application_pumpEvents"]
+    pub fn cbw_Application_pumpEvents(
+        app: *mut cbw_Application,
+        exit_code: *mut ::std::os::raw::c_int,
+    ) -> cBOOL;
 }
 extern "C" {
-    #[link_name = "\u{1}sprintf"]
-    pub fn csprintf(
-        __s: *mut ::std::os::raw::c_char,
-        __format: *const ::std::os::raw::c_char,
-        ...
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    #[link_name = "\u{1}vfprintf"]
-    pub fn cvfprintf(
-        __s: *mut cFILE,
-        __format: *const ::std::os::raw::c_char,
-        __arg: *mut c__va_list_tag,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    #[link_name = "\u{1}vprintf"]
-    pub fn cvprintf(
-        __format: *const ::std::os::raw::c_char,
-        __arg: *mut c__va_list_tag,
+    #[doc = " Runs the event loop."]
+    #[doc = " Calls the `on_ready` callback when `app` can be used."]
+    #[link_name = "\u{1}bw_Application_run"]
+    pub fn cbw_Application_run(
+        app: *mut cbw_Application,
+        on_ready: cbw_ApplicationReadyFn,
+        user_data: *mut ::std::os::raw::c_void,
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[link_name = "\u{1}vsprintf"]
-    pub fn cvsprintf(
-        __s: *mut ::std::os::raw::c_char,
-        __format: *const ::std::os::raw::c_char,
-        __arg: *mut c__va_list_tag,
-    ) -> ::std::os::raw::c_int;
+    #[doc = " Sets the function that is invoked on the GUI thread whenever the system switches between its light and dark theme."]
+    #[doc = " A previously set handler is replaced, and NULL stops the notifications."]
+    #[doc = ""]
+    #[doc = " *Note:* The handler is never invoked when CEF provides the windows itself."]
+    #[link_name = "\u{1}bw_Application_setThemeHandler"]
+    pub fn cbw_Application_setThemeHandler(
+        app: *mut cbw_Application,
+        handler: cbw_ApplicationThemeFn,
+        data: *mut ::std::os::raw::c_void,
+    );
 }
 extern "C" {
-    #[link_name = "\u{1}snprintf"]
-    pub fn csnprintf(
-        __s: *mut ::std::os::raw::c_char,
-        __maxlen: ::std::os::raw::c_ulong,
-        __format: *const ::std::os::raw::c_char,
-        ...
-    ) -> ::std::os::raw::c_int;
+    #[doc = " Executes the given closure after the specified delay."]
+    #[link_name = "\u{1}bw_Application_dispatchDelayed"]
+    pub fn cbw_Application_dispatchDelayed(
+        app: *mut cbw_Application,
+        func: cbw_ApplicationDispatchFn,
+        user_data: *mut ::std::os::raw::c_void,
+        milliseconds: u64,
+    ) -> cBOOL;
 }
 extern "C" {
-    #[link_name = "\u{1}vsnprintf"]
-    pub fn cvsnprintf(
-        __s: *mut ::std::os::raw::c_char,
-        __maxlen: ::std::os::raw::c_ulong,
-        __format: *const ::std::os::raw::c_char,
-        __arg: *mut c__va_list_tag,
-    ) -> ::std::os::raw::c_int;
+    #[doc = " Replaces the contents of the clipboard with a list of files, so that they can be pasted in a file manager."]
+    #[doc = " Every path in `paths` needs to be terminated by a null character."]
+    #[doc = " Returns FALSE if the clipboard can't be written to."]
+    #[link_name = "\u{1}bw_Application_writeClipboardFiles"]
+    pub fn cbw_Application_writeClipboardFiles(
+        app: *mut cbw_Application,
+        paths: cbw_CStrSlice,
+    ) -> cBOOL;
 }
 extern "C" {
-    #[link_name = "\u{1}vdprintf"]
-    pub fn cvdprintf(
-        __fd: ::std::os::raw::c_int,
-        __fmt: *const ::std::os::raw::c_char,
-        __arg: *mut c__va_list_tag,
-    ) -> ::std::os::raw::c_int;
+    #[doc = " Replaces the contents of the clipboard with an HTML fragment."]
+    #[doc = " Returns FALSE if the clipboard can't be written to."]
+    #[link_name = "\u{1}bw_Application_writeClipboardHtml"]
+    pub fn cbw_Application_writeClipboardHtml(
+        app: *mut cbw_Application,
+        html: cbw_CStrSlice,
+    ) -> cBOOL;
 }
 extern "C" {
-    #[link_name = "\u{1}dprintf"]
-    pub fn cdprintf(
-        __fd: ::std::os::raw::c_int,
-        __fmt: *const ::std::os::raw::c_char,
-        ...
-    ) -> ::std::os::raw::c_int;
+    #[doc = " Replaces the contents of the clipboard with an image, given as RGBA pixels that go row by row from the top, without any padding."]
+    #[doc = " Returns FALSE if the clipboard can't be written to."]
+    #[link_name = "\u{1}bw_Application_writeClipboardImage"]
+    pub fn cbw_Application_writeClipboardImage(
+        app: *mut cbw_Application,
+        pixels: *const u8,
+        width: u32,
+        height: u32,
+    ) -> cBOOL;
 }
 extern "C" {
-    #[link_name = "\u{1}fscanf"]
-    pub fn cfscanf(
-        __stream: *mut cFILE,
-        __format: *const ::std::os::raw::c_char,
-        ...
-    ) -> ::std::os::raw::c_int;
+    #[doc = " Replaces the contents of the clipboard with text."]
+    #[doc = " Returns FALSE if the clipboard can't be written to."]
+    #[link_name = "\u{1}bw_Application_writeClipboardText"]
+    pub fn cbw_Application_writeClipboardText(
+        app: *mut cbw_Application,
+        text: cbw_CStrSlice,
+    ) -> cBOOL;
 }
-extern "C" {
-    #[link_name = "\u{1}scanf"]
-    pub fn cscanf(__format: *const ::std::os::raw::c_char, ...) -> ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cbw_BrowserWindowImpl {
+    pub cef_ptr: *mut ::std::os::raw::c_void,
+    pub resource_path: *mut ::std::os::raw::c_char,
+    pub zoom: f64,
+    pub devtools: *mut ::std::os::raw::c_void,
 }
-extern "C" {
-    #[link_name = "\u{1}sscanf"]
-    pub fn csscanf(
-        __s: *const ::std::os::raw::c_char,
-        __format: *const ::std::os::raw::c_char,
-        ...
-    ) -> ::std::os::raw::c_int;
+#[test]
+fn bindgen_test_layout_cbw_BrowserWindowImpl() {
+    assert_eq!(
+        ::std::mem::size_of::<cbw_BrowserWindowImpl>(),
+        32usize,
+        concat!("Size of: ", stringify!(cbw_BrowserWindowImpl))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<cbw_BrowserWindowImpl>(),
+        8usize,
+        concat!("Alignment of ", stringify!(cbw_BrowserWindowImpl))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowImpl>())).cef_ptr as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowImpl),
+            "::",
+            stringify!(cef_ptr)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::std::ptr::null::<cbw_BrowserWindowImpl>())).resource_path as *const _ as usize
+        },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowImpl),
+            "::",
+            stringify!(resource_path)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowImpl>())).zoom as *const _ as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowImpl),
+            "::",
+            stringify!(zoom)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowImpl>())).devtools as *const _ as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowImpl),
+            "::",
+            stringify!(devtools)
+        )
+    );
 }
 extern "C" {
-    #[link_name = "\u{1}__isoc99_fscanf"]
-    pub fn cfscanf1(
-        __stream: *mut cFILE,
-        __format: *const ::std::os::raw::c_char,
-        ...
-    ) -> ::std::os::raw::c_int;
+    #[link_name = "\u{1}__assert_fail"]
+    pub fn c__assert_fail(
+        __assertion: *const ::std::os::raw::c_char,
+        __file: *const ::std::os::raw::c_char,
+        __line: ::std::os::raw::c_uint,
+        __function: *const ::std::os::raw::c_char,
+    );
 }
 extern "C" {
-    #[link_name = "\u{1}__isoc99_scanf"]
-    pub fn cscanf1(__format: *const ::std::os::raw::c_char, ...) -> ::std::os::raw::c_int;
+    #[link_name = "\u{1}__assert_perror_fail"]
+    pub fn c__assert_perror_fail(
+        __errnum: ::std::os::raw::c_int,
+        __file: *const ::std::os::raw::c_char,
+        __line: ::std::os::raw::c_uint,
+        __function: *const ::std::os::raw::c_char,
+    );
 }
 extern "C" {
-    #[link_name = "\u{1}__isoc99_sscanf"]
-    pub fn csscanf1(
-        __s: *const ::std::os::raw::c_char,
-        __format: *const ::std::os::raw::c_char,
-        ...
-    ) -> ::std::os::raw::c_int;
+    #[link_name = "\u{1}__assert"]
+    pub fn c__assert(
+        __assertion: *const ::std::os::raw::c_char,
+        __file: *const ::std::os::raw::c_char,
+        __line: ::std::os::raw::c_int,
+    );
 }
-extern "C" {
-    #[link_name = "\u{1}vfscanf"]
-    pub fn cvfscanf(
-        __s: *mut cFILE,
-        __format: *const ::std::os::raw::c_char,
-        __arg: *mut c__va_list_tag,
-    ) -> ::std::os::raw::c_int;
+pub type cva_list = c__builtin_va_list;
+pub type c__gnuc_va_list = c__builtin_va_list;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct c__mbstate_t {
+    pub __count: ::std::os::raw::c_int,
+    pub __value: c__mbstate_t__bindgen_ty_1,
 }
-extern "C" {
-    #[link_name = "\u{1}vscanf"]
-    pub fn cvscanf(
-        __format: *const ::std::os::raw::c_char,
-        __arg: *mut c__va_list_tag,
-    ) -> ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union c__mbstate_t__bindgen_ty_1 {
+    pub __wch: ::std::os::raw::c_uint,
+    pub __wchb: [::std::os::raw::c_char; 4usize],
+    _bindgen_union_align: u32,
 }
-extern "C" {
-    #[link_name = "\u{1}vsscanf"]
-    pub fn cvsscanf(
-        __s: *const ::std::os::raw::c_char,
-        __format: *const ::std::os::raw::c_char,
-        __arg: *mut c__va_list_tag,
-    ) -> ::std::os::raw::c_int;
+#[test]
+fn bindgen_test_layout_c__mbstate_t__bindgen_ty_1() {
+    assert_eq!(
+        ::std::mem::size_of::<c__mbstate_t__bindgen_ty_1>(),
+        4usize,
+        concat!("Size of: ", stringify!(c__mbstate_t__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<c__mbstate_t__bindgen_ty_1>(),
+        4usize,
+        concat!("Alignment of ", stringify!(c__mbstate_t__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::std::ptr::null::<c__mbstate_t__bindgen_ty_1>())).__wch as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c__mbstate_t__bindgen_ty_1),
+            "::",
+            stringify!(__wch)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::std::ptr::null::<c__mbstate_t__bindgen_ty_1>())).__wchb as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c__mbstate_t__bindgen_ty_1),
+            "::",
+            stringify!(__wchb)
+        )
+    );
 }
-extern "C" {
-    #[link_name = "\u{1}__isoc99_vfscanf"]
-    pub fn cvfscanf1(
-        __s: *mut cFILE,
-        __format: *const ::std::os::raw::c_char,
-        __arg: *mut c__va_list_tag,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    #[link_name = "\u{1}__isoc99_vscanf"]
-    pub fn cvscanf1(
-        __format: *const ::std::os::raw::c_char,
-        __arg: *mut c__va_list_tag,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    #[link_name = "\u{1}__isoc99_vsscanf"]
-    pub fn cvsscanf1(
-        __s: *const ::std::os::raw::c_char,
-        __format: *const ::std::os::raw::c_char,
-        __arg: *mut c__va_list_tag,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    #[link_name = "\u{1}fgetc"]
-    pub fn cfgetc(__stream: *mut cFILE) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    #[link_name = "\u{1}getc"]
-    pub fn cgetc(__stream: *mut cFILE) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    #[link_name = "\u{1}getchar"]
-    pub fn cgetchar() -> ::std::os::raw::c_int;
-}
-extern "C" {
-    #[link_name = "\u{1}getc_unlocked"]
-    pub fn cgetc_unlocked(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+#[test]
+fn bindgen_test_layout_c__mbstate_t() {
+    assert_eq!(
+        ::std::mem::size_of::<c__mbstate_t>(),
+        8usize,
+        concat!("Size of: ", stringify!(c__mbstate_t))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<c__mbstate_t>(),
+        4usize,
+        concat!("Alignment of ", stringify!(c__mbstate_t))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c__mbstate_t>())).__count as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c__mbstate_t),
+            "::",
+            stringify!(__count)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c__mbstate_t>())).__value as *const _ as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c__mbstate_t),
+            "::",
+            stringify!(__value)
+        )
+    );
 }
-extern "C" {
-    #[link_name = "\u{1}getchar_unlocked"]
-    pub fn cgetchar_unlocked() -> ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct c_G_fpos_t {
+    pub __pos: c__off_t,
+    pub __state: c__mbstate_t,
 }
-extern "C" {
-    #[link_name = "\u{1}fgetc_unlocked"]
-    pub fn cfgetc_unlocked(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+#[test]
+fn bindgen_test_layout_c_G_fpos_t() {
+    assert_eq!(
+        ::std::mem::size_of::<c_G_fpos_t>(),
+        16usize,
+        concat!("Size of: ", stringify!(c_G_fpos_t))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<c_G_fpos_t>(),
+        8usize,
+        concat!("Alignment of ", stringify!(c_G_fpos_t))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_G_fpos_t>())).__pos as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_G_fpos_t),
+            "::",
+            stringify!(__pos)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_G_fpos_t>())).__state as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_G_fpos_t),
+            "::",
+            stringify!(__state)
+        )
+    );
 }
-extern "C" {
-    #[link_name = "\u{1}fputc"]
-    pub fn cfputc(__c: ::std::os::raw::c_int, __stream: *mut cFILE) -> ::std::os::raw::c_int;
+pub type c__fpos_t = c_G_fpos_t;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct c_G_fpos64_t {
+    pub __pos: c__off64_t,
+    pub __state: c__mbstate_t,
 }
-extern "C" {
-    #[link_name = "\u{1}putc"]
-    pub fn cputc(__c: ::std::os::raw::c_int, __stream: *mut cFILE) -> ::std::os::raw::c_int;
+#[test]
+fn bindgen_test_layout_c_G_fpos64_t() {
+    assert_eq!(
+        ::std::mem::size_of::<c_G_fpos64_t>(),
+        16usize,
+        concat!("Size of: ", stringify!(c_G_fpos64_t))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<c_G_fpos64_t>(),
+        8usize,
+        concat!("Alignment of ", stringify!(c_G_fpos64_t))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_G_fpos64_t>())).__pos as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_G_fpos64_t),
+            "::",
+            stringify!(__pos)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_G_fpos64_t>())).__state as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_G_fpos64_t),
+            "::",
+            stringify!(__state)
+        )
+    );
 }
-extern "C" {
-    #[link_name = "\u{1}putchar"]
-    pub fn cputchar(__c: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+pub type c__fpos64_t = c_G_fpos64_t;
+pub type c__FILE = c_IO_FILE;
+pub type cFILE = c_IO_FILE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct c_IO_marker {
+    _unused: [u8; 0],
 }
-extern "C" {
-    #[link_name = "\u{1}fputc_unlocked"]
-    pub fn cfputc_unlocked(
-        __c: ::std::os::raw::c_int,
-        __stream: *mut cFILE,
-    ) -> ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct c_IO_codecvt {
+    _unused: [u8; 0],
 }
-extern "C" {
-    #[link_name = "\u{1}putc_unlocked"]
-    pub fn cputc_unlocked(
-        __c: ::std::os::raw::c_int,
-        __stream: *mut cFILE,
-    ) -> ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct c_IO_wide_data {
+    _unused: [u8; 0],
 }
-extern "C" {
-    #[link_name = "\u{1}putchar_unlocked"]
-    pub fn cputchar_unlocked(__c: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+pub type c_IO_lock_t = ::std::os::raw::c_void;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct c_IO_FILE {
+    pub _flags: ::std::os::raw::c_int,
+    pub _IO_read_ptr: *mut ::std::os::raw::c_char,
+    pub _IO_read_end: *mut ::std::os::raw::c_char,
+    pub _IO_read_base: *mut ::std::os::raw::c_char,
+    pub _IO_write_base: *mut ::std::os::raw::c_char,
+    pub _IO_write_ptr: *mut ::std::os::raw::c_char,
+    pub _IO_write_end: *mut ::std::os::raw::c_char,
+    pub _IO_buf_base: *mut ::std::os::raw::c_char,
+    pub _IO_buf_end: *mut ::std::os::raw::c_char,
+    pub _IO_save_base: *mut ::std::os::raw::c_char,
+    pub _IO_backup_base: *mut ::std::os::raw::c_char,
+    pub _IO_save_end: *mut ::std::os::raw::c_char,
+    pub _markers: *mut c_IO_marker,
+    pub _chain: *mut c_IO_FILE,
+    pub _fileno: ::std::os::raw::c_int,
+    pub _flags2: ::std::os::raw::c_int,
+    pub _old_offset: c__off_t,
+    pub _cur_column: ::std::os::raw::c_ushort,
+    pub _vtable_offset: ::std::os::raw::c_schar,
+    pub _shortbuf: [::std::os::raw::c_char; 1usize],
+    pub _lock: *mut c_IO_lock_t,
+    pub _offset: c__off64_t,
+    pub _codecvt: *mut c_IO_codecvt,
+    pub _wide_data: *mut c_IO_wide_data,
+    pub _freeres_list: *mut c_IO_FILE,
+    pub _freeres_buf: *mut ::std::os::raw::c_void,
+    pub __pad5: csize_t,
+    pub _mode: ::std::os::raw::c_int,
+    pub _unused2: [::std::os::raw::c_char; 20usize],
 }
-extern "C" {
-    #[link_name = "\u{1}getw"]
-    pub fn cgetw(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+#[test]
+fn bindgen_test_layout_c_IO_FILE() {
+    assert_eq!(
+        ::std::mem::size_of::<c_IO_FILE>(),
+        216usize,
+        concat!("Size of: ", stringify!(c_IO_FILE))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<c_IO_FILE>(),
+        8usize,
+        concat!("Alignment of ", stringify!(c_IO_FILE))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._flags as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_flags)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._IO_read_ptr as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_IO_read_ptr)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._IO_read_end as *const _ as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_IO_read_end)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._IO_read_base as *const _ as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_IO_read_base)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._IO_write_base as *const _ as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_IO_write_base)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._IO_write_ptr as *const _ as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_IO_write_ptr)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._IO_write_end as *const _ as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_IO_write_end)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._IO_buf_base as *const _ as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_IO_buf_base)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._IO_buf_end as *const _ as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_IO_buf_end)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._IO_save_base as *const _ as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_IO_save_base)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._IO_backup_base as *const _ as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_IO_backup_base)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._IO_save_end as *const _ as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_IO_save_end)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._markers as *const _ as usize },
+        96usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_markers)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._chain as *const _ as usize },
+        104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_chain)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._fileno as *const _ as usize },
+        112usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_fileno)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._flags2 as *const _ as usize },
+        116usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_flags2)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._old_offset as *const _ as usize },
+        120usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_old_offset)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._cur_column as *const _ as usize },
+        128usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_cur_column)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._vtable_offset as *const _ as usize },
+        130usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_vtable_offset)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._shortbuf as *const _ as usize },
+        131usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_shortbuf)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._lock as *const _ as usize },
+        136usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_lock)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._offset as *const _ as usize },
+        144usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_offset)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._codecvt as *const _ as usize },
+        152usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_codecvt)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._wide_data as *const _ as usize },
+        160usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_wide_data)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._freeres_list as *const _ as usize },
+        168usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_freeres_list)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._freeres_buf as *const _ as usize },
+        176usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_freeres_buf)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>())).__pad5 as *const _ as usize },
+        184usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(__pad5)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._mode as *const _ as usize },
+        192usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_mode)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<c_IO_FILE>()))._unused2 as *const _ as usize },
+        196usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(c_IO_FILE),
+            "::",
+            stringify!(_unused2)
+        )
+    );
+}
+pub type cfpos_t = c__fpos_t;
+extern "C" {
+    #[link_name = "\u{1}stdin"]
+    pub static mut cstdin: *mut cFILE;
+}
+extern "C" {
+    #[link_name = "\u{1}stdout"]
+    pub static mut cstdout: *mut cFILE;
+}
+extern "C" {
+    #[link_name = "\u{1}stderr"]
+    pub static mut cstderr: *mut cFILE;
+}
+extern "C" {
+    #[link_name = "\u{1}remove"]
+    pub fn cremove(__filename: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}rename"]
+    pub fn crename(
+        __old: *const ::std::os::raw::c_char,
+        __new: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}renameat"]
+    pub fn crenameat(
+        __oldfd: ::std::os::raw::c_int,
+        __old: *const ::std::os::raw::c_char,
+        __newfd: ::std::os::raw::c_int,
+        __new: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}tmpfile"]
+    pub fn ctmpfile() -> *mut cFILE;
+}
+extern "C" {
+    #[link_name = "\u{1}tmpnam"]
+    pub fn ctmpnam(__s: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    #[link_name = "\u{1}tmpnam_r"]
+    pub fn ctmpnam_r(__s: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    #[link_name = "\u{1}tempnam"]
+    pub fn ctempnam(
+        __dir: *const ::std::os::raw::c_char,
+        __pfx: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    #[link_name = "\u{1}fclose"]
+    pub fn cfclose(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}fflush"]
+    pub fn cfflush(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}fflush_unlocked"]
+    pub fn cfflush_unlocked(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}fopen"]
+    pub fn cfopen(
+        __filename: *const ::std::os::raw::c_char,
+        __modes: *const ::std::os::raw::c_char,
+    ) -> *mut cFILE;
+}
+extern "C" {
+    #[link_name = "\u{1}freopen"]
+    pub fn cfreopen(
+        __filename: *const ::std::os::raw::c_char,
+        __modes: *const ::std::os::raw::c_char,
+        __stream: *mut cFILE,
+    ) -> *mut cFILE;
+}
+extern "C" {
+    #[link_name = "\u{1}fdopen"]
+    pub fn cfdopen(
+        __fd: ::std::os::raw::c_int,
+        __modes: *const ::std::os::raw::c_char,
+    ) -> *mut cFILE;
+}
+extern "C" {
+    #[link_name = "\u{1}fmemopen"]
+    pub fn cfmemopen(
+        __s: *mut ::std::os::raw::c_void,
+        __len: csize_t,
+        __modes: *const ::std::os::raw::c_char,
+    ) -> *mut cFILE;
+}
+extern "C" {
+    #[link_name = "\u{1}open_memstream"]
+    pub fn copen_memstream(
+        __bufloc: *mut *mut ::std::os::raw::c_char,
+        __sizeloc: *mut csize_t,
+    ) -> *mut cFILE;
+}
+extern "C" {
+    #[link_name = "\u{1}setbuf"]
+    pub fn csetbuf(__stream: *mut cFILE, __buf: *mut ::std::os::raw::c_char);
+}
+extern "C" {
+    #[link_name = "\u{1}setvbuf"]
+    pub fn csetvbuf(
+        __stream: *mut cFILE,
+        __buf: *mut ::std::os::raw::c_char,
+        __modes: ::std::os::raw::c_int,
+        __n: csize_t,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}setbuffer"]
+    pub fn csetbuffer(__stream: *mut cFILE, __buf: *mut ::std::os::raw::c_char, __size: csize_t);
+}
+extern "C" {
+    #[link_name = "\u{1}setlinebuf"]
+    pub fn csetlinebuf(__stream: *mut cFILE);
+}
+extern "C" {
+    #[link_name = "\u{1}fprintf"]
+    pub fn cfprintf(
+        __stream: *mut cFILE,
+        __format: *const ::std::os::raw::c_char,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}printf"]
+    pub fn cprintf(__format: *const ::std::os::raw::c_char, ...) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}sprintf"]
+    pub fn csprintf(
+        __s: *mut ::std::os::raw::c_char,
+        __format: *const ::std::os::raw::c_char,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}vfprintf"]
+    pub fn cvfprintf(
+        __s: *mut cFILE,
+        __format: *const ::std::os::raw::c_char,
+        __arg: *mut c__va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}vprintf"]
+    pub fn cvprintf(
+        __format: *const ::std::os::raw::c_char,
+        __arg: *mut c__va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}vsprintf"]
+    pub fn cvsprintf(
+        __s: *mut ::std::os::raw::c_char,
+        __format: *const ::std::os::raw::c_char,
+        __arg: *mut c__va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}snprintf"]
+    pub fn csnprintf(
+        __s: *mut ::std::os::raw::c_char,
+        __maxlen: ::std::os::raw::c_ulong,
+        __format: *const ::std::os::raw::c_char,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}vsnprintf"]
+    pub fn cvsnprintf(
+        __s: *mut ::std::os::raw::c_char,
+        __maxlen: ::std::os::raw::c_ulong,
+        __format: *const ::std::os::raw::c_char,
+        __arg: *mut c__va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}vdprintf"]
+    pub fn cvdprintf(
+        __fd: ::std::os::raw::c_int,
+        __fmt: *const ::std::os::raw::c_char,
+        __arg: *mut c__va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}dprintf"]
+    pub fn cdprintf(
+        __fd: ::std::os::raw::c_int,
+        __fmt: *const ::std::os::raw::c_char,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}fscanf"]
+    pub fn cfscanf(
+        __stream: *mut cFILE,
+        __format: *const ::std::os::raw::c_char,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}scanf"]
+    pub fn cscanf(__format: *const ::std::os::raw::c_char, ...) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}sscanf"]
+    pub fn csscanf(
+        __s: *const ::std::os::raw::c_char,
+        __format: *const ::std::os::raw::c_char,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}__isoc99_fscanf"]
+    pub fn cfscanf1(
+        __stream: *mut cFILE,
+        __format: *const ::std::os::raw::c_char,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}__isoc99_scanf"]
+    pub fn cscanf1(__format: *const ::std::os::raw::c_char, ...) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}__isoc99_sscanf"]
+    pub fn csscanf1(
+        __s: *const ::std::os::raw::c_char,
+        __format: *const ::std::os::raw::c_char,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}vfscanf"]
+    pub fn cvfscanf(
+        __s: *mut cFILE,
+        __format: *const ::std::os::raw::c_char,
+        __arg: *mut c__va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}vscanf"]
+    pub fn cvscanf(
+        __format: *const ::std::os::raw::c_char,
+        __arg: *mut c__va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}vsscanf"]
+    pub fn cvsscanf(
+        __s: *const ::std::os::raw::c_char,
+        __format: *const ::std::os::raw::c_char,
+        __arg: *mut c__va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}__isoc99_vfscanf"]
+    pub fn cvfscanf1(
+        __s: *mut cFILE,
+        __format: *const ::std::os::raw::c_char,
+        __arg: *mut c__va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}__isoc99_vscanf"]
+    pub fn cvscanf1(
+        __format: *const ::std::os::raw::c_char,
+        __arg: *mut c__va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}__isoc99_vsscanf"]
+    pub fn cvsscanf1(
+        __s: *const ::std::os::raw::c_char,
+        __format: *const ::std::os::raw::c_char,
+        __arg: *mut c__va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}fgetc"]
+    pub fn cfgetc(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}getc"]
+    pub fn cgetc(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}getchar"]
+    pub fn cgetchar() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}getc_unlocked"]
+    pub fn cgetc_unlocked(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}getchar_unlocked"]
+    pub fn cgetchar_unlocked() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}fgetc_unlocked"]
+    pub fn cfgetc_unlocked(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}fputc"]
+    pub fn cfputc(__c: ::std::os::raw::c_int, __stream: *mut cFILE) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}putc"]
+    pub fn cputc(__c: ::std::os::raw::c_int, __stream: *mut cFILE) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}putchar"]
+    pub fn cputchar(__c: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}fputc_unlocked"]
+    pub fn cfputc_unlocked(
+        __c: ::std::os::raw::c_int,
+        __stream: *mut cFILE,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}putc_unlocked"]
+    pub fn cputc_unlocked(
+        __c: ::std::os::raw::c_int,
+        __stream: *mut cFILE,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}putchar_unlocked"]
+    pub fn cputchar_unlocked(__c: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}getw"]
+    pub fn cgetw(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}putw"]
+    pub fn cputw(__w: ::std::os::raw::c_int, __stream: *mut cFILE) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}fgets"]
+    pub fn cfgets(
+        __s: *mut ::std::os::raw::c_char,
+        __n: ::std::os::raw::c_int,
+        __stream: *mut cFILE,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    #[link_name = "\u{1}__getdelim"]
+    pub fn c__getdelim(
+        __lineptr: *mut *mut ::std::os::raw::c_char,
+        __n: *mut csize_t,
+        __delimiter: ::std::os::raw::c_int,
+        __stream: *mut cFILE,
+    ) -> c__ssize_t;
+}
+extern "C" {
+    #[link_name = "\u{1}getdelim"]
+    pub fn cgetdelim(
+        __lineptr: *mut *mut ::std::os::raw::c_char,
+        __n: *mut csize_t,
+        __delimiter: ::std::os::raw::c_int,
+        __stream: *mut cFILE,
+    ) -> c__ssize_t;
+}
+extern "C" {
+    #[link_name = "\u{1}getline"]
+    pub fn cgetline(
+        __lineptr: *mut *mut ::std::os::raw::c_char,
+        __n: *mut csize_t,
+        __stream: *mut cFILE,
+    ) -> c__ssize_t;
+}
+extern "C" {
+    #[link_name = "\u{1}fputs"]
+    pub fn cfputs(
+        __s: *const ::std::os::raw::c_char,
+        __stream: *mut cFILE,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}puts"]
+    pub fn cputs(__s: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}ungetc"]
+    pub fn cungetc(__c: ::std::os::raw::c_int, __stream: *mut cFILE) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}fread"]
+    pub fn cfread(
+        __ptr: *mut ::std::os::raw::c_void,
+        __size: ::std::os::raw::c_ulong,
+        __n: ::std::os::raw::c_ulong,
+        __stream: *mut cFILE,
+    ) -> ::std::os::raw::c_ulong;
+}
+extern "C" {
+    #[link_name = "\u{1}fwrite"]
+    pub fn cfwrite(
+        __ptr: *const ::std::os::raw::c_void,
+        __size: ::std::os::raw::c_ulong,
+        __n: ::std::os::raw::c_ulong,
+        __s: *mut cFILE,
+    ) -> ::std::os::raw::c_ulong;
+}
+extern "C" {
+    #[link_name = "\u{1}fread_unlocked"]
+    pub fn cfread_unlocked(
+        __ptr: *mut ::std::os::raw::c_void,
+        __size: csize_t,
+        __n: csize_t,
+        __stream: *mut cFILE,
+    ) -> csize_t;
+}
+extern "C" {
+    #[link_name = "\u{1}fwrite_unlocked"]
+    pub fn cfwrite_unlocked(
+        __ptr: *const ::std::os::raw::c_void,
+        __size: csize_t,
+        __n: csize_t,
+        __stream: *mut cFILE,
+    ) -> csize_t;
+}
+extern "C" {
+    #[link_name = "\u{1}fseek"]
+    pub fn cfseek(
+        __stream: *mut cFILE,
+        __off: ::std::os::raw::c_long,
+        __whence: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}ftell"]
+    pub fn cftell(__stream: *mut cFILE) -> ::std::os::raw::c_long;
+}
+extern "C" {
+    #[link_name = "\u{1}rewind"]
+    pub fn crewind(__stream: *mut cFILE);
+}
+extern "C" {
+    #[link_name = "\u{1}fseeko"]
+    pub fn cfseeko(
+        __stream: *mut cFILE,
+        __off: c__off_t,
+        __whence: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}ftello"]
+    pub fn cftello(__stream: *mut cFILE) -> c__off_t;
+}
+extern "C" {
+    #[link_name = "\u{1}fgetpos"]
+    pub fn cfgetpos(__stream: *mut cFILE, __pos: *mut cfpos_t) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}fsetpos"]
+    pub fn cfsetpos(__stream: *mut cFILE, __pos: *const cfpos_t) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}clearerr"]
+    pub fn cclearerr(__stream: *mut cFILE);
+}
+extern "C" {
+    #[link_name = "\u{1}feof"]
+    pub fn cfeof(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}ferror"]
+    pub fn cferror(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}clearerr_unlocked"]
+    pub fn cclearerr_unlocked(__stream: *mut cFILE);
+}
+extern "C" {
+    #[link_name = "\u{1}feof_unlocked"]
+    pub fn cfeof_unlocked(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}ferror_unlocked"]
+    pub fn cferror_unlocked(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}perror"]
+    pub fn cperror(__s: *const ::std::os::raw::c_char);
+}
+extern "C" {
+    #[link_name = "\u{1}sys_nerr"]
+    pub static mut csys_nerr: ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}sys_errlist"]
+    pub static mut csys_errlist: [*const ::std::os::raw::c_char; 0usize];
+}
+extern "C" {
+    #[link_name = "\u{1}fileno"]
+    pub fn cfileno(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}fileno_unlocked"]
+    pub fn cfileno_unlocked(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}popen"]
+    pub fn cpopen(
+        __command: *const ::std::os::raw::c_char,
+        __modes: *const ::std::os::raw::c_char,
+    ) -> *mut cFILE;
+}
+extern "C" {
+    #[link_name = "\u{1}pclose"]
+    pub fn cpclose(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}ctermid"]
+    pub fn cctermid(__s: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    #[link_name = "\u{1}flockfile"]
+    pub fn cflockfile(__stream: *mut cFILE);
+}
+extern "C" {
+    #[link_name = "\u{1}ftrylockfile"]
+    pub fn cftrylockfile(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}funlockfile"]
+    pub fn cfunlockfile(__stream: *mut cFILE);
+}
+extern "C" {
+    #[link_name = "\u{1}__uflow"]
+    pub fn c__uflow(arg1: *mut cFILE) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}__overflow"]
+    pub fn c__overflow(arg1: *mut cFILE, arg2: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cbw_Dims2D {
+    pub width: u16,
+    pub height: u16,
+}
+#[test]
+fn bindgen_test_layout_cbw_Dims2D() {
+    assert_eq!(
+        ::std::mem::size_of::<cbw_Dims2D>(),
+        4usize,
+        concat!("Size of: ", stringify!(cbw_Dims2D))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<cbw_Dims2D>(),
+        2usize,
+        concat!("Alignment of ", stringify!(cbw_Dims2D))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_Dims2D>())).width as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_Dims2D),
+            "::",
+            stringify!(width)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_Dims2D>())).height as *const _ as usize },
+        2usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_Dims2D),
+            "::",
+            stringify!(height)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cbw_Pos2D {
+    pub x: u16,
+    pub y: u16,
+}
+#[test]
+fn bindgen_test_layout_cbw_Pos2D() {
+    assert_eq!(
+        ::std::mem::size_of::<cbw_Pos2D>(),
+        4usize,
+        concat!("Size of: ", stringify!(cbw_Pos2D))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<cbw_Pos2D>(),
+        2usize,
+        concat!("Alignment of ", stringify!(cbw_Pos2D))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_Pos2D>())).x as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_Pos2D),
+            "::",
+            stringify!(x)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_Pos2D>())).y as *const _ as usize },
+        2usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_Pos2D),
+            "::",
+            stringify!(y)
+        )
+    );
+}
+#[doc = " A menu that holds items, separators and submenus."]
+#[doc = " When used as a menu bar, it should only hold submenus, which will become the menus on the bar."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cbw_Menu {
+    _unused: [u8; 0],
+}
+extern "C" {
+    #[doc = " Appends an item to the menu."]
+    #[doc = ""]
+    #[doc = " # Arguments"]
+    #[doc = " * `item_id` - The identifier that is passed to the menu handler of the window when the item gets activated. May not be 0."]
+    #[doc = " * `label` - The text of the item. A `&` marks the character after it as the mnemonic, `&&` is a literal `&`."]
+    #[doc = " * `accelerator` - A key combination like `Ctrl+Shift+S`, or an empty string for no accelerator."]
+    #[doc = " * `checkable` - Makes the item toggle a check mark every time it gets activated."]
+    #[doc = " * `checked` - Whether or not a checkable item starts out checked."]
+    #[link_name = "\u{1}bw_Menu_addItem"]
+    pub fn cbw_Menu_addItem(
+        menu: *mut cbw_Menu,
+        item_id: u32,
+        label: cbw_CStrSlice,
+        accelerator: cbw_CStrSlice,
+        checkable: cBOOL,
+        checked: cBOOL,
+    );
+}
+extern "C" {
+    #[link_name = "\u{1}bw_Menu_addSeparator"]
+    pub fn cbw_Menu_addSeparator(menu: *mut cbw_Menu);
+}
+extern "C" {
+    #[doc = " Appends a submenu to the menu."]
+    #[doc = " `menu` takes ownership of `submenu`, so it is freed together with `menu`."]
+    #[link_name = "\u{1}bw_Menu_addSubmenu"]
+    pub fn cbw_Menu_addSubmenu(menu: *mut cbw_Menu, label: cbw_CStrSlice, submenu: *mut cbw_Menu);
+}
+extern "C" {
+    #[doc = " Frees the menu, together with all of its submenus."]
+    #[link_name = "\u{1}bw_Menu_free"]
+    pub fn cbw_Menu_free(menu: *mut cbw_Menu);
+}
+extern "C" {
+    #[link_name = "\u{1}bw_Menu_new"]
+    pub fn cbw_Menu_new() -> *mut cbw_Menu;
+}
+#[doc = " An icon in the notification area of the taskbar, or the system tray."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cbw_TrayIcon {
+    _unused: [u8; 0],
+}
+#[doc = " Invoked when the tray icon has been clicked on with the left mouse button."]
+pub type cbw_TrayIconClickFn = ::std::option::Option<
+    unsafe extern "C" fn(icon: *mut cbw_TrayIcon, data: *mut ::std::os::raw::c_void),
+>;
+#[doc = " Invoked when an item of the tray icon's context menu has been activated, with the new check state of the item."]
+pub type cbw_TrayIconMenuHandlerFn = ::std::option::Option<
+    unsafe extern "C" fn(
+        icon: *mut cbw_TrayIcon,
+        item_id: u32,
+        checked: cBOOL,
+        data: *mut ::std::os::raw::c_void,
+    ),
+>;
+extern "C" {
+    #[doc = " Removes the tray icon and frees it."]
+    #[doc = " The application keeps running for as long as it has tray icons, just like it does for windows."]
+    #[link_name = "\u{1}bw_TrayIcon_free"]
+    pub fn cbw_TrayIcon_free(icon: *mut cbw_TrayIcon);
+}
+extern "C" {
+    #[doc = " Puts a new icon in the system tray."]
+    #[doc = ""]
+    #[doc = " # Arguments"]
+    #[doc = " * `icon_path` - The image file of the icon, which needs to be an `.ico` file on Windows. If empty or if it can't be loaded, the default application icon is used."]
+    #[doc = " * `tooltip` - The text that is shown when hovering over the icon."]
+    #[doc = " * `menu` - The context menu of the icon, or NULL for none. It is only read during this call."]
+    #[doc = " * `on_click` - May be NULL."]
+    #[doc = " * `on_menu_item` - May be NULL."]
+    #[doc = ""]
+    #[doc = " *Note:* Tray icons are not supported when CEF provides the windows itself. The icon is never shown then."]
+    #[link_name = "\u{1}bw_TrayIcon_new"]
+    pub fn cbw_TrayIcon_new(
+        app: *mut cbw_Application,
+        icon_path: cbw_CStrSlice,
+        tooltip: cbw_CStrSlice,
+        menu: *const cbw_Menu,
+        on_click: cbw_TrayIconClickFn,
+        on_menu_item: cbw_TrayIconMenuHandlerFn,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut cbw_TrayIcon;
+}
+extern "C" {
+    #[doc = " Replaces the image of the icon."]
+    #[doc = " See `bw_TrayIcon_new` for the requirements of `icon_path`."]
+    #[link_name = "\u{1}bw_TrayIcon_setIcon"]
+    pub fn cbw_TrayIcon_setIcon(icon: *mut cbw_TrayIcon, icon_path: cbw_CStrSlice);
+}
+extern "C" {
+    #[link_name = "\u{1}bw_TrayIcon_setTooltip"]
+    pub fn cbw_TrayIcon_setTooltip(icon: *mut cbw_TrayIcon, tooltip: cbw_CStrSlice);
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cbw_WindowImpl {}
+#[test]
+fn bindgen_test_layout_cbw_WindowImpl() {
+    assert_eq!(
+        ::std::mem::size_of::<cbw_WindowImpl>(),
+        0usize,
+        concat!("Size of: ", stringify!(cbw_WindowImpl))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<cbw_WindowImpl>(),
+        1usize,
+        concat!("Alignment of ", stringify!(cbw_WindowImpl))
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cbw_WindowCallbacks {
+    #[doc = " Fired just before the window gets destroyed and freed from memory."]
+    #[doc = " Should be implemented to free the user data provided to the window."]
+    pub do_cleanup: ::std::option::Option<unsafe extern "C" fn(arg1: *mut cbw_Window)>,
+    #[doc = " Fired when the window has been closed, either by the user or programmatically."]
+    pub on_close: ::std::option::Option<unsafe extern "C" fn(arg1: *const cbw_Window)>,
+    #[doc = " Fired when a window has finished loading"]
+    pub on_loaded: ::std::option::Option<unsafe extern "C" fn(arg1: *const cbw_Window)>,
+    #[doc = " Fired when a window is resizing"]
+    pub on_resize: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *const cbw_Window,
+            width: ::std::os::raw::c_uint,
+            height: ::std::os::raw::c_uint,
+        ),
+    >,
+    #[doc = " Fired when the window has moved to a display with another scale factor, or when the scale factor of its display has changed."]
+    pub on_scale_factor_changed: ::std::option::Option<
+        unsafe extern "C" fn(arg1: *const cbw_Window, scale_factor: f32),
+    >,
+    #[doc = " Fired when the window has moved, with the new position of its top left corner."]
+    pub on_move: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *const cbw_Window,
+            x: ::std::os::raw::c_int,
+            y: ::std::os::raw::c_int,
+        ),
+    >,
+    #[doc = " Fired when the window has gained or lost the keyboard focus."]
+    pub on_focus: ::std::option::Option<unsafe extern "C" fn(arg1: *const cbw_Window, focused: cBOOL)>,
+}
+#[test]
+fn bindgen_test_layout_cbw_WindowCallbacks() {
+    assert_eq!(
+        ::std::mem::size_of::<cbw_WindowCallbacks>(),
+        56usize,
+        concat!("Size of: ", stringify!(cbw_WindowCallbacks))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<cbw_WindowCallbacks>(),
+        8usize,
+        concat!("Alignment of ", stringify!(cbw_WindowCallbacks))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowCallbacks>())).do_cleanup as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowCallbacks),
+            "::",
+            stringify!(do_cleanup)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowCallbacks>())).on_close as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowCallbacks),
+            "::",
+            stringify!(on_close)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowCallbacks>())).on_loaded as *const _ as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowCallbacks),
+            "::",
+            stringify!(on_loaded)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowCallbacks>())).on_resize as *const _ as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowCallbacks),
+            "::",
+            stringify!(on_resize)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::std::ptr::null::<cbw_WindowCallbacks>())).on_scale_factor_changed as *const _
+                as usize
+        },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowCallbacks),
+            "::",
+            stringify!(on_scale_factor_changed)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowCallbacks>())).on_move as *const _ as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowCallbacks),
+            "::",
+            stringify!(on_move)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowCallbacks>())).on_focus as *const _ as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowCallbacks),
+            "::",
+            stringify!(on_focus)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cbw_WindowOptions {
+    pub borders: bool,
+    pub minimizable: bool,
+    pub resizable: bool,
+    #[doc = " A small utility window, which has a thin titlebar and doesn't appear in the taskbar or when alt-tabbing."]
+    pub tool_window: bool,
+    #[doc = " The material behind the window's content: 0 for none, 1 for mica, 2 for acrylic or 3 for vibrancy. Falls back to none when it isn't supported."]
+    pub backdrop: u8,
+    #[doc = " 0 for the platform's default, 1 to show a drop shadow around the window or 2 to hide it."]
+    pub shadow: u8,
+    #[doc = " The radius of the window's corners in pixels, or -1 for the platform's default."]
+    pub corner_radius: i16,
+    #[doc = " How the content keeps up with the window while it is being resized: 0 for the platform's default, 1 to resize and repaint it before the resize completes, or 2 to fill the uncovered area with the background color."]
+    pub resize_strategy: u8,
+    #[doc = " Whether the width and height given to `bw_Window_new` are in physical pixels, instead of in logical pixels that are scaled by the scale factor of the display."]
+    pub physical_size: bool,
+    #[doc = " The menu bar of the window, or NULL for none. It is only read during the creation of the window."]
+    pub menu_bar: *const cbw_Menu,
+    #[doc = " The window that this window stays above and is centered on, or NULL for none."]
+    pub owner: *const cbw_Window,
+    #[doc = " Whether the owner can't be interacted with while this window is visible. Only has an effect if there is an owner."]
+    pub modal: bool,
+    #[doc = " A native window of another toolkit, like the HWND of a window of winit, that this window is embedded in as a child filling its client area, or NULL for none. Only supported on Windows, and ignored elsewhere."]
+    pub native_parent: *mut ::std::os::raw::c_void,
+    #[doc = " The height in physical pixels of the area at the top of the client area of `native_parent` that is left uncovered, like for a tab strip."]
+    pub native_parent_top: u16,
+}
+#[test]
+fn bindgen_test_layout_cbw_WindowOptions() {
+    assert_eq!(
+        ::std::mem::size_of::<cbw_WindowOptions>(),
+        56usize,
+        concat!("Size of: ", stringify!(cbw_WindowOptions))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<cbw_WindowOptions>(),
+        8usize,
+        concat!("Alignment of ", stringify!(cbw_WindowOptions))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowOptions>())).borders as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowOptions),
+            "::",
+            stringify!(borders)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowOptions>())).minimizable as *const _ as usize },
+        1usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowOptions),
+            "::",
+            stringify!(minimizable)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowOptions>())).resizable as *const _ as usize },
+        2usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowOptions),
+            "::",
+            stringify!(resizable)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowOptions>())).tool_window as *const _ as usize },
+        3usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowOptions),
+            "::",
+            stringify!(tool_window)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowOptions>())).backdrop as *const _ as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowOptions),
+            "::",
+            stringify!(backdrop)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowOptions>())).shadow as *const _ as usize },
+        5usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowOptions),
+            "::",
+            stringify!(shadow)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowOptions>())).corner_radius as *const _ as usize },
+        6usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowOptions),
+            "::",
+            stringify!(corner_radius)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowOptions>())).resize_strategy as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowOptions),
+            "::",
+            stringify!(resize_strategy)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowOptions>())).physical_size as *const _ as usize },
+        9usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowOptions),
+            "::",
+            stringify!(physical_size)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowOptions>())).menu_bar as *const _ as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowOptions),
+            "::",
+            stringify!(menu_bar)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowOptions>())).owner as *const _ as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowOptions),
+            "::",
+            stringify!(owner)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowOptions>())).modal as *const _ as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowOptions),
+            "::",
+            stringify!(modal)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowOptions>())).native_parent as *const _ as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowOptions),
+            "::",
+            stringify!(native_parent)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowOptions>())).native_parent_top as *const _ as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowOptions),
+            "::",
+            stringify!(native_parent_top)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cbw_WindowNativeHandle {
+    #[doc = " 1 for a Win32 HWND in `window` with its HINSTANCE in `display`, 2 for an X11 window in `xid` with its `Display*` in `display`, or 3 for a Wayland `wl_surface*` in `window` with its `wl_display*` in `display`."]
+    pub kind: u8,
+    pub window: *mut ::std::os::raw::c_void,
+    #[doc = " May be NULL if it is unknown."]
+    pub display: *mut ::std::os::raw::c_void,
+    pub xid: ::std::os::raw::c_ulong,
+}
+#[test]
+fn bindgen_test_layout_cbw_WindowNativeHandle() {
+    assert_eq!(
+        ::std::mem::size_of::<cbw_WindowNativeHandle>(),
+        32usize,
+        concat!("Size of: ", stringify!(cbw_WindowNativeHandle))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<cbw_WindowNativeHandle>(),
+        8usize,
+        concat!("Alignment of ", stringify!(cbw_WindowNativeHandle))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowNativeHandle>())).kind as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowNativeHandle),
+            "::",
+            stringify!(kind)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowNativeHandle>())).window as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowNativeHandle),
+            "::",
+            stringify!(window)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowNativeHandle>())).display as *const _ as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowNativeHandle),
+            "::",
+            stringify!(display)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowNativeHandle>())).xid as *const _ as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowNativeHandle),
+            "::",
+            stringify!(xid)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cbw_WindowMessageBox {
+    #[doc = " The icon of the message box: 0 for information, 1 for a warning, 2 for an error or 3 for a question."]
+    pub kind: u8,
+    pub title: cbw_CStrSlice,
+    pub message: cbw_CStrSlice,
+    #[doc = " The captions of the buttons, in the order in which they are shown."]
+    pub buttons: *const cbw_CStrSlice,
+    pub button_count: usize,
+}
+#[test]
+fn bindgen_test_layout_cbw_WindowMessageBox() {
+    assert_eq!(
+        ::std::mem::size_of::<cbw_WindowMessageBox>(),
+        56usize,
+        concat!("Size of: ", stringify!(cbw_WindowMessageBox))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<cbw_WindowMessageBox>(),
+        8usize,
+        concat!("Alignment of ", stringify!(cbw_WindowMessageBox))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowMessageBox>())).kind as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowMessageBox),
+            "::",
+            stringify!(kind)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowMessageBox>())).title as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowMessageBox),
+            "::",
+            stringify!(title)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowMessageBox>())).message as *const _ as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowMessageBox),
+            "::",
+            stringify!(message)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowMessageBox>())).buttons as *const _ as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowMessageBox),
+            "::",
+            stringify!(buttons)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_WindowMessageBox>())).button_count as *const _ as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_WindowMessageBox),
+            "::",
+            stringify!(button_count)
+        )
+    );
 }
-extern "C" {
-    #[link_name = "\u{1}putw"]
-    pub fn cputw(__w: ::std::os::raw::c_int, __stream: *mut cFILE) -> ::std::os::raw::c_int;
+pub type cbw_WindowDispatchFn = ::std::option::Option<
+    unsafe extern "C" fn(window: *mut cbw_Window, data: *mut ::std::os::raw::c_void),
+>;
+#[doc = " Invoked when a message box has been closed, with the index of the button that has been chosen, or -1 if it has been dismissed without choosing one."]
+pub type cbw_WindowMessageBoxFn = ::std::option::Option<
+    unsafe extern "C" fn(
+        window: *mut cbw_Window,
+        button: ::std::os::raw::c_int,
+        data: *mut ::std::os::raw::c_void,
+    ),
+>;
+#[doc = " Invoked when a menu item has been activated, with the new check state of the item."]
+pub type cbw_WindowMenuHandlerFn = ::std::option::Option<
+    unsafe extern "C" fn(
+        window: *mut cbw_Window,
+        item_id: u32,
+        checked: cBOOL,
+        data: *mut ::std::os::raw::c_void,
+    ),
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cbw_WindowDispatchData {
+    _unused: [u8; 0],
 }
-extern "C" {
-    #[link_name = "\u{1}fgets"]
-    pub fn cfgets(
-        __s: *mut ::std::os::raw::c_char,
-        __n: ::std::os::raw::c_int,
-        __stream: *mut cFILE,
-    ) -> *mut ::std::os::raw::c_char;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cbw_Window {
+    pub app: *mut cbw_Application,
+    pub parent: *const cbw_Window,
+    pub closed: bool,
+    pub dropped: bool,
+    pub callbacks: cbw_WindowCallbacks,
+    pub user_data: *mut ::std::os::raw::c_void,
+    pub menu_handler: cbw_WindowMenuHandlerFn,
+    pub menu_handler_data: *mut ::std::os::raw::c_void,
+    pub impl_: cbw_WindowImpl,
 }
-extern "C" {
-    #[link_name = "\u{1}__getdelim"]
-    pub fn c__getdelim(
-        __lineptr: *mut *mut ::std::os::raw::c_char,
-        __n: *mut csize_t,
-        __delimiter: ::std::os::raw::c_int,
-        __stream: *mut cFILE,
-    ) -> c__ssize_t;
+#[test]
+fn bindgen_test_layout_cbw_Window() {
+    assert_eq!(
+        ::std::mem::size_of::<cbw_Window>(),
+        104usize,
+        concat!("Size of: ", stringify!(cbw_Window))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<cbw_Window>(),
+        8usize,
+        concat!("Alignment of ", stringify!(cbw_Window))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_Window>())).app as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_Window),
+            "::",
+            stringify!(app)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_Window>())).parent as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_Window),
+            "::",
+            stringify!(parent)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_Window>())).closed as *const _ as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_Window),
+            "::",
+            stringify!(closed)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_Window>())).dropped as *const _ as usize },
+        17usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_Window),
+            "::",
+            stringify!(dropped)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_Window>())).callbacks as *const _ as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_Window),
+            "::",
+            stringify!(callbacks)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_Window>())).user_data as *const _ as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_Window),
+            "::",
+            stringify!(user_data)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_Window>())).menu_handler as *const _ as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_Window),
+            "::",
+            stringify!(menu_handler)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_Window>())).menu_handler_data as *const _ as usize },
+        96usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_Window),
+            "::",
+            stringify!(menu_handler_data)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_Window>())).impl_ as *const _ as usize },
+        104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_Window),
+            "::",
+            stringify!(impl_)
+        )
+    );
 }
 extern "C" {
-    #[link_name = "\u{1}getdelim"]
-    pub fn cgetdelim(
-        __lineptr: *mut *mut ::std::os::raw::c_char,
-        __n: *mut csize_t,
-        __delimiter: ::std::os::raw::c_int,
-        __stream: *mut cFILE,
-    ) -> c__ssize_t;
+    #[doc = " Destroy the window, releasing all resources it holds."]
+    #[doc = " After this call, the handle is considered invalid."]
+    #[link_name = "\u{1}bw_Window_destroy"]
+    pub fn cbw_Window_destroy(window: *mut cbw_Window);
 }
 extern "C" {
-    #[link_name = "\u{1}getline"]
-    pub fn cgetline(
-        __lineptr: *mut *mut ::std::os::raw::c_char,
-        __n: *mut csize_t,
-        __stream: *mut cFILE,
-    ) -> c__ssize_t;
+    #[doc = " Invalidates the window handle."]
+    #[doc = " The window will get destroyed when it is deemed possible."]
+    #[link_name = "\u{1}bw_Window_drop"]
+    pub fn cbw_Window_drop(window: *mut cbw_Window);
 }
 extern "C" {
-    #[link_name = "\u{1}fputs"]
-    pub fn cfputs(
-        __s: *const ::std::os::raw::c_char,
-        __stream: *mut cFILE,
-    ) -> ::std::os::raw::c_int;
+    #[doc = " Gets the width and height of the usable area inside the window."]
+    #[link_name = "\u{1}bw_Window_getContentDimensions"]
+    pub fn cbw_Window_getContentDimensions(window: *mut cbw_Window) -> cbw_Dims2D;
 }
 extern "C" {
-    #[link_name = "\u{1}puts"]
-    pub fn cputs(__s: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+    #[doc = " Gets the handle of the window of the platform, so that it can be handed to other toolkits."]
+    #[doc = " Returns FALSE if there is none, like for GTK windows that haven't been realized yet."]
+    #[link_name = "\u{1}bw_Window_getNativeHandle"]
+    pub fn cbw_Window_getNativeHandle(
+        window: *const cbw_Window,
+        handle: *mut cbw_WindowNativeHandle,
+    ) -> cBOOL;
 }
 extern "C" {
-    #[link_name = "\u{1}ungetc"]
-    pub fn cungetc(__c: ::std::os::raw::c_int, __stream: *mut cFILE) -> ::std::os::raw::c_int;
+    #[doc = " Gets the opacity of the window as a value from 0 to 255."]
+    #[link_name = "\u{1}bw_Window_getOpacity"]
+    pub fn cbw_Window_getOpacity(window: *mut cbw_Window) -> u8;
 }
 extern "C" {
-    #[link_name = "\u{1}fread"]
-    pub fn cfread(
-        __ptr: *mut ::std::os::raw::c_void,
-        __size: ::std::os::raw::c_ulong,
-        __n: ::std::os::raw::c_ulong,
-        __stream: *mut cFILE,
-    ) -> ::std::os::raw::c_ulong;
+    #[doc = " Gets the X and Y coordinates of the window position relative to the desktop screen."]
+    #[link_name = "\u{1}bw_Window_getPosition"]
+    pub fn cbw_Window_getPosition(window: *mut cbw_Window) -> cbw_Pos2D;
 }
 extern "C" {
-    #[link_name = "\u{1}fwrite"]
-    pub fn cfwrite(
-        __ptr: *const ::std::os::raw::c_void,
-        __size: ::std::os::raw::c_ulong,
-        __n: ::std::os::raw::c_ulong,
-        __s: *mut cFILE,
-    ) -> ::std::os::raw::c_ulong;
+    #[doc = " Stores the display that contains the largest part of the window in `monitor`."]
+    #[doc = " Returns FALSE if the window isn't on any display."]
+    #[link_name = "\u{1}bw_Window_getMonitor"]
+    pub fn cbw_Window_getMonitor(window: *const cbw_Window, monitor: *mut cbw_Monitor) -> cBOOL;
 }
 extern "C" {
-    #[link_name = "\u{1}fread_unlocked"]
-    pub fn cfread_unlocked(
-        __ptr: *mut ::std::os::raw::c_void,
-        __size: csize_t,
-        __n: csize_t,
-        __stream: *mut cFILE,
-    ) -> csize_t;
+    #[doc = " Gets the number of physical pixels that make up one logical pixel on the display that the window is on."]
+    #[doc = " This is 1.0 on displays that aren't scaled, and 2.0 on displays that are scaled to 200%."]
+    #[link_name = "\u{1}bw_Window_getScaleFactor"]
+    pub fn cbw_Window_getScaleFactor(window: *const cbw_Window) -> f32;
 }
 extern "C" {
-    #[link_name = "\u{1}fwrite_unlocked"]
-    pub fn cfwrite_unlocked(
-        __ptr: *const ::std::os::raw::c_void,
-        __size: csize_t,
-        __n: csize_t,
-        __stream: *mut cFILE,
+    #[doc = " Copies as many bytes into `title` that fit in there."]
+    #[doc = " Returns the number of characters the title actually has."]
+    #[link_name = "\u{1}bw_Window_getTitle"]
+    pub fn cbw_Window_getTitle(
+        window: *mut cbw_Window,
+        title: *mut *mut ::std::os::raw::c_char,
     ) -> csize_t;
 }
 extern "C" {
-    #[link_name = "\u{1}fseek"]
-    pub fn cfseek(
-        __stream: *mut cFILE,
-        __off: ::std::os::raw::c_long,
-        __whence: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    #[link_name = "\u{1}ftell"]
-    pub fn cftell(__stream: *mut cFILE) -> ::std::os::raw::c_long;
-}
-extern "C" {
-    #[link_name = "\u{1}rewind"]
-    pub fn crewind(__stream: *mut cFILE);
-}
-extern "C" {
-    #[link_name = "\u{1}fseeko"]
-    pub fn cfseeko(
-        __stream: *mut cFILE,
-        __off: c__off_t,
-        __whence: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+    #[doc = " Gets the width and height of the window including the title bar and borders."]
+    #[link_name = "\u{1}bw_Window_getWindowDimensions"]
+    pub fn cbw_Window_getWindowDimensions(window: *mut cbw_Window) -> cbw_Dims2D;
 }
 extern "C" {
-    #[link_name = "\u{1}ftello"]
-    pub fn cftello(__stream: *mut cFILE) -> c__off_t;
+    #[link_name = "\u{1}bw_Window_hide"]
+    pub fn cbw_Window_hide(window: *mut cbw_Window);
 }
 extern "C" {
-    #[link_name = "\u{1}fgetpos"]
-    pub fn cfgetpos(__stream: *mut cFILE, __pos: *mut cfpos_t) -> ::std::os::raw::c_int;
+    #[doc = " Returns whether or not the window is not hidden."]
+    #[doc = " `bw_Window_show` and `bw_Window_hide` change the visibility."]
+    #[link_name = "\u{1}bw_Window_isVisible"]
+    pub fn cbw_Window_isVisible(window: *const cbw_Window) -> bool;
 }
 extern "C" {
-    #[link_name = "\u{1}fsetpos"]
-    pub fn cfsetpos(__stream: *mut cFILE, __pos: *const cfpos_t) -> ::std::os::raw::c_int;
+    #[doc = " Creates a new (empty) window"]
+    #[doc = " The returned pointer is a handler for the window."]
+    #[doc = " bw_Window_drop needs to be called on it after it is done being used,"]
+    #[doc = "     otherwise the window is never actually destroyed and memory leakes happen."]
+    #[link_name = "\u{1}bw_Window_new"]
+    pub fn cbw_Window_new(
+        app: *mut cbw_Application,
+        parent: *const cbw_Window,
+        _title: cbw_CStrSlice,
+        width: ::std::os::raw::c_int,
+        height: ::std::os::raw::c_int,
+        options: *const cbw_WindowOptions,
+        user_data: *mut ::std::os::raw::c_void,
+    ) -> *mut cbw_Window;
 }
 extern "C" {
-    #[link_name = "\u{1}clearerr"]
-    pub fn cclearerr(__stream: *mut cFILE);
+    #[link_name = "\u{1}bw_Window_setContentDimensions"]
+    pub fn cbw_Window_setContentDimensions(window: *mut cbw_Window, dimensions: cbw_Dims2D);
 }
 extern "C" {
-    #[link_name = "\u{1}feof"]
-    pub fn cfeof(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+    #[doc = " Marks the area of the custom maximize button of a frameless window, that draws its own titlebar."]
+    #[doc = " The position is relative to the content area of the window."]
+    #[doc = " Hovering over the area shows the snap layouts on Windows 11, and clicking it maximizes or restores the window."]
+    #[doc = " A size of 0 removes the area again."]
+    #[doc = " Does nothing on other platforms than Windows."]
+    #[link_name = "\u{1}bw_Window_setMaximizeButtonArea"]
+    pub fn cbw_Window_setMaximizeButtonArea(
+        window: *mut cbw_Window,
+        position: cbw_Pos2D,
+        size: cbw_Dims2D,
+    );
 }
 extern "C" {
-    #[link_name = "\u{1}ferror"]
-    pub fn cferror(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+    #[doc = " Sets the icon of the window, which is shown in its title bar and in the taskbar."]
+    #[doc = " If `width` and `height` are 0, `data` is a PNG image of `size` bytes, otherwise it contains `width` * `height` pixels in the RGBA format."]
+    #[doc = " The data is only read during this call, and nothing happens if it can't be decoded."]
+    #[link_name = "\u{1}bw_Window_setIcon"]
+    pub fn cbw_Window_setIcon(
+        window: *mut cbw_Window,
+        data: *const ::std::os::raw::c_void,
+        size: csize_t,
+        width: ::std::os::raw::c_uint,
+        height: ::std::os::raw::c_uint,
+    );
 }
 extern "C" {
-    #[link_name = "\u{1}clearerr_unlocked"]
-    pub fn cclearerr_unlocked(__stream: *mut cFILE);
+    #[doc = " Sets the function that is invoked whenever an item of the window's menu bar gets activated."]
+    #[link_name = "\u{1}bw_Window_setMenuHandler"]
+    pub fn cbw_Window_setMenuHandler(
+        window: *mut cbw_Window,
+        handler: cbw_WindowMenuHandlerFn,
+        data: *mut ::std::os::raw::c_void,
+    );
 }
 extern "C" {
-    #[link_name = "\u{1}feof_unlocked"]
-    pub fn cfeof_unlocked(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+    #[link_name = "\u{1}bw_Window_setOpacity"]
+    pub fn cbw_Window_setOpacity(window: *mut cbw_Window, opacity: u8);
 }
 extern "C" {
-    #[link_name = "\u{1}ferror_unlocked"]
-    pub fn cferror_unlocked(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+    #[link_name = "\u{1}bw_Window_setPosition"]
+    pub fn cbw_Window_setPosition(window: *mut cbw_Window, position: cbw_Pos2D);
 }
 extern "C" {
-    #[link_name = "\u{1}perror"]
-    pub fn cperror(__s: *const ::std::os::raw::c_char);
+    #[doc = " Applies the given title;"]
+    #[link_name = "\u{1}bw_Window_setTitle"]
+    pub fn cbw_Window_setTitle(window: *mut cbw_Window, title: cbw_CStrSlice);
 }
 extern "C" {
-    #[link_name = "\u{1}sys_nerr"]
-    pub static mut csys_nerr: ::std::os::raw::c_int;
+    #[link_name = "\u{1}bw_Window_setWindowDimensions"]
+    pub fn cbw_Window_setWindowDimensions(window: *mut cbw_Window, dimensions: cbw_Dims2D);
 }
 extern "C" {
-    #[link_name = "\u{1}sys_errlist"]
-    pub static mut csys_errlist: [*const ::std::os::raw::c_char; 0usize];
+    #[doc = " Shows the window if it was previously hidden"]
+    #[doc = " Is generally called after window creation."]
+    #[link_name = "\u{1}bw_Window_show"]
+    pub fn cbw_Window_show(window: *mut cbw_Window);
 }
 extern "C" {
-    #[link_name = "\u{1}fileno"]
-    pub fn cfileno(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+    #[doc = " Shows a message box that is modal to the window, and invokes `handler` once it has been closed."]
+    #[doc = " If `button_count` is 0, the message box only has an OK button."]
+    #[doc = " This returns right away, unless the platform only has blocking message boxes."]
+    #[link_name = "\u{1}bw_Window_showMessageBox"]
+    pub fn cbw_Window_showMessageBox(
+        window: *mut cbw_Window,
+        message_box: *const cbw_WindowMessageBox,
+        handler: cbw_WindowMessageBoxFn,
+        data: *mut ::std::os::raw::c_void,
+    );
 }
 extern "C" {
-    #[link_name = "\u{1}fileno_unlocked"]
-    pub fn cfileno_unlocked(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+    #[doc = " Starts a drag operation that carries the given files and text out of the window, to wherever the user drops them."]
+    #[doc = " The paths of the files need to be absolute. Either `file_count` can be 0 or `text` can be empty, to only drag the other one."]
+    #[doc = " It should be called while a mouse button is held down, the drag ends when the button is released."]
+    #[doc = " Returns whether or not the drag could be started."]
+    #[link_name = "\u{1}bw_Window_startDrag"]
+    pub fn cbw_Window_startDrag(
+        window: *mut cbw_Window,
+        files: *const cbw_CStrSlice,
+        file_count: usize,
+        text: cbw_CStrSlice,
+    ) -> cBOOL;
 }
 extern "C" {
-    #[link_name = "\u{1}popen"]
-    pub fn cpopen(
-        __command: *const ::std::os::raw::c_char,
-        __modes: *const ::std::os::raw::c_char,
-    ) -> *mut cFILE;
+    #[link_name = "\u{1}bw_Window_triggerClose"]
+    pub fn cbw_Window_triggerClose(window: *mut cbw_Window);
 }
 extern "C" {
-    #[link_name = "\u{1}pclose"]
-    pub fn cpclose(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+    #[doc = " Should be called by the window implementations when a menu item has been activated."]
+    #[link_name = "\u{1}bw_Window_triggerMenuItem"]
+    pub fn cbw_Window_triggerMenuItem(window: *mut cbw_Window, item_id: u32, checked: cBOOL);
 }
 extern "C" {
-    #[link_name = "\u{1}ctermid"]
-    pub fn cctermid(__s: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+    #[link_name = "\u{1}_bw_Window_onResize"]
+    pub fn c_bw_Window_onResize(
+        window: *const cbw_Window,
+        width: ::std::os::raw::c_uint,
+        height: ::std::os::raw::c_uint,
+    );
 }
-extern "C" {
-    #[link_name = "\u{1}flockfile"]
-    pub fn cflockfile(__stream: *mut cFILE);
+#[doc = " A request of the page to choose files, like for `<input type=\"file\">`, that is answered by the application itself."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cbw_FileChooser {
+    _unused: [u8; 0],
 }
-extern "C" {
-    #[link_name = "\u{1}ftrylockfile"]
-    pub fn cftrylockfile(__stream: *mut cFILE) -> ::std::os::raw::c_int;
+#[doc = " A request of the page that is held back until the application has decided what happens to it."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cbw_InterceptedRequest {
+    _unused: [u8; 0],
 }
-extern "C" {
-    #[link_name = "\u{1}funlockfile"]
-    pub fn cfunlockfile(__stream: *mut cFILE);
+#[doc = " A request of one of the custom schemes of the application, that is answered by the application itself."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cbw_SchemeRequest {
+    _unused: [u8; 0],
 }
-extern "C" {
-    #[link_name = "\u{1}__uflow"]
-    pub fn c__uflow(arg1: *mut cFILE) -> ::std::os::raw::c_int;
+#[doc = " `shared_handle` is the shared handle of a D3D11 texture in the BGRA format, which can be opened with `ID3D11Device::OpenSharedResource`."]
+#[doc = " The texture is reused for later frames, so it should be copied rather than kept."]
+pub type cbw_BrowserWindowAcceleratedPaintFn = ::std::option::Option<
+    unsafe extern "C" fn(
+        window: *mut cbw_BrowserWindow,
+        is_popup: cBOOL,
+        dirty_rects: *const cbw_BrowserWindowRect,
+        dirty_rect_count: csize_t,
+        shared_handle: *mut ::std::os::raw::c_void,
+    ),
+>;
+pub type cbw_BrowserWindowAcceleratorFn = ::std::option::Option<
+    unsafe extern "C" fn(window: *mut cbw_BrowserWindow, accelerator_id: u32),
+>;
+#[doc = " `cursor` is one of: 0 default, 1 pointer, 2 text, 3 wait, 4 progress, 5 crosshair, 6 move, 7 not allowed, 8 help,"]
+#[doc = "     9 resize east-west, 10 resize north-south, 11 resize northeast-southwest, 12 resize northwest-southeast, 13 grab, 14 grabbing, 15 hidden."]
+pub type cbw_BrowserWindowCursorFn =
+    ::std::option::Option<unsafe extern "C" fn(window: *mut cbw_BrowserWindow, cursor: u8)>;
+pub type cbw_BrowserWindowCreationCallbackFn = ::std::option::Option<
+    unsafe extern "C" fn(window: *mut cbw_BrowserWindow, data: *mut ::std::os::raw::c_void),
+>;
+pub type cbw_BrowserWindowDestroyFn =
+    ::std::option::Option<unsafe extern "C" fn(window: *mut cbw_BrowserWindow)>;
+#[doc = " `method` is the name of the event of the Chrome DevTools Protocol, like `Network.requestWillBeSent`, and `params` is the JSON object with its parameters."]
+#[doc = " Both are only valid during the call."]
+pub type cbw_BrowserWindowDevToolsEventFn = ::std::option::Option<
+    unsafe extern "C" fn(
+        window: *mut cbw_BrowserWindow,
+        method: cbw_CStrSlice,
+        params: cbw_CStrSlice,
+    ),
+>;
+#[doc = " `result` is the JSON object with the result of the method, or with the error if `success` isn't set, and is only valid during the call."]
+pub type cbw_BrowserWindowDevToolsResultFn = ::std::option::Option<
+    unsafe extern "C" fn(
+        window: *mut cbw_BrowserWindow,
+        data: *mut ::std::os::raw::c_void,
+        success: cBOOL,
+        result: cbw_CStrSlice,
+    ),
+>;
+#[doc = " If `width` and `height` are 0, `data` is a PNG image of `size` bytes, otherwise it contains `width` * `height` pixels in the RGBA format."]
+#[doc = " The data is only valid during the call."]
+pub type cbw_BrowserWindowFaviconFn = ::std::option::Option<
+    unsafe extern "C" fn(
+        window: *mut cbw_BrowserWindow,
+        data: *const ::std::os::raw::c_void,
+        size: csize_t,
+        width: ::std::os::raw::c_uint,
+        height: ::std::os::raw::c_uint,
+    ),
+>;
+#[doc = " `mode` is 0 to open a file, 1 to open multiple files, 2 to open a folder, and 3 to save a file."]
+#[doc = " `accept` contains a line for every type of file that the page accepts, like `.png` or `image/*`, and is empty if it accepts any file."]
+#[doc = " The strings are only valid during the call, but `chooser` stays valid until it has been answered with `bw_FileChooser_select`."]
+pub type cbw_BrowserWindowFileChooserFn = ::std::option::Option<
+    unsafe extern "C" fn(
+        window: *mut cbw_BrowserWindow,
+        chooser: *mut cbw_FileChooser,
+        mode: u8,
+        title: cbw_CStrSlice,
+        default_path: cbw_CStrSlice,
+        accept: cbw_CStrSlice,
+    ),
+>;
+pub type cbw_BrowserWindowFocusFn =
+    ::std::option::Option<unsafe extern "C" fn(window: *mut cbw_BrowserWindow, focused: cBOOL)>;
+pub type cbw_BrowserWindowHandlerFn = ::std::option::Option<
+    unsafe extern "C" fn(
+        window: *mut cbw_BrowserWindow,
+        cmd: cbw_CStrSlice,
+        args: *mut cbw_CStrSlice,
+        arg_count: csize_t,
+    ),
+>;
+pub type cbw_BrowserWindowJsCallbackFn = ::std::option::Option<
+    unsafe extern "C" fn(
+        window: *mut cbw_BrowserWindow,
+        user_data: *mut ::std::os::raw::c_void,
+        result: *const ::std::os::raw::c_char,
+        err: *const cbw_Err,
+    ),
+>;
+#[doc = " Invoked on the GUI thread for every key that is pressed or released while the browser window has focus, before the page gets it."]
+#[doc = " `kind` is 0 for a key that is pressed and 1 for a key that is released, `key_code` is the virtual key code as it is used on Windows, and `modifiers` holds the `BW_MODIFIER_*` flags of the keys."]
+#[doc = " `is_repeat` is set when the key is pressed again because it is being held down."]
+#[doc = " Returns whether the key has been handled, in which case neither the page nor the browser engine get it."]
+pub type cbw_BrowserWindowKeyFn = ::std::option::Option<
+    unsafe extern "C" fn(
+        window: *mut cbw_BrowserWindow,
+        kind: u8,
+        key_code: u16,
+        modifiers: u32,
+        is_repeat: cBOOL,
+    ) -> cBOOL,
+>;
+#[doc = " Invoked when the main frame has finished loading its document."]
+#[doc = " `error` is NULL if it has loaded, or describes why it couldn't be loaded, and is only valid during the call."]
+pub type cbw_BrowserWindowLoadFn = ::std::option::Option<
+    unsafe extern "C" fn(window: *mut cbw_BrowserWindow, error: *const cbw_Err),
+>;
+#[doc = " `progress` is how far the main frame has come with loading, from 0.0 to 1.0 once it is done."]
+pub type cbw_BrowserWindowLoadingProgressFn =
+    ::std::option::Option<unsafe extern "C" fn(window: *mut cbw_BrowserWindow, progress: f64)>;
+#[doc = " `x` and `y` are the new position of the window."]
+pub type cbw_BrowserWindowMoveFn = ::std::option::Option<
+    unsafe extern "C" fn(
+        window: *mut cbw_BrowserWindow,
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+    ),
+>;
+#[doc = " `url` is the new address of the main frame, and is only valid during the call."]
+pub type cbw_BrowserWindowNavigationFn =
+    ::std::option::Option<unsafe extern "C" fn(window: *mut cbw_BrowserWindow, url: cbw_CStrSlice)>;
+#[doc = " `pixels` contains `width` * `height` pixels in the BGRA format, and is only valid during the call."]
+#[doc = " `is_popup` is set for the frames of popup widgets, like the list of a `<select>` element."]
+pub type cbw_BrowserWindowPaintFn = ::std::option::Option<
+    unsafe extern "C" fn(
+        window: *mut cbw_BrowserWindow,
+        is_popup: cBOOL,
+        dirty_rects: *const cbw_BrowserWindowRect,
+        dirty_rect_count: csize_t,
+        pixels: *const ::std::os::raw::c_void,
+        width: ::std::os::raw::c_int,
+        height: ::std::os::raw::c_int,
+    ),
+>;
+#[doc = " `permission` is 0 for the camera and microphone, 1 for the clipboard, 2 for opening popups, 3 for downloads, and 4 for capturing the screen."]
+#[doc = " `url` is the address of the page or the origin that asks for it, and is only valid during the call."]
+#[doc = " Returns 1 to allow it, 0 to deny it, or -1 to leave it to the browser engine, which may ask the user."]
+pub type cbw_BrowserWindowPermissionFn = ::std::option::Option<
+    unsafe extern "C" fn(
+        window: *mut cbw_BrowserWindow,
+        permission: u8,
+        url: cbw_CStrSlice,
+    ) -> ::std::os::raw::c_int,
+>;
+#[doc = " `reason` is 0 if the render process has exited abnormally, 1 if it has been killed, 2 if it has crashed, and 3 if it has run out of memory."]
+pub type cbw_BrowserWindowProcessTerminatedFn = ::std::option::Option<
+    unsafe extern "C" fn(window: *mut cbw_BrowserWindow, reason: u8),
+>;
+#[doc = " Invoked on the GUI thread for every request of the page that matches the filters of `bw_BrowserWindow_setRequestHandler`, before it is sent."]
+#[doc = " `headers` contains a `Name: value` line for every header."]
+#[doc = " The strings are only valid during the call, but `request` stays valid until it has been decided on with one of the `bw_InterceptedRequest_*` functions."]
+pub type cbw_BrowserWindowRequestFn = ::std::option::Option<
+    unsafe extern "C" fn(
+        window: *mut cbw_BrowserWindow,
+        request: *mut cbw_InterceptedRequest,
+        method: cbw_CStrSlice,
+        url: cbw_CStrSlice,
+        headers: cbw_CStrSlice,
+    ),
+>;
+#[doc = " `rect` is the area of the popup widget within the view, or NULL when it has been hidden."]
+pub type cbw_BrowserWindowPopupFn = ::std::option::Option<
+    unsafe extern "C" fn(window: *mut cbw_BrowserWindow, rect: *const cbw_BrowserWindowRect),
+>;
+#[doc = " `width` and `height` are the new size of the content area of the window."]
+pub type cbw_BrowserWindowResizeFn = ::std::option::Option<
+    unsafe extern "C" fn(
+        window: *mut cbw_BrowserWindow,
+        width: ::std::os::raw::c_uint,
+        height: ::std::os::raw::c_uint,
+    ),
+>;
+#[doc = " `scale_factor` is the new scale factor of the display that the window is on."]
+pub type cbw_BrowserWindowScaleFactorFn = ::std::option::Option<
+    unsafe extern "C" fn(window: *mut cbw_BrowserWindow, scale_factor: f32),
+>;
+#[doc = " Invoked on the GUI thread for every request of one of the custom schemes of the application."]
+#[doc = " `headers` contains a `Name: value` line for every header, and `body` is empty for requests without a body."]
+#[doc = " The strings are only valid during the call, but `request` stays valid until it has been finished with `bw_SchemeRequest_finish`."]
+pub type cbw_BrowserWindowSchemeFn = ::std::option::Option<
+    unsafe extern "C" fn(
+        window: *mut cbw_BrowserWindow,
+        request: *mut cbw_SchemeRequest,
+        method: cbw_CStrSlice,
+        url: cbw_CStrSlice,
+        headers: cbw_CStrSlice,
+        body: cbw_CStrSlice,
+    ),
+>;
+#[doc = " `text` is what browsers show in their status bar, like the address of the link under the mouse cursor, and is only valid during the call."]
+#[doc = " It is empty once there is nothing to show anymore."]
+pub type cbw_BrowserWindowStatusTextFn = ::std::option::Option<
+    unsafe extern "C" fn(window: *mut cbw_BrowserWindow, text: cbw_CStrSlice),
+>;
+#[doc = " `title` is the new title of the page, and is only valid during the call."]
+pub type cbw_BrowserWindowTitleFn = ::std::option::Option<
+    unsafe extern "C" fn(window: *mut cbw_BrowserWindow, title: cbw_CStrSlice),
+>;
+#[doc = " `unresponsive` is set when the page has stopped responding to input, and unset once it responds again."]
+pub type cbw_BrowserWindowUnresponsiveFn = ::std::option::Option<
+    unsafe extern "C" fn(window: *mut cbw_BrowserWindow, unresponsive: cBOOL),
+>;
+#[doc = " A key combination that has been registered with `bw_BrowserWindow_registerAccelerator`."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cbw_BrowserWindowAccelerator {
+    pub id: u32,
+    #[doc = " The virtual key code, as it is used on Windows."]
+    pub key_code: u16,
+    pub ctrl: cBOOL,
+    pub shift: cBOOL,
+    pub alt: cBOOL,
+    pub super_: cBOOL,
 }
-extern "C" {
-    #[link_name = "\u{1}__overflow"]
-    pub fn c__overflow(arg1: *mut cFILE, arg2: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+#[test]
+fn bindgen_test_layout_cbw_BrowserWindowAccelerator() {
+    assert_eq!(
+        ::std::mem::size_of::<cbw_BrowserWindowAccelerator>(),
+        24usize,
+        concat!("Size of: ", stringify!(cbw_BrowserWindowAccelerator))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<cbw_BrowserWindowAccelerator>(),
+        4usize,
+        concat!("Alignment of ", stringify!(cbw_BrowserWindowAccelerator))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowAccelerator>())).id as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowAccelerator),
+            "::",
+            stringify!(id)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowAccelerator>())).key_code as *const _ as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowAccelerator),
+            "::",
+            stringify!(key_code)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowAccelerator>())).ctrl as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowAccelerator),
+            "::",
+            stringify!(ctrl)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowAccelerator>())).shift as *const _ as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowAccelerator),
+            "::",
+            stringify!(shift)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowAccelerator>())).alt as *const _ as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowAccelerator),
+            "::",
+            stringify!(alt)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowAccelerator>())).super_ as *const _ as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowAccelerator),
+            "::",
+            stringify!(super_)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cbw_BrowserWindowOptions {
+    pub dev_tools: cBOOL,
+    pub resource_path: cbw_CStrSlice,
+    #[doc = " JavaScript that gets executed whenever a new document is loaded, before the page's own scripts."]
+    pub preload_script: cbw_CStrSlice,
+    #[doc = " The encoding for pages that don't declare one. Empty for the engine's default."]
+    pub default_encoding: cbw_CStrSlice,
+    #[doc = " Lets an offscreen browser paint into GPU textures that are shared with the application, instead of into pixel buffers."]
+    pub shared_textures: cBOOL,
+    #[doc = " Makes the preload script the only way for pages to reach the application, so that other frames don't get `invoke_extern` either."]
+    pub isolate_scripts: cBOOL,
+    #[doc = " The decisions for the permissions that the permission handler leaves open: 1 to allow, 0 to deny, or -1 to leave them to the browser engine."]
+    pub permission_defaults: [i8; 5usize],
+}
+#[test]
+fn bindgen_test_layout_cbw_BrowserWindowOptions() {
+    assert_eq!(
+        ::std::mem::size_of::<cbw_BrowserWindowOptions>(),
+        72usize,
+        concat!("Size of: ", stringify!(cbw_BrowserWindowOptions))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<cbw_BrowserWindowOptions>(),
+        8usize,
+        concat!("Alignment of ", stringify!(cbw_BrowserWindowOptions))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowOptions>())).dev_tools as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowOptions),
+            "::",
+            stringify!(dev_tools)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowOptions>())).resource_path as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowOptions),
+            "::",
+            stringify!(resource_path)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowOptions>())).preload_script as *const _ as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowOptions),
+            "::",
+            stringify!(preload_script)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowOptions>())).default_encoding as *const _ as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowOptions),
+            "::",
+            stringify!(default_encoding)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowOptions>())).shared_textures as *const _ as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowOptions),
+            "::",
+            stringify!(shared_textures)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowOptions>())).isolate_scripts as *const _ as usize },
+        60usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowOptions),
+            "::",
+            stringify!(isolate_scripts)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowOptions>())).permission_defaults as *const _ as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowOptions),
+            "::",
+            stringify!(permission_defaults)
+        )
+    );
 }
+#[doc = " Information about a (sub)frame of a browser window."]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct cbw_Dims2D {
-    pub width: u16,
-    pub height: u16,
+pub struct cbw_BrowserWindowFrame {
+    pub id: i64,
+    #[doc = " -1 if the frame has no parent."]
+    pub parent_id: i64,
+    pub name: cbw_StrSlice,
+    pub url: cbw_StrSlice,
+    pub is_main: cBOOL,
 }
 #[test]
-fn bindgen_test_layout_cbw_Dims2D() {
+fn bindgen_test_layout_cbw_BrowserWindowFrame() {
     assert_eq!(
-        ::std::mem::size_of::<cbw_Dims2D>(),
-        4usize,
-        concat!("Size of: ", stringify!(cbw_Dims2D))
+        ::std::mem::size_of::<cbw_BrowserWindowFrame>(),
+        56usize,
+        concat!("Size of: ", stringify!(cbw_BrowserWindowFrame))
     );
     assert_eq!(
-        ::std::mem::align_of::<cbw_Dims2D>(),
-        2usize,
-        concat!("Alignment of ", stringify!(cbw_Dims2D))
+        ::std::mem::align_of::<cbw_BrowserWindowFrame>(),
+        8usize,
+        concat!("Alignment of ", stringify!(cbw_BrowserWindowFrame))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_Dims2D>())).width as *const _ as usize },
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowFrame>())).id as *const _ as usize },
         0usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_Dims2D),
+            stringify!(cbw_BrowserWindowFrame),
             "::",
-            stringify!(width)
+            stringify!(id)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_Dims2D>())).height as *const _ as usize },
-        2usize,
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowFrame>())).parent_id as *const _ as usize },
+        8usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_Dims2D),
+            stringify!(cbw_BrowserWindowFrame),
             "::",
-            stringify!(height)
+            stringify!(parent_id)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowFrame>())).name as *const _ as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowFrame),
+            "::",
+            stringify!(name)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowFrame>())).url as *const _ as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowFrame),
+            "::",
+            stringify!(url)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowFrame>())).is_main as *const _ as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowFrame),
+            "::",
+            stringify!(is_main)
         )
     );
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct cbw_Pos2D {
-    pub x: u16,
-    pub y: u16,
+pub struct cbw_BrowserWindowRect {
+    pub x: ::std::os::raw::c_int,
+    pub y: ::std::os::raw::c_int,
+    pub width: ::std::os::raw::c_int,
+    pub height: ::std::os::raw::c_int,
 }
 #[test]
-fn bindgen_test_layout_cbw_Pos2D() {
+fn bindgen_test_layout_cbw_BrowserWindowRect() {
     assert_eq!(
-        ::std::mem::size_of::<cbw_Pos2D>(),
-        4usize,
-        concat!("Size of: ", stringify!(cbw_Pos2D))
+        ::std::mem::size_of::<cbw_BrowserWindowRect>(),
+        16usize,
+        concat!("Size of: ", stringify!(cbw_BrowserWindowRect))
     );
     assert_eq!(
-        ::std::mem::align_of::<cbw_Pos2D>(),
-        2usize,
-        concat!("Alignment of ", stringify!(cbw_Pos2D))
+        ::std::mem::align_of::<cbw_BrowserWindowRect>(),
+        4usize,
+        concat!("Alignment of ", stringify!(cbw_BrowserWindowRect))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_Pos2D>())).x as *const _ as usize },
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowRect>())).x as *const _ as usize },
         0usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_Pos2D),
+            stringify!(cbw_BrowserWindowRect),
             "::",
             stringify!(x)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_Pos2D>())).y as *const _ as usize },
-        2usize,
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowRect>())).y as *const _ as usize },
+        4usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_Pos2D),
+            stringify!(cbw_BrowserWindowRect),
             "::",
             stringify!(y)
         )
     );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowRect>())).width as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowRect),
+            "::",
+            stringify!(width)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowRect>())).height as *const _ as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowRect),
+            "::",
+            stringify!(height)
+        )
+    );
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct cbw_WindowImpl {}
+pub struct cbw_BrowserWindowSource {
+    pub data: cbw_CStrSlice,
+    pub is_html: cBOOL,
+}
 #[test]
-fn bindgen_test_layout_cbw_WindowImpl() {
+fn bindgen_test_layout_cbw_BrowserWindowSource() {
     assert_eq!(
-        ::std::mem::size_of::<cbw_WindowImpl>(),
+        ::std::mem::size_of::<cbw_BrowserWindowSource>(),
+        24usize,
+        concat!("Size of: ", stringify!(cbw_BrowserWindowSource))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<cbw_BrowserWindowSource>(),
+        8usize,
+        concat!("Alignment of ", stringify!(cbw_BrowserWindowSource))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowSource>())).data as *const _ as usize },
         0usize,
-        concat!("Size of: ", stringify!(cbw_WindowImpl))
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowSource),
+            "::",
+            stringify!(data)
+        )
     );
     assert_eq!(
-        ::std::mem::align_of::<cbw_WindowImpl>(),
-        1usize,
-        concat!("Alignment of ", stringify!(cbw_WindowImpl))
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowSource>())).is_html as *const _ as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindowSource),
+            "::",
+            stringify!(is_html)
+        )
     );
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct cbw_WindowCallbacks {
-    #[doc = " Fired just before the window gets destroyed and freed from memory."]
-    #[doc = " Should be implemented to free the user data provided to the window."]
-    pub do_cleanup: ::std::option::Option<unsafe extern "C" fn(arg1: *mut cbw_Window)>,
-    #[doc = " Fired when the window has been closed, either by the user or programmatically."]
-    pub on_close: ::std::option::Option<unsafe extern "C" fn(arg1: *const cbw_Window)>,
-    #[doc = " Fired when a window has finished loading"]
-    pub on_loaded: ::std::option::Option<unsafe extern "C" fn(arg1: *const cbw_Window)>,
-    #[doc = " Fired when a window is resizing"]
-    pub on_resize: ::std::option::Option<
-        unsafe extern "C" fn(
-            arg1: *const cbw_Window,
-            width: ::std::os::raw::c_uint,
-            height: ::std::os::raw::c_uint,
-        ),
-    >,
+pub struct cbw_BrowserWindow {
+    pub window: *mut cbw_Window,
+    pub external_handler: cbw_BrowserWindowHandlerFn,
+    pub user_data: *mut ::std::os::raw::c_void,
+    pub on_destroy: cbw_BrowserWindowDestroyFn,
+    pub on_accelerator: cbw_BrowserWindowAcceleratorFn,
+    pub accelerators: *mut cbw_BrowserWindowAccelerator,
+    pub accelerator_count: csize_t,
+    pub on_paint: cbw_BrowserWindowPaintFn,
+    pub on_popup: cbw_BrowserWindowPopupFn,
+    pub on_cursor_change: cbw_BrowserWindowCursorFn,
+    pub on_accelerated_paint: cbw_BrowserWindowAcceleratedPaintFn,
+    pub on_resize: cbw_BrowserWindowResizeFn,
+    pub on_scale_factor_changed: cbw_BrowserWindowScaleFactorFn,
+    pub on_move: cbw_BrowserWindowMoveFn,
+    pub on_focus: cbw_BrowserWindowFocusFn,
+    pub on_navigate: cbw_BrowserWindowNavigationFn,
+    pub on_load: cbw_BrowserWindowLoadFn,
+    pub on_loading_progress: cbw_BrowserWindowLoadingProgressFn,
+    pub on_title_change: cbw_BrowserWindowTitleFn,
+    pub on_favicon_changed: cbw_BrowserWindowFaviconFn,
+    pub on_status_text: cbw_BrowserWindowStatusTextFn,
+    pub on_render_process_terminated: cbw_BrowserWindowProcessTerminatedFn,
+    pub on_unresponsive: cbw_BrowserWindowUnresponsiveFn,
+    pub on_file_chooser: cbw_BrowserWindowFileChooserFn,
+    pub on_permission_request: cbw_BrowserWindowPermissionFn,
+    pub on_scheme_request: cbw_BrowserWindowSchemeFn,
+    pub on_request: cbw_BrowserWindowRequestFn,
+    pub request_filters: *mut ::std::os::raw::c_char,
+    pub on_devtools_event: cbw_BrowserWindowDevToolsEventFn,
+    pub on_key: cbw_BrowserWindowKeyFn,
+    pub permission_defaults: [i8; 5usize],
+    pub impl_: cbw_BrowserWindowImpl,
 }
 #[test]
-fn bindgen_test_layout_cbw_WindowCallbacks() {
+fn bindgen_test_layout_cbw_BrowserWindow() {
     assert_eq!(
-        ::std::mem::size_of::<cbw_WindowCallbacks>(),
-        32usize,
-        concat!("Size of: ", stringify!(cbw_WindowCallbacks))
+        ::std::mem::size_of::<cbw_BrowserWindow>(),
+        280usize,
+        concat!("Size of: ", stringify!(cbw_BrowserWindow))
     );
     assert_eq!(
-        ::std::mem::align_of::<cbw_WindowCallbacks>(),
+        ::std::mem::align_of::<cbw_BrowserWindow>(),
         8usize,
-        concat!("Alignment of ", stringify!(cbw_WindowCallbacks))
+        concat!("Alignment of ", stringify!(cbw_BrowserWindow))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_WindowCallbacks>())).do_cleanup as *const _ as usize },
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).window as *const _ as usize },
         0usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_WindowCallbacks),
+            stringify!(cbw_BrowserWindow),
             "::",
-            stringify!(do_cleanup)
+            stringify!(window)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_WindowCallbacks>())).on_close as *const _ as usize },
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).external_handler as *const _ as usize },
         8usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_WindowCallbacks),
+            stringify!(cbw_BrowserWindow),
             "::",
-            stringify!(on_close)
+            stringify!(external_handler)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_WindowCallbacks>())).on_loaded as *const _ as usize },
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).user_data as *const _ as usize },
         16usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_WindowCallbacks),
+            stringify!(cbw_BrowserWindow),
             "::",
-            stringify!(on_loaded)
+            stringify!(user_data)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_WindowCallbacks>())).on_resize as *const _ as usize },
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_destroy as *const _ as usize },
         24usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_WindowCallbacks),
+            stringify!(cbw_BrowserWindow),
             "::",
-            stringify!(on_resize)
+            stringify!(on_destroy)
         )
     );
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct cbw_WindowOptions {
-    pub borders: bool,
-    pub minimizable: bool,
-    pub resizable: bool,
-}
-#[test]
-fn bindgen_test_layout_cbw_WindowOptions() {
     assert_eq!(
-        ::std::mem::size_of::<cbw_WindowOptions>(),
-        3usize,
-        concat!("Size of: ", stringify!(cbw_WindowOptions))
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_accelerator as *const _ as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindow),
+            "::",
+            stringify!(on_accelerator)
+        )
     );
     assert_eq!(
-        ::std::mem::align_of::<cbw_WindowOptions>(),
-        1usize,
-        concat!("Alignment of ", stringify!(cbw_WindowOptions))
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).accelerators as *const _ as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindow),
+            "::",
+            stringify!(accelerators)
+        )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_WindowOptions>())).borders as *const _ as usize },
-        0usize,
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).accelerator_count as *const _ as usize },
+        48usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_WindowOptions),
+            stringify!(cbw_BrowserWindow),
             "::",
-            stringify!(borders)
+            stringify!(accelerator_count)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_WindowOptions>())).minimizable as *const _ as usize },
-        1usize,
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_paint as *const _ as usize },
+        56usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_WindowOptions),
+            stringify!(cbw_BrowserWindow),
             "::",
-            stringify!(minimizable)
+            stringify!(on_paint)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_WindowOptions>())).resizable as *const _ as usize },
-        2usize,
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_popup as *const _ as usize },
+        64usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_WindowOptions),
+            stringify!(cbw_BrowserWindow),
             "::",
-            stringify!(resizable)
+            stringify!(on_popup)
         )
     );
-}
-pub type cbw_WindowDispatchFn = ::std::option::Option<
-    unsafe extern "C" fn(window: *mut cbw_Window, data: *mut ::std::os::raw::c_void),
->;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct cbw_WindowDispatchData {
-    _unused: [u8; 0],
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct cbw_Window {
-    pub app: *mut cbw_Application,
-    pub parent: *const cbw_Window,
-    pub closed: bool,
-    pub dropped: bool,
-    pub callbacks: cbw_WindowCallbacks,
-    pub user_data: *mut ::std::os::raw::c_void,
-    pub impl_: cbw_WindowImpl,
-}
-#[test]
-fn bindgen_test_layout_cbw_Window() {
     assert_eq!(
-        ::std::mem::size_of::<cbw_Window>(),
-        64usize,
-        concat!("Size of: ", stringify!(cbw_Window))
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_cursor_change as *const _ as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindow),
+            "::",
+            stringify!(on_cursor_change)
+        )
     );
     assert_eq!(
-        ::std::mem::align_of::<cbw_Window>(),
-        8usize,
-        concat!("Alignment of ", stringify!(cbw_Window))
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_accelerated_paint as *const _ as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindow),
+            "::",
+            stringify!(on_accelerated_paint)
+        )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_Window>())).app as *const _ as usize },
-        0usize,
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_resize as *const _ as usize },
+        88usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_Window),
+            stringify!(cbw_BrowserWindow),
             "::",
-            stringify!(app)
+            stringify!(on_resize)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_Window>())).parent as *const _ as usize },
-        8usize,
+        unsafe {
+            &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_scale_factor_changed as *const _
+                as usize
+        },
+        96usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_Window),
+            stringify!(cbw_BrowserWindow),
             "::",
-            stringify!(parent)
+            stringify!(on_scale_factor_changed)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_Window>())).closed as *const _ as usize },
-        16usize,
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_move as *const _ as usize },
+        104usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_Window),
+            stringify!(cbw_BrowserWindow),
             "::",
-            stringify!(closed)
+            stringify!(on_move)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_Window>())).dropped as *const _ as usize },
-        17usize,
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_focus as *const _ as usize },
+        112usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_Window),
+            stringify!(cbw_BrowserWindow),
             "::",
-            stringify!(dropped)
+            stringify!(on_focus)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_Window>())).callbacks as *const _ as usize },
-        24usize,
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_navigate as *const _ as usize },
+        120usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_Window),
+            stringify!(cbw_BrowserWindow),
             "::",
-            stringify!(callbacks)
+            stringify!(on_navigate)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_Window>())).user_data as *const _ as usize },
-        56usize,
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_load as *const _ as usize },
+        128usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_Window),
+            stringify!(cbw_BrowserWindow),
             "::",
-            stringify!(user_data)
+            stringify!(on_load)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_Window>())).impl_ as *const _ as usize },
-        64usize,
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_loading_progress as *const _ as usize },
+        136usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_Window),
+            stringify!(cbw_BrowserWindow),
             "::",
-            stringify!(impl_)
+            stringify!(on_loading_progress)
         )
     );
-}
-extern "C" {
-    #[doc = " Destroy the window, releasing all resources it holds."]
-    #[doc = " After this call, the handle is considered invalid."]
-    #[link_name = "\u{1}bw_Window_destroy"]
-    pub fn cbw_Window_destroy(window: *mut cbw_Window);
-}
-extern "C" {
-    #[doc = " Invalidates the window handle."]
-    #[doc = " The window will get destroyed when it is deemed possible."]
-    #[link_name = "\u{1}bw_Window_drop"]
-    pub fn cbw_Window_drop(window: *mut cbw_Window);
-}
-extern "C" {
-    #[doc = " Gets the width and height of the usable area inside the window."]
-    #[link_name = "\u{1}bw_Window_getContentDimensions"]
-    pub fn cbw_Window_getContentDimensions(window: *mut cbw_Window) -> cbw_Dims2D;
-}
-extern "C" {
-    #[doc = " Gets the opacity of the window as a value from 0 to 255."]
-    #[link_name = "\u{1}bw_Window_getOpacity"]
-    pub fn cbw_Window_getOpacity(window: *mut cbw_Window) -> u8;
-}
-extern "C" {
-    #[doc = " Gets the X and Y coordinates of the window position relative to the desktop screen."]
-    #[link_name = "\u{1}bw_Window_getPosition"]
-    pub fn cbw_Window_getPosition(window: *mut cbw_Window) -> cbw_Pos2D;
-}
-extern "C" {
-    #[doc = " Copies as many bytes into `title` that fit in there."]
-    #[doc = " Returns the number of characters the title actually has."]
-    #[link_name = "\u{1}bw_Window_getTitle"]
-    pub fn cbw_Window_getTitle(
-        window: *mut cbw_Window,
-        title: *mut *mut ::std::os::raw::c_char,
-    ) -> csize_t;
-}
-extern "C" {
-    #[doc = " Gets the width and height of the window including the title bar and borders."]
-    #[link_name = "\u{1}bw_Window_getWindowDimensions"]
-    pub fn cbw_Window_getWindowDimensions(window: *mut cbw_Window) -> cbw_Dims2D;
-}
-extern "C" {
-    #[link_name = "\u{1}bw_Window_hide"]
-    pub fn cbw_Window_hide(window: *mut cbw_Window);
-}
-extern "C" {
-    #[doc = " Returns whether or not the window is not hidden."]
-    #[doc = " `bw_Window_show` and `bw_Window_hide` change the visibility."]
-    #[link_name = "\u{1}bw_Window_isVisible"]
-    pub fn cbw_Window_isVisible(window: *const cbw_Window) -> bool;
-}
-extern "C" {
-    #[doc = " Creates a new (empty) window"]
-    #[doc = " The returned pointer is a handler for the window."]
-    #[doc = " bw_Window_drop needs to be called on it after it is done being used,"]
-    #[doc = "     otherwise the window is never actually destroyed and memory leakes happen."]
-    #[link_name = "\u{1}bw_Window_new"]
-    pub fn cbw_Window_new(
-        app: *mut cbw_Application,
-        parent: *const cbw_Window,
-        _title: cbw_CStrSlice,
-        width: ::std::os::raw::c_int,
-        height: ::std::os::raw::c_int,
-        options: *const cbw_WindowOptions,
-        user_data: *mut ::std::os::raw::c_void,
-    ) -> *mut cbw_Window;
-}
-extern "C" {
-    #[link_name = "\u{1}bw_Window_setContentDimensions"]
-    pub fn cbw_Window_setContentDimensions(window: *mut cbw_Window, dimensions: cbw_Dims2D);
-}
-extern "C" {
-    #[link_name = "\u{1}bw_Window_setOpacity"]
-    pub fn cbw_Window_setOpacity(window: *mut cbw_Window, opacity: u8);
-}
-extern "C" {
-    #[link_name = "\u{1}bw_Window_setPosition"]
-    pub fn cbw_Window_setPosition(window: *mut cbw_Window, position: cbw_Pos2D);
-}
-extern "C" {
-    #[doc = " Applies the given title;"]
-    #[link_name = "\u{1}bw_Window_setTitle"]
-    pub fn cbw_Window_setTitle(window: *mut cbw_Window, title: cbw_CStrSlice);
-}
-extern "C" {
-    #[link_name = "\u{1}bw_Window_setWindowDimensions"]
-    pub fn cbw_Window_setWindowDimensions(window: *mut cbw_Window, dimensions: cbw_Dims2D);
-}
-extern "C" {
-    #[doc = " Shows the window if it was previously hidden"]
-    #[doc = " Is generally called after window creation."]
-    #[link_name = "\u{1}bw_Window_show"]
-    pub fn cbw_Window_show(window: *mut cbw_Window);
-}
-extern "C" {
-    #[link_name = "\u{1}bw_Window_triggerClose"]
-    pub fn cbw_Window_triggerClose(window: *mut cbw_Window);
-}
-extern "C" {
-    #[link_name = "\u{1}_bw_Window_onResize"]
-    pub fn c_bw_Window_onResize(
-        window: *const cbw_Window,
-        width: ::std::os::raw::c_uint,
-        height: ::std::os::raw::c_uint,
-    );
-}
-pub type cbw_BrowserWindowCreationCallbackFn = ::std::option::Option<
-    unsafe extern "C" fn(window: *mut cbw_BrowserWindow, data: *mut ::std::os::raw::c_void),
->;
-pub type cbw_BrowserWindowHandlerFn = ::std::option::Option<
-    unsafe extern "C" fn(
-        window: *mut cbw_BrowserWindow,
-        cmd: cbw_CStrSlice,
-        args: *mut cbw_CStrSlice,
-        arg_count: csize_t,
-    ),
->;
-pub type cbw_BrowserWindowJsCallbackFn = ::std::option::Option<
-    unsafe extern "C" fn(
-        window: *mut cbw_BrowserWindow,
-        user_data: *mut ::std::os::raw::c_void,
-        result: *const ::std::os::raw::c_char,
-        err: *const cbw_Err,
-    ),
->;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct cbw_BrowserWindowOptions {
-    pub dev_tools: cBOOL,
-    pub resource_path: cbw_CStrSlice,
-}
-#[test]
-fn bindgen_test_layout_cbw_BrowserWindowOptions() {
     assert_eq!(
-        ::std::mem::size_of::<cbw_BrowserWindowOptions>(),
-        24usize,
-        concat!("Size of: ", stringify!(cbw_BrowserWindowOptions))
+        unsafe {
+            &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_title_change as *const _ as usize
+        },
+        144usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindow),
+            "::",
+            stringify!(on_title_change)
+        )
     );
     assert_eq!(
-        ::std::mem::align_of::<cbw_BrowserWindowOptions>(),
-        8usize,
-        concat!("Alignment of ", stringify!(cbw_BrowserWindowOptions))
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_favicon_changed as *const _ as usize },
+        152usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindow),
+            "::",
+            stringify!(on_favicon_changed)
+        )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<cbw_BrowserWindowOptions>())).dev_tools as *const _ as usize
-        },
-        0usize,
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_status_text as *const _ as usize },
+        160usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_BrowserWindowOptions),
+            stringify!(cbw_BrowserWindow),
             "::",
-            stringify!(dev_tools)
+            stringify!(on_status_text)
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<cbw_BrowserWindowOptions>())).resource_path as *const _ as usize
-        },
-        8usize,
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_render_process_terminated as *const _ as usize },
+        168usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_BrowserWindowOptions),
+            stringify!(cbw_BrowserWindow),
             "::",
-            stringify!(resource_path)
+            stringify!(on_render_process_terminated)
         )
     );
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct cbw_BrowserWindowSource {
-    pub data: cbw_CStrSlice,
-    pub is_html: cBOOL,
-}
-#[test]
-fn bindgen_test_layout_cbw_BrowserWindowSource() {
     assert_eq!(
-        ::std::mem::size_of::<cbw_BrowserWindowSource>(),
-        24usize,
-        concat!("Size of: ", stringify!(cbw_BrowserWindowSource))
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_unresponsive as *const _ as usize },
+        176usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindow),
+            "::",
+            stringify!(on_unresponsive)
+        )
     );
     assert_eq!(
-        ::std::mem::align_of::<cbw_BrowserWindowSource>(),
-        8usize,
-        concat!("Alignment of ", stringify!(cbw_BrowserWindowSource))
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_file_chooser as *const _ as usize },
+        184usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindow),
+            "::",
+            stringify!(on_file_chooser)
+        )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowSource>())).data as *const _ as usize },
-        0usize,
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_permission_request as *const _ as usize },
+        192usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_BrowserWindowSource),
+            stringify!(cbw_BrowserWindow),
             "::",
-            stringify!(data)
+            stringify!(on_permission_request)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindowSource>())).is_html as *const _ as usize },
-        16usize,
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_scheme_request as *const _ as usize },
+        200usize,
         concat!(
             "Offset of field: ",
-            stringify!(cbw_BrowserWindowSource),
+            stringify!(cbw_BrowserWindow),
             "::",
-            stringify!(is_html)
+            stringify!(on_scheme_request)
         )
     );
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct cbw_BrowserWindow {
-    pub window: *mut cbw_Window,
-    pub external_handler: cbw_BrowserWindowHandlerFn,
-    pub user_data: *mut ::std::os::raw::c_void,
-    pub impl_: cbw_BrowserWindowImpl,
-}
-#[test]
-fn bindgen_test_layout_cbw_BrowserWindow() {
     assert_eq!(
-        ::std::mem::size_of::<cbw_BrowserWindow>(),
-        40usize,
-        concat!("Size of: ", stringify!(cbw_BrowserWindow))
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_request as *const _ as usize },
+        208usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindow),
+            "::",
+            stringify!(on_request)
+        )
     );
     assert_eq!(
-        ::std::mem::align_of::<cbw_BrowserWindow>(),
-        8usize,
-        concat!("Alignment of ", stringify!(cbw_BrowserWindow))
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).request_filters as *const _ as usize },
+        216usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(cbw_BrowserWindow),
+            "::",
+            stringify!(request_filters)
+        )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).window as *const _ as usize },
-        0usize,
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_devtools_event as *const _ as usize },
+        224usize,
         concat!(
             "Offset of field: ",
             stringify!(cbw_BrowserWindow),
             "::",
-            stringify!(window)
+            stringify!(on_devtools_event)
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<cbw_BrowserWindow>())).external_handler as *const _ as usize
-        },
-        8usize,
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).on_key as *const _ as usize },
+        232usize,
         concat!(
             "Offset of field: ",
             stringify!(cbw_BrowserWindow),
             "::",
-            stringify!(external_handler)
+            stringify!(on_key)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).user_data as *const _ as usize },
-        16usize,
+        unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).permission_defaults as *const _ as usize },
+        240usize,
         concat!(
             "Offset of field: ",
             stringify!(cbw_BrowserWindow),
             "::",
-            stringify!(user_data)
+            stringify!(permission_defaults)
         )
     );
     assert_eq!(
         unsafe { &(*(::std::ptr::null::<cbw_BrowserWindow>())).impl_ as *const _ as usize },
-        24usize,
+        248usize,
         concat!(
             "Offset of field: ",
             stringify!(cbw_BrowserWindow),
@@ -5067,6 +6996,29 @@ fn bindgen_test_layout_cbw_BrowserWindow() {
         )
     );
 }
+extern "C" {
+    #[doc = " Calls a method of the Chrome DevTools Protocol, like `Page.captureScreenshot`, with the JSON object of its parameters."]
+    #[doc = " An empty `params` is the same as `{}`."]
+    #[doc = " The callback is invoked on the GUI thread once the result is there."]
+    #[doc = " If the browser window is destroyed before that, it is invoked with an error right before the window is gone."]
+    #[link_name = "\u{1}bw_BrowserWindow_callDevToolsMethod"]
+    pub fn cbw_BrowserWindow_callDevToolsMethod(
+        bw: *mut cbw_BrowserWindow,
+        method: cbw_CStrSlice,
+        params: cbw_CStrSlice,
+        callback: cbw_BrowserWindowDevToolsResultFn,
+        data: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    #[doc = " Removes all cookies and stored data (local storage, IndexedDB, cache, etc.) of the given origin."]
+    #[doc = " This happens asynchronously, returns false if it couldn't be started."]
+    #[link_name = "\u{1}bw_BrowserWindow_clearOriginData"]
+    pub fn cbw_BrowserWindow_clearOriginData(
+        bw: *mut cbw_BrowserWindow,
+        origin: cbw_CStrSlice,
+    ) -> cBOOL;
+}
 extern "C" {
     #[link_name = "\u{1}bw_BrowserWindow_destroy"]
     pub fn cbw_BrowserWindow_destroy(bw: *mut cbw_BrowserWindow);
@@ -5097,10 +7049,48 @@ extern "C" {
         cb_data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    #[doc = " Like `bw_BrowserWindow_evalJs`, but executes the JavaScript within the frame with the given identifier."]
+    #[doc = " If there is no such frame, the callback is invoked with an error."]
+    #[link_name = "\u{1}bw_BrowserWindow_evalJsInFrame"]
+    pub fn cbw_BrowserWindow_evalJsInFrame(
+        bw: *mut cbw_BrowserWindow,
+        frame_id: i64,
+        js: cbw_CStrSlice,
+        callback: cbw_BrowserWindowJsCallbackFn,
+        cb_data: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    #[doc = " Executes an editing command in the frame that has the focus, or in the main frame if none has."]
+    #[doc = " `command` is 0 to undo, 1 to redo, 2 to cut, 3 to copy, 4 to paste, 5 to delete the selection, and 6 to select all."]
+    #[link_name = "\u{1}bw_BrowserWindow_execCommand"]
+    pub fn cbw_BrowserWindow_execCommand(bw: *mut cbw_BrowserWindow, command: u8);
+}
+extern "C" {
+    #[doc = " Causes an offscreen browser to paint its whole view again."]
+    #[link_name = "\u{1}bw_BrowserWindow_invalidate"]
+    pub fn cbw_BrowserWindow_invalidate(bw: *mut cbw_BrowserWindow);
+}
+extern "C" {
+    #[doc = " Frees the frames obtained from `bw_BrowserWindow_getFrames`."]
+    #[link_name = "\u{1}bw_BrowserWindow_freeFrames"]
+    pub fn cbw_BrowserWindow_freeFrames(frames: *mut cbw_BrowserWindowFrame, count: csize_t);
+}
 extern "C" {
     #[link_name = "\u{1}bw_BrowserWindow_getApp"]
     pub fn cbw_BrowserWindow_getApp(bw: *mut cbw_BrowserWindow) -> *mut cbw_Application;
 }
+extern "C" {
+    #[doc = " Allocates an array with information about all frames that are currently in the browser window, and stores it in `frames`."]
+    #[doc = " Returns the number of frames in the array."]
+    #[doc = " The array needs to be freed with `bw_BrowserWindow_freeFrames`."]
+    #[link_name = "\u{1}bw_BrowserWindow_getFrames"]
+    pub fn cbw_BrowserWindow_getFrames(
+        bw: *mut cbw_BrowserWindow,
+        frames: *mut *mut cbw_BrowserWindowFrame,
+    ) -> csize_t;
+}
 extern "C" {
     #[link_name = "\u{1}bw_BrowserWindow_getUserData"]
     pub fn cbw_BrowserWindow_getUserData(bw: *mut cbw_BrowserWindow)
@@ -5114,10 +7104,366 @@ extern "C" {
     #[link_name = "\u{1}bw_BrowserWindow_getWindow"]
     pub fn cbw_BrowserWindow_getWindow(bw: *mut cbw_BrowserWindow) -> *mut cbw_Window;
 }
+extern "C" {
+    #[doc = " Gets the zoom factor of the page, where 1.0 is 100%."]
+    #[link_name = "\u{1}bw_BrowserWindow_getZoom"]
+    pub fn cbw_BrowserWindow_getZoom(bw: *mut cbw_BrowserWindow) -> f64;
+}
 extern "C" {
     #[link_name = "\u{1}bw_BrowserWindow_navigate"]
     pub fn cbw_BrowserWindow_navigate(bw: *mut cbw_BrowserWindow, url: cbw_CStrSlice) -> cbw_Err;
 }
+extern "C" {
+    #[doc = " Causes the frame with the given identifier to navigate to `url`."]
+    #[doc = " Returns false if there is no such frame."]
+    #[link_name = "\u{1}bw_BrowserWindow_navigateFrame"]
+    pub fn cbw_BrowserWindow_navigateFrame(
+        bw: *mut cbw_BrowserWindow,
+        frame_id: i64,
+        url: cbw_CStrSlice,
+    ) -> cBOOL;
+}
+extern "C" {
+    #[doc = " Loads the page of the main frame again, which also starts a new render process if the old one has gone."]
+    #[doc = " If `ignore_cache` is set, nothing is taken from the cache."]
+    #[link_name = "\u{1}bw_BrowserWindow_reload"]
+    pub fn cbw_BrowserWindow_reload(bw: *mut cbw_BrowserWindow, ignore_cache: cBOOL);
+}
+extern "C" {
+    #[doc = " The following functions forward the input for offscreen browsers, which don't receive any input from their window."]
+    #[doc = " Coordinates are relative to the top-left corner of the view, and `modifiers` holds the `BW_MODIFIER_*` flags."]
+    #[doc = " They don't do anything for browsers that are not offscreen."]
+    #[link_name = "\u{1}bw_BrowserWindow_sendFocus"]
+    pub fn cbw_BrowserWindow_sendFocus(bw: *mut cbw_BrowserWindow, focused: cBOOL);
+}
+extern "C" {
+    #[doc = " `kind` is 0 for a key press, 1 for a key release, and 2 for a typed character."]
+    #[doc = " `key_code` is the virtual key code, as it is used on Windows, and `character` is the Unicode code point of the typed character."]
+    #[link_name = "\u{1}bw_BrowserWindow_sendKey"]
+    pub fn cbw_BrowserWindow_sendKey(
+        bw: *mut cbw_BrowserWindow,
+        kind: u8,
+        key_code: ::std::os::raw::c_int,
+        character: u32,
+        modifiers: u32,
+    );
+}
+extern "C" {
+    #[doc = " `button` is 0 for the left, 1 for the middle and 2 for the right mouse button."]
+    #[link_name = "\u{1}bw_BrowserWindow_sendMouseButton"]
+    pub fn cbw_BrowserWindow_sendMouseButton(
+        bw: *mut cbw_BrowserWindow,
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+        button: u8,
+        released: cBOOL,
+        click_count: ::std::os::raw::c_int,
+        modifiers: u32,
+    );
+}
+extern "C" {
+    #[doc = " `leave` is set when the mouse has left the view."]
+    #[link_name = "\u{1}bw_BrowserWindow_sendMouseMove"]
+    pub fn cbw_BrowserWindow_sendMouseMove(
+        bw: *mut cbw_BrowserWindow,
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+        leave: cBOOL,
+        modifiers: u32,
+    );
+}
+extern "C" {
+    #[link_name = "\u{1}bw_BrowserWindow_sendMouseWheel"]
+    pub fn cbw_BrowserWindow_sendMouseWheel(
+        bw: *mut cbw_BrowserWindow,
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+        delta_x: ::std::os::raw::c_int,
+        delta_y: ::std::os::raw::c_int,
+        modifiers: u32,
+    );
+}
+extern "C" {
+    #[doc = " Overrides the character encoding of the pages, and reloads the current page."]
+    #[doc = " An empty `charset` removes the override."]
+    #[link_name = "\u{1}bw_BrowserWindow_setCharsetOverride"]
+    pub fn cbw_BrowserWindow_setCharsetOverride(bw: *mut cbw_BrowserWindow, charset: cbw_CStrSlice);
+}
+extern "C" {
+    #[doc = " Registers a key combination like `Ctrl+S`, that invokes the accelerator handler with `accelerator_id` whenever it is pressed while the browser window has focus."]
+    #[doc = " The key press is handled before the page sees it, so the page doesn't receive it at all."]
+    #[doc = " Registering the same key combination again replaces the previous registration."]
+    #[doc = " Returns FALSE if the key combination isn't valid."]
+    #[link_name = "\u{1}bw_BrowserWindow_registerAccelerator"]
+    pub fn cbw_BrowserWindow_registerAccelerator(
+        bw: *mut cbw_BrowserWindow,
+        accelerator_id: u32,
+        accelerator: cbw_CStrSlice,
+    ) -> cBOOL;
+}
+extern "C" {
+    #[doc = " Sets a function that will be invoked when an offscreen browser has painted a frame into a shared texture."]
+    #[doc = " This only happens for browsers that have been created with `shared_textures` set, instead of invoking `on_paint`."]
+    #[doc = " If the GPU can't be used, `on_paint` is still invoked instead."]
+    #[doc = ""]
+    #[doc = " *Note:* Shared textures are only supported by CEF on Windows, where they are D3D11 textures."]
+    #[doc = " On other platforms, the frames are always given to `on_paint`."]
+    #[link_name = "\u{1}bw_BrowserWindow_setAcceleratedPaintHandler"]
+    pub fn cbw_BrowserWindow_setAcceleratedPaintHandler(
+        bw: *mut cbw_BrowserWindow,
+        handler: cbw_BrowserWindowAcceleratedPaintFn,
+    );
+}
+extern "C" {
+    #[doc = " Sets a function that will be invoked whenever one of the registered accelerators is pressed."]
+    #[link_name = "\u{1}bw_BrowserWindow_setAcceleratorHandler"]
+    pub fn cbw_BrowserWindow_setAcceleratorHandler(
+        bw: *mut cbw_BrowserWindow,
+        handler: cbw_BrowserWindowAcceleratorFn,
+    );
+}
+extern "C" {
+    #[doc = " Sets the functions that will be invoked when the window of the browser has moved or gained or lost the focus, when the main frame has navigated to another address, or when the title of the page has changed."]
+    #[link_name = "\u{1}bw_BrowserWindow_setEventHandlers"]
+    pub fn cbw_BrowserWindow_setEventHandlers(
+        bw: *mut cbw_BrowserWindow,
+        on_move: cbw_BrowserWindowMoveFn,
+        on_focus: cbw_BrowserWindowFocusFn,
+        on_navigate: cbw_BrowserWindowNavigationFn,
+        on_title_change: cbw_BrowserWindowTitleFn,
+    );
+}
+extern "C" {
+    #[doc = " Sets a function that will be invoked whenever the page has got another favicon, once it has been downloaded."]
+    #[doc = " CEF gives the pixels of the icon, while WebView2 gives it as a PNG image, and only does so from version 1.0.1722 on."]
+    #[link_name = "\u{1}bw_BrowserWindow_setFaviconHandler"]
+    pub fn cbw_BrowserWindow_setFaviconHandler(
+        bw: *mut cbw_BrowserWindow,
+        handler: cbw_BrowserWindowFaviconFn,
+    );
+}
+extern "C" {
+    #[doc = " Sets a function that will be invoked on the GUI thread whenever the page wants the user to choose files, instead of showing the dialog of the browser engine."]
+    #[doc = " Without one, the dialog of the browser engine is shown."]
+    #[doc = " WebView2 always shows its own dialog."]
+    #[link_name = "\u{1}bw_BrowserWindow_setFileChooserHandler"]
+    pub fn cbw_BrowserWindow_setFileChooserHandler(
+        bw: *mut cbw_BrowserWindow,
+        handler: cbw_BrowserWindowFileChooserFn,
+    );
+}
+extern "C" {
+    #[doc = " Sets a function that will be invoked for every key that is pressed or released while the browser window has focus, to decide whether the page gets it."]
+    #[doc = " WebView2 only gives the keys that don't type a character, like the function keys, and the keys that are pressed together with Ctrl or Alt."]
+    #[doc = " The other keys go to the page right away."]
+    #[link_name = "\u{1}bw_BrowserWindow_setKeyHandler"]
+    pub fn cbw_BrowserWindow_setKeyHandler(bw: *mut cbw_BrowserWindow, handler: cbw_BrowserWindowKeyFn);
+}
+extern "C" {
+    #[doc = " Sets a function that will be invoked whenever the main frame has finished loading, or has failed to load."]
+    #[doc = " Loads that are interrupted, like by navigating elsewhere before they are done, are not reported."]
+    #[link_name = "\u{1}bw_BrowserWindow_setLoadHandler"]
+    pub fn cbw_BrowserWindow_setLoadHandler(bw: *mut cbw_BrowserWindow, handler: cbw_BrowserWindowLoadFn);
+}
+extern "C" {
+    #[doc = " Sets a function that will be invoked whenever the progress of loading the page changes."]
+    #[doc = " WebView2 doesn't report the actual progress, so there it only moves through a few steps between starting and finishing a load."]
+    #[link_name = "\u{1}bw_BrowserWindow_setLoadingProgressHandler"]
+    pub fn cbw_BrowserWindow_setLoadingProgressHandler(
+        bw: *mut cbw_BrowserWindow,
+        handler: cbw_BrowserWindowLoadingProgressFn,
+    );
+}
+extern "C" {
+    #[doc = " Sets the functions that will be invoked when an offscreen browser has painted a frame, shows or hides a popup widget, or changes its cursor."]
+    #[doc = " On Windows, the frames are copied so that `on_paint` can be invoked on the GUI thread."]
+    #[link_name = "\u{1}bw_BrowserWindow_setOffscreenHandlers"]
+    pub fn cbw_BrowserWindow_setOffscreenHandlers(
+        bw: *mut cbw_BrowserWindow,
+        on_paint: cbw_BrowserWindowPaintFn,
+        on_popup: cbw_BrowserWindowPopupFn,
+        on_cursor_change: cbw_BrowserWindowCursorFn,
+    );
+}
+extern "C" {
+    #[doc = " Sets a function that will be invoked on the GUI thread for the events of the Chrome DevTools Protocol that have been subscribed to."]
+    #[link_name = "\u{1}bw_BrowserWindow_setDevToolsEventHandler"]
+    pub fn cbw_BrowserWindow_setDevToolsEventHandler(
+        bw: *mut cbw_BrowserWindow,
+        handler: cbw_BrowserWindowDevToolsEventFn,
+    );
+}
+extern "C" {
+    #[doc = " Sets a function that will be invoked whenever the page asks for a permission, to decide whether it is granted."]
+    #[doc = " Unlike the other handlers, it may be invoked from another thread than the GUI thread."]
+    #[link_name = "\u{1}bw_BrowserWindow_setPermissionHandler"]
+    pub fn cbw_BrowserWindow_setPermissionHandler(
+        bw: *mut cbw_BrowserWindow,
+        handler: cbw_BrowserWindowPermissionFn,
+    );
+}
+extern "C" {
+    #[doc = " Sets the functions that will be invoked when the render process of the page has terminated, and when the page stops or starts responding again."]
+    #[doc = " The page stays blank after its render process has terminated, until it is reloaded or navigated elsewhere."]
+    #[doc = " WebView2 doesn't report that the page responds again."]
+    #[link_name = "\u{1}bw_BrowserWindow_setRenderProcessHandlers"]
+    pub fn cbw_BrowserWindow_setRenderProcessHandlers(
+        bw: *mut cbw_BrowserWindow,
+        on_terminated: cbw_BrowserWindowProcessTerminatedFn,
+        on_unresponsive: cbw_BrowserWindowUnresponsiveFn,
+    );
+}
+extern "C" {
+    #[doc = " Sets a function that will be invoked for every request of the page of which the URL matches one of the given patterns, before it is sent."]
+    #[doc = " `filters` contains a pattern on every line, in which `*` matches any text, like `https://api.example.com/*` or `*` for all requests."]
+    #[doc = " The requests of the custom schemes of the application are not intercepted, they go to the scheme handler."]
+    #[doc = " Requests that the handler hasn't decided on are held back, so it needs to decide on all of them eventually."]
+    #[doc = " The filters are read from the threads of the browser engine, so this should only be done once, right after the browser window has been created."]
+    #[link_name = "\u{1}bw_BrowserWindow_setRequestHandler"]
+    pub fn cbw_BrowserWindow_setRequestHandler(
+        bw: *mut cbw_BrowserWindow,
+        handler: cbw_BrowserWindowRequestFn,
+        filters: cbw_CStrSlice,
+    );
+}
+extern "C" {
+    #[doc = " Sets a function that will be invoked whenever the window of the browser has been resized."]
+    #[link_name = "\u{1}bw_BrowserWindow_setResizeHandler"]
+    pub fn cbw_BrowserWindow_setResizeHandler(
+        bw: *mut cbw_BrowserWindow,
+        handler: cbw_BrowserWindowResizeFn,
+    );
+}
+extern "C" {
+    #[doc = " Sets a function that will be invoked for every request of one of the custom schemes of the application, which are given by `bw_ApplicationSettings`."]
+    #[doc = " Without one, these requests are answered with status 404."]
+    #[link_name = "\u{1}bw_BrowserWindow_setSchemeHandler"]
+    pub fn cbw_BrowserWindow_setSchemeHandler(
+        bw: *mut cbw_BrowserWindow,
+        handler: cbw_BrowserWindowSchemeFn,
+    );
+}
+extern "C" {
+    #[doc = " Sets a function that will be invoked whenever the scale factor of the window's display changes, or the window moves to a display with another scale factor."]
+    #[link_name = "\u{1}bw_BrowserWindow_setScaleFactorHandler"]
+    pub fn cbw_BrowserWindow_setScaleFactorHandler(
+        bw: *mut cbw_BrowserWindow,
+        handler: cbw_BrowserWindowScaleFactorFn,
+    );
+}
+extern "C" {
+    #[doc = " Sets a function that will be invoked whenever the text of the status bar changes, like when the mouse cursor moves onto or off a link."]
+    #[doc = " WebView2 only reports it from version 1.0.1185 on."]
+    #[link_name = "\u{1}bw_BrowserWindow_setStatusTextHandler"]
+    pub fn cbw_BrowserWindow_setStatusTextHandler(
+        bw: *mut cbw_BrowserWindow,
+        handler: cbw_BrowserWindowStatusTextFn,
+    );
+}
+extern "C" {
+    #[doc = " Sets the zoom factor of the page, where 1.0 is 100%."]
+    #[link_name = "\u{1}bw_BrowserWindow_setZoom"]
+    pub fn cbw_BrowserWindow_setZoom(bw: *mut cbw_BrowserWindow, factor: f64);
+}
+extern "C" {
+    #[doc = " Sets a function that will be invoked when the browser window is being destroyed."]
+    #[doc = " This is the last moment that the browser window handle and its user data can be used."]
+    #[link_name = "\u{1}bw_BrowserWindow_setDestroyHandler"]
+    pub fn cbw_BrowserWindow_setDestroyHandler(
+        bw: *mut cbw_BrowserWindow,
+        handler: cbw_BrowserWindowDestroyFn,
+    );
+}
+extern "C" {
+    #[doc = " Lets the handler of `bw_BrowserWindow_setDevToolsEventHandler` receive the event of the Chrome DevTools Protocol with the given name, like `Network.requestWillBeSent`."]
+    #[doc = " Most events are only sent once their domain has been enabled, with a method like `Network.enable`."]
+    #[link_name = "\u{1}bw_BrowserWindow_subscribeDevToolsEvent"]
+    pub fn cbw_BrowserWindow_subscribeDevToolsEvent(
+        bw: *mut cbw_BrowserWindow,
+        event: cbw_CStrSlice,
+    );
+}
+extern "C" {
+    #[doc = " Answers the file chooser with the given paths, after which `chooser` can't be used anymore."]
+    #[doc = " If `path_count` is 0, the chooser is cancelled, as if the user had closed the dialog."]
+    #[doc = " This function is thread safe."]
+    #[link_name = "\u{1}bw_FileChooser_select"]
+    pub fn cbw_FileChooser_select(
+        chooser: *mut cbw_FileChooser,
+        paths: *const cbw_CStrSlice,
+        path_count: csize_t,
+    );
+}
+extern "C" {
+    #[doc = " The following functions decide on an intercepted request, after which `request` can't be used anymore."]
+    #[doc = " They need to be called on the GUI thread."]
+    #[doc = ""]
+    #[doc = " Fails the request, as if the network had refused it."]
+    #[doc = " WebView2 can't fail requests, so it answers them with status 403 instead."]
+    #[link_name = "\u{1}bw_InterceptedRequest_block"]
+    pub fn cbw_InterceptedRequest_block(request: *mut cbw_InterceptedRequest);
+}
+extern "C" {
+    #[doc = " Sends the request, with the given headers instead of its own."]
+    #[doc = " `headers` is in the same format as the headers that the request handler is given."]
+    #[link_name = "\u{1}bw_InterceptedRequest_continue"]
+    pub fn cbw_InterceptedRequest_continue(
+        request: *mut cbw_InterceptedRequest,
+        headers: cbw_CStrSlice,
+    );
+}
+extern "C" {
+    #[doc = " Sends the request to the given URL instead."]
+    #[doc = " CEF lets the page know that it has been redirected, WebView2 gives the response as if it came from the original URL."]
+    #[link_name = "\u{1}bw_InterceptedRequest_redirect"]
+    pub fn cbw_InterceptedRequest_redirect(request: *mut cbw_InterceptedRequest, url: cbw_CStrSlice);
+}
+extern "C" {
+    #[doc = " Lets the application answer the request itself, in the same way as a request of a custom scheme."]
+    #[doc = " Returns the scheme request to give the response to, which needs to be finished with `bw_SchemeRequest_finish`."]
+    #[link_name = "\u{1}bw_InterceptedRequest_respond"]
+    pub fn cbw_InterceptedRequest_respond(
+        request: *mut cbw_InterceptedRequest,
+    ) -> *mut cbw_SchemeRequest;
+}
+extern "C" {
+    #[doc = " Ends the response with a failure, like when its body couldn't be read, after which `request` can't be used anymore."]
+    #[doc = " The page sees the request fail, instead of getting a body that has been cut short."]
+    #[doc = " If it hasn't been responded to yet, it is answered with status 500."]
+    #[doc = " This function is thread safe."]
+    #[link_name = "\u{1}bw_SchemeRequest_abort"]
+    pub fn cbw_SchemeRequest_abort(request: *mut cbw_SchemeRequest);
+}
+extern "C" {
+    #[doc = " Ends the body of the response, after which `request` can't be used anymore."]
+    #[doc = " If it hasn't been responded to yet, it is answered with status 500."]
+    #[doc = " This function is thread safe."]
+    #[link_name = "\u{1}bw_SchemeRequest_finish"]
+    pub fn cbw_SchemeRequest_finish(request: *mut cbw_SchemeRequest);
+}
+extern "C" {
+    #[doc = " Gives the status code and headers of the response, which needs to be done before its body is written."]
+    #[doc = " `headers` is in the same format as the headers of the request."]
+    #[doc = " This function is thread safe."]
+    #[link_name = "\u{1}bw_SchemeRequest_respond"]
+    pub fn cbw_SchemeRequest_respond(
+        request: *mut cbw_SchemeRequest,
+        status: u16,
+        headers: cbw_CStrSlice,
+    );
+}
+extern "C" {
+    #[doc = " Appends `data` to the body of the response."]
+    #[doc = " Blocks while much of the body is still waiting to be read by the browser engine, so that large bodies don't need to be in memory all at once."]
+    #[doc = " Returns FALSE if the request has been cancelled, like when the page has navigated away, in which case the rest of the body isn't needed anymore."]
+    #[doc = " This function is thread safe."]
+    #[link_name = "\u{1}bw_SchemeRequest_write"]
+    pub fn cbw_SchemeRequest_write(
+        request: *mut cbw_SchemeRequest,
+        data: *const ::std::os::raw::c_void,
+        length: csize_t,
+    ) -> cBOOL;
+}
 extern "C" {
     #[doc = " Creates a new browser window"]
     #[link_name = "\u{1}bw_BrowserWindow_new"]
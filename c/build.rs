@@ -110,9 +110,19 @@ fn main() {
 		return
 	}
 
+	// The mock backend of `browser-window-core` is written in Rust, so only the types of the bindings are needed.
+	if cfg!(feature = "mock") {
+		fs::copy( &backup_file, out_path.join("c_bindings.rs") ).expect("Unable to copy backup c bindings");
+		return
+	}
+
 	let mut build = cc::Build::new();
 	let mut build_se = cc::Build::new();	// For seperate executable
-	let std_flag = if cfg!(feature = "cef") {
+	if cfg!(feature = "cef") && cfg!(feature = "webview2") {
+		panic!("Only one browser engine can be selected. Disable the default features to use WebView2.")
+	}
+
+	let std_flag = if cfg!(feature = "cef") || cfg!(feature = "webview2") {
 		if target.contains("msvc") {
 			"/std:c++latest"
 		}
@@ -140,7 +150,9 @@ fn main() {
 		.header("src/cookie.h")
 		.header("src/common.h")
 		.header("src/err.h")
+		.header("src/menu.h")
 		.header("src/string.h")
+		.header("src/tray_icon.h")
 		.header("src/window.h");
 
 	/**************************************
@@ -153,9 +165,23 @@ fn main() {
 		build
 			.file("src/win32.c")
 			.file("src/application/win32.c")
+			.file("src/clipboard/win32.c")
+			.file("src/drag/win32.c")
+			.file("src/hotkey/win32.c")
+			.file("src/menu/win32.c")
+			.file("src/message_box/win32.c")
+			.file("src/monitor/win32.c")
+			.file("src/notification/win32.c")
+			.file("src/theme/win32.c")
+			.file("src/tray_icon/win32.c")
 			.file("src/window/win32.c")
 			.define("BW_WIN32", None)
 			.define("_CRT_SECURE_NO_WARNINGS", None);	// Disable sprintf_s warnings. sprintf_s tends to cause segfaults anyway...
+		println!("cargo:rustc-link-lib=dylib=advapi32");	// For reading the theme of the system from the registry
+		println!("cargo:rustc-link-lib=dylib=comctl32");	// For embedding windows in the windows of other toolkits
+		println!("cargo:rustc-link-lib=dylib=dwmapi");	// For the window backdrops
+		println!("cargo:rustc-link-lib=dylib=ole32");	// For dragging data out of windows
+		println!("cargo:rustc-link-lib=dylib=shell32");
 
 		build_se
 			.define("BW_WIN32", None)
@@ -177,6 +203,15 @@ fn main() {
 		// GTK source files
 		build
 			.file("src/application/gtk.c")
+			.file("src/clipboard/gtk.c")
+			.file("src/drag/gtk.c")
+			.file("src/hotkey/gtk.c")
+			.file("src/menu/gtk.c")
+			.file("src/message_box/gtk.c")
+			.file("src/monitor/gtk.c")
+			.file("src/notification/gtk.c")
+			.file("src/theme/gtk.c")
+			.file("src/tray_icon/gtk.c")
 			.file("src/window/gtk.c")
 			.define("BW_GTK", None);
 		build_se
@@ -192,12 +227,32 @@ fn main() {
 				}
 			}
 		}
+
+		// Global hotkeys are grabbed with Xlib when GDK runs on X11
+		if !target.contains("windows") {
+			match pkg_config::Config::new().probe("x11") {
+				Err(e) => panic!("Unable to find X11 development files: {}", e),
+				Ok( lib ) => {
+					for inc in &lib.include_paths {
+						build.include( inc );
+					}
+				}
+			}
+		}
 	}
 	// Non-windows systems that opt for using CEF, use CEF's own internal windowing features
 	else if cfg!(feature = "cef") && !target.contains("windows") {
 		bgbuilder = bgbuilder.clang_arg("-DBW_CEF_WINDOW");
 		build
 			.file("src/application/cef_window.cpp")
+			.file("src/clipboard/cef.cpp")
+			.file("src/drag/cef.cpp")
+			.file("src/hotkey/cef.cpp")
+			.file("src/message_box/cef.cpp")
+			.file("src/monitor/cef.cpp")
+			.file("src/notification/cef.cpp")
+			.file("src/theme/cef.cpp")
+			.file("src/tray_icon/cef.cpp")
 			.file("src/window/cef.cpp")
 			.define("BW_CEF_WINDOW", None);
 		build_se
@@ -271,6 +326,8 @@ fn main() {
 		build
 			.file("src/application/cef.cpp")
 			.file("src/browser_window/cef.cpp")
+			.file("src/browser_window/request.cpp")
+			.file("src/browser_window/scheme.cpp")
 			.file("src/cookie/cef.cpp")
 			.file("src/cef/bw_handle_map.cpp")
 			.file("src/cef/client_handler.cpp")
@@ -305,6 +362,51 @@ fn main() {
 	}
 
 
+	/**************************************
+	 *	The Browser Engine (WebView2) source files
+	 **************************************/
+	if cfg!(feature = "webview2") {
+		if !target.contains("windows") {
+			panic!("The WebView2 browser engine is only available on Windows.")
+		}
+		bgbuilder = bgbuilder.clang_arg("-DBW_WEBVIEW2");
+
+		match env::var("WEBVIEW2_PATH") {
+			Err(e) => {
+				match e {
+					env::VarError::NotPresent => {
+						if let Err(_) = env::var("DOCS_RS") {
+							panic!("Environment variable WEBVIEW2_PATH is not set! This is needed by Browser Window to find the development files of the WebView2 SDK. It should point to the build/native directory of the Microsoft.Web.WebView2 package.")
+						}
+					},
+					other => panic!("Unable to use WEBVIEW2_PATH: {}", other)
+				}
+			},
+			Ok(webview2_path) => {
+				let arch = if target.starts_with("x86_64") { "x64" }
+					else if target.starts_with("aarch64") { "arm64" }
+					else { "x86" };
+
+				build.include(format!("{}/include", &webview2_path));
+				println!("cargo:rustc-link-search={}/{}", &webview2_path, arch);
+				println!("cargo:rustc-link-lib=static=WebView2LoaderStatic");
+			}
+		}
+
+		// Source files
+		build
+			.file("src/application/webview2.cpp")
+			.file("src/browser_window/request.cpp")
+			.file("src/browser_window/scheme.cpp")
+			.file("src/browser_window/webview2.cpp")
+			.file("src/cookie/webview2.cpp")
+			.file("src/webview2/environment.cpp")
+			.file("src/webview2/util.cpp")
+			.define("BW_WEBVIEW2", None)
+			.cpp(true);
+	}
+
+
 
 	/**************************************
 	 *	All other source files
@@ -313,7 +415,9 @@ fn main() {
 		.file("src/application/common.c")
 		.file("src/browser_window/common.c")
 		.file("src/err.c")
+		.file("src/menu/common.c")
 		.file("src/string.c")
+		.file("src/tray_icon/common.c")
 		.file("src/window/common.c")
 		.flag( std_flag )
 		.compile("browser-window-c");
@@ -8,11 +8,24 @@ extern "C" {
 #include "bool.h"
 #include "string.h"
 
+#include <stdint.h>
+
 
 
 struct bw_Application;
 typedef void (*bw_ApplicationDispatchFn)( struct bw_Application* app, void* data );
 typedef bw_ApplicationDispatchFn bw_ApplicationReadyFn;
+/// Invoked when a notification has gone away, with whether or not the user has clicked on it.
+typedef void (*bw_ApplicationNotificationFn)( struct bw_Application* app, BOOL clicked, void* data );
+/// Invoked when a global hotkey has been pressed.
+typedef void (*bw_ApplicationHotkeyFn)( struct bw_Application* app, uint32_t hotkey_id, void* data );
+/// Invoked when the system has switched between its light and dark theme, with the `BW_THEME_*` value of the new one.
+typedef void (*bw_ApplicationThemeFn)( struct bw_Application* app, uint8_t theme, void* data );
+
+// The themes of the system, as given by `bw_Application_getSystemTheme`.
+#define BW_THEME_UNKNOWN 0
+#define BW_THEME_LIGHT 1
+#define BW_THEME_DARK 2
 
 
 
@@ -31,6 +44,8 @@ typedef bw_ApplicationDispatchFn bw_ApplicationReadyFn;
 
 #if defined(BW_CEF)
 #include "application/cef.h"
+#elif defined(BW_WEBVIEW2)
+#include "application/webview2.h"
 #else
 typedef struct {} bw_ApplicationEngineImpl;
 #endif
@@ -42,14 +57,13 @@ typedef struct {} bw_ApplicationEngineImpl;
 
 #include "err.h"
 
-#include <stdint.h>
-
 
 
 struct bw_Application {
 	unsigned int windows_alive;
 	BOOL is_running;
 	BOOL is_done;
+	BOOL headless;	/// Windows are never shown when set.
 	bw_ApplicationImpl impl;
 	bw_ApplicationEngineImpl engine_impl;	/// Can be set by the implementation of a browser engine
 };
@@ -65,8 +79,39 @@ typedef struct {
 typedef struct {
 	bw_CStrSlice engine_seperate_executable_path;
 	bw_CStrSlice resource_dir;
+	BOOL headless;	/// Runs the browser engine without ever showing any windows.
+	bw_CStrSlice cache_path;	/// Empty for the engine's default.
+	bw_CStrSlice user_data_dir;	/// Empty for the engine's default.
+	int log_severity;	/// 0 for the engine's default, otherwise from 1 (verbose) up to 5 (fatal), or 6 to disable logging.
+	bw_CStrSlice log_file;	/// Empty for the engine's default.
+	bw_CStrSlice locale;	/// Empty for the system's locale.
+	int remote_debugging_port;	/// 0 to disable remote debugging.
+	const bw_CStrSlice* engine_switches;	/// Extra command-line switches for the browser engine, like `--disable-gpu`.
+	size_t engine_switch_count;
+	BOOL persist_session_cookies;	/// Keeps the cookies that expire with the session across restarts, which needs `cache_path` to be set.
+	const bw_CStrSlice* custom_schemes;	/// The schemes of which browser windows answer the requests themselves, with their scheme handler.
+	size_t custom_scheme_count;
+	BOOL disable_gpu;	/// Renders everything without the GPU.
+	BOOL software_rasterization;	/// Rasterizes the pages without the GPU, while still compositing them with it.
+	unsigned int max_frame_rate;	/// The maximum number of frames per second that offscreen browsers paint, or 0 for the engine's default.
+	BOOL autoplay_without_gesture;	/// Lets pages play media with sound before the user has interacted with them.
 } bw_ApplicationSettings;
 
+/// A display that is connected to the system.
+/// Its coordinates are in the same units as the positions of windows.
+typedef struct {
+	int32_t x;
+	int32_t y;
+	uint32_t width;
+	uint32_t height;
+	int32_t work_x;	/// The work area is the part of the display that isn't covered by taskbars, docks or panels.
+	int32_t work_y;
+	uint32_t work_width;
+	uint32_t work_height;
+	float scale_factor;
+	BOOL is_primary;
+} bw_Monitor;
+
 
 
 /// Safety check that makes sure the given application handle is used on the correct thread.
@@ -90,6 +135,15 @@ void bw_Application_exitAsync(  bw_Application* app, int exit_code );
 /// Dispatching a function fails when the application has already been terminated.
 BOOL bw_Application_dispatch( bw_Application* app, bw_ApplicationDispatchFn func, void* data );
 
+/// Prepares the application to have its events processed by `bw_Application_pumpEvents`, instead of by its own event loop with `bw_Application_run`.
+/// Use this when another event loop, like the one of winit, already runs on the main thread.
+void bw_Application_beginPumping( bw_Application* app );
+
+/// Stores a newly allocated string with the version of the browser engine in `version`.
+/// Returns false if the version could not be determined.
+/// The string needs to be freed with `bw_string_free`.
+BOOL bw_Application_getEngineVersion( bw_StrSlice* version );
+
 /// Shuts down all application processes and performs necessary clean-up code.
 void bw_Application_finish( bw_Application* app );
 
@@ -98,6 +152,24 @@ void bw_Application_finish( bw_Application* app );
 /// Freeing the application handle is generally not necessary, as all memory in use by the process gets released anyway after shutdown.
 void bw_Application_free( bw_Application* app );
 
+/// Frees the monitors given by `bw_Application_getMonitors`.
+void bw_Application_freeMonitors( bw_Monitor* monitors );
+
+/// Frees the pixels given by `bw_Application_readClipboardImage`.
+void bw_Application_freeClipboardImage( uint8_t* pixels );
+
+/// Stores a newly allocated array with all displays that are connected to the system in `monitors`, and returns its length.
+/// The array needs to be freed with `bw_Application_freeMonitors`.
+size_t bw_Application_getMonitors( bw_Application* app, bw_Monitor** monitors );
+
+/// Gives whether the system uses a light or a dark theme for applications, as one of the `BW_THEME_*` values.
+/// Returns `BW_THEME_UNKNOWN` if the system doesn't tell.
+///
+/// *Note:* On Windows, this is the theme of applications in the personalization settings.
+/// With GTK, this is derived from the GTK theme, which is dark if its name ends with `-dark` or if dark themes are preferred.
+/// The theme is not known when CEF provides the windows itself.
+uint8_t bw_Application_getSystemTheme( bw_Application* app );
+
 /// Initializes browser window.
 /// Starts up browser engine process(es).
 /// Returns an application handle.
@@ -105,15 +177,88 @@ bw_Err bw_Application_initialize( bw_Application** application, int argc, char**
 
 BOOL bw_Application_isRunning( const bw_Application* app );
 
+/// Shows a desktop notification.
+///
+/// # Arguments
+/// * `icon_path` - The image file that is shown with the notification, which needs to be an `.ico` file on Windows. May be empty.
+/// * `handler` - Invoked once the notification has been clicked or dismissed. May be NULL.
+///
+/// *Note:* GTK doesn't report when notifications are dismissed, so `handler` is only invoked when the notification is clicked.
+/// Notifications are not supported when CEF provides the windows itself, `handler` is invoked with `clicked` set to FALSE right away.
+void bw_Application_notify( bw_Application* app, bw_CStrSlice title, bw_CStrSlice body, bw_CStrSlice icon_path, bw_ApplicationNotificationFn handler, void* data );
+
 void bw_Application_markAsDone(bw_Application* app);
 
+/// Gives the paths of the files that are on the clipboard, like the ones that have been copied in a file manager.
+/// Every path in `paths` is terminated by a null character.
+/// Returns FALSE if the clipboard doesn't contain any files.
+/// The string needs to be freed with `bw_string_free`.
+BOOL bw_Application_readClipboardFiles( bw_Application* app, bw_StrSlice* paths );
+
+/// Gives the HTML fragment that is on the clipboard.
+/// Returns FALSE if the clipboard doesn't contain any HTML.
+/// The string needs to be freed with `bw_string_free`.
+BOOL bw_Application_readClipboardHtml( bw_Application* app, bw_StrSlice* html );
+
+/// Gives the image that is on the clipboard, as RGBA pixels that go row by row from the top, without any padding.
+/// Returns FALSE if the clipboard doesn't contain an image.
+/// The pixels need to be freed with `bw_Application_freeClipboardImage`.
+///
+/// *Note:* The clipboard is not supported when CEF provides the windows itself, it always appears to be empty and can't be written to.
+BOOL bw_Application_readClipboardImage( bw_Application* app, uint8_t** pixels, uint32_t* width, uint32_t* height );
+
+/// Gives the text that is on the clipboard.
+/// Returns FALSE if the clipboard doesn't contain any text.
+/// The string needs to be freed with `bw_string_free`.
+BOOL bw_Application_readClipboardText( bw_Application* app, bw_StrSlice* text );
+
+/// Registers a key combination, like `Ctrl+Alt+Space`, that invokes `handler` on the GUI thread whenever it is pressed.
+/// This happens even when none of the windows of the application has focus.
+/// `hotkey_id` should not be in use by another hotkey.
+/// Returns FALSE if the key combination is invalid, or if it can't be registered because another application has taken it already.
+///
+/// *Note:* Global hotkeys are only supported on Windows and on X11.
+/// Wayland compositors don't allow applications to grab keys, and CEF doesn't provide a way to do so when it provides the windows itself.
+BOOL bw_Application_registerGlobalHotkey( bw_Application* app, uint32_t hotkey_id, bw_CStrSlice hotkey, bw_ApplicationHotkeyFn handler, void* data );
+
+/// Unregisters the global hotkey with the given ID.
+/// Returns the `data` that it has been registered with, so that it can be freed, or NULL if there is no such hotkey.
+void* bw_Application_unregisterGlobalHotkey( bw_Application* app, uint32_t hotkey_id );
+
+/// Processes all events that are pending, without waiting for new ones.
+/// Returns FALSE once the application has exited, in which case the exit code is stored in `exit_code`.
+BOOL bw_Application_pumpEvents( bw_Application* app, int* exit_code );
+
 /// Runs the event loop.
 /// Calls the `on_ready` callback when `app` can be used.
 int bw_Application_run( bw_Application* app, bw_ApplicationReadyFn on_ready, void* user_data );
 
+/// Sets the function that is invoked on the GUI thread whenever the system switches between its light and dark theme.
+/// A previously set handler is replaced, and NULL stops the notifications.
+///
+/// *Note:* The handler is never invoked when CEF provides the windows itself.
+void bw_Application_setThemeHandler( bw_Application* app, bw_ApplicationThemeFn handler, void* data );
+
 /// Executes the given closure after the specified delay.
 BOOL bw_Application_dispatchDelayed(bw_Application* app, bw_ApplicationDispatchFn func, void* user_data, uint64_t milliseconds);
 
+/// Replaces the contents of the clipboard with a list of files, so that they can be pasted in a file manager.
+/// Every path in `paths` needs to be terminated by a null character.
+/// Returns FALSE if the clipboard can't be written to.
+BOOL bw_Application_writeClipboardFiles( bw_Application* app, bw_CStrSlice paths );
+
+/// Replaces the contents of the clipboard with an HTML fragment.
+/// Returns FALSE if the clipboard can't be written to.
+BOOL bw_Application_writeClipboardHtml( bw_Application* app, bw_CStrSlice html );
+
+/// Replaces the contents of the clipboard with an image, given as RGBA pixels that go row by row from the top, without any padding.
+/// Returns FALSE if the clipboard can't be written to.
+BOOL bw_Application_writeClipboardImage( bw_Application* app, const uint8_t* pixels, uint32_t width, uint32_t height );
+
+/// Replaces the contents of the clipboard with text.
+/// Returns FALSE if the clipboard can't be written to.
+BOOL bw_Application_writeClipboardText( bw_Application* app, bw_CStrSlice text );
+
 
 
 #ifdef __cplusplus
@@ -2,15 +2,20 @@
 #include "../debug.h"
 #include "../cef/app_handler.hpp"
 #include "../cef/client_handler.hpp"
+#include "../cef/scheme_handler.hpp"
+#include "../cef/util.hpp"
 
 #include "impl.h"
 
 #include <include/cef_app.h>
 #include <include/cef_base.h>
+#include <include/cef_version.h>
 #ifdef BW_MACOS
 #include <include/wrapper/cef_library_loader.h>
 #endif
 #include <stdlib.h>
+#include <string>
+#include <vector>
 
 // X11 headers, when used by CEF
 #if defined(CEF_X11)
@@ -25,6 +30,8 @@
 // Causes the current process to exit with the given exit code.
 void _bw_Application_exitProcess( int exit_code );
 CefString to_string( bw_CStrSlice );
+// Converts the log severity of `bw_ApplicationSettings` to CEF's equivalent.
+cef_log_severity_t bw_ApplicationCef_logSeverity( int severity );
 
 #ifdef CEF_X11
 int _bw_ApplicationCef_xErrorHandler( Display* display, XErrorEvent* event );
@@ -55,8 +62,25 @@ bw_Err bw_ApplicationEngineImpl_initialize( bw_ApplicationEngineImpl* impl, bw_A
 	CefMainArgs main_args( argc, argv );
 #endif
 
+	std::vector<std::string> switches;
+	for ( size_t i = 0; i < settings->engine_switch_count; i++ )
+		switches.push_back( std::string( settings->engine_switches[i].data, settings->engine_switches[i].len ) );
+	// Some combinations of drivers crash the GPU process, these switches keep the GPU out of it
+	if ( settings->disable_gpu ) {
+		switches.push_back( "disable-gpu" );
+		switches.push_back( "disable-gpu-compositing" );
+	}
+	if ( settings->software_rasterization )
+		switches.push_back( "disable-gpu-rasterization" );
+	if ( settings->autoplay_without_gesture )
+		switches.push_back( "autoplay-policy=no-user-gesture-required" );
+
+	std::vector<std::string> custom_schemes;
+	for ( size_t i = 0; i < settings->custom_scheme_count; i++ )
+		custom_schemes.push_back( std::string( settings->custom_schemes[i].data, settings->custom_schemes[i].len ) );
+
 	CefSettings app_settings;
-	CefRefPtr<CefApp> cef_app_handle( new AppHandler( app ) );
+	CefRefPtr<CefApp> cef_app_handle( new AppHandler( app, switches, custom_schemes ) );
 
 	if (settings->engine_seperate_executable_path.len == 0) {
 		int exit_code = CefExecuteProcess( main_args, cef_app_handle.get(), 0 );
@@ -87,16 +111,55 @@ bw_Err bw_ApplicationEngineImpl_initialize( bw_ApplicationEngineImpl* impl, bw_A
 		bw_string_freeCstr(path);
 	}
 
+	if ( settings->cache_path.len != 0 )
+		CefString( &app_settings.cache_path ) = bw_cef_copyFromStrSlice( settings->cache_path );
+	if ( settings->user_data_dir.len != 0 )
+		CefString( &app_settings.user_data_path ) = bw_cef_copyFromStrSlice( settings->user_data_dir );
+	if ( settings->log_file.len != 0 )
+		CefString( &app_settings.log_file ) = bw_cef_copyFromStrSlice( settings->log_file );
+	if ( settings->locale.len != 0 )
+		CefString( &app_settings.locale ) = bw_cef_copyFromStrSlice( settings->locale );
+	app_settings.log_severity = bw_ApplicationCef_logSeverity( settings->log_severity );
+	app_settings.remote_debugging_port = settings->remote_debugging_port;
+	// CEF writes the session cookies to the cache when it shuts down, and reads them back when it starts up.
+	app_settings.persist_session_cookies = settings->persist_session_cookies;
+
+	// Headless browsers render offscreen, so that they don't need a window.
+	app_settings.windowless_rendering_enabled = settings->headless;
+
 	CefInitialize( main_args, app_settings, cef_app_handle.get(), 0 );
 
-	CefRefPtr<CefClient>* client = new CefRefPtr<CefClient>(new ClientHandler( app ));
+	// The requests of the custom schemes are answered by the browser windows they come from
+	CefRefPtr<CefSchemeHandlerFactory> scheme_handler_factory( new bw::SchemeHandlerFactory );
+	for ( const std::string& scheme : custom_schemes )
+		CefRegisterSchemeHandlerFactory( scheme, "", scheme_handler_factory );
+
+	CefRefPtr<CefClient>* client = new CefRefPtr<CefClient>(new ClientHandler( app, custom_schemes ));
 
 	impl->exit_code = 0;
 	impl->cef_client = (void*)client;
+	impl->max_frame_rate = settings->max_frame_rate;
 
 	BW_ERR_RETURN_SUCCESS;
 }
 
+cef_log_severity_t bw_ApplicationCef_logSeverity( int severity ) {
+	switch ( severity ) {
+	case 1:	return LOGSEVERITY_VERBOSE;
+	case 2:	return LOGSEVERITY_INFO;
+	case 3:	return LOGSEVERITY_WARNING;
+	case 4:	return LOGSEVERITY_ERROR;
+	case 5:	return LOGSEVERITY_FATAL;
+	case 6:	return LOGSEVERITY_DISABLE;
+	default:	return LOGSEVERITY_DEFAULT;
+	}
+}
+
+BOOL bw_Application_getEngineVersion( bw_StrSlice* version ) {
+	*version = bw_cef_copyToStrSlice( CefString( CEF_VERSION ) );
+	return TRUE;
+}
+
 void bw_ApplicationEngineImpl_finish( bw_ApplicationEngineImpl* app ) {
 	CefShutdown();
 	delete (CefRefPtr<CefClient>*)app->cef_client;
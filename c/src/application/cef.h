@@ -10,6 +10,7 @@ extern "C" {
 typedef struct {
 	void* cef_client;
 	int exit_code;
+	unsigned int max_frame_rate;	// Applies to the offscreen browsers, 0 leaves it to CEF.
 } bw_ApplicationEngineImpl;
 
 
@@ -22,13 +22,21 @@ void bw_Application_exit( bw_Application* app, int exit_code ) {
 
 	app->impl.exit_code = exit_code;
 
-	CefQuitMessageLoop();
+	if ( app->impl.is_pumped )
+		app->impl.has_exited = TRUE;
+	else
+		CefQuitMessageLoop();
 }
 
 void bw_Application_exitAsync( bw_Application* app, int exit_code ) {
 	CefPostTask( TID_UI, base::Bind( &bw_Application_exit, app, exit_code ));
 }
 
+void bw_ApplicationImpl_beginPumping( bw_Application* app ) {
+	app->impl.is_pumped = TRUE;
+	app->impl.has_exited = FALSE;
+}
+
 BOOL bw_ApplicationImpl_dispatchDelayed(bw_Application* app, bw_ApplicationDispatchData* data,  uint64_t milliseconds) {
 	BW_ASSERT(milliseconds < 0x8000000000000000, "CEF doesn't support delays of 0x8000000000000000 or longer");	// The milliseconds in CefPostDelayedTask is signed.
 
@@ -53,6 +61,16 @@ void bw_ApplicationImpl_finish( bw_ApplicationImpl* app ) {
 	CefShutdown();
 }
 
+BOOL bw_ApplicationImpl_pumpEvents( bw_Application* app, int* exit_code ) {
+	CefDoMessageLoopWork();
+
+	if ( app->impl.has_exited ) {
+		*exit_code = app->impl.exit_code;
+		return FALSE;
+	}
+	return TRUE;
+}
+
 int bw_ApplicationImpl_run( bw_Application* app, bw_ApplicationImpl_ReadyHandlerData* ready_handler_data ) {
 	bw_Application_assertCorrectThread( app );
 
@@ -72,5 +90,8 @@ bw_ApplicationImpl bw_ApplicationImpl_initialize( bw_Application* app, int argc,
 	UNUSED( settings );
 
 	bw_ApplicationImpl impl;
+	impl.exit_code = 0;
+	impl.is_pumped = FALSE;
+	impl.has_exited = FALSE;
 	return impl;
 }
\ No newline at end of file
@@ -15,6 +15,8 @@ extern "C" {
 
 typedef struct {
 	int exit_code;
+	BOOL is_pumped;	// Whether or not another event loop pumps the events
+	BOOL has_exited;	// Only used when pumping
 } bw_ApplicationImpl;
 
 
@@ -7,10 +7,22 @@
 
 
 
+void bw_Application_beginPumping( bw_Application* app ) {
+	bw_Application_assertCorrectThread( app );
+	app->is_running = TRUE;
+	app->is_done = FALSE;
+
+	bw_ApplicationImpl_beginPumping( app );
+}
+
 void bw_Application_free( bw_Application* app ) {
 	free( app );
 }
 
+void bw_Application_freeClipboardImage( uint8_t* pixels ) {
+	free( pixels );
+}
+
 BOOL bw_Application_isRunning( const bw_Application* app ) {
 	return app->is_running;
 }
@@ -21,6 +33,16 @@ void bw_Application_markAsDone(bw_Application* app) {
 		bw_Application_exit(app, 0);
 }
 
+BOOL bw_Application_pumpEvents( bw_Application* app, int* exit_code ) {
+	bw_Application_assertCorrectThread( app );
+
+	if ( !bw_ApplicationImpl_pumpEvents( app, exit_code ) ) {
+		app->is_running = FALSE;
+		return FALSE;
+	}
+	return TRUE;
+}
+
 void bw_Application_runOnReady(bw_Application* app, void* user_data) {
 	bw_ApplicationImpl_ReadyHandlerData* ready_handler_data = (bw_ApplicationImpl_ReadyHandlerData*)user_data;
 
@@ -61,6 +83,7 @@ bw_Err bw_Application_initialize( bw_Application** app, int argc, char** argv, c
 	(*app)->windows_alive = 0;
 	(*app)->is_running = FALSE;
 	(*app)->is_done = FALSE;
+	(*app)->headless = settings->headless;
 
 	bw_Err error = bw_ApplicationEngineImpl_initialize( &(*app)->engine_impl, (*app), argc, argv, settings );
 	if (BW_ERR_IS_FAIL(error))	return error;
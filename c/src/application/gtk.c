@@ -52,6 +52,35 @@ void bw_Application_exitAsync( bw_Application* app, int exit_code ) {
 	gdk_threads_add_idle( _bw_ApplicationImpl_exitHandler, (gpointer)&data );
 }
 
+void bw_ApplicationImpl_beginPumping( bw_Application* app ) {
+	// Registering is what running the application would otherwise do first
+	g_application_register( G_APPLICATION(app->impl.handle), NULL, NULL );
+
+	pthread_mutex_lock( &app->impl.is_running_mtx );
+	app->impl.is_running = true;
+	pthread_mutex_unlock( &app->impl.is_running_mtx );
+}
+
+BOOL bw_ApplicationImpl_pumpEvents( bw_Application* app, int* exit_code ) {
+	bool is_running = true;
+
+	while ( is_running && g_main_context_iteration( NULL, FALSE ) ) {
+		pthread_mutex_lock( &app->impl.is_running_mtx );
+		is_running = app->impl.is_running;
+		pthread_mutex_unlock( &app->impl.is_running_mtx );
+	}
+
+	pthread_mutex_lock( &app->impl.is_running_mtx );
+	is_running = app->impl.is_running;
+	pthread_mutex_unlock( &app->impl.is_running_mtx );
+
+	if ( !is_running ) {
+		*exit_code = app->impl.exit_code;
+		return FALSE;
+	}
+	return TRUE;
+}
+
 void bw_ApplicationGtk_onActivate( GtkApplication* gtk_handle, gpointer data ) {
 	UNUSED( gtk_handle );
 
@@ -94,6 +123,28 @@ BOOL bw_ApplicationImpl_dispatch( bw_Application* app, bw_ApplicationDispatchDat
 	return is_running;
 }
 
+BOOL bw_ApplicationImpl_dispatchDelayed( bw_Application* app, bw_ApplicationDispatchData* _data, uint64_t milliseconds ) {
+	BW_ASSERT( milliseconds <= G_MAXUINT, "GTK doesn't support delays longer than G_MAXUINT milliseconds" );
+
+	BOOL is_running = true;
+
+	pthread_mutex_lock( &app->impl.is_running_mtx );
+
+	if ( app->impl.is_running ) {
+		bw_ApplicationImplDispatchData* data = (bw_ApplicationImplDispatchData*)malloc( sizeof( bw_ApplicationImplDispatchData ) );
+		data->app = app;
+		data->inner = _data;
+
+		gdk_threads_add_timeout( (guint)milliseconds, _bw_ApplicationImpl_dispatchHandler, (gpointer)data );
+	}
+	else
+		is_running = false;
+
+	pthread_mutex_unlock( &app->impl.is_running_mtx );
+
+	return is_running;
+}
+
 void bw_ApplicationImpl_dispatchHandler( bw_Application* app, bw_ApplicationDispatchData* data ) {
 	data->func( app, data->data );
 }
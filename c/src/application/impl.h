@@ -17,11 +17,13 @@ typedef struct {
 
 
 
+void bw_ApplicationImpl_beginPumping( bw_Application* app );
 BOOL bw_ApplicationImpl_dispatch( bw_Application* app, bw_ApplicationDispatchData* data );
 BOOL bw_ApplicationImpl_dispatchDelayed( bw_Application* app, bw_ApplicationDispatchData* data, uint64_t milliseconds );
 void bw_ApplicationImpl_finish( bw_ApplicationImpl* );
 int bw_ApplicationImpl_run( bw_Application* app, bw_ApplicationImpl_ReadyHandlerData* ready_handler_data );
 bw_ApplicationImpl bw_ApplicationImpl_initialize( bw_Application* app, int argc, char** argv, const bw_ApplicationSettings* settings );
+BOOL bw_ApplicationImpl_pumpEvents( bw_Application* app, int* exit_code );
 
 void bw_ApplicationEngineImpl_finish( bw_ApplicationEngineImpl* );
 bw_Err bw_ApplicationEngineImpl_initialize( bw_ApplicationEngineImpl* impl, bw_Application* app, int argc, char** argv, const bw_ApplicationSettings* settings );
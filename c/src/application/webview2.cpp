@@ -0,0 +1,51 @@
+#include "../application.h"
+#include "../common.h"
+#include "../webview2/environment.hpp"
+#include "../webview2/util.hpp"
+#include "../win32.h"
+
+#include "impl.h"
+
+#include <objbase.h>
+#include <WebView2.h>
+
+#pragma comment(lib, "Ole32.lib")
+
+
+
+bw_Err bw_ApplicationEngineImpl_initialize( bw_ApplicationEngineImpl* impl, bw_Application* app, int argc, char** argv, const bw_ApplicationSettings* settings ) {
+	UNUSED( app );
+	UNUSED( argc );
+	UNUSED( argv );
+
+	impl->environment_options = (void*)bw_webview2_environmentOptions( settings );
+
+	// WebView2 needs to be used from a single-threaded apartment, which is our GUI thread.
+	HRESULT result = CoInitializeEx( NULL, COINIT_APARTMENTTHREADED );
+	if ( FAILED( result ) ) {
+		impl->com_initialized = FALSE;
+		return bw_win32_unhandledHresult( result );
+	}
+
+	impl->com_initialized = TRUE;
+	BW_ERR_RETURN_SUCCESS;
+}
+
+BOOL bw_Application_getEngineVersion( bw_StrSlice* version ) {
+	LPWSTR version_string = 0;
+
+	// Fails if the WebView2 runtime is not installed
+	if ( FAILED( GetAvailableCoreWebView2BrowserVersionString( nullptr, &version_string ) ) || version_string == 0 )
+		return FALSE;
+
+	*version = bw_webview2_copyToStrSlice( version_string );
+	CoTaskMemFree( version_string );
+	return TRUE;
+}
+
+void bw_ApplicationEngineImpl_finish( bw_ApplicationEngineImpl* app ) {
+	delete (bw_WebView2EnvironmentOptions*)app->environment_options;
+
+	if ( app->com_initialized )
+		CoUninitialize();
+}
@@ -0,0 +1,23 @@
+#ifndef BW_APPLICATION_WEBVIEW2_H
+#define BW_APPLICATION_WEBVIEW2_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#include "../bool.h"
+
+
+
+typedef struct {
+	BOOL com_initialized;
+	void* environment_options;	// bw_WebView2EnvironmentOptions*
+} bw_ApplicationEngineImpl;
+
+
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif//BW_APPLICATION_WEBVIEW2_H
@@ -13,6 +13,10 @@
 #include <WinDef.h>
 #include <Windows.h>
 
+#include "../hotkey/win32.h"
+#include "../theme/win32.h"
+#include "../notification/win32.h"
+#include "../tray_icon/win32.h"
 #include "../win32.h"
 #include "../window/win32.h"
 
@@ -29,13 +33,15 @@ typedef struct {
 
 
 
+LRESULT CALLBACK bw_ApplicationWin32_dispatcherProc( HWND hwnd, UINT msg, WPARAM wp, LPARAM lp );
 void bw_ApplicationWin32_dispatchWrapper(bw_Application* app, void* _data);
+void bw_ApplicationWin32_enableDpiAwareness();
 void bw_ApplicationWin32_setTimer(bw_Application* app, bw_ApplicationDispatchData* dispatch_data, uint64_t delay);
 void bw_ApplicationWin32_timerHandler(HWND _hwnd, UINT _, UINT_PTR nIDEvent, DWORD _2);
 
 
 
-PSLIST_HEADER timer_map = NULL;
+bw_ApplicationTimerMapEntry* timer_map = NULL;
 
 
 void bw_Application_assertCorrectThread( const bw_Application* app ) {
@@ -56,7 +62,11 @@ BOOL bw_ApplicationImpl_dispatch( bw_Application* app, bw_ApplicationDispatchDat
 	if ( result == false )
 		return false;
 
-	PostThreadMessageW( app->impl.thread_id, WM_APP, (WPARAM)NULL, (LPARAM)dispatch_data );
+	// Other event loops ignore messages that are not meant for a window
+	if ( app->impl.dispatch_window != NULL )
+		PostMessageW( app->impl.dispatch_window, WM_APP, (WPARAM)NULL, (LPARAM)dispatch_data );
+	else
+		PostThreadMessageW( app->impl.thread_id, WM_APP, (WPARAM)NULL, (LPARAM)dispatch_data );
 
 	return true;
 }
@@ -72,9 +82,10 @@ BOOL bw_ApplicationImpl_dispatchDelayed(bw_Application* app, bw_ApplicationDispa
 	if ( result == false )
 		return false;
 
-	// If we are on the right thread, just call `SendMessageTimeout`.
-	if (app->impl.thread_id == GetCurrentThreadId()) {;
+	// If we are on the right thread, just set the timer.
+	if (app->impl.thread_id == GetCurrentThreadId()) {
 		bw_ApplicationWin32_setTimer(app, dispatch_data, milliseconds);
+		return true;
 	}
 	// Otherwise, post to the GUI thread first
 	else {
@@ -87,8 +98,60 @@ BOOL bw_ApplicationImpl_dispatchDelayed(bw_Application* app, bw_ApplicationDispa
 		wrapper_data->func = bw_ApplicationWin32_dispatchWrapper;
 		wrapper_data->data = (void*)delayed_data;
 
-		bw_ApplicationImpl_dispatch(app, wrapper_data);
-	};
+		return bw_ApplicationImpl_dispatch(app, wrapper_data);
+	}
+}
+
+void bw_ApplicationImpl_beginPumping( bw_Application* app ) {
+	app->impl.dispatch_window = CreateWindowExW( 0, L"bw-dispatcher", L"", 0, 0, 0, 0, 0, HWND_MESSAGE, NULL, app->impl.handle, NULL );
+	if ( app->impl.dispatch_window == NULL )
+		BW_WIN32_PANIC_LAST_ERROR;
+	SetWindowLongPtrW( app->impl.dispatch_window, GWLP_USERDATA, (LONG_PTR)app );
+}
+
+BOOL bw_ApplicationImpl_pumpEvents( bw_Application* app, int* exit_code ) {
+	MSG msg;
+
+	while ( !app->impl.has_exited && PeekMessageW( &msg, 0, 0, 0, PM_REMOVE ) ) {
+
+		// The other event loop needs to see the quit message as well
+		if ( msg.message == WM_QUIT ) {
+			app->impl.exit_code = (int)msg.wParam;
+			app->impl.has_exited = TRUE;
+			PostQuitMessage( (int)msg.wParam );
+		}
+		else if ( !bw_WindowWin32_translateAccelerator( &msg ) ) {
+			TranslateMessage( &msg );
+			DispatchMessageW( &msg );
+		}
+	}
+
+	if ( app->impl.has_exited ) {
+		*exit_code = app->impl.exit_code;
+		return FALSE;
+	}
+	return TRUE;
+}
+
+LRESULT CALLBACK bw_ApplicationWin32_dispatcherProc( HWND hwnd, UINT msg, WPARAM wp, LPARAM lp ) {
+	bw_Application* app = (bw_Application*)GetWindowLongPtrW( hwnd, GWLP_USERDATA );
+	if ( app == NULL )
+		return DefWindowProcW( hwnd, msg, wp, lp );
+
+	switch ( msg ) {
+	case WM_APP: {
+		bw_ApplicationDispatchData* params = (bw_ApplicationDispatchData*)lp;
+		(params->func)( app, params->data );
+		free( params );
+	}	return 0;
+	// Exits the application
+	case WM_APP + 1:
+		app->impl.exit_code = (int)wp;
+		app->impl.has_exited = TRUE;
+		return 0;
+	}
+
+	return DefWindowProcW( hwnd, msg, wp, lp );
 }
 
 void bw_ApplicationWin32_addToTimerMap(bw_Application* app, UINT_PTR timer_id, bw_ApplicationDispatchData* dispatch_data) {
@@ -131,6 +194,7 @@ bw_ApplicationTimerMapEntry* bw_ApplicationWin32_findInTimerMap(UINT_PTR timer_i
 	while (entry->timer_id != timer_id) {
 		if (entry->next == 0)
 			return 0;
+		entry = entry->next;
 	}
 
 	return entry;
@@ -147,6 +211,8 @@ void bw_ApplicationWin32_freeTimerMap() {
 
 		entry = next;
 	}
+
+	timer_map = NULL;
 }
 
 bool bw_ApplicationWin32_removeFromTimerMap(bw_ApplicationTimerMapEntry* entry) {
@@ -161,11 +227,12 @@ bool bw_ApplicationWin32_removeFromTimerMap(bw_ApplicationTimerMapEntry* entry)
 	bw_ApplicationTimerMapEntry* i = prev->next;
 	while (i != 0) {
 		if (i == entry) {
-			prev = i->next;
+			prev->next = i->next;
 			free(i);
 			return true;
 		}
 
+		prev = i;
 		i = i->next;
 	}
 
@@ -227,7 +294,8 @@ int bw_ApplicationImpl_run( bw_Application* app, bw_ApplicationImpl_ReadyHandler
 		if (res == -1) {
 			BW_WIN32_PANIC_LAST_ERROR;
 		}
-		else {
+		// Accelerator keys of the menu bar are taken care of before the key gets dispatched
+		else if ( !bw_WindowWin32_translateAccelerator( &msg ) ) {
 			TranslateMessage( &msg );
 			DispatchMessageW( &msg );
 
@@ -245,6 +313,22 @@ int bw_ApplicationImpl_run( bw_Application* app, bw_ApplicationImpl_ReadyHandler
 }
 
 
+// Makes every window follow the scale factor of the display it is on.
+// If the application has declared its DPI awareness in its manifest already, that is left as it is.
+void bw_ApplicationWin32_enableDpiAwareness() {
+	typedef BOOL (WINAPI *SetProcessDpiAwarenessContextFn)( HANDLE );
+	// DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2, which is not defined by older SDKs
+	const HANDLE PER_MONITOR_AWARE_V2 = (HANDLE)-4;
+
+	SetProcessDpiAwarenessContextFn set_awareness_context = (SetProcessDpiAwarenessContextFn)GetProcAddress( GetModuleHandleW( L"user32.dll" ), "SetProcessDpiAwarenessContext" );
+
+	// Versions before Windows 10 can only scale everything by the scale factor of the primary display
+	if ( set_awareness_context == NULL )
+		SetProcessDPIAware();
+	else
+		set_awareness_context( PER_MONITOR_AWARE_V2 );
+}
+
 bw_ApplicationImpl bw_ApplicationImpl_initialize( bw_Application* _app, int argc, char** argv, const bw_ApplicationSettings* settings ) {
 	UNUSED(_app);
 	UNUSED(argc);
@@ -255,6 +339,12 @@ bw_ApplicationImpl bw_ApplicationImpl_initialize( bw_Application* _app, int argc
 	InitializeSRWLock( &app.is_running_mtx );
 	app.thread_id = GetCurrentThreadId();
 	app.handle = GetModuleHandle(NULL);
+	app.dispatch_window = NULL;
+	app.has_exited = FALSE;
+	app.exit_code = 0;
+
+	// Otherwise Windows stretches the windows on scaled displays, which makes them look blurry
+	bw_ApplicationWin32_enableDpiAwareness();
 
 	// Register window class
 	memset( &app.wc, 0, sizeof(WNDCLASSEXW) );
@@ -264,6 +354,61 @@ bw_ApplicationImpl bw_ApplicationImpl_initialize( bw_Application* _app, int argc
 	app.wc.lpszClassName = L"bw-window";
 	RegisterClassExW( &app.wc );
 
+	// The class of the windows that cover the custom maximize buttons of frameless windows
+	WNDCLASSEXW button_wc;
+	memset( &button_wc, 0, sizeof(WNDCLASSEXW) );
+	button_wc.cbSize = sizeof( WNDCLASSEXW );
+	button_wc.hInstance = app.handle;
+	button_wc.lpfnWndProc = bw_WindowWin32_maximizeButtonProc;
+	button_wc.hCursor = LoadCursor( NULL, IDC_ARROW );
+	button_wc.lpszClassName = L"bw-maximize-button";
+	RegisterClassExW( &button_wc );
+
+	// The class of the hidden windows that receive the messages of the tray icons
+	WNDCLASSEXW tray_wc;
+	memset( &tray_wc, 0, sizeof(WNDCLASSEXW) );
+	tray_wc.cbSize = sizeof( WNDCLASSEXW );
+	tray_wc.hInstance = app.handle;
+	tray_wc.lpfnWndProc = bw_TrayIconWin32_proc;
+	tray_wc.lpszClassName = L"bw-tray-icon";
+	RegisterClassExW( &tray_wc );
+
+	// The class of the hidden windows that own the notifications
+	WNDCLASSEXW notification_wc;
+	memset( &notification_wc, 0, sizeof(WNDCLASSEXW) );
+	notification_wc.cbSize = sizeof( WNDCLASSEXW );
+	notification_wc.hInstance = app.handle;
+	notification_wc.lpfnWndProc = bw_NotificationWin32_proc;
+	notification_wc.lpszClassName = L"bw-notification";
+	RegisterClassExW( &notification_wc );
+
+	// The class of the hidden window that receives the global hotkeys
+	WNDCLASSEXW hotkey_wc;
+	memset( &hotkey_wc, 0, sizeof(WNDCLASSEXW) );
+	hotkey_wc.cbSize = sizeof( WNDCLASSEXW );
+	hotkey_wc.hInstance = app.handle;
+	hotkey_wc.lpfnWndProc = bw_HotkeyWin32_proc;
+	hotkey_wc.lpszClassName = L"bw-hotkey";
+	RegisterClassExW( &hotkey_wc );
+
+	// The class of the hidden window that is told when the theme of the system changes
+	WNDCLASSEXW theme_wc;
+	memset( &theme_wc, 0, sizeof(WNDCLASSEXW) );
+	theme_wc.cbSize = sizeof( WNDCLASSEXW );
+	theme_wc.hInstance = app.handle;
+	theme_wc.lpfnWndProc = bw_ThemeWin32_proc;
+	theme_wc.lpszClassName = L"bw-theme";
+	RegisterClassExW( &theme_wc );
+
+	// The class of the window that receives the dispatched functions, when another event loop pumps the events
+	WNDCLASSEXW dispatcher_wc;
+	memset( &dispatcher_wc, 0, sizeof(WNDCLASSEXW) );
+	dispatcher_wc.cbSize = sizeof( WNDCLASSEXW );
+	dispatcher_wc.hInstance = app.handle;
+	dispatcher_wc.lpfnWndProc = bw_ApplicationWin32_dispatcherProc;
+	dispatcher_wc.lpszClassName = L"bw-dispatcher";
+	RegisterClassExW( &dispatcher_wc );
+
 	return app;
 }
 
@@ -273,7 +418,11 @@ void bw_Application_exit( bw_Application* app, int exit_code ) {
 	// This should be true for 32 and 64 bit systems in general.
 	_STATIC_ASSERT( sizeof(int) <= sizeof(WPARAM) );
 
-	PostThreadMessageW( app->impl.thread_id, WM_QUIT, (WPARAM)exit_code, (LPARAM)NULL );
+	// Quitting would also end the other event loop
+	if ( app->impl.dispatch_window != NULL )
+		PostMessageW( app->impl.dispatch_window, WM_APP + 1, (WPARAM)exit_code, (LPARAM)NULL );
+	else
+		PostThreadMessageW( app->impl.thread_id, WM_QUIT, (WPARAM)exit_code, (LPARAM)NULL );
 }
 
 void bw_Application_exitAsync( bw_Application* app, int code ) {
@@ -283,5 +432,7 @@ void bw_Application_exitAsync( bw_Application* app, int code ) {
 
 void bw_ApplicationImpl_finish( bw_ApplicationImpl* app ) {
 	bw_ApplicationWin32_freeTimerMap();
+	if ( app->dispatch_window != NULL )
+		DestroyWindow( app->dispatch_window );
 	UnregisterClassW( L"bw-window", app->handle );
 }
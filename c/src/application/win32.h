@@ -3,8 +3,8 @@
 
 #if defined(BW_CEF)
 #include "cef.h"
-#elif defined(BW_EDGE)
-#include "edge.h"
+#elif defined(BW_WEBVIEW2)
+#include "webview2.h"
 #else
 #error Unsupported browser engine selected
 #endif
@@ -24,6 +24,9 @@ typedef struct {
 	HINSTANCE handle;
 	WNDCLASSEXW wc;
 	SRWLOCK is_running_mtx;
+	HWND dispatch_window;	// Receives the dispatched functions when another event loop pumps the events, NULL otherwise
+	BOOL has_exited;	// Only used when pumping
+	int exit_code;	// Only used when pumping
 } bw_ApplicationImpl;
 
 typedef struct {
@@ -5,8 +5,10 @@
 extern "C" {
 #endif
 
-#ifdef BW_CEF
+#if defined(BW_CEF)
 #include "browser_window/cef.h"
+#elif defined(BW_WEBVIEW2)
+#include "browser_window/webview2.h"
 #else
 #error Unsupported engine
 #endif
@@ -21,20 +23,137 @@ extern "C" {
 
 
 typedef struct bw_BrowserWindow bw_BrowserWindow;
+typedef struct bw_BrowserWindowRect bw_BrowserWindowRect;
+/// A request of the page to choose files, like for `<input type="file">`, that is answered by the application itself.
+typedef struct bw_FileChooser bw_FileChooser;
+/// A request of the page that is held back until the application has decided what happens to it.
+typedef struct bw_InterceptedRequest bw_InterceptedRequest;
+/// A request of one of the custom schemes of the application, that is answered by the application itself.
+typedef struct bw_SchemeRequest bw_SchemeRequest;
 
 
 
+// The flags of the modifier keys and mouse buttons that are held down, for the input that is sent to offscreen browsers.
+#define BW_MODIFIER_CTRL 0x01
+#define BW_MODIFIER_SHIFT 0x02
+#define BW_MODIFIER_ALT 0x04
+#define BW_MODIFIER_SUPER 0x08
+#define BW_MODIFIER_LEFT_BUTTON 0x10
+#define BW_MODIFIER_MIDDLE_BUTTON 0x20
+#define BW_MODIFIER_RIGHT_BUTTON 0x40
+
+// The number of permissions that `bw_BrowserWindowPermissionFn` is asked about.
+#define BW_PERMISSION_COUNT 5
+
+
+
+/// `shared_handle` is the shared handle of a D3D11 texture in the BGRA format, which can be opened with `ID3D11Device::OpenSharedResource`.
+/// The texture is reused for later frames, so it should be copied rather than kept.
+typedef void (*bw_BrowserWindowAcceleratedPaintFn)( bw_BrowserWindow* window, BOOL is_popup, const bw_BrowserWindowRect* dirty_rects, size_t dirty_rect_count, void* shared_handle );
+typedef void (*bw_BrowserWindowAcceleratorFn)( bw_BrowserWindow* window, uint32_t accelerator_id );
+/// `cursor` is one of: 0 default, 1 pointer, 2 text, 3 wait, 4 progress, 5 crosshair, 6 move, 7 not allowed, 8 help,
+///     9 resize east-west, 10 resize north-south, 11 resize northeast-southwest, 12 resize northwest-southeast, 13 grab, 14 grabbing, 15 hidden.
+typedef void (*bw_BrowserWindowCursorFn)( bw_BrowserWindow* window, uint8_t cursor );
 typedef void (*bw_BrowserWindowCreationCallbackFn)( bw_BrowserWindow* window, void* data );
+typedef void (*bw_BrowserWindowDestroyFn)( bw_BrowserWindow* window );
+/// `method` is the name of the event of the Chrome DevTools Protocol, like `Network.requestWillBeSent`, and `params` is the JSON object with its parameters.
+/// Both are only valid during the call.
+typedef void (*bw_BrowserWindowDevToolsEventFn)( bw_BrowserWindow* window, bw_CStrSlice method, bw_CStrSlice params );
+/// `result` is the JSON object with the result of the method, or with the error if `success` isn't set, and is only valid during the call.
+typedef void (*bw_BrowserWindowDevToolsResultFn)( bw_BrowserWindow* window, void* data, BOOL success, bw_CStrSlice result );
+/// If `width` and `height` are 0, `data` is a PNG image of `size` bytes, otherwise it contains `width` * `height` pixels in the RGBA format.
+/// The data is only valid during the call.
+typedef void (*bw_BrowserWindowFaviconFn)( bw_BrowserWindow* window, const void* data, size_t size, unsigned int width, unsigned int height );
+/// `mode` is 0 to open a file, 1 to open multiple files, 2 to open a folder, and 3 to save a file.
+/// `accept` contains a line for every type of file that the page accepts, like `.png` or `image/*`, and is empty if it accepts any file.
+/// The strings are only valid during the call, but `chooser` stays valid until it has been answered with `bw_FileChooser_select`.
+typedef void (*bw_BrowserWindowFileChooserFn)( bw_BrowserWindow* window, bw_FileChooser* chooser, uint8_t mode, bw_CStrSlice title, bw_CStrSlice default_path, bw_CStrSlice accept );
+typedef void (*bw_BrowserWindowFocusFn)( bw_BrowserWindow* window, BOOL focused );
 typedef void (*bw_BrowserWindowHandlerFn)( bw_BrowserWindow* window, bw_CStrSlice cmd, bw_CStrSlice* args, size_t arg_count );
 typedef void (*bw_BrowserWindowJsCallbackFn)( bw_BrowserWindow* window, void* user_data, const char* result, const bw_Err* err );
+/// Invoked on the GUI thread for every key that is pressed or released while the browser window has focus, before the page gets it.
+/// `kind` is 0 for a key that is pressed and 1 for a key that is released, `key_code` is the virtual key code as it is used on Windows, and `modifiers` holds the `BW_MODIFIER_*` flags of the keys.
+/// `is_repeat` is set when the key is pressed again because it is being held down.
+/// Returns whether the key has been handled, in which case neither the page nor the browser engine get it.
+typedef BOOL (*bw_BrowserWindowKeyFn)( bw_BrowserWindow* window, uint8_t kind, uint16_t key_code, uint32_t modifiers, BOOL is_repeat );
+/// Invoked when the main frame has finished loading its document.
+/// `error` is NULL if it has loaded, or describes why it couldn't be loaded, and is only valid during the call.
+typedef void (*bw_BrowserWindowLoadFn)( bw_BrowserWindow* window, const bw_Err* error );
+/// `progress` is how far the main frame has come with loading, from 0.0 to 1.0 once it is done.
+typedef void (*bw_BrowserWindowLoadingProgressFn)( bw_BrowserWindow* window, double progress );
+/// `x` and `y` are the new position of the window.
+typedef void (*bw_BrowserWindowMoveFn)( bw_BrowserWindow* window, int x, int y );
+/// `url` is the new address of the main frame, and is only valid during the call.
+typedef void (*bw_BrowserWindowNavigationFn)( bw_BrowserWindow* window, bw_CStrSlice url );
+/// `pixels` contains `width` * `height` pixels in the BGRA format, and is only valid during the call.
+/// `is_popup` is set for the frames of popup widgets, like the list of a `<select>` element.
+typedef void (*bw_BrowserWindowPaintFn)( bw_BrowserWindow* window, BOOL is_popup, const bw_BrowserWindowRect* dirty_rects, size_t dirty_rect_count, const void* pixels, int width, int height );
+/// `permission` is 0 for the camera and microphone, 1 for the clipboard, 2 for opening popups, 3 for downloads, and 4 for capturing the screen.
+/// `url` is the address of the page or the origin that asks for it, and is only valid during the call.
+/// Returns 1 to allow it, 0 to deny it, or -1 to leave it to the browser engine, which may ask the user.
+typedef int (*bw_BrowserWindowPermissionFn)( bw_BrowserWindow* window, uint8_t permission, bw_CStrSlice url );
+/// `reason` is 0 if the render process has exited abnormally, 1 if it has been killed, 2 if it has crashed, and 3 if it has run out of memory.
+typedef void (*bw_BrowserWindowProcessTerminatedFn)( bw_BrowserWindow* window, uint8_t reason );
+/// Invoked on the GUI thread for every request of the page that matches the filters of `bw_BrowserWindow_setRequestHandler`, before it is sent.
+/// `headers` contains a `Name: value` line for every header.
+/// The strings are only valid during the call, but `request` stays valid until it has been decided on with one of the `bw_InterceptedRequest_*` functions.
+typedef void (*bw_BrowserWindowRequestFn)( bw_BrowserWindow* window, bw_InterceptedRequest* request, bw_CStrSlice method, bw_CStrSlice url, bw_CStrSlice headers );
+/// `rect` is the area of the popup widget within the view, or NULL when it has been hidden.
+typedef void (*bw_BrowserWindowPopupFn)( bw_BrowserWindow* window, const bw_BrowserWindowRect* rect );
+/// `width` and `height` are the new size of the content area of the window.
+typedef void (*bw_BrowserWindowResizeFn)( bw_BrowserWindow* window, unsigned int width, unsigned int height );
+/// `scale_factor` is the new scale factor of the display that the window is on.
+typedef void (*bw_BrowserWindowScaleFactorFn)( bw_BrowserWindow* window, float scale_factor );
+/// Invoked on the GUI thread for every request of one of the custom schemes of the application.
+/// `headers` contains a `Name: value` line for every header, and `body` is empty for requests without a body.
+/// The strings are only valid during the call, but `request` stays valid until it has been finished with `bw_SchemeRequest_finish`.
+typedef void (*bw_BrowserWindowSchemeFn)( bw_BrowserWindow* window, bw_SchemeRequest* request, bw_CStrSlice method, bw_CStrSlice url, bw_CStrSlice headers, bw_CStrSlice body );
+/// `text` is what browsers show in their status bar, like the address of the link under the mouse cursor, and is only valid during the call.
+/// It is empty once there is nothing to show anymore.
+typedef void (*bw_BrowserWindowStatusTextFn)( bw_BrowserWindow* window, bw_CStrSlice text );
+/// `title` is the new title of the page, and is only valid during the call.
+typedef void (*bw_BrowserWindowTitleFn)( bw_BrowserWindow* window, bw_CStrSlice title );
+/// `unresponsive` is set when the page has stopped responding to input, and unset once it responds again.
+typedef void (*bw_BrowserWindowUnresponsiveFn)( bw_BrowserWindow* window, BOOL unresponsive );
+
 
 
+/// A key combination that has been registered with `bw_BrowserWindow_registerAccelerator`.
+typedef struct bw_BrowserWindowAccelerator {
+	uint32_t id;
+	uint16_t key_code;	/// The virtual key code, as it is used on Windows.
+	BOOL ctrl;
+	BOOL shift;
+	BOOL alt;
+	BOOL super;
+} bw_BrowserWindowAccelerator;
 
 typedef struct bw_BrowserWindowOptions {
 	BOOL dev_tools;
 	bw_CStrSlice resource_path;
+	bw_CStrSlice preload_script;	/// JavaScript that gets executed whenever a new document is loaded, before the page's own scripts.
+	bw_CStrSlice default_encoding;	/// The encoding for pages that don't declare one. Empty for the engine's default.
+	BOOL shared_textures;	/// Lets an offscreen browser paint into GPU textures that are shared with the application, instead of into pixel buffers.
+	BOOL isolate_scripts;	/// Makes the preload script the only way for pages to reach the application, so that other frames don't get `invoke_extern` either.
+	int8_t permission_defaults[BW_PERMISSION_COUNT];	/// The decisions for the permissions that the permission handler leaves open: 1 to allow, 0 to deny, or -1 to leave them to the browser engine.
 } bw_BrowserWindowOptions;
 
+/// Information about a (sub)frame of a browser window.
+typedef struct bw_BrowserWindowFrame {
+	int64_t id;
+	int64_t parent_id;	/// -1 if the frame has no parent.
+	bw_StrSlice name;
+	bw_StrSlice url;
+	BOOL is_main;
+} bw_BrowserWindowFrame;
+
+struct bw_BrowserWindowRect {
+	int x;
+	int y;
+	int width;
+	int height;
+};
+
 typedef struct bw_BrowserWindowSource {
 	bw_CStrSlice data;
 	BOOL is_html;
@@ -46,11 +165,49 @@ struct bw_BrowserWindow {
 	bw_Window* window;
 	bw_BrowserWindowHandlerFn external_handler;
 	void* user_data;
+	bw_BrowserWindowDestroyFn on_destroy;	// Invoked right before the browser window is freed from memory.
+	bw_BrowserWindowAcceleratorFn on_accelerator;
+	bw_BrowserWindowAccelerator* accelerators;
+	size_t accelerator_count;
+	bw_BrowserWindowPaintFn on_paint;	// Only used by offscreen browsers, like the ones below.
+	bw_BrowserWindowPopupFn on_popup;
+	bw_BrowserWindowCursorFn on_cursor_change;
+	bw_BrowserWindowAcceleratedPaintFn on_accelerated_paint;
+	bw_BrowserWindowResizeFn on_resize;	// Invoked after the browser has been resized along with its window.
+	bw_BrowserWindowScaleFactorFn on_scale_factor_changed;
+	bw_BrowserWindowMoveFn on_move;
+	bw_BrowserWindowFocusFn on_focus;
+	bw_BrowserWindowNavigationFn on_navigate;	// Only invoked for the main frame.
+	bw_BrowserWindowLoadFn on_load;	// Only invoked for the main frame.
+	bw_BrowserWindowLoadingProgressFn on_loading_progress;
+	bw_BrowserWindowTitleFn on_title_change;
+	bw_BrowserWindowFaviconFn on_favicon_changed;
+	bw_BrowserWindowStatusTextFn on_status_text;
+	bw_BrowserWindowProcessTerminatedFn on_render_process_terminated;
+	bw_BrowserWindowUnresponsiveFn on_unresponsive;
+	bw_BrowserWindowFileChooserFn on_file_chooser;	// Only set when the application answers the file choosers itself.
+	bw_BrowserWindowPermissionFn on_permission_request;	// May be invoked from another thread than the GUI thread, as the browser engine needs the answer right away.
+	bw_BrowserWindowSchemeFn on_scheme_request;
+	bw_BrowserWindowRequestFn on_request;	// Only invoked for the requests that match `request_filters`.
+	char* request_filters;	// The patterns of the URLs of the requests that are intercepted, one per line, where `*` matches anything.
+	bw_BrowserWindowDevToolsEventFn on_devtools_event;	// Only invoked for the events that have been subscribed to.
+	bw_BrowserWindowKeyFn on_key;	// Invoked before the accelerators are looked at, as the key is not an accelerator press once it has been handled.
+	int8_t permission_defaults[BW_PERMISSION_COUNT];	// Never changes after creation, so it can be read from any thread.
 	bw_BrowserWindowImpl impl;
 };
 
 
 
+/// Calls a method of the Chrome DevTools Protocol, like `Page.captureScreenshot`, with the JSON object of its parameters.
+/// An empty `params` is the same as `{}`.
+/// The callback is invoked on the GUI thread once the result is there.
+/// If the browser window is destroyed before that, it is invoked with an error right before the window is gone.
+void bw_BrowserWindow_callDevToolsMethod( bw_BrowserWindow* bw, bw_CStrSlice method, bw_CStrSlice params, bw_BrowserWindowDevToolsResultFn callback, void* data );
+
+/// Removes all cookies and stored data (local storage, IndexedDB, cache, etc.) of the given origin.
+/// This happens asynchronously, returns false if it couldn't be started.
+BOOL bw_BrowserWindow_clearOriginData( bw_BrowserWindow* bw, bw_CStrSlice origin );
+
 void bw_BrowserWindow_destroy( bw_BrowserWindow* bw );
 
 /// Marks the browser window handle as not being used anymore.
@@ -61,13 +218,189 @@ void bw_BrowserWindow_drop( bw_BrowserWindow* bw );
 /// Executes the given JavaScript and calls the given callback (on the GUI thread) to provide the result.
 void bw_BrowserWindow_evalJs( bw_BrowserWindow* bw, bw_CStrSlice js, bw_BrowserWindowJsCallbackFn callback, void* cb_data );
 void bw_BrowserWindow_evalJsThreaded( bw_BrowserWindow* bw, bw_CStrSlice js, bw_BrowserWindowJsCallbackFn callback, void* cb_data );
+/// Like `bw_BrowserWindow_evalJs`, but executes the JavaScript within the frame with the given identifier.
+/// If there is no such frame, the callback is invoked with an error.
+void bw_BrowserWindow_evalJsInFrame( bw_BrowserWindow* bw, int64_t frame_id, bw_CStrSlice js, bw_BrowserWindowJsCallbackFn callback, void* cb_data );
+
+/// Executes an editing command in the frame that has the focus, or in the main frame if none has.
+/// `command` is 0 to undo, 1 to redo, 2 to cut, 3 to copy, 4 to paste, 5 to delete the selection, and 6 to select all.
+void bw_BrowserWindow_execCommand( bw_BrowserWindow* bw, uint8_t command );
+
+/// Causes an offscreen browser to paint its whole view again.
+void bw_BrowserWindow_invalidate( bw_BrowserWindow* bw );
+
+/// Frees the frames obtained from `bw_BrowserWindow_getFrames`.
+void bw_BrowserWindow_freeFrames( bw_BrowserWindowFrame* frames, size_t count );
 
 bw_Application* bw_BrowserWindow_getApp( bw_BrowserWindow* bw );
+/// Allocates an array with information about all frames that are currently in the browser window, and stores it in `frames`.
+/// Returns the number of frames in the array.
+/// The array needs to be freed with `bw_BrowserWindow_freeFrames`.
+size_t bw_BrowserWindow_getFrames( bw_BrowserWindow* bw, bw_BrowserWindowFrame** frames );
 void* bw_BrowserWindow_getUserData( bw_BrowserWindow* bw );
 BOOL bw_BrowserWindow_getUrl(bw_BrowserWindow* bw, bw_StrSlice* url);
 bw_Window* bw_BrowserWindow_getWindow( bw_BrowserWindow* bw );
+/// Gets the zoom factor of the page, where 1.0 is 100%.
+double bw_BrowserWindow_getZoom( bw_BrowserWindow* bw );
 
 bw_Err bw_BrowserWindow_navigate( bw_BrowserWindow* bw, bw_CStrSlice url );
+/// Causes the frame with the given identifier to navigate to `url`.
+/// Returns false if there is no such frame.
+BOOL bw_BrowserWindow_navigateFrame( bw_BrowserWindow* bw, int64_t frame_id, bw_CStrSlice url );
+
+/// Loads the page of the main frame again, which also starts a new render process if the old one has gone.
+/// If `ignore_cache` is set, nothing is taken from the cache.
+void bw_BrowserWindow_reload( bw_BrowserWindow* bw, BOOL ignore_cache );
+
+/// The following functions forward the input for offscreen browsers, which don't receive any input from their window.
+/// Coordinates are relative to the top-left corner of the view, and `modifiers` holds the `BW_MODIFIER_*` flags.
+/// They don't do anything for browsers that are not offscreen.
+void bw_BrowserWindow_sendFocus( bw_BrowserWindow* bw, BOOL focused );
+/// `kind` is 0 for a key press, 1 for a key release, and 2 for a typed character.
+/// `key_code` is the virtual key code, as it is used on Windows, and `character` is the Unicode code point of the typed character.
+void bw_BrowserWindow_sendKey( bw_BrowserWindow* bw, uint8_t kind, int key_code, uint32_t character, uint32_t modifiers );
+/// `button` is 0 for the left, 1 for the middle and 2 for the right mouse button.
+void bw_BrowserWindow_sendMouseButton( bw_BrowserWindow* bw, int x, int y, uint8_t button, BOOL released, int click_count, uint32_t modifiers );
+/// `leave` is set when the mouse has left the view.
+void bw_BrowserWindow_sendMouseMove( bw_BrowserWindow* bw, int x, int y, BOOL leave, uint32_t modifiers );
+void bw_BrowserWindow_sendMouseWheel( bw_BrowserWindow* bw, int x, int y, int delta_x, int delta_y, uint32_t modifiers );
+
+/// Overrides the character encoding of the pages, and reloads the current page.
+/// An empty `charset` removes the override.
+void bw_BrowserWindow_setCharsetOverride( bw_BrowserWindow* bw, bw_CStrSlice charset );
+
+/// Registers a key combination like `Ctrl+S`, that invokes the accelerator handler with `accelerator_id` whenever it is pressed while the browser window has focus.
+/// The key press is handled before the page sees it, so the page doesn't receive it at all.
+/// Registering the same key combination again replaces the previous registration.
+/// Returns FALSE if the key combination isn't valid.
+BOOL bw_BrowserWindow_registerAccelerator( bw_BrowserWindow* bw, uint32_t accelerator_id, bw_CStrSlice accelerator );
+
+/// Sets a function that will be invoked when an offscreen browser has painted a frame into a shared texture.
+/// This only happens for browsers that have been created with `shared_textures` set, instead of invoking `on_paint`.
+/// If the GPU can't be used, `on_paint` is still invoked instead.
+///
+/// *Note:* Shared textures are only supported by CEF on Windows, where they are D3D11 textures.
+/// On other platforms, the frames are always given to `on_paint`.
+void bw_BrowserWindow_setAcceleratedPaintHandler( bw_BrowserWindow* bw, bw_BrowserWindowAcceleratedPaintFn handler );
+
+/// Sets a function that will be invoked whenever one of the registered accelerators is pressed.
+void bw_BrowserWindow_setAcceleratorHandler( bw_BrowserWindow* bw, bw_BrowserWindowAcceleratorFn handler );
+
+/// Sets the functions that will be invoked when the window of the browser has moved or gained or lost the focus, when the main frame has navigated to another address, or when the title of the page has changed.
+void bw_BrowserWindow_setEventHandlers( bw_BrowserWindow* bw, bw_BrowserWindowMoveFn on_move, bw_BrowserWindowFocusFn on_focus, bw_BrowserWindowNavigationFn on_navigate, bw_BrowserWindowTitleFn on_title_change );
+
+/// Sets a function that will be invoked whenever the page has got another favicon, once it has been downloaded.
+/// CEF gives the pixels of the icon, while WebView2 gives it as a PNG image, and only does so from version 1.0.1722 on.
+void bw_BrowserWindow_setFaviconHandler( bw_BrowserWindow* bw, bw_BrowserWindowFaviconFn handler );
+
+/// Sets a function that will be invoked on the GUI thread whenever the page wants the user to choose files, instead of showing the dialog of the browser engine.
+/// Without one, the dialog of the browser engine is shown.
+/// WebView2 always shows its own dialog.
+void bw_BrowserWindow_setFileChooserHandler( bw_BrowserWindow* bw, bw_BrowserWindowFileChooserFn handler );
+
+/// Sets a function that will be invoked for every key that is pressed or released while the browser window has focus, to decide whether the page gets it.
+/// WebView2 only gives the keys that don't type a character, like the function keys, and the keys that are pressed together with Ctrl or Alt.
+/// The other keys go to the page right away.
+void bw_BrowserWindow_setKeyHandler( bw_BrowserWindow* bw, bw_BrowserWindowKeyFn handler );
+
+/// Sets a function that will be invoked whenever the main frame has finished loading, or has failed to load.
+/// Loads that are interrupted, like by navigating elsewhere before they are done, are not reported.
+void bw_BrowserWindow_setLoadHandler( bw_BrowserWindow* bw, bw_BrowserWindowLoadFn handler );
+
+/// Sets a function that will be invoked whenever the progress of loading the page changes.
+/// WebView2 doesn't report the actual progress, so there it only moves through a few steps between starting and finishing a load.
+void bw_BrowserWindow_setLoadingProgressHandler( bw_BrowserWindow* bw, bw_BrowserWindowLoadingProgressFn handler );
+
+/// Sets the functions that will be invoked when an offscreen browser has painted a frame, shows or hides a popup widget, or changes its cursor.
+/// On Windows, the frames are copied so that `on_paint` can be invoked on the GUI thread.
+void bw_BrowserWindow_setOffscreenHandlers( bw_BrowserWindow* bw, bw_BrowserWindowPaintFn on_paint, bw_BrowserWindowPopupFn on_popup, bw_BrowserWindowCursorFn on_cursor_change );
+
+/// Sets a function that will be invoked on the GUI thread for the events of the Chrome DevTools Protocol that have been subscribed to.
+void bw_BrowserWindow_setDevToolsEventHandler( bw_BrowserWindow* bw, bw_BrowserWindowDevToolsEventFn handler );
+
+/// Sets a function that will be invoked whenever the page asks for a permission, to decide whether it is granted.
+/// Unlike the other handlers, it may be invoked from another thread than the GUI thread.
+void bw_BrowserWindow_setPermissionHandler( bw_BrowserWindow* bw, bw_BrowserWindowPermissionFn handler );
+
+/// Sets the functions that will be invoked when the render process of the page has terminated, and when the page stops or starts responding again.
+/// The page stays blank after its render process has terminated, until it is reloaded or navigated elsewhere.
+/// WebView2 doesn't report that the page responds again.
+void bw_BrowserWindow_setRenderProcessHandlers( bw_BrowserWindow* bw, bw_BrowserWindowProcessTerminatedFn on_terminated, bw_BrowserWindowUnresponsiveFn on_unresponsive );
+
+/// Sets a function that will be invoked for every request of the page of which the URL matches one of the given patterns, before it is sent.
+/// `filters` contains a pattern on every line, in which `*` matches any text, like `https://api.example.com/*` or `*` for all requests.
+/// The requests of the custom schemes of the application are not intercepted, they go to the scheme handler.
+/// Requests that the handler hasn't decided on are held back, so it needs to decide on all of them eventually.
+/// The filters are read from the threads of the browser engine, so this should only be done once, right after the browser window has been created.
+void bw_BrowserWindow_setRequestHandler( bw_BrowserWindow* bw, bw_BrowserWindowRequestFn handler, bw_CStrSlice filters );
+
+/// Sets a function that will be invoked whenever the window of the browser has been resized.
+void bw_BrowserWindow_setResizeHandler( bw_BrowserWindow* bw, bw_BrowserWindowResizeFn handler );
+
+/// Sets a function that will be invoked for every request of one of the custom schemes of the application, which are given by `bw_ApplicationSettings`.
+/// Without one, these requests are answered with status 404.
+void bw_BrowserWindow_setSchemeHandler( bw_BrowserWindow* bw, bw_BrowserWindowSchemeFn handler );
+
+/// Sets a function that will be invoked whenever the scale factor of the window's display changes, or the window moves to a display with another scale factor.
+void bw_BrowserWindow_setScaleFactorHandler( bw_BrowserWindow* bw, bw_BrowserWindowScaleFactorFn handler );
+
+/// Sets a function that will be invoked whenever the text of the status bar changes, like when the mouse cursor moves onto or off a link.
+/// WebView2 only reports it from version 1.0.1185 on.
+void bw_BrowserWindow_setStatusTextHandler( bw_BrowserWindow* bw, bw_BrowserWindowStatusTextFn handler );
+
+/// Sets the zoom factor of the page, where 1.0 is 100%.
+void bw_BrowserWindow_setZoom( bw_BrowserWindow* bw, double factor );
+
+/// Sets a function that will be invoked when the browser window is being destroyed.
+/// This is the last moment that the browser window handle and its user data can be used.
+void bw_BrowserWindow_setDestroyHandler( bw_BrowserWindow* bw, bw_BrowserWindowDestroyFn handler );
+
+/// Lets the handler of `bw_BrowserWindow_setDevToolsEventHandler` receive the event of the Chrome DevTools Protocol with the given name, like `Network.requestWillBeSent`.
+/// Most events are only sent once their domain has been enabled, with a method like `Network.enable`.
+void bw_BrowserWindow_subscribeDevToolsEvent( bw_BrowserWindow* bw, bw_CStrSlice event );
+
+/// Answers the file chooser with the given paths, after which `chooser` can't be used anymore.
+/// If `path_count` is 0, the chooser is cancelled, as if the user had closed the dialog.
+/// This function is thread safe.
+void bw_FileChooser_select( bw_FileChooser* chooser, const bw_CStrSlice* paths, size_t path_count );
+
+/// The following functions decide on an intercepted request, after which `request` can't be used anymore.
+/// They need to be called on the GUI thread.
+///
+/// Fails the request, as if the network had refused it.
+/// WebView2 can't fail requests, so it answers them with status 403 instead.
+void bw_InterceptedRequest_block( bw_InterceptedRequest* request );
+/// Sends the request, with the given headers instead of its own.
+/// `headers` is in the same format as the headers that the request handler is given.
+void bw_InterceptedRequest_continue( bw_InterceptedRequest* request, bw_CStrSlice headers );
+/// Sends the request to the given URL instead.
+/// CEF lets the page know that it has been redirected, WebView2 gives the response as if it came from the original URL.
+void bw_InterceptedRequest_redirect( bw_InterceptedRequest* request, bw_CStrSlice url );
+/// Lets the application answer the request itself, in the same way as a request of a custom scheme.
+/// Returns the scheme request to give the response to, which needs to be finished with `bw_SchemeRequest_finish`.
+bw_SchemeRequest* bw_InterceptedRequest_respond( bw_InterceptedRequest* request );
+
+/// Ends the response with a failure, like when its body couldn't be read, after which `request` can't be used anymore.
+/// The page sees the request fail, instead of getting a body that has been cut short.
+/// If it hasn't been responded to yet, it is answered with status 500.
+/// This function is thread safe.
+void bw_SchemeRequest_abort( bw_SchemeRequest* request );
+
+/// Ends the body of the response, after which `request` can't be used anymore.
+/// If it hasn't been responded to yet, it is answered with status 500.
+/// This function is thread safe.
+void bw_SchemeRequest_finish( bw_SchemeRequest* request );
+
+/// Gives the status code and headers of the response, which needs to be done before its body is written.
+/// `headers` is in the same format as the headers of the request.
+/// This function is thread safe.
+void bw_SchemeRequest_respond( bw_SchemeRequest* request, uint16_t status, bw_CStrSlice headers );
+
+/// Appends `data` to the body of the response.
+/// Blocks while much of the body is still waiting to be read by the browser engine, so that large bodies don't need to be in memory all at once.
+/// Returns FALSE if the request has been cancelled, like when the page has navigated away, in which case the rest of the body isn't needed anymore.
+/// This function is thread safe.
+BOOL bw_SchemeRequest_write( bw_SchemeRequest* request, const void* data, size_t length );
 
 /// Creates a new browser window
 void bw_BrowserWindow_new(
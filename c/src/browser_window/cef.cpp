@@ -3,16 +3,21 @@
 #include "../application/cef.h"
 #include "../browser_window.h"
 #include "../cef/bw_handle_map.hpp"
+#include "../cef/devtools_observer.hpp"
 #include "../cef/exception.hpp"
 #include "../cef/util.hpp"
 #include "../common.h"
 #include "../debug.h"
 #include "impl.h"
 
+#include <cmath>
 #include <string>
+#include <vector>
 #include <include/base/cef_bind.h>
 #include <include/cef_browser.h>
 #include <include/cef_client.h>
+#include <include/cef_request_context.h>
+#include <include/cef_task.h>
 #include <include/cef_v8.h>
 #include <include/views/cef_browser_view.h>
 #include <include/views/cef_window.h>
@@ -43,37 +48,73 @@ void bw_BrowserWindowCef_connectToWin32Window( bw_BrowserWindow* bw, CefWindowIn
 
 
 
-// Sends the given Javascript code to the renderer process, expecting the code to be executed over there.
-// script_id should be a script id obtained from storing a callback in the eval callback store.
+// Sends the given Javascript code to the renderer process, expecting the code to be executed over there, within the given frame.
 void bw_BrowserWindowCef_sendJsToRendererProcess(
 	bw_BrowserWindow* bw,
-	CefRefPtr<CefBrowser>& cef_browser,
+	CefRefPtr<CefFrame> frame,
 	CefString& code,
 	bw_BrowserWindowJsCallbackFn cb,
 	void* user_data
 );
+// CEF can't override the encoding of a page, so the default encoding of the browser's request context is changed instead.
+// The original default is remembered, so that it can be restored when the override is removed.
+void bw_BrowserWindowCef_applyCharsetOverride( CefRefPtr<CefBrowser> cef_browser, std::string charset );
+// Gives the host of the browser if it is rendered offscreen, or nullptr otherwise.
+CefRefPtr<CefBrowserHost> bw_BrowserWindowCef_offscreenHost( bw_BrowserWindow* bw );
+// Converts the `BW_MODIFIER_*` flags into CEF's event flags.
+uint32_t bw_BrowserWindowCef_eventFlags( uint32_t modifiers );
+// Wraps the given JavaScript code so that its value is returned from within a function.
+CefString bw_BrowserWindowCef_wrapJs( bw_CStrSlice js );
 char* bw_cef_errorMessage( bw_ErrCode code, const void* data );
 /// Constructs the platform-specific window info needed by CEF.
 CefWindowInfo _bw_BrowserWindow_windowInfo( bw_Window* window, int width, int height );
+CefRefPtr<bw::DevToolsObserver> bw_BrowserWindowCef_devTools( bw_BrowserWindow* bw );
 
 
 
+void bw_BrowserWindow_callDevToolsMethod( bw_BrowserWindow* bw, bw_CStrSlice method, bw_CStrSlice params, bw_BrowserWindowDevToolsResultFn callback, void* data ) {
+	CefRefPtr<CefBrowser> cef_browser = *(CefRefPtr<CefBrowser>*)bw->impl.cef_ptr;
+
+	bw_BrowserWindowCef_devTools( bw )->call( cef_browser, std::string( method.data, method.len ), std::string( params.data, params.len ), callback, data );
+}
+
+BOOL bw_BrowserWindow_clearOriginData( bw_BrowserWindow* bw, bw_CStrSlice origin ) {
+	CefRefPtr<CefBrowser> cef_browser = *(CefRefPtr<CefBrowser>*)bw->impl.cef_ptr;
+
+	// The DevTools protocol is able to clear everything that an origin has stored, cookies included.
+	CefRefPtr<CefDictionaryValue> params = CefDictionaryValue::Create();
+	params->SetString( "origin", bw_cef_copyFromStrSlice( origin ) );
+	params->SetString( "storageTypes", "all" );
+
+	return cef_browser->GetHost()->ExecuteDevToolsMethod( 0, "Storage.clearDataForOrigin", params ) != 0;
+}
+
 void bw_BrowserWindow_evalJs( bw_BrowserWindow* bw, bw_CStrSlice js, bw_BrowserWindowJsCallbackFn cb, void* user_data ) {
 
-	// Wrap the JS code within a temporary function and execute it, and convert the return value to a string
-	// This allows executing JS code that isn't terminated with a semicolon, and does the javascript value string conversion inside JS.
-	std::string _code = "(function () { return ";
-	_code.append( js.data, js.len );
-	_code += "; })()";
-	CefString code = _code;
-	// Note: For the sake of simplicity, I've used std::string to append some strings together.
-	//       CefString unfortunately doesn't provide this functionality.
-	//       There is some overhead because of this, but for now it is ok.
+	CefString code = bw_BrowserWindowCef_wrapJs( js );
 
 	// Execute the javascript on the renderer process, and invoke the callback from there:
 	CefRefPtr<CefBrowser> cef_browser = *(CefRefPtr<CefBrowser>*)(bw->impl.cef_ptr);
 
-	bw_BrowserWindowCef_sendJsToRendererProcess( bw, cef_browser, code, cb, user_data );
+	bw_BrowserWindowCef_sendJsToRendererProcess( bw, cef_browser->GetMainFrame(), code, cb, user_data );
+}
+
+void bw_BrowserWindow_evalJsInFrame( bw_BrowserWindow* bw, int64_t frame_id, bw_CStrSlice js, bw_BrowserWindowJsCallbackFn cb, void* user_data ) {
+
+	CefRefPtr<CefBrowser> cef_browser = *(CefRefPtr<CefBrowser>*)(bw->impl.cef_ptr);
+	CefRefPtr<CefFrame> frame = cef_browser->GetFrame( frame_id );
+
+	if ( frame == nullptr ) {
+		bw_Err error = bw_Err_new_with_msg( 1, "frame not found" );
+
+		cb( bw, user_data, 0, &error );
+		bw_Err_free( &error );
+		return;
+	}
+
+	CefString code = bw_BrowserWindowCef_wrapJs( js );
+
+	bw_BrowserWindowCef_sendJsToRendererProcess( bw, frame, code, cb, user_data );
 }
 
 // It really doesn't matter from which thread we're sending the JavaScript code from,
@@ -82,6 +123,35 @@ void bw_BrowserWindow_evalJsThreaded( bw_BrowserWindow* bw, bw_CStrSlice js, bw_
 	bw_BrowserWindow_evalJs( bw, js, cb, user_data );
 }
 
+size_t bw_BrowserWindow_getFrames( bw_BrowserWindow* bw, bw_BrowserWindowFrame** frames ) {
+	CefRefPtr<CefBrowser> cef_browser = *(CefRefPtr<CefBrowser>*)bw->impl.cef_ptr;
+
+	std::vector<int64> identifiers;
+	cef_browser->GetFrameIdentifiers( identifiers );
+
+	bw_BrowserWindowFrame* array = (bw_BrowserWindowFrame*)malloc( sizeof( bw_BrowserWindowFrame ) * identifiers.size() );
+	size_t count = 0;
+
+	for ( int64 id : identifiers ) {
+		CefRefPtr<CefFrame> frame = cef_browser->GetFrame( id );
+		// The frame may have been removed in the meantime
+		if ( frame == nullptr )
+			continue;
+
+		CefRefPtr<CefFrame> parent = frame->GetParent();
+
+		bw_BrowserWindowFrame* info = &array[ count++ ];
+		info->id = id;
+		info->parent_id = parent != nullptr ? parent->GetIdentifier() : -1;
+		info->name = bw_cef_copyToStrSlice( frame->GetName() );
+		info->url = bw_cef_copyToStrSlice( frame->GetURL() );
+		info->is_main = frame->IsMain();
+	}
+
+	*frames = array;
+	return count;
+}
+
 BOOL bw_BrowserWindow_getUrl(bw_BrowserWindow* bw, bw_StrSlice* url) {
 	CefRefPtr<CefBrowser> cef_browser = *(CefRefPtr<CefBrowser>*)bw->impl.cef_ptr;
 
@@ -100,7 +170,12 @@ void bw_BrowserWindowCef_connectToGtkWindow( bw_BrowserWindow* bw, CefWindowInfo
 	GdkWindow* gdk_window = gtk_widget_get_window( bw->window->impl.handle );
 	Window x_window = GDK_WINDOW_XID( gdk_window );
 
-	CefRect rect( 0, 0, width, height );
+	// Place the browser below the menu bar, if there is one
+	int menu_height = 0;
+	if ( bw->window->impl.menu_bar != NULL )
+		gtk_widget_get_preferred_height( bw->window->impl.menu_bar, NULL, &menu_height );
+
+	CefRect rect( 0, menu_height, width, height - menu_height );
 	
 	info.SetAsChild( x_window, rect );
 #else
@@ -142,6 +217,41 @@ void bw_BrowserWindowImpl_doCleanup( bw_Window* window ) {
 	// Delete the CefBrowser pointer that we have stored in our bw_BrowserWindow handle
 	delete cef_ptr;
 	delete bw_ptr->impl.resource_path;
+
+	if ( bw_ptr->impl.devtools != 0 ) {
+		CefRefPtr<bw::DevToolsObserver>* observer = (CefRefPtr<bw::DevToolsObserver>*)bw_ptr->impl.devtools;
+		(*observer)->detach();
+		delete observer;
+	}
+}
+
+void bw_BrowserWindow_invalidate( bw_BrowserWindow* bw ) {
+	CefRefPtr<CefBrowserHost> host = bw_BrowserWindowCef_offscreenHost( bw );
+
+	if ( host != nullptr )
+		host->Invalidate( PET_VIEW );
+}
+
+double bw_BrowserWindow_getZoom( bw_BrowserWindow* bw ) {
+	return bw->impl.zoom;
+}
+
+void bw_BrowserWindow_execCommand( bw_BrowserWindow* bw, uint8_t command ) {
+	CefRefPtr<CefBrowser> cef_browser = *(CefRefPtr<CefBrowser>*)bw->impl.cef_ptr;
+	CefRefPtr<CefFrame> frame = cef_browser->GetFocusedFrame();
+
+	if ( frame == nullptr )
+		frame = cef_browser->GetMainFrame();
+
+	switch ( command ) {
+	case 0: frame->Undo(); break;
+	case 1: frame->Redo(); break;
+	case 2: frame->Cut(); break;
+	case 3: frame->Copy(); break;
+	case 4: frame->Paste(); break;
+	case 5: frame->Delete(); break;
+	case 6: frame->SelectAll(); break;
+	}
 }
 
 bw_Err bw_BrowserWindow_navigate( bw_BrowserWindow* bw, bw_CStrSlice url ) {
@@ -156,6 +266,119 @@ bw_Err bw_BrowserWindow_navigate( bw_BrowserWindow* bw, bw_CStrSlice url ) {
 	BW_ERR_RETURN_SUCCESS;
 }
 
+void bw_BrowserWindow_reload( bw_BrowserWindow* bw, BOOL ignore_cache ) {
+	CefRefPtr<CefBrowser> cef_browser = *(CefRefPtr<CefBrowser>*)bw->impl.cef_ptr;
+
+	if ( ignore_cache )
+		cef_browser->ReloadIgnoreCache();
+	else
+		cef_browser->Reload();
+}
+
+BOOL bw_BrowserWindow_navigateFrame( bw_BrowserWindow* bw, int64_t frame_id, bw_CStrSlice url ) {
+
+	CefRefPtr<CefBrowser> cef_browser = *(CefRefPtr<CefBrowser>*)bw->impl.cef_ptr;
+	CefRefPtr<CefFrame> frame = cef_browser->GetFrame( frame_id );
+
+	if ( frame == nullptr )
+		return FALSE;
+
+	frame->LoadURL( bw_cef_copyFromStrSlice( url ) );
+	return TRUE;
+}
+
+void bw_BrowserWindow_sendFocus( bw_BrowserWindow* bw, BOOL focused ) {
+	CefRefPtr<CefBrowserHost> host = bw_BrowserWindowCef_offscreenHost( bw );
+
+	if ( host != nullptr )
+		host->SendFocusEvent( focused != FALSE );
+}
+
+void bw_BrowserWindow_sendKey( bw_BrowserWindow* bw, uint8_t kind, int key_code, uint32_t character, uint32_t modifiers ) {
+	CefRefPtr<CefBrowserHost> host = bw_BrowserWindowCef_offscreenHost( bw );
+	if ( host == nullptr )
+		return;
+
+	CefKeyEvent event;
+	event.modifiers = bw_BrowserWindowCef_eventFlags( modifiers );
+	event.character = (char16)character;
+	event.unmodified_character = (char16)character;
+	switch ( kind ) {
+	case 0: event.type = KEYEVENT_RAWKEYDOWN; event.windows_key_code = key_code; break;
+	case 1: event.type = KEYEVENT_KEYUP; event.windows_key_code = key_code; break;
+	// Typed characters carry the character in the key code as well
+	default: event.type = KEYEVENT_CHAR; event.windows_key_code = (int)character;
+	}
+
+	host->SendKeyEvent( event );
+}
+
+void bw_BrowserWindow_sendMouseButton( bw_BrowserWindow* bw, int x, int y, uint8_t button, BOOL released, int click_count, uint32_t modifiers ) {
+	CefRefPtr<CefBrowserHost> host = bw_BrowserWindowCef_offscreenHost( bw );
+	if ( host == nullptr )
+		return;
+
+	CefMouseEvent event;
+	event.x = x;
+	event.y = y;
+	event.modifiers = bw_BrowserWindowCef_eventFlags( modifiers );
+
+	CefBrowserHost::MouseButtonType type = MBT_LEFT;
+	if ( button == 1 )
+		type = MBT_MIDDLE;
+	else if ( button == 2 )
+		type = MBT_RIGHT;
+
+	host->SendMouseClickEvent( event, type, released != FALSE, click_count );
+}
+
+void bw_BrowserWindow_sendMouseMove( bw_BrowserWindow* bw, int x, int y, BOOL leave, uint32_t modifiers ) {
+	CefRefPtr<CefBrowserHost> host = bw_BrowserWindowCef_offscreenHost( bw );
+	if ( host == nullptr )
+		return;
+
+	CefMouseEvent event;
+	event.x = x;
+	event.y = y;
+	event.modifiers = bw_BrowserWindowCef_eventFlags( modifiers );
+
+	host->SendMouseMoveEvent( event, leave != FALSE );
+}
+
+void bw_BrowserWindow_sendMouseWheel( bw_BrowserWindow* bw, int x, int y, int delta_x, int delta_y, uint32_t modifiers ) {
+	CefRefPtr<CefBrowserHost> host = bw_BrowserWindowCef_offscreenHost( bw );
+	if ( host == nullptr )
+		return;
+
+	CefMouseEvent event;
+	event.x = x;
+	event.y = y;
+	event.modifiers = bw_BrowserWindowCef_eventFlags( modifiers );
+
+	host->SendMouseWheelEvent( event, delta_x, delta_y );
+}
+
+void bw_BrowserWindow_setCharsetOverride( bw_BrowserWindow* bw, bw_CStrSlice charset ) {
+	CefRefPtr<CefBrowser> cef_browser = *(CefRefPtr<CefBrowser>*)bw->impl.cef_ptr;
+
+	// Preferences can only be changed on CEF's UI thread
+	CefPostTask( TID_UI, base::Bind( &bw_BrowserWindowCef_applyCharsetOverride, cef_browser, std::string( charset.data, charset.len ) ) );
+}
+
+void bw_BrowserWindow_setZoom( bw_BrowserWindow* bw, double factor ) {
+	CefRefPtr<CefBrowser> cef_browser = *(CefRefPtr<CefBrowser>*)bw->impl.cef_ptr;
+
+	// CEF's zoom levels are on a logarithmic scale, where every level zooms in by 20%
+	cef_browser->GetHost()->SetZoomLevel( log( factor ) / log( 1.2 ) );
+	bw->impl.zoom = factor;
+}
+
+void bw_BrowserWindow_subscribeDevToolsEvent( bw_BrowserWindow* bw, bw_CStrSlice event ) {
+	CefRefPtr<CefBrowser> cef_browser = *(CefRefPtr<CefBrowser>*)bw->impl.cef_ptr;
+
+	bw_BrowserWindowCef_devTools( bw )->subscribe( cef_browser, std::string( event.data, event.len ) );
+}
+
 void bw_BrowserWindowImpl_new(
 	bw_BrowserWindow* browser,
 	bw_BrowserWindowSource source,
@@ -166,6 +389,9 @@ void bw_BrowserWindowImpl_new(
 ) {
 	CefWindowInfo info;
 	CefBrowserSettings settings;
+	// CEF can only give offscreen browsers a transparent background, so the backdrop of a window is only visible around the browser.
+	if ( browser_window_options->default_encoding.len != 0 )
+		CefString( &settings.default_encoding ) = bw_cef_copyFromStrSlice( browser_window_options->default_encoding );
 
 	// Set up a CefString with the source
 	CefString source_string;
@@ -180,12 +406,28 @@ void bw_BrowserWindowImpl_new(
 	}
 
 	// Update window size in CefWindowInfo
-	bw_BrowserWindowCef_connectToWindow( browser, info, width, height );
+	// Headless browsers are not connected to their window at all, they are rendered offscreen.
+	BOOL headless = browser->window->app->headless;
+	if ( headless ) {
+		info.SetAsWindowless( 0 );
+#ifdef BW_WIN32
+		// CEF only shares its textures as D3D11 textures
+		info.shared_texture_enabled = browser_window_options->shared_textures;
+#endif
+		// CEF only accepts rates from 1 up to 60 frames per second
+		unsigned int max_frame_rate = browser->window->app->engine_impl.max_frame_rate;
+		if ( max_frame_rate != 0 )
+			settings.windowless_frame_rate = max_frame_rate > 60 ? 60 : max_frame_rate;
+	}
+	else
+		bw_BrowserWindowCef_connectToWindow( browser, info, width, height );
 
 	// Create the browser window handle
 	bw_BrowserWindowImpl bw;
 	bw.cef_ptr = 0;
 	bw.resource_path = 0;
+	bw.zoom = 1.0;
+	bw.devtools = 0;
 
 	// Store the resource path if set
 	if ( browser_window_options->resource_path.len != 0 ) {
@@ -200,25 +442,29 @@ void bw_BrowserWindowImpl_new(
 	dict->SetBinary( "callback", CefBinaryValue::Create( (const void*)&callback, sizeof(callback) ) );
 	dict->SetBinary( "callback-data", CefBinaryValue::Create( (const void*)&callback_data, sizeof(callback_data) ) );
 	dict->SetBool( "dev-tools", browser_window_options->dev_tools );
+	dict->SetString( "preload-script", bw_cef_copyFromStrSlice( browser_window_options->preload_script ) );
+	dict->SetBool( "isolate-scripts", browser_window_options->isolate_scripts );
+
+	browser->impl = bw;
 	
 	// Create the browser
 	CefRefPtr<CefClient>* cef_client = (CefRefPtr<CefClient>*)browser->window->app->engine_impl.cef_client;
-#ifndef BW_CEF_WINDOW
-	bool success = CefBrowserHost::CreateBrowser( info, *cef_client, source_string, settings, dict, nullptr );
-	BW_ASSERT( success, "CefBrowserHost::CreateBrowser failed!\n" );
-#else
+#ifdef BW_CEF_WINDOW
 	// CefBrowserHoset::CreateBrowser doesn't work well with Cefwindow, so we use the CefBrowserView
-	CefRefPtr<CefBrowserView> browser_view = CefBrowserView::CreateBrowserView( *cef_client, source_string, settings, dict, nullptr, nullptr );
-	CefRefPtr<CefWindow>* window = (CefRefPtr<CefWindow>*)browser->window->impl.handle_ptr;
-	(*window)->AddChildView(browser_view);
+	if ( !headless ) {
+		CefRefPtr<CefBrowserView> browser_view = CefBrowserView::CreateBrowserView( *cef_client, source_string, settings, dict, nullptr, nullptr );
+		CefRefPtr<CefWindow>* window = (CefRefPtr<CefWindow>*)browser->window->impl.handle_ptr;
+		(*window)->AddChildView(browser_view);
+		return;
+	}
 #endif
-
-	browser->impl = bw;
+	bool success = CefBrowserHost::CreateBrowser( info, *cef_client, source_string, settings, dict, nullptr );
+	BW_ASSERT( success, "CefBrowserHost::CreateBrowser failed!\n" );
 }
 
 void bw_BrowserWindowCef_sendJsToRendererProcess(
 	bw_BrowserWindow* bw,
-	CefRefPtr<CefBrowser>& cef_browser,
+	CefRefPtr<CefFrame> frame,
 	CefString& code,
 	bw_BrowserWindowJsCallbackFn cb,
 	void* user_data
@@ -236,10 +482,86 @@ void bw_BrowserWindowCef_sendJsToRendererProcess(
 	CefRefPtr<CefBinaryValue> user_data_bin = CefBinaryValue::Create( (const void*)&user_data, sizeof( user_data ) );
 	args->SetBinary( 3, user_data_bin );
 
-	cef_browser->GetMainFrame()->SendProcessMessage( PID_RENDERER, msg );
+	frame->SendProcessMessage( PID_RENDERER, msg );
+}
+
+void bw_BrowserWindowCef_applyCharsetOverride( CefRefPtr<CefBrowser> cef_browser, std::string charset ) {
+	static CefRefPtr<CefValue> original_charset = nullptr;
+
+	CefRefPtr<CefRequestContext> context = cef_browser->GetHost()->GetRequestContext();
+	if ( original_charset == nullptr )
+		original_charset = context->GetPreference( "intl.charset_default" );
+
+	CefRefPtr<CefValue> value = original_charset;
+	if ( charset.length() != 0 ) {
+		value = CefValue::Create();
+		value->SetString( charset );
+	}
+
+	CefString error;
+	if ( !context->SetPreference( "intl.charset_default", value, error ) )
+		fprintf( stderr, "Unable to set charset override: %s\n", error.ToString().c_str() );
+
+	cef_browser->Reload();
+}
+
+CefRefPtr<bw::DevToolsObserver> bw_BrowserWindowCef_devTools( bw_BrowserWindow* bw ) {
+	if ( bw->impl.devtools == 0 )
+		bw->impl.devtools = new CefRefPtr<bw::DevToolsObserver>( new bw::DevToolsObserver( bw ) );
+
+	return *(CefRefPtr<bw::DevToolsObserver>*)bw->impl.devtools;
+}
+
+uint32_t bw_BrowserWindowCef_eventFlags( uint32_t modifiers ) {
+	uint32_t flags = EVENTFLAG_NONE;
+
+	if ( modifiers & BW_MODIFIER_CTRL )
+		flags |= EVENTFLAG_CONTROL_DOWN;
+	if ( modifiers & BW_MODIFIER_SHIFT )
+		flags |= EVENTFLAG_SHIFT_DOWN;
+	if ( modifiers & BW_MODIFIER_ALT )
+		flags |= EVENTFLAG_ALT_DOWN;
+	if ( modifiers & BW_MODIFIER_SUPER )
+		flags |= EVENTFLAG_COMMAND_DOWN;
+	if ( modifiers & BW_MODIFIER_LEFT_BUTTON )
+		flags |= EVENTFLAG_LEFT_MOUSE_BUTTON;
+	if ( modifiers & BW_MODIFIER_MIDDLE_BUTTON )
+		flags |= EVENTFLAG_MIDDLE_MOUSE_BUTTON;
+	if ( modifiers & BW_MODIFIER_RIGHT_BUTTON )
+		flags |= EVENTFLAG_RIGHT_MOUSE_BUTTON;
+
+	return flags;
 }
 
+CefRefPtr<CefBrowserHost> bw_BrowserWindowCef_offscreenHost( bw_BrowserWindow* bw ) {
 
+	// Browsers are only rendered offscreen when they are headless
+	if ( !bw->window->app->headless || bw->impl.cef_ptr == 0 )
+		return nullptr;
+
+	return (*(CefRefPtr<CefBrowser>*)bw->impl.cef_ptr)->GetHost();
+}
+
+CefString bw_BrowserWindowCef_wrapJs( bw_CStrSlice js ) {
+
+	// Wrap the JS code within a temporary function and execute it, and convert the return value to a string
+	// This allows executing JS code that isn't terminated with a semicolon, and does the javascript value string conversion inside JS.
+	std::string code = "(function () { return ";
+	code.append( js.data, js.len );
+	code += "; })()";
+	// Note: For the sake of simplicity, I've used std::string to append some strings together.
+	//       CefString unfortunately doesn't provide this functionality.
+	//       There is some overhead because of this, but for now it is ok.
+
+	return CefString( code );
+}
+
+
+
+// CEF asks for every request whether it is intercepted, which is when the filters are looked at
+void bw_BrowserWindowImpl_setRequestFilters( bw_BrowserWindow* bw ) {
+	(void)(bw);
+}
 
 void bw_BrowserWindowImpl_onResize( const bw_Window* window, unsigned int width, unsigned int height ) {
 	bw_BrowserWindow* bw = (bw_BrowserWindow*)window->user_data;
@@ -249,6 +571,12 @@ void bw_BrowserWindowImpl_onResize( const bw_Window* window, unsigned int width,
 
 		CefRefPtr<CefBrowser> cef = *(CefRefPtr<CefBrowser>*)bw->impl.cef_ptr;
 
+		// Offscreen browsers ask for their size through the render handler
+		if ( window->app->headless ) {
+			cef->GetHost()->WasResized();
+			return;
+		}
+
 #if defined(BW_WIN32)
 		SetWindowPos( cef->GetHost()->GetWindowHandle(), 0, 0, 0, width, height, SWP_SHOWWINDOW | SWP_NOZORDER | SWP_NOACTIVATE );
 #elif defined(BW_GTK)
@@ -10,6 +10,8 @@ extern "C" {
 typedef struct {
 	void* cef_ptr;
 	char* resource_path;
+	double zoom;	// The zoom factor that has been set last, because CEF only reports it on its own UI thread
+	void* devtools;	// The observer of the DevTools protocol, once it's needed
 } bw_BrowserWindowImpl;
 
 
@@ -1,13 +1,56 @@
 #include "../browser_window.h"
 #include "../common.h"
+#include "../menu/common.h"
 
 #include "impl.h"
 
+#include <ctype.h>
+#include <stdlib.h>
+#include <string.h>
 
 
 
+typedef struct {
+	const char* name;
+	uint16_t key_code;
+} bw_BrowserWindow_NamedKey;
+
+// The virtual key codes of Windows, which CEF uses on all platforms as well
+static const bw_BrowserWindow_NamedKey bw_BrowserWindow_namedKeys[] = {
+	{ "backspace", 0x08 },
+	{ "delete", 0x2E },
+	{ "del", 0x2E },
+	{ "down", 0x28 },
+	{ "end", 0x23 },
+	{ "enter", 0x0D },
+	{ "return", 0x0D },
+	{ "escape", 0x1B },
+	{ "esc", 0x1B },
+	{ "home", 0x24 },
+	{ "insert", 0x2D },
+	{ "left", 0x25 },
+	{ "minus", 0xBD },
+	{ "-", 0xBD },
+	{ "pagedown", 0x22 },
+	{ "pageup", 0x21 },
+	{ "plus", 0xBB },
+	{ "+", 0xBB },
+	{ "right", 0x27 },
+	{ "space", 0x20 },
+	{ "tab", 0x09 },
+	{ "up", 0x26 }
+};
+
+
+
+uint16_t bw_BrowserWindow_keyCode( const char* key );
 void bw_BrowserWindow_onLoad( bw_Window* w );
 void bw_BrowserWindow_onDestroy( bw_Window* w );
+void bw_BrowserWindow_onResize( const bw_Window* window, unsigned int width, unsigned int height );
+void bw_BrowserWindow_onScaleFactorChanged( const bw_Window* window, float scale_factor );
+void bw_BrowserWindow_onMove( const bw_Window* window, int x, int y );
+void bw_BrowserWindow_onFocus( const bw_Window* window, BOOL focused );
+void bw_BrowserWindow_doCleanup( bw_Window* w );
 
 
 
@@ -15,6 +58,22 @@ void bw_BrowserWindow_destroy( bw_BrowserWindow* bw ) {
 	bw_Window_destroy( bw->window );
 }
 
+void bw_BrowserWindow_doCleanup( bw_Window* window ) {
+	bw_BrowserWindow* bw = (bw_BrowserWindow*)window->user_data;
+
+	bw_BrowserWindowImpl_doCleanup( window );
+
+	if ( bw->on_destroy != 0 )
+		bw->on_destroy( bw );
+
+	free( bw->accelerators );
+	bw->accelerators = NULL;
+	bw->accelerator_count = 0;
+
+	free( bw->request_filters );
+	bw->request_filters = NULL;
+}
+
 void bw_BrowserWindow_drop( bw_BrowserWindow* bw ) {
 	bw_Application_assertCorrectThread( bw->window->app );
 
@@ -22,6 +81,15 @@ void bw_BrowserWindow_drop( bw_BrowserWindow* bw ) {
 	bw_Window_drop( bw->window );
 }
 
+void bw_BrowserWindow_freeFrames( bw_BrowserWindowFrame* frames, size_t count ) {
+	for ( size_t i = 0; i < count; i++ ) {
+		bw_string_free( frames[i].name );
+		bw_string_free( frames[i].url );
+	}
+
+	free( frames );
+}
+
 bw_Application* bw_BrowserWindow_getApp( bw_BrowserWindow* bw ) {
 	return bw->window->app;
 }
@@ -34,6 +102,186 @@ bw_Window* bw_BrowserWindow_getWindow( bw_BrowserWindow* bw ) {
     return bw->window;
 }
 
+uint16_t bw_BrowserWindow_keyCode( const char* key ) {
+	size_t len = strlen( key );
+
+	// Letters and digits have the same virtual key codes as their uppercase ASCII characters
+	if ( len == 1 && isalnum( (unsigned char)key[0] ) )
+		return (uint16_t)toupper( (unsigned char)key[0] );
+
+	// Function keys
+	if ( len >= 2 && len <= 3 && (key[0] == 'F' || key[0] == 'f') ) {
+		int n = atoi( key + 1 );
+		if ( n >= 1 && n <= 24 )
+			return (uint16_t)(0x70 + n - 1);
+	}
+
+	for ( size_t i = 0; i < sizeof( bw_BrowserWindow_namedKeys ) / sizeof( bw_BrowserWindow_namedKeys[0] ); i++ ) {
+		const char* name = bw_BrowserWindow_namedKeys[i].name;
+		size_t j = 0;
+		while ( name[j] != '\0' && tolower( (unsigned char)key[j] ) == name[j] )
+			j++;
+		if ( name[j] == '\0' && key[j] == '\0' )
+			return bw_BrowserWindow_namedKeys[i].key_code;
+	}
+
+	return 0;
+}
+
+BOOL bw_BrowserWindow_registerAccelerator( bw_BrowserWindow* bw, uint32_t accelerator_id, bw_CStrSlice accelerator ) {
+	bw_Application_assertCorrectThread( bw->window->app );
+
+	char* accelerator_str = bw_string_copyAsNewCstr( accelerator );
+	bw_MenuAccelerator parsed;
+	BOOL is_valid = bw_MenuAccelerator_parse( accelerator_str, &parsed );
+	free( accelerator_str );
+	if ( !is_valid )
+		return FALSE;
+
+	uint16_t key_code = bw_BrowserWindow_keyCode( parsed.key );
+	if ( key_code == 0 )
+		return FALSE;
+
+	// Replace the registration of the same key combination, if there is one
+	bw_BrowserWindowAccelerator* entry = NULL;
+	for ( size_t i = 0; i < bw->accelerator_count; i++ ) {
+		bw_BrowserWindowAccelerator* a = &bw->accelerators[i];
+		if ( a->key_code == key_code && a->ctrl == parsed.ctrl && a->shift == parsed.shift && a->alt == parsed.alt && a->super == parsed.super ) {
+			entry = a;
+			break;
+		}
+	}
+
+	if ( entry == NULL ) {
+		bw->accelerators = (bw_BrowserWindowAccelerator*)realloc( bw->accelerators, sizeof( bw_BrowserWindowAccelerator ) * (bw->accelerator_count + 1) );
+		entry = &bw->accelerators[ bw->accelerator_count ];
+		bw->accelerator_count += 1;
+	}
+
+	entry->id = accelerator_id;
+	entry->key_code = key_code;
+	entry->ctrl = parsed.ctrl;
+	entry->shift = parsed.shift;
+	entry->alt = parsed.alt;
+	entry->super = parsed.super;
+	return TRUE;
+}
+
+void bw_BrowserWindow_setAcceleratedPaintHandler( bw_BrowserWindow* bw, bw_BrowserWindowAcceleratedPaintFn handler ) {
+	bw->on_accelerated_paint = handler;
+}
+
+void bw_BrowserWindow_setAcceleratorHandler( bw_BrowserWindow* bw, bw_BrowserWindowAcceleratorFn handler ) {
+	bw->on_accelerator = handler;
+}
+
+void bw_BrowserWindow_setEventHandlers( bw_BrowserWindow* bw, bw_BrowserWindowMoveFn on_move, bw_BrowserWindowFocusFn on_focus, bw_BrowserWindowNavigationFn on_navigate, bw_BrowserWindowTitleFn on_title_change ) {
+	bw->on_move = on_move;
+	bw->on_focus = on_focus;
+	bw->on_navigate = on_navigate;
+	bw->on_title_change = on_title_change;
+}
+
+void bw_BrowserWindow_setFaviconHandler( bw_BrowserWindow* bw, bw_BrowserWindowFaviconFn handler ) {
+	bw->on_favicon_changed = handler;
+}
+
+void bw_BrowserWindow_setFileChooserHandler( bw_BrowserWindow* bw, bw_BrowserWindowFileChooserFn handler ) {
+	bw->on_file_chooser = handler;
+}
+
+void bw_BrowserWindow_setKeyHandler( bw_BrowserWindow* bw, bw_BrowserWindowKeyFn handler ) {
+	bw->on_key = handler;
+}
+
+void bw_BrowserWindow_setLoadHandler( bw_BrowserWindow* bw, bw_BrowserWindowLoadFn handler ) {
+	bw->on_load = handler;
+}
+
+void bw_BrowserWindow_setLoadingProgressHandler( bw_BrowserWindow* bw, bw_BrowserWindowLoadingProgressFn handler ) {
+	bw->on_loading_progress = handler;
+}
+
+void bw_BrowserWindow_setOffscreenHandlers( bw_BrowserWindow* bw, bw_BrowserWindowPaintFn on_paint, bw_BrowserWindowPopupFn on_popup, bw_BrowserWindowCursorFn on_cursor_change ) {
+	bw->on_paint = on_paint;
+	bw->on_popup = on_popup;
+	bw->on_cursor_change = on_cursor_change;
+}
+
+void bw_BrowserWindow_setDestroyHandler( bw_BrowserWindow* bw, bw_BrowserWindowDestroyFn handler ) {
+	bw->on_destroy = handler;
+}
+
+void bw_BrowserWindow_setPermissionHandler( bw_BrowserWindow* bw, bw_BrowserWindowPermissionFn handler ) {
+	bw->on_permission_request = handler;
+}
+
+void bw_BrowserWindow_setRenderProcessHandlers( bw_BrowserWindow* bw, bw_BrowserWindowProcessTerminatedFn on_terminated, bw_BrowserWindowUnresponsiveFn on_unresponsive ) {
+	bw->on_render_process_terminated = on_terminated;
+	bw->on_unresponsive = on_unresponsive;
+}
+
+void bw_BrowserWindow_setRequestHandler( bw_BrowserWindow* bw, bw_BrowserWindowRequestFn handler, bw_CStrSlice filters ) {
+	free( bw->request_filters );
+	bw->request_filters = (char*)malloc( filters.len + 1 );
+	memcpy( bw->request_filters, filters.data, filters.len );
+	bw->request_filters[ filters.len ] = '\0';
+
+	bw->on_request = handler;
+	bw_BrowserWindowImpl_setRequestFilters( bw );
+}
+
+void bw_BrowserWindow_setResizeHandler( bw_BrowserWindow* bw, bw_BrowserWindowResizeFn handler ) {
+	bw->on_resize = handler;
+}
+
+void bw_BrowserWindow_setDevToolsEventHandler( bw_BrowserWindow* bw, bw_BrowserWindowDevToolsEventFn handler ) {
+	bw->on_devtools_event = handler;
+}
+
+void bw_BrowserWindow_setSchemeHandler( bw_BrowserWindow* bw, bw_BrowserWindowSchemeFn handler ) {
+	bw->on_scheme_request = handler;
+}
+
+void bw_BrowserWindow_setStatusTextHandler( bw_BrowserWindow* bw, bw_BrowserWindowStatusTextFn handler ) {
+	bw->on_status_text = handler;
+}
+
+void bw_BrowserWindow_setScaleFactorHandler( bw_BrowserWindow* bw, bw_BrowserWindowScaleFactorFn handler ) {
+	bw->on_scale_factor_changed = handler;
+}
+
+int bw_BrowserWindow_checkPermission( bw_BrowserWindow* bw, uint8_t permission, bw_CStrSlice url ) {
+	int decision = -1;
+	if ( bw->on_permission_request != 0 )
+		decision = bw->on_permission_request( bw, permission, url );
+
+	// The defaults of the window only decide what the handler leaves open
+	if ( decision == -1 && permission < BW_PERMISSION_COUNT )
+		decision = bw->permission_defaults[ permission ];
+	return decision;
+}
+
+BOOL bw_BrowserWindow_handleKey( bw_BrowserWindow* bw, uint8_t kind, uint16_t key_code, uint32_t modifiers, BOOL is_repeat ) {
+	if ( bw->on_key == 0 )
+		return FALSE;
+
+	return bw->on_key( bw, kind, key_code, modifiers, is_repeat );
+}
+
+BOOL bw_BrowserWindow_findAccelerator( const bw_BrowserWindow* bw, uint16_t key_code, BOOL ctrl, BOOL shift, BOOL alt, BOOL super, uint32_t* accelerator_id ) {
+	for ( size_t i = 0; i < bw->accelerator_count; i++ ) {
+		const bw_BrowserWindowAccelerator* a = &bw->accelerators[i];
+
+		if ( a->key_code == key_code && a->ctrl == ctrl && a->shift == shift && a->alt == alt && a->super == super ) {
+			*accelerator_id = a->id;
+			return TRUE;
+		}
+	}
+
+	return FALSE;
+}
+
 void bw_BrowserWindow_new(
 	bw_Application* app,
 	const bw_Window* parent,
@@ -51,10 +299,37 @@ void bw_BrowserWindow_new(
 
 	bw_BrowserWindow* browser = (bw_BrowserWindow*)malloc( sizeof( bw_BrowserWindow ) );
 	browser->window = bw_Window_new( app, parent, title, width, height, window_options, browser );
-	browser->window->callbacks.do_cleanup = bw_BrowserWindowImpl_doCleanup;
+	browser->window->callbacks.do_cleanup = bw_BrowserWindow_doCleanup;
 	browser->external_handler = handler;
 	browser->user_data = user_data;
-
+	browser->on_destroy = 0;
+	browser->on_accelerator = 0;
+	browser->accelerators = NULL;
+	browser->accelerator_count = 0;
+	browser->on_paint = 0;
+	browser->on_popup = 0;
+	browser->on_cursor_change = 0;
+	browser->on_accelerated_paint = 0;
+	browser->on_resize = 0;
+	browser->on_scale_factor_changed = 0;
+	browser->on_move = 0;
+	browser->on_focus = 0;
+	browser->on_navigate = 0;
+	browser->on_load = 0;
+	browser->on_loading_progress = 0;
+	browser->on_title_change = 0;
+	browser->on_favicon_changed = 0;
+	browser->on_status_text = 0;
+	browser->on_render_process_terminated = 0;
+	browser->on_unresponsive = 0;
+	browser->on_file_chooser = 0;
+	browser->on_permission_request = 0;
+	browser->on_scheme_request = 0;
+	browser->on_request = 0;
+	browser->request_filters = NULL;
+	browser->on_devtools_event = 0;
+	browser->on_key = 0;
+	memcpy( browser->permission_defaults, browser_window_options->permission_defaults, sizeof( browser->permission_defaults ) );
 
 	bw_BrowserWindowImpl_new(
 		browser,
@@ -66,7 +341,36 @@ void bw_BrowserWindow_new(
 		callback_data
 	);
 
-	// bw_BrowserWindowImpl_onResize depends on browser->impl being initialized already.
+	// bw_BrowserWindow_onResize depends on browser->impl being initialized already.
 	// Therefore we initialize this event after everything
-	browser->window->callbacks.on_resize = bw_BrowserWindowImpl_onResize;
+	browser->window->callbacks.on_resize = bw_BrowserWindow_onResize;
+	browser->window->callbacks.on_scale_factor_changed = bw_BrowserWindow_onScaleFactorChanged;
+	browser->window->callbacks.on_move = bw_BrowserWindow_onMove;
+	browser->window->callbacks.on_focus = bw_BrowserWindow_onFocus;
+}
+
+void bw_BrowserWindow_onResize( const bw_Window* window, unsigned int width, unsigned int height ) {
+	bw_BrowserWindowImpl_onResize( window, width, height );
+
+	bw_BrowserWindow* bw = (bw_BrowserWindow*)window->user_data;
+	if ( bw != 0 && bw->on_resize != 0 )
+		bw->on_resize( bw, width, height );
+}
+
+void bw_BrowserWindow_onScaleFactorChanged( const bw_Window* window, float scale_factor ) {
+	bw_BrowserWindow* bw = (bw_BrowserWindow*)window->user_data;
+	if ( bw != 0 && bw->on_scale_factor_changed != 0 )
+		bw->on_scale_factor_changed( bw, scale_factor );
+}
+
+void bw_BrowserWindow_onMove( const bw_Window* window, int x, int y ) {
+	bw_BrowserWindow* bw = (bw_BrowserWindow*)window->user_data;
+	if ( bw != 0 && bw->on_move != 0 )
+		bw->on_move( bw, x, y );
+}
+
+void bw_BrowserWindow_onFocus( const bw_Window* window, BOOL focused ) {
+	bw_BrowserWindow* bw = (bw_BrowserWindow*)window->user_data;
+	if ( bw != 0 && bw->on_focus != 0 )
+		bw->on_focus( bw, focused );
 }
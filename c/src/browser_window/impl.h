@@ -9,6 +9,22 @@ extern "C" {
 
 void bw_BrowserWindowImpl_doCleanup( bw_Window* bw );
 
+// Is implemented in common.c, to be used by the underlying browser engine for every key that is pressed, before the page gets it.
+// `key_code` is the virtual key code as it is used on Windows, and the modifiers need to be either TRUE or FALSE.
+// Returns TRUE and stores the identifier in `accelerator_id` if the key press belongs to a registered accelerator.
+// The page shouldn't receive the key press in that case, and the accelerator handler should be invoked on the GUI thread.
+BOOL bw_BrowserWindow_findAccelerator( const bw_BrowserWindow* bw, uint16_t key_code, BOOL ctrl, BOOL shift, BOOL alt, BOOL super, uint32_t* accelerator_id );
+
+// Is implemented in common.c, to be used by the underlying browser engine for every key that is pressed or released, before the accelerators are looked at.
+// The arguments are those of `bw_BrowserWindowKeyFn`, and it needs to be called on the GUI thread.
+// Returns TRUE if the key handler has handled the key, in which case neither the page nor the accelerators should get it.
+BOOL bw_BrowserWindow_handleKey( bw_BrowserWindow* bw, uint8_t kind, uint16_t key_code, uint32_t modifiers, BOOL is_repeat );
+
+// Is implemented in common.c, to be used by the underlying browser engine whenever the page asks for a permission.
+// `permission` is one of the kinds of `bw_BrowserWindowPermissionFn`.
+// Returns 1 if it should be granted, 0 if it should be denied, or -1 if the engine should do what it normally does.
+int bw_BrowserWindow_checkPermission( bw_BrowserWindow* bw, uint8_t permission, bw_CStrSlice url );
+
 // Should be implemented by the underlying browser engine to create a new browser and invoke the callback.
 void bw_BrowserWindowImpl_new(
 	bw_BrowserWindow* browser,
@@ -19,6 +35,9 @@ void bw_BrowserWindowImpl_new(
 	void* callback_data
 );
 
+// Should be implemented by the underlying browser engine, to start passing the requests that match `request_filters` to `on_request`.
+void bw_BrowserWindowImpl_setRequestFilters( bw_BrowserWindow* bw );
+
 void bw_BrowserWindowImpl_onResize( const bw_Window* window, unsigned int width, unsigned int height );
 
 
@@ -0,0 +1,128 @@
+#include "request.hpp"
+#include "scheme.hpp"
+#include "../application.h"
+#include "../common.h"
+
+#include <cstring>
+
+
+
+// Everything the request handler of the browser window gets to know about the request, on the GUI thread.
+struct bw_InterceptedRequestData {
+	bw_BrowserWindow* bw;
+	bw_InterceptedRequest* request;
+	std::string method;
+	std::string url;
+};
+
+
+
+void bw_InterceptedRequest_invokeHandler( bw_Application* app, void* data );
+// Whether `text` matches `pattern` from the given positions on, where `*` in the pattern matches any text.
+bool bw_InterceptedRequest_matchesPattern( const char* pattern, size_t pattern_len, const char* text );
+
+
+
+void bw_InterceptedRequest_block( bw_InterceptedRequest* request ) {
+	request->decide( BW_REQUEST_BLOCK, std::string(), nullptr );
+	delete request;
+}
+
+void bw_InterceptedRequest_continue( bw_InterceptedRequest* request, bw_CStrSlice headers ) {
+	request->decide( BW_REQUEST_CONTINUE, std::string( headers.data, headers.len ), nullptr );
+	delete request;
+}
+
+void bw_InterceptedRequest_dispatch(
+	bw_BrowserWindow* bw,
+	std::string method,
+	std::string url,
+	std::string headers,
+	std::function<void( bw_InterceptedRequestAction action, std::string value, bw_SchemeRequest* response )> decide
+) {
+	bw_InterceptedRequest* request = new bw_InterceptedRequest;
+	request->headers = headers;
+	request->decide = decide;
+
+	auto data = new bw_InterceptedRequestData {
+		bw,
+		request,
+		method,
+		url
+	};
+
+	// When the application is shutting down, there is nobody left to decide
+	if ( !bw_Application_dispatch( bw->window->app, bw_InterceptedRequest_invokeHandler, data ) ) {
+		delete data;
+		bw_InterceptedRequest_continue( request, { request->headers.size(), request->headers.c_str() } );
+	}
+}
+
+void bw_InterceptedRequest_invokeHandler( bw_Application* app, void* _data ) {
+	UNUSED( app );
+	bw_InterceptedRequestData* data = (bw_InterceptedRequestData*)_data;
+	bw_BrowserWindow* bw = data->bw;
+	bw_InterceptedRequest* request = data->request;
+
+	if ( bw->on_request == 0 )
+		bw_InterceptedRequest_continue( request, { request->headers.size(), request->headers.c_str() } );
+	else {
+		bw_CStrSlice method = { data->method.size(), data->method.c_str() };
+		bw_CStrSlice url = { data->url.size(), data->url.c_str() };
+		bw_CStrSlice headers = { request->headers.size(), request->headers.c_str() };
+
+		bw->on_request( bw, request, method, url, headers );
+	}
+
+	delete data;
+}
+
+bool bw_InterceptedRequest_matches( const bw_BrowserWindow* bw, const std::string& url ) {
+	if ( bw->on_request == 0 || bw->request_filters == NULL )
+		return false;
+
+	const char* line = bw->request_filters;
+	while ( *line != '\0' ) {
+		const char* end = strchr( line, '\n' );
+		size_t len = end == NULL ? strlen( line ) : (size_t)(end - line);
+
+		if ( len > 0 && bw_InterceptedRequest_matchesPattern( line, len, url.c_str() ) )
+			return true;
+
+		if ( end == NULL )
+			break;
+		line = end + 1;
+	}
+	return false;
+}
+
+bool bw_InterceptedRequest_matchesPattern( const char* pattern, size_t pattern_len, const char* text ) {
+	if ( pattern_len == 0 )
+		return *text == '\0';
+
+	if ( *pattern == '*' ) {
+		// The wildcard takes as much of the text as the rest of the pattern allows
+		for ( const char* rest = text; ; rest++ ) {
+			if ( bw_InterceptedRequest_matchesPattern( pattern + 1, pattern_len - 1, rest ) )
+				return true;
+			if ( *rest == '\0' )
+				return false;
+		}
+	}
+
+	return *text == *pattern && bw_InterceptedRequest_matchesPattern( pattern + 1, pattern_len - 1, text + 1 );
+}
+
+void bw_InterceptedRequest_redirect( bw_InterceptedRequest* request, bw_CStrSlice url ) {
+	request->decide( BW_REQUEST_REDIRECT, std::string( url.data, url.len ), nullptr );
+	delete request;
+}
+
+bw_SchemeRequest* bw_InterceptedRequest_respond( bw_InterceptedRequest* request ) {
+	// The response is read by the browser engine, like the one of a custom scheme
+	bw_SchemeRequest* response = new bw_SchemeRequest;
+
+	request->decide( BW_REQUEST_RESPOND, std::string(), response );
+	delete request;
+	return response;
+}
@@ -0,0 +1,44 @@
+#ifndef BW_BROWSER_WINDOW_REQUEST_HPP
+#define BW_BROWSER_WINDOW_REQUEST_HPP
+
+#include "../browser_window.h"
+
+#include <functional>
+#include <string>
+
+
+
+// What the request handler has decided to do with an intercepted request.
+enum bw_InterceptedRequestAction {
+	BW_REQUEST_BLOCK,
+	BW_REQUEST_CONTINUE,	// `value` holds the headers to send the request with.
+	BW_REQUEST_REDIRECT,	// `value` holds the URL to send the request to instead.
+	BW_REQUEST_RESPOND	// `response` is the scheme request that the application answers.
+};
+
+// A request of the page that waits for the decision of the request handler of the browser window.
+struct bw_InterceptedRequest {
+	std::string headers;
+	// Is invoked once, on the GUI thread, with the decision.
+	std::function<void( bw_InterceptedRequestAction action, std::string value, bw_SchemeRequest* response )> decide;
+};
+
+
+
+// Lets the request handler of the browser window decide on the request on the GUI thread, and invokes `decide` with the decision.
+// If the application is shutting down, the request is sent as it is.
+// `headers` contains one `Name: value` line for every header.
+void bw_InterceptedRequest_dispatch(
+	bw_BrowserWindow* bw,
+	std::string method,
+	std::string url,
+	std::string headers,
+	std::function<void( bw_InterceptedRequestAction action, std::string value, bw_SchemeRequest* response )> decide
+);
+
+// Whether the URL matches one of the patterns of `request_filters`, and the request should be passed to the request handler.
+bool bw_InterceptedRequest_matches( const bw_BrowserWindow* bw, const std::string& url );
+
+
+
+#endif//BW_BROWSER_WINDOW_REQUEST_HPP
@@ -0,0 +1,251 @@
+#include "scheme.hpp"
+#include "../application.h"
+#include "../common.h"
+
+#include <algorithm>
+#include <cctype>
+#include <cstring>
+
+
+
+// Everything the scheme handler of the browser window gets to know about the request, on the GUI thread.
+struct bw_SchemeRequestData {
+	bw_BrowserWindow* bw;
+	bw_SchemeRequest* request;
+	std::string method;
+	std::string url;
+	std::string headers;
+	std::string body;
+};
+
+
+
+void bw_SchemeRequest_invokeHandler( bw_Application* app, void* data );
+// Lets go of one of the references to the request, and frees it if it was the last one.
+void bw_SchemeRequest_unref( bw_SchemeRequest* request );
+
+
+
+void bw_SchemeRequest::cancel() {
+	std::lock_guard<std::mutex> lock( this->mutex );
+
+	this->cancelled = true;
+	this->changed.notify_all();
+}
+
+std::string bw_SchemeRequest::header( const char* name ) {
+	std::lock_guard<std::mutex> lock( this->mutex );
+
+	for ( const auto& header : this->headers ) {
+		const std::string& n = header.first;
+		if ( n.size() == strlen( name ) && std::equal( n.begin(), n.end(), name, []( char a, char b ) { return tolower( (unsigned char)a ) == tolower( (unsigned char)b ); } ) )
+			return header.second;
+	}
+	return std::string();
+}
+
+long bw_SchemeRequest::read( void* data, size_t size, bool wait ) {
+	std::unique_lock<std::mutex> lock( this->mutex );
+
+	if ( wait )
+		this->changed.wait( lock, [this]() { return this->offset < this->buffer.size() || this->finished || this->cancelled; } );
+
+	if ( this->failed )
+		return BW_SCHEME_READ_FAILED;
+
+	size_t available = this->buffer.size() - this->offset;
+	if ( available == 0 ) {
+		if ( this->finished || this->cancelled )
+			return 0;
+
+		this->waiting = true;
+		return -1;
+	}
+
+	size_t count = std::min( available, size );
+	memcpy( data, this->buffer.data() + this->offset, count );
+	this->offset += count;
+
+	// The application may be waiting for room in the buffer
+	this->changed.notify_all();
+	return (long)count;
+}
+
+void bw_SchemeRequest::release() {
+	std::function<void()> on_response, on_data;
+	{
+		std::lock_guard<std::mutex> lock( this->mutex );
+
+		// The functions may hold on to the handler of the engine, so they are let go of outside of the lock
+		on_response.swap( this->on_response );
+		on_data.swap( this->on_data );
+		this->cancelled = true;
+		this->changed.notify_all();
+	}
+
+	bw_SchemeRequest_unref( this );
+}
+
+void bw_SchemeRequest_abort( bw_SchemeRequest* request ) {
+	{
+		std::lock_guard<std::mutex> lock( request->mutex );
+		request->failed = true;
+	}
+
+	bw_SchemeRequest_finish( request );
+}
+
+void bw_SchemeRequest_finish( bw_SchemeRequest* request ) {
+	std::function<void()> on_response, on_data;
+	{
+		std::lock_guard<std::mutex> lock( request->mutex );
+
+		if ( !request->responded ) {
+			request->responded = true;
+			request->status = 500;
+			on_response.swap( request->on_response );
+		}
+		if ( request->waiting ) {
+			request->waiting = false;
+			on_data = request->on_data;
+		}
+		request->finished = true;
+		request->changed.notify_all();
+	}
+
+	if ( on_response )
+		on_response();
+	if ( on_data )
+		on_data();
+
+	bw_SchemeRequest_unref( request );
+}
+
+bw_SchemeRequest* bw_SchemeRequest_new(
+	bw_BrowserWindow* bw,
+	std::string method,
+	std::string url,
+	std::string headers,
+	std::string body,
+	std::function<void()> on_response
+) {
+	bw_SchemeRequest* request = new bw_SchemeRequest;
+	request->on_response = on_response;
+
+	auto data = new bw_SchemeRequestData {
+		bw,
+		request,
+		method,
+		url,
+		headers,
+		body
+	};
+
+	// When the application is shutting down, there is nobody left to answer the request
+	if ( !bw_Application_dispatch( bw->window->app, bw_SchemeRequest_invokeHandler, data ) ) {
+		delete data;
+		bw_SchemeRequest_finish( request );
+	}
+
+	return request;
+}
+
+void bw_SchemeRequest_invokeHandler( bw_Application* app, void* _data ) {
+	UNUSED( app );
+	bw_SchemeRequestData* data = (bw_SchemeRequestData*)_data;
+	bw_BrowserWindow* bw = data->bw;
+
+	if ( bw->on_scheme_request == 0 ) {
+		bw_CStrSlice no_headers = { 0, "" };
+
+		bw_SchemeRequest_respond( data->request, 404, no_headers );
+		bw_SchemeRequest_finish( data->request );
+	}
+	else {
+		bw_CStrSlice method = { data->method.size(), data->method.c_str() };
+		bw_CStrSlice url = { data->url.size(), data->url.c_str() };
+		bw_CStrSlice headers = { data->headers.size(), data->headers.c_str() };
+		bw_CStrSlice body = { data->body.size(), data->body.data() };
+
+		bw->on_scheme_request( bw, data->request, method, url, headers, body );
+	}
+
+	delete data;
+}
+
+void bw_SchemeRequest_respond( bw_SchemeRequest* request, uint16_t status, bw_CStrSlice headers ) {
+	std::function<void()> on_response;
+	{
+		std::lock_guard<std::mutex> lock( request->mutex );
+		if ( request->responded )
+			return;
+
+		request->responded = true;
+		request->status = status;
+
+		// Every line is a header, in the form of `Name: value`
+		std::string lines( headers.data, headers.len );
+		size_t start = 0;
+		while ( start < lines.size() ) {
+			size_t end = lines.find( '\n', start );
+			if ( end == std::string::npos )
+				end = lines.size();
+
+			std::string line = lines.substr( start, end - start );
+			if ( !line.empty() && line.back() == '\r' )
+				line.pop_back();
+
+			size_t colon = line.find( ':' );
+			if ( colon != std::string::npos ) {
+				size_t value = line.find_first_not_of( ' ', colon + 1 );
+				request->headers.push_back( std::make_pair( line.substr( 0, colon ), value == std::string::npos ? std::string() : line.substr( value ) ) );
+			}
+			start = end + 1;
+		}
+
+		on_response.swap( request->on_response );
+	}
+
+	if ( on_response )
+		on_response();
+}
+
+void bw_SchemeRequest_unref( bw_SchemeRequest* request ) {
+	bool last;
+	{
+		std::lock_guard<std::mutex> lock( request->mutex );
+		request->references -= 1;
+		last = request->references == 0;
+	}
+
+	if ( last )
+		delete request;
+}
+
+BOOL bw_SchemeRequest_write( bw_SchemeRequest* request, const void* data, size_t length ) {
+	std::function<void()> on_data;
+	{
+		std::unique_lock<std::mutex> lock( request->mutex );
+
+		// Waits for the browser engine to catch up, so that the body doesn't need to be kept in memory all at once
+		request->changed.wait( lock, [request]() { return request->cancelled || request->buffer.size() - request->offset < BW_SCHEME_MAX_BUFFERED; } );
+		if ( request->cancelled )
+			return FALSE;
+
+		if ( request->offset > 0 ) {
+			request->buffer.erase( 0, request->offset );
+			request->offset = 0;
+		}
+		request->buffer.append( (const char*)data, length );
+		request->changed.notify_all();
+
+		if ( request->waiting ) {
+			request->waiting = false;
+			on_data = request->on_data;
+		}
+	}
+
+	if ( on_data )
+		on_data();
+	return TRUE;
+}
@@ -0,0 +1,78 @@
+#ifndef BW_BROWSER_WINDOW_SCHEME_HPP
+#define BW_BROWSER_WINDOW_SCHEME_HPP
+
+#include "../browser_window.h"
+
+#include <condition_variable>
+#include <functional>
+#include <mutex>
+#include <string>
+#include <utility>
+#include <vector>
+
+
+
+// How much of the body may be waiting to be read by the browser engine, before `bw_SchemeRequest_write` blocks.
+#define BW_SCHEME_MAX_BUFFERED (1024 * 1024)
+// What `bw_SchemeRequest::read` returns when the response has been aborted.
+#define BW_SCHEME_READ_FAILED (-2)
+
+
+
+// A request of a custom scheme, which is shared by the browser engine that reads the response, and the application that writes it.
+// Both sides hold a reference to it, the request is freed once both have let go of it.
+struct bw_SchemeRequest {
+	std::mutex mutex;
+	std::condition_variable changed;
+	int references = 2;
+
+	bool responded = false;
+	bool finished = false;
+	// Whether the response has been aborted, in which case the rest of the body isn't given to the engine.
+	bool failed = false;
+	bool cancelled = false;
+	uint16_t status = 0;
+	std::vector<std::pair<std::string, std::string>> headers;
+
+	// The part of the body that hasn't been read yet, starts at `offset`.
+	std::string buffer;
+	size_t offset = 0;
+	// Whether the engine is waiting for more of the body, in which case `on_data` is invoked once it is there.
+	bool waiting = false;
+
+	// Invoked once the status and headers are known, or the request has been finished without them.
+	std::function<void()> on_response;
+	// Invoked when the engine has been waiting for the body, and more of it has been written, or it has ended.
+	std::function<void()> on_data;
+
+	// Lets the application know that the rest of the response isn't needed anymore.
+	void cancel();
+	// The value of the header with the given (case-insensitive) name, or an empty string if there is no such header.
+	std::string header( const char* name );
+	// Moves up to `size` bytes of the body into `data`, and returns how many.
+	// Returns 0 when the body has ended, and BW_SCHEME_READ_FAILED when the response has been aborted.
+	// If nothing of the body is available yet, it returns -1 right away unless `wait` is set, in which case it blocks until there is.
+	// `on_data` is invoked when the engine doesn't wait and something becomes available later on.
+	long read( void* data, size_t size, bool wait );
+	// Lets go of the reference of the browser engine.
+	void release();
+};
+
+
+
+// Creates a new request and lets the scheme handler of the browser window answer it on the GUI thread.
+// `on_response` is invoked as soon as the status and headers can be obtained from the request.
+// `headers` contains one `Name: value` line for every header.
+// The returned request needs to be released with `release` once the browser engine is done with it.
+bw_SchemeRequest* bw_SchemeRequest_new(
+	bw_BrowserWindow* bw,
+	std::string method,
+	std::string url,
+	std::string headers,
+	std::string body,
+	std::function<void()> on_response
+);
+
+
+
+#endif//BW_BROWSER_WINDOW_SCHEME_HPP
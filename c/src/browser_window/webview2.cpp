@@ -0,0 +1,1185 @@
+#include "webview2.h"
+#include "../application.h"
+#include "../browser_window.h"
+#include "../common.h"
+#include "../debug.h"
+#include "../webview2/environment.hpp"
+#include "../webview2/util.hpp"
+#include "../win32.h"
+#include "impl.h"
+#include "request.hpp"
+#include "scheme.hpp"
+
+#include <cstdlib>
+#include <map>
+#include <memory>
+#include <set>
+#include <string>
+#include <vector>
+#include <WebView2.h>
+#include <wrl.h>
+
+using Microsoft::WRL::Callback;
+using Microsoft::WRL::ComPtr;
+using Microsoft::WRL::Make;
+
+
+
+// Defines the `invoke_extern` function, which the CEF backend provides natively, on top of WebView2's messaging.
+// Non-string arguments are converted in the same way as the CEF backend does.
+// The built-in functions are taken before the page gets a chance to replace them, so that the page can't intercept the arguments.
+static const char* BW_WEBVIEW2_INVOKE_EXTERN_JS = R"((function() {
+	var apply = Reflect.apply, map = Array.prototype.map, is_array = Array.isArray, to_string = String, date = Date;
+	var webview = window.chrome.webview, post = webview.postMessage;
+	window.invoke_extern = function() {
+		var args = apply(map, arguments, [function(a) {
+			if (typeof a === 'string') return a;
+			if (typeof a === 'function') return '[function]';
+			if (is_array(a)) return '[array]';
+			if (a instanceof date) return '[date]';
+			if (a !== null && typeof a === 'object') return '[object]';
+			return to_string(a);
+		}]);
+		apply(post, webview, [args]);
+	};
+})();
+)";
+
+// Everything that is needed to finish the creation of the browser window once WebView2 has set up its controller.
+struct bw_BrowserWindowWebView2Creation {
+	bw_BrowserWindow* browser;
+	std::string source_data;
+	BOOL is_html;
+	BOOL dev_tools;
+	std::string preload_script;
+	bw_BrowserWindowCreationCallbackFn callback;
+	void* callback_data;
+	ComPtr<ICoreWebView2Environment> environment;
+};
+
+// The DevTools methods of a browser window that are waiting for their result, and the events that have been subscribed to.
+// It is shared with the handlers of WebView2, which may outlive the browser window.
+struct bw_BrowserWindowWebView2DevTools {
+	bw_BrowserWindow* bw;	// Is set to null once the browser window is being destroyed
+	std::map<int, std::pair<bw_BrowserWindowDevToolsResultFn, void*>> pending;
+	int next_id = 1;
+	std::set<std::string> events;
+};
+
+struct bw_BrowserWindowWebView2EvalJsData {
+	bw_BrowserWindow* bw;
+	std::string js;
+	bw_BrowserWindowJsCallbackFn cb;
+	void* user_data;
+};
+
+// A request of a custom scheme, that is waiting for the scheme handler to respond.
+struct bw_BrowserWindowWebView2SchemeResponse {
+	bw_BrowserWindow* bw;
+	bw_SchemeRequest* request;
+	ComPtr<ICoreWebView2Environment> environment;
+	ComPtr<ICoreWebView2WebResourceRequestedEventArgs> args;
+	ComPtr<ICoreWebView2Deferral> deferral;
+};
+
+// The body of a response of a custom scheme, which WebView2 reads while the application is still writing it.
+// WebView2 reads the content of responses on a thread of its own, so it can wait for the application to write more.
+class bw_BrowserWindowWebView2SchemeStream : public Microsoft::WRL::RuntimeClass<Microsoft::WRL::RuntimeClassFlags<Microsoft::WRL::ClassicCom>, IStream> {
+	bw_SchemeRequest* request;
+
+public:
+	bw_BrowserWindowWebView2SchemeStream( bw_SchemeRequest* request ) : request(request) {}
+	~bw_BrowserWindowWebView2SchemeStream() { this->request->release(); }
+
+	HRESULT STDMETHODCALLTYPE Read( void* data, ULONG size, ULONG* read ) override {
+		long count = this->request->read( data, size, true );
+		if ( read != nullptr )
+			*read = count > 0 ? (ULONG)count : 0;
+		if ( count == BW_SCHEME_READ_FAILED )
+			return STG_E_READFAULT;
+		return count > 0 ? S_OK : S_FALSE;
+	}
+
+	// The body can only be read from start to end
+	HRESULT STDMETHODCALLTYPE Write( const void*, ULONG, ULONG* ) override { return STG_E_ACCESSDENIED; }
+	HRESULT STDMETHODCALLTYPE Seek( LARGE_INTEGER, DWORD, ULARGE_INTEGER* ) override { return E_NOTIMPL; }
+	HRESULT STDMETHODCALLTYPE SetSize( ULARGE_INTEGER ) override { return E_NOTIMPL; }
+	HRESULT STDMETHODCALLTYPE CopyTo( IStream*, ULARGE_INTEGER, ULARGE_INTEGER*, ULARGE_INTEGER* ) override { return E_NOTIMPL; }
+	HRESULT STDMETHODCALLTYPE Commit( DWORD ) override { return E_NOTIMPL; }
+	HRESULT STDMETHODCALLTYPE Revert() override { return E_NOTIMPL; }
+	HRESULT STDMETHODCALLTYPE LockRegion( ULARGE_INTEGER, ULARGE_INTEGER, DWORD ) override { return E_NOTIMPL; }
+	HRESULT STDMETHODCALLTYPE UnlockRegion( ULARGE_INTEGER, ULARGE_INTEGER, DWORD ) override { return E_NOTIMPL; }
+	HRESULT STDMETHODCALLTYPE Stat( STATSTG*, DWORD ) override { return E_NOTIMPL; }
+	HRESULT STDMETHODCALLTYPE Clone( IStream** ) override { return E_NOTIMPL; }
+};
+
+
+
+// Asks the permission handler of the browser window whether the permission is granted to the given address.
+int bw_BrowserWindowWebView2_checkPermission( bw_BrowserWindow* bw, uint8_t permission, LPCWSTR url );
+// The state of the DevTools protocol for the browser window, which is created once it is needed.
+std::shared_ptr<bw_BrowserWindowWebView2DevTools> bw_BrowserWindowWebView2_devTools( bw_BrowserWindow* bw );
+void bw_BrowserWindowWebView2_evalJsDispatched( bw_Application* app, void* data );
+// Lets the request handler of the browser window decide on the given request, which matches its filters.
+void bw_BrowserWindowWebView2_interceptRequest( bw_BrowserWindow* bw, ComPtr<ICoreWebView2Environment> environment, ICoreWebView2WebResourceRequestedEventArgs* args );
+void bw_BrowserWindowWebView2_invokeHandler( bw_BrowserWindow* bw, LPCWSTR json );
+void bw_BrowserWindowWebView2_onControllerCreated( bw_BrowserWindowWebView2Creation* creation, HRESULT result, ICoreWebView2Controller* controller );
+// Parses the `Name: value` lines of headers.
+std::vector<std::pair<std::string, std::string>> bw_BrowserWindowWebView2_parseHeaders( const std::string& lines );
+// The headers of the request, with a `Name: value` line for every header.
+std::string bw_BrowserWindowWebView2_requestHeaders( ICoreWebView2WebResourceRequest* request );
+// Lets the scheme handler of the browser window answer the given request of a custom scheme.
+void bw_BrowserWindowWebView2_requestScheme( bw_BrowserWindow* bw, ComPtr<ICoreWebView2Environment> environment, ICoreWebView2WebResourceRequestedEventArgs* args );
+// Gives WebView2 the response of the scheme handler, once it has responded.
+void bw_BrowserWindowWebView2_respondToScheme( bw_Application* app, void* data );
+// Wraps the given JavaScript code so that its value is converted to a string within a function.
+std::string bw_BrowserWindowWebView2_wrapJs( bw_CStrSlice js );
+
+
+
+void bw_BrowserWindow_callDevToolsMethod( bw_BrowserWindow* bw, bw_CStrSlice method, bw_CStrSlice params, bw_BrowserWindowDevToolsResultFn callback, void* data ) {
+	ICoreWebView2* webview = (ICoreWebView2*)bw->impl.webview;
+	std::shared_ptr<bw_BrowserWindowWebView2DevTools> devtools = bw_BrowserWindowWebView2_devTools( bw );
+
+	int id = devtools->next_id++;
+	devtools->pending[ id ] = std::make_pair( callback, data );
+
+	std::wstring name = bw_webview2_toWstring( std::string( method.data, method.len ) );
+	std::wstring json = bw_webview2_toWstring( params.len == 0 ? std::string( "{}" ) : std::string( params.data, params.len ) );
+
+	HRESULT hr = webview->CallDevToolsProtocolMethod( name.c_str(), json.c_str(), Callback<ICoreWebView2CallDevToolsProtocolMethodCompletedHandler>(
+		[devtools, id]( HRESULT error_code, LPCWSTR result ) -> HRESULT {
+			// If the browser window has been destroyed, the callback has been invoked already
+			auto it = devtools->pending.find( id );
+			if ( devtools->bw == nullptr || it == devtools->pending.end() )
+				return S_OK;
+
+			auto callback = it->second;
+			devtools->pending.erase( it );
+
+			// The result of a failed method is the JSON object of its error
+			std::string json = result != nullptr ? bw_webview2_toUtf8( result ) : std::string( "{}" );
+			callback.first( devtools->bw, callback.second, SUCCEEDED( error_code ), { json.size(), json.c_str() } );
+			return S_OK;
+		}
+	).Get() );
+
+	if ( FAILED( hr ) ) {
+		devtools->pending.erase( id );
+
+		std::string error = "{\"message\":\"The method could not be called\"}";
+		callback( bw, data, FALSE, { error.size(), error.c_str() } );
+	}
+}
+
+BOOL bw_BrowserWindow_clearOriginData( bw_BrowserWindow* bw, bw_CStrSlice origin ) {
+	ICoreWebView2* webview = (ICoreWebView2*)bw->impl.webview;
+
+	// The DevTools protocol is able to clear everything that an origin has stored, cookies included.
+	std::string params = "{\"origin\":";
+	params += bw_webview2_toJsonString( std::string( origin.data, origin.len ) );
+	params += ",\"storageTypes\":\"all\"}";
+
+	HRESULT hr = webview->CallDevToolsProtocolMethod( L"Storage.clearDataForOrigin", bw_webview2_toWstring( params ).c_str(), Callback<ICoreWebView2CallDevToolsProtocolMethodCompletedHandler>(
+		[]( HRESULT error_code, LPCWSTR result ) -> HRESULT {
+			UNUSED( error_code );
+			UNUSED( result );
+			return S_OK;
+		}
+	).Get() );
+
+	return SUCCEEDED( hr );
+}
+
+void bw_BrowserWindow_evalJs( bw_BrowserWindow* bw, bw_CStrSlice js, bw_BrowserWindowJsCallbackFn cb, void* user_data ) {
+	ICoreWebView2* webview = (ICoreWebView2*)bw->impl.webview;
+
+	std::wstring code = bw_webview2_toWstring( bw_BrowserWindowWebView2_wrapJs( js ) );
+
+	HRESULT hr = webview->ExecuteScript( code.c_str(), Callback<ICoreWebView2ExecuteScriptCompletedHandler>(
+		[bw, cb, user_data]( HRESULT error_code, LPCWSTR result ) -> HRESULT {
+
+			if ( FAILED( error_code ) ) {
+				bw_Err error = bw_win32_unhandledHresult( error_code );
+				cb( bw, user_data, 0, &error );
+				bw_Err_free( &error );
+				return S_OK;
+			}
+
+			// The result is JSON encoded, and the wrapper returns an array of strings.
+			// It is either `["ok", value]`, or `["error", name, message, stack, line, column]` if the code has thrown an exception.
+			// If the code couldn't even be parsed, the result is `null`.
+			std::string json = bw_webview2_toUtf8( result );
+			std::vector<std::string> strings;
+
+			if ( bw_webview2_parseJsonStringArray( json, strings ) && strings.size() == 2 && strings[0] == "ok" )
+				cb( bw, user_data, strings[1].c_str(), 0 );
+			else {
+				bw_Err error;
+				if ( bw_webview2_parseJsonStringArray( json, strings ) && strings.size() == 6 && strings[0] == "error" )
+					error = bw_Err_newJsException(
+						strings[1].c_str(),
+						strings[2].c_str(),
+						strings[3].c_str(),
+						(unsigned int)strtoul( strings[4].c_str(), 0, 10 ),
+						(unsigned int)strtoul( strings[5].c_str(), 0, 10 )
+					);
+				else
+					error = bw_Err_new_with_msg( 1, "javascript error" );
+
+				cb( bw, user_data, 0, &error );
+				bw_Err_free( &error );
+			}
+
+			return S_OK;
+		}
+	).Get() );
+
+	if ( FAILED( hr ) ) {
+		bw_Err error = bw_win32_unhandledHresult( hr );
+		cb( bw, user_data, 0, &error );
+		bw_Err_free( &error );
+	}
+}
+
+// Only the main frame can be accessed with this backend for now, which has identifier 0.
+void bw_BrowserWindow_evalJsInFrame( bw_BrowserWindow* bw, int64_t frame_id, bw_CStrSlice js, bw_BrowserWindowJsCallbackFn cb, void* user_data ) {
+
+	if ( frame_id != 0 ) {
+		bw_Err error = bw_Err_new_with_msg( 1, "frame not found" );
+
+		cb( bw, user_data, 0, &error );
+		bw_Err_free( &error );
+		return;
+	}
+
+	bw_BrowserWindow_evalJs( bw, js, cb, user_data );
+}
+
+// WebView2 may only be used from the GUI thread, so the call is dispatched to it.
+void bw_BrowserWindow_evalJsThreaded( bw_BrowserWindow* bw, bw_CStrSlice js, bw_BrowserWindowJsCallbackFn cb, void* user_data ) {
+
+	bw_BrowserWindowWebView2EvalJsData* data = new bw_BrowserWindowWebView2EvalJsData;
+	data->bw = bw;
+	data->js = std::string( js.data, js.len );
+	data->cb = cb;
+	data->user_data = user_data;
+
+	if ( !bw_Application_dispatch( bw->window->app, bw_BrowserWindowWebView2_evalJsDispatched, (void*)data ) ) {
+		bw_Err error = bw_Err_new_with_msg( 1, "application has exited" );
+		cb( bw, user_data, 0, &error );
+		bw_Err_free( &error );
+		delete data;
+	}
+}
+
+// WebView2 has no editing commands, but the DevTools Protocol can attach them to a key press, which runs them in the focused frame like the shortcuts do.
+void bw_BrowserWindow_execCommand( bw_BrowserWindow* bw, uint8_t command ) {
+	static const char* names[] = { "undo", "redo", "cut", "copy", "paste", "delete", "selectAll" };
+	ICoreWebView2* webview = (ICoreWebView2*)bw->impl.webview;
+
+	if ( command >= sizeof( names ) / sizeof( names[0] ) )
+		return;
+
+	std::string params = std::string( "{\"type\":\"rawKeyDown\",\"commands\":[\"" ) + names[ command ] + "\"]}";
+	webview->CallDevToolsProtocolMethod( L"Input.dispatchKeyEvent", bw_webview2_toWstring( params ).c_str(), nullptr );
+	webview->CallDevToolsProtocolMethod( L"Input.dispatchKeyEvent", L"{\"type\":\"keyUp\"}", nullptr );
+}
+
+size_t bw_BrowserWindow_getFrames( bw_BrowserWindow* bw, bw_BrowserWindowFrame** frames ) {
+
+	bw_BrowserWindowFrame* main = (bw_BrowserWindowFrame*)malloc( sizeof( bw_BrowserWindowFrame ) );
+	main->id = 0;
+	main->parent_id = -1;
+	main->name.len = 0;
+	main->name.data = 0;
+	bw_BrowserWindow_getUrl( bw, &main->url );
+	main->is_main = TRUE;
+
+	*frames = main;
+	return 1;
+}
+
+BOOL bw_BrowserWindow_getUrl( bw_BrowserWindow* bw, bw_StrSlice* url ) {
+	ICoreWebView2* webview = (ICoreWebView2*)bw->impl.webview;
+
+	LPWSTR source;
+	if ( FAILED( webview->get_Source( &source ) ) ) {
+		url->len = 0;
+		url->data = 0;
+		return FALSE;
+	}
+
+	*url = bw_webview2_copyToStrSlice( source );
+	CoTaskMemFree( source );
+	return TRUE;
+}
+
+void bw_BrowserWindowImpl_doCleanup( bw_Window* window ) {
+	bw_BrowserWindow* bw = (bw_BrowserWindow*)window->user_data;
+
+	if ( bw->impl.controller != 0 ) {
+		ICoreWebView2Controller* controller = (ICoreWebView2Controller*)bw->impl.controller;
+		controller->Close();
+		controller->Release();
+	}
+	if ( bw->impl.webview != 0 )
+		((ICoreWebView2*)bw->impl.webview)->Release();
+
+	// The methods that are still waiting for their result won't get it anymore
+	if ( bw->impl.devtools != 0 ) {
+		auto devtools = (std::shared_ptr<bw_BrowserWindowWebView2DevTools>*)bw->impl.devtools;
+		auto pending = std::move( (*devtools)->pending );
+		(*devtools)->bw = nullptr;
+
+		std::string error = "{\"message\":\"The browser window has been destroyed\"}";
+		for ( const auto& entry : pending )
+			entry.second.first( bw, entry.second.second, FALSE, { error.size(), error.c_str() } );
+
+		delete devtools;
+	}
+}
+
+double bw_BrowserWindow_getZoom( bw_BrowserWindow* bw ) {
+	double factor = 1.0;
+	((ICoreWebView2Controller*)bw->impl.controller)->get_ZoomFactor( &factor );
+	return factor;
+}
+
+bw_Err bw_BrowserWindow_navigate( bw_BrowserWindow* bw, bw_CStrSlice url ) {
+	ICoreWebView2* webview = (ICoreWebView2*)bw->impl.webview;
+
+	std::wstring wurl = bw_webview2_toWstring( url );
+	HRESULT hr = webview->Navigate( wurl.c_str() );
+	if ( FAILED( hr ) )
+		return bw_win32_unhandledHresult( hr );
+
+	BW_ERR_RETURN_SUCCESS;
+}
+
+void bw_BrowserWindow_reload( bw_BrowserWindow* bw, BOOL ignore_cache ) {
+	ICoreWebView2* webview = (ICoreWebView2*)bw->impl.webview;
+
+	// WebView2 has no reload that skips the cache, but the DevTools Protocol does
+	if ( ignore_cache )
+		webview->CallDevToolsProtocolMethod( L"Page.reload", L"{\"ignoreCache\":true}", nullptr );
+	else
+		webview->Reload();
+}
+
+BOOL bw_BrowserWindow_navigateFrame( bw_BrowserWindow* bw, int64_t frame_id, bw_CStrSlice url ) {
+
+	if ( frame_id != 0 )
+		return FALSE;
+
+	bw_Err error = bw_BrowserWindow_navigate( bw, url );
+	if ( BW_ERR_IS_FAIL( error ) ) {
+		bw_Err_free( &error );
+		return FALSE;
+	}
+
+	return TRUE;
+}
+
+// WebView2 has no way to override the encoding of a page.
+void bw_BrowserWindow_setCharsetOverride( bw_BrowserWindow* bw, bw_CStrSlice charset ) {
+	UNUSED( bw );
+	UNUSED( charset );
+}
+
+void bw_BrowserWindow_setZoom( bw_BrowserWindow* bw, double factor ) {
+	((ICoreWebView2Controller*)bw->impl.controller)->put_ZoomFactor( factor );
+}
+
+void bw_BrowserWindow_subscribeDevToolsEvent( bw_BrowserWindow* bw, bw_CStrSlice event ) {
+	ICoreWebView2* webview = (ICoreWebView2*)bw->impl.webview;
+	std::shared_ptr<bw_BrowserWindowWebView2DevTools> devtools = bw_BrowserWindowWebView2_devTools( bw );
+
+	std::string name( event.data, event.len );
+	if ( !devtools->events.insert( name ).second )
+		return;
+
+	// WebView2 has a receiver for every event
+	ComPtr<ICoreWebView2DevToolsProtocolEventReceiver> receiver;
+	std::wstring wide_name = bw_webview2_toWstring( name );
+	if ( FAILED( webview->GetDevToolsProtocolEventReceiver( wide_name.c_str(), &receiver ) ) )
+		return;
+
+	EventRegistrationToken token;
+	receiver->add_DevToolsProtocolEventReceived( Callback<ICoreWebView2DevToolsProtocolEventReceivedEventHandler>(
+		[devtools, name]( ICoreWebView2* sender, ICoreWebView2DevToolsProtocolEventReceivedEventArgs* args ) -> HRESULT {
+			UNUSED( sender );
+			bw_BrowserWindow* bw = devtools->bw;
+			if ( bw == nullptr || bw->on_devtools_event == 0 )
+				return S_OK;
+
+			LPWSTR params_json;
+			if ( FAILED( args->get_ParameterObjectAsJson( &params_json ) ) )
+				return S_OK;
+			std::string params = bw_webview2_toUtf8( params_json );
+			CoTaskMemFree( params_json );
+
+			bw->on_devtools_event( bw, { name.size(), name.c_str() }, { params.size(), params.c_str() } );
+			return S_OK;
+		}
+	).Get(), &token );
+}
+
+// WebView2 doesn't render offscreen, so there are no offscreen browsers to send input to.
+
+void bw_BrowserWindow_invalidate( bw_BrowserWindow* bw ) {
+	UNUSED( bw );
+}
+
+void bw_BrowserWindow_sendFocus( bw_BrowserWindow* bw, BOOL focused ) {
+	UNUSED( bw );
+	UNUSED( focused );
+}
+
+void bw_BrowserWindow_sendKey( bw_BrowserWindow* bw, uint8_t kind, int key_code, uint32_t character, uint32_t modifiers ) {
+	UNUSED( bw );
+	UNUSED( kind );
+	UNUSED( key_code );
+	UNUSED( character );
+	UNUSED( modifiers );
+}
+
+void bw_BrowserWindow_sendMouseButton( bw_BrowserWindow* bw, int x, int y, uint8_t button, BOOL released, int click_count, uint32_t modifiers ) {
+	UNUSED( bw );
+	UNUSED( x );
+	UNUSED( y );
+	UNUSED( button );
+	UNUSED( released );
+	UNUSED( click_count );
+	UNUSED( modifiers );
+}
+
+void bw_BrowserWindow_sendMouseMove( bw_BrowserWindow* bw, int x, int y, BOOL leave, uint32_t modifiers ) {
+	UNUSED( bw );
+	UNUSED( x );
+	UNUSED( y );
+	UNUSED( leave );
+	UNUSED( modifiers );
+}
+
+void bw_BrowserWindow_sendMouseWheel( bw_BrowserWindow* bw, int x, int y, int delta_x, int delta_y, uint32_t modifiers ) {
+	UNUSED( bw );
+	UNUSED( x );
+	UNUSED( y );
+	UNUSED( delta_x );
+	UNUSED( delta_y );
+	UNUSED( modifiers );
+}
+
+// WebView2 has no way to answer its file dialogs, so the file chooser handler is never invoked
+void bw_FileChooser_select( bw_FileChooser* chooser, const bw_CStrSlice* paths, size_t path_count ) {
+	UNUSED( chooser );
+	UNUSED( paths );
+	UNUSED( path_count );
+}
+
+void bw_BrowserWindowImpl_new(
+	bw_BrowserWindow* browser,
+	bw_BrowserWindowSource source,
+	int width, int height,
+	const bw_BrowserWindowOptions* browser_window_options,
+	bw_BrowserWindowCreationCallbackFn callback,
+	void* callback_data
+) {
+	UNUSED( width );
+	UNUSED( height );
+
+	browser->impl.controller = 0;
+	browser->impl.webview = 0;
+	browser->impl.devtools = 0;
+
+	// The options are not available anymore when the controller has been created, so everything is copied.
+	// TODO: Support `resource_path` and `default_encoding`.
+	bw_BrowserWindowWebView2Creation* creation = new bw_BrowserWindowWebView2Creation;
+	creation->browser = browser;
+	creation->source_data = std::string( source.data.data, source.data.len );
+	creation->is_html = source.is_html;
+	creation->dev_tools = browser_window_options->dev_tools;
+	creation->preload_script = std::string( browser_window_options->preload_script.data, browser_window_options->preload_script.len );
+	creation->callback = callback;
+	creation->callback_data = callback_data;
+
+	HRESULT hr = bw_webview2_createEnvironment( browser->window->app, Callback<ICoreWebView2CreateCoreWebView2EnvironmentCompletedHandler>(
+		[creation]( HRESULT result, ICoreWebView2Environment* environment ) -> HRESULT {
+
+			if ( FAILED( result ) ) {
+				BW_WIN32_PANIC_HRESULT( result );
+				return result;
+			}
+
+			HWND hwnd = creation->browser->window->impl.handle;
+			creation->environment = environment;
+
+			return environment->CreateCoreWebView2Controller( hwnd, Callback<ICoreWebView2CreateCoreWebView2ControllerCompletedHandler>(
+				[creation]( HRESULT result, ICoreWebView2Controller* controller ) -> HRESULT {
+					bw_BrowserWindowWebView2_onControllerCreated( creation, result, controller );
+					return S_OK;
+				}
+			).Get() );
+		}
+	).Get() );
+
+	if ( FAILED( hr ) )
+		BW_WIN32_PANIC_HRESULT( hr );
+}
+
+// The requests that match the filters are passed on by the handler of `WebResourceRequested`, see `bw_BrowserWindowWebView2_interceptRequest`
+void bw_BrowserWindowImpl_setRequestFilters( bw_BrowserWindow* bw ) {
+	ICoreWebView2* webview = (ICoreWebView2*)bw->impl.webview;
+	std::string filters( bw->request_filters );
+
+	size_t start = 0;
+	while ( start < filters.size() ) {
+		size_t end = filters.find( '\n', start );
+		if ( end == std::string::npos )
+			end = filters.size();
+
+		if ( end > start )
+			webview->AddWebResourceRequestedFilter( bw_webview2_toWstring( filters.substr( start, end - start ) ).c_str(), COREWEBVIEW2_WEB_RESOURCE_CONTEXT_ALL );
+		start = end + 1;
+	}
+}
+
+void bw_BrowserWindowImpl_onResize( const bw_Window* window, unsigned int width, unsigned int height ) {
+	bw_BrowserWindow* bw = (bw_BrowserWindow*)window->user_data;
+
+	// Only do something when our browser window object and the underlying WebView2 controller has been created.
+	if ( bw != 0 && bw->impl.controller != 0 ) {
+		RECT bounds = { 0, 0, (LONG)width, (LONG)height };
+		((ICoreWebView2Controller*)bw->impl.controller)->put_Bounds( bounds );
+	}
+}
+
+
+
+void bw_BrowserWindowWebView2_evalJsDispatched( bw_Application* app, void* _data ) {
+	UNUSED( app );
+	bw_BrowserWindowWebView2EvalJsData* data = (bw_BrowserWindowWebView2EvalJsData*)_data;
+
+	bw_CStrSlice js = { data->js.length(), data->js.c_str() };
+	bw_BrowserWindow_evalJs( data->bw, js, data->cb, data->user_data );
+
+	delete data;
+}
+
+void bw_BrowserWindowWebView2_invokeHandler( bw_BrowserWindow* bw, LPCWSTR json ) {
+
+	// `invoke_extern` posts its arguments as an array of strings, of which the first one is the command.
+	std::vector<std::string> strings;
+	if ( !bw_webview2_parseJsonStringArray( bw_webview2_toUtf8( json ), strings ) || strings.size() == 0 ) {
+		BW_DEBUG( "Invalid message received from WebView2" );
+		return;
+	}
+
+	bw_CStrSlice cmd = { strings[0].length(), strings[0].c_str() };
+
+	std::vector<bw_CStrSlice> args;
+	for ( size_t i = 1; i < strings.size(); i++ ) {
+		bw_CStrSlice arg = { strings[i].length(), strings[i].c_str() };
+		args.push_back( arg );
+	}
+
+	bw->external_handler( bw, cmd, args.data(), args.size() );
+}
+
+void bw_BrowserWindowWebView2_onControllerCreated( bw_BrowserWindowWebView2Creation* creation, HRESULT result, ICoreWebView2Controller* controller ) {
+	bw_BrowserWindow* bw = creation->browser;
+
+	if ( FAILED( result ) )
+		BW_WIN32_PANIC_HRESULT( result );
+
+	ICoreWebView2* webview;
+	controller->AddRef();
+	controller->get_CoreWebView2( &webview );
+	bw->impl.controller = (void*)controller;
+	bw->impl.webview = (void*)webview;
+
+	// Fill the whole window
+	RECT bounds;
+	GetClientRect( bw->window->impl.handle, &bounds );
+	controller->put_Bounds( bounds );
+
+	// Let the backdrop of the window show through where the page has no background of its own
+	if ( bw->window->impl.has_backdrop ) {
+		ICoreWebView2Controller2* controller2;
+		if ( SUCCEEDED( controller->QueryInterface( IID_PPV_ARGS( &controller2 ) ) ) ) {
+			COREWEBVIEW2_COLOR transparent = { 0, 0, 0, 0 };
+			controller2->put_DefaultBackgroundColor( transparent );
+			controller2->Release();
+		}
+	}
+
+	// Lets the key handler and the registered accelerators catch keys before the page gets to see them
+	controller->add_AcceleratorKeyPressed( Callback<ICoreWebView2AcceleratorKeyPressedEventHandler>(
+		[bw]( ICoreWebView2Controller* sender, ICoreWebView2AcceleratorKeyPressedEventArgs* args ) -> HRESULT {
+			UNUSED( sender );
+
+			COREWEBVIEW2_KEY_EVENT_KIND kind;
+			args->get_KeyEventKind( &kind );
+			BOOL pressed = kind == COREWEBVIEW2_KEY_EVENT_KIND_KEY_DOWN || kind == COREWEBVIEW2_KEY_EVENT_KIND_SYSTEM_KEY_DOWN;
+
+			UINT key;
+			args->get_VirtualKey( &key );
+			COREWEBVIEW2_PHYSICAL_KEY_STATUS status;
+			args->get_PhysicalKeyStatus( &status );
+
+			BOOL ctrl = (GetKeyState( VK_CONTROL ) & 0x8000) != 0;
+			BOOL shift = (GetKeyState( VK_SHIFT ) & 0x8000) != 0;
+			BOOL alt = (GetKeyState( VK_MENU ) & 0x8000) != 0;
+			BOOL super = ((GetKeyState( VK_LWIN ) | GetKeyState( VK_RWIN )) & 0x8000) != 0;
+			uint32_t modifiers = (ctrl ? BW_MODIFIER_CTRL : 0) | (shift ? BW_MODIFIER_SHIFT : 0) | (alt ? BW_MODIFIER_ALT : 0) | (super ? BW_MODIFIER_SUPER : 0);
+
+			if ( bw_BrowserWindow_handleKey( bw, pressed ? 0 : 1, (uint16_t)key, modifiers, status.WasKeyDown && pressed ) ) {
+				args->put_Handled( TRUE );
+				return S_OK;
+			}
+			if ( !pressed )
+				return S_OK;
+
+			uint32_t accelerator_id;
+			BOOL found = bw_BrowserWindow_findAccelerator( bw, (uint16_t)key, ctrl, shift, alt, super, &accelerator_id );
+			if ( found ) {
+				args->put_Handled( TRUE );
+
+				if ( bw->on_accelerator != 0 )
+					bw->on_accelerator( bw, accelerator_id );
+			}
+			return S_OK;
+		}
+	).Get(), nullptr );
+
+	ICoreWebView2Settings* settings;
+	webview->get_Settings( &settings );
+	settings->put_AreDevToolsEnabled( creation->dev_tools );
+	settings->put_IsWebMessageEnabled( TRUE );
+	settings->Release();
+
+	webview->add_WebMessageReceived( Callback<ICoreWebView2WebMessageReceivedEventHandler>(
+		[bw]( ICoreWebView2* sender, ICoreWebView2WebMessageReceivedEventArgs* args ) -> HRESULT {
+			UNUSED( sender );
+
+			LPWSTR json;
+			if ( SUCCEEDED( args->get_WebMessageAsJson( &json ) ) ) {
+				bw_BrowserWindowWebView2_invokeHandler( bw, json );
+				CoTaskMemFree( json );
+			}
+			return S_OK;
+		}
+	).Get(), nullptr );
+
+	// WebView2 only reports the source of the main frame
+	webview->add_SourceChanged( Callback<ICoreWebView2SourceChangedEventHandler>(
+		[bw]( ICoreWebView2* sender, ICoreWebView2SourceChangedEventArgs* args ) -> HRESULT {
+			UNUSED( args );
+
+			LPWSTR url;
+			if ( bw->on_navigate != 0 && SUCCEEDED( sender->get_Source( &url ) ) ) {
+				std::string utf8 = bw_webview2_toUtf8( url );
+				CoTaskMemFree( url );
+
+				bw_CStrSlice slice = { utf8.size(), utf8.c_str() };
+				bw->on_navigate( bw, slice );
+			}
+			return S_OK;
+		}
+	).Get(), nullptr );
+
+	// WebView2 doesn't report how far a load has come, so the progress moves through the steps that it does report
+	webview->add_NavigationStarting( Callback<ICoreWebView2NavigationStartingEventHandler>(
+		[bw]( ICoreWebView2* sender, ICoreWebView2NavigationStartingEventArgs* args ) -> HRESULT {
+			UNUSED( sender );
+			UNUSED( args );
+
+			if ( bw->on_loading_progress != 0 )
+				bw->on_loading_progress( bw, 0.1 );
+			return S_OK;
+		}
+	).Get(), nullptr );
+
+	webview->add_ContentLoading( Callback<ICoreWebView2ContentLoadingEventHandler>(
+		[bw]( ICoreWebView2* sender, ICoreWebView2ContentLoadingEventArgs* args ) -> HRESULT {
+			UNUSED( sender );
+			UNUSED( args );
+
+			if ( bw->on_loading_progress != 0 )
+				bw->on_loading_progress( bw, 0.5 );
+			return S_OK;
+		}
+	).Get(), nullptr );
+
+	webview->add_NavigationCompleted( Callback<ICoreWebView2NavigationCompletedEventHandler>(
+		[bw]( ICoreWebView2* sender, ICoreWebView2NavigationCompletedEventArgs* args ) -> HRESULT {
+			UNUSED( sender );
+
+			if ( bw->on_loading_progress != 0 )
+				bw->on_loading_progress( bw, 1.0 );
+
+			BOOL success;
+			COREWEBVIEW2_WEB_ERROR_STATUS status;
+			if ( bw->on_load == 0 || FAILED( args->get_IsSuccess( &success ) ) )
+				return S_OK;
+
+			if ( success )
+				bw->on_load( bw, 0 );
+			else if ( SUCCEEDED( args->get_WebErrorStatus( &status ) ) ) {
+				// Navigations are canceled when another navigation takes their place, which is reported on its own
+				if ( status == COREWEBVIEW2_WEB_ERROR_STATUS_OPERATION_CANCELED )
+					return S_OK;
+
+				std::string message = "unable to load the page, web error status " + std::to_string( (int)status );
+				bw_Err error = bw_Err_new_with_msg( (bw_ErrCode)status, message.c_str() );
+				bw->on_load( bw, &error );
+				bw_Err_free( &error );
+			}
+			return S_OK;
+		}
+	).Get(), nullptr );
+
+	webview->add_DocumentTitleChanged( Callback<ICoreWebView2DocumentTitleChangedEventHandler>(
+		[bw]( ICoreWebView2* sender, IUnknown* args ) -> HRESULT {
+			UNUSED( args );
+
+			LPWSTR title;
+			if ( bw->on_title_change != 0 && SUCCEEDED( sender->get_DocumentTitle( &title ) ) ) {
+				std::string utf8 = bw_webview2_toUtf8( title );
+				CoTaskMemFree( title );
+
+				bw_CStrSlice slice = { utf8.size(), utf8.c_str() };
+				bw->on_title_change( bw, slice );
+			}
+			return S_OK;
+		}
+	).Get(), nullptr );
+
+	// Only the failures of the render process of the page itself are reported, the other processes are restarted by WebView2
+	webview->add_ProcessFailed( Callback<ICoreWebView2ProcessFailedEventHandler>(
+		[bw]( ICoreWebView2* sender, ICoreWebView2ProcessFailedEventArgs* args ) -> HRESULT {
+			UNUSED( sender );
+
+			COREWEBVIEW2_PROCESS_FAILED_KIND kind;
+			if ( FAILED( args->get_ProcessFailedKind( &kind ) ) )
+				return S_OK;
+
+			if ( kind == COREWEBVIEW2_PROCESS_FAILED_KIND_RENDER_PROCESS_UNRESPONSIVE ) {
+				if ( bw->on_unresponsive != 0 )
+					bw->on_unresponsive( bw, TRUE );
+			}
+			else if ( kind == COREWEBVIEW2_PROCESS_FAILED_KIND_RENDER_PROCESS_EXITED && bw->on_render_process_terminated != 0 ) {
+				uint8_t reason = 0;
+
+				ComPtr<ICoreWebView2ProcessFailedEventArgs2> args2;
+				COREWEBVIEW2_PROCESS_FAILED_REASON failed_reason;
+				if ( SUCCEEDED( args->QueryInterface( IID_PPV_ARGS( &args2 ) ) ) && SUCCEEDED( args2->get_Reason( &failed_reason ) ) ) {
+					switch ( failed_reason ) {
+					case COREWEBVIEW2_PROCESS_FAILED_REASON_TERMINATED:	reason = 1; break;
+					case COREWEBVIEW2_PROCESS_FAILED_REASON_CRASHED:	reason = 2; break;
+					case COREWEBVIEW2_PROCESS_FAILED_REASON_OUT_OF_MEMORY:	reason = 3; break;
+					default:	break;
+					}
+				}
+
+				bw->on_render_process_terminated( bw, reason );
+			}
+			return S_OK;
+		}
+	).Get(), nullptr );
+
+	// Permissions are decided by the permission handler, or left to WebView2 when it has no answer
+	webview->add_PermissionRequested( Callback<ICoreWebView2PermissionRequestedEventHandler>(
+		[bw]( ICoreWebView2* sender, ICoreWebView2PermissionRequestedEventArgs* args ) -> HRESULT {
+			UNUSED( sender );
+
+			COREWEBVIEW2_PERMISSION_KIND kind;
+			args->get_PermissionKind( &kind );
+
+			uint8_t permission;
+			if ( kind == COREWEBVIEW2_PERMISSION_KIND_CAMERA || kind == COREWEBVIEW2_PERMISSION_KIND_MICROPHONE )
+				permission = 0;
+			else if ( kind == COREWEBVIEW2_PERMISSION_KIND_CLIPBOARD_READ )
+				permission = 1;
+			else
+				return S_OK;
+
+			LPWSTR uri;
+			if ( FAILED( args->get_Uri( &uri ) ) )
+				return S_OK;
+			int decision = bw_BrowserWindowWebView2_checkPermission( bw, permission, uri );
+			CoTaskMemFree( uri );
+
+			if ( decision == 1 )
+				args->put_State( COREWEBVIEW2_PERMISSION_STATE_ALLOW );
+			else if ( decision == 0 )
+				args->put_State( COREWEBVIEW2_PERMISSION_STATE_DENY );
+			return S_OK;
+		}
+	).Get(), nullptr );
+
+	webview->add_NewWindowRequested( Callback<ICoreWebView2NewWindowRequestedEventHandler>(
+		[bw]( ICoreWebView2* sender, ICoreWebView2NewWindowRequestedEventArgs* args ) -> HRESULT {
+
+			// Marking the request as handled without providing a new window cancels the popup
+			LPWSTR source;
+			if ( SUCCEEDED( sender->get_Source( &source ) ) ) {
+				if ( bw_BrowserWindowWebView2_checkPermission( bw, 2, source ) == 0 )
+					args->put_Handled( TRUE );
+				CoTaskMemFree( source );
+			}
+			return S_OK;
+		}
+	).Get(), nullptr );
+
+	// Downloads are only reported by newer versions of WebView2
+	ICoreWebView2_4* webview4;
+	if ( SUCCEEDED( webview->QueryInterface( IID_PPV_ARGS( &webview4 ) ) ) ) {
+		webview4->add_DownloadStarting( Callback<ICoreWebView2DownloadStartingEventHandler>(
+			[bw]( ICoreWebView2* sender, ICoreWebView2DownloadStartingEventArgs* args ) -> HRESULT {
+
+				LPWSTR source;
+				if ( SUCCEEDED( sender->get_Source( &source ) ) ) {
+					if ( bw_BrowserWindowWebView2_checkPermission( bw, 3, source ) == 0 )
+						args->put_Cancel( TRUE );
+					CoTaskMemFree( source );
+				}
+				return S_OK;
+			}
+		).Get(), nullptr );
+		webview4->Release();
+	}
+
+	// Favicons are only reported by newer versions of WebView2, which give them as PNG images
+	ICoreWebView2_15* webview15;
+	if ( SUCCEEDED( webview->QueryInterface( IID_PPV_ARGS( &webview15 ) ) ) ) {
+		webview15->add_FaviconChanged( Callback<ICoreWebView2FaviconChangedEventHandler>(
+			[bw]( ICoreWebView2* sender, IUnknown* args ) -> HRESULT {
+				UNUSED( args );
+
+				ComPtr<ICoreWebView2_15> sender15;
+				if ( bw->on_favicon_changed == 0 || FAILED( sender->QueryInterface( IID_PPV_ARGS( &sender15 ) ) ) )
+					return S_OK;
+
+				sender15->GetFavicon( COREWEBVIEW2_FAVICON_IMAGE_FORMAT_PNG, Callback<ICoreWebView2GetFaviconCompletedHandler>(
+					[bw]( HRESULT error_code, IStream* stream ) -> HRESULT {
+						if ( FAILED( error_code ) || stream == nullptr || bw->on_favicon_changed == 0 )
+							return S_OK;
+
+						std::vector<char> png;
+						char buffer[4096];
+						ULONG read;
+						while ( SUCCEEDED( stream->Read( buffer, sizeof( buffer ), &read ) ) && read > 0 )
+							png.insert( png.end(), buffer, buffer + read );
+
+						// Pages without a favicon give an empty image
+						if ( png.size() > 0 )
+							bw->on_favicon_changed( bw, png.data(), png.size(), 0, 0 );
+						return S_OK;
+					}
+				).Get() );
+				return S_OK;
+			}
+		).Get(), nullptr );
+		webview15->Release();
+	}
+
+	ICoreWebView2_12* webview12;
+	if ( SUCCEEDED( webview->QueryInterface( IID_PPV_ARGS( &webview12 ) ) ) ) {
+		webview12->add_StatusBarTextChanged( Callback<ICoreWebView2StatusBarTextChangedEventHandler>(
+			[bw]( ICoreWebView2* sender, IUnknown* args ) -> HRESULT {
+				UNUSED( args );
+
+				ComPtr<ICoreWebView2_12> sender12;
+				LPWSTR text;
+				if ( bw->on_status_text == 0 || FAILED( sender->QueryInterface( IID_PPV_ARGS( &sender12 ) ) ) || FAILED( sender12->get_StatusBarText( &text ) ) )
+					return S_OK;
+
+				std::string utf8 = bw_webview2_toUtf8( text );
+				CoTaskMemFree( text );
+
+				bw_CStrSlice slice = { utf8.size(), utf8.c_str() };
+				bw->on_status_text( bw, slice );
+				return S_OK;
+			}
+		).Get(), nullptr );
+		webview12->Release();
+	}
+
+	// Capturing the screen is only reported by newer versions of WebView2, which always let the user pick what to share
+	ICoreWebView2_27* webview27;
+	if ( SUCCEEDED( webview->QueryInterface( IID_PPV_ARGS( &webview27 ) ) ) ) {
+		webview27->add_ScreenCaptureStarting( Callback<ICoreWebView2ScreenCaptureStartingEventHandler>(
+			[bw]( ICoreWebView2* sender, ICoreWebView2ScreenCaptureStartingEventArgs* args ) -> HRESULT {
+
+				LPWSTR source;
+				if ( SUCCEEDED( sender->get_Source( &source ) ) ) {
+					if ( bw_BrowserWindowWebView2_checkPermission( bw, 4, source ) == 0 )
+						args->put_Cancel( TRUE );
+					CoTaskMemFree( source );
+				}
+				return S_OK;
+			}
+		).Get(), nullptr );
+		webview27->Release();
+	}
+
+	// The requests of the custom schemes are answered by the scheme handler of the browser window, the other requests are passed to the request handler
+	bw_WebView2EnvironmentOptions* environment_options = (bw_WebView2EnvironmentOptions*)bw->window->app->engine_impl.environment_options;
+	for ( const std::wstring& scheme : environment_options->custom_schemes )
+		webview->AddWebResourceRequestedFilter( (scheme + L":*").c_str(), COREWEBVIEW2_WEB_RESOURCE_CONTEXT_ALL );
+
+	ComPtr<ICoreWebView2Environment> environment = creation->environment;
+	webview->add_WebResourceRequested( Callback<ICoreWebView2WebResourceRequestedEventHandler>(
+		[bw, environment, environment_options]( ICoreWebView2* sender, ICoreWebView2WebResourceRequestedEventArgs* args ) -> HRESULT {
+			UNUSED( sender );
+
+			ComPtr<ICoreWebView2WebResourceRequest> request;
+			LPWSTR uri;
+			if ( FAILED( args->get_Request( &request ) ) || FAILED( request->get_Uri( &uri ) ) )
+				return S_OK;
+			std::wstring scheme( uri, wcscspn( uri, L":" ) );
+			CoTaskMemFree( uri );
+
+			bool is_custom = false;
+			for ( const std::wstring& custom_scheme : environment_options->custom_schemes )
+				is_custom = is_custom || _wcsicmp( custom_scheme.c_str(), scheme.c_str() ) == 0;
+
+			if ( is_custom )
+				bw_BrowserWindowWebView2_requestScheme( bw, environment, args );
+			else
+				bw_BrowserWindowWebView2_interceptRequest( bw, environment, args );
+			return S_OK;
+		}
+	).Get(), nullptr );
+
+	std::string preload_script = BW_WEBVIEW2_INVOKE_EXTERN_JS;
+	preload_script += creation->preload_script;
+	webview->AddScriptToExecuteOnDocumentCreated( bw_webview2_toWstring( preload_script ).c_str(), nullptr );
+
+	std::wstring source = bw_webview2_toWstring( creation->source_data );
+	if ( creation->is_html )
+		webview->NavigateToString( source.c_str() );
+	else
+		webview->Navigate( source.c_str() );
+
+	creation->callback( bw, creation->callback_data );
+	delete creation;
+}
+
+int bw_BrowserWindowWebView2_checkPermission( bw_BrowserWindow* bw, uint8_t permission, LPCWSTR url ) {
+	std::string utf8 = bw_webview2_toUtf8( url );
+	bw_CStrSlice slice = { utf8.size(), utf8.c_str() };
+
+	return bw_BrowserWindow_checkPermission( bw, permission, slice );
+}
+
+void bw_BrowserWindowWebView2_interceptRequest( bw_BrowserWindow* bw, ComPtr<ICoreWebView2Environment> environment, ICoreWebView2WebResourceRequestedEventArgs* args ) {
+	ComPtr<ICoreWebView2WebResourceRequest> request;
+	if ( FAILED( args->get_Request( &request ) ) )
+		return;
+
+	LPWSTR method, uri;
+	request->get_Method( &method );
+	request->get_Uri( &uri );
+	std::string method_utf8 = bw_webview2_toUtf8( method );
+	std::string uri_utf8 = bw_webview2_toUtf8( uri );
+	CoTaskMemFree( method );
+	CoTaskMemFree( uri );
+
+	std::string headers = bw_BrowserWindowWebView2_requestHeaders( request.Get() );
+
+	// WebView2 waits with the request until the deferral is completed, which is done on the GUI thread along with the decision
+	ComPtr<ICoreWebView2WebResourceRequestedEventArgs> args_ptr = args;
+	ComPtr<ICoreWebView2Deferral> deferral;
+	args->GetDeferral( &deferral );
+
+	bw_InterceptedRequest_dispatch( bw, method_utf8, uri_utf8, headers,
+		[bw, environment, args_ptr, deferral, request, headers]( bw_InterceptedRequestAction action, std::string value, bw_SchemeRequest* response ) {
+			switch ( action ) {
+			case BW_REQUEST_BLOCK: {
+				ComPtr<ICoreWebView2WebResourceResponse> blocked;
+				if ( SUCCEEDED( environment->CreateWebResourceResponse( nullptr, 403, L"Forbidden", L"", &blocked ) ) )
+					args_ptr->put_Response( blocked.Get() );
+				break;
+			}
+			case BW_REQUEST_CONTINUE: {
+				ComPtr<ICoreWebView2HttpRequestHeaders> request_headers;
+				if ( FAILED( request->get_Headers( &request_headers ) ) )
+					break;
+
+				// The headers are replaced as a whole, so the old ones are removed first
+				for ( const auto& header : bw_BrowserWindowWebView2_parseHeaders( headers ) )
+					request_headers->RemoveHeader( bw_webview2_toWstring( header.first ).c_str() );
+				for ( const auto& header : bw_BrowserWindowWebView2_parseHeaders( value ) )
+					request_headers->SetHeader( bw_webview2_toWstring( header.first ).c_str(), bw_webview2_toWstring( header.second ).c_str() );
+				break;
+			}
+			case BW_REQUEST_REDIRECT:
+				request->put_Uri( bw_webview2_toWstring( value ).c_str() );
+				break;
+			case BW_REQUEST_RESPOND: {
+				auto data = new bw_BrowserWindowWebView2SchemeResponse;
+				data->bw = bw;
+				data->request = response;
+				data->environment = environment;
+				data->args = args_ptr;
+				data->deferral = deferral;
+
+				// The response is given to WebView2 in the same way as the one of a custom scheme, once the application has responded
+				std::unique_lock<std::mutex> lock( response->mutex );
+				if ( response->responded ) {
+					lock.unlock();
+					bw_Application_dispatch( bw->window->app, bw_BrowserWindowWebView2_respondToScheme, data );
+				}
+				else
+					response->on_response = [data]() {
+						bw_Application_dispatch( data->bw->window->app, bw_BrowserWindowWebView2_respondToScheme, data );
+					};
+				return;
+			}
+			}
+
+			deferral->Complete();
+		}
+	);
+}
+
+void bw_BrowserWindowWebView2_requestScheme( bw_BrowserWindow* bw, ComPtr<ICoreWebView2Environment> environment, ICoreWebView2WebResourceRequestedEventArgs* args ) {
+	ComPtr<ICoreWebView2WebResourceRequest> request;
+	if ( FAILED( args->get_Request( &request ) ) )
+		return;
+
+	LPWSTR method, uri;
+	request->get_Method( &method );
+	request->get_Uri( &uri );
+	std::string method_utf8 = bw_webview2_toUtf8( method );
+	std::string uri_utf8 = bw_webview2_toUtf8( uri );
+	CoTaskMemFree( method );
+	CoTaskMemFree( uri );
+
+	std::string headers = bw_BrowserWindowWebView2_requestHeaders( request.Get() );
+
+	std::string body;
+	ComPtr<IStream> content;
+	if ( SUCCEEDED( request->get_Content( &content ) ) && content != nullptr ) {
+		char buffer[4096];
+		ULONG read = 0;
+		while ( SUCCEEDED( content->Read( buffer, sizeof( buffer ), &read ) ) && read > 0 )
+			body.append( buffer, read );
+	}
+
+	// WebView2 waits for the response until the deferral is completed
+	auto response = new bw_BrowserWindowWebView2SchemeResponse;
+	response->bw = bw;
+	response->request = nullptr;
+	response->environment = environment;
+	response->args = args;
+	args->GetDeferral( &response->deferral );
+
+	// The response is given on the GUI thread, as the scheme handler may respond from any thread.
+	// This is also the thread that we're on, so the dispatched function can't run before the request has been stored.
+	response->request = bw_SchemeRequest_new( bw, method_utf8, uri_utf8, headers, body, [response]() {
+		bw_Application_dispatch( response->bw->window->app, bw_BrowserWindowWebView2_respondToScheme, response );
+	} );
+}
+
+std::vector<std::pair<std::string, std::string>> bw_BrowserWindowWebView2_parseHeaders( const std::string& lines ) {
+	std::vector<std::pair<std::string, std::string>> headers;
+
+	size_t start = 0;
+	while ( start < lines.size() ) {
+		size_t end = lines.find( '\n', start );
+		if ( end == std::string::npos )
+			end = lines.size();
+
+		std::string line = lines.substr( start, end - start );
+		if ( !line.empty() && line.back() == '\r' )
+			line.pop_back();
+
+		size_t colon = line.find( ':' );
+		if ( colon != std::string::npos ) {
+			size_t value = line.find_first_not_of( ' ', colon + 1 );
+			headers.push_back( std::make_pair( line.substr( 0, colon ), value == std::string::npos ? std::string() : line.substr( value ) ) );
+		}
+		start = end + 1;
+	}
+	return headers;
+}
+
+std::string bw_BrowserWindowWebView2_requestHeaders( ICoreWebView2WebResourceRequest* request ) {
+	std::string headers;
+	ComPtr<ICoreWebView2HttpRequestHeaders> request_headers;
+	ComPtr<ICoreWebView2HttpHeadersCollectionIterator> iterator;
+	if ( SUCCEEDED( request->get_Headers( &request_headers ) ) && SUCCEEDED( request_headers->GetIterator( &iterator ) ) ) {
+		BOOL has_header = FALSE;
+		while ( SUCCEEDED( iterator->get_HasCurrentHeader( &has_header ) ) && has_header ) {
+			LPWSTR name, value;
+			if ( SUCCEEDED( iterator->GetCurrentHeader( &name, &value ) ) ) {
+				headers += bw_webview2_toUtf8( name ) + ": " + bw_webview2_toUtf8( value ) + "\r\n";
+				CoTaskMemFree( name );
+				CoTaskMemFree( value );
+			}
+
+			BOOL has_next;
+			if ( FAILED( iterator->MoveNext( &has_next ) ) || !has_next )
+				break;
+		}
+	}
+	return headers;
+}
+
+void bw_BrowserWindowWebView2_respondToScheme( bw_Application* app, void* _data ) {
+	UNUSED( app );
+	bw_BrowserWindowWebView2SchemeResponse* data = (bw_BrowserWindowWebView2SchemeResponse*)_data;
+
+	uint16_t status;
+	std::wstring headers;
+	{
+		std::lock_guard<std::mutex> lock( data->request->mutex );
+
+		status = data->request->status;
+		for ( const auto& header : data->request->headers )
+			headers += bw_webview2_toWstring( header.first ) + L": " + bw_webview2_toWstring( header.second ) + L"\r\n";
+	}
+
+	// The stream takes over the reference of the browser engine to the request
+	ComPtr<IStream> stream = Make<bw_BrowserWindowWebView2SchemeStream>( data->request );
+	ComPtr<ICoreWebView2WebResourceResponse> response;
+	if ( SUCCEEDED( data->environment->CreateWebResourceResponse( stream.Get(), status, L"", headers.c_str(), &response ) ) )
+		data->args->put_Response( response.Get() );
+
+	data->deferral->Complete();
+	delete data;
+}
+
+std::shared_ptr<bw_BrowserWindowWebView2DevTools> bw_BrowserWindowWebView2_devTools( bw_BrowserWindow* bw ) {
+	if ( bw->impl.devtools == 0 ) {
+		auto devtools = std::make_shared<bw_BrowserWindowWebView2DevTools>();
+		devtools->bw = bw;
+		bw->impl.devtools = new std::shared_ptr<bw_BrowserWindowWebView2DevTools>( devtools );
+	}
+
+	return *(std::shared_ptr<bw_BrowserWindowWebView2DevTools>*)bw->impl.devtools;
+}
+
+std::string bw_BrowserWindowWebView2_wrapJs( bw_CStrSlice js ) {
+
+	// Just like with CEF, the code is wrapped within a temporary function, so that code that isn't terminated with a semicolon can be executed too.
+	// The value is converted to a string inside JS.
+	// Exceptions are caught, so that their details can be passed on instead of just `null`.
+	// The position of the exception is taken from the stack, without the part of the wrapper that comes before the code on the first line.
+	std::string prefix = "(function () { try { return [\"ok\", String((function () { return ";
+	std::string code = prefix;
+	code.append( js.data, js.len );
+	code += "; })())]; } catch (e) {"
+		" var m = /<anonymous>:(\\d+):(\\d+)/.exec(String(e && e.stack));"
+		" var line = m ? +m[1] : 0, column = m ? +m[2] : 0;"
+		" if (line == 1) column = Math.max(column - " + std::to_string( prefix.size() ) + ", 0);"
+		" var error = e instanceof Error;"
+		" return [\"error\", error ? String(e.name) : \"\", error ? String(e.message) : String(e), error && e.stack ? String(e.stack) : \"\", String(line), String(column)];"
+		" } })()";
+
+	return code;
+}
@@ -0,0 +1,22 @@
+#ifndef BW_BROWSER_WINDOW_WEBVIEW2_H
+#define BW_BROWSER_WINDOW_WEBVIEW2_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+
+
+typedef struct {
+	void* controller;	// ICoreWebView2Controller*
+	void* webview;	// ICoreWebView2*
+	void* devtools;	// std::shared_ptr<bw_BrowserWindowWebView2DevTools>*, once it's needed
+} bw_BrowserWindowImpl;
+
+
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#endif//BW_BROWSER_WINDOW_WEBVIEW2_H
@@ -11,17 +11,71 @@
 
 #include <include/cef_app.h>
 #include <include/cef_client.h>
+#include <include/cef_command_line.h>
 #include <include/cef_life_span_handler.h>
+#include <include/cef_scheme.h>
 #include <include/cef_v8.h>
+#include <cstring>
+#include <map>
+#include <set>
+#include <string>
+#include <vector>
 
 
 
-class AppHandler : public CefApp, public CefRenderProcessHandler {
+// The switch by which the browser process lets the other processes know about the custom schemes.
+#define BW_CEF_CUSTOM_SCHEMES_SWITCH "bw-custom-schemes"
+
+
+
+class AppHandler : public CefApp, public CefBrowserProcessHandler, public CefRenderProcessHandler {
 
 	bw_Application* app;
+	// Extra command-line switches for the browser process, like `--disable-gpu`.
+	std::vector<std::string> switches;
+	// The schemes of which the browser windows answer the requests themselves.
+	std::vector<std::string> custom_schemes;
+	// The preload scripts of every browser in this (renderer) process, by browser identifier.
+	std::map<int, CefString> preload_scripts;
+	// The browsers of this (renderer) process that only let the preload script reach the application.
+	std::set<int> isolated_browsers;
 
 public:
 	AppHandler( bw_Application* app ) : app(app) {}
+	AppHandler( bw_Application* app, std::vector<std::string> switches, std::vector<std::string> custom_schemes ) : app(app), switches(switches), custom_schemes(custom_schemes) {}
+
+	virtual CefRefPtr<CefBrowserProcessHandler> GetBrowserProcessHandler() override {
+		return this;
+	}
+
+	// The other processes need to know the custom schemes as well, but only the browser process has been given them.
+	virtual void OnBeforeChildProcessLaunch( CefRefPtr<CefCommandLine> command_line ) override {
+		std::string schemes;
+		for ( const std::string& scheme : this->custom_schemes )
+			schemes += (schemes.empty() ? "" : ",") + scheme;
+
+		if ( !schemes.empty() )
+			command_line->AppendSwitchWithValue( BW_CEF_CUSTOM_SCHEMES_SWITCH, schemes );
+	}
+
+	virtual void OnBeforeCommandLineProcessing( const CefString& process_type, CefRefPtr<CefCommandLine> command_line ) override {
+
+		// The browser process is the one without a process type, it passes the switches on to the other processes itself.
+		if ( !process_type.empty() )
+			return;
+
+		for ( const std::string& _switch : this->switches ) {
+			std::string name = _switch;
+			if ( name.rfind( "--", 0 ) == 0 )
+				name = name.substr( 2 );
+
+			size_t separator = name.find( '=' );
+			if ( separator == std::string::npos )
+				command_line->AppendSwitch( name );
+			else
+				command_line->AppendSwitchWithValue( name.substr( 0, separator ), name.substr( separator + 1 ) );
+		}
+	}
 
 	virtual void OnBrowserCreated( CefRefPtr<CefBrowser> browser, CefRefPtr<CefDictionaryValue> extra_info ) override {
 
@@ -39,20 +93,63 @@ public:
 		void* ptr = 0;
 		test->GetData( &ptr, sizeof( ptr ), 0 );
 
+		// Remember the preload script, it is needed every time a new JavaScript context is created
+		CefString preload_script = extra_info->GetString( "preload-script" );
+		if ( !preload_script.empty() )
+			this->preload_scripts[ browser->GetIdentifier() ] = preload_script;
+		if ( extra_info->GetBool( "isolate-scripts" ) )
+			this->isolated_browsers.insert( browser->GetIdentifier() );
+
 		browser->GetMainFrame()->SendProcessMessage( PID_BROWSER, msg );
 	}
 
-	virtual void OnContextCreated( CefRefPtr<CefBrowser> browser, CefRefPtr<CefFrame> frame, CefRefPtr<CefV8Context> context ) override {
-		// Unused parameters
-		(void)(frame);
+	// Custom schemes are standard schemes that are treated as secure, so that pages loaded from them can use the same features as the ones that are loaded over HTTPS.
+	virtual void OnRegisterCustomSchemes( CefRawPtr<CefSchemeRegistrar> registrar ) override {
+		std::vector<std::string> schemes = this->custom_schemes;
+
+		if ( schemes.empty() ) {
+			std::string value = CefCommandLine::GetGlobalCommandLine()->GetSwitchValue( BW_CEF_CUSTOM_SCHEMES_SWITCH ).ToString();
+			size_t start = 0;
+			while ( start < value.size() ) {
+				size_t end = value.find( ',', start );
+				if ( end == std::string::npos )
+					end = value.size();
+				schemes.push_back( value.substr( start, end - start ) );
+				start = end + 1;
+			}
+		}
+
+		for ( const std::string& scheme : schemes )
+			registrar->AddCustomScheme( scheme, CEF_SCHEME_OPTION_STANDARD | CEF_SCHEME_OPTION_SECURE | CEF_SCHEME_OPTION_CORS_ENABLED | CEF_SCHEME_OPTION_FETCH_ENABLED );
+	}
 
+	virtual void OnBrowserDestroyed( CefRefPtr<CefBrowser> browser ) override {
+		this->preload_scripts.erase( browser->GetIdentifier() );
+		this->isolated_browsers.erase( browser->GetIdentifier() );
+	}
+
+	virtual void OnContextCreated( CefRefPtr<CefBrowser> browser, CefRefPtr<CefFrame> frame, CefRefPtr<CefV8Context> context ) override {
 		CefRefPtr<CefV8Value> object = context->GetGlobal();
 
+		// The preload script only runs in the main frame, the pages of other frames have no business with the application
+		if ( !frame->IsMain() && this->isolated_browsers.count( browser->GetIdentifier() ) )
+			return;
+
 		CefRefPtr<CefV8Handler> handler = new bw::ExternalInvocationHandler( browser );
 		CefRefPtr<CefV8Value> func = CefV8Value::CreateFunction("invoke_extern", handler);
 
 		bool result = object->SetValue( "invoke_extern", func, V8_PROPERTY_ATTRIBUTE_NONE );
 		BW_ASSERT( result, "Unable to set invoke_extern function." );
+
+		// Run the preload script before the page gets a chance to run its own scripts
+		auto it = this->preload_scripts.find( browser->GetIdentifier() );
+		if ( frame->IsMain() && it != this->preload_scripts.end() ) {
+			CefRefPtr<CefV8Value> ret_val;
+			CefRefPtr<CefV8Exception> exception;
+
+			if ( !context->Eval( (*it).second, "preload", 0, ret_val, exception ) )
+				fprintf( stderr, "Preload script error: %s\n", exception->GetMessage().ToString().c_str() );
+		}
 	}
 
 	virtual CefRefPtr<CefRenderProcessHandler> GetRenderProcessHandler() override {
@@ -116,11 +213,32 @@ public:
 		CefRefPtr<CefListValue> msg_args = msg->GetArgumentList();
 
 		if ( !result ) {
+			std::string message = exception->GetMessage().ToString();
+			std::string name;
+
+			// V8 gives messages like `Uncaught TypeError: x is not a function`, from which the name of the error is taken
+			const std::string uncaught = "Uncaught ";
+			if ( message.compare( 0, uncaught.size(), uncaught ) == 0 )
+				message.erase( 0, uncaught.size() );
+			size_t colon = message.find( ": " );
+			if ( colon != std::string::npos && colon > 0 && message.find( ' ' ) > colon ) {
+				name = message.substr( 0, colon );
+				message.erase( 0, colon + 2 );
+			}
 
 			// The first parameter specifies whether or not an error has resulted
 			msg_args->SetBool( 0, false );
 			// The second parameter specifies the error message
-			msg_args->SetString( 1, exception->GetMessage() );
+			msg_args->SetString( 1, message );
+			// After the binaries come the name of the error, and the line and column where it occurred
+			msg_args->SetString( 5, name );
+			// The code has been wrapped by `bw_BrowserWindowCef_wrapJs`, of which the part before the code is left out of the column on the first line
+			int line = exception->GetLineNumber();
+			int column = exception->GetStartColumn() + 1;
+			if ( line == 1 )
+				column -= (int)strlen( "(function () { return " );
+			msg_args->SetInt( 6, line );
+			msg_args->SetInt( 7, column > 0 ? column : 0 );
 		}
 		else {
 
@@ -44,8 +44,10 @@ namespace bw {
 				auto it = this->map.find( cef_handle->GetIdentifier() );
 
 				// If not found return nothing
-				if ( it == this->map.end() )
+				if ( it == this->map.end() ) {
+					this->mutex.unlock();
 					return std::optional<bw_BrowserWindow*>();
+				}
 
 				// If found:
 				std::optional<bw_BrowserWindow*> result( (*it).second );
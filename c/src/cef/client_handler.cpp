@@ -1,5 +1,157 @@
 #include "client_handler.hpp"
+#include "request_handler.hpp"
 
+#include <algorithm>
+
+
+
+// Passes the downloaded favicon of a page on to the favicon handler of its browser window.
+class FaviconDownloadCallback : public CefDownloadImageCallback {
+	CefRefPtr<CefBrowser> browser;
+
+public:
+	FaviconDownloadCallback( CefRefPtr<CefBrowser> browser ) : browser(browser) {}
+
+	virtual void OnDownloadImageFinished( const CefString& image_url, int http_status_code, CefRefPtr<CefImage> image ) override;
+
+	IMPLEMENT_REFCOUNTING(FaviconDownloadCallback);
+};
+
+static void fileChooserEventHandlerFunc( bw_Application* app, void* _data ) {
+	(void)(app);
+	auto data = (FileChooserEventData*)_data;
+
+	bw_CStrSlice title = { data->title.size(), data->title.c_str() };
+	bw_CStrSlice default_path = { data->default_path.size(), data->default_path.c_str() };
+	bw_CStrSlice accept = { data->accept.size(), data->accept.c_str() };
+
+	// The handler might have been unset in the meantime, in which case nothing is chosen
+	if ( data->bw->on_file_chooser != 0 )
+		data->bw->on_file_chooser( data->bw, data->chooser, data->mode, title, default_path, accept );
+	else
+		bw_FileChooser_select( data->chooser, 0, 0 );
+
+	delete data;
+}
+
+static void faviconEventHandlerFunc( bw_Application* app, void* _data ) {
+	(void)(app);
+	auto data = (FaviconEventData*)_data;
+
+	if ( data->bw->on_favicon_changed != 0 )
+		data->bw->on_favicon_changed( data->bw, data->pixels.data(), data->pixels.size(), (unsigned int)data->width, (unsigned int)data->height );
+
+	delete data;
+}
+
+static void triggerFaviconEvent( FaviconEventData* data ) {
+#ifdef BW_WIN32
+	bw_Application_dispatch( data->bw->window->app, faviconEventHandlerFunc, data );
+#else
+	faviconEventHandlerFunc( data->bw->window->app, data );
+#endif
+}
+
+void FaviconDownloadCallback::OnDownloadImageFinished( const CefString& image_url, int http_status_code, CefRefPtr<CefImage> image ) {
+	(void)(image_url);
+	(void)(http_status_code);
+
+	// The image is empty if it couldn't be downloaded or decoded
+	if ( image == nullptr || image->IsEmpty() )
+		return;
+
+	std::optional<bw_BrowserWindow*> bw_handle = bw::bw_handle_map.fetch( browser );
+	if ( !bw_handle.has_value() )
+		return;
+
+	int width, height;
+	CefRefPtr<CefBinaryValue> bitmap = image->GetAsBitmap( 1.0f, CEF_COLOR_TYPE_RGBA_8888, CEF_ALPHA_TYPE_POSTMULTIPLIED, width, height );
+	if ( bitmap == nullptr )
+		return;
+
+	auto data = new FaviconEventData { *bw_handle, std::vector<uint8_t>( bitmap->GetSize() ), width, height };
+	bitmap->GetData( data->pixels.data(), data->pixels.size(), 0 );
+	triggerFaviconEvent( data );
+}
+
+
+
+
+void ClientHandler::acceleratorHandlerFunc( bw_Application* app, void* _data ) {
+	(void)(app);
+	auto data = (AcceleratorHandlerData*)_data;
+
+	if ( data->bw->on_accelerator != 0 )
+		data->bw->on_accelerator( data->bw, data->accelerator_id );
+
+	delete data;
+}
+
+static BOOL keyEventHandlerFunc( KeyEventData* data ) {
+	return bw_BrowserWindow_handleKey( data->bw, data->kind, data->key_code, data->modifiers, data->is_repeat );
+}
+
+#ifdef BW_WIN32
+static LRESULT keyEventInvokeFunc( void* data ) {
+	return keyEventHandlerFunc( (KeyEventData*)data );
+}
+#endif
+
+int ClientHandler::checkPermission( CefRefPtr<CefBrowser> browser, uint8_t permission, const CefString& url ) {
+	std::optional<bw_BrowserWindow*> bw_handle = bw::bw_handle_map.fetch( browser );
+	if ( !bw_handle.has_value() )
+		return -1;
+
+	std::string url_str = url.ToString();
+	bw_CStrSlice slice = { url_str.size(), url_str.c_str() };
+	return bw_BrowserWindow_checkPermission( *bw_handle, permission, slice );
+}
+
+void ClientHandler::evalJsResultHandlerFunc( bw_Application* app, void* _data ) {
+	(void)(app);
+	auto data = (EvalJsResultData*)_data;
+
+	// Invoke the callback with either a result string or an error
+	if ( data->success ) {
+		data->callback( data->bw, data->user_data, data->result.c_str(), 0 );
+	}
+	else {
+		// V8 doesn't give the stack trace of the exceptions that `Eval` catches
+		bw_Err error = bw_Err_newJsException( data->name.c_str(), data->result.c_str(), "", data->line, data->column );
+
+		data->callback( data->bw, data->user_data, 0, &error );
+		bw_Err_free( &error );
+	}
+
+	delete data;
+}
+
+bool ClientHandler::handleKey( bw_BrowserWindow* bw, const CefKeyEvent& event ) {
+	// Without a key handler, there is no need to wait for the GUI thread
+	if ( bw->on_key == 0 )
+		return false;
+
+	uint32_t modifiers = 0;
+	if ( event.modifiers & EVENTFLAG_CONTROL_DOWN )	modifiers |= BW_MODIFIER_CTRL;
+	if ( event.modifiers & EVENTFLAG_SHIFT_DOWN )	modifiers |= BW_MODIFIER_SHIFT;
+	if ( event.modifiers & EVENTFLAG_ALT_DOWN )	modifiers |= BW_MODIFIER_ALT;
+	if ( event.modifiers & EVENTFLAG_COMMAND_DOWN )	modifiers |= BW_MODIFIER_SUPER;
+
+	KeyEventData data = {
+		bw,
+		(uint8_t)(event.type == KEYEVENT_RAWKEYDOWN ? 0 : 1),
+		(uint16_t)event.windows_key_code,
+		modifiers,
+		(event.modifiers & EVENTFLAG_IS_REPEAT) != 0
+	};
+
+#ifdef BW_WIN32
+	// CEF's UI thread isn't the GUI thread on Windows, so the handler is invoked through the window, which sends it to the GUI thread and waits for it
+	return SendMessageW( bw->window->impl.handle, BW_WM_INVOKE, (WPARAM)keyEventInvokeFunc, (LPARAM)&data ) != 0;
+#else
+	return keyEventHandlerFunc( &data ) != 0;
+#endif
+}
 
 void ClientHandler::externalInvocationHandlerFunc( bw_Application* app, void* _data ) {
 	auto data = (ExternalInvocationHandlerData*)_data;
@@ -30,4 +182,590 @@ void ClientHandler::externalInvocationHandlerFunc( bw_Application* app, void* _d
 		params_slices.data(),
 		params_slices.size()
 	);
-}
\ No newline at end of file
+}
+void ClientHandler::OnCursorChange(
+	CefRefPtr<CefBrowser> browser,
+	CefCursorHandle cursor,
+	CursorType type,
+	const CefCursorInfo& custom_cursor_info
+) {
+	(void)(cursor);
+	(void)(custom_cursor_info);
+
+	std::optional<bw_BrowserWindow*> bw_handle = bw::bw_handle_map.fetch( browser );
+	if ( !bw_handle.has_value() )
+		return;
+
+	uint8_t our_cursor;
+	switch ( type ) {
+	case CT_HAND: our_cursor = 1; break;
+	case CT_IBEAM:
+	case CT_VERTICALTEXT: our_cursor = 2; break;
+	case CT_WAIT: our_cursor = 3; break;
+	case CT_PROGRESS: our_cursor = 4; break;
+	case CT_CROSS:
+	case CT_CELL: our_cursor = 5; break;
+	case CT_MOVE:
+	case CT_MIDDLEPANNING: our_cursor = 6; break;
+	case CT_NOTALLOWED:
+	case CT_NODROP: our_cursor = 7; break;
+	case CT_HELP: our_cursor = 8; break;
+	case CT_EASTRESIZE:
+	case CT_WESTRESIZE:
+	case CT_EASTWESTRESIZE:
+	case CT_COLUMNRESIZE: our_cursor = 9; break;
+	case CT_NORTHRESIZE:
+	case CT_SOUTHRESIZE:
+	case CT_NORTHSOUTHRESIZE:
+	case CT_ROWRESIZE: our_cursor = 10; break;
+	case CT_NORTHEASTRESIZE:
+	case CT_SOUTHWESTRESIZE:
+	case CT_NORTHEASTSOUTHWESTRESIZE: our_cursor = 11; break;
+	case CT_NORTHWESTRESIZE:
+	case CT_SOUTHEASTRESIZE:
+	case CT_NORTHWESTSOUTHEASTRESIZE: our_cursor = 12; break;
+	case CT_GRAB: our_cursor = 13; break;
+	case CT_GRABBING: our_cursor = 14; break;
+	case CT_NONE: our_cursor = 15; break;
+	default: our_cursor = 0;
+	}
+
+	auto data = new OffscreenEventData;
+	data->bw = *bw_handle;
+	data->kind = 2;
+	data->cursor = our_cursor;
+	triggerOffscreenEvent( data );
+}
+
+void ClientHandler::OnAcceleratedPaint(
+	CefRefPtr<CefBrowser> browser,
+	PaintElementType type,
+	const RectList& dirty_rects,
+	void* shared_handle
+) {
+	std::optional<bw_BrowserWindow*> bw_handle = bw::bw_handle_map.fetch( browser );
+	if ( !bw_handle.has_value() || (*bw_handle)->on_accelerated_paint == 0 )
+		return;
+
+	// The texture outlives this call, so only the rects need to be copied
+	auto data = new OffscreenEventData;
+	data->bw = *bw_handle;
+	data->kind = 3;
+	data->is_popup = type == PET_POPUP;
+	data->shared_handle = shared_handle;
+	for ( const CefRect& rect : dirty_rects ) {
+		bw_BrowserWindowRect our_rect = { rect.x, rect.y, rect.width, rect.height };
+		data->rects.push_back( our_rect );
+	}
+	triggerOffscreenEvent( data );
+}
+
+void ClientHandler::OnPaint(
+	CefRefPtr<CefBrowser> browser,
+	PaintElementType type,
+	const RectList& dirty_rects,
+	const void* buffer,
+	int width, int height
+) {
+	std::optional<bw_BrowserWindow*> bw_handle = bw::bw_handle_map.fetch( browser );
+	if ( !bw_handle.has_value() || (*bw_handle)->on_paint == 0 )
+		return;
+
+	auto data = new OffscreenEventData;
+	data->bw = *bw_handle;
+	data->kind = 0;
+	data->is_popup = type == PET_POPUP;
+	data->width = width;
+	data->height = height;
+	for ( const CefRect& rect : dirty_rects ) {
+		bw_BrowserWindowRect our_rect = { rect.x, rect.y, rect.width, rect.height };
+		data->rects.push_back( our_rect );
+	}
+
+#ifdef BW_WIN32
+	// The buffer is only valid during this call, so it is copied to be used on the GUI thread
+	const uint8_t* pixels = (const uint8_t*)buffer;
+	data->pixels.assign( pixels, pixels + (size_t)width * (size_t)height * 4 );
+	triggerOffscreenEvent( data );
+#else
+	bw_BrowserWindow* bw = data->bw;
+	bw->on_paint( bw, data->is_popup, data->rects.data(), data->rects.size(), buffer, width, height );
+	delete data;
+#endif
+}
+
+void ClientHandler::OnPopupShow( CefRefPtr<CefBrowser> browser, bool show ) {
+
+	// The rect of a popup that is shown is given by `OnPopupSize`
+	if ( show )
+		return;
+
+	std::optional<bw_BrowserWindow*> bw_handle = bw::bw_handle_map.fetch( browser );
+	if ( !bw_handle.has_value() )
+		return;
+
+	auto data = new OffscreenEventData;
+	data->bw = *bw_handle;
+	data->kind = 1;
+	triggerOffscreenEvent( data );
+}
+
+void ClientHandler::OnPopupSize( CefRefPtr<CefBrowser> browser, const CefRect& rect ) {
+	std::optional<bw_BrowserWindow*> bw_handle = bw::bw_handle_map.fetch( browser );
+	if ( !bw_handle.has_value() )
+		return;
+
+	auto data = new OffscreenEventData;
+	data->bw = *bw_handle;
+	data->kind = 1;
+	bw_BrowserWindowRect our_rect = { rect.x, rect.y, rect.width, rect.height };
+	data->rects.push_back( our_rect );
+	triggerOffscreenEvent( data );
+}
+
+bool ClientHandler::OnPreKeyEvent(
+	CefRefPtr<CefBrowser> browser,
+	const CefKeyEvent& event,
+	CefEventHandle os_event,
+	bool* is_keyboard_shortcut
+) {
+	(void)(os_event);
+	(void)(is_keyboard_shortcut);
+
+	if ( event.type != KEYEVENT_RAWKEYDOWN && event.type != KEYEVENT_KEYUP )
+		return false;
+
+	std::optional<bw_BrowserWindow*> bw_handle = bw::bw_handle_map.fetch( browser );
+	if ( !bw_handle.has_value() )
+		return false;
+
+	if ( handleKey( *bw_handle, event ) )
+		return true;
+	if ( event.type != KEYEVENT_RAWKEYDOWN )
+		return false;
+
+	// FIXME: The accelerators are registered on the GUI thread, which isn't CEF's UI thread on Windows.
+	uint32_t accelerator_id;
+	BOOL found = bw_BrowserWindow_findAccelerator(
+		*bw_handle,
+		(uint16_t)event.windows_key_code,
+		(event.modifiers & EVENTFLAG_CONTROL_DOWN) != 0,
+		(event.modifiers & EVENTFLAG_SHIFT_DOWN) != 0,
+		(event.modifiers & EVENTFLAG_ALT_DOWN) != 0,
+		(event.modifiers & EVENTFLAG_COMMAND_DOWN) != 0,
+		&accelerator_id
+	);
+	if ( !found )
+		return false;
+
+	// Invoke the handler on the thread from which the BrowserWindow main loop runs
+	auto dispatch_data = new AcceleratorHandlerData {
+		*bw_handle,
+		accelerator_id
+	};
+	bw_Application_dispatch(
+		(*bw_handle)->window->app,
+		acceleratorHandlerFunc,
+		dispatch_data
+	);
+	return true;
+}
+
+void ClientHandler::OnAddressChange( CefRefPtr<CefBrowser> browser, CefRefPtr<CefFrame> frame, const CefString& url ) {
+	if ( !frame->IsMain() )
+		return;
+
+	std::optional<bw_BrowserWindow*> bw_handle = bw::bw_handle_map.fetch( browser );
+	if ( !bw_handle.has_value() )
+		return;
+
+	triggerPageEvent( new PageEventData { *bw_handle, 0, url.ToString(), 0, 0.0 } );
+}
+
+void ClientHandler::OnLoadEnd( CefRefPtr<CefBrowser> browser, CefRefPtr<CefFrame> frame, int http_status_code ) {
+	(void)(http_status_code);
+
+	if ( !frame->IsMain() )
+		return;
+
+	std::optional<bw_BrowserWindow*> bw_handle = bw::bw_handle_map.fetch( browser );
+	if ( !bw_handle.has_value() )
+		return;
+
+	triggerPageEvent( new PageEventData { *bw_handle, 2, "", 0, 0.0 } );
+}
+
+void ClientHandler::OnLoadError(
+	CefRefPtr<CefBrowser> browser,
+	CefRefPtr<CefFrame> frame,
+	ErrorCode error_code,
+	const CefString& error_text,
+	const CefString& failed_url
+) {
+	// Loads are aborted when another navigation takes their place, which is reported on its own
+	if ( !frame->IsMain() || error_code == ERR_ABORTED )
+		return;
+
+	std::optional<bw_BrowserWindow*> bw_handle = bw::bw_handle_map.fetch( browser );
+	if ( !bw_handle.has_value() )
+		return;
+
+	// The network error codes of CEF are negative
+	std::string text = error_text.ToString() + " (" + failed_url.ToString() + ")";
+	triggerPageEvent( new PageEventData { *bw_handle, 3, text, (bw_ErrCode)-error_code, 0.0 } );
+}
+
+void ClientHandler::OnFaviconURLChange( CefRefPtr<CefBrowser> browser, const std::vector<CefString>& icon_urls ) {
+	// The icons are given in the order of preference of the page
+	if ( icon_urls.empty() )
+		return;
+
+	std::optional<bw_BrowserWindow*> bw_handle = bw::bw_handle_map.fetch( browser );
+	if ( !bw_handle.has_value() || (*bw_handle)->on_favicon_changed == 0 )
+		return;
+
+	browser->GetHost()->DownloadImage( icon_urls[0], true, 0, false, new FaviconDownloadCallback( browser ) );
+}
+
+bool ClientHandler::OnFileDialog(
+	CefRefPtr<CefBrowser> browser,
+	FileDialogMode mode,
+	const CefString& title,
+	const CefString& default_file_path,
+	const std::vector<CefString>& accept_filters,
+	CefRefPtr<CefFileDialogCallback> callback
+) {
+	std::optional<bw_BrowserWindow*> bw_handle = bw::bw_handle_map.fetch( browser );
+	if ( !bw_handle.has_value() || (*bw_handle)->on_file_chooser == 0 )
+		return false;
+
+	uint8_t kind = 0;
+	switch ( mode ) {
+	case FILE_DIALOG_OPEN_MULTIPLE:	kind = 1; break;
+	case FILE_DIALOG_OPEN_FOLDER:	kind = 2; break;
+	case FILE_DIALOG_SAVE:	kind = 3; break;
+	default:	break;
+	}
+
+	std::string accept;
+	for ( const CefString& filter : accept_filters ) {
+		if ( !accept.empty() )
+			accept += "\n";
+		accept += filter.ToString();
+	}
+
+	auto data = new FileChooserEventData {
+		*bw_handle,
+		new bw_FileChooser { callback },
+		kind,
+		title.ToString(),
+		default_file_path.ToString(),
+		accept
+	};
+#ifdef BW_WIN32
+	bw_Application_dispatch( data->bw->window->app, fileChooserEventHandlerFunc, data );
+#else
+	fileChooserEventHandlerFunc( data->bw->window->app, data );
+#endif
+	return true;
+}
+
+void ClientHandler::OnBeforeDownload(
+	CefRefPtr<CefBrowser> browser,
+	CefRefPtr<CefDownloadItem> download_item,
+	const CefString& suggested_name,
+	CefRefPtr<CefBeforeDownloadCallback> callback
+) {
+	(void)(suggested_name);
+
+	CefString url = browser->GetMainFrame()->GetURL();
+	if ( url.empty() )
+		url = download_item->GetURL();
+
+	// An empty path lets the user choose where the file is saved
+	if ( checkPermission( browser, 3, url ) == 1 )
+		callback->Continue( "", true );
+}
+
+bool ClientHandler::OnBeforePopup(
+	CefRefPtr<CefBrowser> browser,
+	CefRefPtr<CefFrame> frame,
+	const CefString& target_url,
+	const CefString& target_frame_name,
+	CefLifeSpanHandler::WindowOpenDisposition target_disposition,
+	bool user_gesture,
+	const CefPopupFeatures& popup_features,
+	CefWindowInfo& window_info,
+	CefRefPtr<CefClient>& client,
+	CefBrowserSettings& settings,
+	CefRefPtr<CefDictionaryValue>& extra_info,
+	bool* no_javascript_access
+) {
+	(void)(target_url);
+	(void)(target_frame_name);
+	(void)(target_disposition);
+	(void)(user_gesture);
+	(void)(popup_features);
+	(void)(window_info);
+	(void)(client);
+	(void)(settings);
+	(void)(extra_info);
+	(void)(no_javascript_access);
+
+	// Returning true cancels the popup
+	return checkPermission( browser, 2, frame->GetURL() ) == 0;
+}
+
+bool ClientHandler::OnRequestMediaAccessPermission(
+	CefRefPtr<CefBrowser> browser,
+	CefRefPtr<CefFrame> frame,
+	const CefString& requesting_origin,
+	uint32_t requested_permissions,
+	CefRefPtr<CefMediaAccessCallback> callback
+) {
+	(void)(frame);
+
+	// Capturing the screen is a permission of its own, the camera and microphone are the other one
+	const uint32_t desktop = CEF_MEDIA_PERMISSION_DESKTOP_AUDIO_CAPTURE | CEF_MEDIA_PERMISSION_DESKTOP_VIDEO_CAPTURE;
+	int decision = 1;
+	if ( requested_permissions & ~desktop )
+		decision = checkPermission( browser, 0, requesting_origin );
+	if ( (requested_permissions & desktop) && decision != 0 ) {
+		int desktop_decision = checkPermission( browser, 4, requesting_origin );
+		decision = desktop_decision == 1 ? decision : desktop_decision;
+	}
+
+	switch ( decision ) {
+	case 1:
+		callback->Continue( requested_permissions );
+		return true;
+	case 0:
+		callback->Cancel();
+		return true;
+	default:
+		return false;
+	}
+}
+
+bool ClientHandler::OnShowPermissionPrompt(
+	CefRefPtr<CefBrowser> browser,
+	uint64_t prompt_id,
+	const CefString& requesting_origin,
+	uint32_t requested_permissions,
+	CefRefPtr<CefPermissionPromptCallback> callback
+) {
+	(void)(prompt_id);
+
+	// Only prompts that are just about the clipboard are decided here
+	if ( requested_permissions != CEF_PERMISSION_TYPE_CLIPBOARD )
+		return false;
+
+	switch ( checkPermission( browser, 1, requesting_origin ) ) {
+	case 1:
+		callback->Continue( CEF_PERMISSION_RESULT_ACCEPT );
+		return true;
+	case 0:
+		callback->Continue( CEF_PERMISSION_RESULT_DENY );
+		return true;
+	default:
+		return false;
+	}
+}
+
+void ClientHandler::OnLoadingProgressChange( CefRefPtr<CefBrowser> browser, double progress ) {
+	std::optional<bw_BrowserWindow*> bw_handle = bw::bw_handle_map.fetch( browser );
+	if ( !bw_handle.has_value() )
+		return;
+
+	triggerPageEvent( new PageEventData { *bw_handle, 4, "", 0, progress } );
+}
+
+CefRefPtr<CefResourceRequestHandler> ClientHandler::GetResourceRequestHandler(
+	CefRefPtr<CefBrowser> browser,
+	CefRefPtr<CefFrame> frame,
+	CefRefPtr<CefRequest> request,
+	bool is_navigation,
+	bool is_download,
+	const CefString& request_initiator,
+	bool& disable_default_handling
+) {
+	(void)(frame);
+	(void)(is_navigation);
+	(void)(is_download);
+	(void)(request_initiator);
+	(void)(disable_default_handling);
+
+	// Requests that don't come from a browser window, like the ones of service workers, can't be intercepted
+	if ( browser == nullptr )
+		return nullptr;
+
+	std::optional<bw_BrowserWindow*> bw_handle = bw::bw_handle_map.fetch( browser );
+	if ( !bw_handle.has_value() )
+		return nullptr;
+
+	std::string url = request->GetURL().ToString();
+	std::string scheme = url.substr( 0, url.find( ':' ) );
+	std::transform( scheme.begin(), scheme.end(), scheme.begin(), []( unsigned char c ) { return (char)tolower( c ); } );
+	if ( std::find( this->custom_schemes.begin(), this->custom_schemes.end(), scheme ) != this->custom_schemes.end() )
+		return nullptr;
+
+	if ( !bw_InterceptedRequest_matches( *bw_handle, url ) )
+		return nullptr;
+
+	return new bw::InterceptingResourceRequestHandler( *bw_handle );
+}
+
+void ClientHandler::OnRenderProcessTerminated( CefRefPtr<CefBrowser> browser, TerminationStatus status, int error_code, const CefString& error_string ) {
+	(void)(error_code);
+	(void)(error_string);
+
+	std::optional<bw_BrowserWindow*> bw_handle = bw::bw_handle_map.fetch( browser );
+	if ( !bw_handle.has_value() )
+		return;
+
+	bw_ErrCode reason = 0;
+	switch ( status ) {
+	case TS_PROCESS_WAS_KILLED:	reason = 1; break;
+	case TS_PROCESS_CRASHED:	reason = 2; break;
+	case TS_PROCESS_OOM:	reason = 3; break;
+	default:	break;
+	}
+
+	triggerPageEvent( new PageEventData { *bw_handle, 6, "", reason, 0.0 } );
+}
+
+bool ClientHandler::OnRenderProcessUnresponsive( CefRefPtr<CefBrowser> browser, CefRefPtr<CefUnresponsiveProcessCallback> callback ) {
+	(void)(callback);
+
+	std::optional<bw_BrowserWindow*> bw_handle = bw::bw_handle_map.fetch( browser );
+	if ( bw_handle.has_value() )
+		triggerPageEvent( new PageEventData { *bw_handle, 7, "", 0, 0.0 } );
+	return false;
+}
+
+void ClientHandler::OnRenderProcessResponsive( CefRefPtr<CefBrowser> browser ) {
+	std::optional<bw_BrowserWindow*> bw_handle = bw::bw_handle_map.fetch( browser );
+	if ( bw_handle.has_value() )
+		triggerPageEvent( new PageEventData { *bw_handle, 8, "", 0, 0.0 } );
+}
+
+bool ClientHandler::OnStatusMessage( CefRefPtr<CefBrowser> browser, const CefString& value ) {
+	std::optional<bw_BrowserWindow*> bw_handle = bw::bw_handle_map.fetch( browser );
+	if ( !bw_handle.has_value() )
+		return false;
+
+	triggerPageEvent( new PageEventData { *bw_handle, 5, value.ToString(), 0, 0.0 } );
+	return false;
+}
+
+void ClientHandler::OnTitleChange( CefRefPtr<CefBrowser> browser, const CefString& title ) {
+	std::optional<bw_BrowserWindow*> bw_handle = bw::bw_handle_map.fetch( browser );
+	if ( !bw_handle.has_value() )
+		return;
+
+	triggerPageEvent( new PageEventData { *bw_handle, 1, title.ToString(), 0, 0.0 } );
+}
+
+void ClientHandler::offscreenEventHandlerFunc( bw_Application* app, void* _data ) {
+	(void)(app);
+	auto data = (OffscreenEventData*)_data;
+	bw_BrowserWindow* bw = data->bw;
+
+	switch ( data->kind ) {
+	case 0:
+		if ( bw->on_paint != 0 )
+			bw->on_paint( bw, data->is_popup, data->rects.data(), data->rects.size(), data->pixels.data(), data->width, data->height );
+		break;
+	case 1:
+		if ( bw->on_popup != 0 )
+			bw->on_popup( bw, data->rects.size() > 0 ? &data->rects[0] : NULL );
+		break;
+	case 2:
+		if ( bw->on_cursor_change != 0 )
+			bw->on_cursor_change( bw, data->cursor );
+		break;
+	case 3:
+		if ( bw->on_accelerated_paint != 0 )
+			bw->on_accelerated_paint( bw, data->is_popup, data->rects.data(), data->rects.size(), data->shared_handle );
+		break;
+	}
+
+	delete data;
+}
+
+void ClientHandler::triggerOffscreenEvent( OffscreenEventData* data ) {
+#ifdef BW_WIN32
+	// CEF runs its own UI thread on Windows
+	bw_Application_dispatch( data->bw->window->app, offscreenEventHandlerFunc, data );
+#else
+	offscreenEventHandlerFunc( data->bw->window->app, data );
+#endif
+}
+
+void ClientHandler::pageEventHandlerFunc( bw_Application* app, void* _data ) {
+	(void)(app);
+	auto data = (PageEventData*)_data;
+	bw_BrowserWindow* bw = data->bw;
+
+	bw_CStrSlice text = { data->text.size(), data->text.c_str() };
+	if ( data->kind == 0 ) {
+		if ( bw->on_navigate != 0 )
+			bw->on_navigate( bw, text );
+	}
+	else if ( data->kind == 1 ) {
+		if ( bw->on_title_change != 0 )
+			bw->on_title_change( bw, text );
+	}
+	else if ( data->kind == 4 ) {
+		if ( bw->on_loading_progress != 0 )
+			bw->on_loading_progress( bw, data->progress );
+	}
+	else if ( data->kind == 5 ) {
+		if ( bw->on_status_text != 0 )
+			bw->on_status_text( bw, text );
+	}
+	else if ( data->kind == 6 ) {
+		if ( bw->on_render_process_terminated != 0 )
+			bw->on_render_process_terminated( bw, (uint8_t)data->error_code );
+	}
+	else if ( data->kind == 7 || data->kind == 8 ) {
+		if ( bw->on_unresponsive != 0 )
+			bw->on_unresponsive( bw, data->kind == 7 );
+	}
+	else if ( bw->on_load != 0 ) {
+		if ( data->kind == 2 )
+			bw->on_load( bw, 0 );
+		else {
+			bw_Err error = bw_Err_new_with_msg( data->error_code, data->text.c_str() );
+			bw->on_load( bw, &error );
+			bw_Err_free( &error );
+		}
+	}
+
+	delete data;
+}
+
+void ClientHandler::triggerPageEvent( PageEventData* data ) {
+#ifdef BW_WIN32
+	bw_Application_dispatch( data->bw->window->app, pageEventHandlerFunc, data );
+#else
+	pageEventHandlerFunc( data->bw->window->app, data );
+#endif
+}
+
+
+
+void bw_FileChooser_select( bw_FileChooser* chooser, const bw_CStrSlice* paths, size_t path_count ) {
+
+	if ( path_count == 0 )
+		chooser->callback->Cancel();
+	else {
+		std::vector<CefString> file_paths;
+		for ( size_t i = 0; i < path_count; i++ ) {
+			file_paths.push_back( std::string( paths[i].data, paths[i].len ) );
+		}
+		chooser->callback->Continue( file_paths );
+	}
+
+	delete chooser;
+}
@@ -2,34 +2,284 @@
 #define BW_CEF_CLIENT_HANDLER_H
 
 #include <include/cef_client.h>
+#include <include/cef_dialog_handler.h>
+#include <include/cef_display_handler.h>
+#include <include/cef_download_handler.h>
+#include <include/cef_image.h>
+#include <include/cef_keyboard_handler.h>
 #include <include/cef_life_span_handler.h>
+#include <include/cef_permission_handler.h>
+#include <include/cef_render_handler.h>
+#include <include/cef_request_handler.h>
 #include <include/cef_v8.h>
 #include <string>
 #include <vector>
 
 #include "bw_handle_map.hpp"
 #include "../application.h"
+#include "../browser_window/impl.h"
 #include "../common.h"
 
 
 
+struct AcceleratorHandlerData {
+	bw_BrowserWindow* bw;
+	uint32_t accelerator_id;
+};
+
+// A key that is given to the key handler, which is invoked on the GUI thread while CEF's UI thread waits for it.
+struct KeyEventData {
+	bw_BrowserWindow* bw;
+	uint8_t kind;
+	uint16_t key_code;
+	uint32_t modifiers;
+	BOOL is_repeat;
+};
+
+// The data of the offscreen events, which are dispatched to the GUI thread when CEF runs its own UI thread.
+struct OffscreenEventData {
+	bw_BrowserWindow* bw;
+	int kind;	// 0 for painting, 1 for the popup, 2 for the cursor, and 3 for painting into a shared texture
+	BOOL is_popup;
+	std::vector<bw_BrowserWindowRect> rects;	// The dirty rects, or the rect of the popup if it is visible
+	std::vector<uint8_t> pixels;
+	int width;
+	int height;
+	uint8_t cursor;
+	void* shared_handle;
+};
+
+// The result of JavaScript code, which is dispatched to the GUI thread in the same way as the invocations of the external handler
+struct EvalJsResultData {
+	bw_BrowserWindow* bw;
+	bw_BrowserWindowJsCallbackFn callback;
+	void* user_data;
+	bool success;
+	std::string result;
+	// The details of the exception, if it wasn't a success
+	std::string name;
+	unsigned int line;
+	unsigned int column;
+};
+
+// The new address of the main frame, the new title of the page or the text of the status bar, which is dispatched to the GUI thread when CEF runs its own UI thread.
+struct PageEventData {
+	bw_BrowserWindow* bw;
+	int kind;	// 0 for the address, 1 for the title, 2 for a finished load, 3 for a failed load, 4 for the progress of a load, 5 for the status text, 6 for a terminated render process, and 7 and 8 for a page that has become unresponsive or responsive
+	std::string text;	// The description of the error for a failed load
+	bw_ErrCode error_code;	// The code of the error for a failed load, or the reason for a terminated render process
+	double progress;
+};
+
+// The favicon of the page, which is dispatched to the GUI thread when CEF runs its own UI thread.
+struct FaviconEventData {
+	bw_BrowserWindow* bw;
+	std::vector<uint8_t> pixels;	// In the RGBA format
+	int width;
+	int height;
+};
+
+// The callback of a file dialog that the application answers itself, see `bw_FileChooser_select`.
+struct bw_FileChooser {
+	CefRefPtr<CefFileDialogCallback> callback;
+};
+
+// A file dialog that is passed on to the file chooser handler, which is dispatched to the GUI thread when CEF runs its own UI thread.
+struct FileChooserEventData {
+	bw_BrowserWindow* bw;
+	bw_FileChooser* chooser;
+	uint8_t mode;
+	std::string title;
+	std::string default_path;
+	std::string accept;	// A line for every accepted type of file
+};
+
 struct ExternalInvocationHandlerData {
 	bw_BrowserWindow* bw;
 	std::string cmd;
 	std::vector<std::string> params;
 };
 
-class ClientHandler : public CefClient, public CefLifeSpanHandler {
+class ClientHandler : public CefClient, public CefDialogHandler, public CefDisplayHandler, public CefDownloadHandler, public CefKeyboardHandler, public CefLifeSpanHandler, public CefLoadHandler, public CefPermissionHandler, public CefRenderHandler, public CefRequestHandler {
 
 	bw_Application* app;
+	// The requests of these schemes are answered by the scheme handlers, so they are not intercepted
+	std::vector<std::string> custom_schemes;
 
 public:
-	ClientHandler( bw_Application* app ) : app(app) {}
+	ClientHandler( bw_Application* app, std::vector<std::string> custom_schemes ) : app(app), custom_schemes(custom_schemes) {}
+
+	virtual CefRefPtr<CefDialogHandler> GetDialogHandler() override {
+		return this;
+	}
+
+	virtual CefRefPtr<CefDisplayHandler> GetDisplayHandler() override {
+		return this;
+	}
+
+	virtual CefRefPtr<CefDownloadHandler> GetDownloadHandler() override {
+		return this;
+	}
+
+	virtual CefRefPtr<CefKeyboardHandler> GetKeyboardHandler() override {
+		return this;
+	}
 
 	virtual CefRefPtr<CefLifeSpanHandler> GetLifeSpanHandler() override {
 		return this;
 	}
 
+	virtual CefRefPtr<CefLoadHandler> GetLoadHandler() override {
+		return this;
+	}
+
+	virtual CefRefPtr<CefPermissionHandler> GetPermissionHandler() override {
+		return this;
+	}
+
+	virtual CefRefPtr<CefRequestHandler> GetRequestHandler() override {
+		return this;
+	}
+
+	// The render handler is only used by headless browsers
+	virtual CefRefPtr<CefRenderHandler> GetRenderHandler() override {
+		return this;
+	}
+
+	virtual void GetViewRect( CefRefPtr<CefBrowser> browser, CefRect& rect ) override {
+		std::optional<bw_BrowserWindow*> bw_handle = bw::bw_handle_map.fetch( browser );
+
+		// The browser may not have been linked to our handle yet, in which case some default size is used.
+		if ( !bw_handle.has_value() ) {
+			rect = CefRect( 0, 0, 800, 600 );
+			return;
+		}
+
+		bw_Dims2D dims = bw_Window_getContentDimensions( (*bw_handle)->window );
+		rect = CefRect( 0, 0, dims.width > 0 ? dims.width : 1, dims.height > 0 ? dims.height : 1 );
+	}
+
+	// Lets the key handler and the registered accelerators catch keys before the page gets to see them
+	virtual bool OnPreKeyEvent(
+		CefRefPtr<CefBrowser> browser,
+		const CefKeyEvent& event,
+		CefEventHandle os_event,
+		bool* is_keyboard_shortcut
+	) override;
+
+	virtual void OnAddressChange( CefRefPtr<CefBrowser> browser, CefRefPtr<CefFrame> frame, const CefString& url ) override;
+
+	virtual void OnFaviconURLChange( CefRefPtr<CefBrowser> browser, const std::vector<CefString>& icon_urls ) override;
+
+	virtual void OnLoadEnd( CefRefPtr<CefBrowser> browser, CefRefPtr<CefFrame> frame, int http_status_code ) override;
+
+	virtual void OnLoadError(
+		CefRefPtr<CefBrowser> browser,
+		CefRefPtr<CefFrame> frame,
+		ErrorCode error_code,
+		const CefString& error_text,
+		const CefString& failed_url
+	) override;
+
+	// Downloads are cancelled by CEF unless they are continued here, so they are only started when the permission has been granted explicitly.
+	virtual void OnBeforeDownload(
+		CefRefPtr<CefBrowser> browser,
+		CefRefPtr<CefDownloadItem> download_item,
+		const CefString& suggested_name,
+		CefRefPtr<CefBeforeDownloadCallback> callback
+	) override;
+
+	// The dialog of CEF is only shown when the application doesn't answer the file choosers itself
+	virtual bool OnFileDialog(
+		CefRefPtr<CefBrowser> browser,
+		FileDialogMode mode,
+		const CefString& title,
+		const CefString& default_file_path,
+		const std::vector<CefString>& accept_filters,
+		CefRefPtr<CefFileDialogCallback> callback
+	) override;
+
+	virtual bool OnBeforePopup(
+		CefRefPtr<CefBrowser> browser,
+		CefRefPtr<CefFrame> frame,
+		const CefString& target_url,
+		const CefString& target_frame_name,
+		CefLifeSpanHandler::WindowOpenDisposition target_disposition,
+		bool user_gesture,
+		const CefPopupFeatures& popup_features,
+		CefWindowInfo& window_info,
+		CefRefPtr<CefClient>& client,
+		CefBrowserSettings& settings,
+		CefRefPtr<CefDictionaryValue>& extra_info,
+		bool* no_javascript_access
+	) override;
+
+	virtual bool OnRequestMediaAccessPermission(
+		CefRefPtr<CefBrowser> browser,
+		CefRefPtr<CefFrame> frame,
+		const CefString& requesting_origin,
+		uint32_t requested_permissions,
+		CefRefPtr<CefMediaAccessCallback> callback
+	) override;
+
+	virtual bool OnShowPermissionPrompt(
+		CefRefPtr<CefBrowser> browser,
+		uint64_t prompt_id,
+		const CefString& requesting_origin,
+		uint32_t requested_permissions,
+		CefRefPtr<CefPermissionPromptCallback> callback
+	) override;
+
+	// The requests that the request handler of the browser window is interested in are held back until it has decided on them
+	virtual CefRefPtr<CefResourceRequestHandler> GetResourceRequestHandler(
+		CefRefPtr<CefBrowser> browser,
+		CefRefPtr<CefFrame> frame,
+		CefRefPtr<CefRequest> request,
+		bool is_navigation,
+		bool is_download,
+		const CefString& request_initiator,
+		bool& disable_default_handling
+	) override;
+
+	virtual void OnRenderProcessTerminated( CefRefPtr<CefBrowser> browser, TerminationStatus status, int error_code, const CefString& error_string ) override;
+
+	// CEF keeps waiting for the page when false is returned, which is left to the application
+	virtual bool OnRenderProcessUnresponsive( CefRefPtr<CefBrowser> browser, CefRefPtr<CefUnresponsiveProcessCallback> callback ) override;
+
+	virtual void OnRenderProcessResponsive( CefRefPtr<CefBrowser> browser ) override;
+
+	virtual bool OnStatusMessage( CefRefPtr<CefBrowser> browser, const CefString& value ) override;
+
+	virtual void OnTitleChange( CefRefPtr<CefBrowser> browser, const CefString& title ) override;
+
+	virtual void OnLoadingProgressChange( CefRefPtr<CefBrowser> browser, double progress ) override;
+
+	virtual void OnCursorChange(
+		CefRefPtr<CefBrowser> browser,
+		CefCursorHandle cursor,
+		CursorType type,
+		const CefCursorInfo& custom_cursor_info
+	) override;
+
+	virtual void OnAcceleratedPaint(
+		CefRefPtr<CefBrowser> browser,
+		PaintElementType type,
+		const RectList& dirty_rects,
+		void* shared_handle
+	) override;
+
+	virtual void OnPaint(
+		CefRefPtr<CefBrowser> browser,
+		PaintElementType type,
+		const RectList& dirty_rects,
+		const void* buffer,
+		int width, int height
+	) override;
+
+	virtual void OnPopupShow( CefRefPtr<CefBrowser> browser, bool show ) override;
+
+	virtual void OnPopupSize( CefRefPtr<CefBrowser> browser, const CefRect& rect ) override;
+
 	virtual bool OnProcessMessageReceived(
 		CefRefPtr<CefBrowser> browser,
 		CefRefPtr<CefFrame> frame,
@@ -60,7 +310,19 @@ public:
 
 protected:
 
+	static void acceleratorHandlerFunc( bw_Application* app, void* data );
+	// Asks the permission handler of the browser window whether the permission is granted, which is done on CEF's UI thread as the answer is needed right away.
+	static int checkPermission( CefRefPtr<CefBrowser> browser, uint8_t permission, const CefString& url );
+	static void evalJsResultHandlerFunc( bw_Application* app, void* data );
+	// Asks the key handler of the browser window whether it handles the key, which is done on the GUI thread while CEF's UI thread waits for the answer.
+	static bool handleKey( bw_BrowserWindow* bw, const CefKeyEvent& event );
 	static void externalInvocationHandlerFunc( bw_Application* app, void* data );
+	static void offscreenEventHandlerFunc( bw_Application* app, void* data );
+	static void pageEventHandlerFunc( bw_Application* app, void* data );
+	// Invokes the offscreen event handler right away when on the GUI thread, or dispatches it to the GUI thread otherwise.
+	static void triggerOffscreenEvent( OffscreenEventData* data );
+	// Same as above, for the page events.
+	static void triggerPageEvent( PageEventData* data );
 
 	void onBrowserCreated(
 		CefRefPtr<CefBrowser> browser,
@@ -87,7 +349,7 @@ protected:
 		bw::bw_handle_map.store( *cef_ptr, bw_handle );
 
 		// Open dev-tools window
-		if ( dev_tools_enabled )
+		if ( dev_tools_enabled && !bw_handle->window->app->headless )
 			this->openDevTools( bw_handle, browser->GetHost() );
 
 		// Invoke the completion callback
@@ -127,18 +389,32 @@ protected:
 		CefRefPtr<CefBinaryValue> user_data_bin = msg_args->GetBinary( 4 );
 		user_data_bin->GetData( (void*)&user_data, sizeof( user_data ), 0 );
 
-		// FIXME: call the relevant code on the right thread...
-
-		// // Invoke the callback with either a result string or an error
-		if (success) {
-			callback( bw_handle, user_data, result.c_str(), 0 );
+		// The details of the exception
+		std::string name;
+		int line = 0, column = 0;
+		if ( !success ) {
+			name = msg_args->GetString( 5 ).ToString();
+			line = msg_args->GetInt( 6 );
+			column = msg_args->GetInt( 7 );
 		}
-		else {
-			bw_Err error = bw_Err_new_with_msg( 1, result.c_str() );
 
-			callback( bw_handle, user_data, 0, &error );
-			bw_Err_free( &error );
-		}
+		// The result is dispatched to the GUI thread, just like the messages of the external handler.
+		// Because both come in through this handler, they reach the application in the order in which the renderer process has sent them.
+		auto dispatch_data = new EvalJsResultData {
+			bw_handle,
+			callback,
+			user_data,
+			success,
+			result,
+			name,
+			(unsigned int)( line > 0 ? line : 0 ),
+			(unsigned int)( column > 0 ? column : 0 )
+		};
+		bw_Application_dispatch(
+			bw_handle->window->app,
+			evalJsResultHandlerFunc,
+			dispatch_data
+		);
 	}
 
 	void onInvokeHandlerReceived(
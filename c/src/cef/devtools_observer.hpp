@@ -0,0 +1,189 @@
+#ifndef BW_CEF_DEVTOOLS_OBSERVER
+#define BW_CEF_DEVTOOLS_OBSERVER
+
+#include <include/base/cef_bind.h>
+#include <include/cef_browser.h>
+#include <include/cef_devtools_message_observer.h>
+#include <include/cef_task.h>
+#include <map>
+#include <mutex>
+#include <set>
+#include <string>
+
+#include "../application.h"
+#include "../browser_window.h"
+
+
+
+namespace bw {
+
+	// Sends the messages of the DevTools protocol for a browser window, and passes the results and events that come back on to the GUI thread.
+	// CEF only lets the messages be sent and observed on its UI thread.
+	class DevToolsObserver : public CefDevToolsMessageObserver {
+		// Guards everything below, as the GUI thread and CEF's UI thread both use it
+		std::mutex mutex;
+		bw_Application* app;
+		// Is set to null once the browser window is being destroyed
+		bw_BrowserWindow* bw;
+		std::set<std::string> events;
+		// The methods that are waiting for their result, by their message ID
+		std::map<int, std::pair<bw_BrowserWindowDevToolsResultFn, void*>> pending;
+		int next_id = 1;
+		CefRefPtr<CefRegistration> registration;
+
+		struct Event {
+			CefRefPtr<DevToolsObserver> observer;
+			std::string method;
+			std::string params;
+		};
+
+		struct Result {
+			CefRefPtr<DevToolsObserver> observer;
+			bw_BrowserWindowDevToolsResultFn callback;
+			void* data;
+			bool success;
+			std::string result;
+		};
+
+	public:
+		DevToolsObserver( bw_BrowserWindow* bw ) : app(bw->window->app), bw(bw) {}
+
+		// Calls the method on CEF's UI thread, and gives its result to the callback on the GUI thread.
+		void call( CefRefPtr<CefBrowser> browser, std::string method, std::string params, bw_BrowserWindowDevToolsResultFn callback, void* data ) {
+			int id;
+			{
+				std::lock_guard<std::mutex> lock( this->mutex );
+				id = this->next_id++;
+				this->pending[ id ] = std::make_pair( callback, data );
+			}
+
+			std::string message = "{\"id\":" + std::to_string( id ) + ",\"method\":\"" + escape( method ) + "\",\"params\":" + ( params.empty() ? "{}" : params ) + "}";
+			CefPostTask( TID_UI, base::Bind( &DevToolsObserver::send, this, browser, id, message ) );
+		}
+
+		// Lets go of the browser window, and fails the methods that are still waiting for their result.
+		// Needs to be called on the GUI thread, before the browser window is freed.
+		void detach() {
+			std::map<int, std::pair<bw_BrowserWindowDevToolsResultFn, void*>> pending;
+			bw_BrowserWindow* bw;
+			{
+				std::lock_guard<std::mutex> lock( this->mutex );
+				pending.swap( this->pending );
+				bw = this->bw;
+				this->bw = nullptr;
+			}
+
+			std::string error = "{\"message\":\"The browser window has been destroyed\"}";
+			for ( const auto& entry : pending )
+				entry.second.first( bw, entry.second.second, FALSE, { error.size(), error.c_str() } );
+
+			CefPostTask( TID_UI, base::Bind( &DevToolsObserver::unregister, this ) );
+		}
+
+		// Lets the event handler of the browser window receive the event with the given name.
+		void subscribe( CefRefPtr<CefBrowser> browser, std::string event ) {
+			{
+				std::lock_guard<std::mutex> lock( this->mutex );
+				this->events.insert( event );
+			}
+
+			CefPostTask( TID_UI, base::Bind( &DevToolsObserver::observe, this, browser ) );
+		}
+
+		virtual void OnDevToolsEvent( CefRefPtr<CefBrowser> browser, const CefString& method, const void* params, size_t params_size ) override {
+			(void)(browser);
+			std::string name = method.ToString();
+			{
+				std::lock_guard<std::mutex> lock( this->mutex );
+				if ( this->bw == nullptr || this->events.count( name ) == 0 )
+					return;
+			}
+
+			auto event = new Event { this, name, std::string( (const char*)params, params_size ) };
+			if ( !bw_Application_dispatch( this->app, &DevToolsObserver::deliverEvent, event ) )
+				delete event;
+		}
+
+		virtual void OnDevToolsMethodResult( CefRefPtr<CefBrowser> browser, int message_id, bool success, const void* result, size_t result_size ) override {
+			(void)(browser);
+			std::pair<bw_BrowserWindowDevToolsResultFn, void*> callback;
+			{
+				std::lock_guard<std::mutex> lock( this->mutex );
+				auto it = this->pending.find( message_id );
+				if ( it == this->pending.end() )
+					return;
+
+				callback = it->second;
+				this->pending.erase( it );
+			}
+
+			auto data = new Result { this, callback.first, callback.second, success, std::string( (const char*)result, result_size ) };
+			if ( !bw_Application_dispatch( this->app, &DevToolsObserver::deliverResult, data ) )
+				delete data;
+		}
+
+	protected:
+		static void deliverEvent( bw_Application* app, void* _data ) {
+			(void)(app);
+			Event* event = (Event*)_data;
+
+			// As this runs on the GUI thread, the browser window can't be destroyed in the meantime
+			bw_BrowserWindow* bw = event->observer->window();
+			if ( bw != nullptr && bw->on_devtools_event != 0 )
+				bw->on_devtools_event( bw, { event->method.size(), event->method.c_str() }, { event->params.size(), event->params.c_str() } );
+
+			delete event;
+		}
+
+		static void deliverResult( bw_Application* app, void* _data ) {
+			(void)(app);
+			Result* data = (Result*)_data;
+
+			// If the window has been destroyed, the callback has been invoked by `detach` already
+			bw_BrowserWindow* bw = data->observer->window();
+			if ( bw != nullptr )
+				data->callback( bw, data->data, data->success, { data->result.size(), data->result.c_str() } );
+
+			delete data;
+		}
+
+		static std::string escape( const std::string& string ) {
+			std::string escaped;
+			for ( char c : string ) {
+				if ( c == '"' || c == '\\' )
+					escaped += '\\';
+				escaped += c;
+			}
+			return escaped;
+		}
+
+		void observe( CefRefPtr<CefBrowser> browser ) {
+			if ( this->registration == nullptr && this->window() != nullptr )
+				this->registration = browser->GetHost()->AddDevToolsMessageObserver( this );
+		}
+
+		void send( CefRefPtr<CefBrowser> browser, int id, std::string message ) {
+			this->observe( browser );
+
+			if ( !browser->GetHost()->SendDevToolsMessage( message.data(), message.size() ) ) {
+				std::string error = "{\"message\":\"The message could not be sent\"}";
+				this->OnDevToolsMethodResult( browser, id, false, error.data(), error.size() );
+			}
+		}
+
+		void unregister() {
+			this->registration = nullptr;
+		}
+
+		bw_BrowserWindow* window() {
+			std::lock_guard<std::mutex> lock( this->mutex );
+			return this->bw;
+		}
+
+		IMPLEMENT_REFCOUNTING(DevToolsObserver);
+	};
+}
+
+
+
+#endif//BW_CEF_DEVTOOLS_OBSERVER
@@ -0,0 +1,114 @@
+#ifndef BW_CEF_REQUEST_HANDLER
+#define BW_CEF_REQUEST_HANDLER
+
+#include <include/cef_resource_request_handler.h>
+#include <mutex>
+#include <string>
+
+#include "scheme_handler.hpp"
+#include "../browser_window/request.hpp"
+
+
+
+namespace bw {
+
+	// Holds back a request of the page until the request handler of the browser window has decided on it, and carries out the decision.
+	class InterceptingResourceRequestHandler : public CefResourceRequestHandler {
+		bw_BrowserWindow* bw;
+		// Guards the response, as CEF and the application use it from different threads
+		std::mutex mutex;
+		// The response of the application, if it answers the request itself, until it is handed to CEF
+		bw_SchemeRequest* response = nullptr;
+
+	public:
+		InterceptingResourceRequestHandler( bw_BrowserWindow* bw ) : bw(bw) {}
+
+		~InterceptingResourceRequestHandler() {
+			if ( this->response != nullptr )
+				this->response->release();
+		}
+
+		virtual CefRefPtr<CefResourceHandler> GetResourceHandler( CefRefPtr<CefBrowser> browser, CefRefPtr<CefFrame> frame, CefRefPtr<CefRequest> request ) override {
+			(void)(browser);
+			(void)(frame);
+			(void)(request);
+			std::lock_guard<std::mutex> lock( this->mutex );
+
+			if ( this->response == nullptr )
+				return nullptr;
+
+			// The resource handler takes over the reference of the browser engine to the response
+			CefRefPtr<CefResourceHandler> handler = new SchemeResourceHandler( this->bw, this->response );
+			this->response = nullptr;
+			return handler;
+		}
+
+		virtual ReturnValue OnBeforeResourceLoad( CefRefPtr<CefBrowser> browser, CefRefPtr<CefFrame> frame, CefRefPtr<CefRequest> request, CefRefPtr<CefCallback> callback ) override {
+			(void)(browser);
+			(void)(frame);
+
+			std::string headers;
+			CefRequest::HeaderMap header_map;
+			request->GetHeaderMap( header_map );
+			for ( const auto& header : header_map )
+				headers += header.first.ToString() + ": " + header.second.ToString() + "\r\n";
+
+			// The decision is made on the GUI thread, the request and the callback can be used from any thread
+			CefRefPtr<InterceptingResourceRequestHandler> self = this;
+			bw_InterceptedRequest_dispatch( this->bw, request->GetMethod().ToString(), request->GetURL().ToString(), headers,
+				[self, request, callback]( bw_InterceptedRequestAction action, std::string value, bw_SchemeRequest* response ) {
+					switch ( action ) {
+					case BW_REQUEST_BLOCK:
+						callback->Cancel();
+						return;
+					case BW_REQUEST_CONTINUE:
+						request->SetHeaderMap( parseHeaders( value ) );
+						break;
+					case BW_REQUEST_REDIRECT:
+						// CEF redirects the request when its URL is changed here
+						request->SetURL( value );
+						break;
+					case BW_REQUEST_RESPOND: {
+						std::lock_guard<std::mutex> lock( self->mutex );
+						self->response = response;
+						break;
+					}
+					}
+					callback->Continue();
+				}
+			);
+			return RV_CONTINUE_ASYNC;
+		}
+
+	protected:
+		// Parses the `Name: value` lines of headers.
+		static CefRequest::HeaderMap parseHeaders( const std::string& lines ) {
+			CefRequest::HeaderMap headers;
+
+			size_t start = 0;
+			while ( start < lines.size() ) {
+				size_t end = lines.find( '\n', start );
+				if ( end == std::string::npos )
+					end = lines.size();
+
+				std::string line = lines.substr( start, end - start );
+				if ( !line.empty() && line.back() == '\r' )
+					line.pop_back();
+
+				size_t colon = line.find( ':' );
+				if ( colon != std::string::npos ) {
+					size_t value = line.find_first_not_of( ' ', colon + 1 );
+					headers.insert( std::make_pair( CefString( line.substr( 0, colon ) ), CefString( value == std::string::npos ? std::string() : line.substr( value ) ) ) );
+				}
+				start = end + 1;
+			}
+			return headers;
+		}
+
+		IMPLEMENT_REFCOUNTING(InterceptingResourceRequestHandler);
+	};
+}
+
+
+
+#endif//BW_CEF_REQUEST_HANDLER
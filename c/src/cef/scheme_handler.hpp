@@ -0,0 +1,235 @@
+#ifndef BW_CEF_SCHEME_HANDLER
+#define BW_CEF_SCHEME_HANDLER
+
+#include <include/cef_resource_handler.h>
+#include <include/cef_scheme.h>
+#include <mutex>
+#include <optional>
+#include <string>
+
+#include "bw_handle_map.hpp"
+#include "../browser_window/scheme.hpp"
+
+
+
+namespace bw {
+
+	// Answers a request of a custom scheme, with the response that the scheme handler of the browser window writes.
+	// It also answers the intercepted requests that the application responds to itself, in which case it is given the request to read the response from.
+	class SchemeResourceHandler : public CefResourceHandler {
+		bw_BrowserWindow* bw;
+		// Guards everything below, as CEF and the application use it from different threads
+		std::mutex mutex;
+		bw_SchemeRequest* request = nullptr;
+		// The read that waits for more of the body, which is completed once the application has written it
+		void* pending_data = nullptr;
+		int pending_size = 0;
+		CefRefPtr<CefResourceReadCallback> pending_callback;
+
+	public:
+		SchemeResourceHandler( bw_BrowserWindow* bw ) : bw(bw) {}
+		SchemeResourceHandler( bw_BrowserWindow* bw, bw_SchemeRequest* request ) : bw(bw), request(request) {}
+
+		~SchemeResourceHandler() {
+			if ( this->request != nullptr )
+				this->request->release();
+		}
+
+		virtual void Cancel() override {
+			std::lock_guard<std::mutex> lock( this->mutex );
+
+			this->pending_callback = nullptr;
+			this->releaseRequest();
+		}
+
+		virtual void GetResponseHeaders( CefRefPtr<CefResponse> response, int64& response_length, CefString& redirect_url ) override {
+			(void)(redirect_url);
+			std::lock_guard<std::mutex> lock( this->mutex );
+
+			response_length = -1;
+			if ( this->request == nullptr ) {
+				response->SetStatus( 500 );
+				return;
+			}
+
+			CefResponse::HeaderMap headers;
+			{
+				std::lock_guard<std::mutex> request_lock( this->request->mutex );
+
+				response->SetStatus( this->request->status );
+				for ( const auto& header : this->request->headers )
+					headers.insert( std::make_pair( CefString( header.first ), CefString( header.second ) ) );
+			}
+			response->SetHeaderMap( headers );
+
+			// CEF takes the MIME type and charset separately from the headers
+			std::string content_type = this->request->header( "Content-Type" );
+			size_t separator = content_type.find( ';' );
+			response->SetMimeType( content_type.substr( 0, separator ) );
+			if ( separator != std::string::npos ) {
+				size_t charset = content_type.find( "charset=", separator );
+				if ( charset != std::string::npos )
+					response->SetCharset( content_type.substr( charset + 8 ) );
+			}
+
+			std::string content_length = this->request->header( "Content-Length" );
+			if ( !content_length.empty() )
+				response_length = std::stoll( content_length );
+		}
+
+		virtual bool Open( CefRefPtr<CefRequest> cef_request, bool& handle_request, CefRefPtr<CefCallback> callback ) override {
+			// The response is given once the scheme handler has responded, which happens on the GUI thread
+			handle_request = false;
+
+			std::lock_guard<std::mutex> lock( this->mutex );
+			// Keeps this handler alive until the request is released, which is when the body has ended or the request has been cancelled
+			CefRefPtr<SchemeResourceHandler> self = this;
+
+			// An intercepted request that the application answers has been given its request already
+			if ( this->request != nullptr ) {
+				std::lock_guard<std::mutex> request_lock( this->request->mutex );
+
+				this->request->on_data = [self]() { self->completeRead(); };
+				if ( this->request->responded )
+					handle_request = true;
+				else
+					this->request->on_response = [callback]() { callback->Continue(); };
+				return true;
+			}
+
+			std::string headers;
+			CefRequest::HeaderMap header_map;
+			cef_request->GetHeaderMap( header_map );
+			for ( const auto& header : header_map )
+				headers += header.first.ToString() + ": " + header.second.ToString() + "\r\n";
+
+			std::string body;
+			CefRefPtr<CefPostData> post_data = cef_request->GetPostData();
+			if ( post_data != nullptr ) {
+				CefPostData::ElementVector elements;
+				post_data->GetElements( elements );
+
+				// Only the parts of the body that are in memory are passed on, not the files of uploads
+				for ( const auto& element : elements ) {
+					if ( element->GetType() != PDE_TYPE_BYTES )
+						continue;
+
+					std::string bytes( element->GetBytesCount(), '\0' );
+					element->GetBytes( bytes.size(), &bytes[0] );
+					body += bytes;
+				}
+			}
+
+			this->request = bw_SchemeRequest_new(
+				this->bw,
+				cef_request->GetMethod().ToString(),
+				cef_request->GetURL().ToString(),
+				headers,
+				body,
+				[callback]() { callback->Continue(); }
+			);
+
+			std::lock_guard<std::mutex> request_lock( this->request->mutex );
+			this->request->on_data = [self]() { self->completeRead(); };
+			return true;
+		}
+
+		virtual bool Read( void* data_out, int bytes_to_read, int& bytes_read, CefRefPtr<CefResourceReadCallback> callback ) override {
+			std::lock_guard<std::mutex> lock( this->mutex );
+
+			bytes_read = 0;
+			if ( this->request == nullptr )
+				return false;
+
+			// If nothing is available yet, the read is completed later on by `completeRead`
+			this->pending_data = data_out;
+			this->pending_size = bytes_to_read;
+			this->pending_callback = callback;
+
+			long count = this->request->read( data_out, bytes_to_read, false );
+			if ( count == BW_SCHEME_READ_FAILED ) {
+				this->pending_callback = nullptr;
+				this->releaseRequest();
+				bytes_read = ERR_FAILED;
+				return false;
+			}
+			if ( count < 0 )
+				return true;
+
+			this->pending_callback = nullptr;
+			if ( count == 0 ) {
+				this->releaseRequest();
+				return false;
+			}
+
+			bytes_read = (int)count;
+			return true;
+		}
+
+	protected:
+		// Is invoked by the request when more of the body has been written while CEF waits for it.
+		void completeRead() {
+			CefRefPtr<CefResourceReadCallback> callback;
+			long count;
+			{
+				std::lock_guard<std::mutex> lock( this->mutex );
+				if ( this->pending_callback == nullptr || this->request == nullptr )
+					return;
+
+				count = this->request->read( this->pending_data, this->pending_size, false );
+				if ( count == BW_SCHEME_READ_FAILED )
+					count = ERR_FAILED;
+				else if ( count < 0 )
+					return;
+
+				callback = this->pending_callback;
+				this->pending_callback = nullptr;
+				if ( count <= 0 )
+					this->releaseRequest();
+			}
+
+			// A count of 0 lets CEF know that the body has ended, and a negative one that it has failed
+			callback->Continue( (int)count );
+		}
+
+		void releaseRequest() {
+			if ( this->request != nullptr ) {
+				this->request->release();
+				this->request = nullptr;
+			}
+		}
+
+		IMPLEMENT_REFCOUNTING(SchemeResourceHandler);
+	};
+
+	// Creates the handlers for the requests of the custom schemes of the application.
+	class SchemeHandlerFactory : public CefSchemeHandlerFactory {
+	public:
+		virtual CefRefPtr<CefResourceHandler> Create(
+			CefRefPtr<CefBrowser> browser,
+			CefRefPtr<CefFrame> frame,
+			const CefString& scheme_name,
+			CefRefPtr<CefRequest> request
+		) override {
+			(void)(frame);
+			(void)(scheme_name);
+			(void)(request);
+
+			// Requests that don't come from a browser window, like the ones of service workers, can't be answered
+			if ( browser == nullptr )
+				return nullptr;
+
+			std::optional<bw_BrowserWindow*> bw_handle = bw::bw_handle_map.fetch( browser );
+			if ( !bw_handle.has_value() )
+				return nullptr;
+
+			return new SchemeResourceHandler( *bw_handle );
+		}
+
+		IMPLEMENT_REFCOUNTING(SchemeHandlerFactory);
+	};
+}
+
+
+
+#endif//BW_CEF_SCHEME_HANDLER
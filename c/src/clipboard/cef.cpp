@@ -0,0 +1,65 @@
+#include "../application.h"
+#include "../common.h"
+
+
+
+// TODO: Implement the clipboard for when CEF provides the windows itself
+BOOL bw_Application_readClipboardFiles( bw_Application* app, bw_StrSlice* paths ) {
+	UNUSED( app );
+	UNUSED( paths );
+
+	return FALSE;
+}
+
+BOOL bw_Application_readClipboardHtml( bw_Application* app, bw_StrSlice* html ) {
+	UNUSED( app );
+	UNUSED( html );
+
+	return FALSE;
+}
+
+BOOL bw_Application_readClipboardImage( bw_Application* app, uint8_t** pixels, uint32_t* width, uint32_t* height ) {
+	UNUSED( app );
+	UNUSED( pixels );
+	UNUSED( width );
+	UNUSED( height );
+
+	return FALSE;
+}
+
+BOOL bw_Application_readClipboardText( bw_Application* app, bw_StrSlice* text ) {
+	UNUSED( app );
+	UNUSED( text );
+
+	return FALSE;
+}
+
+BOOL bw_Application_writeClipboardFiles( bw_Application* app, bw_CStrSlice paths ) {
+	UNUSED( app );
+	UNUSED( paths );
+
+	return FALSE;
+}
+
+BOOL bw_Application_writeClipboardHtml( bw_Application* app, bw_CStrSlice html ) {
+	UNUSED( app );
+	UNUSED( html );
+
+	return FALSE;
+}
+
+BOOL bw_Application_writeClipboardImage( bw_Application* app, const uint8_t* pixels, uint32_t width, uint32_t height ) {
+	UNUSED( app );
+	UNUSED( pixels );
+	UNUSED( width );
+	UNUSED( height );
+
+	return FALSE;
+}
+
+BOOL bw_Application_writeClipboardText( bw_Application* app, bw_CStrSlice text ) {
+	UNUSED( app );
+	UNUSED( text );
+
+	return FALSE;
+}
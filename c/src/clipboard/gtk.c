@@ -0,0 +1,274 @@
+#include "../application.h"
+#include "../common.h"
+
+#include <gtk/gtk.h>
+#include <stdlib.h>
+#include <string.h>
+
+
+
+// The contents that have been put on the clipboard, which are given to other applications in the format that they ask for.
+typedef struct {
+	char* html;
+	char* uri_list;
+	char* gnome_copied_files;
+} _bw_ClipboardGtkContents;
+
+// The formats of `_bw_ClipboardGtkContents`, which are the info of the targets.
+enum {
+	_BW_CLIPBOARD_GTK_HTML,
+	_BW_CLIPBOARD_GTK_URI_LIST,
+	_BW_CLIPBOARD_GTK_GNOME_COPIED_FILES
+};
+
+
+
+void _bw_ClipboardGtk_clearContents( GtkClipboard* clipboard, gpointer data );
+void _bw_ClipboardGtk_freePixels( guchar* pixels, gpointer data );
+void _bw_ClipboardGtk_getContents( GtkClipboard* clipboard, GtkSelectionData* selection, guint info, gpointer data );
+BOOL _bw_ClipboardGtk_readContents( const char* target, bw_StrSlice* output );
+
+
+
+BOOL bw_Application_readClipboardFiles( bw_Application* app, bw_StrSlice* paths ) {
+	bw_Application_assertCorrectThread( app );
+
+	// This runs the main loop until the owner of the clipboard has responded
+	GtkClipboard* clipboard = gtk_clipboard_get( GDK_SELECTION_CLIPBOARD );
+	gchar** uris = gtk_clipboard_wait_for_uris( clipboard );
+
+	// GNOME's file manager only offers its own format
+	if ( uris == NULL ) {
+		bw_StrSlice copied;
+		if ( !_bw_ClipboardGtk_readContents( "x-special/gnome-copied-files", &copied ) )
+			return FALSE;
+
+		// The first line is either "copy" or "cut", and every other line is a URI
+		char* string = g_strndup( copied.data, copied.len );
+		bw_string_free( copied );
+		gchar** lines = g_strsplit( string, "\n", -1 );
+		g_free( string );
+
+		uris = lines[0] != NULL ? g_strdupv( lines + 1 ) : NULL;
+		g_strfreev( lines );
+		if ( uris == NULL )
+			return FALSE;
+	}
+
+	paths->len = 0;
+	paths->data = NULL;
+	for ( gchar** uri = uris; *uri != NULL; uri++ ) {
+
+		// Only local files have paths
+		gchar* path = g_filename_from_uri( *uri, NULL, NULL );
+		if ( path == NULL )
+			continue;
+
+		// The terminating null character is kept, as it separates the paths
+		size_t len = strlen( path ) + 1;
+		paths->data = (char*)realloc( paths->data, paths->len + len );
+		memcpy( paths->data + paths->len, path, len );
+		paths->len += len;
+		g_free( path );
+	}
+	g_strfreev( uris );
+
+	return paths->len > 0;
+}
+
+BOOL bw_Application_readClipboardHtml( bw_Application* app, bw_StrSlice* html ) {
+	bw_Application_assertCorrectThread( app );
+
+	if ( !_bw_ClipboardGtk_readContents( "text/html", html ) )
+		return FALSE;
+
+	// Some browsers offer the HTML in UTF-16, with a byte order mark
+	if ( html->len >= 2 && (uint8_t)html->data[0] == 0xFF && (uint8_t)html->data[1] == 0xFE ) {
+		glong len;
+		gchar* utf8 = g_utf16_to_utf8( (const gunichar2*)(html->data + 2), (glong)(html->len - 2) / 2, NULL, &len, NULL );
+		bw_string_free( *html );
+		if ( utf8 == NULL )
+			return FALSE;
+
+		html->len = (size_t)len;
+		html->data = (char*)malloc( html->len );
+		memcpy( html->data, utf8, html->len );
+		g_free( utf8 );
+	}
+
+	return TRUE;
+}
+
+BOOL bw_Application_readClipboardImage( bw_Application* app, uint8_t** pixels, uint32_t* width, uint32_t* height ) {
+	bw_Application_assertCorrectThread( app );
+
+	// This runs the main loop until the owner of the clipboard has responded
+	GtkClipboard* clipboard = gtk_clipboard_get( GDK_SELECTION_CLIPBOARD );
+	GdkPixbuf* image = gtk_clipboard_wait_for_image( clipboard );
+	if ( image == NULL )
+		return FALSE;
+
+	*width = (uint32_t)gdk_pixbuf_get_width( image );
+	*height = (uint32_t)gdk_pixbuf_get_height( image );
+	int channels = gdk_pixbuf_get_n_channels( image );
+	int stride = gdk_pixbuf_get_rowstride( image );
+	const guchar* src = gdk_pixbuf_read_pixels( image );
+
+	*pixels = (uint8_t*)malloc( (size_t)*width * *height * 4 );
+	for ( uint32_t y = 0; y < *height; y++ ) {
+		const guchar* row = src + (size_t)y * stride;
+		uint8_t* dest = *pixels + (size_t)y * *width * 4;
+
+		for ( uint32_t x = 0; x < *width; x++ ) {
+			const guchar* pixel = row + x * channels;
+			dest[ x * 4 ] = pixel[0];
+			dest[ x * 4 + 1 ] = pixel[1];
+			dest[ x * 4 + 2 ] = pixel[2];
+			dest[ x * 4 + 3 ] = gdk_pixbuf_get_has_alpha( image ) ? pixel[3] : 0xFF;
+		}
+	}
+
+	g_object_unref( image );
+	return TRUE;
+}
+
+BOOL bw_Application_readClipboardText( bw_Application* app, bw_StrSlice* text ) {
+	bw_Application_assertCorrectThread( app );
+
+	// This runs the main loop until the owner of the clipboard has responded
+	GtkClipboard* clipboard = gtk_clipboard_get( GDK_SELECTION_CLIPBOARD );
+	gchar* string = gtk_clipboard_wait_for_text( clipboard );
+	if ( string == NULL )
+		return FALSE;
+
+	// The string is copied, because it needs to be freed with `bw_string_free`
+	text->len = strlen( string );
+	text->data = (char*)malloc( text->len );
+	memcpy( text->data, string, text->len );
+
+	g_free( string );
+	return TRUE;
+}
+
+BOOL bw_Application_writeClipboardFiles( bw_Application* app, bw_CStrSlice paths ) {
+	bw_Application_assertCorrectThread( app );
+
+	GString* uri_list = g_string_new( NULL );
+	GString* gnome_copied_files = g_string_new( "copy" );
+
+	// The paths are separated by null characters
+	for ( const char* path = paths.data; path < paths.data + paths.len; path += strlen( path ) + 1 ) {
+		gchar* uri = g_filename_to_uri( path, NULL, NULL );
+		if ( uri == NULL )
+			continue;
+
+		g_string_append_printf( uri_list, "%s\r\n", uri );
+		g_string_append_printf( gnome_copied_files, "\n%s", uri );
+		g_free( uri );
+	}
+
+	_bw_ClipboardGtkContents* contents = (_bw_ClipboardGtkContents*)calloc( 1, sizeof( _bw_ClipboardGtkContents ) );
+	contents->uri_list = g_string_free( uri_list, FALSE );
+	contents->gnome_copied_files = g_string_free( gnome_copied_files, FALSE );
+
+	GtkTargetEntry targets[] = {
+		{ "text/uri-list", 0, _BW_CLIPBOARD_GTK_URI_LIST },
+		{ "x-special/gnome-copied-files", 0, _BW_CLIPBOARD_GTK_GNOME_COPIED_FILES }
+	};
+	BOOL result = gtk_clipboard_set_with_data( gtk_clipboard_get( GDK_SELECTION_CLIPBOARD ), targets, 2, _bw_ClipboardGtk_getContents, _bw_ClipboardGtk_clearContents, contents );
+	if ( !result )
+		_bw_ClipboardGtk_clearContents( NULL, contents );
+	return result;
+}
+
+BOOL bw_Application_writeClipboardHtml( bw_Application* app, bw_CStrSlice html ) {
+	bw_Application_assertCorrectThread( app );
+
+	_bw_ClipboardGtkContents* contents = (_bw_ClipboardGtkContents*)calloc( 1, sizeof( _bw_ClipboardGtkContents ) );
+	contents->html = g_strndup( html.data, html.len );
+
+	GtkTargetEntry targets[] = {
+		{ "text/html", 0, _BW_CLIPBOARD_GTK_HTML }
+	};
+	BOOL result = gtk_clipboard_set_with_data( gtk_clipboard_get( GDK_SELECTION_CLIPBOARD ), targets, 1, _bw_ClipboardGtk_getContents, _bw_ClipboardGtk_clearContents, contents );
+	if ( !result )
+		_bw_ClipboardGtk_clearContents( NULL, contents );
+	return result;
+}
+
+BOOL bw_Application_writeClipboardImage( bw_Application* app, const uint8_t* pixels, uint32_t width, uint32_t height ) {
+	bw_Application_assertCorrectThread( app );
+
+	size_t size = (size_t)width * height * 4;
+	guchar* copy = (guchar*)malloc( size );
+	memcpy( copy, pixels, size );
+
+	GdkPixbuf* image = gdk_pixbuf_new_from_data( copy, GDK_COLORSPACE_RGB, TRUE, 8, (int)width, (int)height, (int)width * 4, _bw_ClipboardGtk_freePixels, NULL );
+	gtk_clipboard_set_image( gtk_clipboard_get( GDK_SELECTION_CLIPBOARD ), image );
+	g_object_unref( image );
+
+	return TRUE;
+}
+
+BOOL bw_Application_writeClipboardText( bw_Application* app, bw_CStrSlice text ) {
+	bw_Application_assertCorrectThread( app );
+
+	gtk_clipboard_set_text( gtk_clipboard_get( GDK_SELECTION_CLIPBOARD ), text.data, (gint)text.len );
+	return TRUE;
+}
+
+
+
+// Invoked when something else has been put on the clipboard.
+void _bw_ClipboardGtk_clearContents( GtkClipboard* clipboard, gpointer data ) {
+	UNUSED( clipboard );
+	_bw_ClipboardGtkContents* contents = (_bw_ClipboardGtkContents*)data;
+
+	g_free( contents->html );
+	g_free( contents->uri_list );
+	g_free( contents->gnome_copied_files );
+	free( contents );
+}
+
+void _bw_ClipboardGtk_freePixels( guchar* pixels, gpointer data ) {
+	UNUSED( data );
+
+	free( pixels );
+}
+
+// Invoked when another application asks for the contents of the clipboard.
+void _bw_ClipboardGtk_getContents( GtkClipboard* clipboard, GtkSelectionData* selection, guint info, gpointer data ) {
+	UNUSED( clipboard );
+	_bw_ClipboardGtkContents* contents = (_bw_ClipboardGtkContents*)data;
+
+	const char* string = NULL;
+	switch ( info ) {
+	case _BW_CLIPBOARD_GTK_HTML: string = contents->html; break;
+	case _BW_CLIPBOARD_GTK_URI_LIST: string = contents->uri_list; break;
+	case _BW_CLIPBOARD_GTK_GNOME_COPIED_FILES: string = contents->gnome_copied_files; break;
+	}
+
+	if ( string != NULL )
+		gtk_selection_data_set( selection, gtk_selection_data_get_target( selection ), 8, (const guchar*)string, (gint)strlen( string ) );
+}
+
+// Gives the contents of the clipboard for the given target, copied so that they can be freed with `bw_string_free`.
+BOOL _bw_ClipboardGtk_readContents( const char* target, bw_StrSlice* output ) {
+
+	// This runs the main loop until the owner of the clipboard has responded
+	GtkClipboard* clipboard = gtk_clipboard_get( GDK_SELECTION_CLIPBOARD );
+	GtkSelectionData* selection = gtk_clipboard_wait_for_contents( clipboard, gdk_atom_intern( target, FALSE ) );
+	if ( selection == NULL )
+		return FALSE;
+
+	gint len = gtk_selection_data_get_length( selection );
+	BOOL result = len >= 0;
+	if ( result ) {
+		output->len = (size_t)len;
+		output->data = (char*)malloc( output->len );
+		memcpy( output->data, gtk_selection_data_get_data( selection ), output->len );
+	}
+
+	gtk_selection_data_free( selection );
+	return result;
+}
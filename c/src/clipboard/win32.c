@@ -0,0 +1,359 @@
+#include "../application.h"
+#include "../common.h"
+#include "../win32.h"
+
+#include <shlobj.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <wchar.h>
+
+
+
+// The header of the "HTML Format", in which the offsets are always written with 10 digits so that its length is known beforehand.
+#define BW_CLIPBOARD_WIN32_HTML_HEADER "Version:0.9\r\nStartHTML:%010u\r\nEndHTML:%010u\r\nStartFragment:%010u\r\nEndFragment:%010u\r\n"
+#define BW_CLIPBOARD_WIN32_HTML_PREFIX "<html><body>\r\n<!--StartFragment-->"
+#define BW_CLIPBOARD_WIN32_HTML_SUFFIX "<!--EndFragment-->\r\n</body></html>"
+
+
+
+UINT bw_ClipboardWin32_htmlFormat( void );
+long bw_ClipboardWin32_htmlOffset( const char* data, size_t size, const char* key );
+BOOL bw_ClipboardWin32_open( void );
+void bw_ClipboardWin32_readPixels( const BITMAPINFOHEADER* header, const uint8_t* bits, uint8_t* pixels );
+BOOL bw_ClipboardWin32_write( UINT format, HGLOBAL memory );
+
+
+
+BOOL bw_Application_readClipboardFiles( bw_Application* app, bw_StrSlice* paths ) {
+	bw_Application_assertCorrectThread( app );
+
+	if ( !IsClipboardFormatAvailable( CF_HDROP ) || !bw_ClipboardWin32_open() )
+		return FALSE;
+
+	BOOL result = FALSE;
+	HDROP drop = (HDROP)GetClipboardData( CF_HDROP );
+
+	if ( drop != NULL ) {
+		UINT count = DragQueryFileW( drop, 0xFFFFFFFF, NULL, 0 );
+		paths->len = 0;
+		paths->data = NULL;
+
+		for ( UINT i = 0; i < count; i++ ) {
+			UINT len = DragQueryFileW( drop, i, NULL, 0 );
+			WCHAR* path = (WCHAR*)malloc( (len + 1) * sizeof( WCHAR ) );
+			DragQueryFileW( drop, i, path, len + 1 );
+
+			// The terminating null character is kept, as it separates the paths
+			char* utf8;
+			size_t utf8_len = bw_win32_copyAsNewUtf8Str( path, &utf8 );
+			paths->data = (char*)realloc( paths->data, paths->len + utf8_len );
+			memcpy( paths->data + paths->len, utf8, utf8_len );
+			paths->len += utf8_len;
+
+			free( utf8 );
+			free( path );
+		}
+
+		result = count > 0;
+		if ( !result )
+			free( paths->data );
+	}
+
+	CloseClipboard();
+	return result;
+}
+
+BOOL bw_Application_readClipboardHtml( bw_Application* app, bw_StrSlice* html ) {
+	bw_Application_assertCorrectThread( app );
+
+	UINT format = bw_ClipboardWin32_htmlFormat();
+	if ( !IsClipboardFormatAvailable( format ) || !bw_ClipboardWin32_open() )
+		return FALSE;
+
+	BOOL result = FALSE;
+	HGLOBAL memory = GetClipboardData( format );
+	const char* data = memory != NULL ? (const char*)GlobalLock( memory ) : NULL;
+
+	// The data is UTF-8, with a header that contains the offsets of the fragment in bytes
+	if ( data != NULL ) {
+		size_t size = GlobalSize( memory );
+		long start = bw_ClipboardWin32_htmlOffset( data, size, "StartFragment:" );
+		long end = bw_ClipboardWin32_htmlOffset( data, size, "EndFragment:" );
+
+		if ( start >= 0 && end >= start && (size_t)end <= size ) {
+			html->len = (size_t)(end - start);
+			html->data = (char*)malloc( html->len );
+			memcpy( html->data, data + start, html->len );
+			result = TRUE;
+		}
+
+		GlobalUnlock( memory );
+	}
+
+	CloseClipboard();
+	return result;
+}
+
+BOOL bw_Application_readClipboardImage( bw_Application* app, uint8_t** pixels, uint32_t* width, uint32_t* height ) {
+	bw_Application_assertCorrectThread( app );
+
+	if ( !IsClipboardFormatAvailable( CF_DIB ) || !bw_ClipboardWin32_open() )
+		return FALSE;
+
+	BOOL result = FALSE;
+	HGLOBAL memory = GetClipboardData( CF_DIB );
+	const BITMAPINFOHEADER* header = memory != NULL ? (const BITMAPINFOHEADER*)GlobalLock( memory ) : NULL;
+
+	// Only uncompressed bitmaps of 24 or 32 bits are supported, which is what applications put on the clipboard in practice
+	if ( header != NULL ) {
+		BOOL is_supported = (header->biBitCount == 24 || header->biBitCount == 32) &&
+			(header->biCompression == BI_RGB || (header->biCompression == BI_BITFIELDS && header->biBitCount == 32));
+
+		if ( is_supported && header->biWidth > 0 && header->biHeight != 0 ) {
+			*width = (uint32_t)header->biWidth;
+			*height = (uint32_t)(header->biHeight > 0 ? header->biHeight : -header->biHeight);
+
+			// The color masks of BI_BITFIELDS come after the header, unless it is one of the newer headers that contain them
+			BOOL has_masks = header->biCompression == BI_BITFIELDS && header->biSize == sizeof( BITMAPINFOHEADER );
+			const uint8_t* bits = (const uint8_t*)header + header->biSize + (has_masks ? 3 * sizeof( DWORD ) : 0);
+
+			*pixels = (uint8_t*)malloc( (size_t)*width * *height * 4 );
+			bw_ClipboardWin32_readPixels( header, bits, *pixels );
+			result = TRUE;
+		}
+
+		GlobalUnlock( memory );
+	}
+
+	CloseClipboard();
+	return result;
+}
+
+BOOL bw_Application_readClipboardText( bw_Application* app, bw_StrSlice* text ) {
+	bw_Application_assertCorrectThread( app );
+
+	if ( !IsClipboardFormatAvailable( CF_UNICODETEXT ) || !bw_ClipboardWin32_open() )
+		return FALSE;
+
+	BOOL result = FALSE;
+	HGLOBAL memory = GetClipboardData( CF_UNICODETEXT );
+	const WCHAR* string = memory != NULL ? (const WCHAR*)GlobalLock( memory ) : NULL;
+
+	if ( string != NULL ) {
+		// The length includes the terminating null character
+		text->len = bw_win32_copyAsNewUtf8Str( string, &text->data ) - 1;
+		result = TRUE;
+
+		GlobalUnlock( memory );
+	}
+
+	CloseClipboard();
+	return result;
+}
+
+BOOL bw_Application_writeClipboardFiles( bw_Application* app, bw_CStrSlice paths ) {
+	bw_Application_assertCorrectThread( app );
+
+	// The paths are converted one by one, as they are separated by null characters
+	size_t count = 0;
+	for ( size_t i = 0; i < paths.len; i++ ) {
+		if ( paths.data[i] == '\0' )
+			count++;
+	}
+	WCHAR** wpaths = (WCHAR**)malloc( count * sizeof( WCHAR* ) );
+	size_t total_len = 1;	// The list ends with an additional null character
+
+	const char* path = paths.data;
+	for ( size_t i = 0; i < count; i++ ) {
+		bw_CStrSlice slice = { strlen( path ), path };
+		wpaths[i] = bw_win32_copyAsNewWstr( slice );
+		total_len += wcslen( wpaths[i] ) + 1;
+		path += slice.len + 1;
+	}
+
+	HGLOBAL memory = GlobalAlloc( GMEM_MOVEABLE | GMEM_ZEROINIT, sizeof( DROPFILES ) + total_len * sizeof( WCHAR ) );
+	if ( memory != NULL ) {
+		DROPFILES* drop = (DROPFILES*)GlobalLock( memory );
+		drop->pFiles = sizeof( DROPFILES );
+		drop->fWide = TRUE;
+
+		WCHAR* list = (WCHAR*)(drop + 1);
+		for ( size_t i = 0; i < count; i++ ) {
+			size_t len = wcslen( wpaths[i] ) + 1;
+			memcpy( list, wpaths[i], len * sizeof( WCHAR ) );
+			list += len;
+		}
+		GlobalUnlock( memory );
+	}
+
+	for ( size_t i = 0; i < count; i++ )
+		free( wpaths[i] );
+	free( wpaths );
+
+	if ( memory == NULL )
+		return FALSE;
+	return bw_ClipboardWin32_write( CF_HDROP, memory );
+}
+
+BOOL bw_Application_writeClipboardHtml( bw_Application* app, bw_CStrSlice html ) {
+	bw_Application_assertCorrectThread( app );
+
+	size_t header_len = (size_t)snprintf( NULL, 0, BW_CLIPBOARD_WIN32_HTML_HEADER, 0, 0, 0, 0 );
+	unsigned int start_fragment = (unsigned int)(header_len + strlen( BW_CLIPBOARD_WIN32_HTML_PREFIX ));
+	unsigned int end_fragment = start_fragment + (unsigned int)html.len;
+	unsigned int end_html = end_fragment + (unsigned int)strlen( BW_CLIPBOARD_WIN32_HTML_SUFFIX );
+
+	HGLOBAL memory = GlobalAlloc( GMEM_MOVEABLE, end_html + 1 );
+	if ( memory == NULL )
+		return FALSE;
+
+	char* data = (char*)GlobalLock( memory );
+	snprintf( data, header_len + 1, BW_CLIPBOARD_WIN32_HTML_HEADER, (unsigned int)header_len, end_html, start_fragment, end_fragment );
+	memcpy( data + header_len, BW_CLIPBOARD_WIN32_HTML_PREFIX, start_fragment - header_len );
+	memcpy( data + start_fragment, html.data, html.len );
+	memcpy( data + end_fragment, BW_CLIPBOARD_WIN32_HTML_SUFFIX, end_html - end_fragment );
+	data[ end_html ] = '\0';
+	GlobalUnlock( memory );
+
+	return bw_ClipboardWin32_write( bw_ClipboardWin32_htmlFormat(), memory );
+}
+
+BOOL bw_Application_writeClipboardImage( bw_Application* app, const uint8_t* pixels, uint32_t width, uint32_t height ) {
+	bw_Application_assertCorrectThread( app );
+
+	size_t pixels_size = (size_t)width * height * 4;
+	HGLOBAL memory = GlobalAlloc( GMEM_MOVEABLE, sizeof( BITMAPINFOHEADER ) + pixels_size );
+	if ( memory == NULL )
+		return FALSE;
+
+	// A bottom-up bitmap of 32 bits, which is understood by the most applications
+	BITMAPINFOHEADER* header = (BITMAPINFOHEADER*)GlobalLock( memory );
+	memset( header, 0, sizeof( BITMAPINFOHEADER ) );
+	header->biSize = sizeof( BITMAPINFOHEADER );
+	header->biWidth = (LONG)width;
+	header->biHeight = (LONG)height;
+	header->biPlanes = 1;
+	header->biBitCount = 32;
+	header->biCompression = BI_RGB;
+	header->biSizeImage = (DWORD)pixels_size;
+
+	uint8_t* bits = (uint8_t*)(header + 1);
+	for ( uint32_t y = 0; y < height; y++ ) {
+		const uint8_t* src = pixels + (size_t)y * width * 4;
+		uint8_t* dest = bits + (size_t)(height - y - 1) * width * 4;
+
+		for ( uint32_t x = 0; x < width; x++ ) {
+			dest[ x * 4 ] = src[ x * 4 + 2 ];
+			dest[ x * 4 + 1 ] = src[ x * 4 + 1 ];
+			dest[ x * 4 + 2 ] = src[ x * 4 ];
+			dest[ x * 4 + 3 ] = src[ x * 4 + 3 ];
+		}
+	}
+	GlobalUnlock( memory );
+
+	return bw_ClipboardWin32_write( CF_DIB, memory );
+}
+
+BOOL bw_Application_writeClipboardText( bw_Application* app, bw_CStrSlice text ) {
+	bw_Application_assertCorrectThread( app );
+
+	WCHAR* string = bw_win32_copyAsNewWstr( text );
+	size_t size = (wcslen( string ) + 1) * sizeof( WCHAR );
+
+	HGLOBAL memory = GlobalAlloc( GMEM_MOVEABLE, size );
+	if ( memory == NULL ) {
+		free( string );
+		return FALSE;
+	}
+
+	memcpy( GlobalLock( memory ), string, size );
+	GlobalUnlock( memory );
+	free( string );
+
+	return bw_ClipboardWin32_write( CF_UNICODETEXT, memory );
+}
+
+
+
+// The format that browsers and office applications exchange HTML with.
+UINT bw_ClipboardWin32_htmlFormat( void ) {
+	static UINT format = 0;
+
+	if ( format == 0 )
+		format = RegisterClipboardFormatW( L"HTML Format" );
+	return format;
+}
+
+// Gives the number that follows `key` in the header of the HTML format, or -1 if it isn't there.
+// The header ends where the HTML starts.
+long bw_ClipboardWin32_htmlOffset( const char* data, size_t size, const char* key ) {
+	size_t key_len = strlen( key );
+
+	for ( size_t i = 0; i + key_len < size && data[i] != '<'; i++ ) {
+		if ( memcmp( data + i, key, key_len ) == 0 )
+			return strtol( data + i + key_len, NULL, 10 );
+	}
+
+	return -1;
+}
+
+// Another application may have the clipboard opened briefly, so it is tried a few times.
+BOOL bw_ClipboardWin32_open( void ) {
+	for ( int i = 0; i < 10; i++ ) {
+		if ( OpenClipboard( NULL ) )
+			return TRUE;
+		Sleep( 10 );
+	}
+
+	return FALSE;
+}
+
+// Converts the bitmap's BGR(A) pixels to RGBA pixels that go from the top down.
+void bw_ClipboardWin32_readPixels( const BITMAPINFOHEADER* header, const uint8_t* bits, uint8_t* pixels ) {
+	uint32_t width = (uint32_t)header->biWidth;
+	uint32_t height = (uint32_t)(header->biHeight > 0 ? header->biHeight : -header->biHeight);
+	size_t bytes_per_pixel = header->biBitCount / 8;
+	// The rows of bitmaps are aligned to 4 bytes
+	size_t stride = ((width * header->biBitCount + 31) / 32) * 4;
+	BOOL has_alpha = FALSE;
+
+	for ( uint32_t y = 0; y < height; y++ ) {
+		// Bitmaps with a positive height go from the bottom up
+		const uint8_t* src = bits + (header->biHeight > 0 ? height - y - 1 : y) * stride;
+		uint8_t* dest = pixels + (size_t)y * width * 4;
+
+		for ( uint32_t x = 0; x < width; x++ ) {
+			const uint8_t* pixel = src + x * bytes_per_pixel;
+			dest[ x * 4 ] = pixel[2];
+			dest[ x * 4 + 1 ] = pixel[1];
+			dest[ x * 4 + 2 ] = pixel[0];
+			dest[ x * 4 + 3 ] = bytes_per_pixel == 4 ? pixel[3] : 0xFF;
+
+			if ( bytes_per_pixel == 4 && pixel[3] != 0 )
+				has_alpha = TRUE;
+		}
+	}
+
+	// Many applications leave the alpha channel of 32 bit bitmaps at zero, which means that the image is opaque
+	if ( bytes_per_pixel == 4 && !has_alpha ) {
+		for ( size_t i = 0; i < (size_t)width * height; i++ )
+			pixels[ i * 4 + 3 ] = 0xFF;
+	}
+}
+
+// Puts the memory on the clipboard, which takes ownership of it if successful.
+BOOL bw_ClipboardWin32_write( UINT format, HGLOBAL memory ) {
+	if ( !bw_ClipboardWin32_open() ) {
+		GlobalFree( memory );
+		return FALSE;
+	}
+
+	EmptyClipboard();
+	BOOL result = SetClipboardData( format, memory ) != NULL;
+	if ( !result )
+		GlobalFree( memory );
+
+	CloseClipboard();
+	return result;
+}
@@ -11,6 +11,8 @@ extern "C" {
 
 #if defined(BW_CEF)
 #include "cookie/cef.h"
+#elif defined(BW_WEBVIEW2)
+#include "cookie/webview2.h"
 #endif
 
 
@@ -0,0 +1,175 @@
+#include "../cookie.h"
+#include "../common.h"
+
+#include <stdlib.h>
+#include <string.h>
+#include <string>
+
+// WebView2 only provides a cookie manager per web view, so there is no global cookie jar that can be accessed.
+// Cookies can still be constructed and inspected, but storing, iterating and deleting them is not supported (yet).
+
+
+
+struct bw_CookieWebView2 {
+	std::string name;
+	std::string value;
+	std::string domain;
+	std::string path;
+	uint64_t creation_time = 0;
+	uint64_t expires = 0;
+	bool http_only = false;
+	bool secure = false;
+};
+
+#define WEBVIEW2_COOKIE(COOKIE) \
+	((bw_CookieWebView2*)(COOKIE)->impl.data_ptr)
+
+
+
+bw_StrSlice bw_CookieWebView2_copyToStrSlice( const std::string& string );
+
+
+
+void bw_Cookie_free(bw_Cookie* cookie) {
+	delete WEBVIEW2_COOKIE(cookie);
+	free(cookie);
+}
+
+bw_Cookie* bw_Cookie_new(bw_CStrSlice name, bw_CStrSlice value) {
+	bw_CookieWebView2* data = new bw_CookieWebView2();
+	data->name = std::string(name.data, name.len);
+	data->value = std::string(value.data, value.len);
+
+	bw_Cookie* cookie = (bw_Cookie*)malloc(sizeof(bw_Cookie));
+	cookie->impl.data_ptr = (void*)data;
+	return cookie;
+}
+
+uint64_t bw_Cookie_getCreationTime(const bw_Cookie* cookie) {
+	return WEBVIEW2_COOKIE(cookie)->creation_time;
+}
+
+void bw_Cookie_setCreationTime(bw_Cookie* cookie, uint64_t time) {
+	WEBVIEW2_COOKIE(cookie)->creation_time = time;
+}
+
+BOOL bw_Cookie_getDomain(const bw_Cookie* cookie, bw_StrSlice* domain) {
+	*domain = bw_CookieWebView2_copyToStrSlice(WEBVIEW2_COOKIE(cookie)->domain);
+	return TRUE;
+}
+
+void bw_Cookie_setDomain(bw_Cookie* cookie, bw_CStrSlice domain) {
+	WEBVIEW2_COOKIE(cookie)->domain = std::string(domain.data, domain.len);
+}
+
+uint64_t bw_Cookie_getExpires(const bw_Cookie* cookie) {
+	return WEBVIEW2_COOKIE(cookie)->expires;
+}
+
+void bw_Cookie_setExpires(bw_Cookie* cookie, uint64_t time) {
+	WEBVIEW2_COOKIE(cookie)->expires = time;
+}
+
+BOOL bw_Cookie_getName(const bw_Cookie* cookie, bw_StrSlice* name) {
+	*name = bw_CookieWebView2_copyToStrSlice(WEBVIEW2_COOKIE(cookie)->name);
+	return TRUE;
+}
+
+void bw_Cookie_setName(bw_Cookie* cookie, bw_CStrSlice name) {
+	WEBVIEW2_COOKIE(cookie)->name = std::string(name.data, name.len);
+}
+
+BOOL bw_Cookie_getPath(const bw_Cookie* cookie, bw_StrSlice* path) {
+	*path = bw_CookieWebView2_copyToStrSlice(WEBVIEW2_COOKIE(cookie)->path);
+	return TRUE;
+}
+
+void bw_Cookie_setPath(bw_Cookie* cookie, bw_CStrSlice path) {
+	WEBVIEW2_COOKIE(cookie)->path = std::string(path.data, path.len);
+}
+
+BOOL bw_Cookie_getValue(const bw_Cookie* cookie, bw_StrSlice* value) {
+	*value = bw_CookieWebView2_copyToStrSlice(WEBVIEW2_COOKIE(cookie)->value);
+	return TRUE;
+}
+
+void bw_Cookie_setValue(bw_Cookie* cookie, bw_CStrSlice value) {
+	WEBVIEW2_COOKIE(cookie)->value = std::string(value.data, value.len);
+}
+
+BOOL bw_Cookie_isHttpOnly(const bw_Cookie* cookie) {
+	return WEBVIEW2_COOKIE(cookie)->http_only;
+}
+
+void bw_Cookie_makeHttpOnly(bw_Cookie* cookie) {
+	WEBVIEW2_COOKIE(cookie)->http_only = true;
+}
+
+BOOL bw_Cookie_isSecure(const bw_Cookie* cookie) {
+	return WEBVIEW2_COOKIE(cookie)->secure;
+}
+
+void bw_Cookie_makeSecure(bw_Cookie* cookie) {
+	WEBVIEW2_COOKIE(cookie)->secure = true;
+}
+
+void bw_CookieJar_delete(bw_CookieJar* jar, bw_CStrSlice url, bw_CStrSlice name, bw_CookieJarDeleteCallbackFn cb, void* cb_data) {
+	UNUSED(url);
+	UNUSED(name);
+
+	cb(jar, cb_data, 0);
+}
+
+void bw_CookieJar_free(bw_CookieJar* jar) {
+	free(jar);
+}
+
+void bw_CookieJar_iterator(bw_CookieJar* jar, bw_CookieIterator** iterator, BOOL include_http_only, bw_CStrSlice url) {
+	UNUSED(include_http_only);
+	UNUSED(url);
+
+	bw_CookieJar_iteratorAll(jar, iterator);
+}
+
+void bw_CookieJar_iteratorAll(bw_CookieJar* jar, bw_CookieIterator** iterator) {
+	UNUSED(jar);
+
+	*iterator = (bw_CookieIterator*)malloc(sizeof(bw_CookieIterator));
+	(*iterator)->impl._unused = 0;
+}
+
+bw_CookieJar* bw_CookieJar_newGlobal() {
+	bw_CookieJar* cj = (bw_CookieJar*)malloc(sizeof(bw_CookieJar));
+	cj->impl._unused = 0;
+	return cj;
+}
+
+bw_Err bw_CookieJar_store(bw_CookieJar* jar, bw_CStrSlice url, const bw_Cookie* cookie, bw_CookieJarStorageCallbackFn cb, void* cb_data) {
+	UNUSED(jar);
+	UNUSED(url);
+	UNUSED(cookie);
+	UNUSED(cb);
+	UNUSED(cb_data);
+
+	return bw_Err_new_with_msg(1, "storing cookies is not supported by the WebView2 backend");
+}
+
+void bw_CookieIterator_free(bw_CookieIterator* iterator) {
+	free(iterator);
+}
+
+BOOL bw_CookieIterator_next(bw_CookieIterator* iterator, bw_CookieIteratorNextCallbackFn on_next, void* cb_data) {
+	UNUSED(iterator);
+	UNUSED(on_next);
+	UNUSED(cb_data);
+
+	return FALSE;
+}
+
+bw_StrSlice bw_CookieWebView2_copyToStrSlice( const std::string& string ) {
+	bw_StrSlice slice;
+	slice.len = string.length();
+	slice.data = (char*)malloc(slice.len);
+	memcpy(slice.data, string.c_str(), slice.len);
+	return slice;
+}
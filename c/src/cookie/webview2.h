@@ -0,0 +1,22 @@
+#ifndef BW_COOKIE_WEBVIEW2_H
+#define BW_COOKIE_WEBVIEW2_H
+
+#include <stdint.h>
+
+
+
+struct bw_CookieImpl {
+	void* data_ptr;
+};
+
+struct bw_CookieJarImpl {
+	void* _unused;
+};
+
+struct bw_CookieIteratorImpl {
+	void* _unused;
+};
+
+
+
+#endif//BW_COOKIE_WEBVIEW2_H
@@ -0,0 +1,14 @@
+#include "../common.h"
+#include "../window.h"
+
+
+
+// TODO: Implement dragging out of windows for when CEF provides the windows itself
+BOOL bw_Window_startDrag( bw_Window* window, const bw_CStrSlice* files, size_t file_count, bw_CStrSlice text ) {
+	UNUSED( window );
+	UNUSED( files );
+	UNUSED( file_count );
+	UNUSED( text );
+
+	return FALSE;
+}
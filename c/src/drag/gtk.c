@@ -0,0 +1,102 @@
+#include "../application.h"
+#include "../common.h"
+#include "../window.h"
+
+#include <gtk/gtk.h>
+#include <stdlib.h>
+
+
+
+// The data of a drag that is in progress, which is handed out whenever the destination asks for it
+typedef struct {
+	GtkWidget* widget;
+	gchar** uris;	// NULL-terminated, or NULL if no files are being dragged
+	gchar* text;	// NULL if no text is being dragged
+	gulong data_get_handler;
+	gulong end_handler;
+} bw_DragGtkData;
+
+enum {
+	BW_DRAG_GTK_TARGET_URIS,
+	BW_DRAG_GTK_TARGET_TEXT
+};
+
+
+
+void _bw_DragGtk_onDataGet( GtkWidget* widget, GdkDragContext* context, GtkSelectionData* selection, guint info, guint time, gpointer data );
+void _bw_DragGtk_onEnd( GtkWidget* widget, GdkDragContext* context, gpointer data );
+void bw_DragGtk_free( bw_DragGtkData* drag );
+
+
+
+BOOL bw_Window_startDrag( bw_Window* window, const bw_CStrSlice* files, size_t file_count, bw_CStrSlice text ) {
+	bw_Application_assertCorrectThread( window->app );
+
+	bw_DragGtkData* drag = (bw_DragGtkData*)calloc( 1, sizeof( bw_DragGtkData ) );
+	drag->widget = window->impl.handle;
+	GtkTargetList* targets = gtk_target_list_new( NULL, 0 );
+
+	if ( file_count > 0 ) {
+		drag->uris = (gchar**)calloc( file_count + 1, sizeof( gchar* ) );
+
+		size_t uri_count = 0;
+		for ( size_t i = 0; i < file_count; i++ ) {
+			gchar* path = g_strndup( files[i].data, files[i].len );
+			gchar* uri = g_filename_to_uri( path, NULL, NULL );
+			g_free( path );
+
+			// Paths that are not absolute can't be turned into an URI
+			if ( uri != NULL )
+				drag->uris[ uri_count++ ] = uri;
+		}
+
+		gtk_target_list_add_uri_targets( targets, BW_DRAG_GTK_TARGET_URIS );
+	}
+	if ( text.len > 0 ) {
+		drag->text = g_strndup( text.data, text.len );
+		gtk_target_list_add_text_targets( targets, BW_DRAG_GTK_TARGET_TEXT );
+	}
+
+	// The drag takes the pointer over from the button press that is currently being handled, if there is one
+	GdkEvent* event = gtk_get_current_event();
+	GdkDragContext* context = gtk_drag_begin_with_coordinates( drag->widget, targets, GDK_ACTION_COPY, 1, event, -1, -1 );
+	if ( event != NULL )
+		gdk_event_free( event );
+	gtk_target_list_unref( targets );
+
+	if ( context == NULL ) {
+		bw_DragGtk_free( drag );
+		return FALSE;
+	}
+
+	drag->data_get_handler = g_signal_connect( drag->widget, "drag-data-get", G_CALLBACK( _bw_DragGtk_onDataGet ), drag );
+	drag->end_handler = g_signal_connect( drag->widget, "drag-end", G_CALLBACK( _bw_DragGtk_onEnd ), drag );
+	return TRUE;
+}
+
+void bw_DragGtk_free( bw_DragGtkData* drag ) {
+	g_strfreev( drag->uris );
+	g_free( drag->text );
+	free( drag );
+}
+
+void _bw_DragGtk_onDataGet( GtkWidget* widget, GdkDragContext* context, GtkSelectionData* selection, guint info, guint time, gpointer data ) {
+	UNUSED( widget );
+	UNUSED( context );
+	UNUSED( time );
+	bw_DragGtkData* drag = (bw_DragGtkData*)data;
+
+	if ( info == BW_DRAG_GTK_TARGET_URIS )
+		gtk_selection_data_set_uris( selection, drag->uris );
+	else if ( info == BW_DRAG_GTK_TARGET_TEXT )
+		gtk_selection_data_set_text( selection, drag->text, -1 );
+}
+
+void _bw_DragGtk_onEnd( GtkWidget* widget, GdkDragContext* context, gpointer data ) {
+	UNUSED( context );
+	bw_DragGtkData* drag = (bw_DragGtkData*)data;
+
+	g_signal_handler_disconnect( widget, drag->data_get_handler );
+	g_signal_handler_disconnect( widget, drag->end_handler );
+	bw_DragGtk_free( drag );
+}
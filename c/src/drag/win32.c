@@ -0,0 +1,183 @@
+#define COBJMACROS
+
+#include "../application.h"
+#include "../common.h"
+#include "../win32.h"
+#include "../window.h"
+
+#include <ole2.h>
+#include <shlobj.h>
+#include <stdlib.h>
+#include <string.h>
+#include <wchar.h>
+
+
+
+// The drop source that tells OLE when the drag ends, which is when the mouse button gets released
+typedef struct {
+	IDropSource iface;
+	LONG ref_count;
+} bw_DragWin32Source;
+
+
+
+HGLOBAL bw_DragWin32_allocFiles( const bw_CStrSlice* files, size_t file_count );
+HGLOBAL bw_DragWin32_allocText( bw_CStrSlice text );
+ULONG STDMETHODCALLTYPE bw_DragWin32Source_addRef( IDropSource* self );
+HRESULT STDMETHODCALLTYPE bw_DragWin32Source_giveFeedback( IDropSource* self, DWORD effect );
+HRESULT STDMETHODCALLTYPE bw_DragWin32Source_queryContinueDrag( IDropSource* self, BOOL escape_pressed, DWORD key_state );
+HRESULT STDMETHODCALLTYPE bw_DragWin32Source_queryInterface( IDropSource* self, REFIID riid, void** object );
+ULONG STDMETHODCALLTYPE bw_DragWin32Source_release( IDropSource* self );
+BOOL bw_DragWin32_setData( IDataObject* data, CLIPFORMAT format, HGLOBAL memory );
+
+
+
+static IDropSourceVtbl bw_DragWin32Source_vtbl = {
+	bw_DragWin32Source_queryInterface,
+	bw_DragWin32Source_addRef,
+	bw_DragWin32Source_release,
+	bw_DragWin32Source_queryContinueDrag,
+	bw_DragWin32Source_giveFeedback
+};
+
+
+
+BOOL bw_Window_startDrag( bw_Window* window, const bw_CStrSlice* files, size_t file_count, bw_CStrSlice text ) {
+	bw_Application_assertCorrectThread( window->app );
+
+	// OLE may already have been initialized on this thread, in which case this only adds a reference
+	HRESULT ole_result = OleInitialize( NULL );
+
+	// The shell's data object carries any format that is set on it
+	IDataObject* data = NULL;
+	BOOL result = SUCCEEDED( SHCreateDataObject( NULL, 0, NULL, NULL, &IID_IDataObject, (void**)&data ) );
+
+	if ( result && file_count > 0 )
+		result = bw_DragWin32_setData( data, CF_HDROP, bw_DragWin32_allocFiles( files, file_count ) );
+	if ( result && text.len > 0 )
+		result = bw_DragWin32_setData( data, CF_UNICODETEXT, bw_DragWin32_allocText( text ) );
+
+	if ( result ) {
+		bw_DragWin32Source* source = (bw_DragWin32Source*)malloc( sizeof( bw_DragWin32Source ) );
+		source->iface.lpVtbl = &bw_DragWin32Source_vtbl;
+		source->ref_count = 1;
+
+		// This runs its own message loop until the data has been dropped
+		DWORD effect;
+		result = SUCCEEDED( DoDragDrop( data, &source->iface, DROPEFFECT_COPY, &effect ) );
+		IDropSource_Release( &source->iface );
+	}
+
+	if ( data != NULL )
+		IDataObject_Release( data );
+	if ( SUCCEEDED( ole_result ) )
+		OleUninitialize();
+
+	return result;
+}
+
+// Constructs a DROPFILES structure, which is followed by the paths as a list of wide strings that ends with an empty string.
+HGLOBAL bw_DragWin32_allocFiles( const bw_CStrSlice* files, size_t file_count ) {
+	WCHAR** paths = (WCHAR**)malloc( file_count * sizeof( WCHAR* ) );
+	size_t total_len = 1;
+	for ( size_t i = 0; i < file_count; i++ ) {
+		paths[i] = bw_win32_copyAsNewWstr( files[i] );
+		total_len += wcslen( paths[i] ) + 1;
+	}
+
+	HGLOBAL memory = GlobalAlloc( GMEM_MOVEABLE | GMEM_ZEROINIT, sizeof( DROPFILES ) + total_len * sizeof( WCHAR ) );
+	DROPFILES* header = memory != NULL ? (DROPFILES*)GlobalLock( memory ) : NULL;
+	if ( header != NULL ) {
+		header->pFiles = sizeof( DROPFILES );
+		header->fWide = TRUE;
+
+		WCHAR* dest = (WCHAR*)(header + 1);
+		for ( size_t i = 0; i < file_count; i++ ) {
+			size_t len = wcslen( paths[i] ) + 1;
+			memcpy( dest, paths[i], len * sizeof( WCHAR ) );
+			dest += len;
+		}
+		// The terminating empty string is already zeroed out
+
+		GlobalUnlock( memory );
+	}
+
+	for ( size_t i = 0; i < file_count; i++ )
+		free( paths[i] );
+	free( paths );
+	return memory;
+}
+
+HGLOBAL bw_DragWin32_allocText( bw_CStrSlice text ) {
+	WCHAR* string = bw_win32_copyAsNewWstr( text );
+	size_t size = (wcslen( string ) + 1) * sizeof( WCHAR );
+
+	HGLOBAL memory = GlobalAlloc( GMEM_MOVEABLE, size );
+	void* dest = memory != NULL ? GlobalLock( memory ) : NULL;
+	if ( dest != NULL ) {
+		memcpy( dest, string, size );
+		GlobalUnlock( memory );
+	}
+
+	free( string );
+	return memory;
+}
+
+// Hands the memory over to the data object, or frees it if that fails
+BOOL bw_DragWin32_setData( IDataObject* data, CLIPFORMAT format, HGLOBAL memory ) {
+	if ( memory == NULL )
+		return FALSE;
+
+	FORMATETC format_etc = { format, NULL, DVASPECT_CONTENT, -1, TYMED_HGLOBAL };
+	STGMEDIUM medium;
+	memset( &medium, 0, sizeof( STGMEDIUM ) );
+	medium.tymed = TYMED_HGLOBAL;
+	medium.hGlobal = memory;
+
+	if ( FAILED( IDataObject_SetData( data, &format_etc, &medium, TRUE ) ) ) {
+		GlobalFree( memory );
+		return FALSE;
+	}
+	return TRUE;
+}
+
+ULONG STDMETHODCALLTYPE bw_DragWin32Source_addRef( IDropSource* self ) {
+	return (ULONG)InterlockedIncrement( &((bw_DragWin32Source*)self)->ref_count );
+}
+
+HRESULT STDMETHODCALLTYPE bw_DragWin32Source_giveFeedback( IDropSource* self, DWORD effect ) {
+	UNUSED( self );
+	UNUSED( effect );
+
+	return DRAGDROP_S_USEDEFAULTCURSORS;
+}
+
+HRESULT STDMETHODCALLTYPE bw_DragWin32Source_queryContinueDrag( IDropSource* self, BOOL escape_pressed, DWORD key_state ) {
+	UNUSED( self );
+
+	if ( escape_pressed )
+		return DRAGDROP_S_CANCEL;
+	if ( !(key_state & (MK_LBUTTON | MK_MBUTTON | MK_RBUTTON)) )
+		return DRAGDROP_S_DROP;
+	return S_OK;
+}
+
+HRESULT STDMETHODCALLTYPE bw_DragWin32Source_queryInterface( IDropSource* self, REFIID riid, void** object ) {
+	if ( IsEqualIID( riid, &IID_IUnknown ) || IsEqualIID( riid, &IID_IDropSource ) ) {
+		*object = self;
+		IDropSource_AddRef( self );
+		return S_OK;
+	}
+
+	*object = NULL;
+	return E_NOINTERFACE;
+}
+
+ULONG STDMETHODCALLTYPE bw_DragWin32Source_release( IDropSource* self ) {
+	bw_DragWin32Source* source = (bw_DragWin32Source*)self;
+
+	LONG count = InterlockedDecrement( &source->ref_count );
+	if ( count == 0 )
+		free( source );
+	return (ULONG)count;
+}
@@ -16,6 +16,12 @@ char* bw_Err_msg_string( bw_ErrCode code, const void* message ) {
 	return (char*)message;
 }
 
+char* bw_Err_msg_jsException( bw_ErrCode code, const void* data ) {
+	(void)(code);
+
+	return (char*)((const bw_JsException*)data)->message;
+}
+
 
 
 void bw_Err_free( bw_Err* err ) {
@@ -36,3 +42,31 @@ bw_Err bw_Err_new_with_msg( bw_ErrCode code, const char* msg ) {
 	};
 	return e;
 }
+
+bw_Err bw_Err_newJsException( const char* name, const char* message, const char* stack, unsigned int line, unsigned int column ) {
+
+	size_t name_size = strlen( name ) + 1;
+	size_t message_size = strlen( message ) + 1;
+	size_t stack_size = strlen( stack ) + 1;
+
+	// The strings are put right after the struct, in the same allocation
+	bw_JsException* exception = (bw_JsException*)malloc( sizeof( bw_JsException ) + name_size + message_size + stack_size );
+	char* strings = (char*)( exception + 1 );
+
+	memcpy( strings, name, name_size );
+	memcpy( strings + name_size, message, message_size );
+	memcpy( strings + name_size + message_size, stack, stack_size );
+
+	exception->name = strings;
+	exception->message = strings + name_size;
+	exception->stack = strings + name_size + message_size;
+	exception->line = line;
+	exception->column = column;
+
+	bw_Err e = {
+		1,
+		exception,
+		bw_Err_msg_jsException
+	};
+	return e;
+}
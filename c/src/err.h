@@ -18,6 +18,16 @@ typedef struct bw_Err {
 	char* (*alloc_message)( bw_ErrCode code, const void* data );	/// Returns a newly allocated pointer to a null terminated utf8 string that describes the error
 } bw_Err;
 
+// The details of an exception that JavaScript code has thrown, which are the data of the errors that `bw_Err_newJsException` creates.
+// The strings are null terminated, and are allocated together with this struct, so that `bw_Err_free` frees them all at once.
+typedef struct bw_JsException {
+	const char* name;	/// The name of the error, like `TypeError`, or empty if it is unknown
+	const char* message;
+	const char* stack;	/// The stack trace, or empty if the browser engine doesn't give it
+	unsigned int line;	/// Starts at 1, or is 0 if it is unknown
+	unsigned int column;	/// Starts at 1, or is 0 if it is unknown
+} bw_JsException;
+
 #define BW_ERR_CODE_SUCCESS 0
 
 #define BW_ERR_IS_FAIL(ERROR) \
@@ -50,6 +60,8 @@ typedef struct bw_Err {
 
 char* bw_Err_msg_success( bw_ErrCode, const void* );
 char* bw_Err_msg_string( bw_ErrCode, const void* );
+// The message function of the errors that `bw_Err_newJsException` creates, by which their data can be recognized as a `bw_JsException`.
+char* bw_Err_msg_jsException( bw_ErrCode, const void* );
 
 // Should always be called on a bw_Err.
 // Frees internal data from the heap.
@@ -59,6 +71,9 @@ void bw_Err_free( bw_Err* err );
 // Creates a new initialized bw_Err, with the given code and message.
 // The alloc_message pointer will return the same message as given here.
 bw_Err bw_Err_new_with_msg( bw_ErrCode code, const char* msg );
+// Creates a new bw_Err for an exception that JavaScript code has thrown, with code 1.
+// Its message is the message of the exception, and its data is a `bw_JsException`.
+bw_Err bw_Err_newJsException( const char* name, const char* message, const char* stack, unsigned int line, unsigned int column );
 
 char* bw_Err_message(const bw_Err* error);
 
@@ -0,0 +1,22 @@
+#include "../application.h"
+#include "../common.h"
+
+
+
+// TODO: Implement global hotkeys for when CEF provides the windows itself
+BOOL bw_Application_registerGlobalHotkey( bw_Application* app, uint32_t hotkey_id, bw_CStrSlice hotkey, bw_ApplicationHotkeyFn handler, void* data ) {
+	UNUSED( app );
+	UNUSED( hotkey_id );
+	UNUSED( hotkey );
+	UNUSED( handler );
+	UNUSED( data );
+
+	return FALSE;
+}
+
+void* bw_Application_unregisterGlobalHotkey( bw_Application* app, uint32_t hotkey_id ) {
+	UNUSED( app );
+	UNUSED( hotkey_id );
+
+	return NULL;
+}
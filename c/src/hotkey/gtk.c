@@ -0,0 +1,180 @@
+#include "../application.h"
+#include "../common.h"
+#include "../menu/common.h"
+#include "../menu/gtk.h"
+
+#include <gtk/gtk.h>
+#include <stdlib.h>
+
+#ifdef GDK_WINDOWING_X11
+#include <gdk/gdkx.h>
+#include <X11/Xlib.h>
+#endif
+
+
+
+#ifdef GDK_WINDOWING_X11
+// The modifiers that are compared when a key is pressed
+#define BW_HOTKEY_GTK_MODIFIERS (ControlMask | ShiftMask | Mod1Mask | Mod4Mask)
+
+// The hotkeys that are registered.
+typedef struct bw_HotkeyGtk {
+	struct bw_HotkeyGtk* next;
+	uint32_t id;
+	bw_Application* app;
+	bw_ApplicationHotkeyFn handler;
+	void* data;
+	KeyCode key_code;
+	unsigned int modifiers;
+} bw_HotkeyGtk;
+
+static bw_HotkeyGtk* bw_HotkeyGtk_registered = NULL;
+static BOOL bw_HotkeyGtk_isFilterAdded = FALSE;
+
+// The key is grabbed once for every combination of the lock modifiers, so that Caps Lock and Num Lock don't get in the way.
+static const unsigned int bw_HotkeyGtk_lockModifiers[] = { 0, LockMask, Mod2Mask, LockMask | Mod2Mask };
+
+
+
+GdkFilterReturn _bw_HotkeyGtk_filter( GdkXEvent* xevent, GdkEvent* event, gpointer data );
+void bw_HotkeyGtk_grab( Display* display, const bw_HotkeyGtk* hotkey, BOOL grab );
+#endif
+
+
+
+BOOL bw_Application_registerGlobalHotkey( bw_Application* app, uint32_t hotkey_id, bw_CStrSlice hotkey, bw_ApplicationHotkeyFn handler, void* data ) {
+	bw_Application_assertCorrectThread( app );
+
+#ifdef GDK_WINDOWING_X11
+	// Wayland doesn't let applications grab keys
+	GdkDisplay* display = gdk_display_get_default();
+	if ( !GDK_IS_X11_DISPLAY( display ) )
+		return FALSE;
+	Display* xdisplay = GDK_DISPLAY_XDISPLAY( display );
+
+	char* hotkey_str = bw_string_copyAsNewCstr( hotkey );
+	bw_MenuAccelerator parsed;
+	BOOL is_valid = bw_MenuAccelerator_parse( hotkey_str, &parsed );
+	free( hotkey_str );
+	if ( !is_valid )
+		return FALSE;
+
+	guint keyval = bw_MenuGtk_keyval( parsed.key );
+	if ( keyval == 0 || keyval == GDK_KEY_VoidSymbol )
+		return FALSE;
+	KeyCode key_code = XKeysymToKeycode( xdisplay, keyval );
+	if ( key_code == 0 )
+		return FALSE;
+
+	bw_HotkeyGtk* entry = (bw_HotkeyGtk*)malloc( sizeof( bw_HotkeyGtk ) );
+	entry->id = hotkey_id;
+	entry->app = app;
+	entry->handler = handler;
+	entry->data = data;
+	entry->key_code = key_code;
+	entry->modifiers = 0;
+	if ( parsed.ctrl )
+		entry->modifiers |= ControlMask;
+	if ( parsed.shift )
+		entry->modifiers |= ShiftMask;
+	if ( parsed.alt )
+		entry->modifiers |= Mod1Mask;
+	if ( parsed.super )
+		entry->modifiers |= Mod4Mask;
+
+	// Grabbing fails with an X error when another application has grabbed the same key combination already
+	gdk_x11_display_error_trap_push( display );
+	bw_HotkeyGtk_grab( xdisplay, entry, TRUE );
+	XSync( xdisplay, False );
+	if ( gdk_x11_display_error_trap_pop( display ) != 0 ) {
+		gdk_x11_display_error_trap_push( display );
+		bw_HotkeyGtk_grab( xdisplay, entry, FALSE );
+		gdk_x11_display_error_trap_pop_ignored( display );
+
+		free( entry );
+		return FALSE;
+	}
+
+	// The key presses of grabbed keys are sent to the root window, which GDK only passes on to filters
+	if ( !bw_HotkeyGtk_isFilterAdded ) {
+		gdk_window_add_filter( NULL, _bw_HotkeyGtk_filter, NULL );
+		bw_HotkeyGtk_isFilterAdded = TRUE;
+	}
+
+	entry->next = bw_HotkeyGtk_registered;
+	bw_HotkeyGtk_registered = entry;
+	return TRUE;
+#else
+	UNUSED( hotkey_id );
+	UNUSED( hotkey );
+	UNUSED( handler );
+	UNUSED( data );
+	return FALSE;
+#endif
+}
+
+void* bw_Application_unregisterGlobalHotkey( bw_Application* app, uint32_t hotkey_id ) {
+	bw_Application_assertCorrectThread( app );
+
+#ifdef GDK_WINDOWING_X11
+	bw_HotkeyGtk** link = &bw_HotkeyGtk_registered;
+	while ( *link != NULL ) {
+		bw_HotkeyGtk* entry = *link;
+
+		if ( entry->id == hotkey_id ) {
+			*link = entry->next;
+
+			GdkDisplay* display = gdk_display_get_default();
+			gdk_x11_display_error_trap_push( display );
+			bw_HotkeyGtk_grab( GDK_DISPLAY_XDISPLAY( display ), entry, FALSE );
+			gdk_x11_display_error_trap_pop_ignored( display );
+
+			void* data = entry->data;
+			free( entry );
+			return data;
+		}
+
+		link = &entry->next;
+	}
+#else
+	UNUSED( hotkey_id );
+#endif
+
+	return NULL;
+}
+
+
+
+#ifdef GDK_WINDOWING_X11
+GdkFilterReturn _bw_HotkeyGtk_filter( GdkXEvent* xevent, GdkEvent* event, gpointer data ) {
+	UNUSED( event );
+	UNUSED( data );
+
+	XEvent* e = (XEvent*)xevent;
+	if ( e->type != KeyPress )
+		return GDK_FILTER_CONTINUE;
+
+	unsigned int modifiers = e->xkey.state & BW_HOTKEY_GTK_MODIFIERS;
+	for ( bw_HotkeyGtk* entry = bw_HotkeyGtk_registered; entry != NULL; entry = entry->next ) {
+		if ( entry->key_code == e->xkey.keycode && entry->modifiers == modifiers ) {
+			entry->handler( entry->app, entry->id, entry->data );
+			return GDK_FILTER_REMOVE;
+		}
+	}
+
+	return GDK_FILTER_CONTINUE;
+}
+
+void bw_HotkeyGtk_grab( Display* display, const bw_HotkeyGtk* hotkey, BOOL grab ) {
+	Window root = DefaultRootWindow( display );
+
+	for ( size_t i = 0; i < sizeof( bw_HotkeyGtk_lockModifiers ) / sizeof( bw_HotkeyGtk_lockModifiers[0] ); i++ ) {
+		unsigned int modifiers = hotkey->modifiers | bw_HotkeyGtk_lockModifiers[i];
+
+		if ( grab )
+			XGrabKey( display, hotkey->key_code, modifiers, root, False, GrabModeAsync, GrabModeAsync );
+		else
+			XUngrabKey( display, hotkey->key_code, modifiers, root );
+	}
+}
+#endif
@@ -0,0 +1,114 @@
+#include "win32.h"
+#include "../application.h"
+#include "../common.h"
+#include "../menu/common.h"
+#include "../menu/win32.h"
+#include "../win32.h"
+
+#include <stdlib.h>
+
+
+
+// The hotkeys that are registered.
+// Windows identifies hotkeys by a number between 0 and 0xBFFF, so they get their own number, separately from their ID.
+typedef struct bw_HotkeyWin32 {
+	struct bw_HotkeyWin32* next;
+	int native_id;
+	uint32_t id;
+	bw_Application* app;
+	bw_ApplicationHotkeyFn handler;
+	void* data;
+} bw_HotkeyWin32;
+
+static bw_HotkeyWin32* bw_HotkeyWin32_registered = NULL;
+static HWND bw_HotkeyWin32_window = NULL;
+static int bw_HotkeyWin32_nextNativeId = 1;
+
+
+
+BOOL bw_Application_registerGlobalHotkey( bw_Application* app, uint32_t hotkey_id, bw_CStrSlice hotkey, bw_ApplicationHotkeyFn handler, void* data ) {
+	bw_Application_assertCorrectThread( app );
+
+	char* hotkey_str = bw_string_copyAsNewCstr( hotkey );
+	bw_MenuAccelerator parsed;
+	BOOL is_valid = bw_MenuAccelerator_parse( hotkey_str, &parsed );
+	free( hotkey_str );
+	if ( !is_valid )
+		return FALSE;
+
+	WORD key = bw_MenuWin32_virtualKey( parsed.key );
+	if ( key == 0 || bw_HotkeyWin32_nextNativeId > 0xBFFF )
+		return FALSE;
+
+	// All hotkeys are received by the same hidden window, which lives as long as the application
+	if ( bw_HotkeyWin32_window == NULL ) {
+		bw_HotkeyWin32_window = CreateWindowExW( 0, L"bw-hotkey", L"", 0, 0, 0, 0, 0, HWND_MESSAGE, NULL, app->impl.handle, NULL );
+		if ( bw_HotkeyWin32_window == NULL )
+			BW_WIN32_PANIC_LAST_ERROR
+	}
+
+	// Holding the key down shouldn't cause the handler to be invoked over and over again
+	UINT modifiers = MOD_NOREPEAT;
+	if ( parsed.ctrl )
+		modifiers |= MOD_CONTROL;
+	if ( parsed.shift )
+		modifiers |= MOD_SHIFT;
+	if ( parsed.alt )
+		modifiers |= MOD_ALT;
+	if ( parsed.super )
+		modifiers |= MOD_WIN;
+
+	int native_id = bw_HotkeyWin32_nextNativeId;
+	if ( !RegisterHotKey( bw_HotkeyWin32_window, native_id, modifiers, key ) )
+		return FALSE;
+	bw_HotkeyWin32_nextNativeId += 1;
+
+	bw_HotkeyWin32* entry = (bw_HotkeyWin32*)malloc( sizeof( bw_HotkeyWin32 ) );
+	entry->next = bw_HotkeyWin32_registered;
+	entry->native_id = native_id;
+	entry->id = hotkey_id;
+	entry->app = app;
+	entry->handler = handler;
+	entry->data = data;
+	bw_HotkeyWin32_registered = entry;
+
+	return TRUE;
+}
+
+void* bw_Application_unregisterGlobalHotkey( bw_Application* app, uint32_t hotkey_id ) {
+	bw_Application_assertCorrectThread( app );
+
+	bw_HotkeyWin32** link = &bw_HotkeyWin32_registered;
+	while ( *link != NULL ) {
+		bw_HotkeyWin32* entry = *link;
+
+		if ( entry->id == hotkey_id ) {
+			*link = entry->next;
+			UnregisterHotKey( bw_HotkeyWin32_window, entry->native_id );
+
+			void* data = entry->data;
+			free( entry );
+			return data;
+		}
+
+		link = &entry->next;
+	}
+
+	return NULL;
+}
+
+
+
+LRESULT CALLBACK bw_HotkeyWin32_proc( HWND hwnd, UINT msg, WPARAM wp, LPARAM lp ) {
+	if ( msg != WM_HOTKEY )
+		return DefWindowProcW( hwnd, msg, wp, lp );
+
+	for ( bw_HotkeyWin32* entry = bw_HotkeyWin32_registered; entry != NULL; entry = entry->next ) {
+		if ( entry->native_id == (int)wp ) {
+			entry->handler( entry->app, entry->id, entry->data );
+			break;
+		}
+	}
+
+	return 0;
+}
@@ -0,0 +1,14 @@
+#ifndef BW_HOTKEY_WIN32_H
+#define BW_HOTKEY_WIN32_H
+
+#define WIN32_LEAN_AND_MEAN
+#include <Windows.h>
+
+
+
+/// The window procedure of the hidden window that receives the messages of the global hotkeys.
+LRESULT CALLBACK bw_HotkeyWin32_proc( HWND hwnd, UINT msg, WPARAM wp, LPARAM lp );
+
+
+
+#endif//BW_HOTKEY_WIN32_H
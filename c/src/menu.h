@@ -0,0 +1,48 @@
+#ifndef BW_MENU_H
+#define BW_MENU_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#include "bool.h"
+#include "string.h"
+
+#include <stdint.h>
+
+
+
+/// A menu that holds items, separators and submenus.
+/// When used as a menu bar, it should only hold submenus, which will become the menus on the bar.
+typedef struct bw_Menu bw_Menu;
+
+
+
+/// Appends an item to the menu.
+///
+/// # Arguments
+/// * `item_id` - The identifier that is passed to the menu handler of the window when the item gets activated. May not be 0.
+/// * `label` - The text of the item. A `&` marks the character after it as the mnemonic, `&&` is a literal `&`.
+/// * `accelerator` - A key combination like `Ctrl+Shift+S`, or an empty string for no accelerator.
+/// * `checkable` - Makes the item toggle a check mark every time it gets activated.
+/// * `checked` - Whether or not a checkable item starts out checked.
+void bw_Menu_addItem( bw_Menu* menu, uint32_t item_id, bw_CStrSlice label, bw_CStrSlice accelerator, BOOL checkable, BOOL checked );
+
+void bw_Menu_addSeparator( bw_Menu* menu );
+
+/// Appends a submenu to the menu.
+/// `menu` takes ownership of `submenu`, so it is freed together with `menu`.
+void bw_Menu_addSubmenu( bw_Menu* menu, bw_CStrSlice label, bw_Menu* submenu );
+
+/// Frees the menu, together with all of its submenus.
+void bw_Menu_free( bw_Menu* menu );
+
+bw_Menu* bw_Menu_new();
+
+
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#endif//BW_MENU_H
@@ -0,0 +1,116 @@
+#include "common.h"
+
+#include <ctype.h>
+#include <stdlib.h>
+#include <string.h>
+
+
+
+bw_MenuEntry* bw_Menu_appendEntry( bw_Menu* menu );
+BOOL bw_MenuAccelerator_isModifier( const char* part, size_t len, const char* name );
+
+
+
+void bw_Menu_addItem( bw_Menu* menu, uint32_t item_id, bw_CStrSlice label, bw_CStrSlice accelerator, BOOL checkable, BOOL checked ) {
+	bw_MenuEntry* entry = bw_Menu_appendEntry( menu );
+
+	entry->kind = BW_MENU_ENTRY_ITEM;
+	entry->item_id = item_id;
+	entry->label = bw_string_copyAsNewCstr( label );
+	entry->accelerator = accelerator.len != 0 ? bw_string_copyAsNewCstr( accelerator ) : NULL;
+	entry->checkable = checkable;
+	entry->checked = checked;
+}
+
+void bw_Menu_addSeparator( bw_Menu* menu ) {
+	bw_MenuEntry* entry = bw_Menu_appendEntry( menu );
+
+	entry->kind = BW_MENU_ENTRY_SEPARATOR;
+}
+
+void bw_Menu_addSubmenu( bw_Menu* menu, bw_CStrSlice label, bw_Menu* submenu ) {
+	bw_MenuEntry* entry = bw_Menu_appendEntry( menu );
+
+	entry->kind = BW_MENU_ENTRY_SUBMENU;
+	entry->label = bw_string_copyAsNewCstr( label );
+	entry->submenu = submenu;
+}
+
+bw_MenuEntry* bw_Menu_appendEntry( bw_Menu* menu ) {
+	menu->entries = (bw_MenuEntry*)realloc( menu->entries, sizeof( bw_MenuEntry ) * (menu->entry_count + 1) );
+
+	bw_MenuEntry* entry = &menu->entries[ menu->entry_count ];
+	memset( entry, 0, sizeof( bw_MenuEntry ) );
+	menu->entry_count += 1;
+
+	return entry;
+}
+
+void bw_Menu_free( bw_Menu* menu ) {
+
+	for ( size_t i = 0; i < menu->entry_count; i++ ) {
+		bw_MenuEntry* entry = &menu->entries[i];
+
+		free( entry->label );
+		free( entry->accelerator );
+		if ( entry->submenu != NULL )
+			bw_Menu_free( entry->submenu );
+	}
+
+	free( menu->entries );
+	free( menu );
+}
+
+bw_Menu* bw_Menu_new() {
+	bw_Menu* menu = (bw_Menu*)malloc( sizeof( bw_Menu ) );
+	menu->entries = NULL;
+	menu->entry_count = 0;
+	return menu;
+}
+
+
+
+BOOL bw_MenuAccelerator_isModifier( const char* part, size_t len, const char* name ) {
+	if ( strlen( name ) != len )
+		return FALSE;
+
+	for ( size_t i = 0; i < len; i++ ) {
+		if ( tolower( (unsigned char)part[i] ) != name[i] )
+			return FALSE;
+	}
+	return TRUE;
+}
+
+BOOL bw_MenuAccelerator_parse( const char* accelerator, bw_MenuAccelerator* output ) {
+	memset( output, 0, sizeof( bw_MenuAccelerator ) );
+
+	const char* part = accelerator;
+	while ( TRUE ) {
+		const char* plus = strchr( part, '+' );
+
+		// The last part is the key.
+		// A plus right at the end is the plus key itself, like in `Ctrl++`.
+		if ( plus == NULL || plus[1] == '\0' ) {
+			size_t len = strlen( part );
+			if ( len == 0 || len >= sizeof( output->key ) )
+				return FALSE;
+
+			memcpy( output->key, part, len + 1 );
+			return TRUE;
+		}
+
+		size_t len = plus - part;
+		if ( bw_MenuAccelerator_isModifier( part, len, "ctrl" ) || bw_MenuAccelerator_isModifier( part, len, "control" ) )
+			output->ctrl = TRUE;
+		else if ( bw_MenuAccelerator_isModifier( part, len, "shift" ) )
+			output->shift = TRUE;
+		else if ( bw_MenuAccelerator_isModifier( part, len, "alt" ) )
+			output->alt = TRUE;
+		else if ( bw_MenuAccelerator_isModifier( part, len, "super" ) || bw_MenuAccelerator_isModifier( part, len, "cmd" ) || bw_MenuAccelerator_isModifier( part, len, "meta" ) )
+			output->super = TRUE;
+		else
+			return FALSE;
+
+		part = plus + 1;
+	}
+}
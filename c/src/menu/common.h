@@ -0,0 +1,49 @@
+#ifndef BW_MENU_COMMON_H
+#define BW_MENU_COMMON_H
+
+#include "../menu.h"
+
+#include <stddef.h>
+
+
+
+typedef enum {
+	BW_MENU_ENTRY_ITEM,
+	BW_MENU_ENTRY_SEPARATOR,
+	BW_MENU_ENTRY_SUBMENU
+} bw_MenuEntryKind;
+
+typedef struct {
+	bw_MenuEntryKind kind;
+	uint32_t item_id;
+	char* label;	// NULL for separators
+	char* accelerator;	// NULL if there is no accelerator
+	BOOL checkable;
+	BOOL checked;
+	bw_Menu* submenu;	// Only set for submenus
+} bw_MenuEntry;
+
+struct bw_Menu {
+	bw_MenuEntry* entries;
+	size_t entry_count;
+};
+
+/// The key combination of an accelerator, like `Ctrl+Shift+S`.
+typedef struct {
+	BOOL ctrl;
+	BOOL shift;
+	BOOL alt;
+	BOOL super;
+	char key[32];	// The name of the key, like "S", "F5" or "Delete"
+} bw_MenuAccelerator;
+
+
+
+/// Splits an accelerator into its modifiers and its key.
+/// The modifiers are case insensitive, and can be any of `Ctrl`, `Control`, `Shift`, `Alt`, `Super`, `Cmd` and `Meta`.
+/// Returns FALSE if there is no key or the key name is too long.
+BOOL bw_MenuAccelerator_parse( const char* accelerator, bw_MenuAccelerator* output );
+
+
+
+#endif//BW_MENU_COMMON_H
@@ -0,0 +1,152 @@
+#include "gtk.h"
+
+#include <stdlib.h>
+#include <string.h>
+
+
+
+typedef struct {
+	const char* name;
+	const char* gdk_name;
+} bw_MenuGtk_NamedKey;
+
+// The key names that are different for GDK
+static const bw_MenuGtk_NamedKey bw_MenuGtk_namedKeys[] = {
+	{ "backspace", "BackSpace" },
+	{ "del", "Delete" },
+	{ "enter", "Return" },
+	{ "esc", "Escape" },
+	{ "minus", "minus" },
+	{ "-", "minus" },
+	{ "pagedown", "Page_Down" },
+	{ "pageup", "Page_Up" },
+	{ "plus", "plus" },
+	{ "+", "plus" },
+	{ "space", "space" }
+};
+
+
+
+void bw_MenuGtk_addAccelerator( GtkWidget* item, const char* accelerator, GtkAccelGroup* accel_group );
+char* bw_MenuGtk_mnemonicLabel( const char* label );
+void _bw_MenuGtk_onActivate( GtkMenuItem* item, gpointer data );
+
+
+
+GtkWidget* bw_MenuGtk_create( const bw_Menu* menu, BOOL is_bar, GtkAccelGroup* accel_group, bw_MenuGtkHandlerFn handler, void* target ) {
+	GtkWidget* handle = is_bar ? gtk_menu_bar_new() : gtk_menu_new();
+
+	for ( size_t i = 0; i < menu->entry_count; i++ ) {
+		const bw_MenuEntry* entry = &menu->entries[i];
+		GtkWidget* item;
+
+		if ( entry->kind == BW_MENU_ENTRY_SEPARATOR ) {
+			item = gtk_separator_menu_item_new();
+		}
+		else {
+			char* label = bw_MenuGtk_mnemonicLabel( entry->label );
+
+			if ( entry->kind == BW_MENU_ENTRY_SUBMENU ) {
+				item = gtk_menu_item_new_with_mnemonic( label );
+				gtk_menu_item_set_submenu( GTK_MENU_ITEM(item), bw_MenuGtk_create( entry->submenu, FALSE, accel_group, handler, target ) );
+			}
+			else {
+				if ( entry->checkable ) {
+					item = gtk_check_menu_item_new_with_mnemonic( label );
+					gtk_check_menu_item_set_active( GTK_CHECK_MENU_ITEM(item), entry->checked );
+				}
+				else
+					item = gtk_menu_item_new_with_mnemonic( label );
+
+				if ( entry->accelerator != NULL && accel_group != NULL )
+					bw_MenuGtk_addAccelerator( item, entry->accelerator, accel_group );
+
+				g_object_set_data( G_OBJECT(item), "bw-item-id", GUINT_TO_POINTER( entry->item_id ) );
+				g_object_set_data( G_OBJECT(item), "bw-handler", (gpointer)handler );
+				// Connect after setting the check state, otherwise it gets reported as an activation
+				g_signal_connect( item, "activate", G_CALLBACK( _bw_MenuGtk_onActivate ), target );
+			}
+
+			free( label );
+		}
+
+		gtk_menu_shell_append( GTK_MENU_SHELL(handle), item );
+	}
+
+	return handle;
+}
+
+void bw_MenuGtk_addAccelerator( GtkWidget* item, const char* accelerator, GtkAccelGroup* accel_group ) {
+	bw_MenuAccelerator parsed;
+	if ( !bw_MenuAccelerator_parse( accelerator, &parsed ) )
+		return;
+
+	guint keyval = bw_MenuGtk_keyval( parsed.key );
+	if ( keyval == 0 || keyval == GDK_KEY_VoidSymbol )
+		return;
+
+	GdkModifierType modifiers = 0;
+	if ( parsed.ctrl )
+		modifiers |= GDK_CONTROL_MASK;
+	if ( parsed.shift )
+		modifiers |= GDK_SHIFT_MASK;
+	if ( parsed.alt )
+		modifiers |= GDK_MOD1_MASK;
+	if ( parsed.super )
+		modifiers |= GDK_SUPER_MASK;
+
+	gtk_widget_add_accelerator( item, "activate", accel_group, keyval, modifiers, GTK_ACCEL_VISIBLE );
+}
+
+guint bw_MenuGtk_keyval( const char* key ) {
+
+	// Single characters are looked up by their lowercase unicode value
+	if ( strlen( key ) == 1 && key[0] != '+' && key[0] != '-' )
+		return gdk_unicode_to_keyval( g_ascii_tolower( key[0] ) );
+
+	for ( size_t i = 0; i < sizeof( bw_MenuGtk_namedKeys ) / sizeof( bw_MenuGtk_namedKeys[0] ); i++ ) {
+		if ( g_ascii_strcasecmp( bw_MenuGtk_namedKeys[i].name, key ) == 0 )
+			return gdk_keyval_from_name( bw_MenuGtk_namedKeys[i].gdk_name );
+	}
+
+	// Other keys, like "F5", "Delete", "Home" and "Tab", have the same name in GDK
+	return gdk_keyval_from_name( key );
+}
+
+// Converts the mnemonic of the label from `&File` to GTK's `_File`.
+char* bw_MenuGtk_mnemonicLabel( const char* label ) {
+	size_t len = strlen( label );
+	// Every underscore may need to be escaped
+	char* output = (char*)malloc( len * 2 + 1 );
+	size_t j = 0;
+
+	for ( size_t i = 0; i < len; i++ ) {
+		if ( label[i] == '&' ) {
+			if ( label[i + 1] == '&' ) {
+				output[ j++ ] = '&';
+				i++;
+			}
+			else
+				output[ j++ ] = '_';
+		}
+		else if ( label[i] == '_' ) {
+			output[ j++ ] = '_';
+			output[ j++ ] = '_';
+		}
+		else
+			output[ j++ ] = label[i];
+	}
+
+	output[ j ] = '\0';
+	return output;
+}
+
+void _bw_MenuGtk_onActivate( GtkMenuItem* item, gpointer data ) {
+	uint32_t item_id = GPOINTER_TO_UINT( g_object_get_data( G_OBJECT(item), "bw-item-id" ) );
+	bw_MenuGtkHandlerFn handler = (bw_MenuGtkHandlerFn)g_object_get_data( G_OBJECT(item), "bw-handler" );
+
+	// Check menu items have already toggled their state by now
+	BOOL checked = GTK_IS_CHECK_MENU_ITEM(item) && gtk_check_menu_item_get_active( GTK_CHECK_MENU_ITEM(item) );
+
+	handler( data, item_id, checked );
+}
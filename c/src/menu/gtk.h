@@ -0,0 +1,25 @@
+#ifndef BW_MENU_GTK_H
+#define BW_MENU_GTK_H
+
+#include "common.h"
+
+#include <gtk/gtk.h>
+
+
+
+/// Invoked when an item of a menu has been activated, with the new check state of the item.
+typedef void (*bw_MenuGtkHandlerFn)( void* target, uint32_t item_id, BOOL checked );
+
+
+
+/// Creates the GTK widget for the given menu.
+/// The accelerators of its items are added to `accel_group`, if it isn't NULL.
+/// Activating its items invokes `handler` with `target`.
+GtkWidget* bw_MenuGtk_create( const bw_Menu* menu, BOOL is_bar, GtkAccelGroup* accel_group, bw_MenuGtkHandlerFn handler, void* target );
+/// Gives the GDK key value of the key of an accelerator, like "S" or "F5".
+/// Returns 0 or `GDK_KEY_VoidSymbol` if the key is unknown.
+guint bw_MenuGtk_keyval( const char* key );
+
+
+
+#endif//BW_MENU_GTK_H
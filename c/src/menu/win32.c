@@ -0,0 +1,201 @@
+#include "win32.h"
+#include "../win32.h"
+
+#include <ctype.h>
+#include <stdlib.h>
+#include <string.h>
+
+
+
+typedef struct {
+	ACCEL* items;
+	size_t count;
+} bw_MenuWin32_AccelList;
+
+typedef struct {
+	const char* name;
+	WORD key;
+} bw_MenuWin32_NamedKey;
+
+static const bw_MenuWin32_NamedKey bw_MenuWin32_namedKeys[] = {
+	{ "backspace", VK_BACK },
+	{ "delete", VK_DELETE },
+	{ "del", VK_DELETE },
+	{ "down", VK_DOWN },
+	{ "end", VK_END },
+	{ "enter", VK_RETURN },
+	{ "return", VK_RETURN },
+	{ "escape", VK_ESCAPE },
+	{ "esc", VK_ESCAPE },
+	{ "home", VK_HOME },
+	{ "insert", VK_INSERT },
+	{ "left", VK_LEFT },
+	{ "minus", VK_OEM_MINUS },
+	{ "-", VK_OEM_MINUS },
+	{ "pagedown", VK_NEXT },
+	{ "pageup", VK_PRIOR },
+	{ "plus", VK_OEM_PLUS },
+	{ "+", VK_OEM_PLUS },
+	{ "right", VK_RIGHT },
+	{ "space", VK_SPACE },
+	{ "tab", VK_TAB },
+	{ "up", VK_UP }
+};
+
+
+
+HMENU bw_MenuWin32_build( const bw_Menu* menu, BOOL is_bar, bw_MenuWin32_AccelList* accels );
+void bw_MenuWin32_addAccelerator( bw_MenuWin32_AccelList* accels, const char* accelerator, uint32_t item_id );
+WCHAR* bw_MenuWin32_label( const char* label, const char* accelerator );
+
+
+
+HMENU bw_MenuWin32_create( const bw_Menu* menu, BOOL is_bar, HACCEL* accelerators ) {
+	bw_MenuWin32_AccelList accels;
+	accels.items = NULL;
+	accels.count = 0;
+
+	HMENU handle = bw_MenuWin32_build( menu, is_bar, &accels );
+
+	if ( accels.count > 0 ) {
+		*accelerators = CreateAcceleratorTableW( accels.items, (int)accels.count );
+		if ( *accelerators == NULL )
+			BW_WIN32_PANIC_LAST_ERROR;
+	}
+	else
+		*accelerators = NULL;
+
+	free( accels.items );
+	return handle;
+}
+
+BOOL bw_MenuWin32_onCommand( HMENU menu, WORD item_id, BOOL* checked ) {
+	MENUITEMINFOW info;
+	memset( &info, 0, sizeof( info ) );
+	info.cbSize = sizeof( info );
+	info.fMask = MIIM_STATE | MIIM_DATA;
+	if ( !GetMenuItemInfoW( menu, item_id, FALSE, &info ) )
+		return FALSE;
+
+	*checked = (info.fState & MFS_CHECKED) != 0;
+	// Checkable items have their data set to TRUE
+	if ( info.dwItemData ) {
+		*checked = !*checked;
+		CheckMenuItem( menu, item_id, MF_BYCOMMAND | (*checked ? MF_CHECKED : MF_UNCHECKED) );
+	}
+
+	return TRUE;
+}
+
+void bw_MenuWin32_addAccelerator( bw_MenuWin32_AccelList* accels, const char* accelerator, uint32_t item_id ) {
+	bw_MenuAccelerator parsed;
+	if ( !bw_MenuAccelerator_parse( accelerator, &parsed ) )
+		return;
+
+	WORD key = bw_MenuWin32_virtualKey( parsed.key );
+	if ( key == 0 )
+		return;
+
+	accels->items = (ACCEL*)realloc( accels->items, sizeof( ACCEL ) * (accels->count + 1) );
+	ACCEL* accel = &accels->items[ accels->count ];
+	accels->count += 1;
+
+	// The Windows key can't be used for accelerators, it is reserved for the system.
+	accel->fVirt = FVIRTKEY;
+	if ( parsed.ctrl )
+		accel->fVirt |= FCONTROL;
+	if ( parsed.shift )
+		accel->fVirt |= FSHIFT;
+	if ( parsed.alt )
+		accel->fVirt |= FALT;
+	accel->key = key;
+	accel->cmd = (WORD)item_id;
+}
+
+HMENU bw_MenuWin32_build( const bw_Menu* menu, BOOL is_bar, bw_MenuWin32_AccelList* accels ) {
+	HMENU handle = is_bar ? CreateMenu() : CreatePopupMenu();
+	if ( handle == NULL )
+		BW_WIN32_PANIC_LAST_ERROR;
+
+	for ( size_t i = 0; i < menu->entry_count; i++ ) {
+		const bw_MenuEntry* entry = &menu->entries[i];
+
+		switch ( entry->kind ) {
+		case BW_MENU_ENTRY_SEPARATOR:
+			AppendMenuW( handle, MF_SEPARATOR, 0, NULL );
+			break;
+		case BW_MENU_ENTRY_SUBMENU: {
+			HMENU submenu = bw_MenuWin32_build( entry->submenu, FALSE, accels );
+
+			WCHAR* label = bw_MenuWin32_label( entry->label, NULL );
+			AppendMenuW( handle, MF_POPUP | MF_STRING, (UINT_PTR)submenu, label );
+			free( label );
+		}	break;
+		case BW_MENU_ENTRY_ITEM: {
+			WCHAR* label = bw_MenuWin32_label( entry->label, entry->accelerator );
+			UINT flags = MF_STRING | (entry->checked ? MF_CHECKED : MF_UNCHECKED);
+			AppendMenuW( handle, flags, (UINT_PTR)entry->item_id, label );
+			free( label );
+
+			// Mark the item as checkable, so that the window knows to toggle it when it gets activated
+			if ( entry->checkable ) {
+				MENUITEMINFOW info;
+				memset( &info, 0, sizeof( info ) );
+				info.cbSize = sizeof( info );
+				info.fMask = MIIM_DATA;
+				info.dwItemData = TRUE;
+				SetMenuItemInfoW( handle, entry->item_id, FALSE, &info );
+			}
+
+			if ( entry->accelerator != NULL )
+				bw_MenuWin32_addAccelerator( accels, entry->accelerator, entry->item_id );
+		}	break;
+		}
+	}
+
+	return handle;
+}
+
+// Win32 shows the accelerator of an item when it comes after a tab in its label.
+WCHAR* bw_MenuWin32_label( const char* label, const char* accelerator ) {
+	size_t label_len = strlen( label );
+	size_t accel_len = accelerator != NULL ? strlen( accelerator ) : 0;
+
+	char* text = (char*)malloc( label_len + accel_len + 2 );
+	memcpy( text, label, label_len );
+	if ( accelerator != NULL ) {
+		text[ label_len ] = '\t';
+		memcpy( text + label_len + 1, accelerator, accel_len );
+		label_len += accel_len + 1;
+	}
+
+	bw_CStrSlice slice;
+	slice.data = text;
+	slice.len = label_len;
+	WCHAR* wide = bw_win32_copyAsNewWstr( slice );
+
+	free( text );
+	return wide;
+}
+
+WORD bw_MenuWin32_virtualKey( const char* key ) {
+	size_t len = strlen( key );
+
+	// Letters and digits have the same virtual key codes as their uppercase ASCII characters
+	if ( len == 1 && isalnum( (unsigned char)key[0] ) )
+		return (WORD)toupper( (unsigned char)key[0] );
+
+	// Function keys
+	if ( len >= 2 && len <= 3 && (key[0] == 'F' || key[0] == 'f') ) {
+		int n = atoi( key + 1 );
+		if ( n >= 1 && n <= 24 )
+			return (WORD)(VK_F1 + n - 1);
+	}
+
+	for ( size_t i = 0; i < sizeof( bw_MenuWin32_namedKeys ) / sizeof( bw_MenuWin32_namedKeys[0] ); i++ ) {
+		if ( _stricmp( bw_MenuWin32_namedKeys[i].name, key ) == 0 )
+			return bw_MenuWin32_namedKeys[i].key;
+	}
+
+	return 0;
+}
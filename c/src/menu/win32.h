@@ -0,0 +1,24 @@
+#ifndef BW_MENU_WIN32_H
+#define BW_MENU_WIN32_H
+
+#include "common.h"
+
+#define WIN32_LEAN_AND_MEAN
+#include <Windows.h>
+
+
+
+/// Creates the native menu for the given menu, and an accelerator table for all the accelerators in it.
+/// `accelerators` is set to NULL if there are no accelerators.
+/// The data of checkable items is set to TRUE, so that they can be recognized when they are activated.
+HMENU bw_MenuWin32_create( const bw_Menu* menu, BOOL is_bar, HACCEL* accelerators );
+/// Toggles the check mark of the item with the given ID if it is checkable, and stores its check state in `checked`.
+/// Returns FALSE if `menu` doesn't contain the item.
+BOOL bw_MenuWin32_onCommand( HMENU menu, WORD item_id, BOOL* checked );
+/// Gives the virtual key code of the key of an accelerator, like "S" or "F5".
+/// Returns 0 if the key is unknown.
+WORD bw_MenuWin32_virtualKey( const char* key );
+
+
+
+#endif//BW_MENU_WIN32_H
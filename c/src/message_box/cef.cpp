@@ -0,0 +1,11 @@
+#include "../common.h"
+#include "../window.h"
+
+
+
+// TODO: Implement message boxes for when CEF provides the windows itself
+void bw_Window_showMessageBox( bw_Window* window, const bw_WindowMessageBox* message_box, bw_WindowMessageBoxFn handler, void* data ) {
+	UNUSED( message_box );
+
+	handler( window, -1, data );
+}
@@ -0,0 +1,81 @@
+#include "../application.h"
+#include "../common.h"
+#include "../window.h"
+
+#include <gtk/gtk.h>
+#include <stdlib.h>
+
+
+
+typedef struct {
+	bw_Window* window;
+	bw_WindowMessageBoxFn handler;
+	void* data;
+	size_t button_count;
+} bw_MessageBoxGtkData;
+
+
+
+void _bw_MessageBoxGtk_onResponse( GtkDialog* dialog, gint response_id, gpointer _data );
+
+
+
+void bw_Window_showMessageBox( bw_Window* window, const bw_WindowMessageBox* message_box, bw_WindowMessageBoxFn handler, void* data ) {
+	bw_Application_assertCorrectThread( window->app );
+
+	GtkMessageType type;
+	switch ( message_box->kind ) {
+	case 1: type = GTK_MESSAGE_WARNING; break;
+	case 2: type = GTK_MESSAGE_ERROR; break;
+	case 3: type = GTK_MESSAGE_QUESTION; break;
+	default: type = GTK_MESSAGE_INFO;
+	}
+
+	gchar* title = g_strndup( message_box->title.data, message_box->title.len );
+	gchar* message = g_strndup( message_box->message.data, message_box->message.len );
+
+	GtkWidget* dialog = gtk_message_dialog_new(
+		GTK_WINDOW( window->impl.handle ),
+		GTK_DIALOG_MODAL | GTK_DIALOG_DESTROY_WITH_PARENT,
+		type,
+		message_box->button_count == 0 ? GTK_BUTTONS_OK : GTK_BUTTONS_NONE,
+		"%s", message
+	);
+	gtk_window_set_title( GTK_WINDOW( dialog ), title );
+	g_free( title );
+	g_free( message );
+
+	// The response ID of every button is its index
+	for ( size_t i = 0; i < message_box->button_count; i++ ) {
+		gchar* caption = g_strndup( message_box->buttons[i].data, message_box->buttons[i].len );
+		gtk_dialog_add_button( GTK_DIALOG( dialog ), caption, (gint)i );
+		g_free( caption );
+	}
+
+	bw_MessageBoxGtkData* box_data = (bw_MessageBoxGtkData*)malloc( sizeof( bw_MessageBoxGtkData ) );
+	box_data->window = window;
+	box_data->handler = handler;
+	box_data->data = data;
+	box_data->button_count = message_box->button_count;
+
+	g_signal_connect( dialog, "response", G_CALLBACK( _bw_MessageBoxGtk_onResponse ), (gpointer)box_data );
+	gtk_widget_show( dialog );
+}
+
+void _bw_MessageBoxGtk_onResponse( GtkDialog* dialog, gint response_id, gpointer _data ) {
+	bw_MessageBoxGtkData* data = (bw_MessageBoxGtkData*)_data;
+
+	int button = -1;
+	if ( data->button_count == 0 ) {
+		if ( response_id == GTK_RESPONSE_OK )
+			button = 0;
+	}
+	// Negative response IDs are the ones of GTK, like GTK_RESPONSE_DELETE_EVENT when the dialog is closed
+	else if ( response_id >= 0 && (size_t)response_id < data->button_count )
+		button = (int)response_id;
+
+	gtk_widget_destroy( GTK_WIDGET( dialog ) );
+
+	data->handler( data->window, button, data->data );
+	free( data );
+}
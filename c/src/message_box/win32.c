@@ -0,0 +1,137 @@
+// Task dialogs are only declared for Windows Vista and up
+#ifndef _WIN32_WINNT
+#define _WIN32_WINNT 0x0600
+#endif
+
+#include "../application.h"
+#include "../common.h"
+#include "../win32.h"
+#include "../window.h"
+
+#include <commctrl.h>
+#include <stdlib.h>
+
+
+
+// The IDs of the custom buttons of a task dialog start here, so that they don't collide with IDCANCEL and friends
+#define BW_MESSAGE_BOX_WIN32_BUTTON_ID 100
+
+
+
+int _bw_MessageBoxWin32_showMessageBox( HWND parent, const bw_WindowMessageBox* message_box, const WCHAR* title, const WCHAR* message );
+int _bw_MessageBoxWin32_showTaskDialog( HWND parent, const bw_WindowMessageBox* message_box, const WCHAR* title, const WCHAR* message, BOOL* shown );
+
+
+
+// Message boxes on Windows run their own modal loop, so the handler is invoked before this returns.
+void bw_Window_showMessageBox( bw_Window* window, const bw_WindowMessageBox* message_box, bw_WindowMessageBoxFn handler, void* data ) {
+	bw_Application_assertCorrectThread( window->app );
+
+	WCHAR* title = bw_win32_copyAsNewWstr( message_box->title );
+	WCHAR* message = bw_win32_copyAsNewWstr( message_box->message );
+
+	BOOL shown = FALSE;
+	int button = _bw_MessageBoxWin32_showTaskDialog( window->impl.handle, message_box, title, message, &shown );
+	if ( !shown )
+		button = _bw_MessageBoxWin32_showMessageBox( window->impl.handle, message_box, title, message );
+
+	free( title );
+	free( message );
+
+	handler( window, button, data );
+}
+
+// Plain message boxes can't have buttons with custom captions, so the closest set of standard buttons is picked by the number of buttons.
+int _bw_MessageBoxWin32_showMessageBox( HWND parent, const bw_WindowMessageBox* message_box, const WCHAR* title, const WCHAR* message ) {
+	UINT type = 0;
+	switch ( message_box->kind ) {
+	case 1: type |= MB_ICONWARNING; break;
+	case 2: type |= MB_ICONERROR; break;
+	case 3: type |= MB_ICONQUESTION; break;
+	default: type |= MB_ICONINFORMATION;
+	}
+
+	if ( message_box->button_count <= 1 )
+		type |= MB_OK;
+	else if ( message_box->button_count == 2 )
+		type |= MB_OKCANCEL;
+	else
+		type |= MB_YESNOCANCEL;
+
+	switch ( MessageBoxW( parent, message, title, type ) ) {
+	case IDOK:
+	case IDYES:
+		return 0;
+	case IDNO:
+		return 1;
+	case IDCANCEL:
+		return message_box->button_count == 2 ? 1 : (message_box->button_count > 2 ? 2 : -1);
+	default:
+		return -1;
+	}
+}
+
+// TaskDialogIndirect is only available with version 6 of the common controls, which needs to be enabled with a manifest.
+// So it is looked up when it is needed, and `shown` is set to FALSE if it isn't available.
+int _bw_MessageBoxWin32_showTaskDialog( HWND parent, const bw_WindowMessageBox* message_box, const WCHAR* title, const WCHAR* message, BOOL* shown ) {
+	typedef HRESULT (WINAPI *TaskDialogIndirectFn)( const TASKDIALOGCONFIG*, int*, int*, BOOL* );
+	static TaskDialogIndirectFn task_dialog_indirect = NULL;
+	static BOOL looked_up = FALSE;
+
+	if ( !looked_up ) {
+		HMODULE comctl32 = LoadLibraryW( L"comctl32.dll" );
+		if ( comctl32 != NULL )
+			task_dialog_indirect = (TaskDialogIndirectFn)GetProcAddress( comctl32, "TaskDialogIndirect" );
+		looked_up = TRUE;
+	}
+
+	*shown = FALSE;
+	if ( task_dialog_indirect == NULL )
+		return -1;
+
+	TASKDIALOG_BUTTON* buttons = (TASKDIALOG_BUTTON*)calloc( message_box->button_count + 1, sizeof( TASKDIALOG_BUTTON ) );
+	for ( size_t i = 0; i < message_box->button_count; i++ ) {
+		buttons[i].nButtonID = BW_MESSAGE_BOX_WIN32_BUTTON_ID + (int)i;
+		buttons[i].pszButtonText = bw_win32_copyAsNewWstr( message_box->buttons[i] );
+	}
+
+	TASKDIALOGCONFIG config;
+	ZeroMemory( &config, sizeof( config ) );
+	config.cbSize = sizeof( config );
+	config.hwndParent = parent;
+	config.dwFlags = TDF_ALLOW_DIALOG_CANCELLATION | TDF_POSITION_RELATIVE_TO_WINDOW;
+	config.pszWindowTitle = title;
+	config.pszContent = message;
+	config.pButtons = buttons;
+	config.cButtons = (UINT)message_box->button_count;
+	if ( message_box->button_count == 0 )
+		config.dwCommonButtons = TDCBF_OK_BUTTON;
+
+	// Task dialogs don't have a question icon of their own, so the one of the system is used
+	switch ( message_box->kind ) {
+	case 1: config.pszMainIcon = TD_WARNING_ICON; break;
+	case 2: config.pszMainIcon = TD_ERROR_ICON; break;
+	case 3:
+		config.dwFlags |= TDF_USE_HICON_MAIN;
+		config.hMainIcon = LoadIconW( NULL, IDI_QUESTION );
+		break;
+	default: config.pszMainIcon = TD_INFORMATION_ICON;
+	}
+
+	int pressed = IDCANCEL;
+	HRESULT result = task_dialog_indirect( &config, &pressed, NULL, NULL );
+
+	for ( size_t i = 0; i < message_box->button_count; i++ )
+		free( (WCHAR*)buttons[i].pszButtonText );
+	free( buttons );
+
+	if ( FAILED( result ) )
+		return -1;
+	*shown = TRUE;
+
+	if ( message_box->button_count == 0 )
+		return pressed == IDOK ? 0 : -1;
+	if ( pressed >= BW_MESSAGE_BOX_WIN32_BUTTON_ID && pressed < BW_MESSAGE_BOX_WIN32_BUTTON_ID + (int)message_box->button_count )
+		return pressed - BW_MESSAGE_BOX_WIN32_BUTTON_ID;
+	return -1;
+}
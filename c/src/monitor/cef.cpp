@@ -0,0 +1,64 @@
+#include "../application.h"
+#include "../common.h"
+#include "../window.h"
+
+#include <include/views/cef_display.h>
+#include <include/views/cef_window.h>
+#include <cstdlib>
+#include <vector>
+
+
+
+void _bw_MonitorCef_fill( CefRefPtr<CefDisplay> display, bw_Monitor* monitor );
+
+
+
+void bw_Application_freeMonitors( bw_Monitor* monitors ) {
+	free( monitors );
+}
+
+size_t bw_Application_getMonitors( bw_Application* app, bw_Monitor** monitors ) {
+	bw_Application_assertCorrectThread( app );
+
+	std::vector<CefRefPtr<CefDisplay>> displays;
+	CefDisplay::GetAllDisplays( displays );
+	if ( displays.empty() ) {
+		*monitors = NULL;
+		return 0;
+	}
+
+	*monitors = (bw_Monitor*)malloc( sizeof(bw_Monitor) * displays.size() );
+	for ( size_t i = 0; i < displays.size(); i++ ) {
+		_bw_MonitorCef_fill( displays[i], &(*monitors)[i] );
+	}
+
+	return displays.size();
+}
+
+BOOL bw_Window_getMonitor( const bw_Window* window, bw_Monitor* monitor ) {
+	CefRefPtr<CefDisplay> display = (*(CefRefPtr<CefWindow>*)window->impl.handle_ptr)->GetDisplay();
+	if ( display == nullptr )
+		return FALSE;
+
+	_bw_MonitorCef_fill( display, monitor );
+	return TRUE;
+}
+
+
+
+// CEF gives the bounds in density independent pixels, which are the same units that the positions of windows are in.
+void _bw_MonitorCef_fill( CefRefPtr<CefDisplay> display, bw_Monitor* monitor ) {
+	CefRect bounds = display->GetBounds();
+	CefRect work_area = display->GetWorkArea();
+
+	monitor->x = bounds.x;
+	monitor->y = bounds.y;
+	monitor->width = (uint32_t)bounds.width;
+	monitor->height = (uint32_t)bounds.height;
+	monitor->work_x = work_area.x;
+	monitor->work_y = work_area.y;
+	monitor->work_width = (uint32_t)work_area.width;
+	monitor->work_height = (uint32_t)work_area.height;
+	monitor->scale_factor = display->GetDeviceScaleFactor();
+	monitor->is_primary = display->GetID() == CefDisplay::GetPrimaryDisplay()->GetID();
+}
@@ -0,0 +1,67 @@
+#include "../application.h"
+#include "../common.h"
+#include "../window.h"
+
+#include <gtk/gtk.h>
+#include <stdlib.h>
+
+
+
+void _bw_MonitorGtk_fill( GdkMonitor* handle, bw_Monitor* monitor );
+
+
+
+void bw_Application_freeMonitors( bw_Monitor* monitors ) {
+	free( monitors );
+}
+
+size_t bw_Application_getMonitors( bw_Application* app, bw_Monitor** monitors ) {
+	bw_Application_assertCorrectThread( app );
+
+	GdkDisplay* display = gdk_display_get_default();
+	int count = gdk_display_get_n_monitors( display );
+	if ( count <= 0 ) {
+		*monitors = NULL;
+		return 0;
+	}
+
+	*monitors = (bw_Monitor*)malloc( sizeof(bw_Monitor) * (size_t)count );
+	for ( int i = 0; i < count; i++ ) {
+		_bw_MonitorGtk_fill( gdk_display_get_monitor( display, i ), &(*monitors)[ i ] );
+	}
+
+	return (size_t)count;
+}
+
+BOOL bw_Window_getMonitor( const bw_Window* window, bw_Monitor* monitor ) {
+	GdkWindow* gdk_window = gtk_widget_get_window( window->impl.handle );
+	if ( gdk_window == NULL )
+		return FALSE;
+
+	GdkMonitor* handle = gdk_display_get_monitor_at_window( gdk_window_get_display( gdk_window ), gdk_window );
+	if ( handle == NULL )
+		return FALSE;
+
+	_bw_MonitorGtk_fill( handle, monitor );
+	return TRUE;
+}
+
+
+
+// GDK gives the geometry in application pixels, which are the same units that the positions of windows are in.
+void _bw_MonitorGtk_fill( GdkMonitor* handle, bw_Monitor* monitor ) {
+	GdkRectangle geometry, workarea;
+	gdk_monitor_get_geometry( handle, &geometry );
+	gdk_monitor_get_workarea( handle, &workarea );
+
+	monitor->x = geometry.x;
+	monitor->y = geometry.y;
+	monitor->width = (uint32_t)geometry.width;
+	monitor->height = (uint32_t)geometry.height;
+	monitor->work_x = workarea.x;
+	monitor->work_y = workarea.y;
+	monitor->work_width = (uint32_t)workarea.width;
+	monitor->work_height = (uint32_t)workarea.height;
+	monitor->scale_factor = (float)gdk_monitor_get_scale_factor( handle );
+	monitor->is_primary = gdk_monitor_is_primary( handle ) ? TRUE : FALSE;
+}
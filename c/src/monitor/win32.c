@@ -0,0 +1,116 @@
+#include "../application.h"
+#include "../common.h"
+#include "../window.h"
+
+#include <stdlib.h>
+#include <windows.h>
+
+#ifndef USER_DEFAULT_SCREEN_DPI
+#define USER_DEFAULT_SCREEN_DPI 96
+#endif
+
+
+
+typedef struct {
+	bw_Monitor* monitors;
+	size_t count;
+	size_t capacity;
+} bw_MonitorWin32_List;
+
+BOOL CALLBACK _bw_MonitorWin32_enumProc( HMONITOR handle, HDC hdc, LPRECT rect, LPARAM lparam );
+BOOL _bw_MonitorWin32_fill( HMONITOR handle, bw_Monitor* monitor );
+UINT _bw_MonitorWin32_getDpi( HMONITOR handle );
+
+
+
+void bw_Application_freeMonitors( bw_Monitor* monitors ) {
+	free( monitors );
+}
+
+size_t bw_Application_getMonitors( bw_Application* app, bw_Monitor** monitors ) {
+	bw_Application_assertCorrectThread( app );
+
+	bw_MonitorWin32_List list;
+	list.monitors = NULL;
+	list.count = 0;
+	list.capacity = 0;
+
+	EnumDisplayMonitors( NULL, NULL, _bw_MonitorWin32_enumProc, (LPARAM)&list );
+
+	*monitors = list.monitors;
+	return list.count;
+}
+
+BOOL bw_Window_getMonitor( const bw_Window* window, bw_Monitor* monitor ) {
+	HMONITOR handle = MonitorFromWindow( window->impl.handle, MONITOR_DEFAULTTONULL );
+	if ( handle == NULL )
+		return FALSE;
+
+	return _bw_MonitorWin32_fill( handle, monitor );
+}
+
+
+
+BOOL CALLBACK _bw_MonitorWin32_enumProc( HMONITOR handle, HDC hdc, LPRECT rect, LPARAM lparam ) {
+	UNUSED( hdc );
+	UNUSED( rect );
+	bw_MonitorWin32_List* list = (bw_MonitorWin32_List*)lparam;
+
+	if ( list->count == list->capacity ) {
+		list->capacity = list->capacity == 0 ? 4 : list->capacity * 2;
+		list->monitors = (bw_Monitor*)realloc( list->monitors, sizeof(bw_Monitor) * list->capacity );
+	}
+
+	if ( _bw_MonitorWin32_fill( handle, &list->monitors[ list->count ] ) )
+		list->count += 1;
+
+	// Continue enumerating
+	return TRUE;
+}
+
+BOOL _bw_MonitorWin32_fill( HMONITOR handle, bw_Monitor* monitor ) {
+	MONITORINFO info;
+	info.cbSize = sizeof(MONITORINFO);
+
+	if ( !GetMonitorInfoW( handle, &info ) )
+		return FALSE;
+
+	monitor->x = info.rcMonitor.left;
+	monitor->y = info.rcMonitor.top;
+	monitor->width = (uint32_t)(info.rcMonitor.right - info.rcMonitor.left);
+	monitor->height = (uint32_t)(info.rcMonitor.bottom - info.rcMonitor.top);
+	monitor->work_x = info.rcWork.left;
+	monitor->work_y = info.rcWork.top;
+	monitor->work_width = (uint32_t)(info.rcWork.right - info.rcWork.left);
+	monitor->work_height = (uint32_t)(info.rcWork.bottom - info.rcWork.top);
+	monitor->scale_factor = (float)_bw_MonitorWin32_getDpi( handle ) / USER_DEFAULT_SCREEN_DPI;
+	monitor->is_primary = (info.dwFlags & MONITORINFOF_PRIMARY) != 0;
+	return TRUE;
+}
+
+// GetDpiForMonitor is only available from Windows 8.1 onwards, so it is looked up when it is needed.
+UINT _bw_MonitorWin32_getDpi( HMONITOR handle ) {
+	typedef HRESULT (WINAPI *GetDpiForMonitorFn)( HMONITOR, int, UINT*, UINT* );
+	static GetDpiForMonitorFn get_dpi_for_monitor = NULL;
+	static BOOL looked_up = FALSE;
+
+	if ( !looked_up ) {
+		HMODULE shcore = LoadLibraryW( L"shcore.dll" );
+		if ( shcore != NULL )
+			get_dpi_for_monitor = (GetDpiForMonitorFn)GetProcAddress( shcore, "GetDpiForMonitor" );
+		looked_up = TRUE;
+	}
+
+	if ( get_dpi_for_monitor != NULL ) {
+		UINT dpi_x, dpi_y;
+		// 0 is MDT_EFFECTIVE_DPI
+		if ( SUCCEEDED( get_dpi_for_monitor( handle, 0, &dpi_x, &dpi_y ) ) )
+			return dpi_x;
+	}
+
+	// Older versions of Windows have the same DPI for all monitors
+	HDC hdc = GetDC( NULL );
+	UINT dpi = (UINT)GetDeviceCaps( hdc, LOGPIXELSX );
+	ReleaseDC( NULL, hdc );
+	return dpi;
+}
@@ -0,0 +1,14 @@
+#include "../application.h"
+#include "../common.h"
+
+
+
+void bw_Application_notify( bw_Application* app, bw_CStrSlice title, bw_CStrSlice body, bw_CStrSlice icon_path, bw_ApplicationNotificationFn handler, void* data ) {
+	UNUSED( title );
+	UNUSED( body );
+	UNUSED( icon_path );
+
+	// TODO: Implement notifications for when CEF provides the windows itself
+	if ( handler != NULL )
+		handler( app, FALSE, data );
+}
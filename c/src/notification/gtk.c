@@ -0,0 +1,96 @@
+#include "../application.h"
+#include "../common.h"
+
+#include <gtk/gtk.h>
+#include <stdlib.h>
+
+
+
+// The notifications that can still be clicked on.
+// The actions of notifications only carry a number, because they can also be activated by another instance of the application.
+typedef struct bw_NotificationGtk {
+	struct bw_NotificationGtk* next;
+	guint64 id;
+	bw_Application* app;
+	bw_ApplicationNotificationFn handler;
+	void* data;
+} bw_NotificationGtk;
+
+static bw_NotificationGtk* bw_NotificationGtk_pending = NULL;
+static guint64 bw_NotificationGtk_nextId = 1;
+
+
+
+void _bw_NotificationGtk_onClick( GSimpleAction* action, GVariant* parameter, gpointer data );
+
+
+
+void bw_Application_notify( bw_Application* app, bw_CStrSlice title, bw_CStrSlice body, bw_CStrSlice icon_path, bw_ApplicationNotificationFn handler, void* data ) {
+	bw_Application_assertCorrectThread( app );
+	GApplication* gapp = G_APPLICATION( app->impl.handle );
+
+	// The action that is activated when a notification gets clicked on, is registered once
+	if ( g_action_map_lookup_action( G_ACTION_MAP(gapp), "bw-notification-clicked" ) == NULL ) {
+		GSimpleAction* action = g_simple_action_new( "bw-notification-clicked", G_VARIANT_TYPE_UINT64 );
+		g_signal_connect( action, "activate", G_CALLBACK( _bw_NotificationGtk_onClick ), NULL );
+		g_action_map_add_action( G_ACTION_MAP(gapp), G_ACTION(action) );
+		g_object_unref( action );
+	}
+
+	guint64 id = bw_NotificationGtk_nextId++;
+	if ( handler != NULL ) {
+		bw_NotificationGtk* pending = (bw_NotificationGtk*)malloc( sizeof( bw_NotificationGtk ) );
+		pending->next = bw_NotificationGtk_pending;
+		pending->id = id;
+		pending->app = app;
+		pending->handler = handler;
+		pending->data = data;
+		bw_NotificationGtk_pending = pending;
+	}
+
+	char* title_str = bw_string_copyAsNewCstr( title );
+	char* body_str = bw_string_copyAsNewCstr( body );
+	GNotification* notification = g_notification_new( title_str );
+	g_notification_set_body( notification, body_str );
+	g_notification_set_default_action_and_target( notification, "app.bw-notification-clicked", "t", id );
+	bw_string_freeCstr( title_str );
+	bw_string_freeCstr( body_str );
+
+	if ( icon_path.len > 0 ) {
+		char* path = bw_string_copyAsNewCstr( icon_path );
+		GFile* file = g_file_new_for_path( path );
+		GIcon* icon = g_file_icon_new( file );
+		g_notification_set_icon( notification, icon );
+		g_object_unref( icon );
+		g_object_unref( file );
+		bw_string_freeCstr( path );
+	}
+
+	char notification_id[32];
+	g_snprintf( notification_id, sizeof( notification_id ), "bw-%" G_GUINT64_FORMAT, id );
+	g_application_send_notification( gapp, notification_id, notification );
+	g_object_unref( notification );
+}
+
+
+
+void _bw_NotificationGtk_onClick( GSimpleAction* action, GVariant* parameter, gpointer data ) {
+	UNUSED( action );
+	UNUSED( data );
+
+	guint64 id = g_variant_get_uint64( parameter );
+
+	bw_NotificationGtk** link = &bw_NotificationGtk_pending;
+	while ( *link != NULL ) {
+		bw_NotificationGtk* pending = *link;
+
+		if ( pending->id == id ) {
+			*link = pending->next;
+			pending->handler( pending->app, TRUE, pending->data );
+			free( pending );
+			return;
+		}
+
+		link = &pending->next;
+	}
+}
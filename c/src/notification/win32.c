@@ -0,0 +1,123 @@
+#pragma comment(lib, "shell32.lib")
+
+#include "win32.h"
+#include "../application.h"
+#include "../common.h"
+#include "../win32.h"
+
+#include <shellapi.h>
+#include <stdlib.h>
+#include <string.h>
+#include <wchar.h>
+
+
+
+// The message that the shell sends to the hidden window when something happens with the notification
+#define BW_NOTIFICATION_WIN32_MESSAGE (WM_APP + 1)
+
+
+
+typedef struct {
+	bw_Application* app;
+	bw_ApplicationNotificationFn handler;
+	void* data;
+	HICON icon;	// NULL if no icon has been loaded
+} bw_NotificationWin32;
+
+
+
+void bw_NotificationWin32_close( HWND hwnd, BOOL clicked );
+void bw_NotificationWin32_copyString( WCHAR* dest, size_t dest_len, bw_CStrSlice string );
+
+
+
+// Windows shows notifications as balloons of icons in the notification area, which look like toasts since Windows 10.
+// So every notification gets its own icon, which is removed again when the notification is gone.
+void bw_Application_notify( bw_Application* app, bw_CStrSlice title, bw_CStrSlice body, bw_CStrSlice icon_path, bw_ApplicationNotificationFn handler, void* data ) {
+	bw_Application_assertCorrectThread( app );
+
+	bw_NotificationWin32* notification = (bw_NotificationWin32*)malloc( sizeof( bw_NotificationWin32 ) );
+	notification->app = app;
+	notification->handler = handler;
+	notification->data = data;
+	notification->icon = NULL;
+
+	if ( icon_path.len > 0 ) {
+		WCHAR* path = bw_win32_copyAsNewWstr( icon_path );
+		notification->icon = (HICON)LoadImageW( NULL, path, IMAGE_ICON, GetSystemMetrics( SM_CXICON ), GetSystemMetrics( SM_CYICON ), LR_LOADFROMFILE );
+		free( path );
+	}
+
+	HWND hwnd = CreateWindowExW( 0, L"bw-notification", L"", 0, 0, 0, 0, 0, NULL, NULL, app->impl.handle, NULL );
+	if ( hwnd == NULL )
+		BW_WIN32_PANIC_LAST_ERROR
+	SetWindowLongPtrW( hwnd, GWLP_USERDATA, (LONG_PTR)notification );
+
+	NOTIFYICONDATAW icon_data;
+	memset( &icon_data, 0, sizeof( icon_data ) );
+	icon_data.cbSize = sizeof( icon_data );
+	icon_data.hWnd = hwnd;
+	icon_data.uID = 1;
+	icon_data.uFlags = NIF_MESSAGE | NIF_ICON | NIF_INFO;
+	icon_data.uCallbackMessage = BW_NOTIFICATION_WIN32_MESSAGE;
+	icon_data.hIcon = LoadIconW( NULL, IDI_APPLICATION );
+	bw_NotificationWin32_copyString( icon_data.szInfoTitle, sizeof( icon_data.szInfoTitle ) / sizeof( WCHAR ), title );
+	bw_NotificationWin32_copyString( icon_data.szInfo, sizeof( icon_data.szInfo ) / sizeof( WCHAR ), body );
+	if ( notification->icon != NULL ) {
+		icon_data.dwInfoFlags = NIIF_USER | NIIF_LARGE_ICON;
+		icon_data.hBalloonIcon = notification->icon;
+	}
+	else
+		icon_data.dwInfoFlags = NIIF_INFO;
+
+	if ( !Shell_NotifyIconW( NIM_ADD, &icon_data ) )
+		bw_NotificationWin32_close( hwnd, FALSE );
+}
+
+void bw_NotificationWin32_close( HWND hwnd, BOOL clicked ) {
+	bw_NotificationWin32* notification = (bw_NotificationWin32*)GetWindowLongPtrW( hwnd, GWLP_USERDATA );
+
+	NOTIFYICONDATAW icon_data;
+	memset( &icon_data, 0, sizeof( icon_data ) );
+	icon_data.cbSize = sizeof( icon_data );
+	icon_data.hWnd = hwnd;
+	icon_data.uID = 1;
+	Shell_NotifyIconW( NIM_DELETE, &icon_data );
+
+	if ( notification->handler != NULL )
+		notification->handler( notification->app, clicked, notification->data );
+
+	if ( notification->icon != NULL )
+		DestroyIcon( notification->icon );
+	free( notification );
+
+	SetWindowLongPtrW( hwnd, GWLP_USERDATA, 0 );
+	DestroyWindow( hwnd );
+}
+
+// Copies the string into a fixed size buffer, cutting it off if it is too long.
+void bw_NotificationWin32_copyString( WCHAR* dest, size_t dest_len, bw_CStrSlice string ) {
+	WCHAR* string_w = bw_win32_copyAsNewWstr( string );
+
+	wcsncpy( dest, string_w, dest_len - 1 );
+	dest[ dest_len - 1 ] = L'\0';
+
+	free( string_w );
+}
+
+LRESULT CALLBACK bw_NotificationWin32_proc( HWND hwnd, UINT msg, WPARAM wp, LPARAM lp ) {
+	if ( msg != BW_NOTIFICATION_WIN32_MESSAGE || GetWindowLongPtrW( hwnd, GWLP_USERDATA ) == 0 )
+		return DefWindowProcW( hwnd, msg, wp, lp );
+
+	switch ( LOWORD( lp ) ) {
+	case NIN_BALLOONUSERCLICK:
+		bw_NotificationWin32_close( hwnd, TRUE );
+		break;
+	case NIN_BALLOONTIMEOUT:
+	case NIN_BALLOONHIDE:
+		bw_NotificationWin32_close( hwnd, FALSE );
+		break;
+	}
+
+	return 0;
+}
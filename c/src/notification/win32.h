@@ -0,0 +1,14 @@
+#ifndef BW_NOTIFICATION_WIN32_H
+#define BW_NOTIFICATION_WIN32_H
+
+#define WIN32_LEAN_AND_MEAN
+#include <Windows.h>
+
+
+
+/// The window procedure of the hidden windows that own the notifications.
+LRESULT CALLBACK bw_NotificationWin32_proc( HWND hwnd, UINT msg, WPARAM wp, LPARAM lp );
+
+
+
+#endif//BW_NOTIFICATION_WIN32_H
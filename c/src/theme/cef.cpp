@@ -0,0 +1,17 @@
+#include "../application.h"
+#include "../common.h"
+
+
+
+// TODO: Find out the theme of the system for when CEF provides the windows itself
+uint8_t bw_Application_getSystemTheme( bw_Application* app ) {
+	UNUSED( app );
+
+	return BW_THEME_UNKNOWN;
+}
+
+void bw_Application_setThemeHandler( bw_Application* app, bw_ApplicationThemeFn handler, void* data ) {
+	UNUSED( app );
+	UNUSED( handler );
+	UNUSED( data );
+}
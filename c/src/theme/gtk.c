@@ -0,0 +1,75 @@
+#include "../application.h"
+#include "../common.h"
+
+#include <gtk/gtk.h>
+#include <string.h>
+
+
+
+void _bw_ThemeGtk_onChange( GObject* settings, GParamSpec* param, gpointer data );
+
+
+
+static bw_Application* bw_ThemeGtk_app = NULL;
+static bw_ApplicationThemeFn bw_ThemeGtk_handler = NULL;
+static void* bw_ThemeGtk_data = NULL;
+static uint8_t bw_ThemeGtk_last = BW_THEME_UNKNOWN;
+static BOOL bw_ThemeGtk_connected = FALSE;
+
+
+
+uint8_t bw_Application_getSystemTheme( bw_Application* app ) {
+	UNUSED( app );
+
+	GtkSettings* settings = gtk_settings_get_default();
+	if ( settings == NULL )
+		return BW_THEME_UNKNOWN;
+
+	gboolean prefer_dark = FALSE;
+	gchar* name = NULL;
+	g_object_get( settings, "gtk-application-prefer-dark-theme", &prefer_dark, "gtk-theme-name", &name, NULL );
+
+	// Themes that have a dark variant, like Adwaita, are named after it with `-dark` appended
+	BOOL is_dark = prefer_dark;
+	if ( name != NULL ) {
+		size_t length = strlen( name );
+		if ( length >= 5 && g_ascii_strcasecmp( name + length - 5, "-dark" ) == 0 )
+			is_dark = TRUE;
+		g_free( name );
+	}
+
+	return is_dark ? BW_THEME_DARK : BW_THEME_LIGHT;
+}
+
+void bw_Application_setThemeHandler( bw_Application* app, bw_ApplicationThemeFn handler, void* data ) {
+	bw_Application_assertCorrectThread( app );
+
+	bw_ThemeGtk_app = app;
+	bw_ThemeGtk_handler = handler;
+	bw_ThemeGtk_data = data;
+	bw_ThemeGtk_last = bw_Application_getSystemTheme( app );
+
+	GtkSettings* settings = gtk_settings_get_default();
+	if ( !bw_ThemeGtk_connected && settings != NULL && handler != NULL ) {
+		g_signal_connect( settings, "notify::gtk-theme-name", G_CALLBACK( _bw_ThemeGtk_onChange ), NULL );
+		g_signal_connect( settings, "notify::gtk-application-prefer-dark-theme", G_CALLBACK( _bw_ThemeGtk_onChange ), NULL );
+		bw_ThemeGtk_connected = TRUE;
+	}
+}
+
+
+
+void _bw_ThemeGtk_onChange( GObject* settings, GParamSpec* param, gpointer data ) {
+	UNUSED( settings );
+	UNUSED( param );
+	UNUSED( data );
+
+	// Switching the theme may change both settings, which would otherwise be reported twice
+	uint8_t theme = bw_Application_getSystemTheme( bw_ThemeGtk_app );
+	if ( theme != bw_ThemeGtk_last ) {
+		bw_ThemeGtk_last = theme;
+
+		if ( bw_ThemeGtk_handler != NULL )
+			bw_ThemeGtk_handler( bw_ThemeGtk_app, theme, bw_ThemeGtk_data );
+	}
+}
@@ -0,0 +1,72 @@
+#include "win32.h"
+#include "../application.h"
+#include "../common.h"
+#include "../win32.h"
+
+#include <wchar.h>
+
+
+
+static bw_Application* bw_ThemeWin32_app = NULL;
+static bw_ApplicationThemeFn bw_ThemeWin32_handler = NULL;
+static void* bw_ThemeWin32_data = NULL;
+static uint8_t bw_ThemeWin32_last = BW_THEME_UNKNOWN;
+static HWND bw_ThemeWin32_window = NULL;
+
+
+
+uint8_t bw_Application_getSystemTheme( bw_Application* app ) {
+	UNUSED( app );
+
+	DWORD light;
+	DWORD size = sizeof( light );
+	// Versions of Windows from before the dark theme don't have the value
+	LSTATUS status = RegGetValueW(
+		HKEY_CURRENT_USER,
+		L"Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize",
+		L"AppsUseLightTheme",
+		RRF_RT_REG_DWORD,
+		NULL,
+		&light,
+		&size
+	);
+	if ( status != ERROR_SUCCESS )
+		return BW_THEME_UNKNOWN;
+
+	return light != 0 ? BW_THEME_LIGHT : BW_THEME_DARK;
+}
+
+void bw_Application_setThemeHandler( bw_Application* app, bw_ApplicationThemeFn handler, void* data ) {
+	bw_Application_assertCorrectThread( app );
+
+	bw_ThemeWin32_app = app;
+	bw_ThemeWin32_handler = handler;
+	bw_ThemeWin32_data = data;
+	bw_ThemeWin32_last = bw_Application_getSystemTheme( app );
+
+	// Message-only windows don't get the messages that are broadcast, so the window is a top-level one that is never shown
+	if ( bw_ThemeWin32_window == NULL && handler != NULL ) {
+		bw_ThemeWin32_window = CreateWindowExW( WS_EX_TOOLWINDOW, L"bw-theme", L"", WS_POPUP, 0, 0, 0, 0, NULL, NULL, app->impl.handle, NULL );
+		if ( bw_ThemeWin32_window == NULL )
+			BW_WIN32_PANIC_LAST_ERROR
+	}
+}
+
+
+
+LRESULT CALLBACK bw_ThemeWin32_proc( HWND hwnd, UINT msg, WPARAM wp, LPARAM lp ) {
+	// The theme is one of the settings that are announced as `ImmersiveColorSet`
+	if ( msg != WM_SETTINGCHANGE || lp == 0 || wcscmp( (const wchar_t*)lp, L"ImmersiveColorSet" ) != 0 )
+		return DefWindowProcW( hwnd, msg, wp, lp );
+
+	// The message also comes for changes of the accent color, which leave the theme as it is
+	uint8_t theme = bw_Application_getSystemTheme( bw_ThemeWin32_app );
+	if ( theme != bw_ThemeWin32_last ) {
+		bw_ThemeWin32_last = theme;
+
+		if ( bw_ThemeWin32_handler != NULL )
+			bw_ThemeWin32_handler( bw_ThemeWin32_app, theme, bw_ThemeWin32_data );
+	}
+
+	return 0;
+}
@@ -0,0 +1,14 @@
+#ifndef BW_THEME_WIN32_H
+#define BW_THEME_WIN32_H
+
+#define WIN32_LEAN_AND_MEAN
+#include <Windows.h>
+
+
+
+/// The window procedure of the hidden window that is told when the settings of the system change.
+LRESULT CALLBACK bw_ThemeWin32_proc( HWND hwnd, UINT msg, WPARAM wp, LPARAM lp );
+
+
+
+#endif//BW_THEME_WIN32_H
@@ -0,0 +1,63 @@
+#ifndef BW_TRAY_ICON_H
+#define BW_TRAY_ICON_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#include "application.h"
+#include "bool.h"
+#include "menu.h"
+#include "string.h"
+
+#include <stdint.h>
+
+
+
+/// An icon in the notification area of the taskbar, or the system tray.
+typedef struct bw_TrayIcon bw_TrayIcon;
+
+/// Invoked when the tray icon has been clicked on with the left mouse button.
+typedef void (*bw_TrayIconClickFn)( bw_TrayIcon* icon, void* data );
+/// Invoked when an item of the tray icon's context menu has been activated, with the new check state of the item.
+typedef void (*bw_TrayIconMenuHandlerFn)( bw_TrayIcon* icon, uint32_t item_id, BOOL checked, void* data );
+
+
+
+/// Removes the tray icon and frees it.
+/// The application keeps running for as long as it has tray icons, just like it does for windows.
+void bw_TrayIcon_free( bw_TrayIcon* icon );
+
+/// Puts a new icon in the system tray.
+///
+/// # Arguments
+/// * `icon_path` - The image file of the icon, which needs to be an `.ico` file on Windows. If empty or if it can't be loaded, the default application icon is used.
+/// * `tooltip` - The text that is shown when hovering over the icon.
+/// * `menu` - The context menu of the icon, or NULL for none. It is only read during this call.
+/// * `on_click` - May be NULL.
+/// * `on_menu_item` - May be NULL.
+///
+/// *Note:* Tray icons are not supported when CEF provides the windows itself. The icon is never shown then.
+bw_TrayIcon* bw_TrayIcon_new(
+	bw_Application* app,
+	bw_CStrSlice icon_path,
+	bw_CStrSlice tooltip,
+	const bw_Menu* menu,
+	bw_TrayIconClickFn on_click,
+	bw_TrayIconMenuHandlerFn on_menu_item,
+	void* data
+);
+
+/// Replaces the image of the icon.
+/// See `bw_TrayIcon_new` for the requirements of `icon_path`.
+void bw_TrayIcon_setIcon( bw_TrayIcon* icon, bw_CStrSlice icon_path );
+
+void bw_TrayIcon_setTooltip( bw_TrayIcon* icon, bw_CStrSlice tooltip );
+
+
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#endif//BW_TRAY_ICON_H
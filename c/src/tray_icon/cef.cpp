@@ -0,0 +1,30 @@
+#include "common.h"
+#include "../common.h"
+
+
+
+void bw_TrayIconImpl_free( bw_TrayIconImpl* impl ) {
+	UNUSED( impl );
+}
+
+bw_TrayIconImpl bw_TrayIconImpl_new( bw_TrayIcon* icon, bw_CStrSlice icon_path, bw_CStrSlice tooltip, const bw_Menu* menu ) {
+	UNUSED( icon );
+	UNUSED( icon_path );
+	UNUSED( tooltip );
+	UNUSED( menu );
+
+	// TODO: Implement tray icons for when CEF provides the windows itself
+	bw_TrayIconImpl impl;
+	impl.unused = 0;
+	return impl;
+}
+
+void bw_TrayIconImpl_setIcon( bw_TrayIconImpl* impl, bw_CStrSlice icon_path ) {
+	UNUSED( impl );
+	UNUSED( icon_path );
+}
+
+void bw_TrayIconImpl_setTooltip( bw_TrayIconImpl* impl, bw_CStrSlice tooltip ) {
+	UNUSED( impl );
+	UNUSED( tooltip );
+}
@@ -0,0 +1,21 @@
+#ifndef BW_TRAY_ICON_CEF_H
+#define BW_TRAY_ICON_CEF_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+
+
+// CEF has no tray icons, so there is nothing to keep track of
+typedef struct {
+	char unused;
+} bw_TrayIconImpl;
+
+
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif//BW_TRAY_ICON_CEF_H
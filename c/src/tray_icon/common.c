@@ -0,0 +1,60 @@
+#include "common.h"
+
+#include <stdlib.h>
+
+
+
+void bw_TrayIcon_free( bw_TrayIcon* icon ) {
+	bw_Application_assertCorrectThread( icon->app );
+
+	bw_TrayIconImpl_free( &icon->impl );
+
+	bw_Application* app = icon->app;
+	free( icon );
+
+	// Tray icons keep the application alive, just like windows do
+	app->windows_alive -= 1;
+	if ( app->windows_alive == 0 && app->is_done )
+		bw_Application_exit( app, 0 );
+}
+
+bw_TrayIcon* bw_TrayIcon_new(
+	bw_Application* app,
+	bw_CStrSlice icon_path,
+	bw_CStrSlice tooltip,
+	const bw_Menu* menu,
+	bw_TrayIconClickFn on_click,
+	bw_TrayIconMenuHandlerFn on_menu_item,
+	void* data
+) {
+	bw_Application_assertCorrectThread( app );
+
+	bw_TrayIcon* icon = (bw_TrayIcon*)malloc( sizeof( bw_TrayIcon ) );
+	icon->app = app;
+	icon->on_click = on_click;
+	icon->on_menu_item = on_menu_item;
+	icon->data = data;
+	icon->impl = bw_TrayIconImpl_new( icon, icon_path, tooltip, menu );
+
+	app->windows_alive += 1;
+
+	return icon;
+}
+
+void bw_TrayIcon_setIcon( bw_TrayIcon* icon, bw_CStrSlice icon_path ) {
+	bw_TrayIconImpl_setIcon( &icon->impl, icon_path );
+}
+
+void bw_TrayIcon_setTooltip( bw_TrayIcon* icon, bw_CStrSlice tooltip ) {
+	bw_TrayIconImpl_setTooltip( &icon->impl, tooltip );
+}
+
+void bw_TrayIcon_triggerClick( bw_TrayIcon* icon ) {
+	if ( icon->on_click != NULL )
+		icon->on_click( icon, icon->data );
+}
+
+void bw_TrayIcon_triggerMenuItem( bw_TrayIcon* icon, uint32_t item_id, BOOL checked ) {
+	if ( icon->on_menu_item != NULL )
+		icon->on_menu_item( icon, item_id, checked, icon->data );
+}
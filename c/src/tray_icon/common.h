@@ -0,0 +1,46 @@
+#ifndef BW_TRAY_ICON_COMMON_H
+#define BW_TRAY_ICON_COMMON_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#include "../tray_icon.h"
+
+#if defined(BW_WIN32)
+#include "win32.h"
+#elif defined(BW_GTK)
+#include "gtk.h"
+#elif defined(BW_CEF_WINDOW)
+#include "cef.h"
+#endif
+
+
+
+struct bw_TrayIcon {
+	bw_Application* app;
+	bw_TrayIconClickFn on_click;
+	bw_TrayIconMenuHandlerFn on_menu_item;
+	void* data;
+	bw_TrayIconImpl impl;
+};
+
+
+
+void bw_TrayIcon_triggerClick( bw_TrayIcon* icon );
+void bw_TrayIcon_triggerMenuItem( bw_TrayIcon* icon, uint32_t item_id, BOOL checked );
+
+void bw_TrayIconImpl_free( bw_TrayIconImpl* impl );
+/// Creates the native icon.
+/// `icon` is fully initialized, except for its `impl` field.
+bw_TrayIconImpl bw_TrayIconImpl_new( bw_TrayIcon* icon, bw_CStrSlice icon_path, bw_CStrSlice tooltip, const bw_Menu* menu );
+void bw_TrayIconImpl_setIcon( bw_TrayIconImpl* impl, bw_CStrSlice icon_path );
+void bw_TrayIconImpl_setTooltip( bw_TrayIconImpl* impl, bw_CStrSlice tooltip );
+
+
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#endif//BW_TRAY_ICON_COMMON_H
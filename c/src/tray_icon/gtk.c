@@ -0,0 +1,94 @@
+#include "common.h"
+#include "../common.h"
+#include "../menu/gtk.h"
+
+// GtkStatusIcon is deprecated, but GTK 3 has nothing else to put icons in the system tray with.
+G_GNUC_BEGIN_IGNORE_DEPRECATIONS
+
+
+
+void bw_TrayIconGtk_setIcon( GtkStatusIcon* handle, bw_CStrSlice icon_path );
+void _bw_TrayIconGtk_onActivate( GtkStatusIcon* handle, gpointer data );
+void _bw_TrayIconGtk_onMenuItem( void* icon, uint32_t item_id, BOOL checked );
+void _bw_TrayIconGtk_onPopupMenu( GtkStatusIcon* handle, guint button, guint activate_time, gpointer data );
+
+
+
+void bw_TrayIconImpl_free( bw_TrayIconImpl* impl ) {
+	gtk_status_icon_set_visible( GTK_STATUS_ICON(impl->handle), FALSE );
+	g_object_unref( impl->handle );
+
+	if ( impl->menu != NULL )
+		gtk_widget_destroy( impl->menu );
+}
+
+bw_TrayIconImpl bw_TrayIconImpl_new( bw_TrayIcon* icon, bw_CStrSlice icon_path, bw_CStrSlice tooltip, const bw_Menu* menu ) {
+	bw_TrayIconImpl impl;
+
+	GtkStatusIcon* handle = gtk_status_icon_new();
+	impl.handle = G_OBJECT(handle);
+	bw_TrayIconGtk_setIcon( handle, icon_path );
+	bw_TrayIconImpl_setTooltip( &impl, tooltip );
+
+	impl.menu = NULL;
+	if ( menu != NULL ) {
+		impl.menu = bw_MenuGtk_create( menu, FALSE, NULL, _bw_TrayIconGtk_onMenuItem, (void*)icon );
+		g_object_ref_sink( impl.menu );
+		gtk_widget_show_all( impl.menu );
+	}
+
+	g_signal_connect( handle, "activate", G_CALLBACK( _bw_TrayIconGtk_onActivate ), (gpointer)icon );
+	g_signal_connect( handle, "popup-menu", G_CALLBACK( _bw_TrayIconGtk_onPopupMenu ), (gpointer)icon );
+
+	gtk_status_icon_set_visible( handle, TRUE );
+	return impl;
+}
+
+void bw_TrayIconImpl_setIcon( bw_TrayIconImpl* impl, bw_CStrSlice icon_path ) {
+	bw_TrayIconGtk_setIcon( GTK_STATUS_ICON(impl->handle), icon_path );
+}
+
+void bw_TrayIconImpl_setTooltip( bw_TrayIconImpl* impl, bw_CStrSlice tooltip ) {
+	char* text = bw_string_copyAsNewCstr( tooltip );
+	gtk_status_icon_set_tooltip_text( GTK_STATUS_ICON(impl->handle), text );
+	bw_string_freeCstr( text );
+}
+
+void bw_TrayIconGtk_setIcon( GtkStatusIcon* handle, bw_CStrSlice icon_path ) {
+	if ( icon_path.len > 0 ) {
+		char* path = bw_string_copyAsNewCstr( icon_path );
+		GdkPixbuf* pixbuf = gdk_pixbuf_new_from_file( path, NULL );
+		bw_string_freeCstr( path );
+
+		if ( pixbuf != NULL ) {
+			gtk_status_icon_set_from_pixbuf( handle, pixbuf );
+			g_object_unref( pixbuf );
+			return;
+		}
+	}
+
+	gtk_status_icon_set_from_icon_name( handle, "application-x-executable" );
+}
+
+
+
+void _bw_TrayIconGtk_onActivate( GtkStatusIcon* handle, gpointer data ) {
+	UNUSED( handle );
+
+	bw_TrayIcon_triggerClick( (bw_TrayIcon*)data );
+}
+
+void _bw_TrayIconGtk_onMenuItem( void* icon, uint32_t item_id, BOOL checked ) {
+	bw_TrayIcon_triggerMenuItem( (bw_TrayIcon*)icon, item_id, checked );
+}
+
+void _bw_TrayIconGtk_onPopupMenu( GtkStatusIcon* handle, guint button, guint activate_time, gpointer data ) {
+	bw_TrayIcon* icon = (bw_TrayIcon*)data;
+
+	if ( icon->impl.menu != NULL )
+		gtk_menu_popup( GTK_MENU(icon->impl.menu), NULL, NULL, gtk_status_icon_position_menu, handle, button, activate_time );
+}
+
+
+
+G_GNUC_END_IGNORE_DEPRECATIONS
@@ -0,0 +1,15 @@
+#ifndef BW_TRAY_ICON_GTK_H
+#define BW_TRAY_ICON_GTK_H
+
+#include <gtk/gtk.h>
+
+
+
+typedef struct {
+	GObject* handle;	// The GtkStatusIcon
+	GtkWidget* menu;	// NULL if there is no context menu
+} bw_TrayIconImpl;
+
+
+
+#endif//BW_TRAY_ICON_GTK_H
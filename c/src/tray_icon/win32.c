@@ -0,0 +1,182 @@
+#pragma comment(lib, "shell32.lib")
+
+#include "common.h"
+#include "../application.h"
+#include "../common.h"
+#include "../menu/win32.h"
+#include "../win32.h"
+
+#include <shellapi.h>
+#include <stdlib.h>
+#include <string.h>
+#include <wchar.h>
+
+
+
+// The message that the shell sends to the hidden window when something happens with the icon
+#define BW_TRAY_ICON_WIN32_MESSAGE (WM_APP + 1)
+
+
+
+void bw_TrayIconWin32_add( bw_TrayIconImpl* impl );
+void bw_TrayIconWin32_initData( const bw_TrayIconImpl* impl, NOTIFYICONDATAW* data );
+void bw_TrayIconWin32_loadIcon( bw_TrayIconImpl* impl, bw_CStrSlice icon_path );
+void bw_TrayIconWin32_setTooltip( bw_TrayIconImpl* impl, bw_CStrSlice tooltip );
+void bw_TrayIconWin32_showMenu( bw_TrayIcon* icon );
+
+
+
+void bw_TrayIconImpl_free( bw_TrayIconImpl* impl ) {
+	NOTIFYICONDATAW data;
+	bw_TrayIconWin32_initData( impl, &data );
+	Shell_NotifyIconW( NIM_DELETE, &data );
+
+	if ( impl->menu != NULL )
+		DestroyMenu( impl->menu );
+	if ( impl->owns_icon )
+		DestroyIcon( impl->icon );
+	DestroyWindow( impl->handle );
+}
+
+bw_TrayIconImpl bw_TrayIconImpl_new( bw_TrayIcon* icon, bw_CStrSlice icon_path, bw_CStrSlice tooltip, const bw_Menu* menu ) {
+	bw_TrayIconImpl impl;
+
+	// The window is never shown, it only exists to receive messages
+	impl.handle = CreateWindowExW( 0, L"bw-tray-icon", L"", 0, 0, 0, 0, 0, NULL, NULL, icon->app->impl.handle, NULL );
+	if ( impl.handle == NULL )
+		BW_WIN32_PANIC_LAST_ERROR
+	SetWindowLongPtrW( impl.handle, GWLP_USERDATA, (LONG_PTR)icon );
+
+	// Context menus don't have accelerators
+	impl.menu = NULL;
+	if ( menu != NULL ) {
+		HACCEL accelerators;
+		impl.menu = bw_MenuWin32_create( menu, FALSE, &accelerators );
+		if ( accelerators != NULL )
+			DestroyAcceleratorTable( accelerators );
+	}
+
+	bw_TrayIconWin32_loadIcon( &impl, icon_path );
+	bw_TrayIconWin32_setTooltip( &impl, tooltip );
+	bw_TrayIconWin32_add( &impl );
+
+	return impl;
+}
+
+void bw_TrayIconImpl_setIcon( bw_TrayIconImpl* impl, bw_CStrSlice icon_path ) {
+	if ( impl->owns_icon )
+		DestroyIcon( impl->icon );
+	bw_TrayIconWin32_loadIcon( impl, icon_path );
+
+	NOTIFYICONDATAW data;
+	bw_TrayIconWin32_initData( impl, &data );
+	data.uFlags = NIF_ICON;
+	data.hIcon = impl->icon;
+	Shell_NotifyIconW( NIM_MODIFY, &data );
+}
+
+void bw_TrayIconImpl_setTooltip( bw_TrayIconImpl* impl, bw_CStrSlice tooltip ) {
+	bw_TrayIconWin32_setTooltip( impl, tooltip );
+
+	NOTIFYICONDATAW data;
+	bw_TrayIconWin32_initData( impl, &data );
+	data.uFlags = NIF_TIP;
+	wcscpy( data.szTip, impl->tooltip );
+	Shell_NotifyIconW( NIM_MODIFY, &data );
+}
+
+void bw_TrayIconWin32_add( bw_TrayIconImpl* impl ) {
+	NOTIFYICONDATAW data;
+	bw_TrayIconWin32_initData( impl, &data );
+	data.uFlags = NIF_MESSAGE | NIF_ICON | NIF_TIP;
+	data.uCallbackMessage = BW_TRAY_ICON_WIN32_MESSAGE;
+	data.hIcon = impl->icon;
+	wcscpy( data.szTip, impl->tooltip );
+
+	if ( !Shell_NotifyIconW( NIM_ADD, &data ) )
+		BW_WIN32_PANIC_LAST_ERROR
+}
+
+void bw_TrayIconWin32_initData( const bw_TrayIconImpl* impl, NOTIFYICONDATAW* data ) {
+	memset( data, 0, sizeof( NOTIFYICONDATAW ) );
+	data->cbSize = sizeof( NOTIFYICONDATAW );
+	data->hWnd = impl->handle;
+	// Every icon has its own window, so they can all have the same ID
+	data->uID = 1;
+}
+
+void bw_TrayIconWin32_loadIcon( bw_TrayIconImpl* impl, bw_CStrSlice icon_path ) {
+	impl->icon = NULL;
+
+	if ( icon_path.len > 0 ) {
+		WCHAR* path = bw_win32_copyAsNewWstr( icon_path );
+		impl->icon = (HICON)LoadImageW( NULL, path, IMAGE_ICON, GetSystemMetrics( SM_CXSMICON ), GetSystemMetrics( SM_CYSMICON ), LR_LOADFROMFILE );
+		free( path );
+	}
+
+	impl->owns_icon = impl->icon != NULL;
+	if ( impl->icon == NULL )
+		impl->icon = LoadIconW( NULL, IDI_APPLICATION );
+}
+
+// Tooltips are cut off at 127 characters
+void bw_TrayIconWin32_setTooltip( bw_TrayIconImpl* impl, bw_CStrSlice tooltip ) {
+	WCHAR* tooltip_w = bw_win32_copyAsNewWstr( tooltip );
+
+	size_t max_len = sizeof( impl->tooltip ) / sizeof( WCHAR ) - 1;
+	wcsncpy( impl->tooltip, tooltip_w, max_len );
+	impl->tooltip[ max_len ] = L'\0';
+
+	free( tooltip_w );
+}
+
+void bw_TrayIconWin32_showMenu( bw_TrayIcon* icon ) {
+	POINT cursor;
+	GetCursorPos( &cursor );
+
+	// The menu doesn't close when clicking elsewhere, unless its window is in the foreground.
+	SetForegroundWindow( icon->impl.handle );
+	TrackPopupMenu( icon->impl.menu, TPM_RIGHTBUTTON, cursor.x, cursor.y, 0, icon->impl.handle, NULL );
+	PostMessageW( icon->impl.handle, WM_NULL, 0, 0 );
+}
+
+LRESULT CALLBACK bw_TrayIconWin32_proc( HWND hwnd, UINT msg, WPARAM wp, LPARAM lp ) {
+	// The shell broadcasts this message when the taskbar has been recreated, after explorer.exe has restarted.
+	static UINT taskbar_created = 0;
+	if ( taskbar_created == 0 )
+		taskbar_created = RegisterWindowMessageW( L"TaskbarCreated" );
+
+	bw_TrayIcon* icon = (bw_TrayIcon*)GetWindowLongPtrW( hwnd, GWLP_USERDATA );
+	if ( icon == NULL )
+		return DefWindowProcW( hwnd, msg, wp, lp );
+
+	if ( msg == taskbar_created ) {
+		bw_TrayIconWin32_add( &icon->impl );
+		return 0;
+	}
+
+	switch ( msg ) {
+	case BW_TRAY_ICON_WIN32_MESSAGE:
+		switch ( LOWORD( lp ) ) {
+		case WM_LBUTTONUP:
+			bw_TrayIcon_triggerClick( icon );
+			break;
+		case WM_RBUTTONUP:
+			if ( icon->impl.menu != NULL )
+				bw_TrayIconWin32_showMenu( icon );
+			break;
+		}
+		break;
+	case WM_COMMAND:
+		if ( HIWORD( wp ) == 0 && icon->impl.menu != NULL ) {
+			BOOL checked;
+			if ( bw_MenuWin32_onCommand( icon->impl.menu, LOWORD( wp ), &checked ) )
+				bw_TrayIcon_triggerMenuItem( icon, LOWORD( wp ), checked );
+		}
+		break;
+	default:
+		return DefWindowProcW( hwnd, msg, wp, lp );
+	}
+
+	return 0;
+}
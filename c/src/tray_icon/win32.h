@@ -0,0 +1,24 @@
+#ifndef BW_TRAY_ICON_WIN32_H
+#define BW_TRAY_ICON_WIN32_H
+
+#define WIN32_LEAN_AND_MEAN
+#include <Windows.h>
+
+
+
+typedef struct {
+	HWND handle;	// The hidden window that receives the messages of the icon
+	HICON icon;
+	BOOL owns_icon;	// Whether or not the icon has been loaded from a file, and needs to be destroyed
+	HMENU menu;	// NULL if there is no context menu
+	WCHAR tooltip[128];	// Kept to be able to add the icon again when the taskbar gets recreated
+} bw_TrayIconImpl;
+
+
+
+/// The window procedure of the hidden windows that receive the messages of the tray icons.
+LRESULT CALLBACK bw_TrayIconWin32_proc( HWND hwnd, UINT msg, WPARAM wp, LPARAM lp );
+
+
+
+#endif//BW_TRAY_ICON_WIN32_H
@@ -0,0 +1,110 @@
+#include "environment.hpp"
+#include "util.hpp"
+
+#include <vector>
+#include <WebView2EnvironmentOptions.h>
+#include <wrl.h>
+
+using Microsoft::WRL::ComPtr;
+using Microsoft::WRL::Make;
+
+
+
+void bw_webview2_appendArgument( std::wstring& arguments, const std::string& argument );
+
+
+
+HRESULT bw_webview2_createEnvironment( bw_Application* app, ICoreWebView2CreateCoreWebView2EnvironmentCompletedHandler* handler ) {
+	bw_WebView2EnvironmentOptions* options = (bw_WebView2EnvironmentOptions*)app->engine_impl.environment_options;
+
+	ComPtr<CoreWebView2EnvironmentOptions> environment_options = Make<CoreWebView2EnvironmentOptions>();
+	if ( !options->browser_arguments.empty() )
+		environment_options->put_AdditionalBrowserArguments( options->browser_arguments.c_str() );
+	if ( !options->language.empty() )
+		environment_options->put_Language( options->language.c_str() );
+
+	// Custom schemes are treated as secure, so that pages loaded from them can use the same features as the ones that are loaded over HTTPS.
+	// Only newer versions of WebView2 support them.
+	ComPtr<ICoreWebView2EnvironmentOptions4> environment_options4;
+	if ( !options->custom_schemes.empty() && SUCCEEDED( environment_options.As( &environment_options4 ) ) ) {
+		std::vector<ComPtr<CoreWebView2CustomSchemeRegistration>> registrations;
+		std::vector<ICoreWebView2CustomSchemeRegistration*> registration_ptrs;
+
+		for ( const std::wstring& scheme : options->custom_schemes ) {
+			ComPtr<CoreWebView2CustomSchemeRegistration> registration = Make<CoreWebView2CustomSchemeRegistration>( scheme.c_str() );
+			registration->put_TreatAsSecure( TRUE );
+			registration->put_HasAuthorityComponent( TRUE );
+
+			registration_ptrs.push_back( registration.Get() );
+			registrations.push_back( registration );
+		}
+
+		environment_options4->SetCustomSchemeRegistrations( (UINT32)registration_ptrs.size(), registration_ptrs.data() );
+	}
+
+	const wchar_t* user_data_dir = options->user_data_dir.empty() ? nullptr : options->user_data_dir.c_str();
+
+	return CreateCoreWebView2EnvironmentWithOptions( nullptr, user_data_dir, environment_options.Get(), handler );
+}
+
+bw_WebView2EnvironmentOptions* bw_webview2_environmentOptions( const bw_ApplicationSettings* settings ) {
+	bw_WebView2EnvironmentOptions* options = new bw_WebView2EnvironmentOptions;
+	options->user_data_dir = bw_webview2_toWstring( settings->user_data_dir );
+	options->language = bw_webview2_toWstring( settings->locale );
+	for ( size_t i = 0; i < settings->custom_scheme_count; i++ )
+		options->custom_schemes.push_back( bw_webview2_toWstring( settings->custom_schemes[i] ) );
+
+	// Everything that WebView2 has no option for, is passed to Chromium as a command-line switch.
+	std::wstring& arguments = options->browser_arguments;
+
+	if ( settings->cache_path.len != 0 )
+		bw_webview2_appendArgument( arguments, "--disk-cache-dir=" + std::string( settings->cache_path.data, settings->cache_path.len ) );
+
+	switch ( settings->log_severity ) {
+	case 0:	break;
+	case 1:	bw_webview2_appendArgument( arguments, "--enable-logging" );	bw_webview2_appendArgument( arguments, "--v=1" );	break;
+	case 6:	bw_webview2_appendArgument( arguments, "--disable-logging" );	break;
+	// Chromium's log levels start at info
+	default:
+		bw_webview2_appendArgument( arguments, "--enable-logging" );
+		bw_webview2_appendArgument( arguments, "--log-level=" + std::to_string( settings->log_severity - 2 ) );
+	}
+	if ( settings->log_file.len != 0 )
+		bw_webview2_appendArgument( arguments, "--log-file=" + std::string( settings->log_file.data, settings->log_file.len ) );
+
+	if ( settings->remote_debugging_port != 0 )
+		bw_webview2_appendArgument( arguments, "--remote-debugging-port=" + std::to_string( settings->remote_debugging_port ) );
+
+	if ( settings->disable_gpu ) {
+		bw_webview2_appendArgument( arguments, "--disable-gpu" );
+		bw_webview2_appendArgument( arguments, "--disable-gpu-compositing" );
+	}
+	if ( settings->software_rasterization )
+		bw_webview2_appendArgument( arguments, "--disable-gpu-rasterization" );
+	if ( settings->autoplay_without_gesture )
+		bw_webview2_appendArgument( arguments, "--autoplay-policy=no-user-gesture-required" );
+	// WebView2 has no offscreen browsers, so `max_frame_rate` doesn't apply to it.
+
+	// TODO: Support `persist_session_cookies`. WebView2 has no option for it, and Chromium has no switch for it either.
+
+	// The switches may be given without their dashes, like CEF takes them
+	for ( size_t i = 0; i < settings->engine_switch_count; i++ ) {
+		std::string argument( settings->engine_switches[i].data, settings->engine_switches[i].len );
+		if ( argument.rfind( "--", 0 ) != 0 )
+			argument = "--" + argument;
+		bw_webview2_appendArgument( arguments, argument );
+	}
+
+	return options;
+}
+
+void bw_webview2_appendArgument( std::wstring& arguments, const std::string& argument ) {
+	if ( !arguments.empty() )
+		arguments += L' ';
+
+	// Quote arguments with spaces in them, like paths
+	if ( argument.find( ' ' ) != std::string::npos )
+		arguments += L'"' + bw_webview2_toWstring( argument ) + L'"';
+	else
+		arguments += bw_webview2_toWstring( argument );
+}
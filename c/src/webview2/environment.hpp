@@ -0,0 +1,29 @@
+#ifndef BW_WEBVIEW2_ENVIRONMENT_HPP
+#define BW_WEBVIEW2_ENVIRONMENT_HPP
+
+#include "../application.h"
+
+#include <string>
+#include <vector>
+#include <WebView2.h>
+
+
+
+// The options that every WebView2 environment of the application is created with.
+struct bw_WebView2EnvironmentOptions {
+	std::wstring user_data_dir;
+	std::wstring browser_arguments;
+	std::wstring language;
+	std::vector<std::wstring> custom_schemes;
+};
+
+
+
+// Starts creating a WebView2 environment with the options of the application.
+HRESULT bw_webview2_createEnvironment( bw_Application* app, ICoreWebView2CreateCoreWebView2EnvironmentCompletedHandler* handler );
+// Translates the application settings into the options for the WebView2 environments.
+bw_WebView2EnvironmentOptions* bw_webview2_environmentOptions( const bw_ApplicationSettings* settings );
+
+
+
+#endif//BW_WEBVIEW2_ENVIRONMENT_HPP
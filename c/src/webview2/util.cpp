@@ -0,0 +1,185 @@
+#include "util.hpp"
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+
+
+void bw_webview2_appendUtf8( std::string& output, unsigned long code_point );
+size_t bw_webview2_skipWhitespace( const std::string& json, size_t pos );
+
+
+
+std::wstring bw_webview2_toWstring( bw_CStrSlice string ) {
+	if ( string.len == 0 )
+		return std::wstring();
+
+	int size = MultiByteToWideChar( CP_UTF8, 0, string.data, (int)string.len, 0, 0 );
+
+	std::wstring output( size, L'\0' );
+	MultiByteToWideChar( CP_UTF8, 0, string.data, (int)string.len, &output[0], size );
+	return output;
+}
+
+std::wstring bw_webview2_toWstring( const std::string& string ) {
+	bw_CStrSlice slice = { string.length(), string.c_str() };
+	return bw_webview2_toWstring( slice );
+}
+
+std::string bw_webview2_toUtf8( LPCWSTR string ) {
+	int len = (int)wcslen( string );
+	if ( len == 0 )
+		return std::string();
+
+	int size = WideCharToMultiByte( CP_UTF8, 0, string, len, 0, 0, 0, 0 );
+
+	std::string output( size, '\0' );
+	WideCharToMultiByte( CP_UTF8, 0, string, len, &output[0], size, 0, 0 );
+	return output;
+}
+
+bw_StrSlice bw_webview2_copyToStrSlice( LPCWSTR string ) {
+	std::string temp = bw_webview2_toUtf8( string );
+
+	bw_StrSlice slice;
+	slice.len = temp.length();
+	slice.data = (char*)malloc( slice.len );
+	memcpy( slice.data, temp.c_str(), slice.len );
+	return slice;
+}
+
+bool bw_webview2_parseJsonString( const std::string& json, size_t& pos, std::string& output ) {
+
+	if ( pos >= json.length() || json[pos] != '"' )
+		return false;
+	pos++;
+
+	while ( pos < json.length() ) {
+		char c = json[pos++];
+
+		if ( c == '"' )
+			return true;
+		if ( c != '\\' ) {
+			output += c;
+			continue;
+		}
+
+		if ( pos >= json.length() )
+			return false;
+
+		switch ( json[pos++] ) {
+		case '"':	output += '"';	break;
+		case '\\':	output += '\\';	break;
+		case '/':	output += '/';	break;
+		case 'b':	output += '\b';	break;
+		case 'f':	output += '\f';	break;
+		case 'n':	output += '\n';	break;
+		case 'r':	output += '\r';	break;
+		case 't':	output += '\t';	break;
+		case 'u': {
+			if ( pos + 4 > json.length() )
+				return false;
+			unsigned long code_unit = strtoul( json.substr( pos, 4 ).c_str(), 0, 16 );
+			pos += 4;
+
+			// Combine surrogate pairs into a single code point
+			if ( code_unit >= 0xD800 && code_unit < 0xDC00 && pos + 6 <= json.length() && json[pos] == '\\' && json[pos+1] == 'u' ) {
+				unsigned long low = strtoul( json.substr( pos + 2, 4 ).c_str(), 0, 16 );
+
+				if ( low >= 0xDC00 && low < 0xE000 ) {
+					code_unit = 0x10000 + ((code_unit - 0xD800) << 10) + (low - 0xDC00);
+					pos += 6;
+				}
+			}
+
+			bw_webview2_appendUtf8( output, code_unit );
+		}	break;
+		default:
+			return false;
+		}
+	}
+
+	return false;
+}
+
+bool bw_webview2_parseJsonStringArray( const std::string& json, std::vector<std::string>& output ) {
+	size_t pos = bw_webview2_skipWhitespace( json, 0 );
+
+	if ( pos >= json.length() || json[pos] != '[' )
+		return false;
+	pos = bw_webview2_skipWhitespace( json, pos + 1 );
+
+	if ( pos < json.length() && json[pos] == ']' )
+		return true;
+
+	while ( true ) {
+		std::string item;
+		if ( !bw_webview2_parseJsonString( json, pos, item ) )
+			return false;
+		output.push_back( item );
+
+		pos = bw_webview2_skipWhitespace( json, pos );
+		if ( pos >= json.length() )
+			return false;
+
+		if ( json[pos] == ']' )
+			return true;
+		if ( json[pos] != ',' )
+			return false;
+
+		pos = bw_webview2_skipWhitespace( json, pos + 1 );
+	}
+}
+
+std::string bw_webview2_toJsonString( const std::string& string ) {
+	std::string output = "\"";
+
+	for ( char c : string ) {
+		switch ( c ) {
+		case '"':	output += "\\\"";	break;
+		case '\\':	output += "\\\\";	break;
+		case '\n':	output += "\\n";	break;
+		case '\r':	output += "\\r";	break;
+		case '\t':	output += "\\t";	break;
+		default:
+			if ( (unsigned char)c < 0x20 ) {
+				char buffer[7];
+				snprintf( buffer, sizeof( buffer ), "\\u%04x", c );
+				output += buffer;
+			}
+			else
+				output += c;
+		}
+	}
+
+	output += '"';
+	return output;
+}
+
+void bw_webview2_appendUtf8( std::string& output, unsigned long code_point ) {
+
+	if ( code_point < 0x80 )
+		output += (char)code_point;
+	else if ( code_point < 0x800 ) {
+		output += (char)(0xC0 | (code_point >> 6));
+		output += (char)(0x80 | (code_point & 0x3F));
+	}
+	else if ( code_point < 0x10000 ) {
+		output += (char)(0xE0 | (code_point >> 12));
+		output += (char)(0x80 | ((code_point >> 6) & 0x3F));
+		output += (char)(0x80 | (code_point & 0x3F));
+	}
+	else {
+		output += (char)(0xF0 | (code_point >> 18));
+		output += (char)(0x80 | ((code_point >> 12) & 0x3F));
+		output += (char)(0x80 | ((code_point >> 6) & 0x3F));
+		output += (char)(0x80 | (code_point & 0x3F));
+	}
+}
+
+size_t bw_webview2_skipWhitespace( const std::string& json, size_t pos ) {
+	while ( pos < json.length() && (json[pos] == ' ' || json[pos] == '\t' || json[pos] == '\n' || json[pos] == '\r') )
+		pos++;
+	return pos;
+}
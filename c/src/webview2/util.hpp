@@ -0,0 +1,33 @@
+#ifndef BW_WEBVIEW2_UTIL_HPP
+#define BW_WEBVIEW2_UTIL_HPP
+
+#include "../string.h"
+
+#include <string>
+#include <vector>
+#define WIN32_LEAN_AND_MEAN
+#include <Windows.h>
+
+
+
+// Converts the given UTF-8 string into a UTF-16 string, as used by the WebView2 API.
+std::wstring bw_webview2_toWstring( bw_CStrSlice string );
+std::wstring bw_webview2_toWstring( const std::string& string );
+// Converts the given UTF-16 string into a UTF-8 string.
+std::string bw_webview2_toUtf8( LPCWSTR string );
+// Copies the given UTF-16 string into a newly allocated UTF-8 string slice.
+// It needs to be freed with `bw_string_free`.
+bw_StrSlice bw_webview2_copyToStrSlice( LPCWSTR string );
+
+// Parses the JSON string literal that starts at `pos` in `json`, and advances `pos` to right after it.
+// Returns false if there is no valid string literal at `pos`.
+bool bw_webview2_parseJsonString( const std::string& json, size_t& pos, std::string& output );
+// Parses a JSON array that only contains strings.
+// Returns false if `json` is not such an array.
+bool bw_webview2_parseJsonStringArray( const std::string& json, std::vector<std::string>& output );
+// Converts the given string into a JSON string literal.
+std::string bw_webview2_toJsonString( const std::string& string );
+
+
+
+#endif//BW_WEBVIEW2_UTIL_HPP
@@ -8,10 +8,26 @@
 
 
 
+// A message that invokes a `bw_WindowWin32InvokeFn` on the thread of the window, with the function in `wParam` and its data in `lParam`.
+// When it is sent with `SendMessageW`, the sender waits for it, and gets its result as the result of the message.
+#define BW_WM_INVOKE (WM_APP + 1)
+
+typedef LRESULT (*bw_WindowWin32InvokeFn)( void* data );
+
 typedef struct {
 	HWND handle;
 	DWORD style;
 	BYTE opacity;
+	HACCEL accelerators;	// The accelerators of the menu bar, or NULL if there are none
+	BOOL has_backdrop;	// Whether or not a backdrop is shown through the window
+	int corner_radius;	// The radius that the corners are cut off with, or 0 if the system rounds the corners itself
+	uint8_t resize_strategy;	// See `bw_WindowOptions::resize_strategy`
+	HWND maximize_button;	// The window that covers the custom maximize button, or NULL if there is none
+	RECT maximize_button_rect;
+	HWND owner;	// The window that this window stays above, or NULL if there is none
+	BOOL modal;	// Whether the owner is disabled while this window is visible
+	HWND native_parent;	// The window of another toolkit that this window is embedded in, or NULL if there is none
+	HICON icon;	// The icon that has been set with `bw_Window_setIcon`, or NULL if the default icon is used
 } bw_WindowImpl;
 
 
@@ -7,6 +7,7 @@ extern "C" {
 
 #include "application.h"
 #include "common.h"
+#include "menu.h"
 #include "string.h"
 
 #ifndef BW_BINDGEN
@@ -43,15 +44,51 @@ typedef struct bw_WindowCallbacks {
 	void (*on_loaded)( const bw_Window* );
 	/// Fired when a window is resizing
 	void (*on_resize)( const bw_Window*, unsigned int width, unsigned int height );
+	/// Fired when the window has moved to a display with another scale factor, or when the scale factor of its display has changed.
+	void (*on_scale_factor_changed)( const bw_Window*, float scale_factor );
+	/// Fired when the window has moved, with the new position of its top left corner.
+	void (*on_move)( const bw_Window*, int x, int y );
+	/// Fired when the window has gained or lost the keyboard focus.
+	void (*on_focus)( const bw_Window*, BOOL focused );
 } bw_WindowCallbacks;
 
 typedef struct bw_WindowOptions {
 	bool borders;
 	bool minimizable;
 	bool resizable;
+	bool tool_window;	/// A small utility window, which has a thin titlebar and doesn't appear in the taskbar or when alt-tabbing.
+	uint8_t backdrop;	/// The material behind the window's content: 0 for none, 1 for mica, 2 for acrylic or 3 for vibrancy. Falls back to none when it isn't supported.
+	uint8_t shadow;	/// 0 for the platform's default, 1 to show a drop shadow around the window or 2 to hide it.
+	int16_t corner_radius;	/// The radius of the window's corners in pixels, or -1 for the platform's default.
+	uint8_t resize_strategy;	/// How the content keeps up with the window while it is being resized: 0 for the platform's default, 1 to resize and repaint it before the resize completes, or 2 to fill the uncovered area with the background color.
+	bool physical_size;	/// Whether the width and height given to `bw_Window_new` are in physical pixels, instead of in logical pixels that are scaled by the scale factor of the display.
+	const bw_Menu* menu_bar;	/// The menu bar of the window, or NULL for none. It is only read during the creation of the window.
+	const bw_Window* owner;	/// The window that this window stays above and is centered on, or NULL for none.
+	bool modal;	/// Whether the owner can't be interacted with while this window is visible. Only has an effect if there is an owner.
+	void* native_parent;	/// A native window of another toolkit, like the HWND of a window of winit, that this window is embedded in as a child filling its client area, or NULL for none. Only supported on Windows, and ignored elsewhere.
+	uint16_t native_parent_top;	/// The height in physical pixels of the area at the top of the client area of `native_parent` that is left uncovered, like for a tab strip.
 } bw_WindowOptions;
 
+typedef struct bw_WindowNativeHandle {
+	uint8_t kind;	/// 1 for a Win32 HWND in `window` with its HINSTANCE in `display`, 2 for an X11 window in `xid` with its `Display*` in `display`, or 3 for a Wayland `wl_surface*` in `window` with its `wl_display*` in `display`.
+	void* window;
+	void* display;	/// May be NULL if it is unknown.
+	unsigned long xid;
+} bw_WindowNativeHandle;
+
+typedef struct bw_WindowMessageBox {
+	uint8_t kind;	/// The icon of the message box: 0 for information, 1 for a warning, 2 for an error or 3 for a question.
+	bw_CStrSlice title;
+	bw_CStrSlice message;
+	const bw_CStrSlice* buttons;	/// The captions of the buttons, in the order in which they are shown.
+	size_t button_count;
+} bw_WindowMessageBox;
+
 typedef void (*bw_WindowDispatchFn)( bw_Window* window, void* data );
+/// Invoked when a message box has been closed, with the index of the button that has been chosen, or -1 if it has been dismissed without choosing one.
+typedef void (*bw_WindowMessageBoxFn)( bw_Window* window, int button, void* data );
+/// Invoked when a menu item has been activated, with the new check state of the item.
+typedef void (*bw_WindowMenuHandlerFn)( bw_Window* window, uint32_t item_id, BOOL checked, void* data );
 typedef struct bw_WindowDispatchData bw_WindowDispatchData;
 
 
@@ -63,6 +100,8 @@ struct bw_Window {
 	bool dropped;	// Whether or not the window may be destroyed when it is actually closed
 	bw_WindowCallbacks callbacks;
 	void* user_data;
+	bw_WindowMenuHandlerFn menu_handler;	// Invoked when an item of the menu bar gets activated
+	void* menu_handler_data;
 	bw_WindowImpl impl;	// Data for the implementation of the window
 };
 
@@ -79,12 +118,24 @@ void bw_Window_drop( bw_Window* window );
 /// Gets the width and height of the usable area inside the window.
 bw_Dims2D bw_Window_getContentDimensions( bw_Window* window );
 
+/// Gets the handle of the window of the platform, so that it can be handed to other toolkits.
+/// Returns FALSE if there is none, like for GTK windows that haven't been realized yet.
+BOOL bw_Window_getNativeHandle( const bw_Window* window, bw_WindowNativeHandle* handle );
+
 /// Gets the opacity of the window as a value from 0 to 255.
 uint8_t bw_Window_getOpacity( bw_Window* window );
 
 /// Gets the X and Y coordinates of the window position relative to the desktop screen.
 bw_Pos2D bw_Window_getPosition( bw_Window* window );
 
+/// Stores the display that contains the largest part of the window in `monitor`.
+/// Returns FALSE if the window isn't on any display.
+BOOL bw_Window_getMonitor( const bw_Window* window, bw_Monitor* monitor );
+
+/// Gets the number of physical pixels that make up one logical pixel on the display that the window is on.
+/// This is 1.0 on displays that aren't scaled, and 2.0 on displays that are scaled to 200%.
+float bw_Window_getScaleFactor( const bw_Window* window );
+
 /// Copies as many bytes into `title` that fit in there.
 /// Returns the number of characters the title actually has.
 size_t bw_Window_getTitle( bw_Window* window, char** title );
@@ -114,6 +165,21 @@ bw_Window* bw_Window_new(
 
 void bw_Window_setContentDimensions( bw_Window* window, bw_Dims2D dimensions );
 
+/// Marks the area of the custom maximize button of a frameless window, that draws its own titlebar.
+/// The position is relative to the content area of the window.
+/// Hovering over the area shows the snap layouts on Windows 11, and clicking it maximizes or restores the window.
+/// A size of 0 removes the area again.
+/// Does nothing on other platforms than Windows.
+void bw_Window_setMaximizeButtonArea( bw_Window* window, bw_Pos2D position, bw_Dims2D size );
+
+/// Sets the icon of the window, which is shown in its title bar and in the taskbar.
+/// If `width` and `height` are 0, `data` is a PNG image of `size` bytes, otherwise it contains `width` * `height` pixels in the RGBA format.
+/// The data is only read during this call, and nothing happens if it can't be decoded.
+void bw_Window_setIcon( bw_Window* window, const void* data, size_t size, unsigned int width, unsigned int height );
+
+/// Sets the function that is invoked whenever an item of the window's menu bar gets activated.
+void bw_Window_setMenuHandler( bw_Window* window, bw_WindowMenuHandlerFn handler, void* data );
+
 void bw_Window_setOpacity( bw_Window* window, uint8_t opacity );
 
 void bw_Window_setPosition( bw_Window* window, bw_Pos2D position );
@@ -127,8 +193,22 @@ void bw_Window_setWindowDimensions( bw_Window* window, bw_Dims2D dimensions );
 /// Is generally called after window creation.
 void bw_Window_show( bw_Window* window );
 
+/// Shows a message box that is modal to the window, and invokes `handler` once it has been closed.
+/// If `button_count` is 0, the message box only has an OK button.
+/// This returns right away, unless the platform only has blocking message boxes.
+void bw_Window_showMessageBox( bw_Window* window, const bw_WindowMessageBox* message_box, bw_WindowMessageBoxFn handler, void* data );
+
+/// Starts a drag operation that carries the given files and text out of the window, to wherever the user drops them.
+/// The paths of the files need to be absolute. Either `file_count` can be 0 or `text` can be empty, to only drag the other one.
+/// It should be called while a mouse button is held down, the drag ends when the button is released.
+/// Returns whether or not the drag could be started.
+BOOL bw_Window_startDrag( bw_Window* window, const bw_CStrSlice* files, size_t file_count, bw_CStrSlice text );
+
 void bw_Window_triggerClose( bw_Window* window );
 
+/// Should be called by the window implementations when a menu item has been activated.
+void bw_Window_triggerMenuItem( bw_Window* window, uint32_t item_id, BOOL checked );
+
 
 
 void _bw_Window_onResize( const bw_Window* window, unsigned int width, unsigned int height );
@@ -4,7 +4,12 @@
 #include "../common.h"
 
 #include <include/cef_base.h>
+#include <include/cef_image.h>
+#include <include/views/cef_display.h>
 #include <include/views/cef_window.h>
+#ifdef _WIN32
+#include <Windows.h>
+#endif
 
 
 
@@ -39,7 +44,10 @@ public:
 		UNUSED( window );
 		UNUSED( is_menu );
 		UNUSED( can_activate_menu );
-		return nullptr;
+
+		if ( this->options.owner == NULL )
+			return nullptr;
+		return *(CefRefPtr<CefWindow>*)this->options.owner->impl.handle_ptr;
 	}
 
 	bool IsFrameless( CefRefPtr<CefWindow> window ) override {
@@ -58,6 +66,21 @@ public:
 		return false;
 	}
 
+	void OnWindowActivationChanged( CefRefPtr<CefWindow> window, bool active ) override {
+		UNUSED( window );
+
+		if ( this->window->callbacks.on_focus != 0 )
+			this->window->callbacks.on_focus( this->window, active );
+	}
+
+	// Also invoked when only the size has changed.
+	void OnWindowBoundsChanged( CefRefPtr<CefWindow> window, const CefRect& new_bounds ) override {
+		UNUSED( window );
+
+		if ( this->window->callbacks.on_move != 0 )
+			this->window->callbacks.on_move( this->window, new_bounds.x, new_bounds.y );
+	}
+
 	void OnWindowCreated( CefRefPtr<CefWindow> window ) override {
 		UNUSED( window );
 	}
@@ -79,6 +102,22 @@ uint8_t bw_Window_getOpacity( bw_Window* window ) {
 	return 255;
 }
 
+void bw_Window_setIcon( bw_Window* window, const void* data, size_t size, unsigned int width, unsigned int height ) {
+	CefRefPtr<CefImage> image = CefImage::CreateImage();
+
+	bool added;
+	if ( width == 0 || height == 0 )
+		added = image->AddPNG( 1.0f, data, size );
+	else
+		added = image->AddBitmap( 1.0f, (int)width, (int)height, CEF_COLOR_TYPE_RGBA_8888, CEF_ALPHA_TYPE_POSTMULTIPLIED, data, size );
+	if ( !added )
+		return;
+
+	CefRefPtr<CefWindow> cef_window = *(CefRefPtr<CefWindow>*)window->impl.handle_ptr;
+	cef_window->SetWindowIcon( image );
+	cef_window->SetWindowAppIcon( image );
+}
+
 void bw_Window_setOpacity( bw_Window* window, uint8_t opacity ) {
 	UNUSED( window );
 	UNUSED( opacity );
@@ -97,9 +136,38 @@ bw_WindowImpl bw_WindowImpl_new(
 
 	window->SetTitle( bw_cef_copyFromStrSlice( _title ) );
 
+	// CEF's views are sized in logical pixels
+	if ( options->physical_size ) {
+		float scale = CefDisplay::GetPrimaryDisplay()->GetDeviceScaleFactor();
+		width = (int)(width / scale + 0.5f);
+		height = (int)(height / scale + 0.5f);
+	}
+
 	CefSize size( width, height );
 	window->SetSize( size );
 
+	// TODO: Block the owner of modal windows. CEF's views only have modal dialogs in newer versions, so `options->modal` is ignored for now.
+	if ( options->owner != NULL ) {
+		CefRect owner_bounds = (*(CefRefPtr<CefWindow>*)options->owner->impl.handle_ptr)->GetBounds();
+		CefSize window_size = window->GetSize();
+		window->SetPosition( CefPoint(
+			owner_bounds.x + ( owner_bounds.width - window_size.width ) / 2,
+			owner_bounds.y + ( owner_bounds.height - window_size.height ) / 2
+		) );
+	}
+
+	// TODO: Fire `on_scale_factor_changed`. CEF's window delegate doesn't tell when the window moves to another display.
+
+	// TODO: Support menu bars. CEF's views don't have them, so `options->menu_bar` is ignored for now.
+
+	// CEF's views have no notion of tool windows, so it is only supported on Windows, through the native handle.
+#ifdef _WIN32
+	if ( options->tool_window ) {
+		HWND hwnd = window->GetWindowHandle();
+		SetWindowLongPtrW( hwnd, GWL_EXSTYLE, GetWindowLongPtrW( hwnd, GWL_EXSTYLE ) | WS_EX_TOOLWINDOW );
+	}
+#endif
+
 	bw_WindowImpl impl;
 	impl.handle_ptr = new CefRefPtr<CefWindow>( window );
 	return impl;
@@ -119,6 +187,28 @@ void bw_WindowImpl_show( bw_WindowImpl* window ) {
 
 
 
+BOOL bw_Window_getNativeHandle( const bw_Window* window, bw_WindowNativeHandle* handle ) {
+	memset( handle, 0, sizeof( bw_WindowNativeHandle ) );
+
+	CefWindowHandle native = (*(CefRefPtr<CefWindow>*)window->impl.handle_ptr)->GetWindowHandle();
+	if ( !native )
+		return FALSE;
+
+#if defined(_WIN32)
+	handle->kind = 1;
+	handle->window = native;
+	handle->display = (void*)GetWindowLongPtrW( native, GWLP_HINSTANCE );
+	return TRUE;
+#elif defined(__linux__)
+	// CEF doesn't expose its X11 display anymore
+	handle->kind = 2;
+	handle->xid = native;
+	return TRUE;
+#else
+	return FALSE;
+#endif
+}
+
 bw_Dims2D bw_Window_getContentDimensions( bw_Window* window ) {
 	CefRect rect = (*(CefRefPtr<CefWindow>*)window->impl.handle_ptr)->GetClientAreaBoundsInScreen();
 
@@ -139,6 +229,12 @@ bw_Pos2D bw_Window_getPosition( bw_Window* window ) {
 	return pos;
 }
 
+float bw_Window_getScaleFactor( const bw_Window* window ) {
+	CefRect bounds = (*(CefRefPtr<CefWindow>*)window->impl.handle_ptr)->GetBounds();
+
+	return CefDisplay::GetDisplayMatchingBounds( bounds, false )->GetDeviceScaleFactor();
+}
+
 size_t bw_Window_getTitle( bw_Window* window, char** title ) {
 	CefString cef_title = (*(CefRefPtr<CefWindow>*)window->impl.handle_ptr)->GetTitle();
 
@@ -181,4 +277,10 @@ void bw_Window_setWindowDimensions( bw_Window* window, bw_Dims2D dims ) {
 	CefRect bounds( 0, 0, dims.width, dims.height );
 
 	(*(CefRefPtr<CefWindow>*)window->impl.handle_ptr)->SetBounds( bounds );
-}
\ No newline at end of file
+}
+
+void bw_Window_setMaximizeButtonArea( bw_Window* window, bw_Pos2D position, bw_Dims2D size ) {
+	UNUSED( window );
+	UNUSED( position );
+	UNUSED( size );
+}
@@ -73,6 +73,8 @@ bw_Window* bw_Window_new(
 	window->closed = true;  // Windows start out hidden to the user
 	window->dropped = false;
 	window->user_data = user_data;
+	window->menu_handler = NULL;
+	window->menu_handler_data = NULL;
 	memset( &window->callbacks, 0, sizeof( window->callbacks ) );
 
 	window->impl = bw_WindowImpl_new( window, title, width, height, options );
@@ -85,7 +87,9 @@ bw_Window* bw_Window_new(
 void bw_Window_show( bw_Window* window ) {
 	window->closed = false;
 
-	bw_WindowImpl_show( &window->impl );
+	// In headless mode, the window is considered to be visible, but it is never actually shown to the user.
+	if ( !window->app->headless )
+		bw_WindowImpl_show( &window->impl );
 }
 
 // Closing a window hides the window,
@@ -102,4 +106,13 @@ void bw_Window_triggerClose( bw_Window* window ) {
 	}
 
 	// TODO: Fire on_closed event
-}
\ No newline at end of file
+}
+void bw_Window_setMenuHandler( bw_Window* window, bw_WindowMenuHandlerFn handler, void* data ) {
+	window->menu_handler = handler;
+	window->menu_handler_data = data;
+}
+
+void bw_Window_triggerMenuItem( bw_Window* window, uint32_t item_id, BOOL checked ) {
+	if ( window->menu_handler != NULL )
+		window->menu_handler( window, item_id, checked, window->menu_handler_data );
+}
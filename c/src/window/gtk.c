@@ -1,12 +1,36 @@
 #include "../window.h"
 
 #include "../common.h"
+#include "../menu/gtk.h"
 
+#include <math.h>
+#include <string.h>
+#ifdef GDK_WINDOWING_X11
+#include <gdk/gdkx.h>
+#endif
+#ifdef GDK_WINDOWING_WAYLAND
+#include <gdk/gdkwayland.h>
+#endif
 
 
+
+void bw_WindowGtk_clipCorners( GtkWidget* handle, int width, int height, int radius );
 gboolean _bw_WindowGtk_closeHandler( GtkWidget* handle, gpointer data );
 gboolean _bw_WindowGtk_stateHandler( GtkWidget *widget, GdkEventWindowState *event, gpointer user_data );
+void _bw_WindowGtk_onMenuItem( void* window, uint32_t item_id, BOOL checked );
+void _bw_WindowGtk_onScaleFactorChanged( GObject* handle, GParamSpec* spec, gpointer data );
+void _bw_WindowGtk_onScaleFactorChanged( GObject* handle, GParamSpec* spec, gpointer user_data ) {
+	UNUSED( spec );
+	bw_Window* window = (bw_Window*)user_data;
+
+	if ( window->callbacks.on_scale_factor_changed != 0 )
+		window->callbacks.on_scale_factor_changed( window, (float)gtk_widget_get_scale_factor( GTK_WIDGET(handle) ) );
+}
+
 void _bw_WindowGtk_onSizeAllocate( GtkWidget* handle, GdkRectangle* alloc, gpointer data );
+gboolean _bw_WindowGtk_onConfigure( GtkWidget* handle, GdkEventConfigure* event, gpointer data );
+gboolean _bw_WindowGtk_onFocusIn( GtkWidget* handle, GdkEventFocus* event, gpointer data );
+gboolean _bw_WindowGtk_onFocusOut( GtkWidget* handle, GdkEventFocus* event, gpointer data );
 
 
 
@@ -16,6 +40,68 @@ uint8_t bw_Window_getOpacity( bw_Window* window ) {
 	return (uint8_t)(fraction * 255);
 }
 
+BOOL bw_Window_getNativeHandle( const bw_Window* window, bw_WindowNativeHandle* handle ) {
+	memset( handle, 0, sizeof( bw_WindowNativeHandle ) );
+
+	// Only windows that have been realized have a window of the platform
+	GdkWindow* gdk_window = gtk_widget_get_window( window->impl.handle );
+	if ( gdk_window == NULL )
+		return FALSE;
+
+#ifdef GDK_WINDOWING_X11
+	if ( GDK_IS_X11_WINDOW( gdk_window ) ) {
+		handle->kind = 2;
+		handle->xid = GDK_WINDOW_XID( gdk_window );
+		handle->display = GDK_DISPLAY_XDISPLAY( gdk_window_get_display( gdk_window ) );
+		return TRUE;
+	}
+#endif
+#ifdef GDK_WINDOWING_WAYLAND
+	if ( GDK_IS_WAYLAND_WINDOW( gdk_window ) ) {
+		handle->kind = 3;
+		handle->window = gdk_wayland_window_get_wl_surface( gdk_window );
+		handle->display = gdk_wayland_display_get_wl_display( gdk_window_get_display( gdk_window ) );
+		return TRUE;
+	}
+#endif
+	return FALSE;
+}
+
+// GDK only scales by whole numbers, fractional scaling is left to the desktop.
+float bw_Window_getScaleFactor( const bw_Window* window ) {
+	return (float)gtk_widget_get_scale_factor( window->impl.handle );
+}
+
+void bw_Window_setIcon( bw_Window* window, const void* data, size_t size, unsigned int width, unsigned int height ) {
+	GdkPixbuf* pixbuf = NULL;
+
+	if ( width == 0 || height == 0 ) {
+		GdkPixbufLoader* loader = gdk_pixbuf_loader_new_with_type( "png", NULL );
+		if ( loader == NULL )
+			return;
+
+		// The loader needs to be closed, even if writing to it has failed
+		gboolean decoded = gdk_pixbuf_loader_write( loader, (const guchar*)data, size, NULL );
+		decoded = gdk_pixbuf_loader_close( loader, NULL ) && decoded;
+		if ( decoded ) {
+			pixbuf = gdk_pixbuf_loader_get_pixbuf( loader );
+			if ( pixbuf != NULL )
+				g_object_ref( pixbuf );
+		}
+		g_object_unref( loader );
+	}
+	else {
+		GBytes* bytes = g_bytes_new( data, size );
+		pixbuf = gdk_pixbuf_new_from_bytes( bytes, GDK_COLORSPACE_RGB, TRUE, 8, (int)width, (int)height, (int)width * 4 );
+		g_bytes_unref( bytes );
+	}
+	if ( pixbuf == NULL )
+		return;
+
+	gtk_window_set_icon( GTK_WINDOW(window->impl.handle), pixbuf );
+	g_object_unref( pixbuf );
+}
+
 void bw_Window_setOpacity( bw_Window* window, uint8_t opacity ) {
 	gtk_widget_set_opacity( window->impl.handle, (double)opacity / 255.0 );
 }
@@ -36,6 +122,14 @@ bw_WindowImpl bw_WindowImpl_new(
 		gtk_window_set_destroy_with_parent( GTK_WINDOW(gtk_handle), FALSE );
 	}
 
+	// GTK leaves it to the window manager to keep transient windows above their owner, and to center them on it.
+	// Modal windows block all other windows of the application, not just their owner.
+	if ( options->owner != NULL ) {
+		gtk_window_set_transient_for( GTK_WINDOW(gtk_handle), GTK_WINDOW(options->owner->impl.handle) );
+		gtk_window_set_position( GTK_WINDOW(gtk_handle), GTK_WIN_POS_CENTER_ON_PARENT );
+		gtk_window_set_modal( GTK_WINDOW(gtk_handle), options->modal );
+	}
+
 	// Title
 	// TODO: Convert c string to utf8 string
 	gchar* title = bw_string_copyAsNewCstr( _title );
@@ -48,6 +142,13 @@ bw_WindowImpl bw_WindowImpl_new(
 		if ( height < 1 )
 			height = 600;
 
+		// GTK sizes windows in logical pixels
+		if ( options->physical_size ) {
+			int scale = gtk_widget_get_scale_factor( gtk_handle );
+			width = (width + scale - 1) / scale;
+			height = (height + scale - 1) / scale;
+		}
+
 		// Width and height
 		gtk_window_resize( GTK_WINDOW(gtk_handle), width, height );
 	}
@@ -57,9 +158,33 @@ bw_WindowImpl bw_WindowImpl_new(
 	// If both not minimizable and not resizable, make it a dialog
 	//gtk_window_set_type_hint( GTK_WINDOW(gtk_handle), GDK_WINDOW_TYPE_HINT_DIALOG );
 
+	// Let the window manager decorate it as a utility window, and keep it out of the taskbar and pager.
+	if ( options->tool_window ) {
+		gtk_window_set_type_hint( GTK_WINDOW(gtk_handle), GDK_WINDOW_TYPE_HINT_UTILITY );
+		gtk_window_set_skip_taskbar_hint( GTK_WINDOW(gtk_handle), TRUE );
+		gtk_window_set_skip_pager_hint( GTK_WINDOW(gtk_handle), TRUE );
+	}
+
+	// The menu bar sits at the top of the window, above the browser.
+	GtkWidget* menu_bar = NULL;
+	if ( options->menu_bar != NULL ) {
+		GtkAccelGroup* accel_group = gtk_accel_group_new();
+		gtk_window_add_accel_group( GTK_WINDOW(gtk_handle), accel_group );
+
+		menu_bar = bw_MenuGtk_create( options->menu_bar, TRUE, accel_group, _bw_WindowGtk_onMenuItem, (void*)window );
+
+		GtkWidget* box = gtk_box_new( GTK_ORIENTATION_VERTICAL, 0 );
+		gtk_box_pack_start( GTK_BOX(box), menu_bar, FALSE, FALSE, 0 );
+		gtk_container_add( GTK_CONTAINER(gtk_handle), box );
+	}
+
 	g_signal_connect( gtk_handle, "window-state-event", G_CALLBACK( _bw_WindowGtk_stateHandler ), (gpointer)window );
 	//g_signal_connect( gtk_handle, "destroy-event", G_CALLBACK( _bw_WindowGtk_closeHandler ), (gpointer)window );
 	g_signal_connect( gtk_handle, "size-allocate", G_CALLBACK( _bw_WindowGtk_onSizeAllocate ), (gpointer)window );
+	g_signal_connect( gtk_handle, "notify::scale-factor", G_CALLBACK( _bw_WindowGtk_onScaleFactorChanged ), (gpointer)window );
+	g_signal_connect( gtk_handle, "configure-event", G_CALLBACK( _bw_WindowGtk_onConfigure ), (gpointer)window );
+	g_signal_connect( gtk_handle, "focus-in-event", G_CALLBACK( _bw_WindowGtk_onFocusIn ), (gpointer)window );
+	g_signal_connect( gtk_handle, "focus-out-event", G_CALLBACK( _bw_WindowGtk_onFocusOut ), (gpointer)window );
 
 	// FIXME: Is this really necessary or not?:
 	gtk_application_add_window( window->app->impl.handle, GTK_WINDOW(gtk_handle) );
@@ -68,6 +193,9 @@ bw_WindowImpl bw_WindowImpl_new(
 
 	bw_WindowImpl impl;
 	impl.handle = gtk_handle;
+	impl.menu_bar = menu_bar;
+	// The window manager decides whether windows get a drop shadow, so `options->shadow` can't be honored.
+	impl.corner_radius = options->corner_radius > 0 ? options->corner_radius : 0;
 	return impl;
 }
 
@@ -85,11 +213,73 @@ void bw_WindowImpl_show( bw_WindowImpl* window ) {
 
 
 
+// Rounds the corners by giving the window a shape, which also applies to the browser within the window.
+void bw_WindowGtk_clipCorners( GtkWidget* handle, int width, int height, int radius ) {
+	if ( radius * 2 > width )
+		radius = width / 2;
+	if ( radius * 2 > height )
+		radius = height / 2;
+
+	cairo_rectangle_int_t middle = { 0, radius, width, height - radius * 2 };
+	cairo_region_t* region = cairo_region_create_rectangle( &middle );
+
+	// The rows of the top and bottom corners get narrower the closer they are to the edge
+	for ( int y = 0; y < radius; y++ ) {
+		double dy = radius - y - 0.5;
+		int inset = (int)(radius - sqrt( (double)radius * radius - dy * dy ) + 0.5);
+
+		cairo_rectangle_int_t top = { inset, y, width - inset * 2, 1 };
+		cairo_rectangle_int_t bottom = { inset, height - y - 1, width - inset * 2, 1 };
+		cairo_region_union_rectangle( region, &top );
+		cairo_region_union_rectangle( region, &bottom );
+	}
+
+	gtk_widget_shape_combine_region( handle, region );
+	cairo_region_destroy( region );
+}
+
+void _bw_WindowGtk_onMenuItem( void* window, uint32_t item_id, BOOL checked ) {
+	bw_Window_triggerMenuItem( (bw_Window*)window, item_id, checked );
+}
+
 void _bw_WindowGtk_onSizeAllocate( GtkWidget* handle, GdkRectangle* alloc, gpointer user_data ) {
 	bw_Window* window = (bw_Window*)user_data;
 
 	if ( window->callbacks.on_resize != 0 )
 		window->callbacks.on_resize( window, alloc->width, alloc->height );
+
+	if ( window->impl.corner_radius > 0 )
+		bw_WindowGtk_clipCorners( handle, alloc->width, alloc->height, window->impl.corner_radius );
+}
+
+// GTK emits this for resizes as well, so the position may not actually have changed.
+gboolean _bw_WindowGtk_onConfigure( GtkWidget* handle, GdkEventConfigure* event, gpointer user_data ) {
+	UNUSED( handle );
+	bw_Window* window = (bw_Window*)user_data;
+
+	if ( window->callbacks.on_move != 0 )
+		window->callbacks.on_move( window, event->x, event->y );
+	return FALSE;
+}
+
+gboolean _bw_WindowGtk_onFocusIn( GtkWidget* handle, GdkEventFocus* event, gpointer user_data ) {
+	UNUSED( handle );
+	UNUSED( event );
+	bw_Window* window = (bw_Window*)user_data;
+
+	if ( window->callbacks.on_focus != 0 )
+		window->callbacks.on_focus( window, TRUE );
+	return FALSE;
+}
+
+gboolean _bw_WindowGtk_onFocusOut( GtkWidget* handle, GdkEventFocus* event, gpointer user_data ) {
+	UNUSED( handle );
+	UNUSED( event );
+	bw_Window* window = (bw_Window*)user_data;
+
+	if ( window->callbacks.on_focus != 0 )
+		window->callbacks.on_focus( window, FALSE );
+	return FALSE;
 }
 
 gboolean _bw_WindowGtk_stateHandler( GtkWidget *widget, GdkEventWindowState *event, gpointer user_data ) {
@@ -116,3 +306,9 @@ gboolean _bw_WindowGtk_closeHandler( GtkWidget* handle, gpointer data ) {
 	bw_WindowImpl_hide( &window->impl );
 	return FALSE;
 }
+
+void bw_Window_setMaximizeButtonArea( bw_Window* window, bw_Pos2D position, bw_Dims2D size ) {
+	UNUSED( window );
+	UNUSED( position );
+	UNUSED( size );
+}
@@ -8,6 +8,8 @@
 typedef struct {
 	GtkWidget* handle;
 	gboolean minimizable;
+	GtkWidget* menu_bar;	// NULL if the window has no menu bar
+	int corner_radius;	// 0 if the corners of the window are not cut off
 } bw_WindowImpl;
 
 
@@ -2,11 +2,25 @@
 #include "../application/win32.h"
 #include "../assert.h"
 #include "../debug.h"
+#include "../menu/win32.h"
 #include "../win32.h"
 #include "../window.h"
 
 #include <stdio.h>
 #include <stdlib.h>
+#include <string.h>
+#include <wchar.h>
+#include <commctrl.h>
+#include <dwmapi.h>
+#include <windowsx.h>
+
+// These are not defined by older SDKs
+#ifndef WM_DPICHANGED
+#define WM_DPICHANGED 0x02E0
+#endif
+#ifndef USER_DEFAULT_SCREEN_DPI
+#define USER_DEFAULT_SCREEN_DPI 96
+#endif
 
 
 
@@ -21,7 +35,19 @@ typedef struct {
 bool _bw_Window_hasUndroppedChildren( const bw_Window* window );
 BOOL CALLBACK _bw_Window_isDroppedCheck( HWND handle, LPARAM lparam );*/
 LRESULT CALLBACK bw_Window_proc(HWND hwnd, UINT msg, WPARAM wp, LPARAM lp);
+BOOL bw_WindowWin32_applyBackdrop( HWND hwnd, uint8_t backdrop );
+int bw_WindowWin32_applyCorners( HWND hwnd, int16_t radius );
+void bw_WindowWin32_applyShadow( HWND hwnd, uint8_t shadow, BOOL has_backdrop );
+void bw_WindowWin32_centerOnOwner( HWND handle, HWND owner );
+void bw_WindowWin32_clipCorners( HWND hwnd, int radius );
+// Creates an icon from pixels in the RGBA format.
+HICON bw_WindowWin32_createIcon( const void* pixels, unsigned int width, unsigned int height );
+UINT bw_WindowWin32_getDpi( HWND hwnd );
+/// Keeps an embedded window as large as the client area of the foreign window that it is embedded in.
+LRESULT CALLBACK bw_WindowWin32_nativeParentProc( HWND hwnd, UINT msg, WPARAM wp, LPARAM lp, UINT_PTR id, DWORD_PTR data );
+void bw_WindowWin32_onMenuCommand( bw_Window* window, WORD item_id );
 void bw_WindowWin32_calculatePositionCentered( int width, int height, int* x, int* y );
+void bw_WindowWin32_releaseOwner( bw_WindowImpl* window );
 
 
 
@@ -37,6 +63,15 @@ bw_Dims2D bw_Window_getContentDimensions( bw_Window* window ) {
 	return dims;
 }
 
+BOOL bw_Window_getNativeHandle( const bw_Window* window, bw_WindowNativeHandle* handle ) {
+	memset( handle, 0, sizeof( bw_WindowNativeHandle ) );
+
+	handle->kind = 1;
+	handle->window = window->impl.handle;
+	handle->display = (void*)GetWindowLongPtrW( window->impl.handle, GWLP_HINSTANCE );
+	return TRUE;
+}
+
 uint8_t bw_Window_getOpacity( bw_Window* window ) {
 	// For some reason the GetLayeredWindowAttributes function gives an "Invalid access to memory loaction [998]" error when trying to access the LWA_ALPHA property.
 	// This is a workaround.
@@ -55,6 +90,10 @@ bw_Pos2D bw_Window_getPosition( bw_Window* window ) {
 	return pos;
 }
 
+float bw_Window_getScaleFactor( const bw_Window* window ) {
+	return (float)bw_WindowWin32_getDpi( window->impl.handle ) / USER_DEFAULT_SCREEN_DPI;
+}
+
 size_t bw_Window_getTitle( bw_Window* window, char** title ) {
 
 	// First get the length of the window title
@@ -107,8 +146,74 @@ void bw_Window_setContentDimensions( bw_Window* window, bw_Dims2D dimensions ) {
 		BW_WIN32_PANIC_LAST_ERROR
 }
 
+void bw_Window_setMaximizeButtonArea( bw_Window* window, bw_Pos2D position, bw_Dims2D size ) {
+	bw_WindowImpl* impl = &window->impl;
+
+	if ( size.width == 0 || size.height == 0 ) {
+		memset( &impl->maximize_button_rect, 0, sizeof( RECT ) );
+		if ( impl->maximize_button != NULL ) {
+			DestroyWindow( impl->maximize_button );
+			impl->maximize_button = NULL;
+		}
+		return;
+	}
+
+	impl->maximize_button_rect.left = position.x;
+	impl->maximize_button_rect.top = position.y;
+	impl->maximize_button_rect.right = position.x + size.width;
+	impl->maximize_button_rect.bottom = position.y + size.height;
+
+	// The browser covers the whole content area, so hit tests never reach the window itself.
+	// Therefore, a nearly invisible window is put on top of the button to catch them.
+	if ( impl->maximize_button == NULL ) {
+		impl->maximize_button = CreateWindowExW(
+			WS_EX_LAYERED | WS_EX_NOACTIVATE,
+			L"bw-maximize-button",
+			L"",
+			WS_CHILD,
+			0, 0, 0, 0,
+			impl->handle,
+			NULL,
+			window->app->impl.handle,
+			NULL
+		);
+		if ( impl->maximize_button == NULL )
+			BW_WIN32_PANIC_LAST_ERROR;
+
+		// A completely transparent window would let the mouse through
+		SetLayeredWindowAttributes( impl->maximize_button, 0, 1, LWA_ALPHA );
+	}
+
+	SetWindowPos( impl->maximize_button, HWND_TOP, position.x, position.y, size.width, size.height, SWP_SHOWWINDOW | SWP_NOACTIVATE );
+}
+
+void bw_Window_setIcon( bw_Window* window, const void* data, size_t size, unsigned int width, unsigned int height ) {
+	HICON icon;
+
+	// Icons can be created from PNG images directly, as they can be stored in .ico files like that
+	if ( width == 0 || height == 0 )
+		icon = CreateIconFromResourceEx( (PBYTE)data, (DWORD)size, TRUE, 0x00030000, 0, 0, LR_DEFAULTCOLOR );
+	else
+		icon = bw_WindowWin32_createIcon( data, width, height );
+	if ( icon == NULL )
+		return;
+
+	SendMessageW( window->impl.handle, WM_SETICON, ICON_SMALL, (LPARAM)icon );
+	SendMessageW( window->impl.handle, WM_SETICON, ICON_BIG, (LPARAM)icon );
+
+	// The window doesn't take ownership of its icon, so the previous one can be destroyed once it has been replaced
+	if ( window->impl.icon != NULL )
+		DestroyIcon( window->impl.icon );
+	window->impl.icon = icon;
+}
+
 void bw_Window_setOpacity( bw_Window* window, uint8_t opacity ) {
-	
+
+	// Windows with a backdrop are not layered, but they can still be made transparent at the cost of their backdrop.
+	LONG_PTR ex_style = GetWindowLongPtrW( window->impl.handle, GWL_EXSTYLE );
+	if ( !(ex_style & WS_EX_LAYERED) )
+		SetWindowLongPtrW( window->impl.handle, GWL_EXSTYLE, ex_style | WS_EX_LAYERED );
+
 	if ( !SetLayeredWindowAttributes( window->impl.handle, 0, opacity, LWA_ALPHA ) )
 		BW_WIN32_PANIC_LAST_ERROR
 	window->impl.opacity = opacity;
@@ -137,11 +242,27 @@ void bw_Window_setWindowDimensions( bw_Window* window, bw_Dims2D dimensions ) {
 
 
 void bw_WindowImpl_destroy( bw_WindowImpl* window ) {
+	// A window may be destroyed while it is still visible
+	bw_WindowWin32_releaseOwner( window );
+
+	if ( window->native_parent != NULL )
+		RemoveWindowSubclass( window->native_parent, bw_WindowWin32_nativeParentProc, (UINT_PTR)window->handle );
+
+	// The menu itself is destroyed together with the window
+	if ( window->accelerators != NULL )
+		DestroyAcceleratorTable( window->accelerators );
+
 	DestroyWindow( window->handle );
+
+	if ( window->icon != NULL )
+		DestroyIcon( window->icon );
 }
 
 void bw_WindowImpl_hide( bw_WindowImpl* window ) {
 
+	// The owner is enabled before the window is hidden, so that the owner gets activated instead of another application
+	bw_WindowWin32_releaseOwner( window );
+
 	// // Hide window and hide all its children, to emulate DestroyWindow without actually destroying it:
 	ShowWindow( window->handle, SW_HIDE );
 }
@@ -162,24 +283,60 @@ bw_WindowImpl bw_WindowImpl_new(
 		impl.style ^= WS_SIZEBOX & WS_MAXIMIZEBOX;
 	if ( !options->minimizable )
 		impl.style ^= WS_MINIMIZEBOX;
+	// The background is only painted around the content, so that it doesn't flicker over it
+	if ( options->resize_strategy == 2 )
+		impl.style |= WS_CLIPCHILDREN;
+
+	// The system backdrops are not drawn behind layered windows
+	DWORD ex_style = options->backdrop == 0 ? WS_EX_LAYERED : 0;
+	// Tool windows have a thin titlebar, and are left out of the taskbar and the alt-tab list.
+	if ( options->tool_window )
+		ex_style |= WS_EX_TOOLWINDOW;
 
 	if ( width == -1 && height == -1 )
 		width = CW_USEDEFAULT;
 
+	// An embedded window is just a part of the window of the other toolkit, so it covers its client area without any decorations of its own
+	impl.native_parent = (HWND)options->native_parent;
+	HWND parent = HWND_DESKTOP;
+	int x = CW_USEDEFAULT, y = 0;
+	if ( impl.native_parent != NULL ) {
+		RECT client;
+		GetClientRect( impl.native_parent, &client );
+
+		impl.style = WS_CHILD | WS_CLIPCHILDREN | WS_CLIPSIBLINGS;
+		ex_style = 0;
+		parent = impl.native_parent;
+		x = 0;
+		y = min( options->native_parent_top, client.bottom );
+		width = client.right;
+		height = client.bottom - y;
+	}
+
+	HMENU menu = NULL;
+	impl.accelerators = NULL;
+	impl.icon = NULL;
+	impl.maximize_button = NULL;
+	impl.owner = options->owner != NULL && impl.native_parent == NULL ? options->owner->impl.handle : NULL;
+	impl.modal = impl.owner != NULL && options->modal;
+	memset( &impl.maximize_button_rect, 0, sizeof( RECT ) );
+	if ( options->menu_bar != NULL && impl.native_parent == NULL )
+		menu = bw_MenuWin32_create( options->menu_bar, TRUE, &impl.accelerators );
+
 	wchar_t* title = bw_win32_copyAsNewWstr( _title );
 
 	// Create the window
 	impl.handle = CreateWindowExW(
-		WS_EX_LAYERED,
+		ex_style,
 		L"bw-window",
 		title,
 		impl.style,
-		CW_USEDEFAULT,  // Let Windows decide where to place our window
-		0,
+		x,  // Let Windows decide where to place our window, unless it is embedded
+		y,
 		width,
 		height,
-		HWND_DESKTOP,	// Always set the window to be top level. Parent relationships are dealt with ourself
-		NULL,
+		impl.owner != NULL ? impl.owner : parent,	// Owned windows stay above their owner. Parent relationships are dealt with ourself
+		menu,
 		window->app->impl.handle,
 		(void*)window
 	);
@@ -192,16 +349,193 @@ bw_WindowImpl bw_WindowImpl_new(
 	SetWindowLongPtrW( impl.handle, GWLP_USERDATA, (LONG_PTR)window );
 	BW_WIN32_ASSERT_SUCCESS;
 
+	if ( impl.native_parent != NULL )
+		SetWindowSubclass( impl.native_parent, bw_WindowWin32_nativeParentProc, (UINT_PTR)impl.handle, (DWORD_PTR)options->native_parent_top );
+
+	// Logical sizes can only be scaled once it is known on which display the window has been placed
+	if ( !options->physical_size && width != CW_USEDEFAULT && impl.native_parent == NULL ) {
+		UINT dpi = bw_WindowWin32_getDpi( impl.handle );
+
+		if ( dpi != USER_DEFAULT_SCREEN_DPI )
+			SetWindowPos( impl.handle, NULL, 0, 0, MulDiv( width, dpi, USER_DEFAULT_SCREEN_DPI ), MulDiv( height, dpi, USER_DEFAULT_SCREEN_DPI ), SWP_NOMOVE | SWP_NOZORDER | SWP_NOACTIVATE );
+	}
+
+	if ( impl.owner != NULL )
+		bw_WindowWin32_centerOnOwner( impl.handle, impl.owner );
+
 	// We give the window an ex-style of WS_EX_LAYERED.
 	// This means however that we need to explicitly set the opacity to a value.
 	// We default to 255 for no transparency.
 	impl.opacity = 255;
-	if ( !SetLayeredWindowAttributes( impl.handle, 0, impl.opacity, LWA_ALPHA ) )
-		BW_WIN32_PANIC_LAST_ERROR
+	if ( ex_style & WS_EX_LAYERED ) {
+		if ( !SetLayeredWindowAttributes( impl.handle, 0, impl.opacity, LWA_ALPHA ) )
+			BW_WIN32_PANIC_LAST_ERROR
+	}
+
+	impl.has_backdrop = options->backdrop != 0 && impl.native_parent == NULL && bw_WindowWin32_applyBackdrop( impl.handle, options->backdrop );
+	impl.corner_radius = bw_WindowWin32_applyCorners( impl.handle, options->corner_radius );
+	impl.resize_strategy = options->resize_strategy;
+	bw_WindowWin32_applyShadow( impl.handle, options->shadow, impl.has_backdrop );
 
 	return impl;
 }
 
+BOOL bw_WindowWin32_applyBackdrop( HWND hwnd, uint8_t backdrop ) {
+	// These attributes are not defined by older SDKs
+	const DWORD DWMWA_MICA_EFFECT = 1029;
+	const DWORD DWMWA_SYSTEMBACKDROP_TYPE_ = 38;
+	const DWORD DWMSBT_MAINWINDOW_ = 2;
+	const DWORD DWMSBT_TRANSIENTWINDOW_ = 3;
+
+	// Let the backdrop show through the whole window, instead of only through the titlebar
+	MARGINS margins = { -1, -1, -1, -1 };
+	DwmExtendFrameIntoClientArea( hwnd, &margins );
+
+	// Vibrancy only exists on macOS, but acrylic looks a lot like it.
+	DWORD type = backdrop == 1 ? DWMSBT_MAINWINDOW_ : DWMSBT_TRANSIENTWINDOW_;
+	if ( SUCCEEDED( DwmSetWindowAttribute( hwnd, DWMWA_SYSTEMBACKDROP_TYPE_, &type, sizeof( type ) ) ) )
+		return TRUE;
+
+	// The first release of Windows 11 only had an undocumented attribute for mica
+	if ( backdrop == 1 ) {
+		BOOL enabled = TRUE;
+		if ( SUCCEEDED( DwmSetWindowAttribute( hwnd, DWMWA_MICA_EFFECT, &enabled, sizeof( enabled ) ) ) )
+			return TRUE;
+	}
+
+	// Older versions of Windows have no backdrops, so the window stays as it is
+	MARGINS no_margins = { 0, 0, 0, 0 };
+	DwmExtendFrameIntoClientArea( hwnd, &no_margins );
+	return FALSE;
+}
+
+// Returns the radius with which the corners need to be cut off whenever the window resizes, or 0 if that isn't needed.
+int bw_WindowWin32_applyCorners( HWND hwnd, int16_t radius ) {
+	// This attribute is not defined by older SDKs
+	const DWORD DWMWA_WINDOW_CORNER_PREFERENCE_ = 33;
+
+	if ( radius < 0 )
+		return 0;
+
+	// Windows 11 only rounds the corners with the radiuses of its own, so take the one that comes closest.
+	// 1 means not to round the corners, 2 to round them, and 3 to round them with a smaller radius.
+	DWORD preference = radius == 0 ? 1 : (radius <= 4 ? 3 : 2);
+	if ( SUCCEEDED( DwmSetWindowAttribute( hwnd, DWMWA_WINDOW_CORNER_PREFERENCE_, &preference, sizeof( preference ) ) ) )
+		return 0;
+
+	// Older versions of Windows don't round the corners at all, so the window will be cut into shape instead.
+	if ( radius > 0 )
+		bw_WindowWin32_clipCorners( hwnd, radius );
+	return radius;
+}
+
+void bw_WindowWin32_applyShadow( HWND hwnd, uint8_t shadow, BOOL has_backdrop ) {
+	if ( shadow == 0 )
+		return;
+
+	// The drop shadow is part of the non-client area that DWM renders
+	DWMNCRENDERINGPOLICY policy = shadow == 1 ? DWMNCRP_ENABLED : DWMNCRP_DISABLED;
+	DwmSetWindowAttribute( hwnd, DWMWA_NCRENDERING_POLICY, &policy, sizeof( policy ) );
+
+	// DWM only draws shadows around the frame of a window, which frameless windows get back by extending it into the client area a little.
+	// Windows with a backdrop already have their frame extended over the whole window.
+	if ( shadow == 1 && !has_backdrop ) {
+		MARGINS margins = { 0, 0, 1, 0 };
+		DwmExtendFrameIntoClientArea( hwnd, &margins );
+	}
+}
+
+// Cutting off the corners also cuts off the drop shadow.
+void bw_WindowWin32_clipCorners( HWND hwnd, int radius ) {
+	RECT rect;
+	GetWindowRect( hwnd, &rect );
+
+	HRGN region = CreateRoundRectRgn( 0, 0, rect.right - rect.left + 1, rect.bottom - rect.top + 1, radius * 2, radius * 2 );
+	// The system owns the region afterwards
+	SetWindowRgn( hwnd, region, TRUE );
+}
+
+// GetDpiForWindow only exists since Windows 10, so older versions fall back on the DPI of the whole screen.
+// Creates an icon from pixels in the RGBA format, which are copied into a bitmap in the BGRA format.
+HICON bw_WindowWin32_createIcon( const void* pixels, unsigned int width, unsigned int height ) {
+	BITMAPV5HEADER header;
+	memset( &header, 0, sizeof( header ) );
+	header.bV5Size = sizeof( header );
+	header.bV5Width = (LONG)width;
+	header.bV5Height = -(LONG)height;	// The rows go from top to bottom
+	header.bV5Planes = 1;
+	header.bV5BitCount = 32;
+	header.bV5Compression = BI_BITFIELDS;
+	header.bV5RedMask = 0x00FF0000;
+	header.bV5GreenMask = 0x0000FF00;
+	header.bV5BlueMask = 0x000000FF;
+	header.bV5AlphaMask = 0xFF000000;
+
+	void* bits;
+	HDC dc = GetDC( NULL );
+	HBITMAP color = CreateDIBSection( dc, (BITMAPINFO*)&header, DIB_RGB_COLORS, &bits, NULL, 0 );
+	ReleaseDC( NULL, dc );
+	if ( color == NULL )
+		return NULL;
+
+	const BYTE* src = (const BYTE*)pixels;
+	BYTE* dst = (BYTE*)bits;
+	for ( size_t i = 0; i < (size_t)width * height * 4; i += 4 ) {
+		dst[i] = src[i + 2];
+		dst[i + 1] = src[i + 1];
+		dst[i + 2] = src[i];
+		dst[i + 3] = src[i + 3];
+	}
+
+	// The mask is not used for icons with an alpha channel, but it has to be there
+	HBITMAP mask = CreateBitmap( (int)width, (int)height, 1, 1, NULL );
+
+	ICONINFO info;
+	info.fIcon = TRUE;
+	info.xHotspot = 0;
+	info.yHotspot = 0;
+	info.hbmMask = mask;
+	info.hbmColor = color;
+	HICON icon = CreateIconIndirect( &info );
+
+	DeleteObject( color );
+	DeleteObject( mask );
+	return icon;
+}
+
+UINT bw_WindowWin32_getDpi( HWND hwnd ) {
+	typedef UINT (WINAPI *GetDpiForWindowFn)( HWND );
+	static GetDpiForWindowFn get_dpi_for_window = NULL;
+	static BOOL looked_up = FALSE;
+
+	if ( !looked_up ) {
+		get_dpi_for_window = (GetDpiForWindowFn)GetProcAddress( GetModuleHandleW( L"user32.dll" ), "GetDpiForWindow" );
+		looked_up = TRUE;
+	}
+
+	if ( get_dpi_for_window != NULL ) {
+		UINT dpi = get_dpi_for_window( hwnd );
+		if ( dpi != 0 )
+			return dpi;
+	}
+
+	HDC hdc = GetDC( NULL );
+	UINT dpi = (UINT)GetDeviceCaps( hdc, LOGPIXELSX );
+	ReleaseDC( NULL, hdc );
+	return dpi;
+}
+
+LRESULT CALLBACK bw_WindowWin32_nativeParentProc( HWND hwnd, UINT msg, WPARAM wp, LPARAM lp, UINT_PTR id, DWORD_PTR data ) {
+
+	// `data` is the height of the area at the top that is left uncovered
+	if ( msg == WM_SIZE ) {
+		int top = min( (int)data, (int)HIWORD( lp ) );
+		MoveWindow( (HWND)id, 0, top, LOWORD( lp ), HIWORD( lp ) - top, TRUE );
+	}
+
+	return DefSubclassProc( hwnd, msg, wp, lp );
+}
+
 LRESULT CALLBACK bw_Window_proc(HWND hwnd, UINT msg, WPARAM wp, LPARAM lp) {
 	bw_Window* window = (bw_Window*)GetWindowLongPtrW( hwnd, GWLP_USERDATA );
 
@@ -218,11 +552,81 @@ LRESULT CALLBACK bw_Window_proc(HWND hwnd, UINT msg, WPARAM wp, LPARAM lp) {
 			if ( window->callbacks.on_resize != 0 )
 				window->callbacks.on_resize( window, width, height );
 
+			// The shape of the window needs to be cut out again for the new size
+			if ( window->impl.corner_radius > 0 )
+				bw_WindowWin32_clipCorners( hwnd, window->impl.corner_radius );
+
+			// Let the content paint itself at its new size, and wait for the compositor to present it, before the frame is drawn at that size.
+			if ( window->impl.resize_strategy == 1 ) {
+				RedrawWindow( hwnd, NULL, NULL, RDW_INVALIDATE | RDW_UPDATENOW | RDW_ALLCHILDREN );
+				DwmFlush();
+			}
+
 		break;
+	// The area that the content hasn't caught up with yet would otherwise show whatever was drawn there before
+	case WM_ERASEBKGND: {
+		if ( window == NULL || window->impl.resize_strategy != 2 )
+			return DefWindowProcW(hwnd, msg, wp, lp);
+
+		RECT client;
+		GetClientRect( hwnd, &client );
+		FillRect( (HDC)wp, &client, GetSysColorBrush( COLOR_WINDOW ) );
+		return 1;
+	}
+	// Windows suggests a size that keeps the window just as big in logical pixels on its new display
+	case WM_DPICHANGED: {
+		const RECT* suggested = (const RECT*)lp;
+		SetWindowPos( hwnd, NULL, suggested->left, suggested->top, suggested->right - suggested->left, suggested->bottom - suggested->top, SWP_NOZORDER | SWP_NOACTIVATE );
+
+		if ( window != NULL && window->callbacks.on_scale_factor_changed != 0 )
+			window->callbacks.on_scale_factor_changed( window, (float)LOWORD( wp ) / USER_DEFAULT_SCREEN_DPI );
+		break;
+	}
+	// The position in `lp` is the one of the client area, while the window's position is reported
+	case WM_MOVE:
+		if ( window != NULL && window->callbacks.on_move != 0 ) {
+			RECT bounds;
+			GetWindowRect( hwnd, &bounds );
+			window->callbacks.on_move( window, bounds.left, bounds.top );
+		}
+		break;
+	// The browser's child window gets the keyboard focus itself, so the activation of the top level window is what counts
+	case WM_ACTIVATE:
+		if ( window != NULL && window->callbacks.on_focus != 0 )
+			window->callbacks.on_focus( window, LOWORD( wp ) != WA_INACTIVE );
+		return DefWindowProcW(hwnd, msg, wp, lp);
 	// When closing the window, only destroy it when it is ready for it to be destroyed
 	case WM_CLOSE:
 		bw_Window_triggerClose( window );
 		break;
+	// The area of a custom maximize button reports itself as one, so that Windows 11 shows the snap layouts for it.
+	case WM_NCHITTEST: {
+		POINT point = { GET_X_LPARAM( lp ), GET_Y_LPARAM( lp ) };
+		if ( window != NULL && ScreenToClient( hwnd, &point ) && PtInRect( &window->impl.maximize_button_rect, point ) )
+			return HTMAXBUTTON;
+		return DefWindowProcW(hwnd, msg, wp, lp);
+	}
+	// Frameless windows don't have a real maximize button, so Windows shouldn't try to press one
+	case WM_NCLBUTTONDOWN:
+	case WM_NCLBUTTONDBLCLK:
+		if ( wp == HTMAXBUTTON )
+			break;
+		return DefWindowProcW(hwnd, msg, wp, lp);
+	case WM_NCLBUTTONUP:
+		if ( wp == HTMAXBUTTON ) {
+			ShowWindow( hwnd, IsZoomed( hwnd ) ? SW_RESTORE : SW_MAXIMIZE );
+			break;
+		}
+		return DefWindowProcW(hwnd, msg, wp, lp);
+	case BW_WM_INVOKE:
+		return ((bw_WindowWin32InvokeFn)wp)( (void*)lp );
+	// Menu items send a notification code of 0, their accelerators send 1
+	case WM_COMMAND:
+		if ( lp == 0 && HIWORD( wp ) <= 1 ) {
+			bw_WindowWin32_onMenuCommand( window, LOWORD( wp ) );
+			break;
+		}
+		return DefWindowProcW(hwnd, msg, wp, lp);
 	default:
 		return DefWindowProcW(hwnd, msg, wp, lp);
 	}
@@ -231,9 +635,41 @@ LRESULT CALLBACK bw_Window_proc(HWND hwnd, UINT msg, WPARAM wp, LPARAM lp) {
 }
 
 void bw_WindowImpl_show( bw_WindowImpl* window ) {
+	if ( window->modal )
+		EnableWindow( window->owner, FALSE );
+
 	ShowWindow( window->handle, SW_SHOW );
 }
 
+// Places the window in the middle of its owner, while keeping it on the display of the owner.
+void bw_WindowWin32_centerOnOwner( HWND handle, HWND owner ) {
+	RECT owner_rect, rect;
+	GetWindowRect( owner, &owner_rect );
+	GetWindowRect( handle, &rect );
+
+	int width = rect.right - rect.left;
+	int height = rect.bottom - rect.top;
+	int x = owner_rect.left + ( owner_rect.right - owner_rect.left - width ) / 2;
+	int y = owner_rect.top + ( owner_rect.bottom - owner_rect.top - height ) / 2;
+
+	MONITORINFO info;
+	info.cbSize = sizeof( info );
+	if ( GetMonitorInfoW( MonitorFromWindow( owner, MONITOR_DEFAULTTONEAREST ), &info ) ) {
+		if ( x + width > info.rcWork.right )	x = info.rcWork.right - width;
+		if ( y + height > info.rcWork.bottom )	y = info.rcWork.bottom - height;
+		if ( x < info.rcWork.left )	x = info.rcWork.left;
+		if ( y < info.rcWork.top )	y = info.rcWork.top;
+	}
+
+	SetWindowPos( handle, NULL, x, y, 0, 0, SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE );
+}
+
+// Lets the user interact with the owner of a modal window again.
+void bw_WindowWin32_releaseOwner( bw_WindowImpl* window ) {
+	if ( window->modal && IsWindow( window->owner ) )
+		EnableWindow( window->owner, TRUE );
+}
+
 void bw_WindowWin32_calculatePositionCentered( int width, int height, int* x, int* y ) {
 	RECT rect;
 
@@ -272,4 +708,51 @@ BOOL CALLBACK _bw_Window_closeChild( HWND handle, LPARAM _window ) {
 	}
 
 	return true;
-}
\ No newline at end of file
+}
+
+void bw_WindowWin32_onMenuCommand( bw_Window* window, WORD item_id ) {
+	HMENU menu = GetMenu( window->impl.handle );
+	if ( menu == NULL )
+		return;
+
+	BOOL checked;
+	if ( !bw_MenuWin32_onCommand( menu, item_id, &checked ) )
+		return;
+
+	bw_Window_triggerMenuItem( window, item_id, checked );
+}
+
+BOOL bw_WindowWin32_translateAccelerator( MSG* msg ) {
+	if ( msg->hwnd == NULL )
+		return FALSE;
+
+	// The message could be meant for any of the child windows of the browser, so look at the top level window instead.
+	HWND root = GetAncestor( msg->hwnd, GA_ROOT );
+	WCHAR class_name[16];
+	if ( GetClassNameW( root, class_name, 16 ) == 0 || wcscmp( class_name, L"bw-window" ) != 0 )
+		return FALSE;
+
+	bw_Window* window = (bw_Window*)GetWindowLongPtrW( root, GWLP_USERDATA );
+	if ( window == NULL || window->impl.accelerators == NULL )
+		return FALSE;
+
+	return TranslateAcceleratorW( root, window->impl.accelerators, msg ) != 0;
+}
+
+LRESULT CALLBACK bw_WindowWin32_maximizeButtonProc( HWND hwnd, UINT msg, WPARAM wp, LPARAM lp ) {
+
+	switch ( msg ) {
+	case WM_NCHITTEST:
+		return HTMAXBUTTON;
+	// The non-client mouse messages are handled by the parent, which is what makes the snap layouts appear
+	case WM_NCMOUSEMOVE:
+	case WM_NCMOUSEHOVER:
+	case WM_NCMOUSELEAVE:
+	case WM_NCLBUTTONDOWN:
+	case WM_NCLBUTTONUP:
+	case WM_NCLBUTTONDBLCLK:
+		return SendMessageW( GetParent( hwnd ), msg, wp, lp );
+	}
+
+	return DefWindowProcW( hwnd, msg, wp, lp );
+}
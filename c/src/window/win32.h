@@ -23,6 +23,12 @@ struct bw_WindowDispatchData {
 
 
 LRESULT CALLBACK bw_Window_proc(HWND hwnd, UINT msg, WPARAM wp, LPARAM lp);
+/// The window procedure of the window that covers the custom maximize button of a frameless window.
+/// It lets its parent window handle the button, because it is only there to be above the browser.
+LRESULT CALLBACK bw_WindowWin32_maximizeButtonProc( HWND hwnd, UINT msg, WPARAM wp, LPARAM lp );
+/// Handles the message if it activates one of the accelerators of the menu bar of its window.
+/// Returns whether or not it did.
+BOOL bw_WindowWin32_translateAccelerator( MSG* msg );
 
 
 
@@ -1,10 +1,18 @@
+#[cfg(not(feature = "mock"))]
 pub mod c;
+#[cfg(feature = "mock")]
+pub mod mock;
 
 
+#[cfg(not(feature = "mock"))]
 pub use c::ApplicationImpl;
+#[cfg(feature = "mock")]
+pub use mock::ApplicationImpl;
 
 use crate::error::CbwResult;
 
+use browser_window_c::*;
+
 use std::{
 	path::PathBuf,
 	os::raw::{c_char, c_int},
@@ -16,36 +24,300 @@ use std::{
 pub trait ApplicationExt: Copy {
 	/// Asserts if not on the GUI thread
 	fn assert_correct_thread( &self );
+	/// Prepares the application to be driven by `pump_events`, instead of by `run`.
+	fn begin_pumping( &self );
 	/// Dispatches work to be executed on the GUI thread.
 	fn dispatch( &self, work: unsafe fn(ApplicationImpl, *mut ()), data: *mut () ) -> bool;
 	/// Dispatches work to be executed on the GUI thread, but delayed by the specified number of milliseconds.
 	fn dispatch_delayed(&self, work: unsafe fn(ApplicationImpl, *mut ()), data: *mut (), delay: Duration ) -> bool;
+	/// Gives the version of the browser engine, if it is available.
+	fn engine_version() -> Option<String>;
 	/// Causes the main loop to exit and lets it return the given code.
 	fn exit( &self, exit_code: i32 );
 	/// Same as `exit`, but is thread-safe.
 	fn exit_threadsafe( self: &Self, exit_code: i32 );
 	/// Shuts down all application processes and performs necessary clean-up code.
 	fn finish( &self ) {}
+	/// Gives all displays that are connected to the system.
+	fn get_monitors( &self ) -> Vec<Monitor>;
+	/// Gives whether the system uses a light or a dark theme, as one of the `THEME_*` values.
+	fn get_system_theme( &self ) -> u8;
 	fn initialize( argc: c_int, argv: *mut *mut c_char, settings: &ApplicationSettings ) -> CbwResult<ApplicationImpl>;
 	/// When this is called, the runtime will exit as soon as there are no more windows left.
 	fn mark_as_done(&self);
+	/// Shows a desktop notification.
+	/// `on_close` is invoked once the notification is gone, with whether or not it has been clicked on.
+	/// On GTK, it is only invoked when it has been clicked on.
+	fn notify( &self, title: &str, body: &str, icon_path: &str, on_close: unsafe fn(ApplicationImpl, bool, *mut ()), data: *mut () );
+	/// Gives the paths of the files on the clipboard, if there are any.
+	fn read_clipboard_files( &self ) -> Option<Vec<String>>;
+	/// Gives the HTML fragment on the clipboard, if there is one.
+	fn read_clipboard_html( &self ) -> Option<String>;
+	/// Gives the image on the clipboard as its width, height and RGBA pixels, if there is one.
+	fn read_clipboard_image( &self ) -> Option<(u32, u32, Vec<u8>)>;
+	/// Gives the text on the clipboard, if there is any.
+	fn read_clipboard_text( &self ) -> Option<String>;
+	/// Registers a global hotkey, for which `handler` is invoked with `hotkey_id` and `data` every time it is pressed.
+	/// Returns false if the hotkey can't be registered.
+	fn register_global_hotkey( &self, hotkey_id: u32, hotkey: &str, handler: unsafe fn(ApplicationImpl, u32, *mut ()), data: *mut () ) -> bool;
+	/// Processes all pending events, without waiting for new ones.
+	/// Gives the exit code once the application has exited.
+	fn pump_events( &self ) -> Option<i32>;
 	/// Runs the main loop.
 	/// This blocks until the application is exitting.
 	fn run( &self, on_ready: unsafe fn(ApplicationImpl, *mut ()), data: *mut () ) -> i32;
+	/// Sets the handler that is invoked with the new `THEME_*` value whenever the system switches between its light and dark theme.
+	fn set_theme_handler( &self, handler: unsafe fn(ApplicationImpl, u8) );
+	/// Unregisters a global hotkey, and gives back the `data` it has been registered with.
+	/// Returns null if there is no hotkey with the given ID.
+	fn unregister_global_hotkey( &self, hotkey_id: u32 ) -> *mut ();
+	/// Puts a list of files on the clipboard, by their paths.
+	fn write_clipboard_files( &self, paths: &[String] ) -> bool;
+	/// Puts an HTML fragment on the clipboard.
+	fn write_clipboard_html( &self, html: &str ) -> bool;
+	/// Puts an image on the clipboard, from its RGBA pixels.
+	fn write_clipboard_image( &self, pixels: &[u8], width: u32, height: u32 ) -> bool;
+	/// Puts text on the clipboard.
+	fn write_clipboard_text( &self, text: &str ) -> bool;
 }
 
+pub type Monitor = cbw_Monitor;
+
+pub const THEME_UNKNOWN: u8 = cBW_THEME_UNKNOWN as _;
+pub const THEME_LIGHT: u8 = cBW_THEME_LIGHT as _;
+pub const THEME_DARK: u8 = cBW_THEME_DARK as _;
+
 pub struct ApplicationSettings {
+	/// Lets pages play audio and video with sound right away, without waiting for the user to interact with them first.
+	/// This is meant for applications that are centered around video, like players and kiosks, so that they don't need to simulate a click.
+	///
+	/// *Note:* Both CEF and WebView2 only have an autoplay policy for the browser engine as a whole, so it applies to all browser windows of the application.
+	pub autoplay_without_gesture: bool,
+	/// The directory in which the browser engine caches its data.
+	/// Uses the engine's default when not set.
+	pub cache_path: Option<PathBuf>,
+	/// The schemes, like `app`, of which the browser windows answer the requests themselves, with their scheme handler.
+	/// They are treated like `https`, so pages loaded from them can use the same features as secure pages.
+	/// Requests to other origins are subject to CORS, like they are for `https`.
+	pub custom_schemes: Vec<String>,
+	/// Keeps the GPU out of rendering altogether, for systems on which the drivers make the browser engine crash or render garbage.
+	/// Pages are rendered on the CPU then, which is slower, especially for animations and WebGL.
+	pub disable_gpu: bool,
 	pub engine_seperate_executable_path: Option<PathBuf>,
-	pub resource_dir: Option<String>
+	/// Additional command-line switches for the browser engine, like `--disable-gpu` or `--lang=nl`.
+	/// They are passed on to the engine as they are, so that options without a setting of their own can still be used.
+	/// The leading dashes may be left out.
+	///
+	/// CEF gives them to its browser process, which passes them on to the other processes where needed.
+	/// WebView2 gets them as its additional browser arguments.
+	pub engine_switches: Vec<String>,
+	/// When set, windows are never shown to the user.
+	/// Browser windows work the same otherwise, so they can still be used to load pages and run JavaScript on them.
+	pub headless: bool,
+	/// The language to use for the browser engine's own UI and the `Accept-Language` header, like `en-US`.
+	/// Uses the system's locale when not set.
+	pub locale: Option<String>,
+	/// The file that the browser engine writes its log to.
+	pub log_file: Option<PathBuf>,
+	/// The minimum severity of the log messages that the browser engine writes.
+	pub log_severity: Option<LogSeverity>,
+	/// The maximum number of frames per second that browsers paint.
+	/// Uses the engine's default when not set.
+	///
+	/// *Note:* Only applies to the offscreen browsers of CEF, which paint at most 60 frames per second.
+	pub max_frame_rate: Option<u32>,
+	/// Keeps the cookies that expire with the session, like the ones that keep users logged in, when the application restarts.
+	/// They are written to the profile when the application exits, and read back when it starts, so `cache_path` needs to be set for this.
+	///
+	/// *Note:* Only supported by CEF, WebView2 always discards the session cookies when the application exits.
+	pub persist_session_cookies: bool,
+	/// The port on which the browser engine can be debugged remotely, with the dev tools of another browser.
+	/// Remote debugging is disabled when not set.
+	pub remote_debugging_port: Option<u16>,
+	pub resource_dir: Option<String>,
+	/// Rasterizes the pages on the CPU, while still compositing them on the GPU.
+	/// This avoids the problems of drivers that only go wrong when rasterizing, at less of a cost than `disable_gpu`.
+	pub software_rasterization: bool,
+	/// The directory in which the browser engine stores its user data, like cookies and local storage.
+	/// Uses the engine's default when not set.
+	pub user_data_dir: Option<PathBuf>
+}
+
+/// The minimum severity of the messages that the browser engine logs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogSeverity {
+	Verbose = 1,
+	Info,
+	Warning,
+	Error,
+	Fatal,
+	/// Nothing gets logged.
+	Disable
 }
 
 
 
+impl ApplicationSettings {
+
+	/// Sets whether or not pages can play media with sound before the user has interacted with them.
+	/// See field `autoplay_without_gesture`.
+	pub fn autoplay_without_gesture( mut self, enabled: bool ) -> Self {
+		self.autoplay_without_gesture = enabled;
+		self
+	}
+
+	/// Sets the directory in which the browser engine caches its data.
+	pub fn cache_path<P: Into<PathBuf>>( mut self, path: P ) -> Self {
+		self.cache_path = Some( path.into() );
+		self
+	}
+
+	/// Registers a custom scheme, like `app`, of which the browser windows answer the requests themselves.
+	/// See field `custom_schemes`.
+	///
+	/// # Example
+	/// ```ignore
+	/// let settings = ApplicationSettings::default()
+	/// 	.custom_scheme( "app" );
+	/// ```
+	pub fn custom_scheme<S: Into<String>>( mut self, scheme: S ) -> Self {
+		self.custom_schemes.push( scheme.into().to_lowercase() );
+		self
+	}
+
+	/// Sets whether or not the GPU is kept out of rendering.
+	/// See field `disable_gpu`.
+	///
+	/// # Example
+	/// ```ignore
+	/// let settings = ApplicationSettings::default()
+	/// 	.disable_gpu( std::env::var_os( "MY_APP_DISABLE_GPU" ).is_some() );
+	/// ```
+	pub fn disable_gpu( mut self, enabled: bool ) -> Self {
+		self.disable_gpu = enabled;
+		self
+	}
+
+	/// Adds a command-line switch for the browser engine, like `--disable-gpu` or `--lang=nl`.
+	/// See field `engine_switches`.
+	///
+	/// # Example
+	/// ```ignore
+	/// let settings = ApplicationSettings::default()
+	/// 	.engine_switch( "--disable-web-security" )
+	/// 	.engine_switch( "--autoplay-policy=no-user-gesture-required" );
+	/// ```
+	pub fn engine_switch<S: Into<String>>( mut self, switch: S ) -> Self {
+		self.engine_switches.push( switch.into() );
+		self
+	}
+
+	/// Adds several command-line switches for the browser engine, like the ones that the application has been given itself.
+	/// See field `engine_switches`.
+	///
+	/// # Example
+	/// ```ignore
+	/// let settings = ApplicationSettings::default()
+	/// 	.engine_switches( std::env::args().filter(|arg| arg.starts_with( "--engine-" ) ).map(|arg| arg.replacen( "--engine-", "--", 1 ) ) );
+	/// ```
+	pub fn engine_switches<I, S>( mut self, switches: I ) -> Self where
+		I: IntoIterator<Item=S>,
+		S: Into<String>
+	{
+		self.engine_switches.extend( switches.into_iter().map( Into::into ) );
+		self
+	}
+
+	/// Sets whether or not the application runs headless.
+	/// See field `headless`.
+	///
+	/// # Example
+	/// ```ignore
+	/// let app = Application::initialize( &ApplicationSettings::default().headless( true ) ).unwrap();
+	/// ```
+	pub fn headless( mut self, enabled: bool ) -> Self {
+		self.headless = enabled;
+		self
+	}
+
+	/// Sets the language of the browser engine, like `en-US`.
+	pub fn locale<S: Into<String>>( mut self, locale: S ) -> Self {
+		self.locale = Some( locale.into() );
+		self
+	}
+
+	/// Sets the file that the browser engine writes its log to.
+	pub fn log_file<P: Into<PathBuf>>( mut self, path: P ) -> Self {
+		self.log_file = Some( path.into() );
+		self
+	}
+
+	/// Sets the minimum severity of the messages that the browser engine logs.
+	pub fn log_severity( mut self, severity: LogSeverity ) -> Self {
+		self.log_severity = Some( severity );
+		self
+	}
+
+	/// Sets the maximum number of frames per second that browsers paint.
+	/// See field `max_frame_rate`.
+	pub fn max_frame_rate( mut self, frames_per_second: u32 ) -> Self {
+		self.max_frame_rate = Some( frames_per_second );
+		self
+	}
+
+	/// Sets whether or not the cookies that expire with the session are kept across restarts.
+	/// See field `persist_session_cookies`.
+	///
+	/// # Example
+	/// ```ignore
+	/// let settings = ApplicationSettings::default()
+	/// 	.cache_path( "profile" )
+	/// 	.persist_session_cookies( true );
+	/// ```
+	pub fn persist_session_cookies( mut self, enabled: bool ) -> Self {
+		self.persist_session_cookies = enabled;
+		self
+	}
+
+	/// Enables remote debugging on the given port.
+	pub fn remote_debugging_port( mut self, port: u16 ) -> Self {
+		self.remote_debugging_port = Some( port );
+		self
+	}
+
+	/// Sets whether or not the pages are rasterized on the CPU.
+	/// See field `software_rasterization`.
+	pub fn software_rasterization( mut self, enabled: bool ) -> Self {
+		self.software_rasterization = enabled;
+		self
+	}
+
+	/// Sets the directory in which the browser engine stores its user data.
+	pub fn user_data_dir<P: Into<PathBuf>>( mut self, path: P ) -> Self {
+		self.user_data_dir = Some( path.into() );
+		self
+	}
+}
+
 impl Default for ApplicationSettings {
 	fn default() -> Self {
 		Self {
+			autoplay_without_gesture: false,
+			cache_path: None,
+			custom_schemes: Vec::new(),
+			disable_gpu: false,
 			engine_seperate_executable_path: None,
-			resource_dir: None
+			engine_switches: Vec::new(),
+			headless: false,
+			locale: None,
+			log_file: None,
+			log_severity: None,
+			max_frame_rate: None,
+			persist_session_cookies: false,
+			remote_debugging_port: None,
+			resource_dir: None,
+			software_rasterization: false,
+			user_data_dir: None
 		}
 	}
 }
\ No newline at end of file
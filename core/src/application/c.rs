@@ -1,7 +1,7 @@
 //! This module implements the `Application` trait with the corresponding function definitions found in the C code base of `browser-window-c`.
 //! All functions are basically wrapping the FFI provided by crate `browser-window-c`.
 
-use super::{ApplicationExt, ApplicationSettings};
+use super::{ApplicationExt, ApplicationSettings, Monitor};
 
 use crate::{
 	error::*,
@@ -9,8 +9,11 @@ use crate::{
 };
 
 use std::{
+	mem,
 	os::raw::{c_char, c_int, c_void},
+	path::PathBuf,
 	ptr,
+	slice,
 	time::Duration
 };
 
@@ -27,6 +30,10 @@ impl ApplicationExt for ApplicationImpl {
 		unsafe { cbw_Application_assertCorrectThread( self.inner ) }
 	}
 
+	fn begin_pumping( &self ) {
+		unsafe { cbw_Application_beginPumping( self.inner ) }
+	}
+
 	fn dispatch( &self, work: unsafe fn(ApplicationImpl, *mut ()), _data: *mut () ) -> bool {
 		let data = Box::new( DispatchData {
 			func: work,
@@ -49,6 +56,18 @@ impl ApplicationExt for ApplicationImpl {
 		unsafe { cbw_Application_dispatchDelayed( self.inner, Some( invocation_handler ), data_ptr as _, delay.as_millis() as _ ) != 0 }
 	}
 	
+	fn engine_version() -> Option<String> {
+		let mut slice = cbw_StrSlice { len: 0, data: ptr::null_mut() };
+
+		if unsafe { cbw_Application_getEngineVersion( &mut slice ) } == 0 {
+			return None;
+		}
+
+		let version: String = slice.into();
+		unsafe { cbw_string_free( slice ) };
+		Some( version )
+	}
+
 	fn exit( &self, exit_code: i32 ) {
 		unsafe { cbw_Application_exit( self.inner, exit_code as _ ) }
 	}
@@ -61,16 +80,59 @@ impl ApplicationExt for ApplicationImpl {
 		unsafe { cbw_Application_finish( self.inner ) }
 	}
 
+	fn get_monitors( &self ) -> Vec<Monitor> {
+		let mut monitors: *mut cbw_Monitor = ptr::null_mut();
+		let count = unsafe { cbw_Application_getMonitors( self.inner, &mut monitors ) };
+
+		if count == 0 {
+			return Vec::new();
+		}
+
+		let copy = unsafe { slice::from_raw_parts( monitors, count as _ ) }.to_vec();
+		unsafe { cbw_Application_freeMonitors( monitors ) };
+		copy
+	}
+
+	fn get_system_theme( &self ) -> u8 {
+		unsafe { cbw_Application_getSystemTheme( self.inner ) }
+	}
+
 	fn initialize( argc: c_int, argv: *mut *mut c_char, _settings: &ApplicationSettings ) -> CbwResult<Self> {
 
 		let exec_path: &str = match _settings.engine_seperate_executable_path.as_ref() {
 			None => "",
 			Some(path) => path.to_str().unwrap()
 		};
+		let path_str = |path: &Option<PathBuf>| -> String {
+			path.as_ref().map( |p| p.to_string_lossy().into_owned() ).unwrap_or_default()
+		};
+		let cache_path = path_str( &_settings.cache_path );
+		let user_data_dir = path_str( &_settings.user_data_dir );
+		let log_file = path_str( &_settings.log_file );
+		let locale = _settings.locale.as_deref().unwrap_or("");
+		// The slices point into `_settings`, which outlives the call
+		let engine_switches: Vec<cbw_CStrSlice> = _settings.engine_switches.iter().map( |s| s.as_str().into() ).collect();
+		let custom_schemes: Vec<cbw_CStrSlice> = _settings.custom_schemes.iter().map( |s| s.as_str().into() ).collect();
 
 		let c_settings = cbw_ApplicationSettings {
+			autoplay_without_gesture: _settings.autoplay_without_gesture as _,
+			cache_path: cache_path.as_str().into(),
+			custom_scheme_count: custom_schemes.len() as _,
+			custom_schemes: custom_schemes.as_ptr(),
+			disable_gpu: _settings.disable_gpu as _,
 			engine_seperate_executable_path: exec_path.into(),
-			resource_dir: _settings.resource_dir.as_ref().unwrap_or(&"".to_owned()).as_str().into()
+			engine_switch_count: engine_switches.len() as _,
+			engine_switches: engine_switches.as_ptr(),
+			headless: _settings.headless as _,
+			locale: locale.into(),
+			log_file: log_file.as_str().into(),
+			log_severity: _settings.log_severity.map( |s| s as c_int ).unwrap_or( 0 ),
+			max_frame_rate: _settings.max_frame_rate.unwrap_or( 0 ),
+			persist_session_cookies: _settings.persist_session_cookies as _,
+			remote_debugging_port: _settings.remote_debugging_port.map( |p| p as c_int ).unwrap_or( 0 ),
+			resource_dir: _settings.resource_dir.as_ref().unwrap_or(&"".to_owned()).as_str().into(),
+			software_rasterization: _settings.software_rasterization as _,
+			user_data_dir: user_data_dir.as_str().into()
 		};
 
 		let mut c_handle: *mut cbw_Application = ptr::null_mut();
@@ -88,6 +150,102 @@ impl ApplicationExt for ApplicationImpl {
 		unsafe { cbw_Application_markAsDone(self.inner) };
 	}
 
+	fn notify( &self, title: &str, body: &str, icon_path: &str, on_close: unsafe fn(ApplicationImpl, bool, *mut ()), _data: *mut () ) {
+		let data = Box::new( NotificationData {
+			func: on_close,
+			data: _data
+		} );
+
+		let data_ptr = Box::into_raw( data );
+
+		unsafe { cbw_Application_notify( self.inner, title.into(), body.into(), icon_path.into(), Some( notification_handler ), data_ptr as _ ) }
+	}
+
+	fn pump_events( &self ) -> Option<i32> {
+		let mut exit_code: c_int = 0;
+
+		if unsafe { cbw_Application_pumpEvents( self.inner, &mut exit_code ) } != 0 {
+			None
+		}
+		else {
+			Some( exit_code as _ )
+		}
+	}
+
+	fn read_clipboard_files( &self ) -> Option<Vec<String>> {
+		let mut slice = cbw_StrSlice { len: 0, data: ptr::null_mut() };
+
+		if unsafe { cbw_Application_readClipboardFiles( self.inner, &mut slice ) } == 0 {
+			return None;
+		}
+
+		// Every path is terminated by a null character
+		let paths: String = slice.into();
+		unsafe { cbw_string_free( slice ) };
+		Some( paths.split_terminator('\0').map( str::to_owned ).collect() )
+	}
+
+	fn read_clipboard_html( &self ) -> Option<String> {
+		let mut slice = cbw_StrSlice { len: 0, data: ptr::null_mut() };
+
+		if unsafe { cbw_Application_readClipboardHtml( self.inner, &mut slice ) } == 0 {
+			return None;
+		}
+
+		let html: String = slice.into();
+		unsafe { cbw_string_free( slice ) };
+		Some( html )
+	}
+
+	fn read_clipboard_image( &self ) -> Option<(u32, u32, Vec<u8>)> {
+		let mut pixels: *mut u8 = ptr::null_mut();
+		let mut width: u32 = 0;
+		let mut height: u32 = 0;
+
+		if unsafe { cbw_Application_readClipboardImage( self.inner, &mut pixels, &mut width, &mut height ) } == 0 {
+			return None;
+		}
+		// An empty image may come without any pixels at all, which can't be made into a slice
+		if pixels.is_null() || width == 0 || height == 0 {
+			if !pixels.is_null() {
+				unsafe { cbw_Application_freeClipboardImage( pixels ) };
+			}
+			return None;
+		}
+
+		let len = width as usize * height as usize * 4;
+		let copy = unsafe { slice::from_raw_parts( pixels, len ) }.to_vec();
+		unsafe { cbw_Application_freeClipboardImage( pixels ) };
+		Some( (width, height, copy) )
+	}
+
+	fn read_clipboard_text( &self ) -> Option<String> {
+		let mut slice = cbw_StrSlice { len: 0, data: ptr::null_mut() };
+
+		if unsafe { cbw_Application_readClipboardText( self.inner, &mut slice ) } == 0 {
+			return None;
+		}
+
+		let text: String = slice.into();
+		unsafe { cbw_string_free( slice ) };
+		Some( text )
+	}
+
+	fn register_global_hotkey( &self, hotkey_id: u32, hotkey: &str, handler: unsafe fn(ApplicationImpl, u32, *mut ()), _data: *mut () ) -> bool {
+		let data = Box::new( HotkeyData {
+			func: handler,
+			data: _data
+		} );
+
+		let data_ptr = Box::into_raw( data );
+
+		let registered = unsafe { cbw_Application_registerGlobalHotkey( self.inner, hotkey_id, hotkey.into(), Some( hotkey_handler ), data_ptr as _ ) != 0 };
+		if !registered {
+			unsafe { drop( Box::from_raw( data_ptr ) ) };
+		}
+		registered
+	}
+
 	fn run( &self, on_ready: unsafe fn( ApplicationImpl, *mut () ), _data: *mut () ) -> i32 {
 		let data = Box::new( DispatchData {
 			func: on_ready,
@@ -99,6 +257,45 @@ impl ApplicationExt for ApplicationImpl {
 		// The dispatch handler does exactly the same thing 
 		unsafe { cbw_Application_run( self.inner, Some( invocation_handler ), data_ptr as _ ) }
 	}
+
+	fn set_theme_handler( &self, handler: unsafe fn(ApplicationImpl, u8) ) {
+		// The handler itself is the data, so nothing needs to be freed when it is replaced
+		unsafe { cbw_Application_setThemeHandler( self.inner, Some( theme_handler ), handler as *mut c_void ) }
+	}
+
+	fn unregister_global_hotkey( &self, hotkey_id: u32 ) -> *mut () {
+		let data_ptr = unsafe { cbw_Application_unregisterGlobalHotkey( self.inner, hotkey_id ) } as *mut HotkeyData;
+		if data_ptr.is_null() {
+			return ptr::null_mut();
+		}
+
+		let data = unsafe { Box::from_raw( data_ptr ) };
+		data.data
+	}
+
+	fn write_clipboard_files( &self, paths: &[String] ) -> bool {
+		let mut joined = String::new();
+		for path in paths {
+			joined.push_str( path );
+			joined.push('\0');
+		}
+
+		unsafe { cbw_Application_writeClipboardFiles( self.inner, joined.as_str().into() ) != 0 }
+	}
+
+	fn write_clipboard_html( &self, html: &str ) -> bool {
+		unsafe { cbw_Application_writeClipboardHtml( self.inner, html.into() ) != 0 }
+	}
+
+	fn write_clipboard_image( &self, pixels: &[u8], width: u32, height: u32 ) -> bool {
+		debug_assert!( pixels.len() == width as usize * height as usize * 4, "pixels don't match the size of the image" );
+
+		unsafe { cbw_Application_writeClipboardImage( self.inner, pixels.as_ptr(), width, height ) != 0 }
+	}
+
+	fn write_clipboard_text( &self, text: &str ) -> bool {
+		unsafe { cbw_Application_writeClipboardText( self.inner, text.into() ) != 0 }
+	}
 }
 
 
@@ -108,6 +305,25 @@ struct DispatchData {
 	data: *mut ()
 }
 
+struct HotkeyData {
+	func: unsafe fn( ApplicationImpl, u32, *mut () ),
+	data: *mut ()
+}
+
+struct NotificationData {
+	func: unsafe fn( ApplicationImpl, bool, *mut () ),
+	data: *mut ()
+}
+
+unsafe extern "C" fn hotkey_handler( _handle: *mut cbw_Application, hotkey_id: u32, _data: *mut c_void ) {
+
+	// The data stays alive until the hotkey is unregistered
+	let data = &*( _data as *const HotkeyData );
+	let handle = ApplicationImpl { inner: _handle };
+
+	(data.func)( handle, hotkey_id, data.data );
+}
+
 unsafe extern "C" fn invocation_handler( _handle: *mut cbw_Application, _data: *mut c_void ) {
 
 	let data_ptr = _data as *mut DispatchData;
@@ -115,4 +331,20 @@ unsafe extern "C" fn invocation_handler( _handle: *mut cbw_Application, _data: *
 	let handle = ApplicationImpl { inner: _handle };
 
 	(data.func)( handle, data.data );
-}
\ No newline at end of file
+}
+
+unsafe extern "C" fn notification_handler( _handle: *mut cbw_Application, clicked: cBOOL, _data: *mut c_void ) {
+
+	let data = Box::from_raw( _data as *mut NotificationData );
+	let handle = ApplicationImpl { inner: _handle };
+
+	(data.func)( handle, clicked != 0, data.data );
+}
+
+unsafe extern "C" fn theme_handler( _handle: *mut cbw_Application, theme: u8, _data: *mut c_void ) {
+
+	let func: unsafe fn( ApplicationImpl, u8 ) = mem::transmute( _data );
+	let handle = ApplicationImpl { inner: _handle };
+
+	func( handle, theme );
+}
@@ -0,0 +1,333 @@
+//! This module implements the `Application` trait without a browser engine or windowing system, for feature `mock`.
+//! The main loop only runs the work that is dispatched to it, and the clipboard is kept in memory.
+//! So applications can be run in environments without a display, like CI.
+
+use super::{ApplicationExt, ApplicationSettings, Monitor, THEME_LIGHT};
+
+use crate::error::*;
+
+use std::{
+	os::raw::{c_char, c_int},
+	ptr,
+	sync::{
+		atomic::{AtomicBool, AtomicUsize, Ordering},
+		Condvar, Mutex
+	},
+	thread::{self, ThreadId},
+	time::{Duration, Instant}
+};
+
+
+
+#[derive(Clone,Copy)]
+pub struct ApplicationImpl {
+	pub(in crate) inner: *mut MockApplication
+}
+
+pub(in crate) struct MockApplication {
+	clipboard: Mutex<Clipboard>,
+	hotkeys: Mutex<Vec<Hotkey>>,
+	is_done: AtomicBool,
+	queue: Mutex<Queue>,
+	theme: Mutex<Theme>,
+	thread: ThreadId,
+	wake: Condvar,
+	/// The number of windows that haven't been destroyed yet.
+	windows_alive: AtomicUsize
+}
+
+#[derive(Default)]
+struct Clipboard {
+	files: Option<Vec<String>>,
+	html: Option<String>,
+	image: Option<(u32, u32, Vec<u8>)>,
+	text: Option<String>
+}
+
+struct Hotkey {
+	id: u32,
+	data: *mut ()
+}
+
+struct Queue {
+	exit_code: Option<i32>,
+	next_seq: u64,
+	work: Vec<Work>
+}
+
+struct Theme {
+	current: u8,
+	handler: Option<unsafe fn( ApplicationImpl, u8 )>
+}
+
+struct Work {
+	due: Instant,
+	/// Work that is due at the same time is run in the order in which it has been dispatched.
+	seq: u64,
+	func: unsafe fn( ApplicationImpl, *mut () ),
+	data: *mut ()
+}
+
+
+
+impl ApplicationImpl {
+
+	/// Lets the application know that a window has been created.
+	pub(in crate) fn window_created( &self ) {
+		self.state().windows_alive.fetch_add( 1, Ordering::SeqCst );
+	}
+
+	/// Lets the application know that a window has been destroyed, and exits if it was the last one after `mark_as_done` has been called.
+	pub(in crate) fn window_destroyed( &self ) {
+		let state = self.state();
+
+		if state.windows_alive.fetch_sub( 1, Ordering::SeqCst ) == 1 && state.is_done.load( Ordering::SeqCst ) {
+			self.exit( 0 );
+		}
+	}
+
+	/// Switches the theme of the system to the given `THEME_*` value, as if the user had changed it.
+	/// If it is a different one, the theme handler is invoked with it from the main loop.
+	pub fn set_system_theme( &self, theme: u8 ) {
+		let mut state = self.state().theme.lock().unwrap();
+		if state.current == theme {
+			return;
+		}
+		state.current = theme;
+
+		if let Some( handler ) = state.handler {
+			let data = Box::into_raw( Box::new( (handler, theme) ) );
+			self.dispatch( theme_changed, data as _ );
+		}
+	}
+
+	fn state( &self ) -> &MockApplication {
+		unsafe { &*self.inner }
+	}
+
+	/// Takes the next work that is due, waiting for it if `wait` is set.
+	/// Gives the exit code instead once the application has exited.
+	fn next( &self, wait: bool ) -> Option<Result<Work, i32>> {
+		let state = self.state();
+		let mut queue = state.queue.lock().unwrap();
+
+		loop {
+			if let Some( code ) = queue.exit_code {
+				return Some( Err( code ) );
+			}
+
+			let now = Instant::now();
+			let earliest = queue.work.iter().enumerate().min_by_key(|(_, w)| (w.due, w.seq) ).map(|(i, w)| (i, w.due) );
+			match earliest {
+				Some( (index, due) ) if due <= now => return Some( Ok( queue.work.remove( index ) ) ),
+				_ if !wait => return None,
+				Some( (_, due) ) => queue = state.wake.wait_timeout( queue, due - now ).unwrap().0,
+				None => queue = state.wake.wait( queue ).unwrap()
+			}
+		}
+	}
+
+	fn push( &self, func: unsafe fn( ApplicationImpl, *mut () ), data: *mut (), delay: Duration ) {
+		let state = self.state();
+		let mut queue = state.queue.lock().unwrap();
+
+		let seq = queue.next_seq;
+		queue.next_seq += 1;
+		queue.work.push( Work { due: Instant::now() + delay, seq, func, data } );
+		state.wake.notify_all();
+	}
+}
+
+impl ApplicationExt for ApplicationImpl {
+
+	fn assert_correct_thread( &self ) {
+		assert!( thread::current().id() == self.state().thread, "not called on the GUI thread" );
+	}
+
+	fn begin_pumping( &self ) {
+		self.state().queue.lock().unwrap().exit_code = None;
+	}
+
+	fn dispatch( &self, work: unsafe fn(ApplicationImpl, *mut ()), data: *mut () ) -> bool {
+		self.push( work, data, Duration::from_millis( 0 ) );
+		true
+	}
+
+	fn dispatch_delayed( &self, work: unsafe fn(ApplicationImpl, *mut ()), data: *mut (), delay: Duration ) -> bool {
+		self.push( work, data, delay );
+		true
+	}
+
+	fn engine_version() -> Option<String> {
+		Some( "mock".to_owned() )
+	}
+
+	fn exit( &self, exit_code: i32 ) {
+		let state = self.state();
+
+		state.queue.lock().unwrap().exit_code = Some( exit_code );
+		state.wake.notify_all();
+	}
+
+	fn exit_threadsafe( self: &Self, exit_code: i32 ) {
+		self.exit( exit_code )
+	}
+
+	fn finish( &self ) {
+		unsafe { drop( Box::from_raw( self.inner ) ) }
+	}
+
+	fn get_monitors( &self ) -> Vec<Monitor> {
+		vec![ Monitor {
+			x: 0,
+			y: 0,
+			width: 1920,
+			height: 1080,
+			work_x: 0,
+			work_y: 0,
+			work_width: 1920,
+			work_height: 1040,
+			scale_factor: 1.0,
+			is_primary: 1
+		} ]
+	}
+
+	fn get_system_theme( &self ) -> u8 {
+		self.state().theme.lock().unwrap().current
+	}
+
+	fn initialize( _argc: c_int, _argv: *mut *mut c_char, _settings: &ApplicationSettings ) -> CbwResult<Self> {
+		let state = Box::new( MockApplication {
+			clipboard: Mutex::new( Clipboard::default() ),
+			hotkeys: Mutex::new( Vec::new() ),
+			is_done: AtomicBool::new( false ),
+			queue: Mutex::new( Queue {
+				exit_code: None,
+				next_seq: 0,
+				work: Vec::new()
+			} ),
+			theme: Mutex::new( Theme {
+				current: THEME_LIGHT,
+				handler: None
+			} ),
+			thread: thread::current().id(),
+			wake: Condvar::new(),
+			windows_alive: AtomicUsize::new( 0 )
+		} );
+
+		Ok( Self { inner: Box::into_raw( state ) } )
+	}
+
+	fn mark_as_done( &self ) {
+		let state = self.state();
+
+		state.is_done.store( true, Ordering::SeqCst );
+		if state.windows_alive.load( Ordering::SeqCst ) == 0 {
+			self.exit( 0 );
+		}
+	}
+
+	fn notify( &self, _title: &str, _body: &str, _icon_path: &str, on_close: unsafe fn(ApplicationImpl, bool, *mut ()), data: *mut () ) {
+		// Nobody is there to click on it
+		let data = Box::into_raw( Box::new( (on_close, data) ) );
+		self.dispatch( notification_closed, data as _ );
+	}
+
+	fn pump_events( &self ) -> Option<i32> {
+		loop {
+			match self.next( false ) {
+				None => return None,
+				Some( Err( code ) ) => return Some( code ),
+				Some( Ok( work ) ) => unsafe { (work.func)( *self, work.data ) }
+			}
+		}
+	}
+
+	fn read_clipboard_files( &self ) -> Option<Vec<String>> {
+		self.state().clipboard.lock().unwrap().files.clone()
+	}
+
+	fn read_clipboard_html( &self ) -> Option<String> {
+		self.state().clipboard.lock().unwrap().html.clone()
+	}
+
+	fn read_clipboard_image( &self ) -> Option<(u32, u32, Vec<u8>)> {
+		self.state().clipboard.lock().unwrap().image.clone()
+	}
+
+	fn read_clipboard_text( &self ) -> Option<String> {
+		self.state().clipboard.lock().unwrap().text.clone()
+	}
+
+	fn register_global_hotkey( &self, hotkey_id: u32, _hotkey: &str, _handler: unsafe fn(ApplicationImpl, u32, *mut ()), data: *mut () ) -> bool {
+		let mut hotkeys = self.state().hotkeys.lock().unwrap();
+
+		if hotkeys.iter().any(|h| h.id == hotkey_id ) {
+			return false;
+		}
+		hotkeys.push( Hotkey { id: hotkey_id, data } );
+		true
+	}
+
+	fn run( &self, on_ready: unsafe fn(ApplicationImpl, *mut ()), data: *mut () ) -> i32 {
+		self.begin_pumping();
+		self.dispatch( on_ready, data );
+
+		loop {
+			match self.next( true ) {
+				None => {},
+				Some( Err( code ) ) => return code,
+				Some( Ok( work ) ) => unsafe { (work.func)( *self, work.data ) }
+			}
+		}
+	}
+
+	fn set_theme_handler( &self, handler: unsafe fn(ApplicationImpl, u8) ) {
+		self.state().theme.lock().unwrap().handler = Some( handler );
+	}
+
+	fn unregister_global_hotkey( &self, hotkey_id: u32 ) -> *mut () {
+		let mut hotkeys = self.state().hotkeys.lock().unwrap();
+
+		match hotkeys.iter().position(|h| h.id == hotkey_id ) {
+			None => ptr::null_mut(),
+			Some( index ) => hotkeys.remove( index ).data
+		}
+	}
+
+	fn write_clipboard_files( &self, paths: &[String] ) -> bool {
+		*self.state().clipboard.lock().unwrap() = Clipboard { files: Some( paths.to_vec() ), ..Default::default() };
+		true
+	}
+
+	fn write_clipboard_html( &self, html: &str ) -> bool {
+		*self.state().clipboard.lock().unwrap() = Clipboard { html: Some( html.to_owned() ), ..Default::default() };
+		true
+	}
+
+	fn write_clipboard_image( &self, pixels: &[u8], width: u32, height: u32 ) -> bool {
+		debug_assert!( pixels.len() == width as usize * height as usize * 4, "pixels don't match the size of the image" );
+
+		*self.state().clipboard.lock().unwrap() = Clipboard { image: Some( (width, height, pixels.to_vec()) ), ..Default::default() };
+		true
+	}
+
+	fn write_clipboard_text( &self, text: &str ) -> bool {
+		*self.state().clipboard.lock().unwrap() = Clipboard { text: Some( text.to_owned() ), ..Default::default() };
+		true
+	}
+}
+
+
+
+unsafe fn notification_closed( app: ApplicationImpl, data: *mut () ) {
+	let data = Box::from_raw( data as *mut (unsafe fn(ApplicationImpl, bool, *mut ()), *mut ()) );
+
+	(data.0)( app, false, data.1 );
+}
+
+unsafe fn theme_changed( app: ApplicationImpl, data: *mut () ) {
+	let data = Box::from_raw( data as *mut (unsafe fn(ApplicationImpl, u8), u8) );
+
+	(data.0)( app, data.1 );
+}
@@ -1,9 +1,30 @@
+#[cfg(not(feature = "mock"))]
 pub mod c;
+#[cfg(feature = "mock")]
+pub mod mock;
 
 use std::borrow::Cow;
 
+#[cfg(not(feature = "mock"))]
 pub use c::BrowserWindowImpl;
+#[cfg(feature = "mock")]
+pub use mock::BrowserWindowImpl;
+#[cfg(not(feature = "mock"))]
+pub use c::FileChooserImpl;
+#[cfg(feature = "mock")]
+pub use mock::FileChooserImpl;
+#[cfg(not(feature = "mock"))]
+pub use c::InterceptedRequestImpl;
+#[cfg(feature = "mock")]
+pub use mock::InterceptedRequestImpl;
+#[cfg(not(feature = "mock"))]
 pub use c::JsEvaluationError;
+#[cfg(feature = "mock")]
+pub use mock::JsEvaluationError;
+#[cfg(not(feature = "mock"))]
+pub use c::SchemeRequestImpl;
+#[cfg(feature = "mock")]
+pub use mock::SchemeRequestImpl;
 
 use super::{
 	application::ApplicationImpl,
@@ -13,17 +34,88 @@ use super::{
 
 use browser_window_c::*;
 
+use crate::error::CbwResult;
 
 
+
+// The flags for the `modifiers` of the input that is sent to offscreen browsers
+pub const MODIFIER_CTRL: u32 = cBW_MODIFIER_CTRL;
+pub const MODIFIER_SHIFT: u32 = cBW_MODIFIER_SHIFT;
+pub const MODIFIER_ALT: u32 = cBW_MODIFIER_ALT;
+pub const MODIFIER_SUPER: u32 = cBW_MODIFIER_SUPER;
+pub const MODIFIER_LEFT_BUTTON: u32 = cBW_MODIFIER_LEFT_BUTTON;
+pub const MODIFIER_MIDDLE_BUTTON: u32 = cBW_MODIFIER_MIDDLE_BUTTON;
+pub const MODIFIER_RIGHT_BUTTON: u32 = cBW_MODIFIER_RIGHT_BUTTON;
+
 pub type BrowserWindowOptions = cbw_BrowserWindowOptions;
+pub type Rect = cbw_BrowserWindowRect;
 pub type Source = cbw_BrowserWindowSource;
 
+pub type AcceleratedPaintHandlerFn = unsafe fn( bw: BrowserWindowImpl, is_popup: bool, dirty_rects: &[Rect], shared_handle: *mut () );
+pub type AcceleratorHandlerFn = unsafe fn( bw: BrowserWindowImpl, accelerator_id: u32 );
 pub type CreationCallbackFn = unsafe fn( bw: BrowserWindowImpl, data: *mut () );
+pub type CursorHandlerFn = unsafe fn( bw: BrowserWindowImpl, cursor: u8 );
+pub type DestroyHandlerFn = unsafe fn( bw: BrowserWindowImpl );
+/// `result` is the JSON object with the result of the method, or with the error of the method.
+pub type DevToolsCallbackFn = unsafe fn( bw: BrowserWindowImpl, data: *mut (), result: Result<String, String> );
+/// `params` is the JSON object with the parameters of the event.
+pub type DevToolsEventHandlerFn = unsafe fn( bw: BrowserWindowImpl, method: &str, params: &str );
 pub type EvalJsCallbackFn = unsafe fn( bw: BrowserWindowImpl, data: *mut (), result: Result<String, JsEvaluationError> ); 
 pub type ExternalInvocationHandlerFn = unsafe fn( bw: BrowserWindowImpl, cmd: &str, args: Vec<String> );
+/// If `width` and `height` are 0, `data` is a PNG image, otherwise it contains the pixels in the RGBA format.
+pub type FaviconHandlerFn = unsafe fn( bw: BrowserWindowImpl, data: &[u8], width: u32, height: u32 );
+/// `mode` is 0 to open a file, 1 to open multiple files, 2 to open a folder, and 3 to save a file.
+/// `accept` contains a line for every type of file that the page accepts, and is empty if it accepts any file.
+pub type FileChooserHandlerFn = unsafe fn( bw: BrowserWindowImpl, chooser: FileChooserImpl, mode: u8, title: &str, default_path: &str, accept: &str );
+pub type FocusHandlerFn = unsafe fn( bw: BrowserWindowImpl, focused: bool );
+/// `kind` is 0 for a key that is pressed and 1 for a key that is released, and `modifiers` holds the `MODIFIER_*` flags of the keys.
+/// Returns whether the key has been handled, in which case the page doesn't get it.
+pub type KeyHandlerFn = unsafe fn( bw: BrowserWindowImpl, kind: u8, key_code: u16, modifiers: u32, is_repeat: bool ) -> bool;
+/// `error` is the code and the message of the error, if the page couldn't be loaded.
+pub type LoadHandlerFn = unsafe fn( bw: BrowserWindowImpl, error: Option<(u32, String)> );
+/// `progress` goes from 0.0 to 1.0 once the page has loaded.
+pub type LoadingProgressHandlerFn = unsafe fn( bw: BrowserWindowImpl, progress: f64 );
+pub type MenuHandlerFn = unsafe fn( bw: BrowserWindowImpl, item_id: u32, checked: bool );
+pub type MoveHandlerFn = unsafe fn( bw: BrowserWindowImpl, x: i32, y: i32 );
+pub type NavigationHandlerFn = unsafe fn( bw: BrowserWindowImpl, url: &str );
+pub type PaintHandlerFn = unsafe fn( bw: BrowserWindowImpl, is_popup: bool, dirty_rects: &[Rect], pixels: &[u8], width: u32, height: u32 );
+/// Returns `Some` to grant or deny the permission, or `None` to leave it to the browser engine.
+/// This may be invoked from another thread than the GUI thread.
+pub type PermissionHandlerFn = unsafe fn( bw: BrowserWindowImpl, permission: u8, url: &str ) -> Option<bool>;
+/// `reason` is 0 if the render process has exited abnormally, 1 if it has been killed, 2 if it has crashed, and 3 if it has run out of memory.
+pub type ProcessTerminatedHandlerFn = unsafe fn( bw: BrowserWindowImpl, reason: u8 );
+/// `headers` contains a `Name: value` line for every header of the request.
+pub type RequestHandlerFn = unsafe fn( bw: BrowserWindowImpl, request: InterceptedRequestImpl, method: &str, url: &str, headers: &str );
+pub type PopupHandlerFn = unsafe fn( bw: BrowserWindowImpl, rect: Option<Rect> );
+pub type ResizeHandlerFn = unsafe fn( bw: BrowserWindowImpl, width: u32, height: u32 );
+pub type ScaleFactorHandlerFn = unsafe fn( bw: BrowserWindowImpl, scale_factor: f32 );
+/// `headers` contains a `Name: value` line for every header of the request.
+pub type SchemeHandlerFn = unsafe fn( bw: BrowserWindowImpl, request: SchemeRequestImpl, method: &str, url: &str, headers: &str, body: &[u8] );
+/// `text` is empty once there is nothing to show in the status bar anymore.
+pub type StatusTextHandlerFn = unsafe fn( bw: BrowserWindowImpl, text: &str );
+pub type TitleHandlerFn = unsafe fn( bw: BrowserWindowImpl, title: &str );
+pub type UnresponsiveHandlerFn = unsafe fn( bw: BrowserWindowImpl, unresponsive: bool );
+
+/// Information about a frame within a browser window.
+#[derive(Clone, Debug)]
+pub struct FrameInfo {
+	pub id: i64,
+	pub parent_id: Option<i64>,
+	pub name: String,
+	pub url: String,
+	pub is_main: bool
+}
 
 pub trait BrowserWindowExt: Copy {
 
+	/// Calls a method of the Chrome DevTools Protocol, with the JSON object of its parameters.
+	/// The result will be provided by invoking the callback function.
+	fn call_devtools_method( &self, method: &str, params: &str, callback: DevToolsCallbackFn, callback_data: *mut () );
+
+	/// Removes all cookies and stored data of the given origin.
+	/// Returns false if this couldn't be started.
+	fn clear_origin_data( &self, origin: &str ) -> bool;
+
 	fn cookie_jar(&self) -> CookieJarImpl;
 
 	/// Executes the given JavaScript string.
@@ -33,8 +125,28 @@ pub trait BrowserWindowExt: Copy {
 	/// Like `eval_js`, except it can be called from any thread.
 	fn eval_js_threadsafe( &self, js: &str, callback: EvalJsCallbackFn, callback_data: *mut () );
 
+	/// Like `eval_js`, except that the JavaScript is executed within the frame with the given identifier.
+	fn eval_js_in_frame( &self, frame_id: i64, js: &str, callback: EvalJsCallbackFn, callback_data: *mut () );
+
+	/// Executes an editing command in the frame that has the focus, or in the main frame if none has.
+	/// `command` is 0 to undo, 1 to redo, 2 to cut, 3 to copy, 4 to paste, 5 to delete the selection, and 6 to select all.
+	fn exec_command( &self, command: u8 );
+
+	/// Gives information about all frames that are currently in the browser window.
+	fn frames( &self ) -> Vec<FrameInfo>;
+
+	/// The zoom factor of the page, where 1.0 is 100%.
+	fn get_zoom( &self ) -> f64;
+
+	/// Causes an offscreen browser to paint its whole view again.
+	fn invalidate( &self );
+
 	/// Causes the browser to navigate to the given URI.
-	fn navigate( &self, uri: &str );
+	fn navigate( &self, uri: &str ) -> CbwResult<()>;
+
+	/// Causes the frame with the given identifer to navigate to the given URI.
+	/// Returns false if the frame doesn't exist (anymore).
+	fn navigate_frame( &self, frame_id: i64, uri: &str ) -> bool;
 
 	/// Creates a new browser window asynchronously.
 	/// The `BrowserWindowImpl` handle to the new browser window will be passed via a callback.
@@ -67,10 +179,149 @@ pub trait BrowserWindowExt: Copy {
 		callback_data: *mut ()
 	);
 
+	/// Loads the page of the main frame again, which also brings it back after its render process has terminated.
+	/// If `ignore_cache` is set, nothing is taken from the cache.
+	fn reload( &self, ignore_cache: bool );
+
+	/// Registers a key combination like `Ctrl+S`, which invokes the accelerator handler with `accelerator_id` before the page sees the key press.
+	/// Returns false if the key combination isn't valid.
+	fn register_accelerator( &self, accelerator_id: u32, accelerator: &str ) -> bool;
+
+	/// The following methods forward input to offscreen browsers.
+	/// `modifiers` holds the `BW_MODIFIER_*` flags.
+	fn send_focus( &self, focused: bool );
+	/// `kind` is 0 for a key press, 1 for a key release, and 2 for a typed character.
+	fn send_key( &self, kind: u8, key_code: i32, character: u32, modifiers: u32 );
+	fn send_mouse_button( &self, x: i32, y: i32, button: u8, released: bool, click_count: i32, modifiers: u32 );
+	fn send_mouse_move( &self, x: i32, y: i32, leave: bool, modifiers: u32 );
+	fn send_mouse_wheel( &self, x: i32, y: i32, delta_x: i32, delta_y: i32, modifiers: u32 );
+
+	/// Overrides the character encoding of the loaded pages, and reloads the page.
+	/// An empty `charset` removes the override.
+	fn set_charset_override( &self, charset: &str );
+
+	/// Sets a handler that will be invoked when an offscreen browser that uses shared textures has painted a frame.
+	fn set_accelerated_paint_handler( &self, handler: AcceleratedPaintHandlerFn );
+
+	/// Sets a handler that will be invoked whenever one of the registered accelerators gets pressed.
+	fn set_accelerator_handler( &self, handler: AcceleratorHandlerFn );
+
+	/// Sets a handler that will be invoked right before the browser window is freed from memory.
+	/// This is the last moment that the user data can be accessed, so it is a good place to free it.
+	fn set_destroy_handler( &self, handler: DestroyHandlerFn );
+
+	/// Sets a handler that will be invoked for the events of the Chrome DevTools Protocol that have been subscribed to.
+	fn set_devtools_event_handler( &self, handler: DevToolsEventHandlerFn );
+
+	/// Sets the handlers that will be invoked when the window moves or gains or loses the focus, when the main frame navigates to another address, or when the title of the page changes.
+	fn set_event_handlers( &self, on_move: MoveHandlerFn, on_focus: FocusHandlerFn, on_navigate: NavigationHandlerFn, on_title_change: TitleHandlerFn );
+
+	/// Sets a handler that will be invoked whenever the page has got another favicon.
+	fn set_favicon_handler( &self, handler: FaviconHandlerFn );
+
+	/// Sets a handler that will be invoked whenever the page wants the user to choose files, instead of showing the dialog of the browser engine.
+	/// Only set it if the file choosers are answered by the application.
+	fn set_file_chooser_handler( &self, handler: FileChooserHandlerFn );
+
+	/// Sets a handler that will be invoked for every key that is pressed or released while the browser window has focus, before the page gets it.
+	/// It needs to decide right away whether it handles the key.
+	fn set_key_handler( &self, handler: KeyHandlerFn );
+
+	/// Sets a handler that will be invoked whenever the main frame has finished loading, or has failed to load.
+	/// Loads that are interrupted by another navigation are not reported.
+	fn set_load_handler( &self, handler: LoadHandlerFn );
+
+	/// Sets a handler that will be invoked whenever the progress of loading the page changes.
+	fn set_loading_progress_handler( &self, handler: LoadingProgressHandlerFn );
+
+	/// Sets a handler that will be invoked whenever an item of the window's menu bar gets activated.
+	fn set_menu_handler( &self, handler: MenuHandlerFn );
+
+	/// Sets the handlers that will be invoked when an offscreen browser paints a frame, shows or hides a popup widget, or changes its cursor.
+	fn set_offscreen_handlers( &self, on_paint: PaintHandlerFn, on_popup: PopupHandlerFn, on_cursor_change: CursorHandlerFn );
+
+	/// Sets a handler that will be invoked whenever the page asks for a permission, to decide whether it gets it.
+	/// `permission` is 0 for the camera and microphone, 1 for the clipboard, 2 for opening popups, 3 for downloads, and 4 for capturing the screen.
+	fn set_permission_handler( &self, handler: PermissionHandlerFn );
+
+	/// Sets the handlers that will be invoked when the render process of the page has terminated, and when the page stops or starts responding again.
+	fn set_render_process_handlers( &self, on_terminated: ProcessTerminatedHandlerFn, on_unresponsive: UnresponsiveHandlerFn );
+
+	/// Sets a handler that will be invoked for every request of the page that matches one of the given filters, before it is sent.
+	/// `filters` contains a pattern on every line, in which `*` matches any text.
+	/// This should only be done once, right after the browser window has been created.
+	fn set_request_handler( &self, handler: RequestHandlerFn, filters: &str );
+
+	/// Sets a handler that will be invoked whenever the window has been resized, with the new size of its content area.
+	fn set_resize_handler( &self, handler: ResizeHandlerFn );
+
+	/// Sets a handler that will be invoked whenever the scale factor of the window's display changes, or the window moves to a display with another scale factor.
+	fn set_scale_factor_handler( &self, handler: ScaleFactorHandlerFn );
+
+	/// Sets a handler that will be invoked for every request of one of the custom schemes of the application.
+	/// Without one, these requests are answered with status 404.
+	fn set_scheme_handler( &self, handler: SchemeHandlerFn );
+
+	/// Sets a handler that will be invoked whenever the text that browsers show in their status bar changes, like the address of the link under the mouse cursor.
+	fn set_status_text_handler( &self, handler: StatusTextHandlerFn );
+
+	/// Sets the zoom factor of the page, where 1.0 is 100%.
+	fn set_zoom( &self, factor: f64 );
+
+	/// Lets the handler of `set_devtools_event_handler` receive the event of the Chrome DevTools Protocol with the given name.
+	fn subscribe_devtools_event( &self, event: &str );
+
 	fn user_data( &self ) -> *mut ();
 
 	fn url<'a>(&'a self) -> Cow<'a, str>;
 
 	/// Gives a handle to the underlying window.
 	fn window( &self ) -> WindowImpl;
-}
\ No newline at end of file
+}
+
+/// A request of the page to choose files, that is answered with the paths of the chosen files.
+/// It can be answered from any thread.
+pub trait FileChooserExt: Copy {
+
+	/// Answers the file chooser with the given paths, after which it can't be used anymore.
+	/// No paths cancel it, as if the user had closed the dialog.
+	fn select( self, paths: &[&str] );
+}
+
+/// A request of the page that is held back until it has been decided on, which needs to be done on the GUI thread.
+pub trait InterceptedRequestExt: Copy {
+
+	/// Fails the request, as if the network had refused it.
+	fn block( self );
+
+	/// Sends the request to the network, with the given headers instead of its own.
+	fn proceed( self, headers: &str );
+
+	/// Sends the request to the given URL instead.
+	fn redirect( self, url: &str );
+
+	/// Lets the application answer the request itself, with the returned scheme request.
+	fn respond( self ) -> SchemeRequestImpl;
+}
+
+/// A request of a custom scheme.
+/// It is answered by responding with the status and headers first, then writing the body, and finishing it at last.
+/// All of this can be done from any thread.
+pub trait SchemeRequestExt: Copy {
+
+	/// Ends the response with a failure, after which the request can't be used anymore.
+	/// The page sees the request fail, instead of getting a body that has been cut short.
+	fn abort( self );
+
+	/// Ends the response, after which the request can't be used anymore.
+	/// If it hasn't been responded to, it is answered with status 500.
+	fn finish( self );
+
+	/// Gives the status code and the headers of the response, in the same format as the headers of the request.
+	fn respond( &self, status: u16, headers: &str );
+
+	/// Appends data to the body of the response.
+	/// Blocks while the browser engine hasn't caught up with reading the body yet.
+	/// Returns false if the request has been cancelled, in which case the rest of the body isn't needed anymore.
+	fn write( &self, data: &[u8] ) -> bool;
+}
@@ -5,11 +5,14 @@ use std::{
 	ffi::CStr,
 	fmt,
 	mem::MaybeUninit,
-	os::raw::*
+	os::raw::*,
+	ptr,
+	slice
 };
 
 use browser_window_c::*;
 
+use crate::error::CbwError;
 use crate::window::WindowImpl;
 
 
@@ -24,20 +27,74 @@ struct CreationCallbackData {
 	data: *mut ()
 }
 
+struct DevToolsCallbackData {
+	callback: DevToolsCallbackFn,
+	data: *mut ()
+}
+
 struct EvalJsCallbackData {
 	callback: EvalJsCallbackFn,
 	data: *mut ()
 }
 
+#[derive(Clone, Copy)]
+pub struct FileChooserImpl {
+	inner: *mut cbw_FileChooser
+}
+// Answering a file chooser is thread safe
+unsafe impl Send for FileChooserImpl {}
+
+#[derive(Clone, Copy)]
+pub struct InterceptedRequestImpl {
+	inner: *mut cbw_InterceptedRequest
+}
+// Deciding on an intercepted request is thread safe
+unsafe impl Send for InterceptedRequestImpl {}
+
 /// An error that may occur when evaluating or executing JavaScript code.
 #[derive(Debug)]
 pub struct JsEvaluationError {
-	message: String
-	// TODO: Add line and column number files, and perhaps even more info about the JS error
+	column: Option<u32>,
+	line: Option<u32>,
+	message: String,
+	name: Option<String>,
+	stack: Option<String>
+}
+
+#[derive(Clone, Copy)]
+pub struct SchemeRequestImpl {
+	inner: *mut cbw_SchemeRequest
 }
+// The functions of a scheme request are thread safe
+unsafe impl Send for SchemeRequestImpl {}
 
 struct UserData {
 	func: ExternalInvocationHandlerFn,
+	on_accelerated_paint: Option<AcceleratedPaintHandlerFn>,
+	on_accelerator: Option<AcceleratorHandlerFn>,
+	on_destroy: Option<DestroyHandlerFn>,
+	on_devtools_event: Option<DevToolsEventHandlerFn>,
+	on_favicon_changed: Option<FaviconHandlerFn>,
+	on_file_chooser: Option<FileChooserHandlerFn>,
+	on_focus: Option<FocusHandlerFn>,
+	on_key: Option<KeyHandlerFn>,
+	on_load: Option<LoadHandlerFn>,
+	on_loading_progress: Option<LoadingProgressHandlerFn>,
+	on_menu_item: Option<MenuHandlerFn>,
+	on_move: Option<MoveHandlerFn>,
+	on_navigate: Option<NavigationHandlerFn>,
+	on_paint: Option<PaintHandlerFn>,
+	on_permission_request: Option<PermissionHandlerFn>,
+	on_popup: Option<PopupHandlerFn>,
+	on_render_process_terminated: Option<ProcessTerminatedHandlerFn>,
+	on_request: Option<RequestHandlerFn>,
+	on_cursor_change: Option<CursorHandlerFn>,
+	on_resize: Option<ResizeHandlerFn>,
+	on_scale_factor_changed: Option<ScaleFactorHandlerFn>,
+	on_scheme_request: Option<SchemeHandlerFn>,
+	on_status_text: Option<StatusTextHandlerFn>,
+	on_title_change: Option<TitleHandlerFn>,
+	on_unresponsive: Option<UnresponsiveHandlerFn>,
 	data: *mut ()
 }
 
@@ -58,6 +115,21 @@ type UsizeFix = u64;
 
 impl BrowserWindowExt for BrowserWindowImpl {
 
+	fn call_devtools_method( &self, method: &str, params: &str, callback: DevToolsCallbackFn, callback_data: *mut () ) {
+		let data = Box::new( DevToolsCallbackData {
+			callback,
+			data: callback_data
+		} );
+
+		let data_ptr = Box::into_raw( data );
+
+		unsafe { cbw_BrowserWindow_callDevToolsMethod( self.inner, method.into(), params.into(), Some( ffi_devtools_callback_handler ), data_ptr as _ ) }
+	}
+
+	fn clear_origin_data( &self, origin: &str ) -> bool {
+		unsafe { cbw_BrowserWindow_clearOriginData( self.inner, origin.into() ) != 0 }
+	}
+
 	fn cookie_jar(&self) -> CookieJarImpl {
 		let inner = unsafe { cbw_CookieJar_newGlobal() };
 
@@ -88,8 +160,64 @@ impl BrowserWindowExt for BrowserWindowImpl {
 		unsafe { cbw_BrowserWindow_evalJsThreaded( self.inner, js.into(), Some( ffi_eval_js_callback_handler ), data_ptr as _ ) }
 	}
 
-	fn navigate( &self, uri: &str ) {
-		unsafe { cbw_BrowserWindow_navigate( self.inner, uri.into() ) };
+	fn eval_js_in_frame( &self, frame_id: i64, js: &str, callback: EvalJsCallbackFn, callback_data: *mut () ) {
+		let data = Box::new( EvalJsCallbackData {
+			callback,
+			data: callback_data
+		} );
+
+		let data_ptr = Box::into_raw( data );
+
+		unsafe { cbw_BrowserWindow_evalJsInFrame( self.inner, frame_id, js.into(), Some( ffi_eval_js_callback_handler ), data_ptr as _ ) }
+	}
+
+	fn exec_command( &self, command: u8 ) {
+		unsafe { cbw_BrowserWindow_execCommand( self.inner, command ) }
+	}
+
+	fn frames( &self ) -> Vec<FrameInfo> {
+		let mut frames_ptr: *mut cbw_BrowserWindowFrame = ptr::null_mut();
+		let count = unsafe { cbw_BrowserWindow_getFrames( self.inner, &mut frames_ptr ) } as usize;
+		if frames_ptr.is_null() {
+			return Vec::new();
+		}
+
+		let frames = unsafe { slice::from_raw_parts( frames_ptr, count ) }.iter().map(|frame| {
+			let name: &str = frame.name.into();
+			let url: &str = frame.url.into();
+
+			FrameInfo {
+				id: frame.id,
+				parent_id: if frame.parent_id == -1 { None } else { Some( frame.parent_id ) },
+				name: name.to_owned(),
+				url: url.to_owned(),
+				is_main: frame.is_main != 0
+			}
+		}).collect();
+
+		unsafe { cbw_BrowserWindow_freeFrames( frames_ptr, count as _ ) };
+		frames
+	}
+
+	fn get_zoom( &self ) -> f64 {
+		unsafe { cbw_BrowserWindow_getZoom( self.inner ) }
+	}
+
+	fn invalidate( &self ) {
+		unsafe { cbw_BrowserWindow_invalidate( self.inner ) }
+	}
+
+	fn navigate( &self, uri: &str ) -> CbwResult<()> {
+		let c_err = unsafe { cbw_BrowserWindow_navigate( self.inner, uri.into() ) };
+
+		if c_err.code != 0 {
+			return Err( c_err.into() )
+		}
+		Ok(())
+	}
+
+	fn navigate_frame( &self, frame_id: i64, uri: &str ) -> bool {
+		unsafe { cbw_BrowserWindow_navigateFrame( self.inner, frame_id, uri.into() ) != 0 }
 	}
 
 	fn new(
@@ -120,6 +248,31 @@ impl BrowserWindowExt for BrowserWindowImpl {
 		// Wrap the callback functions so that they invoke our Rust functions from C
 		let user_data = Box::new( UserData {
 			func: handler,
+			on_accelerated_paint: None,
+			on_accelerator: None,
+			on_destroy: None,
+			on_devtools_event: None,
+			on_favicon_changed: None,
+			on_file_chooser: None,
+			on_focus: None,
+			on_key: None,
+			on_load: None,
+			on_loading_progress: None,
+			on_menu_item: None,
+			on_move: None,
+			on_navigate: None,
+			on_paint: None,
+			on_permission_request: None,
+			on_popup: None,
+			on_render_process_terminated: None,
+			on_request: None,
+			on_cursor_change: None,
+			on_resize: None,
+			on_scale_factor_changed: None,
+			on_scheme_request: None,
+			on_status_text: None,
+			on_title_change: None,
+			on_unresponsive: None,
 			data: _user_data
 		} );
 		let callback_data = Box::new( CreationCallbackData {
@@ -142,6 +295,220 @@ impl BrowserWindowExt for BrowserWindowImpl {
 		) };
 	}
 
+	fn reload( &self, ignore_cache: bool ) {
+		unsafe { cbw_BrowserWindow_reload( self.inner, ignore_cache as _ ) }
+	}
+
+	fn register_accelerator( &self, accelerator_id: u32, accelerator: &str ) -> bool {
+		unsafe { cbw_BrowserWindow_registerAccelerator( self.inner, accelerator_id, accelerator.into() ) != 0 }
+	}
+
+	fn set_accelerated_paint_handler( &self, handler: AcceleratedPaintHandlerFn ) {
+		let c_user_data_ptr: *mut UserData = unsafe { (*self.inner).user_data as _ };
+
+		unsafe {
+			(*c_user_data_ptr).on_accelerated_paint = Some( handler );
+			cbw_BrowserWindow_setAcceleratedPaintHandler( self.inner, Some( ffi_accelerated_paint_handler ) );
+		}
+	}
+
+	fn set_accelerator_handler( &self, handler: AcceleratorHandlerFn ) {
+		let c_user_data_ptr: *mut UserData = unsafe { (*self.inner).user_data as _ };
+
+		unsafe {
+			(*c_user_data_ptr).on_accelerator = Some( handler );
+			cbw_BrowserWindow_setAcceleratorHandler( self.inner, Some( ffi_accelerator_handler ) );
+		}
+	}
+
+	fn send_focus( &self, focused: bool ) {
+		unsafe { cbw_BrowserWindow_sendFocus( self.inner, focused as _ ) }
+	}
+
+	fn send_key( &self, kind: u8, key_code: i32, character: u32, modifiers: u32 ) {
+		unsafe { cbw_BrowserWindow_sendKey( self.inner, kind, key_code, character, modifiers ) }
+	}
+
+	fn send_mouse_button( &self, x: i32, y: i32, button: u8, released: bool, click_count: i32, modifiers: u32 ) {
+		unsafe { cbw_BrowserWindow_sendMouseButton( self.inner, x, y, button, released as _, click_count, modifiers ) }
+	}
+
+	fn send_mouse_move( &self, x: i32, y: i32, leave: bool, modifiers: u32 ) {
+		unsafe { cbw_BrowserWindow_sendMouseMove( self.inner, x, y, leave as _, modifiers ) }
+	}
+
+	fn send_mouse_wheel( &self, x: i32, y: i32, delta_x: i32, delta_y: i32, modifiers: u32 ) {
+		unsafe { cbw_BrowserWindow_sendMouseWheel( self.inner, x, y, delta_x, delta_y, modifiers ) }
+	}
+
+	fn set_charset_override( &self, charset: &str ) {
+		unsafe { cbw_BrowserWindow_setCharsetOverride( self.inner, charset.into() ) }
+	}
+
+	fn set_destroy_handler( &self, handler: DestroyHandlerFn ) {
+		let c_user_data_ptr: *mut UserData = unsafe { (*self.inner).user_data as _ };
+
+		unsafe { (*c_user_data_ptr).on_destroy = Some( handler ) };
+	}
+
+	fn set_devtools_event_handler( &self, handler: DevToolsEventHandlerFn ) {
+		let c_user_data_ptr: *mut UserData = unsafe { (*self.inner).user_data as _ };
+
+		unsafe {
+			(*c_user_data_ptr).on_devtools_event = Some( handler );
+			cbw_BrowserWindow_setDevToolsEventHandler( self.inner, Some( ffi_devtools_event_handler ) );
+		}
+	}
+
+	fn set_favicon_handler( &self, handler: FaviconHandlerFn ) {
+		let c_user_data_ptr: *mut UserData = unsafe { (*self.inner).user_data as _ };
+
+		unsafe {
+			(*c_user_data_ptr).on_favicon_changed = Some( handler );
+			cbw_BrowserWindow_setFaviconHandler( self.inner, Some( ffi_favicon_handler ) );
+		}
+	}
+
+	fn set_file_chooser_handler( &self, handler: FileChooserHandlerFn ) {
+		let c_user_data_ptr: *mut UserData = unsafe { (*self.inner).user_data as _ };
+
+		unsafe {
+			(*c_user_data_ptr).on_file_chooser = Some( handler );
+			cbw_BrowserWindow_setFileChooserHandler( self.inner, Some( ffi_file_chooser_handler ) );
+		}
+	}
+
+	fn set_key_handler( &self, handler: KeyHandlerFn ) {
+		let c_user_data_ptr: *mut UserData = unsafe { (*self.inner).user_data as _ };
+
+		unsafe {
+			(*c_user_data_ptr).on_key = Some( handler );
+			cbw_BrowserWindow_setKeyHandler( self.inner, Some( ffi_key_handler ) );
+		}
+	}
+
+	fn set_load_handler( &self, handler: LoadHandlerFn ) {
+		let c_user_data_ptr: *mut UserData = unsafe { (*self.inner).user_data as _ };
+
+		unsafe {
+			(*c_user_data_ptr).on_load = Some( handler );
+			cbw_BrowserWindow_setLoadHandler( self.inner, Some( ffi_load_handler ) );
+		}
+	}
+
+	fn set_loading_progress_handler( &self, handler: LoadingProgressHandlerFn ) {
+		let c_user_data_ptr: *mut UserData = unsafe { (*self.inner).user_data as _ };
+
+		unsafe {
+			(*c_user_data_ptr).on_loading_progress = Some( handler );
+			cbw_BrowserWindow_setLoadingProgressHandler( self.inner, Some( ffi_loading_progress_handler ) );
+		}
+	}
+
+	fn set_menu_handler( &self, handler: MenuHandlerFn ) {
+		let c_user_data_ptr: *mut UserData = unsafe { (*self.inner).user_data as _ };
+
+		unsafe {
+			(*c_user_data_ptr).on_menu_item = Some( handler );
+			cbw_Window_setMenuHandler( (*self.inner).window, Some( ffi_menu_handler ), self.inner as _ );
+		}
+	}
+
+	fn set_event_handlers( &self, on_move: MoveHandlerFn, on_focus: FocusHandlerFn, on_navigate: NavigationHandlerFn, on_title_change: TitleHandlerFn ) {
+		let c_user_data_ptr: *mut UserData = unsafe { (*self.inner).user_data as _ };
+
+		unsafe {
+			(*c_user_data_ptr).on_move = Some( on_move );
+			(*c_user_data_ptr).on_focus = Some( on_focus );
+			(*c_user_data_ptr).on_navigate = Some( on_navigate );
+			(*c_user_data_ptr).on_title_change = Some( on_title_change );
+			cbw_BrowserWindow_setEventHandlers( self.inner, Some( ffi_move_handler ), Some( ffi_focus_handler ), Some( ffi_navigation_handler ), Some( ffi_title_handler ) );
+		}
+	}
+
+	fn set_offscreen_handlers( &self, on_paint: PaintHandlerFn, on_popup: PopupHandlerFn, on_cursor_change: CursorHandlerFn ) {
+		let c_user_data_ptr: *mut UserData = unsafe { (*self.inner).user_data as _ };
+
+		unsafe {
+			(*c_user_data_ptr).on_paint = Some( on_paint );
+			(*c_user_data_ptr).on_popup = Some( on_popup );
+			(*c_user_data_ptr).on_cursor_change = Some( on_cursor_change );
+			cbw_BrowserWindow_setOffscreenHandlers( self.inner, Some( ffi_paint_handler ), Some( ffi_popup_handler ), Some( ffi_cursor_handler ) );
+		}
+	}
+
+	fn set_permission_handler( &self, handler: PermissionHandlerFn ) {
+		let c_user_data_ptr: *mut UserData = unsafe { (*self.inner).user_data as _ };
+
+		unsafe {
+			(*c_user_data_ptr).on_permission_request = Some( handler );
+			cbw_BrowserWindow_setPermissionHandler( self.inner, Some( ffi_permission_handler ) );
+		}
+	}
+
+	fn set_render_process_handlers( &self, on_terminated: ProcessTerminatedHandlerFn, on_unresponsive: UnresponsiveHandlerFn ) {
+		let c_user_data_ptr: *mut UserData = unsafe { (*self.inner).user_data as _ };
+
+		unsafe {
+			(*c_user_data_ptr).on_render_process_terminated = Some( on_terminated );
+			(*c_user_data_ptr).on_unresponsive = Some( on_unresponsive );
+			cbw_BrowserWindow_setRenderProcessHandlers( self.inner, Some( ffi_render_process_terminated_handler ), Some( ffi_unresponsive_handler ) );
+		}
+	}
+
+	fn set_request_handler( &self, handler: RequestHandlerFn, filters: &str ) {
+		let c_user_data_ptr: *mut UserData = unsafe { (*self.inner).user_data as _ };
+
+		unsafe {
+			(*c_user_data_ptr).on_request = Some( handler );
+			cbw_BrowserWindow_setRequestHandler( self.inner, Some( ffi_request_handler ), filters.into() );
+		}
+	}
+
+	fn set_resize_handler( &self, handler: ResizeHandlerFn ) {
+		let c_user_data_ptr: *mut UserData = unsafe { (*self.inner).user_data as _ };
+
+		unsafe {
+			(*c_user_data_ptr).on_resize = Some( handler );
+			cbw_BrowserWindow_setResizeHandler( self.inner, Some( ffi_resize_handler ) );
+		}
+	}
+
+	fn set_scale_factor_handler( &self, handler: ScaleFactorHandlerFn ) {
+		let c_user_data_ptr: *mut UserData = unsafe { (*self.inner).user_data as _ };
+
+		unsafe {
+			(*c_user_data_ptr).on_scale_factor_changed = Some( handler );
+			cbw_BrowserWindow_setScaleFactorHandler( self.inner, Some( ffi_scale_factor_handler ) );
+		}
+	}
+
+	fn set_scheme_handler( &self, handler: SchemeHandlerFn ) {
+		let c_user_data_ptr: *mut UserData = unsafe { (*self.inner).user_data as _ };
+
+		unsafe {
+			(*c_user_data_ptr).on_scheme_request = Some( handler );
+			cbw_BrowserWindow_setSchemeHandler( self.inner, Some( ffi_scheme_handler ) );
+		}
+	}
+
+	fn set_status_text_handler( &self, handler: StatusTextHandlerFn ) {
+		let c_user_data_ptr: *mut UserData = unsafe { (*self.inner).user_data as _ };
+
+		unsafe {
+			(*c_user_data_ptr).on_status_text = Some( handler );
+			cbw_BrowserWindow_setStatusTextHandler( self.inner, Some( ffi_status_text_handler ) );
+		}
+	}
+
+	fn set_zoom( &self, factor: f64 ) {
+		unsafe { cbw_BrowserWindow_setZoom( self.inner, factor ) }
+	}
+
+	fn subscribe_devtools_event( &self, event: &str ) {
+		unsafe { cbw_BrowserWindow_subscribeDevToolsEvent( self.inner, event.into() ) }
+	}
+
 	fn user_data( &self ) -> *mut () {
 		let c_user_data_ptr: *mut UserData = unsafe { (*self.inner).user_data as _ };
 
@@ -173,17 +540,106 @@ impl BrowserWindowExt for BrowserWindowImpl {
 
 
 
+impl FileChooserExt for FileChooserImpl {
+
+	fn select( self, paths: &[&str] ) {
+		let path_slices: Vec<cbw_CStrSlice> = paths.iter().map(|p| (*p).into() ).collect();
+
+		unsafe { cbw_FileChooser_select( self.inner, path_slices.as_ptr(), path_slices.len() as _ ) }
+	}
+}
+
+impl InterceptedRequestExt for InterceptedRequestImpl {
+
+	fn block( self ) {
+		unsafe { cbw_InterceptedRequest_block( self.inner ) }
+	}
+
+	fn proceed( self, headers: &str ) {
+		unsafe { cbw_InterceptedRequest_continue( self.inner, headers.into() ) }
+	}
+
+	fn redirect( self, url: &str ) {
+		unsafe { cbw_InterceptedRequest_redirect( self.inner, url.into() ) }
+	}
+
+	fn respond( self ) -> SchemeRequestImpl {
+		SchemeRequestImpl {
+			inner: unsafe { cbw_InterceptedRequest_respond( self.inner ) }
+		}
+	}
+}
+
+impl SchemeRequestExt for SchemeRequestImpl {
+
+	fn abort( self ) {
+		unsafe { cbw_SchemeRequest_abort( self.inner ) }
+	}
+
+	fn finish( self ) {
+		unsafe { cbw_SchemeRequest_finish( self.inner ) }
+	}
+
+	fn respond( &self, status: u16, headers: &str ) {
+		unsafe { cbw_SchemeRequest_respond( self.inner, status, headers.into() ) }
+	}
+
+	fn write( &self, data: &[u8] ) -> bool {
+		unsafe { cbw_SchemeRequest_write( self.inner, data.as_ptr() as _, data.len() as _ ) != 0 }
+	}
+}
+
 impl JsEvaluationError {
 	pub(in super) unsafe fn new( err: *const cbw_Err ) -> Self {
 
+		// Exceptions that have been thrown by the code carry more than just a message
+		let is_exception = (*err).alloc_message.map(|f| f as usize == cbw_Err_msg_jsException as usize ).unwrap_or( false );
+		if is_exception {
+			let exception = (*err).data as *const cbw_JsException;
+			let string = |ptr: *const c_char| -> Option<String> {
+				if ptr.is_null() { return None; }
+				let string: String = CStr::from_ptr( ptr ).to_string_lossy().into();
+				if string.len() == 0 { None } else { Some( string ) }
+			};
+
+			return Self {
+				column: if (*exception).column == 0 { None } else { Some( (*exception).column ) },
+				line: if (*exception).line == 0 { None } else { Some( (*exception).line ) },
+				message: string( (*exception).message ).unwrap_or_default(),
+				name: string( (*exception).name ),
+				stack: string( (*exception).stack )
+			};
+		}
+
 		let msg_ptr = ((*err).alloc_message.unwrap())( (*err).code, (*err).data );
 		let cstr = CStr::from_ptr( msg_ptr );
 		let message: String = cstr.to_string_lossy().into();
 
 		Self {
-			message: message
+			column: None,
+			line: None,
+			message: message,
+			name: None,
+			stack: None
 		}
 	}
+
+	/// The column on the line at which the error has been thrown, starting at 1, if known.
+	pub fn column( &self ) -> Option<u32> { self.column }
+
+	/// The line of the evaluated code at which the error has been thrown, starting at 1, if known.
+	pub fn line( &self ) -> Option<u32> { self.line }
+
+	/// The message of the error, without its name.
+	pub fn message( &self ) -> &str { &self.message }
+
+	/// The name of the error that has been thrown, like `TypeError` or `SyntaxError`.
+	/// This is `None` when the error didn't come from the code itself, or when something other than an `Error` object has been thrown.
+	pub fn name( &self ) -> Option<&str> { self.name.as_ref().map(|s| s.as_str() ) }
+
+	/// The stack trace of the error, as the browser engine formats it.
+	/// CEF doesn't provide it for evaluated code, in which case it is `None`.
+	pub fn stack( &self ) -> Option<&str> { self.stack.as_ref().map(|s| s.as_str() ) }
 }
 
 impl Error for JsEvaluationError {
@@ -194,7 +650,14 @@ impl fmt::Display for JsEvaluationError {
 
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 
-		write!(f, "{}", self.message.as_str())
+		if let Some( name ) = &self.name {
+			write!(f, "{}: ", name)?;
+		}
+		write!(f, "{}", self.message.as_str())?;
+		if let (Some( line ), Some( column )) = (self.line, self.column) {
+			write!(f, " at {}:{}", line, column)?;
+		}
+		Ok(())
 	}
 }
 
@@ -204,6 +667,26 @@ impl fmt::Display for JsEvaluationError {
  * The C handler functions that are invoked by external C code, and that in turn invoke relevant Rust handlers. *
  ****************************************************************************************************************/
 
+unsafe extern "C" fn ffi_accelerated_paint_handler( bw: *mut cbw_BrowserWindow, is_popup: cBOOL, dirty_rects: *const cbw_BrowserWindowRect, dirty_rect_count: UsizeFix, shared_handle: *mut c_void ) {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+
+	if let Some( on_accelerated_paint ) = (*data_ptr).on_accelerated_paint {
+		let rects = if dirty_rect_count == 0 { &[] } else { slice::from_raw_parts( dirty_rects, dirty_rect_count as usize ) };
+
+		on_accelerated_paint( BrowserWindowImpl { inner: bw }, is_popup != 0, rects, shared_handle as _ );
+	}
+}
+
+unsafe extern "C" fn ffi_accelerator_handler( bw: *mut cbw_BrowserWindow, accelerator_id: u32 ) {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+
+	if let Some( on_accelerator ) = (*data_ptr).on_accelerator {
+		on_accelerator( BrowserWindowImpl { inner: bw }, accelerator_id );
+	}
+}
+
 unsafe extern "C" fn ffi_creation_callback_handler( bw: *mut cbw_BrowserWindow, _data: *mut c_void ) {
 
 	let data_ptr = _data as *mut CreationCallbackData;
@@ -211,9 +694,53 @@ unsafe extern "C" fn ffi_creation_callback_handler( bw: *mut cbw_BrowserWindow,
 
 	let handle = BrowserWindowImpl { inner: bw };
 
+	// Our `UserData` needs to be freed when the browser window gets destroyed
+	cbw_BrowserWindow_setDestroyHandler( bw, Some( ffi_destroy_handler ) );
+
 	(data.func)( handle, data.data );
 }
 
+unsafe extern "C" fn ffi_cursor_handler( bw: *mut cbw_BrowserWindow, cursor: u8 ) {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+
+	if let Some( on_cursor_change ) = (*data_ptr).on_cursor_change {
+		on_cursor_change( BrowserWindowImpl { inner: bw }, cursor );
+	}
+}
+
+unsafe extern "C" fn ffi_destroy_handler( bw: *mut cbw_BrowserWindow ) {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+
+	let handle = BrowserWindowImpl { inner: bw };
+
+	if let Some( on_destroy ) = (*data_ptr).on_destroy {
+		on_destroy( handle );
+	}
+
+	(*bw).user_data = std::ptr::null_mut();
+	Box::from_raw( data_ptr );
+}
+
+unsafe extern "C" fn ffi_devtools_callback_handler( bw: *mut cbw_BrowserWindow, _data: *mut c_void, success: cBOOL, result: cbw_CStrSlice ) {
+
+	let data_ptr = _data as *mut DevToolsCallbackData;
+	let data = Box::from_raw( data_ptr );
+
+	let result: String = result.into();
+	(data.callback)( BrowserWindowImpl { inner: bw }, data.data, if success != 0 { Ok( result ) } else { Err( result ) } );
+}
+
+unsafe extern "C" fn ffi_devtools_event_handler( bw: *mut cbw_BrowserWindow, method: cbw_CStrSlice, params: cbw_CStrSlice ) {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+
+	if let Some( on_devtools_event ) = (*data_ptr).on_devtools_event {
+		on_devtools_event( BrowserWindowImpl { inner: bw }, method.into(), params.into() );
+	}
+}
+
 unsafe extern "C" fn ffi_eval_js_callback_handler( bw: *mut cbw_BrowserWindow, _data: *mut c_void, _result: *const c_char, error: *const cbw_Err ) {
 
 	let data_ptr = _data as *mut EvalJsCallbackData;
@@ -224,6 +751,216 @@ unsafe extern "C" fn ffi_eval_js_callback_handler( bw: *mut cbw_BrowserWindow, _
 	(data.callback)( handle, data.data, result );
 }
 
+unsafe extern "C" fn ffi_favicon_handler( bw: *mut cbw_BrowserWindow, data: *const c_void, size: csize_t, width: c_uint, height: c_uint ) {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+
+	if let Some( on_favicon_changed ) = (*data_ptr).on_favicon_changed {
+		let data = slice::from_raw_parts( data as *const u8, size as _ );
+
+		on_favicon_changed( BrowserWindowImpl { inner: bw }, data, width, height );
+	}
+}
+
+unsafe extern "C" fn ffi_file_chooser_handler( bw: *mut cbw_BrowserWindow, chooser: *mut cbw_FileChooser, mode: u8, title: cbw_CStrSlice, default_path: cbw_CStrSlice, accept: cbw_CStrSlice ) {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+	let chooser = FileChooserImpl { inner: chooser };
+
+	match (*data_ptr).on_file_chooser {
+		None => chooser.select( &[] ),
+		Some( on_file_chooser ) => on_file_chooser( BrowserWindowImpl { inner: bw }, chooser, mode, title.into(), default_path.into(), accept.into() )
+	}
+}
+
+unsafe extern "C" fn ffi_focus_handler( bw: *mut cbw_BrowserWindow, focused: cBOOL ) {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+
+	if let Some( on_focus ) = (*data_ptr).on_focus {
+		on_focus( BrowserWindowImpl { inner: bw }, focused != 0 );
+	}
+}
+
+unsafe extern "C" fn ffi_key_handler( bw: *mut cbw_BrowserWindow, kind: u8, key_code: u16, modifiers: u32, is_repeat: cBOOL ) -> cBOOL {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+
+	match (*data_ptr).on_key {
+		None => 0,
+		Some( on_key ) => on_key( BrowserWindowImpl { inner: bw }, kind, key_code, modifiers, is_repeat != 0 ) as _
+	}
+}
+
+unsafe extern "C" fn ffi_menu_handler( _window: *mut cbw_Window, item_id: u32, checked: cBOOL, data: *mut c_void ) {
+
+	let bw = data as *mut cbw_BrowserWindow;
+	let data_ptr = (*bw).user_data as *mut UserData;
+
+	if let Some( on_menu_item ) = (*data_ptr).on_menu_item {
+		on_menu_item( BrowserWindowImpl { inner: bw }, item_id, checked != 0 );
+	}
+}
+
+unsafe extern "C" fn ffi_load_handler( bw: *mut cbw_BrowserWindow, error: *const cbw_Err ) {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+
+	if let Some( on_load ) = (*data_ptr).on_load {
+		// The error is freed by the C code after this call, so only its code and message are taken
+		let error = if error.is_null() { None } else {
+			let error = CbwError::from( *error );
+			Some( (error.code(), error.message()) )
+		};
+
+		on_load( BrowserWindowImpl { inner: bw }, error );
+	}
+}
+
+unsafe extern "C" fn ffi_loading_progress_handler( bw: *mut cbw_BrowserWindow, progress: f64 ) {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+
+	if let Some( on_loading_progress ) = (*data_ptr).on_loading_progress {
+		on_loading_progress( BrowserWindowImpl { inner: bw }, progress );
+	}
+}
+
+unsafe extern "C" fn ffi_move_handler( bw: *mut cbw_BrowserWindow, x: c_int, y: c_int ) {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+
+	if let Some( on_move ) = (*data_ptr).on_move {
+		on_move( BrowserWindowImpl { inner: bw }, x, y );
+	}
+}
+
+unsafe extern "C" fn ffi_navigation_handler( bw: *mut cbw_BrowserWindow, url: cbw_CStrSlice ) {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+
+	if let Some( on_navigate ) = (*data_ptr).on_navigate {
+		on_navigate( BrowserWindowImpl { inner: bw }, url.into() );
+	}
+}
+
+unsafe extern "C" fn ffi_paint_handler( bw: *mut cbw_BrowserWindow, is_popup: cBOOL, dirty_rects: *const cbw_BrowserWindowRect, dirty_rect_count: UsizeFix, pixels: *const c_void, width: c_int, height: c_int ) {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+
+	if let Some( on_paint ) = (*data_ptr).on_paint {
+		let rects = if dirty_rect_count == 0 { &[] } else { slice::from_raw_parts( dirty_rects, dirty_rect_count as usize ) };
+		let pixels = slice::from_raw_parts( pixels as *const u8, width as usize * height as usize * 4 );
+
+		on_paint( BrowserWindowImpl { inner: bw }, is_popup != 0, rects, pixels, width as _, height as _ );
+	}
+}
+
+unsafe extern "C" fn ffi_permission_handler( bw: *mut cbw_BrowserWindow, permission: u8, url: cbw_CStrSlice ) -> c_int {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+
+	match (*data_ptr).on_permission_request {
+		None => -1,
+		Some( on_permission_request ) => match on_permission_request( BrowserWindowImpl { inner: bw }, permission, url.into() ) {
+			None => -1,
+			Some( granted ) => if granted {1} else {0}
+		}
+	}
+}
+
+unsafe extern "C" fn ffi_popup_handler( bw: *mut cbw_BrowserWindow, rect: *const cbw_BrowserWindowRect ) {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+
+	if let Some( on_popup ) = (*data_ptr).on_popup {
+		on_popup( BrowserWindowImpl { inner: bw }, if rect.is_null() { None } else { Some( *rect ) } );
+	}
+}
+
+unsafe extern "C" fn ffi_render_process_terminated_handler( bw: *mut cbw_BrowserWindow, reason: u8 ) {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+
+	if let Some( on_render_process_terminated ) = (*data_ptr).on_render_process_terminated {
+		on_render_process_terminated( BrowserWindowImpl { inner: bw }, reason );
+	}
+}
+
+unsafe extern "C" fn ffi_request_handler( bw: *mut cbw_BrowserWindow, request: *mut cbw_InterceptedRequest, method: cbw_CStrSlice, url: cbw_CStrSlice, headers: cbw_CStrSlice ) {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+	let request = InterceptedRequestImpl { inner: request };
+
+	match (*data_ptr).on_request {
+		None => request.proceed( headers.into() ),
+		Some( on_request ) => on_request( BrowserWindowImpl { inner: bw }, request, method.into(), url.into(), headers.into() )
+	}
+}
+
+unsafe extern "C" fn ffi_resize_handler( bw: *mut cbw_BrowserWindow, width: c_uint, height: c_uint ) {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+
+	if let Some( on_resize ) = (*data_ptr).on_resize {
+		on_resize( BrowserWindowImpl { inner: bw }, width as _, height as _ );
+	}
+}
+
+unsafe extern "C" fn ffi_scale_factor_handler( bw: *mut cbw_BrowserWindow, scale_factor: f32 ) {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+
+	if let Some( on_scale_factor_changed ) = (*data_ptr).on_scale_factor_changed {
+		on_scale_factor_changed( BrowserWindowImpl { inner: bw }, scale_factor );
+	}
+}
+
+unsafe extern "C" fn ffi_scheme_handler( bw: *mut cbw_BrowserWindow, request: *mut cbw_SchemeRequest, method: cbw_CStrSlice, url: cbw_CStrSlice, headers: cbw_CStrSlice, body: cbw_CStrSlice ) {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+	let request = SchemeRequestImpl { inner: request };
+
+	match (*data_ptr).on_scheme_request {
+		None => {
+			request.respond( 404, "" );
+			request.finish();
+		},
+		Some( on_scheme_request ) => {
+			let body = if body.len == 0 { &[] } else { slice::from_raw_parts( body.data as *const u8, body.len as usize ) };
+
+			on_scheme_request( BrowserWindowImpl { inner: bw }, request, method.into(), url.into(), headers.into(), body );
+		}
+	}
+}
+
+unsafe extern "C" fn ffi_status_text_handler( bw: *mut cbw_BrowserWindow, text: cbw_CStrSlice ) {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+
+	if let Some( on_status_text ) = (*data_ptr).on_status_text {
+		on_status_text( BrowserWindowImpl { inner: bw }, text.into() );
+	}
+}
+
+unsafe extern "C" fn ffi_title_handler( bw: *mut cbw_BrowserWindow, title: cbw_CStrSlice ) {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+
+	if let Some( on_title_change ) = (*data_ptr).on_title_change {
+		on_title_change( BrowserWindowImpl { inner: bw }, title.into() );
+	}
+}
+
+unsafe extern "C" fn ffi_unresponsive_handler( bw: *mut cbw_BrowserWindow, unresponsive: cBOOL ) {
+
+	let data_ptr = (*bw).user_data as *mut UserData;
+
+	if let Some( on_unresponsive ) = (*data_ptr).on_unresponsive {
+		on_unresponsive( BrowserWindowImpl { inner: bw }, unresponsive != 0 );
+	}
+}
+
 unsafe extern "C" fn ffi_handler( bw: *mut cbw_BrowserWindow, cmd: cbw_CStrSlice, args: *mut cbw_CStrSlice, arg_count: UsizeFix ) {
 
 	let handle = BrowserWindowImpl { inner: bw };
@@ -0,0 +1,803 @@
+//! This module implements the `BrowserWindow` trait without a browser engine, for feature `mock`.
+//! The page is a model without a DOM: it keeps the address, records the navigations, the JavaScript that has been evaluated and the DevTools methods that have been called, and gives the results that handlers decide on.
+//! The handlers are invoked from the event loop of the application, like they are with the browser engines.
+//! The state of a browser window is never borrowed while a handler runs, so that handlers can use the browser window themselves.
+
+use super::*;
+
+use crate::window::{WindowExt, WindowImpl};
+use crate::application::ApplicationExt;
+
+use std::{
+	cell::{RefCell, RefMut},
+	collections::VecDeque,
+	error::Error,
+	fmt
+};
+
+
+
+/// Decides the result of a method of the DevTools protocol that is called for the page, see `BrowserWindowImpl::set_devtools_handler`.
+pub type DevToolsHandler = Box<dyn FnMut( &str, &str ) -> Result<String, String>>;
+/// Decides the result of the JavaScript code that is evaluated in the page, see `BrowserWindowImpl::set_eval_handler`.
+pub type EvalHandler = Box<dyn FnMut( &str ) -> Result<String, String>>;
+
+#[derive(Clone,Copy)]
+pub struct BrowserWindowImpl {
+	inner: *mut RefCell<MockBrowserWindow>
+}
+
+/// A file chooser of the page, that records the paths that it is answered with.
+#[derive(Clone, Copy)]
+pub struct FileChooserImpl {
+	bw: BrowserWindowImpl
+}
+// The mock backend only runs on the GUI thread anyway
+unsafe impl Send for FileChooserImpl {}
+
+/// An intercepted request of the page, that records the request that it is sent on as.
+#[derive(Clone, Copy)]
+pub struct InterceptedRequestImpl {
+	bw: BrowserWindowImpl,
+	/// The index of the request in `Page::requests`.
+	index: usize
+}
+// The mock backend only runs on the GUI thread anyway
+unsafe impl Send for InterceptedRequestImpl {}
+
+/// An error that may occur when evaluating or executing JavaScript code.
+#[derive(Debug)]
+pub struct JsEvaluationError {
+	message: String
+}
+
+/// The answer to an intercepted request, which the mock backend has no page to give to.
+#[derive(Clone, Copy)]
+pub struct SchemeRequestImpl {
+	_private: ()
+}
+
+struct MockBrowserWindow {
+	data: *mut (),
+	/// The evaluations of JavaScript of which the result hasn't been given back yet.
+	evaluations: VecDeque<(EvalJsCallbackFn, *mut (), Result<String, JsEvaluationError>)>,
+	func: ExternalInvocationHandlerFn,
+	on_destroy: Option<DestroyHandlerFn>,
+	on_favicon_changed: Option<FaviconHandlerFn>,
+	on_file_chooser: Option<FileChooserHandlerFn>,
+	on_key: Option<KeyHandlerFn>,
+	on_load: Option<LoadHandlerFn>,
+	on_loading_progress: Option<LoadingProgressHandlerFn>,
+	on_move: Option<MoveHandlerFn>,
+	on_navigate: Option<NavigationHandlerFn>,
+	on_render_process_terminated: Option<ProcessTerminatedHandlerFn>,
+	on_request: Option<RequestHandlerFn>,
+	on_resize: Option<ResizeHandlerFn>,
+	on_status_text: Option<StatusTextHandlerFn>,
+	on_title_change: Option<TitleHandlerFn>,
+	on_unresponsive: Option<UnresponsiveHandlerFn>,
+	page: Page,
+	window: WindowImpl
+}
+
+struct Page {
+	/// The paths that the file choosers of the page have been answered with, in order.
+	chosen_files: Vec<Vec<String>>,
+	/// The editing commands that have been executed in the page, in order.
+	commands: Vec<u8>,
+	/// The methods of the DevTools protocol that have been called, with their parameters, in order.
+	devtools_calls: Vec<(String, String)>,
+	devtools_handler: Option<DevToolsHandler>,
+	eval_handler: Option<EvalHandler>,
+	evaluated: Vec<String>,
+	/// The virtual key codes of the keys that the page has got, because the key handler hasn't handled them, in order.
+	keys: Vec<u16>,
+	navigations: Vec<String>,
+	/// The number of times that the page has been reloaded.
+	reloads: usize,
+	/// The requests that have been intercepted, with their method, URL and headers.
+	requests: Vec<(String, String, String)>,
+	/// The requests that have been sent to the network, with their method, URL and headers, in order.
+	sent_requests: Vec<(String, String, String)>,
+	url: String,
+	zoom: f64
+}
+
+/// The ID of the only frame that the page has.
+const MAIN_FRAME_ID: i64 = 1;
+
+
+
+thread_local! {
+	/// The browser windows that haven't been destroyed yet, so that work that comes in for the others can be ignored.
+	static ALIVE: RefCell<Vec<usize>> = const { RefCell::new( Vec::new() ) };
+}
+
+
+
+impl BrowserWindowImpl {
+
+	/// Sets the handler that decides the results of the methods of the DevTools protocol that are called for the page.
+	/// It is given the method and the JSON of its parameters, and returns the JSON of the result, or the error as the browser engine would give it.
+	/// Without one, every method fails, as if the browser engine had no DevTools.
+	pub fn set_devtools_handler( &self, handler: DevToolsHandler ) {
+		self.state().page.devtools_handler = Some( handler );
+	}
+
+	/// All the methods of the DevTools protocol that have been called for the page, with the JSON of their parameters, in order.
+	pub fn devtools_calls( &self ) -> Vec<(String, String)> {
+		self.state().page.devtools_calls.clone()
+	}
+
+	/// Sets the handler that decides the result of the JavaScript code that is evaluated in the page.
+	/// It is given the code, and returns the result as the browser engine would give it, like `true` or `"text"`, or the message of an error.
+	/// Without one, everything evaluates to `undefined`.
+	pub fn set_eval_handler( &self, handler: EvalHandler ) {
+		self.state().page.eval_handler = Some( handler );
+	}
+
+	/// All the JavaScript code that has been evaluated in the page, in order.
+	pub fn evaluated( &self ) -> Vec<String> {
+		self.state().page.evaluated.clone()
+	}
+
+	/// Invokes the handler of the browser window, as if the page had called `invoke_extern` with the given command and arguments.
+	pub fn invoke( &self, cmd: &str, args: Vec<String> ) {
+		let cmd = cmd.to_owned();
+
+		self.later( move |bw| {
+			let func = bw.state().func;
+			unsafe { func( bw, &cmd, args ) }
+		} );
+	}
+
+	/// All the addresses that the page has navigated to, in order.
+	/// This starts with the address that the browser window has been created with, if it hasn't been created with HTML.
+	pub fn navigations( &self ) -> Vec<String> {
+		self.state().page.navigations.clone()
+	}
+
+	/// The paths that the file choosers of the page have been answered with, in order.
+	/// A file chooser that has been cancelled has no paths.
+	pub fn chosen_files( &self ) -> Vec<Vec<String>> {
+		self.state().page.chosen_files.clone()
+	}
+
+	/// The editing commands that have been executed in the page, in order.
+	pub fn commands( &self ) -> Vec<u8> {
+		self.state().page.commands.clone()
+	}
+
+	/// Opens a file chooser, as if an `<input type="file">` of the page had been clicked.
+	/// If there is no file chooser handler, it is cancelled right away, as there is no dialog to show.
+	pub fn open_file_chooser( &self, mode: u8, accept: &str ) {
+		let accept = accept.to_owned();
+
+		self.later( move |bw| {
+			let on_file_chooser = bw.state().on_file_chooser;
+			match on_file_chooser {
+				None => bw.state().page.chosen_files.push( Vec::new() ),
+				Some( on_file_chooser ) => unsafe { on_file_chooser( bw, FileChooserImpl { bw }, mode, "", "", &accept ) }
+			}
+		} );
+	}
+
+	/// The virtual key codes of the keys that the page has got, because the key handler hasn't handled them, in order.
+	/// Only the key presses are recorded.
+	pub fn keys( &self ) -> Vec<u16> {
+		self.state().page.keys.clone()
+	}
+
+	/// Presses and releases the given key, as if the user had typed it while the browser window had focus.
+	/// Both are given to the key handler first, and the page only gets the press if the handler hasn't handled it.
+	pub fn press_key( &self, key_code: u16, modifiers: u32 ) {
+		self.later( move |bw| {
+			for kind in 0..2 {
+				let on_key = bw.state().on_key;
+				let handled = match on_key {
+					None => false,
+					Some( on_key ) => unsafe { on_key( bw, kind, key_code, modifiers, false ) }
+				};
+
+				if kind == 0 && !handled {
+					bw.state().page.keys.push( key_code );
+				}
+			}
+		} );
+	}
+
+	/// The number of times that the page has been reloaded.
+	pub fn reloads( &self ) -> usize {
+		self.state().page.reloads
+	}
+
+	/// Lets the page make a request, which is given to the request handler if there is one, and otherwise sent to the network right away.
+	/// `headers` contains a `Name: value` line for every header.
+	pub fn request( &self, method: &str, url: &str, headers: &str ) {
+		let request = (method.to_owned(), url.to_owned(), headers.to_owned());
+
+		self.later( move |bw| {
+			let on_request = bw.state().on_request;
+			match on_request {
+				None => bw.state().page.sent_requests.push( request ),
+				Some( on_request ) => {
+					let index = {
+						let requests = &mut bw.state().page.requests;
+						requests.push( request.clone() );
+						requests.len() - 1
+					};
+
+					unsafe { on_request( bw, InterceptedRequestImpl { bw, index }, &request.0, &request.1, &request.2 ) }
+				}
+			}
+		} );
+	}
+
+	/// The requests that have been sent to the network, with their method, URL and headers, in order.
+	/// Requests that have been blocked or answered by the request handler are not among them.
+	pub fn sent_requests( &self ) -> Vec<(String, String, String)> {
+		self.state().page.sent_requests.clone()
+	}
+
+	/// Lets the render process of the page terminate for the given reason, as if it had crashed.
+	/// The page stays as it is, as the mock backend has nothing to make blank.
+	pub fn terminate_render_process( &self, reason: u8 ) {
+		self.later( move |bw| {
+			let on_render_process_terminated = bw.state().on_render_process_terminated;
+			if let Some( on_render_process_terminated ) = on_render_process_terminated {
+				unsafe { on_render_process_terminated( bw, reason ) }
+			}
+		} );
+	}
+
+	/// Lets the page stop or start responding again, as if its JavaScript had got stuck in a loop or had come out of it.
+	pub fn set_page_unresponsive( &self, unresponsive: bool ) {
+		self.later( move |bw| {
+			let on_unresponsive = bw.state().on_unresponsive;
+			if let Some( on_unresponsive ) = on_unresponsive {
+				unsafe { on_unresponsive( bw, unresponsive ) }
+			}
+		} );
+	}
+
+	/// Changes the favicon of the page, as if the browser engine had downloaded another one.
+	/// If `width` and `height` are 0, `data` is a PNG image, otherwise it contains the pixels in the RGBA format.
+	pub fn set_page_favicon( &self, data: &[u8], width: u32, height: u32 ) {
+		let data = data.to_vec();
+
+		self.later( move |bw| {
+			let on_favicon_changed = bw.state().on_favicon_changed;
+			if let Some( on_favicon_changed ) = on_favicon_changed {
+				unsafe { on_favicon_changed( bw, &data, width, height ) }
+			}
+		} );
+	}
+
+	/// Changes the text of the status bar, as if the mouse cursor had moved onto or off a link.
+	pub fn set_page_status_text( &self, text: &str ) {
+		let text = text.to_owned();
+
+		self.later( move |bw| {
+			let on_status_text = bw.state().on_status_text;
+			if let Some( on_status_text ) = on_status_text {
+				unsafe { on_status_text( bw, &text ) }
+			}
+		} );
+	}
+
+	/// Changes the title of the page, as if the page had set `document.title`.
+	pub fn set_page_title( &self, title: &str ) {
+		let title = title.to_owned();
+
+		self.later( move |bw| {
+			let on_title_change = bw.state().on_title_change;
+			if let Some( on_title_change ) = on_title_change {
+				unsafe { on_title_change( bw, &title ) }
+			}
+		} );
+	}
+
+	/// Lets the browser window know that its window has been moved.
+	pub(in crate) fn moved( &self, x: i32, y: i32 ) {
+		self.later( move |bw| {
+			let on_move = bw.state().on_move;
+			if let Some( on_move ) = on_move {
+				unsafe { on_move( bw, x, y ) }
+			}
+		} );
+	}
+
+	/// Lets the browser window know that its window has been resized.
+	pub(in crate) fn resized( &self, width: u32, height: u32 ) {
+		self.later( move |bw| {
+			let on_resize = bw.state().on_resize;
+			if let Some( on_resize ) = on_resize {
+				unsafe { on_resize( bw, width, height ) }
+			}
+		} );
+	}
+
+	/// Fails the evaluations that are still waiting for their result, invokes the destroy handler, and frees the browser window.
+	/// Is called when its window is destroyed.
+	pub(in crate) fn cleanup( &self ) {
+		ALIVE.with(|alive| alive.borrow_mut().retain(|bw| *bw != self.inner as usize ) );
+
+		loop {
+			let evaluation = self.state().evaluations.pop_front();
+			let (callback, data, _) = match evaluation {
+				None => break,
+				Some( evaluation ) => evaluation
+			};
+
+			let error = JsEvaluationError::new( "The browser window has been destroyed".to_owned() );
+			unsafe { callback( *self, data, Err( error ) ) };
+		}
+
+		let on_destroy = self.state().on_destroy;
+		if let Some( on_destroy ) = on_destroy {
+			unsafe { on_destroy( *self ) };
+		}
+
+		unsafe { drop( Box::from_raw( self.inner ) ) };
+	}
+
+	/// Runs `work` on the event loop of the application, if the browser window still exists by then.
+	fn later<W>( &self, work: W ) where
+		W: FnOnce( BrowserWindowImpl ) + 'static
+	{
+		let data: Box<(BrowserWindowImpl, Box<dyn FnOnce( BrowserWindowImpl )>)> = Box::new( (*self, Box::new( work )) );
+
+		self.window().app().dispatch( later_handler, Box::into_raw( data ) as _ );
+	}
+
+	/// Evaluates the JavaScript code with the eval handler, and gives the result back later.
+	fn evaluate( &self, js: &str, callback: EvalJsCallbackFn, callback_data: *mut () ) {
+		self.state().page.evaluated.push( js.to_owned() );
+
+		// The handler is taken out while it runs, so that it can use the page as well
+		let handler = self.state().page.eval_handler.take();
+		let result = match handler {
+			None => Ok( "undefined".to_owned() ),
+			Some( mut handler ) => {
+				let result = handler( js );
+				self.state().page.eval_handler.get_or_insert( handler );
+				result
+			}
+		};
+
+		self.give_result( callback, callback_data, result.map_err( JsEvaluationError::new ) );
+	}
+
+	/// Gives the result of an evaluation back to its callback later, or fails it if the browser window is destroyed before that.
+	fn give_result( &self, callback: EvalJsCallbackFn, callback_data: *mut (), result: Result<String, JsEvaluationError> ) {
+		self.state().evaluations.push_back( (callback, callback_data, result) );
+		self.later(|bw| {
+			let evaluation = bw.state().evaluations.pop_front();
+			if let Some( (callback, data, result) ) = evaluation {
+				unsafe { callback( bw, data, result ) };
+			}
+		} );
+	}
+
+	fn is_alive( &self ) -> bool {
+		ALIVE.with(|alive| alive.borrow().contains( &(self.inner as usize) ) )
+	}
+
+	/// Reports that the page has loaded, which it does right away as there is nothing to load.
+	fn loaded( &self ) {
+		let on_loading_progress = self.state().on_loading_progress;
+		if let Some( on_loading_progress ) = on_loading_progress {
+			unsafe { on_loading_progress( *self, 1.0 ) }
+		}
+		let on_load = self.state().on_load;
+		if let Some( on_load ) = on_load {
+			unsafe { on_load( *self, None ) }
+		}
+	}
+
+	/// Borrows the state of the browser window, which should be released before any handler is invoked.
+	fn state( &self ) -> RefMut<'_, MockBrowserWindow> {
+		unsafe { (*self.inner).borrow_mut() }
+	}
+}
+
+impl BrowserWindowExt for BrowserWindowImpl {
+
+	fn call_devtools_method( &self, method: &str, params: &str, callback: DevToolsCallbackFn, callback_data: *mut () ) {
+		self.state().page.devtools_calls.push( (method.to_owned(), params.to_owned()) );
+
+		// The handler is taken out while it runs, so that it can use the page as well
+		let handler = self.state().page.devtools_handler.take();
+		let result = match handler {
+			None => Err( r#"{"message":"The mock backend has no DevTools"}"#.to_owned() ),
+			Some( mut handler ) => {
+				let result = handler( method, params );
+				self.state().page.devtools_handler.get_or_insert( handler );
+				result
+			}
+		};
+
+		let data = Box::new( (*self, callback, callback_data, result) );
+		self.window().app().dispatch( devtools_result_handler, Box::into_raw( data ) as _ );
+	}
+
+	fn clear_origin_data( &self, _origin: &str ) -> bool {
+		true
+	}
+
+	fn cookie_jar( &self ) -> CookieJarImpl {
+		CookieJarImpl {}
+	}
+
+	fn eval_js( &self, js: &str, callback: EvalJsCallbackFn, callback_data: *mut () ) {
+		self.evaluate( js, callback, callback_data );
+	}
+
+	fn eval_js_threadsafe( &self, js: &str, callback: EvalJsCallbackFn, callback_data: *mut () ) {
+		let data = Box::new( (*self, js.to_owned(), callback, callback_data) );
+
+		// The eval handler can only be used on the GUI thread
+		self.window().app().dispatch( eval_js_threadsafe_handler, Box::into_raw( data ) as _ );
+	}
+
+	fn eval_js_in_frame( &self, frame_id: i64, js: &str, callback: EvalJsCallbackFn, callback_data: *mut () ) {
+		if frame_id == MAIN_FRAME_ID {
+			self.evaluate( js, callback, callback_data );
+		}
+		else {
+			self.give_result( callback, callback_data, Err( JsEvaluationError::new( "The frame doesn't exist".to_owned() ) ) );
+		}
+	}
+
+	fn exec_command( &self, command: u8 ) {
+		self.state().page.commands.push( command );
+	}
+
+	fn frames( &self ) -> Vec<FrameInfo> {
+		vec![ FrameInfo {
+			id: MAIN_FRAME_ID,
+			parent_id: None,
+			name: String::new(),
+			url: self.state().page.url.clone(),
+			is_main: true
+		} ]
+	}
+
+	fn get_zoom( &self ) -> f64 {
+		self.state().page.zoom
+	}
+
+	fn invalidate( &self ) {}
+
+	fn navigate( &self, uri: &str ) -> CbwResult<()> {
+		{
+			let page = &mut self.state().page;
+			page.navigations.push( uri.to_owned() );
+			page.url = uri.to_owned();
+		}
+
+		let url = uri.to_owned();
+		// There is nothing to load, so the page has loaded right after it has navigated
+		self.later( move |bw| {
+			let on_navigate = bw.state().on_navigate;
+			if let Some( on_navigate ) = on_navigate {
+				unsafe { on_navigate( bw, &url ) }
+			}
+			bw.loaded();
+		} );
+		Ok(())
+	}
+
+	fn navigate_frame( &self, frame_id: i64, uri: &str ) -> bool {
+		if frame_id != MAIN_FRAME_ID {
+			return false;
+		}
+
+		self.navigate( uri ).is_ok()
+	}
+
+	fn new(
+		app: ApplicationImpl,
+		parent: WindowImpl,
+		source: Source,
+		title: &str,
+		width: Option<u32>,
+		height: Option<u32>,
+		window_options: &WindowOptions,
+		_browser_window_options: &BrowserWindowOptions,
+		handler: ExternalInvocationHandlerFn,
+		user_data: *mut (),
+		creation_callback: CreationCallbackFn,
+		callback_data: *mut ()
+	) {
+		let window = WindowImpl::new( app, parent, title, width, height, window_options, user_data );
+		let source_data: &str = source.data.into();
+		let is_html = source.is_html != 0;
+
+		let bw = Box::new( RefCell::new( MockBrowserWindow {
+			data: user_data,
+			evaluations: VecDeque::new(),
+			func: handler,
+			on_destroy: None,
+			on_favicon_changed: None,
+			on_file_chooser: None,
+			on_key: None,
+			on_load: None,
+			on_loading_progress: None,
+			on_move: None,
+			on_navigate: None,
+			on_render_process_terminated: None,
+			on_request: None,
+			on_resize: None,
+			on_status_text: None,
+			on_title_change: None,
+			on_unresponsive: None,
+			page: Page {
+				chosen_files: Vec::new(),
+				commands: Vec::new(),
+				devtools_calls: Vec::new(),
+				devtools_handler: None,
+				eval_handler: None,
+				evaluated: Vec::new(),
+				keys: Vec::new(),
+				navigations: Vec::new(),
+				reloads: 0,
+				requests: Vec::new(),
+				sent_requests: Vec::new(),
+				url: if is_html { "about:blank".to_owned() } else { String::new() },
+				zoom: 1.0
+			},
+			window
+		} ) );
+		let handle = Self { inner: Box::into_raw( bw ) };
+
+		window.state().browser = Some( handle );
+		ALIVE.with(|alive| alive.borrow_mut().push( handle.inner as usize ) );
+
+		// Like the browser engines do, the browser window is given back before anything gets loaded
+		let data = Box::new( (handle, creation_callback, callback_data) );
+		app.dispatch( created_handler, Box::into_raw( data ) as _ );
+		if !is_html {
+			let _ = handle.navigate( source_data );
+		}
+		else {
+			handle.later(|bw| bw.loaded() );
+		}
+	}
+
+	fn reload( &self, _ignore_cache: bool ) {
+		self.state().page.reloads += 1;
+
+		self.later(|bw| bw.loaded() );
+	}
+
+	fn register_accelerator( &self, _accelerator_id: u32, accelerator: &str ) -> bool {
+		!accelerator.is_empty()
+	}
+
+	fn send_focus( &self, _focused: bool ) {}
+
+	fn send_key( &self, _kind: u8, _key_code: i32, _character: u32, _modifiers: u32 ) {}
+
+	fn send_mouse_button( &self, _x: i32, _y: i32, _button: u8, _released: bool, _click_count: i32, _modifiers: u32 ) {}
+
+	fn send_mouse_move( &self, _x: i32, _y: i32, _leave: bool, _modifiers: u32 ) {}
+
+	fn send_mouse_wheel( &self, _x: i32, _y: i32, _delta_x: i32, _delta_y: i32, _modifiers: u32 ) {}
+
+	fn set_accelerated_paint_handler( &self, _handler: AcceleratedPaintHandlerFn ) {}
+
+	fn set_accelerator_handler( &self, _handler: AcceleratorHandlerFn ) {}
+
+	fn set_charset_override( &self, _charset: &str ) {}
+
+	fn set_destroy_handler( &self, handler: DestroyHandlerFn ) {
+		self.state().on_destroy = Some( handler );
+	}
+
+	fn set_devtools_event_handler( &self, _handler: DevToolsEventHandlerFn ) {}
+
+	fn set_event_handlers( &self, on_move: MoveHandlerFn, _on_focus: FocusHandlerFn, on_navigate: NavigationHandlerFn, on_title_change: TitleHandlerFn ) {
+		let mut state = self.state();
+
+		state.on_move = Some( on_move );
+		state.on_navigate = Some( on_navigate );
+		state.on_title_change = Some( on_title_change );
+	}
+
+	fn set_favicon_handler( &self, handler: FaviconHandlerFn ) {
+		self.state().on_favicon_changed = Some( handler );
+	}
+
+	fn set_file_chooser_handler( &self, handler: FileChooserHandlerFn ) {
+		self.state().on_file_chooser = Some( handler );
+	}
+
+	fn set_key_handler( &self, handler: KeyHandlerFn ) {
+		self.state().on_key = Some( handler );
+	}
+
+	fn set_load_handler( &self, handler: LoadHandlerFn ) {
+		self.state().on_load = Some( handler );
+	}
+
+	fn set_loading_progress_handler( &self, handler: LoadingProgressHandlerFn ) {
+		self.state().on_loading_progress = Some( handler );
+	}
+
+	fn set_menu_handler( &self, _handler: MenuHandlerFn ) {}
+
+	fn set_offscreen_handlers( &self, _on_paint: PaintHandlerFn, _on_popup: PopupHandlerFn, _on_cursor_change: CursorHandlerFn ) {}
+
+	fn set_permission_handler( &self, _handler: PermissionHandlerFn ) {}
+
+	fn set_render_process_handlers( &self, on_terminated: ProcessTerminatedHandlerFn, on_unresponsive: UnresponsiveHandlerFn ) {
+		let mut state = self.state();
+
+		state.on_render_process_terminated = Some( on_terminated );
+		state.on_unresponsive = Some( on_unresponsive );
+	}
+
+	fn set_request_handler( &self, handler: RequestHandlerFn, _filters: &str ) {
+		// The handler checks the filters again anyway, so it can be given every request
+		self.state().on_request = Some( handler );
+	}
+
+	fn set_resize_handler( &self, handler: ResizeHandlerFn ) {
+		self.state().on_resize = Some( handler );
+	}
+
+	fn set_scale_factor_handler( &self, _handler: ScaleFactorHandlerFn ) {}
+
+	fn set_scheme_handler( &self, _handler: SchemeHandlerFn ) {}
+
+	fn set_status_text_handler( &self, handler: StatusTextHandlerFn ) {
+		self.state().on_status_text = Some( handler );
+	}
+
+	fn set_zoom( &self, factor: f64 ) {
+		self.state().page.zoom = factor;
+	}
+
+	fn subscribe_devtools_event( &self, _event: &str ) {}
+
+	fn user_data( &self ) -> *mut () {
+		self.state().data
+	}
+
+	fn url<'a>(&'a self) -> Cow<'a, str> {
+		self.state().page.url.clone().into()
+	}
+
+	fn window( &self ) -> WindowImpl {
+		self.state().window
+	}
+}
+
+impl FileChooserExt for FileChooserImpl {
+
+	fn select( self, paths: &[&str] ) {
+		if self.bw.is_alive() {
+			self.bw.state().page.chosen_files.push( paths.iter().map(|p| (*p).to_owned() ).collect() );
+		}
+	}
+}
+
+impl InterceptedRequestExt for InterceptedRequestImpl {
+
+	fn block( self ) {}
+
+	fn proceed( self, headers: &str ) {
+		if self.bw.is_alive() {
+			let mut state = self.bw.state();
+			let page = &mut state.page;
+			let (method, url, _) = page.requests[ self.index ].clone();
+			page.sent_requests.push( (method, url, headers.to_owned()) );
+		}
+	}
+
+	fn redirect( self, url: &str ) {
+		if self.bw.is_alive() {
+			let mut state = self.bw.state();
+			let page = &mut state.page;
+			let (method, _, headers) = page.requests[ self.index ].clone();
+			page.sent_requests.push( (method, url.to_owned(), headers) );
+		}
+	}
+
+	fn respond( self ) -> SchemeRequestImpl {
+		SchemeRequestImpl { _private: () }
+	}
+}
+
+impl SchemeRequestExt for SchemeRequestImpl {
+
+	fn abort( self ) {}
+
+	fn finish( self ) {}
+
+	fn respond( &self, _status: u16, _headers: &str ) {}
+
+	fn write( &self, _data: &[u8] ) -> bool {
+		false
+	}
+}
+
+impl JsEvaluationError {
+	fn new( message: String ) -> Self {
+		Self { message }
+	}
+
+	/// The mock backend doesn't run the code, so the position of the error is never known.
+	pub fn column( &self ) -> Option<u32> { None }
+
+	/// See `column`.
+	pub fn line( &self ) -> Option<u32> { None }
+
+	/// The message that the handler of `MockPage::on_eval` has given.
+	pub fn message( &self ) -> &str { &self.message }
+
+	/// Always `None`, as the handler of `MockPage::on_eval` only gives a message.
+	pub fn name( &self ) -> Option<&str> { None }
+
+	/// Always `None`, as no code is run.
+	pub fn stack( &self ) -> Option<&str> { None }
+}
+
+impl Error for JsEvaluationError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> { None }
+}
+
+impl fmt::Display for JsEvaluationError {
+
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+
+		write!(f, "{}", self.message.as_str())
+	}
+}
+
+
+
+unsafe fn created_handler( _app: ApplicationImpl, data: *mut () ) {
+	let data = Box::from_raw( data as *mut (BrowserWindowImpl, CreationCallbackFn, *mut ()) );
+	let (bw, callback, callback_data) = *data;
+
+	if bw.is_alive() {
+		callback( bw, callback_data );
+	}
+}
+
+unsafe fn devtools_result_handler( _app: ApplicationImpl, data: *mut () ) {
+	let data = Box::from_raw( data as *mut (BrowserWindowImpl, DevToolsCallbackFn, *mut (), Result<String, String>) );
+	let (bw, callback, callback_data, result) = *data;
+
+	// The callback is completed either way, so that nobody waits for it forever
+	if bw.is_alive() {
+		callback( bw, callback_data, result );
+	}
+	else {
+		callback( bw, callback_data, Err( r#"{"message":"The browser window has been destroyed"}"#.to_owned() ) );
+	}
+}
+
+unsafe fn eval_js_threadsafe_handler( _app: ApplicationImpl, data: *mut () ) {
+	let data = Box::from_raw( data as *mut (BrowserWindowImpl, String, EvalJsCallbackFn, *mut ()) );
+	let (bw, js, callback, callback_data) = *data;
+
+	// The callback is completed either way, so that nobody waits for it forever
+	if bw.is_alive() {
+		bw.evaluate( &js, callback, callback_data );
+	}
+	else {
+		callback( bw, callback_data, Err( JsEvaluationError::new( "The browser window has been destroyed".to_owned() ) ) );
+	}
+}
+
+unsafe fn later_handler( _app: ApplicationImpl, data: *mut () ) {
+	let data = Box::from_raw( data as *mut (BrowserWindowImpl, Box<dyn FnOnce( BrowserWindowImpl )>) );
+	let (bw, work) = *data;
+
+	if bw.is_alive() {
+		work( bw );
+	}
+}
@@ -1,4 +1,7 @@
+#[cfg(not(feature = "mock"))]
 pub mod c;
+#[cfg(feature = "mock")]
+pub mod mock;
 
 
 use std::{
@@ -8,7 +11,10 @@ use std::{
 	time::SystemTime
 };
 
+#[cfg(not(feature = "mock"))]
 pub use c::*;
+#[cfg(feature = "mock")]
+pub use mock::*;
 
 
 
@@ -0,0 +1,306 @@
+//! This module implements the cookie traits in memory, for feature `mock`.
+//! There is one cookie jar that all browser windows share, like there is with the browser engines.
+//! The callbacks are invoked right away, as there is nothing to wait for.
+
+use super::*;
+
+use std::cell::RefCell;
+
+
+
+pub struct CookieImpl {
+	pub(in crate) inner: *mut MockCookie
+}
+
+pub struct CookieMutImpl (CookieImpl);
+
+pub struct CookieJarImpl {}
+
+pub struct CookieIteratorImpl {
+	pub(in crate) inner: *mut MockCookieIterator
+}
+
+#[derive(Clone)]
+pub(in crate) struct MockCookie {
+	creation_time: SystemTime,
+	domain: String,
+	expires: Option<SystemTime>,
+	http_only: bool,
+	name: String,
+	path: String,
+	secure: bool,
+	value: String
+}
+
+pub(in crate) struct MockCookieIterator {
+	cookies: Vec<MockCookie>
+}
+
+struct CookieStorageCallbackData {
+	callback: CookieStorageCallbackFn,
+	data: *mut ()
+}
+
+struct CookieDeleteCallbackData {
+	callback: CookieDeleteCallbackFn,
+	data: *mut ()
+}
+
+struct CookieIteratorNextCallbackData {
+	callback: CookieIteratorNextCallbackFn,
+	data: *mut ()
+}
+
+
+
+thread_local! {
+	/// The cookies of the global cookie jar.
+	static COOKIES: RefCell<Vec<MockCookie>> = const { RefCell::new( Vec::new() ) };
+}
+
+
+
+impl CookieImpl {
+
+	fn state( &self ) -> &MockCookie {
+		unsafe { &*self.inner }
+	}
+
+	fn state_mut( &mut self ) -> &mut MockCookie {
+		unsafe { &mut *self.inner }
+	}
+}
+
+impl CookieExt for CookieImpl {
+
+	fn creation_time(&self) -> SystemTime {
+		self.state().creation_time
+	}
+
+	fn expires(&self) -> Option<SystemTime> {
+		self.state().expires
+	}
+
+	fn domain<'a>(&'a self) -> Cow<'a, str> {
+		self.state().domain.as_str().into()
+	}
+
+	fn free(&mut self) {
+		unsafe { drop( Box::from_raw( self.inner ) ) }
+	}
+
+	fn is_http_only(&self) -> bool {
+		self.state().http_only
+	}
+
+	fn is_secure(&self) -> bool {
+		self.state().secure
+	}
+
+	fn name<'a>(&'a self) -> Cow<'a, str> {
+		self.state().name.as_str().into()
+	}
+
+	fn new(name: &str, value: &str) -> Self {
+		let cookie = MockCookie {
+			creation_time: SystemTime::now(),
+			domain: String::new(),
+			expires: None,
+			http_only: false,
+			name: name.to_owned(),
+			path: String::new(),
+			secure: false,
+			value: value.to_owned()
+		};
+
+		Self { inner: Box::into_raw( Box::new( cookie ) ) }
+	}
+
+	fn path<'a>(&'a self) -> Cow<'a, str> {
+		self.state().path.as_str().into()
+	}
+
+	fn value<'a>(&'a self) -> Cow<'a, str> {
+		self.state().value.as_str().into()
+	}
+
+	fn make_http_only(&mut self) -> &mut Self {
+		self.state_mut().http_only = true;	self
+	}
+
+	fn make_secure(&mut self) -> &mut Self {
+		self.state_mut().secure = true;	self
+	}
+
+	fn set_creation_time(&mut self, time: &SystemTime) -> &mut Self {
+		self.state_mut().creation_time = *time;	self
+	}
+
+	fn set_expires(&mut self, time: &SystemTime) -> &mut Self {
+		self.state_mut().expires = Some( *time );	self
+	}
+
+	fn set_domain(&mut self, domain: &str) -> &mut Self {
+		self.state_mut().domain = domain.to_owned();	self
+	}
+
+	fn set_name(&mut self, name: &str) -> &mut Self {
+		self.state_mut().name = name.to_owned();	self
+	}
+
+	fn set_path(&mut self, path: &str) -> &mut Self {
+		self.state_mut().path = path.to_owned();	self
+	}
+
+	fn set_value(&mut self, value: &str) -> &mut Self {
+		self.state_mut().value = value.to_owned();	self
+	}
+}
+
+impl CookieJarExt for CookieJarImpl {
+
+	fn delete(&mut self, url: &str, name: &str, complete_cb: CookieDeleteCallbackFn, cb_data: *mut ()) {
+		let deleted = COOKIES.with(|cookies| {
+			let mut cookies = cookies.borrow_mut();
+			let count = cookies.len();
+
+			cookies.retain(|c| !( (url.is_empty() || c.matches( url )) && (name.is_empty() || c.name == name) ) );
+			count - cookies.len()
+		});
+
+		let data = CookieDeleteCallbackData { callback: complete_cb, data: cb_data };
+		unsafe { data.complete( deleted ) }
+	}
+
+	fn free(&mut self) {}
+
+	fn global() -> CookieJarImpl {
+		CookieJarImpl {}
+	}
+
+	fn iterator(&self, url: &str, include_http_only: bool) -> CookieIteratorImpl {
+		let cookies = COOKIES.with(|cookies| cookies.borrow().iter()
+			.filter(|c| c.matches( url ) && (include_http_only || !c.http_only) )
+			.cloned()
+			.collect()
+		);
+
+		CookieIteratorImpl::new( cookies )
+	}
+
+	fn iterator_all(&self) -> CookieIteratorImpl {
+		CookieIteratorImpl::new( COOKIES.with(|cookies| cookies.borrow().clone() ) )
+	}
+
+	fn store(&mut self, url: &str, cookie: &CookieImpl, complete_cb: Option<CookieStorageCallbackFn>, cb_data: *mut ()) {
+		let mut cookie = cookie.state().clone();
+		let result = match host( url ) {
+			None => Err( CookieStorageError::Unknown ),
+			Some( host ) => {
+				// Like browsers do, the cookie belongs to the address that it has been set for, unless it says otherwise
+				if cookie.domain.is_empty() {
+					cookie.domain = host.to_owned();
+				}
+				if cookie.path.is_empty() {
+					cookie.path = "/".to_owned();
+				}
+
+				COOKIES.with(|cookies| {
+					let mut cookies = cookies.borrow_mut();
+
+					cookies.retain(|c| !(c.name == cookie.name && c.domain == cookie.domain && c.path == cookie.path) );
+					cookies.push( cookie );
+				});
+				Ok(())
+			}
+		};
+
+		if let Some( callback ) = complete_cb {
+			let data = CookieStorageCallbackData { callback, data: cb_data };
+			unsafe { data.complete( result ) }
+		}
+	}
+}
+
+impl CookieIteratorImpl {
+
+	fn new( cookies: Vec<MockCookie> ) -> Self {
+		Self {
+			inner: Box::into_raw( Box::new( MockCookieIterator { cookies } ) )
+		}
+	}
+}
+
+impl CookieIteratorExt for CookieIteratorImpl {
+
+	fn free(&mut self) {
+		unsafe { drop( Box::from_raw( self.inner ) ) }
+	}
+
+	fn next(&mut self, on_next: CookieIteratorNextCallbackFn, cb_data: *mut ()) -> bool {
+		let iterator = unsafe { &mut *self.inner };
+		if iterator.cookies.is_empty() {
+			return false;
+		}
+
+		let cookie = CookieImpl { inner: Box::into_raw( Box::new( iterator.cookies.remove( 0 ) ) ) };
+		let data = CookieIteratorNextCallbackData { callback: on_next, data: cb_data };
+		unsafe { data.complete( CookieIteratorImpl { inner: self.inner }, cookie ) };
+		true
+	}
+}
+
+impl CookieStorageCallbackData {
+
+	/// Invokes the callback with the data that it has been given along with.
+	unsafe fn complete( self, result: Result<(), CookieStorageError> ) {
+		(self.callback)( CookieJarImpl {}, self.data, result )
+	}
+}
+
+impl CookieDeleteCallbackData {
+
+	/// Invokes the callback with the data that it has been given along with.
+	unsafe fn complete( self, deleted: usize ) {
+		(self.callback)( CookieJarImpl {}, self.data, deleted )
+	}
+}
+
+impl CookieIteratorNextCallbackData {
+
+	/// Invokes the callback with the data that it has been given along with.
+	unsafe fn complete( self, iterator: CookieIteratorImpl, cookie: CookieImpl ) {
+		(self.callback)( iterator, self.data, Some( cookie ) )
+	}
+}
+
+impl MockCookie {
+
+	/// Whether the cookie would be sent along with a request to the given address.
+	fn matches( &self, url: &str ) -> bool {
+		let host = match host( url ) {
+			None => return false,
+			Some( host ) => host
+		};
+		let path = url.split_once( "://" ).map(|(_, rest)| rest )
+			.and_then(|rest| rest.find( '/' ).map(|i| &rest[i..] ) )
+			.unwrap_or( "/" );
+
+		let domain = self.domain.trim_start_matches( '.' );
+		let domain_matches = host == domain || host.ends_with( &format!( ".{}", domain ) );
+		let expired = self.expires.map(|e| e <= SystemTime::now() ).unwrap_or( false );
+
+		domain_matches && path.starts_with( &self.path ) && !expired
+	}
+}
+
+
+
+/// The host name of the given address, if it has one.
+fn host( url: &str ) -> Option<&str> {
+	let (_, rest) = url.split_once( "://" )?;
+	let authority = rest.split( ['/', '?', '#'] ).next()?;
+	let host = authority.rsplit( '@' ).next()?.split( ':' ).next()?;
+
+	if host.is_empty() { None } else { Some( host ) }
+}
@@ -1,9 +1,8 @@
 use crate::prelude::*;
 
-use std::{
-	ffi::CStr,
-	fmt
-};
+#[cfg(not(feature = "mock"))]
+use std::ffi::CStr;
+use std::fmt;
 
 
 
@@ -15,18 +14,39 @@ pub type CbwResult<T> = Result<T, CbwError>;
 
 
 
-impl fmt::Display for CbwError {
-	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+impl CbwError {
+
+	/// The error code, which is specific to the part of `browser-window-c` that gave the error.
+	pub fn code( &self ) -> u32 {
+		self.0.code as _
+	}
+
+	/// The message of the error, without its code.
+	#[cfg(not(feature = "mock"))]
+	pub fn message( &self ) -> String {
 		unsafe {
 			let c_msg = cbw_Err_message( &self.0 );
 
-			let result = write!(f, "[{}] {}", self.0.code, CStr::from_ptr(c_msg).to_str().expect("invalid utf-8 in bw_Err error message"));
+			let message = CStr::from_ptr(c_msg).to_str().expect("invalid utf-8 in bw_Err error message").to_owned();
 
 			cbw_string_freeCstr(c_msg);
 
-			return result;
+			message
 		}
 	}
+
+	/// The message of the error, without its code.
+	// The mock backend doesn't have the C functions, but its errors carry their own message function
+	#[cfg(feature = "mock")]
+	pub fn message( &self ) -> String {
+		self.0.to_string()
+	}
+}
+
+impl fmt::Display for CbwError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "[{}] {}", self.0.code, self.message())
+	}
 }
 
 impl std::error::Error for CbwError {
@@ -37,4 +57,4 @@ impl From<cbw_Err> for CbwError {
 	fn from(e: cbw_Err) -> Self {
 		Self (e)
 	}
-}
\ No newline at end of file
+}
@@ -2,5 +2,7 @@ pub mod application;
 pub mod browser_window;
 pub mod cookie;
 pub mod error;
+pub mod menu;
 pub mod prelude;
+pub mod tray_icon;
 pub mod window;
\ No newline at end of file
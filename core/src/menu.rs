@@ -0,0 +1,29 @@
+#[cfg(not(feature = "mock"))]
+pub mod c;
+#[cfg(feature = "mock")]
+pub mod mock;
+
+#[cfg(not(feature = "mock"))]
+pub use c::MenuImpl;
+#[cfg(feature = "mock")]
+pub use mock::MenuImpl;
+
+use browser_window_c::*;
+
+
+
+pub trait MenuExt: Copy {
+	/// Appends an item to the menu.
+	/// `item_id` may not be 0.
+	/// An empty `accelerator` means that the item has no accelerator.
+	fn add_item( &self, item_id: u32, label: &str, accelerator: &str, checkable: bool, checked: bool );
+	fn add_separator( &self );
+	/// Appends a submenu to the menu.
+	/// The menu takes ownership of `submenu`, so it shouldn't be freed by itself anymore.
+	fn add_submenu( &self, label: &str, submenu: Self );
+	/// Frees the menu, together with all of its submenus.
+	fn free( &self );
+	fn new() -> Self;
+	/// The pointer to the C handle, as it is used in `WindowOptions`.
+	fn as_ptr( &self ) -> *const cbw_Menu;
+}
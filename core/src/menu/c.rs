@@ -0,0 +1,39 @@
+use super::*;
+
+
+
+#[derive(Clone,Copy)]
+pub struct MenuImpl {
+	pub(in crate) inner: *mut cbw_Menu
+}
+
+
+
+impl MenuExt for MenuImpl {
+
+	fn add_item( &self, item_id: u32, label: &str, accelerator: &str, checkable: bool, checked: bool ) {
+		unsafe { cbw_Menu_addItem( self.inner, item_id, label.into(), accelerator.into(), checkable as _, checked as _ ) }
+	}
+
+	fn add_separator( &self ) {
+		unsafe { cbw_Menu_addSeparator( self.inner ) }
+	}
+
+	fn add_submenu( &self, label: &str, submenu: Self ) {
+		unsafe { cbw_Menu_addSubmenu( self.inner, label.into(), submenu.inner ) }
+	}
+
+	fn as_ptr( &self ) -> *const cbw_Menu {
+		self.inner
+	}
+
+	fn free( &self ) {
+		unsafe { cbw_Menu_free( self.inner ) }
+	}
+
+	fn new() -> Self {
+		Self {
+			inner: unsafe { cbw_Menu_new() }
+		}
+	}
+}
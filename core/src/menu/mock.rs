@@ -0,0 +1,46 @@
+//! This module implements the `Menu` trait in memory, for feature `mock`.
+//! As there is nothing to show the menus on, only their submenus are kept, so that they can be freed together.
+
+use super::*;
+
+
+
+#[derive(Clone,Copy)]
+pub struct MenuImpl {
+	pub(in crate) inner: *mut MockMenu
+}
+
+pub(in crate) struct MockMenu {
+	submenus: Vec<MenuImpl>
+}
+
+
+
+impl MenuExt for MenuImpl {
+
+	fn add_item( &self, _item_id: u32, _label: &str, _accelerator: &str, _checkable: bool, _checked: bool ) {}
+
+	fn add_separator( &self ) {}
+
+	fn add_submenu( &self, _label: &str, submenu: Self ) {
+		unsafe { (*self.inner).submenus.push( submenu ) }
+	}
+
+	fn as_ptr( &self ) -> *const cbw_Menu {
+		self.inner as _
+	}
+
+	fn free( &self ) {
+		let menu = unsafe { Box::from_raw( self.inner ) };
+
+		for submenu in menu.submenus {
+			submenu.free();
+		}
+	}
+
+	fn new() -> Self {
+		Self {
+			inner: Box::into_raw( Box::new( MockMenu { submenus: Vec::new() } ) )
+		}
+	}
+}
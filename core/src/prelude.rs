@@ -4,13 +4,17 @@ pub use crate::cookie::*;
 
 
 
+#[derive(Clone, Copy, Debug)]
 pub struct Dims2D(pub(crate) cbw_Dims2D);
+#[derive(Clone, Copy, Debug)]
 pub struct Pos2D(pub(crate) cbw_Pos2D);
 
 
 
 pub use crate::application::{ApplicationExt, ApplicationImpl};
-pub use crate::browser_window::{BrowserWindowExt, BrowserWindowImpl};
+pub use crate::browser_window::{BrowserWindowExt, BrowserWindowImpl, SchemeRequestExt, SchemeRequestImpl};
+pub use crate::menu::{MenuExt, MenuImpl};
+pub use crate::tray_icon::{TrayIconExt, TrayIconImpl};
 pub use crate::window::{WindowExt, WindowImpl};
 
 
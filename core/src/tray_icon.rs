@@ -0,0 +1,34 @@
+#[cfg(not(feature = "mock"))]
+pub mod c;
+#[cfg(feature = "mock")]
+pub mod mock;
+
+#[cfg(not(feature = "mock"))]
+pub use c::TrayIconImpl;
+#[cfg(feature = "mock")]
+pub use mock::TrayIconImpl;
+
+use crate::application::ApplicationImpl;
+use crate::menu::MenuImpl;
+
+
+
+/// Invoked when the tray icon has been clicked on with the left mouse button.
+pub type TrayIconClickFn = unsafe fn( icon: TrayIconImpl, data: *mut () );
+/// Invoked when an item of the context menu has been activated, with the new check state of the item.
+pub type TrayIconMenuHandlerFn = unsafe fn( icon: TrayIconImpl, data: *mut (), item_id: u32, checked: bool );
+
+
+
+pub trait TrayIconExt: Copy {
+	/// Removes the icon from the system tray.
+	/// The user data is not freed, that is up to the caller.
+	fn free( &self );
+	/// Puts a new icon in the system tray.
+	/// An empty `icon_path` gives the default application icon.
+	/// `menu` is only read during this call, so it can be freed afterwards.
+	fn new( app: ApplicationImpl, icon_path: &str, tooltip: &str, menu: Option<MenuImpl>, on_click: TrayIconClickFn, on_menu_item: TrayIconMenuHandlerFn, user_data: *mut () ) -> Self;
+	fn set_icon( &self, icon_path: &str );
+	fn set_tooltip( &self, tooltip: &str );
+	fn user_data( &self ) -> *mut ();
+}
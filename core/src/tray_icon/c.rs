@@ -0,0 +1,88 @@
+use super::*;
+
+use crate::menu::MenuExt;
+
+use browser_window_c::*;
+
+use std::{
+	os::raw::c_void,
+	ptr
+};
+
+
+
+#[derive(Clone,Copy)]
+pub struct TrayIconImpl {
+	inner: *mut cbw_TrayIcon,
+	user_data: *mut UserData
+}
+
+struct UserData {
+	on_click: TrayIconClickFn,
+	on_menu_item: TrayIconMenuHandlerFn,
+	data: *mut ()
+}
+
+
+
+impl TrayIconExt for TrayIconImpl {
+
+	fn free( &self ) {
+		unsafe {
+			cbw_TrayIcon_free( self.inner );
+			drop( Box::from_raw( self.user_data ) );
+		}
+	}
+
+	fn new( app: ApplicationImpl, icon_path: &str, tooltip: &str, menu: Option<MenuImpl>, on_click: TrayIconClickFn, on_menu_item: TrayIconMenuHandlerFn, user_data: *mut () ) -> Self {
+		let user_data = Box::into_raw( Box::new( UserData {
+			on_click,
+			on_menu_item,
+			data: user_data
+		} ) );
+		let menu_ptr = menu.map(|m| m.as_ptr() ).unwrap_or( ptr::null() );
+
+		let inner = unsafe { cbw_TrayIcon_new(
+			app.inner,
+			icon_path.into(),
+			tooltip.into(),
+			menu_ptr,
+			Some( ffi_click_handler ),
+			Some( ffi_menu_handler ),
+			user_data as _
+		) };
+
+		Self {
+			inner,
+			user_data
+		}
+	}
+
+	fn set_icon( &self, icon_path: &str ) {
+		unsafe { cbw_TrayIcon_setIcon( self.inner, icon_path.into() ) }
+	}
+
+	fn set_tooltip( &self, tooltip: &str ) {
+		unsafe { cbw_TrayIcon_setTooltip( self.inner, tooltip.into() ) }
+	}
+
+	fn user_data( &self ) -> *mut () {
+		unsafe { (*self.user_data).data }
+	}
+}
+
+
+
+unsafe extern "C" fn ffi_click_handler( icon: *mut cbw_TrayIcon, data: *mut c_void ) {
+	let user_data = data as *mut UserData;
+	let handle = TrayIconImpl { inner: icon, user_data };
+
+	((*user_data).on_click)( handle, (*user_data).data );
+}
+
+unsafe extern "C" fn ffi_menu_handler( icon: *mut cbw_TrayIcon, item_id: u32, checked: cBOOL, data: *mut c_void ) {
+	let user_data = data as *mut UserData;
+	let handle = TrayIconImpl { inner: icon, user_data };
+
+	((*user_data).on_menu_item)( handle, (*user_data).data, item_id, checked != 0 );
+}
@@ -0,0 +1,38 @@
+//! This module implements the `TrayIcon` trait in memory, for feature `mock`.
+//! The icons never get clicked on, but they keep their user data like real ones would.
+
+use super::*;
+
+
+
+#[derive(Clone,Copy)]
+pub struct TrayIconImpl {
+	inner: *mut MockTrayIcon
+}
+
+struct MockTrayIcon {
+	data: *mut ()
+}
+
+
+
+impl TrayIconExt for TrayIconImpl {
+
+	fn free( &self ) {
+		unsafe { drop( Box::from_raw( self.inner ) ) }
+	}
+
+	fn new( _app: ApplicationImpl, _icon_path: &str, _tooltip: &str, _menu: Option<MenuImpl>, _on_click: TrayIconClickFn, _on_menu_item: TrayIconMenuHandlerFn, user_data: *mut () ) -> Self {
+		Self {
+			inner: Box::into_raw( Box::new( MockTrayIcon { data: user_data } ) )
+		}
+	}
+
+	fn set_icon( &self, _icon_path: &str ) {}
+
+	fn set_tooltip( &self, _tooltip: &str ) {}
+
+	fn user_data( &self ) -> *mut () {
+		unsafe { (*self.inner).data }
+	}
+}
@@ -1,32 +1,63 @@
+#[cfg(not(feature = "mock"))]
 pub mod c;
+#[cfg(feature = "mock")]
+pub mod mock;
 
+#[cfg(not(feature = "mock"))]
 pub use c::WindowImpl;
+#[cfg(feature = "mock")]
+pub use mock::WindowImpl;
 
+use crate::application::Monitor;
 use crate::prelude::*;
 
 
 
 pub trait WindowExt: Copy + Default {
 	fn app( &self ) -> ApplicationImpl;
+	/// The pointer to the C handle, as it is used in `WindowOptions`.
+	fn as_ptr( &self ) -> *const cbw_Window;
 
 	fn destroy( &self );
 	fn drop( &self );
 
 	fn get_content_dimensions( &self ) -> Dims2D;
+	/// The display that contains the largest part of the window, if it is on any.
+	fn get_monitor( &self ) -> Option<Monitor>;
+	/// The handle of the window of the platform, if it has one yet.
+	fn get_native_handle( &self ) -> Option<NativeHandle>;
 	fn get_opacity( &self ) -> u8;
 	fn get_position( &self ) -> Pos2D;
+	/// The number of physical pixels that make up one logical pixel on the display that the window is on.
+	fn get_scale_factor( &self ) -> f32;
 	fn get_title( &self ) -> String;
 	fn get_window_dimensions( &self ) -> Dims2D;
 
 	fn hide( &self );
 
+	fn is_visible( &self ) -> bool;
+
 	fn set_content_dimensions( &self, dimensions: Dims2D );
+	/// Sets the icon of the window.
+	/// If `width` and `height` are 0, `data` is a PNG image, otherwise it contains the pixels in the RGBA format.
+	fn set_icon( &self, data: &[u8], width: u32, height: u32 );
+	/// Marks the area of the custom maximize button of a frameless window.
+	/// A size of 0 removes the area.
+	fn set_maximize_button_area( &self, position: Pos2D, size: Dims2D );
 	fn set_opacity( &self, opacity: u8 );
 	fn set_position( &self, position: Pos2D );
 	fn set_title( &self, title: &str );
 	fn set_window_dimensions( &self, dimensions: Dims2D );
 
 	fn show( &self );
+	/// Shows a message box that is modal to the window, with the given kind of icon: 0 for information, 1 for a warning, 2 for an error or 3 for a question.
+	/// `on_close` is invoked with the index of the chosen button, or `None` if the message box has been dismissed.
+	fn show_message_box( &self, kind: u8, title: &str, message: &str, buttons: &[&str], on_close: unsafe fn(WindowImpl, Option<usize>, *mut ()), data: *mut () );
+
+	/// Starts dragging the given files and text out of the window.
+	/// Returns whether or not the drag could be started.
+	fn start_drag( &self, files: &[&str], text: &str ) -> bool;
 }
 
+pub type NativeHandle = cbw_WindowNativeHandle;
 pub type WindowOptions = cbw_WindowOptions;
\ No newline at end of file
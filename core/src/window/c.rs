@@ -2,17 +2,19 @@
 //! All functions are basically wrapping the FFI provided by crate `browser-window-c`.
 
 use super::{
+	NativeHandle,
 	WindowExt,
 	WindowOptions
 };
 
 use crate::{
-	application::ApplicationImpl,
+	application::{ApplicationImpl, Monitor},
 	prelude::*
 };
 
 use std::{
-	os::raw::c_char,
+	mem,
+	os::raw::{c_char, c_int, c_void},
 	ptr
 };
 
@@ -68,6 +70,10 @@ impl WindowExt for WindowImpl {
 		ApplicationImpl { inner: unsafe { (*self.inner).app } }
 	}
 
+	fn as_ptr( &self ) -> *const cbw_Window {
+		self.inner
+	}
+
 	fn destroy( &self ) {
 		unsafe { cbw_Window_destroy( self.inner ) }
 	}
@@ -80,6 +86,24 @@ impl WindowExt for WindowImpl {
 		unsafe { Dims2D(cbw_Window_getContentDimensions( self.inner )) }
 	}
 
+	fn get_monitor( &self ) -> Option<Monitor> {
+		let mut monitor: Monitor = unsafe { mem::zeroed() };
+
+		if unsafe { cbw_Window_getMonitor( self.inner, &mut monitor ) } == 0 {
+			return None;
+		}
+		Some( monitor )
+	}
+
+	fn get_native_handle( &self ) -> Option<NativeHandle> {
+		let mut handle: NativeHandle = unsafe { mem::zeroed() };
+
+		if unsafe { cbw_Window_getNativeHandle( self.inner, &mut handle ) } == 0 {
+			return None;
+		}
+		Some( handle )
+	}
+
 	fn get_opacity( &self ) -> u8 {
 		unsafe { cbw_Window_getOpacity( self.inner ) }
 	}
@@ -88,6 +112,10 @@ impl WindowExt for WindowImpl {
 		unsafe { Pos2D(cbw_Window_getPosition( self.inner )) }
 	}
 
+	fn get_scale_factor( &self ) -> f32 {
+		unsafe { cbw_Window_getScaleFactor( self.inner ) }
+	}
+
 	fn get_title( &self ) -> String {
 		// First obtain string size
 		let mut buf: *mut c_char = ptr::null_mut();
@@ -109,10 +137,22 @@ impl WindowExt for WindowImpl {
 		unsafe { cbw_Window_hide( self.inner ) }
 	}
 
+	fn is_visible( &self ) -> bool {
+		unsafe { cbw_Window_isVisible( self.inner ) }
+	}
+
 	fn set_content_dimensions( &self, dimensions: Dims2D ) {
 		unsafe { cbw_Window_setContentDimensions( self.inner, dimensions.0 ) }
 	}
 
+	fn set_icon( &self, data: &[u8], width: u32, height: u32 ) {
+		unsafe { cbw_Window_setIcon( self.inner, data.as_ptr() as _, data.len() as _, width, height ) }
+	}
+
+	fn set_maximize_button_area( &self, position: Pos2D, size: Dims2D ) {
+		unsafe { cbw_Window_setMaximizeButtonArea( self.inner, position.0, size.0 ) }
+	}
+
 	fn set_opacity( &self, opacity: u8 ) {
 		unsafe { cbw_Window_setOpacity( self.inner, opacity ) }
 	}
@@ -133,6 +173,31 @@ impl WindowExt for WindowImpl {
 	fn show( &self ) {
 		unsafe { cbw_Window_show( self.inner ) }
 	}
+
+	fn show_message_box( &self, kind: u8, title: &str, message: &str, buttons: &[&str], on_close: unsafe fn(WindowImpl, Option<usize>, *mut ()), _data: *mut () ) {
+		let button_slices: Vec<cbw_CStrSlice> = buttons.iter().map(|b| (*b).into() ).collect();
+		let message_box = cbw_WindowMessageBox {
+			kind,
+			title: title.into(),
+			message: message.into(),
+			buttons: button_slices.as_ptr(),
+			button_count: button_slices.len()
+		};
+
+		let data = Box::new( MessageBoxData {
+			func: on_close,
+			data: _data
+		} );
+		let data_ptr = Box::into_raw( data );
+
+		unsafe { cbw_Window_showMessageBox( self.inner, &message_box, Some( message_box_handler ), data_ptr as _ ) }
+	}
+
+	fn start_drag( &self, files: &[&str], text: &str ) -> bool {
+		let file_slices: Vec<cbw_CStrSlice> = files.iter().map(|f| (*f).into() ).collect();
+
+		unsafe { cbw_Window_startDrag( self.inner, file_slices.as_ptr(), file_slices.len(), text.into() ) != 0 }
+	}
 }
 
 impl Default for WindowImpl {
@@ -141,4 +206,20 @@ impl Default for WindowImpl {
 			inner: ptr::null_mut()
 		}
 	}
-}
\ No newline at end of file
+}
+
+
+
+struct MessageBoxData {
+	func: unsafe fn( WindowImpl, Option<usize>, *mut () ),
+	data: *mut ()
+}
+
+unsafe extern "C" fn message_box_handler( _handle: *mut cbw_Window, button: c_int, _data: *mut c_void ) {
+
+	let data = Box::from_raw( _data as *mut MessageBoxData );
+	let handle = WindowImpl { inner: _handle };
+
+	let button = if button < 0 { None } else { Some( button as usize ) };
+	(data.func)( handle, button, data.data );
+}
@@ -0,0 +1,215 @@
+//! This module implements the `Window` trait in memory, for feature `mock`.
+//! The windows are never shown, but they keep their title, position, size and visibility, like real ones would.
+//! Like on the platforms, a window is destroyed from the event loop, so never while one of its handlers is still running.
+
+use super::{
+	NativeHandle,
+	WindowExt,
+	WindowOptions
+};
+
+use crate::{
+	application::{ApplicationExt, ApplicationImpl, Monitor},
+	browser_window::BrowserWindowImpl,
+	prelude::*
+};
+
+use std::{
+	cell::{RefCell, RefMut},
+	ptr
+};
+
+
+
+#[derive(Clone,Copy)]
+pub struct WindowImpl {
+	pub(in crate) inner: *mut RefCell<MockWindow>
+}
+
+pub(in crate) struct MockWindow {
+	app: ApplicationImpl,
+	/// The browser window that lives in this window, if any, which needs to be cleaned up when the window is destroyed.
+	pub browser: Option<BrowserWindowImpl>,
+	/// Windows start out hidden, like they do on the platforms.
+	closed: bool,
+	content_dimensions: cbw_Dims2D,
+	opacity: u8,
+	position: cbw_Pos2D,
+	title: String
+}
+
+
+
+impl WindowImpl {
+
+	pub fn new(
+		app: ApplicationImpl,
+		_parent: Self,
+		title: &str,
+		width: Option<u32>,
+		height: Option<u32>,
+		_options: &WindowOptions,
+		_user_data: *mut ()
+	) -> Self {
+		let window = Box::new( RefCell::new( MockWindow {
+			app,
+			browser: None,
+			closed: true,
+			content_dimensions: cbw_Dims2D {
+				width: width.unwrap_or( 800 ) as _,
+				height: height.unwrap_or( 600 ) as _
+			},
+			opacity: 255,
+			position: cbw_Pos2D { x: 0, y: 0 },
+			title: title.to_owned()
+		} ) );
+
+		app.window_created();
+		Self { inner: Box::into_raw( window ) }
+	}
+
+	/// Borrows the state of the window, which should be released before the browser window that lives in it is used.
+	pub(in crate) fn state( &self ) -> RefMut<'_, MockWindow> {
+		unsafe { (*self.inner).borrow_mut() }
+	}
+}
+
+impl WindowExt for WindowImpl {
+
+	fn app( &self ) -> ApplicationImpl {
+		self.state().app
+	}
+
+	fn as_ptr( &self ) -> *const cbw_Window {
+		self.inner as _
+	}
+
+	fn destroy( &self ) {
+		self.app().dispatch( destroy_handler, self.inner as _ );
+	}
+
+	fn drop( &self ) {
+		// Like on the platforms, a window that is still shown lives on until it is closed
+		let closed = self.state().closed;
+		if closed {
+			self.destroy();
+		}
+	}
+
+	fn get_content_dimensions( &self ) -> Dims2D {
+		Dims2D( self.state().content_dimensions )
+	}
+
+	fn get_monitor( &self ) -> Option<Monitor> {
+		self.app().get_monitors().into_iter().next()
+	}
+
+	fn get_native_handle( &self ) -> Option<NativeHandle> {
+		None
+	}
+
+	fn get_opacity( &self ) -> u8 {
+		self.state().opacity
+	}
+
+	fn get_position( &self ) -> Pos2D {
+		Pos2D( self.state().position )
+	}
+
+	fn get_scale_factor( &self ) -> f32 {
+		1.0
+	}
+
+	fn get_title( &self ) -> String {
+		self.state().title.clone()
+	}
+
+	fn get_window_dimensions( &self ) -> Dims2D {
+		// There is no frame around the content
+		self.get_content_dimensions()
+	}
+
+	fn hide( &self ) {
+		self.state().closed = true;
+	}
+
+	fn is_visible( &self ) -> bool {
+		!self.state().closed
+	}
+
+	fn set_content_dimensions( &self, dimensions: Dims2D ) {
+		self.state().content_dimensions = dimensions.0;
+
+		let browser = self.state().browser;
+		if let Some( browser ) = browser {
+			browser.resized( dimensions.width() as _, dimensions.height() as _ );
+		}
+	}
+
+	fn set_icon( &self, _data: &[u8], _width: u32, _height: u32 ) {}
+
+	fn set_maximize_button_area( &self, _position: Pos2D, _size: Dims2D ) {}
+
+	fn set_opacity( &self, opacity: u8 ) {
+		self.state().opacity = opacity;
+	}
+
+	fn set_position( &self, position: Pos2D ) {
+		self.state().position = position.0;
+
+		let browser = self.state().browser;
+		if let Some( browser ) = browser {
+			browser.moved( position.x() as _, position.y() as _ );
+		}
+	}
+
+	fn set_title( &self, title: &str ) {
+		self.state().title = title.to_owned();
+	}
+
+	fn set_window_dimensions( &self, dimensions: Dims2D ) {
+		self.set_content_dimensions( dimensions );
+	}
+
+	fn show( &self ) {
+		self.state().closed = false;
+	}
+
+	fn show_message_box( &self, _kind: u8, _title: &str, _message: &str, _buttons: &[&str], on_close: unsafe fn(WindowImpl, Option<usize>, *mut ()), data: *mut () ) {
+		// Nobody is there to choose a button, so it is dismissed right away
+		let data = Box::into_raw( Box::new( (*self, on_close, data) ) );
+		self.app().dispatch( message_box_closed, data as _ );
+	}
+
+	fn start_drag( &self, _files: &[&str], _text: &str ) -> bool {
+		false
+	}
+}
+
+impl Default for WindowImpl {
+	fn default() -> Self {
+		Self {
+			inner: ptr::null_mut()
+		}
+	}
+}
+
+
+
+unsafe fn destroy_handler( _app: ApplicationImpl, data: *mut () ) {
+	let window = WindowImpl { inner: data as _ };
+
+	let browser = window.state().browser.take();
+	if let Some( browser ) = browser {
+		browser.cleanup();
+	}
+
+	let window = Box::from_raw( window.inner ).into_inner();
+	window.app.window_destroyed();
+}
+
+unsafe fn message_box_closed( _app: ApplicationImpl, data: *mut () ) {
+	let data = Box::from_raw( data as *mut (WindowImpl, unsafe fn(WindowImpl, Option<usize>, *mut ()), *mut ()) );
+
+	(data.1)( data.0, None, data.2 );
+}
@@ -0,0 +1,327 @@
+//! Procedural macros for _Browser Window_.
+//! Use them through the `browser_window` crate with feature `macros` enabled, not directly.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+	parse::{Parse, ParseStream},
+	parse_macro_input,
+	parse_quote,
+	punctuated::Punctuated,
+	FnArg,
+	ImplItem,
+	ImplItemMethod,
+	ItemFn,
+	ItemImpl,
+	LitStr,
+	Pat,
+	Path,
+	ReturnType,
+	Signature,
+	Token,
+	Type,
+	Visibility
+};
+
+
+
+/// The arguments of the `api` and `command` attributes: an optional name, optionally followed by `codec = Path`.
+struct ApiArgs {
+	name: Option<LitStr>,
+	codec: Option<Path>
+}
+
+impl Parse for ApiArgs {
+	fn parse( input: ParseStream ) -> syn::Result<Self> {
+		let mut args = Self { name: None, codec: None };
+
+		if input.peek( LitStr ) {
+			args.name = Some( input.parse()? );
+			if input.is_empty() {
+				return Ok( args );
+			}
+			input.parse::<Token![,]>()?;
+		}
+
+		if !input.is_empty() {
+			let key: syn::Ident = input.parse()?;
+			if key != "codec" {
+				return Err( syn::Error::new_spanned( key, "unknown argument, expected `codec`" ) );
+			}
+			input.parse::<Token![=]>()?;
+			args.codec = Some( input.parse()? );
+		}
+
+		Ok( args )
+	}
+}
+
+
+
+/// Exposes the `pub` methods of an impl block that take `&self` to JavaScript.
+/// Other methods are left alone, so that they can be used as helpers.
+/// The codec of the arguments and return values can be chosen with `#[api("name", codec = Path)]`, and is JSON by default.
+/// See `browser_window::ipc` for more information.
+#[proc_macro_attribute]
+pub fn api( attr: TokenStream, item: TokenStream ) -> TokenStream {
+	let item_impl = parse_macro_input!( item as ItemImpl );
+	let args = parse_macro_input!( attr as ApiArgs );
+
+	// The name of the JavaScript object defaults to the name of the type
+	let js_name = match args.name {
+		Some( name ) => name.value(),
+		None => match type_name( &item_impl.self_ty ) {
+			Some( name ) => name,
+			None => return syn::Error::new_spanned( &item_impl.self_ty, "unable to derive a JavaScript name for this type, provide one like #[api(\"name\")]" )
+				.to_compile_error().into()
+		}
+	};
+	let codec = args.codec.unwrap_or_else(|| parse_quote!( ::browser_window::ipc::Json ) );
+
+	match expand_api( &item_impl, &js_name, &codec ) {
+		Err( e ) => e.to_compile_error().into(),
+		Ok( tokens ) => tokens.into()
+	}
+}
+
+
+
+/// Exposes a function to JavaScript as a command, so that it can be collected with `generate_handler!`.
+/// The name of the command defaults to the name of the function, and can be chosen with `#[command("name")]`.
+/// Just like with `api`, the codec can be chosen with `#[command(codec = Path)]`.
+/// See `browser_window::ipc` for more information.
+#[proc_macro_attribute]
+pub fn command( attr: TokenStream, item: TokenStream ) -> TokenStream {
+	let item_fn = parse_macro_input!( item as ItemFn );
+	let args = parse_macro_input!( attr as ApiArgs );
+
+	let js_name = args.name.map(|n| n.value() ).unwrap_or_else(|| item_fn.sig.ident.to_string() );
+	let codec = args.codec.unwrap_or_else(|| parse_quote!( ::browser_window::ipc::Json ) );
+
+	match expand_command( &item_fn, &js_name, &codec ) {
+		Err( e ) => e.to_compile_error().into(),
+		Ok( tokens ) => tokens.into()
+	}
+}
+
+/// Collects the functions with the `command` attribute into a `browser_window::ipc::Commands`, for `BrowserWindowBuilder::commands`.
+/// The functions can be given by their path, like `generate_handler![get_user, files::save_file]`.
+#[proc_macro]
+pub fn generate_handler( input: TokenStream ) -> TokenStream {
+	let paths = parse_macro_input!( input with Punctuated::<Path, Token![,]>::parse_terminated );
+
+	let commands = paths.into_iter().map(|mut path| {
+		if let Some( last ) = path.segments.last_mut() {
+			last.ident = command_ident( &last.ident );
+		}
+		path
+	});
+
+	(quote! {
+		::browser_window::ipc::Commands::from( ::std::vec![ #(#commands()),* ] )
+	}).into()
+}
+
+
+
+fn expand_api( item_impl: &ItemImpl, js_name: &str, codec: &Path ) -> syn::Result<TokenStream2> {
+	let self_ty = &item_impl.self_ty;
+	let (impl_generics, _, where_clause) = item_impl.generics.split_for_impl();
+
+	let mut arms = Vec::new();
+	let mut stubs = String::new();
+
+	for item in &item_impl.items {
+		if let ImplItem::Method( method ) = item {
+			if !is_exported( method ) {
+				continue;
+			}
+
+			arms.push( expand_method( method, codec )? );
+
+			let name = method.sig.ident.to_string();
+			stubs += &format!(
+				"{}: function() {{ return window.__bw.call({:?}, Array.prototype.slice.call(arguments)); }},\n",
+				name, format!("{}.{}", js_name, name)
+			);
+		}
+	}
+
+	let js_stubs = format!( "window[{:?}] = {{\n{}}};\n", js_name, stubs );
+
+	Ok(quote! {
+		#item_impl
+
+		impl #impl_generics ::browser_window::ipc::Api for #self_ty #where_clause {
+
+			fn js_name() -> &'static str { #js_name }
+
+			// The codec is registered before the stubs, together with the API that uses it
+			fn js_stubs() -> ::std::string::String {
+				::std::format!(
+					"{}window.__bw.api_codecs[{:?}] = {:?};\n{}",
+					<#codec as ::browser_window::ipc::Codec>::js(),
+					#js_name,
+					<#codec as ::browser_window::ipc::Codec>::name(),
+					#js_stubs
+				)
+			}
+
+			fn codec_name() -> &'static str { <#codec as ::browser_window::ipc::Codec>::name() }
+
+			fn invoke(
+				this: ::std::sync::Arc<Self>,
+				handle: ::browser_window::browser::BrowserWindowHandle,
+				method: &str,
+				args: ::std::vec::Vec<::std::string::String>
+			) -> ::std::option::Option<::browser_window::ipc::ApiFuture> {
+				let _ = &handle;
+				match method {
+					#(#arms)*
+					_ => ::std::option::Option::None
+				}
+			}
+		}
+	})
+}
+
+/// Generates the function that gives the command, next to the function itself.
+fn expand_command( item_fn: &ItemFn, js_name: &str, codec: &Path ) -> syn::Result<TokenStream2> {
+	let vis = &item_fn.vis;
+	let name = &item_fn.sig.ident;
+	let command_name = command_ident( name );
+	let body = expand_body( &item_fn.sig, quote!{ #name }, 0, codec )?;
+
+	Ok(quote! {
+		#item_fn
+
+		#[doc(hidden)]
+		#vis fn #command_name() -> ::browser_window::ipc::Command {
+			::browser_window::ipc::Command::new(
+				#js_name,
+				<#codec as ::browser_window::ipc::Codec>::name(),
+				<#codec as ::browser_window::ipc::Codec>::js(),
+				|handle, args| {
+					let _ = &handle;
+					::std::boxed::Box::pin( #body )
+				}
+			)
+		}
+	})
+}
+
+/// Generates the match arm that decodes the arguments, calls the method and encodes its output.
+fn expand_method( method: &ImplItemMethod, codec: &Path ) -> syn::Result<TokenStream2> {
+	let name = &method.sig.ident;
+	let name_str = name.to_string();
+	let body = expand_body( &method.sig, quote!{ this.#name }, 1, codec )?;
+
+	Ok(quote! {
+		#name_str => ::std::option::Option::Some( ::std::boxed::Box::pin( #body ) ),
+	})
+}
+
+/// Generates the async block that decodes the arguments from `args`, calls `callee` with them, and encodes its output.
+/// The first `skip` inputs of the signature are not decoded, like the receiver of a method.
+fn expand_body( sig: &Signature, callee: TokenStream2, skip: usize, codec: &Path ) -> syn::Result<TokenStream2> {
+	let name_str = sig.ident.to_string();
+
+	let mut arg_names = Vec::new();
+	let mut arg_decls = Vec::new();
+	let mut encoded_arg_count: usize = 0;
+
+	for (i, input) in sig.inputs.iter().skip( skip ).enumerate() {
+		let pat_type = match input {
+			FnArg::Typed( t ) => t,
+			FnArg::Receiver( r ) => return Err( syn::Error::new_spanned( r, "unexpected receiver" ) )
+		};
+		let ty = &pat_type.ty;
+		let ident = syn::Ident::new( &format!("arg{}", i), Span::call_site() );
+		let display_name = match &*pat_type.pat {
+			Pat::Ident( p ) => p.ident.to_string(),
+			_ => format!("#{}", i)
+		};
+
+		// A parameter of type `BrowserWindowHandle` receives the handle of the invoking window.
+		if type_name( ty ).as_deref() == Some("BrowserWindowHandle") {
+			arg_decls.push( quote! { let #ident: #ty = handle; } );
+		}
+		else {
+			encoded_arg_count += 1;
+			arg_decls.push( quote! {
+				let #ident: #ty = <#codec as ::browser_window::ipc::Codec>::decode( args.next().as_deref() )
+					.map_err(|e| ::std::format!( "invalid argument `{}` for `{}`: {}", #display_name, #name_str, e ) )?;
+			} );
+		}
+		arg_names.push( ident );
+	}
+
+	let call = if sig.asyncness.is_some() {
+		quote! { #callee( #(#arg_names),* ).await }
+	}
+	else {
+		quote! { #callee( #(#arg_names),* ) }
+	};
+
+	// Methods that return a `Result` reject the JavaScript promise with the error message.
+	let output = if returns_result( &sig.output ) {
+		quote! {
+			match #call {
+				::std::result::Result::Err( e ) => ::std::result::Result::Err( e.to_string() ),
+				::std::result::Result::Ok( value ) => <#codec as ::browser_window::ipc::Codec>::encode( &value )
+			}
+		}
+	}
+	else {
+		quote! {
+			<#codec as ::browser_window::ipc::Codec>::encode( &#call )
+		}
+	};
+
+	Ok(quote! {
+		async move {
+			if args.len() > #encoded_arg_count {
+				return ::std::result::Result::Err( ::std::format!( "`{}` takes at most {} arguments, {} given", #name_str, #encoded_arg_count, args.len() ) );
+			}
+			let mut args = args.into_iter();
+			#(#arg_decls)*
+
+			#output
+		}
+	})
+}
+
+/// The name of the function that gives the command of the function with the given name.
+fn command_ident( name: &syn::Ident ) -> syn::Ident {
+	syn::Ident::new( &format!( "__bw_command_{}", name ), name.span() )
+}
+
+/// Whether the method is exposed to JavaScript, which is when it is `pub` and takes `&self`.
+fn is_exported( method: &ImplItemMethod ) -> bool {
+	if !matches!( method.vis, Visibility::Public(_) ) {
+		return false;
+	}
+
+	match method.sig.inputs.first() {
+		Some( FnArg::Receiver( r ) ) => r.reference.is_some() && r.mutability.is_none(),
+		_ => false
+	}
+}
+
+fn returns_result( output: &ReturnType ) -> bool {
+	match output {
+		ReturnType::Default => false,
+		ReturnType::Type( _, ty ) => type_name( ty ).as_deref() == Some("Result")
+	}
+}
+
+/// Gives the name of the last path segment of the type, if it is a path.
+fn type_name( ty: &Type ) -> Option<String> {
+	match ty {
+		Type::Path( p ) => p.path.segments.last().map(|s| s.ident.to_string()),
+		_ => None
+	}
+}
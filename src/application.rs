@@ -68,25 +68,69 @@ fn main() {
 }
 ```"#)]
 
+mod engine;
+mod hotkey;
+pub(in crate) mod maintenance;
+mod monitor;
+mod notification;
+#[cfg(feature = "recovery")]
+mod recovery;
+#[cfg(feature = "threadsafe")]
+pub(in crate) mod queue;
+mod theme;
+mod timer;
+pub(in crate) mod trace;
+
 
 
+#[cfg(feature = "threadsafe")]
+use std::any;
+use std::cell::Cell;
+#[cfg(not(feature = "threadsafe"))]
+use std::cell::RefCell;
 use std::env;
 use std::ffi::{CString};
+#[cfg(feature = "threadsafe")]
+use std::mem;
 use std::future::Future;
 #[cfg(feature = "threadsafe")]
 use std::ops::Deref;
 use std::os::raw::{c_int};
+#[cfg(feature = "threadsafe")]
+use std::panic::{AssertUnwindSafe, catch_unwind};
 use std::pin::Pin;
+#[cfg(not(feature = "threadsafe"))]
+use std::rc::Rc;
 use std::ptr;
+#[cfg(feature = "threadsafe")]
+use std::sync::{mpsc, Arc, Mutex};
 use std::task::{Context, Poll, Waker, RawWaker, RawWakerVTable};
 use std::time::Duration;
+#[cfg(feature = "threadsafe")]
+use std::time::Instant;
 
 use browser_window_core::application::*;
 use futures_channel::oneshot;
 use lazy_static::lazy_static;
 
-pub use browser_window_core::application::ApplicationSettings;
+pub use browser_window_core::application::{ApplicationSettings, LogSeverity};
+pub use engine::{Engine, EngineInfo, Feature};
+pub use hotkey::HotkeyHandle;
+pub use maintenance::CacheMaintenance;
+pub use monitor::Monitor;
+pub use notification::Notification;
+#[cfg(feature = "recovery")]
+pub use recovery::{RecoverySnapshot, WindowSnapshot};
+#[cfg(feature = "threadsafe")]
+pub use queue::{DispatchPriority, OverflowStrategy};
+pub use theme::SystemTheme;
+pub use timer::TimerHandle;
+pub use trace::{GuiTrace, GuiTraceError, TraceEntry, TraceEntryKind, TraceReplay};
 
+use crate::browser::{registry, BrowserWindowBuilder, BrowserWindowHandle};
+use crate::clipboard::Clipboard;
+#[cfg(feature = "threadsafe")]
+use crate::clipboard::ClipboardThreaded;
 use crate::cookie::CookieJar;
 #[cfg(feature = "threadsafe")]
 use crate::delegate::*;
@@ -125,13 +169,6 @@ struct ApplicationDispatchData<'a> {
 	func: Box<dyn FnOnce(ApplicationHandle) + 'a>
 }
 
-#[cfg(feature = "threadsafe")]
-struct ApplicationDispatchSendData<'a> {
-
-	handle: ApplicationHandle,
-	func: Box<dyn FnOnce(ApplicationHandle) + Send + 'a>
-}
-
 // The trait to be implemented by all (user-level) handles that are able to return an ApplicationHandle.
 // Like: Application, ApplicationAsync, BrowserWindow, BrowserWindowAsync
 pub trait HasAppHandle {
@@ -153,6 +190,14 @@ struct WakerData<'a> {
 
 
 
+/// A closure that gives a new `BrowserWindowBuilder` its default settings.
+#[cfg(not(feature = "threadsafe"))]
+type WindowConfigFn = Rc<dyn Fn( &mut BrowserWindowBuilder )>;
+#[cfg(feature = "threadsafe")]
+type WindowConfigFn = Arc<dyn Fn( &mut BrowserWindowBuilder ) + Send + Sync>;
+
+
+
 /// The future that dispatches a closure onto the GUI thread
 #[cfg(feature = "threadsafe")]
 pub type ApplicationDelegateFuture<'a,R> = DelegateFuture<'a, ApplicationHandle, R>;
@@ -169,6 +214,21 @@ lazy_static! {
 		)
 	};
 }
+#[cfg(feature = "threadsafe")]
+lazy_static! {
+	// Builders can be created on any thread.
+	static ref DEFAULT_WINDOW_CONFIG: Mutex<Option<WindowConfigFn>> = Mutex::new( None );
+}
+
+thread_local! {
+	// The thread that the application got initialized on, which is the GUI thread.
+	static IS_GUI_THREAD: Cell<bool> = const { Cell::new( false ) };
+}
+#[cfg(not(feature = "threadsafe"))]
+thread_local! {
+	// Without feature `threadsafe`, builders are only created on the GUI thread.
+	static DEFAULT_WINDOW_CONFIG: RefCell<Option<WindowConfigFn>> = const { RefCell::new( None ) };
+}
 
 
 
@@ -193,6 +253,11 @@ impl Application {
 		( vec, vec_ptrs )
 	}
 
+	/// Gives information about the browser engine, and which features it supports.
+	pub fn engine_info( &self ) -> EngineInfo {
+		EngineInfo::new()
+	}
+
 	/// Shuts down other processes and performs any necessary clean-up code.
 	/// This is useful if you main function doesn't exit naturally.
 	/// If you call `std::process::exit`, the variables currently available don't get dropped.
@@ -211,6 +276,16 @@ impl Application {
 	/// # Arguments
 	/// `settings` - Some settings that allow you to tweak some application behaviors.
 	///              Use `Settings::default()` for default settings that work for most people.
+	///
+	/// # Example
+	/// ```ignore
+	/// let settings = ApplicationSettings::default()
+	/// 	.user_data_dir( "data" )
+	/// 	.log_severity( LogSeverity::Warning )
+	/// 	.remote_debugging_port( 9222 )
+	/// 	.engine_switch( "--disable-gpu" );
+	/// let application = Application::initialize( &settings ).unwrap();
+	/// ```
 	pub fn initialize( settings: &ApplicationSettings ) -> error::Result<Application> {
 
 		let (args_vec, mut ptrs_vec) = Self::args_ptr_vec();
@@ -218,6 +293,7 @@ impl Application {
 		let argv = ptrs_vec.as_mut_ptr();
 
 		let core_handle = ApplicationImpl::initialize( argc, argv as _, settings )?;
+		IS_GUI_THREAD.with(|is| is.set( true ) );
 
 		Ok(Application::from_core_handle( core_handle ))
 	}
@@ -307,6 +383,78 @@ impl Runtime {
 		})
 	}
 
+	/// Lets another event loop drive the runtime, instead of running it with `run` or `run_async`.
+	/// This is useful when the main thread is already taken by another event loop, like the one of [winit](https://docs.rs/winit).
+	/// `on_ready` is called right away, after which `pump_events` needs to be called regularly from the other event loop.
+	///
+	/// Like with `run`, the application exits once `on_ready` has returned and all windows have been closed, or when `exit` is called.
+	/// To wake up the runtime from other threads, `ApplicationHandleThreaded` can be used in the same way as with `run`.
+	///
+	/// *Note:* The other event loop needs to keep polling, as it doesn't wake up for the events of the browser engine.
+	/// Menu accelerators only work for key presses that are processed by `pump_events`.
+	///
+	/// # Example
+	/// ```ignore
+	/// let runtime = app.start();
+	/// runtime.begin_pumping(|app| {
+	/// 	let bwb = BrowserWindowBuilder::new( Source::Url( "https://www.rust-lang.org".into() ) );
+	/// 	app.spawn( async move { bwb.build( app ).await.show(); } );
+	/// });
+	///
+	/// event_loop.set_control_flow( ControlFlow::Poll );
+	/// event_loop.run(move |event, target| {
+	/// 	if let Event::AboutToWait = event {
+	/// 		if runtime.pump_events().is_some() {
+	/// 			target.exit();
+	/// 		}
+	/// 	}
+	/// });
+	/// ```
+	pub fn begin_pumping<H>( &self, on_ready: H ) where
+		H: FnOnce( ApplicationHandle )
+	{
+		self.handle.inner.begin_pumping();
+
+		on_ready( self.handle );
+		self.handle.inner.mark_as_done();
+	}
+
+	/// Processes all events that are pending, without waiting for new ones.
+	/// Returns the exit code once the application has exited, after which this shouldn't be called anymore.
+	///
+	/// Can only be used after `begin_pumping`.
+	pub fn pump_events( &self ) -> Option<i32> {
+		self.handle.inner.pump_events()
+	}
+
+	/// Sets a closure that gives every new `BrowserWindowBuilder` its default settings.
+	/// This is useful for things that need to be set up for all windows, like preload scripts, APIs or handlers.
+	///
+	/// The closure is applied when the builder is created with `BrowserWindowBuilder::new`, so before the builder's own configuration.
+	/// This means that any setting made at the window creation site overrides the default one.
+	/// Setting a new closure replaces the previous one, for the builders that are created from then on.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn default_window_config<C>( &self, config: C ) where
+		C: Fn( &mut BrowserWindowBuilder ) + 'static
+	{
+		DEFAULT_WINDOW_CONFIG.with(|cell| {
+			*cell.borrow_mut() = Some( Rc::new( config ) );
+		});
+	}
+
+	/// Sets a closure that gives every new `BrowserWindowBuilder` its default settings.
+	/// This is useful for things that need to be set up for all windows, like preload scripts, APIs or handlers.
+	///
+	/// The closure is applied when the builder is created with `BrowserWindowBuilder::new`, so before the builder's own configuration.
+	/// This means that any setting made at the window creation site overrides the default one.
+	/// Setting a new closure replaces the previous one, for the builders that are created from then on.
+	#[cfg(feature = "threadsafe")]
+	pub fn default_window_config<C>( &self, config: C ) where
+		C: Fn( &mut BrowserWindowBuilder ) + Send + Sync + 'static
+	{
+		*DEFAULT_WINDOW_CONFIG.lock().unwrap() = Some( Arc::new( config ) );
+	}
+
 	/// Use `run_async` instead.
 	pub fn spawn<'a,F>( &'a self, future: F ) where
 		F: Future<Output=()> + 'a
@@ -356,10 +504,20 @@ impl From<ApplicationHandle> for Application {
 
 impl ApplicationHandle {
 
+	/// Gives access to the system's clipboard.
+	pub fn clipboard( &self ) -> Clipboard {
+		Clipboard::new( *self )
+	}
+
 	pub fn cookie_jar(&self) -> CookieJar {
 		CookieJar::global()
 	}
 
+	/// Gives information about the browser engine, and which features it supports.
+	pub fn engine_info( &self ) -> EngineInfo {
+		EngineInfo::new()
+	}
+
 	/// Causes the `Runtime` to terminate.
 	/// The `Runtime`'s [`Runtime::run`] or spawn command will return the exit code provided.
 	/// This will mean that not all tasks might complete.
@@ -368,12 +526,68 @@ impl ApplicationHandle {
 		self.inner.exit( exit_code as _ );
 	}
 
+	/// Gives all displays that are connected to the system.
+	/// This can be used to position windows, like on the display that is not the primary one.
+	pub fn monitors( &self ) -> Vec<Monitor> {
+		self.inner.get_monitors().into_iter().map( Monitor::from ).collect()
+	}
+
 	pub(in super) fn new( inner: ApplicationImpl ) -> Self {
 		Self {
 			inner: inner
 		}
 	}
 
+	/// Shows a native desktop notification.
+	/// Unlike the Web Notification API, this doesn't need the permission of the user.
+	///
+	/// # Arguments
+	/// * `notification` - The contents of the notification.
+	/// * `on_click` - Invoked when the user clicks on the notification. It isn't invoked when the notification gets dismissed instead.
+	///
+	/// *Note:* On Windows, the notification comes from a temporary icon in the notification area.
+	/// Notifications are not supported when CEF provides the windows itself, which is when feature `gtk` is disabled on other platforms than Windows.
+	pub fn notify<H>( &self, notification: Notification, on_click: H ) where
+		H: FnOnce( ApplicationHandle ) + 'static
+	{
+		let icon_path = notification.icon.as_ref().map(|p| p.to_string_lossy().into_owned() ).unwrap_or_default();
+		let handler: notification::NotificationClickHandler = Box::new( on_click );
+		let data_ptr = Box::into_raw( Box::new( handler ) );
+
+		self.inner.notify( &notification.title, &notification.body, &icon_path, notification::notification_handler, data_ptr as _ );
+	}
+
+	/// Registers a key combination, like `Ctrl+Alt+Space`, that invokes `handler` whenever it is pressed, even when none of the windows has focus.
+	/// This is useful for launcher-style applications, that are shown and hidden from anywhere.
+	/// The handler is invoked on the GUI thread.
+	///
+	/// Key combinations are written in the same way as the accelerators of menu items.
+	/// Returns `None` if the key combination is invalid, or if it is already taken by another application.
+	///
+	/// *Note:* Global hotkeys are only supported on Windows and on X11.
+	/// They are not available on Wayland, nor when CEF provides the windows itself, which is when feature `gtk` is disabled on other platforms than Windows.
+	///
+	/// # Example
+	/// ```ignore
+	/// let hotkey = app.register_global_hotkey( "Ctrl+Alt+Space", move |_app| {
+	/// 	launcher.window().show();
+	/// }).expect("hotkey is already in use");
+	/// ```
+	pub fn register_global_hotkey<H>( &self, hotkey: &str, handler: H ) -> Option<HotkeyHandle> where
+		H: FnMut( ApplicationHandle ) + 'static
+	{
+		let id = hotkey::next_hotkey_id();
+		let handler: hotkey::HotkeyHandler = Box::new( handler );
+		let data_ptr = Box::into_raw( Box::new( handler ) );
+
+		if !self.inner.register_global_hotkey( id, hotkey, hotkey::hotkey_handler, data_ptr as _ ) {
+			unsafe { drop( Box::from_raw( data_ptr ) ) };
+			return None;
+		}
+
+		Some( HotkeyHandle::new( *self, id ) )
+	}
+
 	/// **Note:** Only available with feature `threadsafe` enabled.
 	///
 	/// Transforms this application handle into a thread-safe version of it.
@@ -425,6 +639,56 @@ impl ApplicationHandle {
 
 		rx.await.unwrap();
 	}
+
+	/// Executes the given closure `func` on the GUI thread once, after the given `delay`.
+	/// Returns a handle that can be used to cancel the timer before it fires.
+	pub fn set_timeout<F>( &self, delay: Duration, func: F ) -> TimerHandle where
+		F: FnOnce( ApplicationHandle ) + 'static
+	{
+		let timer = TimerHandle::new();
+		let timer2 = timer.clone();
+
+		self.dispatch_delayed(move |handle| {
+			if !timer2.is_cancelled() {
+				func( handle );
+			}
+		}, delay );
+
+		timer
+	}
+
+	/// Executes the given closure `func` on the GUI thread repeatedly, every `interval`.
+	/// The interval starts counting after the previous call has finished.
+	/// Returns a handle that can be used to cancel the timer.
+	pub fn set_interval<F>( &self, interval: Duration, func: F ) -> TimerHandle where
+		F: FnMut( ApplicationHandle ) + 'static
+	{
+		let timer = TimerHandle::new();
+
+		timer::schedule_interval( *self, interval, timer.clone(), func );
+
+		timer
+	}
+
+	/// Gives the browser window that has been given the label with `BrowserWindowBuilder::label`.
+	/// If several windows have the same label, the one that has been created first is given.
+	///
+	/// # Example
+	/// ```ignore
+	/// if let Some( settings ) = app.window_by_label( "settings" ) {
+	/// 	settings.window().show();
+	/// }
+	/// ```
+	pub fn window_by_label( &self, label: &str ) -> Option<BrowserWindowHandle> {
+		registry::by_label( label )
+	}
+
+	/// Gives handles to all browser windows that are open, in the order in which they have been created.
+	/// A window is included from the moment that it has been created, until it has been destroyed.
+	/// Keep in mind that a closed window is only destroyed after its `BrowserWindow` has been dropped as well.
+	pub fn windows( &self ) -> Vec<BrowserWindowHandle> {
+		registry::all()
+	}
 }
 
 
@@ -432,6 +696,11 @@ impl ApplicationHandle {
 #[cfg(feature = "threadsafe")]
 impl ApplicationHandleThreaded {
 
+	/// Gives access to the system's clipboard, from any thread.
+	pub fn clipboard( &self ) -> ClipboardThreaded {
+		ClipboardThreaded::new( *self )
+	}
+
 	/// Executes the given closure `func` on the GUI thread, and gives back the result when done.
 	/// This only works when the runtime is still running.
 	/// If the closure panicked, or the runtime is not running, this will return an error.
@@ -525,15 +794,145 @@ impl ApplicationHandleThreaded {
 	/// Queues the given closure `func` to be executed on the GUI thread somewhere in the future.
 	/// The closure will only execute when and if the runtime is still running.
 	/// Returns whether or not the closure will be able to execute.
+	///
+	/// The closure is dispatched with `DispatchPriority::Normal`, see also `dispatch_with_priority`.
 	pub fn dispatch<'a,F>( &self, func: F ) -> bool where
 		F:  FnOnce( ApplicationHandle ) + Send + 'a
 	{
-		let data_ptr = Box::into_raw( Box::new( ApplicationDispatchSendData {
-			handle: self.handle,
-			func: Box::new( func )
-		} ) );
+		self.dispatch_with_priority( DispatchPriority::Normal, func )
+	}
 
-		self.handle.inner.dispatch( dispatch_handler_send, data_ptr as _ )
+	/// Queues the given closure `func` to be executed on the GUI thread, before any closure of a lower priority that is still waiting.
+	/// The closure will only execute when and if the runtime is still running.
+	/// Returns whether or not the closure will be able to execute.
+	///
+	/// Closures of `DispatchPriority::Low` can be rejected or dropped when too many of them are waiting, see `set_low_priority_limit`.
+	///
+	/// # Example
+	/// ```ignore
+	/// for chunk in rows.chunks( 100 ) {
+	/// 	let chunk = chunk.to_vec();
+	/// 	app.dispatch_with_priority( DispatchPriority::Low, move |_| render_rows( chunk ) );
+	/// }
+	///
+	/// // Still gets executed right away
+	/// app.dispatch_with_priority( DispatchPriority::High, |_| focus_search_field() );
+	/// ```
+	pub fn dispatch_with_priority<'a,F>( &self, priority: DispatchPriority, func: F ) -> bool where
+		F:  FnOnce( ApplicationHandle ) + Send + 'a
+	{
+		let func: Box<dyn FnOnce( ApplicationHandle ) + Send + 'a> = Box::new( func );
+		// The closure is only executed while the runtime is running, just like any other dispatched data that outlives its lifetime.
+		let func: Box<dyn FnOnce( ApplicationHandle ) + Send> = unsafe { mem::transmute( func ) };
+
+		queue::dispatch( self.handle.inner, priority, any::type_name::<F>(), func )
+	}
+
+	/// Executes the given closure `func` on the GUI thread, and blocks the calling thread until it has finished.
+	/// Gives back the output of the closure.
+	/// This is meant for synchronous code that can't use `delegate`.
+	///
+	/// If the closure panicked, or the runtime is not running, this will return an error.
+	///
+	/// When called from the GUI thread itself, the closure is executed right away instead, because waiting for it would block forever.
+	/// It then runs before any closures that are still waiting to be dispatched.
+	///
+	/// # Warning
+	/// If the runtime exits before the closure got the chance to execute, this blocks forever.
+	///
+	/// # Example
+	/// ```ignore
+	/// let title = app.dispatch_blocking(|handle| {
+	/// 	"title".to_owned()
+	/// }).unwrap();
+	/// ```
+	pub fn dispatch_blocking<'a,F,R>( &self, func: F ) -> Result<R, DelegateError> where
+		F: FnOnce( ApplicationHandle ) -> R + Send + 'a,
+		R: Send + 'a
+	{
+		if IS_GUI_THREAD.with(|is| is.get() ) {
+			let result = catch_unwind( AssertUnwindSafe(|| func( self.handle ) ) );
+			// The same as with `delegate`, a panic on the GUI thread terminates the runtime.
+			if result.is_err() {
+				self.handle.inner.exit( -1 );
+			}
+			return result.map_err(|_| DelegateError::ClosurePanicked );
+		}
+
+		let (tx, rx) = mpsc::channel::<Result<R, DelegateError>>();
+
+		let dispatched = self.dispatch(move |handle| {
+			let result = catch_unwind( AssertUnwindSafe(|| func( handle ) ) );
+			let panicked = result.is_err();
+
+			let _ = tx.send( result.map_err(|_| DelegateError::ClosurePanicked ) );
+
+			// The same as with `delegate`, a panic on the GUI thread terminates the runtime.
+			if panicked {
+				handle.inner.exit( -1 );
+			}
+		});
+		if !dispatched {
+			return Err( DelegateError::RuntimeNotAvailable );
+		}
+
+		match rx.recv() {
+			Ok( result ) => result,
+			Err( _ ) => Err( DelegateError::RuntimeNotAvailable )
+		}
+	}
+
+	/// Executes the given closure `func` on the GUI thread, and converts its output with `map` on the GUI thread as well.
+	/// The returned future resolves with the converted output.
+	///
+	/// Unlike with `delegate`, the output of `func` doesn't need to be `Send`, only the output of `map` does.
+	/// This way, GUI-only values can be turned into a summary that can be sent, like a `String` or a list of numbers.
+	/// To keep a GUI-only value around for later use, see `dispatch_ref`.
+	///
+	/// If the runtime is not running, or exits before the closure has been executed, this resolves with `DelegateError::RuntimeNotAvailable`.
+	///
+	/// # Example
+	/// ```ignore
+	/// let text: Option<String> = app.dispatch_map(
+	/// 	|handle| handle.clipboard(),
+	/// 	|clipboard| clipboard.read_text()
+	/// ).await?;
+	/// ```
+	pub fn dispatch_map<'a,F,M,T,R>( &self, func: F, map: M ) -> DispatchFuture<R> where
+		F: FnOnce( ApplicationHandle ) -> T + Send + 'a,
+		M: FnOnce( T ) -> R + Send + 'a,
+		R: Send + 'static
+	{
+		let (tx, rx) = oneshot::channel::<R>();
+
+		let dispatched = self.dispatch(move |handle| {
+			// If the receiver has been dropped, nobody is interested in the output anymore.
+			let _ = tx.send( map( func( handle ) ) );
+		});
+
+		DispatchFuture::new( if dispatched { Some( rx ) } else { None } )
+	}
+
+	/// Executes the given closure `func` on the GUI thread, and keeps its output there.
+	/// The returned future resolves with a `GuiRef`, which can be sent to other threads and with which the output can be used later on.
+	///
+	/// This is for values that can't be sent to other threads, but need to be kept around between dispatches.
+	/// If the runtime is not running, or exits before the closure has been executed, this resolves with `DelegateError::RuntimeNotAvailable`.
+	///
+	/// # Example
+	/// ```ignore
+	/// let clipboard = app.dispatch_ref(|handle| handle.clipboard() ).await?;
+	///
+	/// // Later on, from any thread:
+	/// clipboard.with(|clipboard| clipboard.write_text( "copied" ) ).await?;
+	/// ```
+	pub fn dispatch_ref<'a,F,T>( &self, func: F ) -> DispatchFuture<GuiRef<T>> where
+		F: FnOnce( ApplicationHandle ) -> T + Send + 'a,
+		T: 'static
+	{
+		let app = *self;
+
+		self.dispatch_map( func, move |value| GuiRef::new( app.handle, value ) )
 	}
 
 	/// Queues the given closure `func` to be executed on the GUI thread somewhere in the future, at least after the given delay.
@@ -550,19 +949,104 @@ impl ApplicationHandleThreaded {
 		self.handle.inner.dispatch_delayed( dispatch_handler, data_ptr as _, delay )
 	}
 
+	/// Queues the given closure `func` to be executed on the GUI thread after the given `delay` has passed.
+	/// The closure will only execute when and if the runtime is still running.
+	/// Returns whether or not the closure will be able to execute.
+	///
+	/// This is useful for debouncing, for example.
+	pub fn dispatch_after<'a,F>( &self, delay: Duration, func: F ) -> bool where
+		F:  FnOnce( ApplicationHandle ) + Send + 'a
+	{
+		self.dispatch_delayed( func, delay )
+	}
+
+	/// Queues the given closure `func` to be executed on the GUI thread at the given point in time.
+	/// If `instant` lies in the past, the closure is executed as soon as possible.
+	/// The closure will only execute when and if the runtime is still running.
+	/// Returns whether or not the closure will be able to execute.
+	pub fn dispatch_at<'a,F>( &self, instant: Instant, func: F ) -> bool where
+		F:  FnOnce( ApplicationHandle ) + Send + 'a
+	{
+		let delay = instant.saturating_duration_since( Instant::now() );
+
+		self.dispatch_delayed( func, delay )
+	}
+
+	/// Limits the number of closures of `DispatchPriority::Low` that can be waiting to be executed at the same time.
+	/// When the limit is reached, `overflow` decides what happens to the next one that is dispatched.
+	/// By default, there is no limit.
+	///
+	/// This keeps bulk work from piling up on the GUI thread, when it is dispatched faster than it can be executed.
+	/// Closures of higher priorities are never limited.
+	pub fn set_low_priority_limit( &self, capacity: usize, overflow: OverflowStrategy ) {
+		queue::set_low_priority_limit( capacity, overflow );
+	}
+
+	/// Executes the given closure `func` on the GUI thread once, after the given `delay`.
+	/// Returns a handle that can be used to cancel the timer from any thread.
+	pub fn set_timeout<F>( &self, delay: Duration, func: F ) -> TimerHandle where
+		F: FnOnce( ApplicationHandle ) + Send + 'static
+	{
+		let timer = TimerHandle::new();
+		let timer2 = timer.clone();
+
+		self.dispatch_delayed(move |handle| {
+			if !timer2.is_cancelled() {
+				func( handle );
+			}
+		}, delay );
+
+		timer
+	}
+
+	/// Executes the given closure `func` on the GUI thread repeatedly, every `interval`.
+	/// The interval starts counting after the previous call has finished.
+	/// Returns a handle that can be used to cancel the timer from any thread.
+	pub fn set_interval<F>( &self, interval: Duration, func: F ) -> TimerHandle where
+		F: FnMut( ApplicationHandle ) + Send + 'static
+	{
+		let timer = TimerHandle::new();
+		let timer2 = timer.clone();
+
+		self.dispatch(move |handle| {
+			timer::schedule_interval( handle, interval, timer2, func );
+		});
+
+		timer
+	}
+
 	/// Queues the given async closure `func` to be executed on the GUI thread somewhere in the future.
 	/// The closure will only execute when and if the runtime is still running.
 	/// However, there is no guarantee that the whole closure will execute.
 	/// The runtime might exit when the given closure is at a point of waiting.
-	/// Returns whether or not the closure will be able to execute its first part.
-	pub fn dispatch_async<'a,C,F>( &self, func: C ) -> bool where
+	///
+	/// The returned future resolves with the output of the closure's future, once it has completed on the GUI thread.
+	/// It doesn't need to be awaited for the closure to execute.
+	/// If the runtime is not running, or exits before the closure's future completes, it resolves with `DelegateError::RuntimeNotAvailable`.
+	///
+	/// # Example
+	/// ```ignore
+	/// let title: String = app.dispatch_async(|handle| async move {
+	/// 	handle.sleep( Duration::from_millis(100) ).await;
+	/// 	"title".to_owned()
+	/// }).await.unwrap();
+	/// ```
+	pub fn dispatch_async<'a,C,F,R>( &self, func: C ) -> DispatchFuture<R> where
 		C: FnOnce( ApplicationHandle ) -> F + Send + 'a,
-		F: Future<Output=()> + 'static
+		F: Future<Output=R> + 'static,
+		R: Send + 'static
 	{
-		self.dispatch(|handle| {
+		let (tx, rx) = oneshot::channel::<R>();
+
+		let dispatched = self.dispatch(move |handle| {
 			let future = func( handle );
-			handle.spawn( future );
-		})
+			handle.spawn( async move {
+				// If the receiver has been dropped, nobody is interested in the output anymore.
+				let _ = tx.send( future.await );
+			} );
+		});
+
+		DispatchFuture::new( if dispatched { Some( rx ) } else { None } )
 	}
 
 	/// Signals the runtime to exit.
@@ -579,6 +1063,11 @@ impl ApplicationHandleThreaded {
 		}
 	}
 
+	/// Same as `ApplicationHandle::monitors`, but reads them on the GUI thread.
+	pub async fn monitors( &self ) -> Result<Vec<Monitor>, DelegateError> {
+		self.delegate(|app| app.monitors() ).await
+	}
+
 	/// Executes the given future on the GUI thread somewhere in the near future.
 	pub fn spawn<F>( &self, future: F ) where
 		F: Future<Output=()> + 'static
@@ -624,13 +1113,17 @@ unsafe fn dispatch_handler( _app: ApplicationImpl, _data: *mut () ) {
 	(data.func)( data.handle.into() );
 }
 
-#[cfg(feature = "threadsafe")]
-unsafe fn dispatch_handler_send( _app: ApplicationImpl, _data: *mut () ) {
-
-	let data_ptr = _data as *mut ApplicationDispatchSendData<'static>;
-	let data = Box::from_raw( data_ptr );
+/// Gives a new builder the default settings of `Runtime::default_window_config`, if any.
+pub(in crate) fn apply_default_window_config( builder: &mut BrowserWindowBuilder ) {
+	// The closure isn't borrowed while it runs, so that it can set a new closure itself
+	#[cfg(not(feature = "threadsafe"))]
+	let config = DEFAULT_WINDOW_CONFIG.with(|cell| cell.borrow().clone() );
+	#[cfg(feature = "threadsafe")]
+	let config = DEFAULT_WINDOW_CONFIG.lock().unwrap().clone();
 
-	(data.func)( data.handle.into() );
+	if let Some( config ) = config {
+		config( builder );
+	}
 }
 
 /// The handler that is invoked when the runtime is deemed 'ready'.
@@ -0,0 +1,78 @@
+use browser_window_core::application::{ApplicationExt, ApplicationImpl};
+
+
+
+/// The browser engine that _Browser Window_ has been built with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Engine {
+	/// The Chromium Embedded Framework
+	Cef,
+	/// No browser engine at all, but the in-memory page model of feature `mock`
+	Mock,
+	/// Microsoft Edge WebView2, which is based on Chromium as well
+	WebView2
+}
+
+/// A capability that not all browser engines have.
+/// Use `EngineInfo::supports` to check if it is available.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Feature {
+	/// `BrowserWindowHandle::set_charset_override`
+	CharsetOverride,
+	/// `BrowserWindowHandle::clear_origin_data`
+	ClearOriginData,
+	/// Storing, iterating and deleting cookies with the `CookieJar`
+	CookieJar,
+	/// `BrowserWindowBuilder::default_encoding`
+	DefaultEncoding,
+	/// Access to subframes, with `eval_js_in_frame`, `frames`, `frame_tree` and `navigate_frame`
+	Frames,
+	/// `ApplicationSettings::headless`
+	Headless
+}
+
+/// Information about the browser engine.
+#[derive(Clone, Debug)]
+pub struct EngineInfo {
+	engine: Engine,
+	version: Option<String>
+}
+
+
+
+impl EngineInfo {
+
+	pub(in super) fn new() -> Self {
+		Self {
+			engine: if cfg!(feature = "mock") { Engine::Mock } else if cfg!(feature = "webview2") { Engine::WebView2 } else { Engine::Cef },
+			version: ApplicationImpl::engine_version()
+		}
+	}
+
+	pub fn engine( &self ) -> Engine {
+		self.engine
+	}
+
+	/// Whether or not the browser engine has the given capability.
+	pub fn supports( &self, feature: Feature ) -> bool {
+		match self.engine {
+			Engine::Cef => true,
+			// The page model accepts everything, even if it doesn't do much with it
+			Engine::Mock => true,
+			Engine::WebView2 => match feature {
+				Feature::CharsetOverride |
+				Feature::CookieJar |
+				Feature::DefaultEncoding |
+				Feature::Frames => false,
+				Feature::ClearOriginData |
+				Feature::Headless => true
+			}
+		}
+	}
+
+	/// The version of the browser engine, like `"91.1.21+g9dd45fe+chromium-91.0.4472.114"` for CEF.
+	/// Is `None` if the version could not be determined, which can happen when the WebView2 runtime is not installed.
+	pub fn version( &self ) -> Option<&str> {
+		self.version.as_ref().map(|v| v.as_str() )
+	}
+}
@@ -0,0 +1,56 @@
+use super::ApplicationHandle;
+
+use browser_window_core::application::*;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+
+
+/// A global hotkey that has been registered with `ApplicationHandle::register_global_hotkey`.
+/// It can be used to unregister the hotkey again.
+///
+/// Dropping the handle does not unregister the hotkey.
+pub struct HotkeyHandle {
+	app: ApplicationHandle,
+	id: u32
+}
+
+pub(in super) type HotkeyHandler = Box<dyn FnMut( ApplicationHandle )>;
+
+static NEXT_HOTKEY_ID: AtomicU32 = AtomicU32::new( 1 );
+
+
+
+impl HotkeyHandle {
+
+	pub(in super) fn new( app: ApplicationHandle, id: u32 ) -> Self {
+		Self { app, id }
+	}
+
+	/// Gives back the key combination to the system, so that other applications can use it again.
+	/// The handler is dropped.
+	///
+	/// This happens once the handler that is running, if any, has returned, so a hotkey can unregister itself from its own handler.
+	pub fn unregister( self ) {
+		self.app.inner.dispatch( unregister_handler, self.id as usize as _ );
+	}
+}
+
+
+
+pub(in super) unsafe fn hotkey_handler( app: ApplicationImpl, _hotkey_id: u32, data: *mut () ) {
+	let handler = &mut *( data as *mut HotkeyHandler );
+
+	handler( ApplicationHandle::new( app ) );
+}
+
+unsafe fn unregister_handler( app: ApplicationImpl, data: *mut () ) {
+	let data_ptr = app.unregister_global_hotkey( data as usize as u32 );
+
+	if !data_ptr.is_null() {
+		drop( Box::from_raw( data_ptr as *mut HotkeyHandler ) );
+	}
+}
+
+pub(in super) fn next_hotkey_id() -> u32 {
+	NEXT_HOTKEY_ID.fetch_add( 1, Ordering::Relaxed )
+}
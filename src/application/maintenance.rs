@@ -0,0 +1,232 @@
+use std::{
+	fs,
+	io,
+	path::{Path, PathBuf},
+	sync::{
+		Arc,
+		atomic::{AtomicBool, AtomicU64, Ordering}
+	},
+	thread,
+	time::{Duration, Instant, SystemTime}
+};
+
+use lazy_static::lazy_static;
+
+use super::{ApplicationHandle, TimerHandle};
+
+
+
+/// How often is checked whether the application is idle, and whether a pass can be made.
+const CHECK_INTERVAL: Duration = Duration::from_secs( 30 );
+
+lazy_static! {
+	static ref START: Instant = Instant::now();
+}
+// The moment of the last activity, in milliseconds since `START`.
+static LAST_ACTIVITY: AtomicU64 = AtomicU64::new( 0 );
+
+
+
+/// A maintenance task that keeps the cache of the browser engine and its crash dumps from filling the disk, for applications that run for a long time, like kiosks.
+/// It is started with `ApplicationHandle::start_cache_maintenance`.
+///
+/// The task only does its work once the application has been idle for a while, which is when none of its browser windows has reported anything, like navigations, messages from their pages or changes of focus.
+/// The work is done on a thread of its own, a bit at a time, so that the application stays responsive when it becomes active again.
+///
+/// # Example
+/// ```ignore
+/// let maintenance = CacheMaintenance::default()
+/// 	.cache( "data/cache", 512 * 1024 * 1024 )
+/// 	.crash_dumps( "data/Crashpad/reports" )
+/// 	.keep_crash_dumps( 5, Duration::from_secs( 7 * 24 * 60 * 60 ) );
+/// app.start_cache_maintenance( maintenance );
+/// ```
+#[derive(Clone, Debug)]
+pub struct CacheMaintenance {
+	batch_size: usize,
+	caches: Vec<(PathBuf, u64)>,
+	crash_dump_age: Duration,
+	crash_dump_count: usize,
+	crash_dumps: Vec<PathBuf>,
+	idle_after: Duration
+}
+
+
+
+impl CacheMaintenance {
+
+	/// Sets the maximum number of files that are removed in one go, which is 100 by default.
+	/// The remaining files are removed in the next passes, while the application is still idle.
+	pub fn batch_size( mut self, files: usize ) -> Self {
+		self.batch_size = files.max( 1 );
+		self
+	}
+
+	/// Keeps the total size of the files in the directory at `path` under `max_bytes`.
+	/// When it is over the limit, the files that haven't been written to for the longest time are removed first.
+	///
+	/// This is meant for the cache of the browser engine, like the one of `ApplicationSettings::cache_path`, and not for the user data.
+	/// The engine doesn't mind if files of its cache are gone, it just downloads them again.
+	pub fn cache<P: Into<PathBuf>>( mut self, path: P, max_bytes: u64 ) -> Self {
+		self.caches.push( (path.into(), max_bytes) );
+		self
+	}
+
+	/// Prunes the crash dumps in the directory at `path`, which are the files with extension `dmp`.
+	/// See `keep_crash_dumps` for which ones are kept.
+	pub fn crash_dumps<P: Into<PathBuf>>( mut self, path: P ) -> Self {
+		self.crash_dumps.push( path.into() );
+		self
+	}
+
+	/// Sets how long the application needs to be idle before any work is done, which is 2 minutes by default.
+	pub fn idle_after( mut self, duration: Duration ) -> Self {
+		self.idle_after = duration;
+		self
+	}
+
+	/// Sets which crash dumps are kept: at most `count` of the most recent ones, and none that are older than `max_age`.
+	/// By default, 10 crash dumps of up to 30 days old are kept.
+	pub fn keep_crash_dumps( mut self, count: usize, max_age: Duration ) -> Self {
+		self.crash_dump_count = count;
+		self.crash_dump_age = max_age;
+		self
+	}
+
+	/// Does one pass of the maintenance right away, regardless of whether the application is idle.
+	/// Returns the number of files that have been removed, which is at most the batch size.
+	pub fn run_once( &self ) -> io::Result<usize> {
+		let mut budget = self.batch_size;
+
+		for dir in &self.crash_dumps {
+			budget -= prune_crash_dumps( dir, self.crash_dump_count, self.crash_dump_age, budget )?;
+		}
+		for (dir, max_bytes) in &self.caches {
+			budget -= prune_directory( dir, *max_bytes, budget )?;
+		}
+
+		Ok( self.batch_size - budget )
+	}
+}
+
+impl Default for CacheMaintenance {
+	fn default() -> Self {
+		Self {
+			batch_size: 100,
+			caches: Vec::new(),
+			crash_dump_age: Duration::from_secs( 30 * 24 * 60 * 60 ),
+			crash_dump_count: 10,
+			crash_dumps: Vec::new(),
+			idle_after: Duration::from_secs( 2 * 60 )
+		}
+	}
+}
+
+impl ApplicationHandle {
+
+	/// Starts the given maintenance task, that prunes the cache and crash dumps while the application is idle.
+	/// See `CacheMaintenance` for more information.
+	///
+	/// The task keeps running until the returned handle is cancelled.
+	/// Errors, like files that can't be removed because the engine has them opened, are skipped until the next pass.
+	pub fn start_cache_maintenance( &self, maintenance: CacheMaintenance ) -> TimerHandle {
+		let maintenance = Arc::new( maintenance );
+		let running = Arc::new( AtomicBool::new( false ) );
+		// Once a pass hasn't removed anything, there is no need to go on until there has been activity again
+		let done_since = Arc::new( AtomicU64::new( u64::MAX ) );
+
+		self.set_interval( CHECK_INTERVAL, move |_| {
+			let last_activity = LAST_ACTIVITY.load( Ordering::Relaxed );
+			if millis_since_start().saturating_sub( last_activity ) < maintenance.idle_after.as_millis() as u64 { return }
+			if done_since.load( Ordering::Relaxed ) == last_activity { return }
+			if running.swap( true, Ordering::AcqRel ) { return }
+
+			let (maintenance, running, done_since) = (maintenance.clone(), running.clone(), done_since.clone());
+			thread::spawn(move || {
+				// A pass that fails is ignored, the files that are in the way are tried again in the next one
+				if let Ok( 0 ) = maintenance.run_once() {
+					done_since.store( last_activity, Ordering::Relaxed );
+				}
+				running.store( false, Ordering::Release );
+			});
+		})
+	}
+}
+
+
+
+/// Marks the application as active, so that the maintenance waits for it to be idle again.
+pub(in crate) fn note_activity() {
+	LAST_ACTIVITY.store( millis_since_start(), Ordering::Relaxed );
+}
+
+fn millis_since_start() -> u64 {
+	START.elapsed().as_millis() as u64
+}
+
+/// Removes the files in `dir` and its subdirectories that haven't been modified for the longest time, until their total size is at most `max_bytes`.
+/// No more than `budget` files are removed, and the number of removed files is returned.
+/// A directory that doesn't exist is considered to be empty.
+pub(in crate) fn prune_directory( dir: &Path, max_bytes: u64, budget: usize ) -> io::Result<usize> {
+	let mut files = Vec::new();
+	list_files( dir, &mut files )?;
+
+	let mut total: u64 = files.iter().map(|f| f.1 ).sum();
+	files.sort_by_key(|f| f.2 );
+
+	let mut removed = 0;
+	for (path, size, _) in files {
+		if total <= max_bytes || removed == budget { break }
+
+		// Files that are in use can't always be removed, they are left for later
+		if fs::remove_file( &path ).is_ok() {
+			total -= size;
+			removed += 1;
+		}
+	}
+	Ok( removed )
+}
+
+/// Removes the crash dumps in `dir` that are older than `max_age`, and the oldest ones that exceed `count`.
+/// No more than `budget` files are removed, and the number of removed files is returned.
+fn prune_crash_dumps( dir: &Path, count: usize, max_age: Duration, budget: usize ) -> io::Result<usize> {
+	let mut dumps = Vec::new();
+	list_files( dir, &mut dumps )?;
+	dumps.retain(|f| f.0.extension().map(|e| e == "dmp" ).unwrap_or( false ) );
+
+	// The most recent ones first
+	dumps.sort_by_key(|f| std::cmp::Reverse( f.2 ) );
+	let now = SystemTime::now();
+
+	let mut removed = 0;
+	for (index, (path, _, modified)) in dumps.into_iter().enumerate() {
+		if removed == budget { break }
+
+		let too_old = now.duration_since( modified ).map(|age| age > max_age ).unwrap_or( false );
+		if (index >= count || too_old) && fs::remove_file( &path ).is_ok() {
+			removed += 1;
+		}
+	}
+	Ok( removed )
+}
+
+/// Adds all files in `dir` and its subdirectories to `files`, with their size and the moment that they have been modified.
+fn list_files( dir: &Path, files: &mut Vec<(PathBuf, u64, SystemTime)> ) -> io::Result<()> {
+	let entries = match fs::read_dir( dir ) {
+		Err( e ) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+		other => other?
+	};
+
+	for entry in entries {
+		let entry = entry?;
+		let metadata = entry.metadata()?;
+
+		if metadata.is_dir() {
+			list_files( &entry.path(), files )?;
+		}
+		else {
+			files.push( (entry.path(), metadata.len(), metadata.modified().unwrap_or( SystemTime::UNIX_EPOCH )) );
+		}
+	}
+	Ok(())
+}
@@ -0,0 +1,53 @@
+use browser_window_core::application::Monitor as MonitorImpl;
+
+use crate::browser::Rect;
+
+
+
+/// A display that is connected to the system, as given by `ApplicationHandle::monitors` and `WindowHandle::current_monitor`.
+///
+/// The areas are in the same units as the positions and sizes of windows.
+/// On Windows, those are physical pixels, elsewhere they are logical pixels.
+/// So to place a window in the middle of a display, its size can be compared with the work area right away.
+///
+/// # Example
+/// ```ignore
+/// if let Some( monitor ) = app.monitors().into_iter().find(|m| m.is_primary ) {
+/// 	let area = monitor.work_area;
+/// 	bw.position().set( Pos2D::new( area.x as _, area.y as _ ) );
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Monitor {
+	/// The area that the display covers on the desktop.
+	/// Displays other than the primary one may have a negative position.
+	pub bounds: Rect,
+	/// The part of the display that isn't covered by taskbars, docks or panels.
+	pub work_area: Rect,
+	/// The number of physical pixels that make up one logical pixel on the display.
+	pub scale_factor: f32,
+	pub is_primary: bool
+}
+
+
+
+impl From<MonitorImpl> for Monitor {
+	fn from( monitor: MonitorImpl ) -> Self {
+		Self {
+			bounds: Rect {
+				x: monitor.x,
+				y: monitor.y,
+				width: monitor.width,
+				height: monitor.height
+			},
+			work_area: Rect {
+				x: monitor.work_x,
+				y: monitor.work_y,
+				width: monitor.work_width,
+				height: monitor.work_height
+			},
+			scale_factor: monitor.scale_factor,
+			is_primary: monitor.is_primary != 0
+		}
+	}
+}
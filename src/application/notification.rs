@@ -0,0 +1,39 @@
+use super::ApplicationHandle;
+
+use browser_window_core::application::*;
+use std::path::PathBuf;
+
+
+
+/// A desktop notification, as it is shown by `ApplicationHandle::notify`.
+///
+/// # Example
+/// ```ignore
+/// app.notify( Notification {
+/// 	title: "Download finished".into(),
+/// 	body: "report.pdf has been saved to your downloads.".into(),
+/// 	icon: None
+/// }, |_app| {
+/// 	// Open the downloads page ...
+/// });
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Notification {
+	pub title: String,
+	pub body: String,
+	/// The image that is shown with the notification.
+	/// On Windows, this needs to be an `.ico` file.
+	pub icon: Option<PathBuf>
+}
+
+pub(in super) type NotificationClickHandler = Box<dyn FnOnce( ApplicationHandle )>;
+
+
+
+pub(in super) unsafe fn notification_handler( app: ApplicationImpl, clicked: bool, data: *mut () ) {
+	let on_click = Box::from_raw( data as *mut NotificationClickHandler );
+
+	if clicked {
+		on_click( ApplicationHandle::new( app ) );
+	}
+}
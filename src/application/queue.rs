@@ -0,0 +1,149 @@
+use std::{
+	collections::VecDeque,
+	ptr,
+	sync::Mutex
+};
+
+use browser_window_core::application::*;
+use lazy_static::lazy_static;
+use unsafe_send_sync::UnsafeSend;
+
+use super::{trace, ApplicationHandle};
+
+
+
+/// How urgently a closure that is dispatched to the GUI thread needs to be executed.
+///
+/// Whenever the GUI thread gets to a dispatched closure, it executes the most urgent one that is waiting, regardless of the order in which they have been dispatched.
+/// Closures of the same priority are executed in the order in which they have been dispatched.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DispatchPriority {
+	/// For work that responds to user input, which should be done before anything else.
+	High,
+	/// The priority of `dispatch`, `delegate` and the other dispatching functions.
+	Normal,
+	/// For bulk work in the background, which may wait until the GUI thread has nothing else to do.
+	/// Only closures of this priority are subject to the limit set with `ApplicationHandleThreaded::set_low_priority_limit`.
+	Low
+}
+
+/// What happens to a closure of low priority that is dispatched while the maximum number of them are already waiting.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OverflowStrategy {
+	/// The new closure is not dispatched, and the dispatching function returns false.
+	Reject,
+	/// The oldest closure that is waiting is dropped without being executed, to make room for the new one.
+	DropOldest
+}
+
+type QueuedFn = Box<dyn FnOnce( ApplicationHandle ) + Send>;
+
+struct DispatchQueue {
+	/// The waiting closures with their tickets, one queue for every priority.
+	queues: [VecDeque<(u64, QueuedFn)>; 3],
+	low_priority_limit: usize,
+	/// The ticket of the next closure, by which it can be taken out of the queue again.
+	next_ticket: u64,
+	overflow: OverflowStrategy
+}
+
+
+
+lazy_static! {
+	// There is only one application per process, so there is only one queue.
+	static ref QUEUE: Mutex<DispatchQueue> = Mutex::new( DispatchQueue {
+		queues: [VecDeque::new(), VecDeque::new(), VecDeque::new()],
+		low_priority_limit: usize::MAX,
+		next_ticket: 0,
+		overflow: OverflowStrategy::Reject
+	} );
+}
+
+
+
+impl DispatchPriority {
+
+	fn index( &self ) -> usize {
+		match self {
+			Self::High => 0,
+			Self::Normal => 1,
+			Self::Low => 2
+		}
+	}
+}
+
+
+
+/// Queues `func` with the given priority, and dispatches a call to `pump_handler` that executes the most urgent closure.
+/// Every queued closure has a call of its own, so that the GUI thread keeps handling other events in between closures.
+/// Returns false if the closure has been rejected, or if the runtime is not running.
+/// The name is the one that the closure is traced by, see `ApplicationHandle::start_gui_trace`.
+pub(in crate) fn dispatch( app: ApplicationImpl, priority: DispatchPriority, name: &'static str, func: QueuedFn ) -> bool {
+	let ticket = {
+		let mut queue = QUEUE.lock().unwrap();
+		let limit = queue.low_priority_limit;
+		let overflow = queue.overflow;
+		let ticket = queue.next_ticket;
+		queue.next_ticket += 1;
+		let waiting = &mut queue.queues[ priority.index() ];
+
+		if priority == DispatchPriority::Low && waiting.len() >= limit {
+			match overflow {
+				OverflowStrategy::Reject => return false,
+				OverflowStrategy::DropOldest => {
+					// The call that was dispatched for the dropped closure will execute the next one instead.
+					if waiting.pop_front().is_none() {
+						return false;
+					}
+				}
+			}
+		}
+
+		waiting.push_back( (ticket, trace::dispatched( name, priority, func )) );
+		ticket
+	};
+
+	let dispatched = app.dispatch( pump_handler, ptr::null_mut() );
+
+	// Without a running runtime, no call will ever take the closure out of the queue again.
+	// Other threads may have queued closures after it in the meantime, so it is looked up by its ticket.
+	if !dispatched {
+		let removed = {
+			let mut queue = QUEUE.lock().unwrap();
+			let waiting = &mut queue.queues[ priority.index() ];
+			waiting.iter().position(|(t, _)| *t == ticket ).and_then(|index| waiting.remove( index ) )
+		};
+		// The closure may capture anything, so it is dropped outside of the lock
+		drop( removed );
+	}
+	dispatched
+}
+
+/// Like `dispatch`, but queues a handler with its data, like `ApplicationImpl::dispatch` takes them.
+pub(in crate) fn dispatch_raw( app: ApplicationImpl, priority: DispatchPriority, name: &'static str, handler: unsafe fn( ApplicationImpl, *mut () ), data: UnsafeSend<*mut ()> ) -> bool {
+	dispatch( app, priority, name, Box::new(move |handle| unsafe {
+		handler( handle.inner, data.i )
+	}) )
+}
+
+/// Sets the maximum number of closures of low priority that can be waiting at the same time.
+pub(in crate) fn set_low_priority_limit( capacity: usize, overflow: OverflowStrategy ) {
+	let mut queue = QUEUE.lock().unwrap();
+
+	queue.low_priority_limit = capacity;
+	queue.overflow = overflow;
+}
+
+unsafe fn pump_handler( app: ApplicationImpl, _data: *mut () ) {
+
+	// The lock is released before the closure executes, so that it can dispatch closures itself.
+	let func = {
+		let mut queue = QUEUE.lock().unwrap();
+		queue.queues.iter_mut().find_map(|waiting| waiting.pop_front() ).map(|(_, func)| func )
+	};
+
+	// The closure may have been dropped from a full queue.
+	if let Some( func ) = func {
+		func( ApplicationHandle::new( app ) );
+	}
+}
@@ -0,0 +1,253 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+
+use super::{Application, ApplicationHandle, Runtime};
+use crate::browser::{registry, session, BrowserWindow, BrowserWindowBuilder, BrowserWindowHandle, JsValue, Source};
+use crate::prelude::*;
+
+
+
+/// The version of the format of the snapshot, which is increased whenever it changes in an incompatible way.
+const FORMAT_VERSION: u64 = 1;
+
+/// The compression level that is used for zstd, which is its default.
+const COMPRESSION_LEVEL: i32 = 3;
+
+/// A closure that gives the value of a slot, at the moment that the snapshot is written.
+type SlotHook = Box<dyn Fn() -> serde_json::Result<Value>>;
+
+thread_local! {
+	// Snapshots are written on the GUI thread, so that is where the hooks are kept.
+	static SLOT_HOOKS: RefCell<BTreeMap<String, SlotHook>> = RefCell::new( BTreeMap::new() );
+}
+
+
+
+/// The state of an application as it has been read with `Runtime::recover_from`.
+///
+/// A snapshot contains the browser windows that were open, with their address, title, position, size and scroll position.
+/// It also contains the slots, which are named values that the application itself has put in it with `ApplicationHandle::recovery_slot`.
+/// It is stored as JSON, compressed with [zstd](https://facebook.github.io/zstd/).
+///
+/// *Note:* Only available with feature `recovery` enabled.
+#[derive(Clone, Debug, Default)]
+pub struct RecoverySnapshot {
+	/// The browser windows that were open, in the order in which they have been created.
+	pub windows: Vec<WindowSnapshot>,
+	slots: BTreeMap<String, Value>
+}
+
+/// The state of a browser window, as it is part of a `RecoverySnapshot`.
+#[derive(Clone, Debug)]
+pub struct WindowSnapshot {
+	/// The label of the window, if it had one, see `BrowserWindowBuilder::label`.
+	pub label: Option<String>,
+	/// The address of the page that was displayed.
+	/// For pages that were given as HTML, this doesn't point to the HTML itself.
+	pub url: String,
+	/// The title of the window.
+	pub title: String,
+	/// The position of the top left corner of the window.
+	pub position: (u16, u16),
+	/// The size of the window, including its border and title bar.
+	pub size: (u16, u16),
+	/// How far the page was scrolled, horizontally and vertically, in CSS pixels.
+	pub scroll: (f64, f64)
+}
+
+
+
+impl RecoverySnapshot {
+
+	/// Restores the windows of the snapshot, by building a browser window for each of them.
+	/// Because handlers and APIs can't be part of a snapshot, `configure` is called with the builder of every window, so that it can be set up like it was before.
+	///
+	/// # Example
+	/// ```ignore
+	/// match runtime.recover_from( "state.zst" ) {
+	/// 	Ok( snapshot ) => runtime.run_async(|app| async move {
+	/// 		snapshot.restore( app, |_window, builder| { builder.dev_tools( true ); } ).await;
+	/// 	}),
+	/// 	Err(_) => runtime.run_async( start_normally )
+	/// }
+	/// ```
+	pub async fn restore<C>( &self, app: ApplicationHandle, mut configure: C ) -> Vec<BrowserWindow> where
+		C: FnMut( &WindowSnapshot, &mut BrowserWindowBuilder )
+	{
+		let mut windows = Vec::with_capacity( self.windows.len() );
+
+		for window in &self.windows {
+			let mut builder = window.builder();
+			configure( window, &mut builder );
+
+			let bw = builder.build( app ).await;
+			bw.window().position().set( Pos2D::new( window.position.0, window.position.1 ) );
+			windows.push( bw );
+		}
+		windows
+	}
+
+	/// Gives the value of the slot with the given name, see `ApplicationHandle::recovery_slot`.
+	/// Returns `None` if the snapshot doesn't contain that slot, or if it can't be deserialized into a `T`.
+	pub fn slot<T: DeserializeOwned>( &self, name: &str ) -> Option<T> {
+		let value = self.slots.get( name )?;
+		serde_json::from_value( value.clone() ).ok()
+	}
+
+	fn from_json( json: &Value ) -> Option<Self> {
+		if json.get("version")?.as_u64()? != FORMAT_VERSION {
+			return None;
+		}
+
+		Some( Self {
+			windows: json.get("windows")?.as_array()?.iter().map( WindowSnapshot::from_json ).collect::<Option<_>>()?,
+			slots: json.get("slots")?.as_object()?.iter().map(|(k, v)| (k.clone(), v.clone()) ).collect()
+		} )
+	}
+}
+
+impl WindowSnapshot {
+
+	/// Gives a builder for a browser window that displays the same page, with the same label, title and size.
+	/// Once the page has loaded, it is scrolled to where it was.
+	///
+	/// The position is not part of the builder, and needs to be set after the window has been built.
+	/// `RecoverySnapshot::restore` does that.
+	pub fn builder( &self ) -> BrowserWindowBuilder {
+		let mut builder = BrowserWindowBuilder::new( Source::Url( self.url.clone() ) );
+		builder.title( self.title.as_str() );
+		builder.size( self.size.0 as _, self.size.1 as _ );
+		if let Some( label ) = &self.label {
+			builder.label( label.as_str() );
+		}
+
+		// Only the first document is scrolled, so that reloads and navigations aren't affected.
+		builder.preload_js( &session::scroll_once_js( self.scroll ) );
+		builder
+	}
+
+	async fn capture( bw: BrowserWindowHandle ) -> Self {
+		let window = bw.window();
+		let position = window.position().get();
+		let size = window.window_dimensions().get();
+
+		// A page that doesn't respond is stored as not having been scrolled.
+		let scroll = match bw.eval_js_value( "[window.scrollX, window.scrollY]" ).await {
+			Ok( JsValue::Array( s ) ) if s.len() == 2 => (s[0].as_f64().unwrap_or( 0.0 ), s[1].as_f64().unwrap_or( 0.0 )),
+			_ => (0.0, 0.0)
+		};
+
+		Self {
+			label: bw.label(),
+			url: bw.url().into_owned(),
+			title: window.title().get(),
+			position: (position.x(), position.y()),
+			size: (size.width(), size.height()),
+			scroll
+		}
+	}
+
+	fn to_json( &self ) -> Value {
+		json!({
+			"label": self.label,
+			"url": self.url,
+			"title": self.title,
+			"position": [self.position.0, self.position.1],
+			"size": [self.size.0, self.size.1],
+			"scroll": [self.scroll.0, self.scroll.1]
+		})
+	}
+
+	fn from_json( json: &Value ) -> Option<Self> {
+		let pair = |key: &str| -> Option<(u16, u16)> {
+			let a = json.get( key )?.as_array()?;
+			Some( (a.get(0)?.as_u64()? as u16, a.get(1)?.as_u64()? as u16) )
+		};
+		let scroll = json.get("scroll")?.as_array()?;
+
+		Some( Self {
+			label: json.get("label").and_then(|l| l.as_str() ).map( str::to_owned ),
+			url: json.get("url")?.as_str()?.to_owned(),
+			title: json.get("title")?.as_str()?.to_owned(),
+			position: pair("position")?,
+			size: pair("size")?,
+			scroll: (scroll.get(0)?.as_f64()?, scroll.get(1)?.as_f64()?)
+		} )
+	}
+}
+
+
+
+impl Application {
+
+	/// See `ApplicationHandle::write_recovery_snapshot`.
+	pub async fn write_recovery_snapshot<P: AsRef<Path>>( &self, path: P ) -> io::Result<()> {
+		self.handle.write_recovery_snapshot( path ).await
+	}
+}
+
+impl ApplicationHandle {
+
+	/// Adds a slot to the snapshots that are written by `write_recovery_snapshot`.
+	/// Whenever a snapshot is written, `hook` is called for the value of the slot, which is then serialized with serde.
+	/// A slot that already exists with the same name is replaced.
+	///
+	/// After a crash, the value can be read back with `RecoverySnapshot::slot`.
+	pub fn recovery_slot<H,T>( &self, name: &str, hook: H ) where
+		H: Fn() -> T + 'static,
+		T: Serialize
+	{
+		SLOT_HOOKS.with(|hooks| {
+			hooks.borrow_mut().insert( name.to_owned(), Box::new( move || serde_json::to_value( hook() ) ) );
+		});
+	}
+
+	/// Writes the state of the application to the file at `path`, so that it can be restored with `Runtime::recover_from` after a crash.
+	/// The snapshot contains all browser windows that are open, and the slots that have been added with `recovery_slot`.
+	///
+	/// The file is replaced at once, so that a crash while it is being written doesn't leave a broken snapshot behind.
+	pub async fn write_recovery_snapshot<P: AsRef<Path>>( &self, path: P ) -> io::Result<()> {
+		let mut windows = Vec::new();
+		for bw in registry::all() {
+			windows.push( WindowSnapshot::capture( bw ).await.to_json() );
+		}
+
+		let slots = SLOT_HOOKS.with(|hooks| {
+			hooks.borrow().iter()
+				.map(|(name, hook)| hook().map(|value| (name.clone(), value) ) )
+				.collect::<serde_json::Result<serde_json::Map<_,_>>>()
+		}).map_err(|e| io::Error::new( io::ErrorKind::InvalidData, e ) )?;
+
+		let json = json!({
+			"version": FORMAT_VERSION,
+			"windows": windows,
+			"slots": slots
+		});
+		let compressed = zstd::encode_all( json.to_string().as_bytes(), COMPRESSION_LEVEL )?;
+
+		let path = path.as_ref();
+		let temporary = path.with_extension( "tmp" );
+		fs::write( &temporary, compressed )?;
+		fs::rename( &temporary, path )
+	}
+}
+
+impl Runtime {
+
+	/// Reads a snapshot that has been written by `ApplicationHandle::write_recovery_snapshot`.
+	/// If there is no snapshot at `path`, an error of kind `io::ErrorKind::NotFound` is returned.
+	/// If the snapshot is broken, or has been written by an incompatible version, the error is of kind `io::ErrorKind::InvalidData`.
+	pub fn recover_from<P: AsRef<Path>>( &self, path: P ) -> io::Result<RecoverySnapshot> {
+		let compressed = fs::read( path )?;
+		let json: Value = serde_json::from_slice( &zstd::decode_all( compressed.as_slice() )? )
+			.map_err(|e| io::Error::new( io::ErrorKind::InvalidData, e ) )?;
+
+		RecoverySnapshot::from_json( &json ).ok_or_else(|| io::Error::new( io::ErrorKind::InvalidData, "invalid recovery snapshot" ) )
+	}
+}
@@ -0,0 +1,111 @@
+use super::ApplicationHandle;
+#[cfg(feature = "threadsafe")]
+use super::ApplicationHandleThreaded;
+#[cfg(feature = "threadsafe")]
+use crate::delegate::DelegateError;
+use crate::prop::{PropertyChanges, PropertyWatchers};
+
+use browser_window_core::application::*;
+use futures_channel::mpsc;
+use std::cell::RefCell;
+
+
+
+/// Whether the system uses a light or a dark theme for applications, as given by `ApplicationHandle::system_theme`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SystemTheme {
+	Light,
+	Dark
+}
+
+
+
+thread_local! {
+	// The theme handler is only set once the first stream is created, and lives as long as the application.
+	static WATCHERS: RefCell<Option<PropertyWatchers<SystemTheme>>> = RefCell::new( None );
+}
+
+
+
+impl SystemTheme {
+
+	pub(in crate) fn from_c( theme: u8 ) -> Option<Self> {
+		match theme {
+			THEME_LIGHT => Some( Self::Light ),
+			THEME_DARK => Some( Self::Dark ),
+			_ => None
+		}
+	}
+}
+
+impl ApplicationHandle {
+
+	/// Gives whether the system uses a light or a dark theme for applications, so that the HTML of the application can follow it.
+	/// Returns `None` if the system doesn't tell.
+	///
+	/// On Windows, this is the theme of applications in the personalization settings.
+	/// With GTK, it is derived from the GTK theme, which is dark if its name ends with `-dark`, like `Adwaita-dark`.
+	/// The theme is not known when CEF provides the windows itself.
+	pub fn system_theme( &self ) -> Option<SystemTheme> {
+		SystemTheme::from_c( self.inner.get_system_theme() )
+	}
+
+	/// Gives a stream of the themes that the system switches to, whenever the user switches between the light and the dark theme.
+	/// See also `system_theme`.
+	///
+	/// # Example
+	/// ```ignore
+	/// let mut changes = app.system_theme_changes();
+	/// while let Some( theme ) = changes.next().await {
+	/// 	bw.set_color_scheme( theme.into() ).await?;
+	/// }
+	/// ```
+	pub fn system_theme_changes( &self ) -> PropertyChanges<SystemTheme> {
+		let (sender, changes) = PropertyChanges::new();
+
+		follow( *self, sender );
+		changes
+	}
+}
+
+#[cfg(feature = "threadsafe")]
+impl ApplicationHandleThreaded {
+
+	/// Same as `ApplicationHandle::system_theme`, but reads it on the GUI thread.
+	pub async fn system_theme( &self ) -> Result<Option<SystemTheme>, DelegateError> {
+		self.delegate(|app| app.system_theme() ).await
+	}
+
+	/// Same as `ApplicationHandle::system_theme_changes`, but starts following the theme from the GUI thread.
+	/// If the GUI thread can't be reached anymore, the stream ends right away.
+	pub fn system_theme_changes( &self ) -> PropertyChanges<SystemTheme> {
+		let (sender, changes) = PropertyChanges::new();
+
+		self.dispatch(move |app| follow( app, sender ) );
+		changes
+	}
+}
+
+
+
+/// Lets the stream of the sender receive the themes that the system switches to.
+fn follow( app: ApplicationHandle, sender: mpsc::UnboundedSender<SystemTheme> ) {
+	WATCHERS.with(|watchers| {
+		let mut watchers = watchers.borrow_mut();
+		if watchers.is_none() {
+			app.inner.set_theme_handler( theme_handler );
+		}
+
+		watchers.get_or_insert_with( PropertyWatchers::default ).add( sender );
+	});
+}
+
+unsafe fn theme_handler( _app: ApplicationImpl, theme: u8 ) {
+	if let Some( theme ) = SystemTheme::from_c( theme ) {
+		WATCHERS.with(|watchers| {
+			if let Some( watchers ) = watchers.borrow_mut().as_mut() {
+				watchers.notify( theme );
+			}
+		});
+	}
+}
@@ -0,0 +1,60 @@
+use std::{
+	sync::{
+		Arc,
+		atomic::{AtomicBool, Ordering}
+	},
+	time::Duration
+};
+
+use super::ApplicationHandle;
+
+
+
+/// A handle to a timer that has been set with `set_timeout` or `set_interval`.
+/// It can be used to cancel the timer.
+///
+/// Dropping the handle does not cancel the timer.
+#[derive(Clone)]
+pub struct TimerHandle {
+	cancelled: Arc<AtomicBool>
+}
+
+
+
+impl TimerHandle {
+
+	pub(in super) fn new() -> Self {
+		Self {
+			cancelled: Arc::new( AtomicBool::new( false ) )
+		}
+	}
+
+	/// Prevents the timer from firing (again).
+	/// If the closure is currently being executed, it will finish, but it won't be called anymore afterwards.
+	pub fn cancel( &self ) {
+		self.cancelled.store( true, Ordering::SeqCst );
+	}
+
+	/// Whether or not `cancel` has been called on this timer.
+	pub fn is_cancelled( &self ) -> bool {
+		self.cancelled.load( Ordering::SeqCst )
+	}
+}
+
+
+
+/// Calls `func` every `interval`, until the timer gets cancelled.
+/// Each next call is scheduled after the previous one has finished.
+pub(in super) fn schedule_interval<F>( handle: ApplicationHandle, interval: Duration, timer: TimerHandle, mut func: F ) where
+	F: FnMut( ApplicationHandle ) + 'static
+{
+	handle.dispatch_delayed(move |handle| {
+		if timer.is_cancelled() { return }
+
+		func( handle );
+
+		if !timer.is_cancelled() {
+			schedule_interval( handle, interval, timer, func );
+		}
+	}, interval );
+}
@@ -0,0 +1,338 @@
+use std::{
+	collections::HashMap,
+	fmt,
+	str::FromStr,
+	sync::{
+		Mutex,
+		atomic::{AtomicBool, AtomicU64, Ordering}
+	},
+	thread,
+	time::{Duration, Instant}
+};
+
+use lazy_static::lazy_static;
+
+use super::ApplicationHandle;
+#[cfg(feature = "threadsafe")]
+use super::queue::DispatchPriority;
+use crate::window::WindowId;
+
+
+
+/// A recording of what the GUI thread has done, for analyzing ordering bugs offline.
+/// It is recorded with `ApplicationHandle::start_gui_trace` and `stop_gui_trace`.
+///
+/// A trace is serialized with `to_string`, and parsed again with `parse`.
+/// Every entry is written on its own line, with its start and duration in microseconds, its kind, its identifier, the thread it happened on, and its name:
+/// ```text
+/// 1200 0 dispatch 4 ThreadId(3) normal my_app::refresh::{{closure}}
+/// 1315 82 execute 4 main - my_app::refresh::{{closure}}
+/// 1402 230 event 5 main window-1 navigation
+/// ```
+/// The name of a closure is the name of its type, which tells the function in which it has been written.
+/// The name of an event is the kind of event of the browser window, like `move`, `resize` or `invoke`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GuiTrace {
+	/// The entries in the order in which they have been recorded.
+	pub entries: Vec<TraceEntry>
+}
+
+/// The error of parsing a `GuiTrace`, which tells the line (starting at 1) that isn't a valid entry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GuiTraceError {
+	pub line: usize
+}
+
+/// Something that has happened while a `GuiTrace` was being recorded.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TraceEntry {
+	/// An identifier that is unique within the trace.
+	/// The execution of a dispatched closure has the same identifier as its dispatch.
+	pub id: u64,
+	pub kind: TraceEntryKind,
+	/// The name of the closure or the event.
+	pub name: String,
+	/// The name of the thread that it happened on, or the identifier of the thread if it has no name.
+	pub thread: String,
+	/// The moment that it started, since the start of the trace.
+	pub start: Duration,
+	/// How long it took, which is zero for dispatches.
+	pub duration: Duration
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TraceEntryKind {
+	/// A closure has been dispatched to the GUI thread, with the given priority.
+	#[cfg(feature = "threadsafe")]
+	Dispatched( DispatchPriority ),
+	/// A dispatched closure has been executed on the GUI thread.
+	#[cfg(feature = "threadsafe")]
+	Executed,
+	/// An event of the given browser window has been handled.
+	Event( WindowId )
+}
+
+/// Replays the entries of a `GuiTrace` in the order in which the GUI thread has handled them, without a GUI.
+///
+/// There is no browser engine or windowing system involved in a replay.
+/// Instead, the handlers that are registered for the names of the entries are invoked one after the other, on the current thread.
+/// This lets the state of an application, like its model, be driven through the exact order that caused a bug, as often as needed.
+///
+/// # Example
+/// ```ignore
+/// let trace: GuiTrace = std::fs::read_to_string( "bug.trace" )?.parse()?;
+/// let mut model = Model::default();
+///
+/// TraceReplay::new( &trace )
+/// 	.on( "my_app::refresh::{{closure}}", |_| model.refresh() )
+/// 	.on( "invoke", |entry| model.handle_call( entry ) )
+/// 	.run();
+/// ```
+pub struct TraceReplay<'a> {
+	handlers: HashMap<String, Box<dyn FnMut( &TraceEntry ) + 'a>>,
+	trace: &'a GuiTrace
+}
+
+/// The trace that is being recorded.
+struct Recording {
+	entries: Vec<TraceEntry>,
+	started: Instant
+}
+
+
+
+lazy_static! {
+	static ref RECORDING: Mutex<Option<Recording>> = Mutex::new( None );
+}
+// Checked before anything else, so that there is hardly any cost when nothing is being recorded
+static ENABLED: AtomicBool = AtomicBool::new( false );
+static NEXT_ID: AtomicU64 = AtomicU64::new( 0 );
+
+
+
+impl GuiTrace {
+
+	/// The time between the dispatch of the closure with the given identifier and its execution, if both are in the trace.
+	/// This tells how long the closure has been waiting for the GUI thread.
+	#[cfg(feature = "threadsafe")]
+	pub fn wait_time( &self, id: u64 ) -> Option<Duration> {
+		let dispatched = self.entries.iter().find(|e| e.id == id && matches!( e.kind, TraceEntryKind::Dispatched(_) ) )?;
+		let executed = self.entries.iter().find(|e| e.id == id && e.kind == TraceEntryKind::Executed )?;
+
+		Some( executed.start.saturating_sub( dispatched.start ) )
+	}
+}
+
+impl fmt::Display for GuiTrace {
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
+		for entry in &self.entries {
+			let (kind, detail) = match entry.kind {
+				#[cfg(feature = "threadsafe")]
+				TraceEntryKind::Dispatched( priority ) => ("dispatch", match priority {
+					DispatchPriority::High => "high".to_owned(),
+					DispatchPriority::Normal => "normal".to_owned(),
+					DispatchPriority::Low => "low".to_owned()
+				}),
+				#[cfg(feature = "threadsafe")]
+				TraceEntryKind::Executed => ("execute", "-".to_owned()),
+				TraceEntryKind::Event( window ) => ("event", window.to_string())
+			};
+
+			writeln!( f, "{} {} {} {} {} {} {}", entry.start.as_micros(), entry.duration.as_micros(), kind, entry.id, entry.thread, detail, entry.name )?;
+		}
+		Ok(())
+	}
+}
+
+impl FromStr for GuiTrace {
+	type Err = GuiTraceError;
+
+	fn from_str( string: &str ) -> Result<Self, Self::Err> {
+		let mut entries = Vec::new();
+
+		for (index, line) in string.lines().enumerate() {
+			if line.trim().is_empty() { continue }
+
+			entries.push( parse_line( line ).ok_or( GuiTraceError { line: index + 1 } )? );
+		}
+
+		Ok( Self { entries } )
+	}
+}
+
+impl fmt::Display for GuiTraceError {
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
+		write!( f, "invalid trace entry at line {}", self.line )
+	}
+}
+
+impl std::error::Error for GuiTraceError {}
+
+impl<'a> TraceReplay<'a> {
+
+	pub fn new( trace: &'a GuiTrace ) -> Self {
+		Self {
+			handlers: HashMap::new(),
+			trace
+		}
+	}
+
+	/// Invokes `handler` for every entry with the given name that is replayed.
+	pub fn on<H>( mut self, name: &str, handler: H ) -> Self where
+		H: FnMut( &TraceEntry ) + 'a
+	{
+		self.handlers.insert( name.to_owned(), Box::new( handler ) );
+		self
+	}
+
+	/// Replays the executions and events of the trace, in the order in which they have started.
+	/// Dispatches are skipped, as they only tell when the closures have been queued.
+	/// Returns the number of entries that a handler has been invoked for.
+	pub fn run( mut self ) -> usize {
+		let mut order: Vec<&TraceEntry> = self.trace.entries.iter().filter(|e| !e.is_dispatch() ).collect();
+		// The sort is stable, so entries that started at the same moment keep the order in which they were recorded
+		order.sort_by_key(|e| e.start );
+
+		let mut replayed = 0;
+		for entry in order {
+			if let Some( handler ) = self.handlers.get_mut( &entry.name ) {
+				handler( entry );
+				replayed += 1;
+			}
+		}
+		replayed
+	}
+}
+
+impl TraceEntry {
+
+	fn is_dispatch( &self ) -> bool {
+		match self.kind {
+			#[cfg(feature = "threadsafe")]
+			TraceEntryKind::Dispatched(_) => true,
+			_ => false
+		}
+	}
+}
+
+impl ApplicationHandle {
+
+	/// Starts recording a trace of the closures that are dispatched to the GUI thread, and of the events of the browser windows.
+	/// A trace that was already being recorded is discarded.
+	///
+	/// All entries are kept in memory until `stop_gui_trace` is called, so this is meant for reproducing a bug, not for leaving it on.
+	pub fn start_gui_trace( &self ) {
+		*RECORDING.lock().unwrap() = Some( Recording {
+			entries: Vec::new(),
+			started: Instant::now()
+		} );
+		ENABLED.store( true, Ordering::Release );
+	}
+
+	/// Stops recording the trace of `start_gui_trace`, and gives it.
+	/// Gives an empty trace if none was being recorded.
+	pub fn stop_gui_trace( &self ) -> GuiTrace {
+		ENABLED.store( false, Ordering::Release );
+
+		let entries = RECORDING.lock().unwrap().take().map(|r| r.entries ).unwrap_or_default();
+		GuiTrace { entries }
+	}
+}
+
+
+
+/// Records the dispatch of a closure, and wraps the closure so that its execution is recorded as well.
+#[cfg(feature = "threadsafe")]
+pub(in crate) fn dispatched<F>( name: &'static str, priority: DispatchPriority, func: F ) -> Box<dyn FnOnce( ApplicationHandle ) + Send> where
+	F: FnOnce( ApplicationHandle ) + Send + 'static
+{
+	if !ENABLED.load( Ordering::Acquire ) {
+		return Box::new( func );
+	}
+
+	let id = NEXT_ID.fetch_add( 1, Ordering::Relaxed );
+	record( id, TraceEntryKind::Dispatched( priority ), name, now(), Duration::from_secs( 0 ) );
+
+	Box::new(move |app| {
+		let start = now();
+		func( app );
+		record( id, TraceEntryKind::Executed, name, start, now().saturating_sub( start ) );
+	})
+}
+
+/// Handles an event of a browser window, and records it.
+/// The name is the name of the type of the closure that handles it, which is in the function of the handler of the event.
+pub(in crate) fn event<E>( window: WindowId, handler_name: &'static str, event: E ) where
+	E: FnOnce()
+{
+	if !ENABLED.load( Ordering::Acquire ) {
+		return event();
+	}
+
+	let start = now();
+	event();
+	record( NEXT_ID.fetch_add( 1, Ordering::Relaxed ), TraceEntryKind::Event( window ), event_name( handler_name ), start, now().saturating_sub( start ) );
+}
+
+/// Gives the kind of event from the name of the closure that handles it, like `move` for `browser_window_move_handler::{{closure}}`.
+fn event_name( handler_name: &str ) -> &str {
+	let mut name = handler_name;
+	while let Some( n ) = name.strip_suffix( "::{{closure}}" ) {
+		name = n;
+	}
+	let name = name.rsplit( "::" ).next().unwrap_or( name );
+
+	name.strip_prefix( "browser_window_" ).and_then(|n| n.strip_suffix( "_handler" ) ).unwrap_or( name )
+}
+
+/// The time since the start of the trace, or zero if no trace is being recorded.
+/// It is in whole microseconds, so that the trace is parsed again exactly as it has been written.
+fn now() -> Duration {
+	let elapsed = RECORDING.lock().unwrap().as_ref().map(|r| r.started.elapsed() ).unwrap_or_default();
+
+	Duration::from_micros( elapsed.as_micros() as u64 )
+}
+
+fn parse_line( line: &str ) -> Option<TraceEntry> {
+	let mut parts = line.splitn( 7, ' ' );
+	let start = Duration::from_micros( parts.next()?.parse().ok()? );
+	let duration = Duration::from_micros( parts.next()?.parse().ok()? );
+	let kind = parts.next()?;
+	let id = parts.next()?.parse().ok()?;
+	let thread = parts.next()?.to_owned();
+	let detail = parts.next()?;
+	let name = parts.next()?.to_owned();
+
+	let kind = match kind {
+		#[cfg(feature = "threadsafe")]
+		"dispatch" => TraceEntryKind::Dispatched( match detail {
+			"high" => DispatchPriority::High,
+			"normal" => DispatchPriority::Normal,
+			"low" => DispatchPriority::Low,
+			_ => return None
+		} ),
+		#[cfg(feature = "threadsafe")]
+		"execute" => TraceEntryKind::Executed,
+		"event" => TraceEntryKind::Event( detail.parse().ok()? ),
+		_ => return None
+	};
+
+	Some( TraceEntry { id, kind, name, thread, start, duration } )
+}
+
+fn record( id: u64, kind: TraceEntryKind, name: &str, start: Duration, duration: Duration ) {
+	let current = thread::current();
+	// Spaces separate the fields of the serialized trace
+	let thread = current.name().map(|n| n.replace( ' ', "_" ) ).unwrap_or_else(|| format!( "{:?}", current.id() ) );
+
+	if let Some( recording ) = RECORDING.lock().unwrap().as_mut() {
+		recording.entries.push( TraceEntry {
+			id,
+			kind,
+			name: name.to_owned(),
+			thread,
+			start,
+			duration
+		} );
+	}
+}
@@ -0,0 +1,208 @@
+//! This module serves files that are embedded into the executable, over a custom scheme.
+//!
+//! **Note:** Only available with feature `assets` enabled.
+//!
+//! The files are embedded with the `include_dir!` macro of the [include_dir](https://docs.rs/include_dir/0.7) crate.
+//! `BrowserWindowBuilder::assets` then answers the requests of a custom scheme with them.
+//! Every file is given the MIME type that belongs to its extension, and an `ETag`, so that the browser engine only loads it again when it has changed.
+//! Paths that don't have a file of their own, like `/settings/privacy`, get `index.html` instead, so that a single-page application can do its own routing.
+//!
+//! # Example
+//! ```ignore
+//! use include_dir::{include_dir, Dir};
+//!
+//! static DIST: Dir = include_dir!( "$CARGO_MANIFEST_DIR/dist" );
+//!
+//! let settings = ApplicationSettings::default()
+//! 	.custom_scheme( "app" );
+//!
+//! let mut bwb = BrowserWindowBuilder::new( Source::Url( "app://localhost/".into() ) );
+//! bwb.assets( "app", Assets::new( &DIST ) );
+//! ```
+
+use crate::browser::{BrowserWindowBuilder, SchemeRequest, SchemeResponse};
+
+use std::{
+	collections::{hash_map::DefaultHasher, HashMap},
+	hash::Hasher
+};
+
+pub use include_dir::Dir;
+use include_dir::DirEntry;
+
+
+
+/// The MIME types of the extensions that are common on the web.
+const MIME_TYPES: &[(&str, &str)] = &[
+	("avif", "image/avif"),
+	("css", "text/css; charset=utf-8"),
+	("gif", "image/gif"),
+	("htm", "text/html; charset=utf-8"),
+	("html", "text/html; charset=utf-8"),
+	("ico", "image/x-icon"),
+	("jpeg", "image/jpeg"),
+	("jpg", "image/jpeg"),
+	("js", "text/javascript; charset=utf-8"),
+	("json", "application/json"),
+	("map", "application/json"),
+	("mjs", "text/javascript; charset=utf-8"),
+	("mp3", "audio/mpeg"),
+	("mp4", "video/mp4"),
+	("ogg", "audio/ogg"),
+	("otf", "font/otf"),
+	("pdf", "application/pdf"),
+	("png", "image/png"),
+	("svg", "image/svg+xml"),
+	("ttf", "font/ttf"),
+	("txt", "text/plain; charset=utf-8"),
+	("wasm", "application/wasm"),
+	("wav", "audio/wav"),
+	("webm", "video/webm"),
+	("webmanifest", "application/manifest+json"),
+	("webp", "image/webp"),
+	("woff", "font/woff"),
+	("woff2", "font/woff2"),
+	("xml", "application/xml")
+];
+
+
+
+/// The files of an embedded directory, as they are served over a custom scheme.
+/// See the [module documentation](index.html) for more information.
+pub struct Assets {
+	fallback: Option<String>,
+	files: HashMap<String, Asset>
+}
+
+struct Asset {
+	contents: &'static [u8],
+	etag: String
+}
+
+
+
+impl Assets {
+
+	/// Collects the files of the given directory.
+	/// Their `ETag`s are calculated right away, so this is best done only once.
+	pub fn new( dir: &'static Dir<'static> ) -> Self {
+		let mut files = HashMap::new();
+		collect_files( dir, &mut files );
+
+		Self {
+			fallback: Some( "index.html".to_owned() ),
+			files
+		}
+	}
+
+	/// Sets the file that is served for paths that have no file of their own and no extension, which is `index.html` by default.
+	/// With `None`, those paths are answered with status 404 instead.
+	pub fn fallback( mut self, path: Option<&str> ) -> Self {
+		self.fallback = path.map(|p| p.trim_start_matches( '/' ).to_owned() );
+		self
+	}
+
+	/// Answers the given request with the file at its path.
+	/// This is what `BrowserWindowBuilder::assets` does for every request of its scheme, and can be used to serve the assets from a scheme handler of your own.
+	pub fn respond( &self, request: &SchemeRequest ) -> SchemeResponse {
+		let head = request.method().eq_ignore_ascii_case( "HEAD" );
+		if !head && !request.method().eq_ignore_ascii_case( "GET" ) {
+			return SchemeResponse::new( 405 ).header( "Allow", "GET, HEAD" );
+		}
+
+		let mut path = percent_decode( request.path().trim_start_matches( '/' ) );
+		if path.is_empty() || path.ends_with( '/' ) {
+			path += "index.html";
+		}
+
+		let (path, asset) = match self.files.get_key_value( &path ) {
+			Some( found ) => found,
+			None => {
+				// Routes of single-page applications don't look like files
+				let is_route = !path.rsplit( '/' ).next().unwrap_or( "" ).contains( '.' );
+				match self.fallback.as_ref().filter(|_| is_route ).and_then(|f| self.files.get_key_value( f ) ) {
+					None => return SchemeResponse::new( 404 ),
+					Some( found ) => found
+				}
+			}
+		};
+
+		// The page still has the latest version of the file
+		if request.header( "If-None-Match" ).map(|tags| tags.split( ',' ).any(|t| t.trim() == asset.etag ) ).unwrap_or( false ) {
+			return SchemeResponse::new( 304 ).header( "ETag", asset.etag.as_str() );
+		}
+
+		let response = SchemeResponse::new( 200 )
+			.header( "Content-Type", mime_type( path ) )
+			.header( "ETag", asset.etag.as_str() )
+			// The file may be cached, but it needs to be checked against its `ETag` every time
+			.header( "Cache-Control", "no-cache" );
+
+		if head {
+			response.header( "Content-Length", asset.contents.len().to_string() )
+		}
+		else {
+			response.body( asset.contents )
+		}
+	}
+}
+
+impl BrowserWindowBuilder {
+
+	/// Serves the given assets over the given custom scheme, like `app` for `app://localhost/index.html`.
+	/// The scheme needs to be registered with `ApplicationSettings::custom_scheme`.
+	/// See the [`assets`](../assets/index.html) module for more information.
+	///
+	/// **Note:** Only available with feature `assets` enabled.
+	pub fn assets( &mut self, scheme: &str, assets: Assets ) -> &mut Self {
+		self.scheme_handler( scheme, move |_, request| assets.respond( &request ) )
+	}
+}
+
+
+
+fn collect_files( dir: &'static Dir<'static>, files: &mut HashMap<String, Asset> ) {
+	for entry in dir.entries() {
+		match entry {
+			DirEntry::Dir( dir ) => collect_files( dir, files ),
+			DirEntry::File( file ) => {
+				let mut hasher = DefaultHasher::new();
+				hasher.write( file.contents() );
+
+				// The paths in the URLs always have forward slashes
+				let path = file.path().to_string_lossy().replace( '\\', "/" );
+				files.insert( path, Asset {
+					contents: file.contents(),
+					etag: format!( "\"{:016x}\"", hasher.finish() )
+				} );
+			}
+		}
+	}
+}
+
+/// The MIME type that belongs to the extension of the given path.
+pub(in crate) fn mime_type( path: &str ) -> &'static str {
+	let extension = match path.rsplit( '/' ).next().and_then(|name| name.rfind( '.' ).map(|i| &name[ i + 1.. ] ) ) {
+		None => return "application/octet-stream",
+		Some( e ) => e.to_lowercase()
+	};
+
+	MIME_TYPES.iter().find(|(e, _)| *e == extension ).map(|(_, m)| *m ).unwrap_or( "application/octet-stream" )
+}
+
+/// Decodes the escaped characters of the path of a URL, like `%20`.
+fn percent_decode( path: &str ) -> String {
+	let bytes = path.as_bytes();
+	let mut decoded = Vec::with_capacity( bytes.len() );
+
+	let mut i = 0;
+	while i < bytes.len() {
+		let escaped = if bytes[i] == b'%' { path.get( i + 1 .. i + 3 ).and_then(|h| u8::from_str_radix( h, 16 ).ok() ) } else { None };
+		match escaped {
+			None => { decoded.push( bytes[i] ); i += 1 },
+			Some( byte ) => { decoded.push( byte ); i += 3 }
+		}
+	}
+
+	String::from_utf8_lossy( &decoded ).into_owned()
+}
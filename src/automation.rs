@@ -0,0 +1,283 @@
+//! This module drives the page of a browser window from Rust, so that integration tests of an application can be written like the user would use it.
+//!
+//! `Automation` waits for elements to appear, clicks them, types into them and waits for navigations to finish.
+//! Every step waits until it can be done, up to a timeout, so that the tests don't need to know how long the page takes to get there.
+//! All of it is done with JavaScript, so the events that the page receives are not trusted, like the ones of the user are.
+//!
+//! # Example
+//! ```ignore
+//! let page = Automation::new( bw );
+//!
+//! page.type_text( "#username", "alice" ).await?;
+//! page.type_text( "#password", "secret" ).await?;
+//!
+//! let navigation = page.wait_for_navigation();
+//! page.click( "button[type=submit]" ).await?;
+//! let url = navigation.await?;
+//! assert!( url.ends_with( "/dashboard" ) );
+//! ```
+
+use crate::application::ApplicationHandle;
+use crate::browser::{registry, BrowserEvent, BrowserWindowHandle, JsValue};
+use crate::browser::js_value::call_js;
+use crate::window::WindowId;
+
+use browser_window_core::browser_window::JsEvaluationError;
+
+use std::{
+	fmt,
+	future::Future,
+	pin::Pin,
+	task::{Context, Poll},
+	time::{Duration, Instant}
+};
+
+
+
+/// How often the page is checked while waiting for it.
+const POLL_INTERVAL: Duration = Duration::from_millis( 50 );
+
+/// Resolves to true once the document has loaded completely.
+const LOADED_JS: &'static str = r#"function() { return document.readyState === "complete"; }"#;
+
+/// Resolves to true if an element matches the selector.
+const EXISTS_JS: &'static str = r#"function(selector) { return document.querySelector(selector) !== null; }"#;
+
+/// Clicks in the middle of the first element that matches the selector, or resolves to false if there is none.
+const CLICK_JS: &'static str = r#"function(selector) {
+	var element = document.querySelector(selector);
+	if (element === null) return false;
+
+	element.scrollIntoView({ block: "center", inline: "center" });
+	var rect = element.getBoundingClientRect();
+	var init = { bubbles: true, cancelable: true, composed: true, view: window, button: 0, clientX: rect.left + rect.width / 2, clientY: rect.top + rect.height / 2 };
+	var Pointer = window.PointerEvent || MouseEvent;
+
+	element.dispatchEvent(new Pointer("pointerdown", init));
+	element.dispatchEvent(new MouseEvent("mousedown", init));
+	if (element.focus) element.focus();
+	element.dispatchEvent(new Pointer("pointerup", init));
+	element.dispatchEvent(new MouseEvent("mouseup", init));
+	element.dispatchEvent(new MouseEvent("click", init));
+	return true;
+}"#;
+
+/// Types the text into the first element that matches the selector, one character at a time, or resolves to false if there is none.
+/// The value is set with the setter of the element's prototype, so that frameworks that track the value themselves notice the change.
+const TYPE_JS: &'static str = r#"function(selector, text) {
+	var element = document.querySelector(selector);
+	if (element === null) return false;
+
+	element.focus();
+	var proto = element instanceof HTMLTextAreaElement ? HTMLTextAreaElement.prototype : element instanceof HTMLInputElement ? HTMLInputElement.prototype : null;
+	var setter = proto && Object.getOwnPropertyDescriptor(proto, "value").set;
+
+	Array.from(text).forEach(function(key) {
+		var init = { key: key, bubbles: true, cancelable: true, composed: true };
+		if (element.dispatchEvent(new KeyboardEvent("keydown", init)) && element.dispatchEvent(new KeyboardEvent("keypress", init))) {
+			if (setter) {
+				setter.call(element, element.value + key);
+				element.dispatchEvent(new InputEvent("input", { data: key, inputType: "insertText", bubbles: true, composed: true }));
+			}
+			else if (element.isContentEditable) {
+				document.execCommand("insertText", false, key);
+			}
+		}
+		element.dispatchEvent(new KeyboardEvent("keyup", init));
+	});
+
+	element.dispatchEvent(new Event("change", { bubbles: true }));
+	return true;
+}"#;
+
+
+
+/// Drives the page of a browser window, see the [module documentation](index.html).
+#[derive(Clone)]
+pub struct Automation {
+	app: ApplicationHandle,
+	timeout: Duration,
+	window: WindowId
+}
+
+/// The reason that a step of `Automation` has failed.
+#[derive(Debug)]
+pub enum AutomationError {
+	/// The window has been closed.
+	Closed,
+	/// The JavaScript code of the step couldn't be evaluated, like when the page is in the middle of loading another one.
+	Js( JsEvaluationError ),
+	/// The step couldn't be done before the timeout, like when the element never appeared.
+	/// It is given a description of what was waited for.
+	Timeout( String )
+}
+
+/// The future returned by `Automation::wait_for_navigation`.
+pub struct Navigation {
+	future: Pin<Box<dyn Future<Output=Result<String, AutomationError>>>>
+}
+
+/// Resolves to the output of `future`, or to `None` if `timeout` resolves first.
+struct WithTimeout<F, T> {
+	future: Pin<Box<F>>,
+	timeout: Pin<Box<T>>
+}
+
+
+
+impl Automation {
+
+	/// Drives the page of the given window.
+	/// The steps time out after 10 seconds, see `timeout`.
+	pub fn new( bw: BrowserWindowHandle ) -> Self {
+		Self {
+			app: bw.app(),
+			timeout: Duration::from_secs( 10 ),
+			window: bw.id()
+		}
+	}
+
+	/// Sets how long every step waits until it can be done, before it fails with `AutomationError::Timeout`.
+	pub fn timeout( mut self, timeout: Duration ) -> Self {
+		self.timeout = timeout;
+		self
+	}
+
+	/// Waits for an element to match the CSS selector, and clicks in the middle of it.
+	/// The element is scrolled into view and focused, and receives the events of pressing and releasing the mouse button before the click itself.
+	pub async fn click( &self, selector: &str ) -> Result<(), AutomationError> {
+		self.wait_until( CLICK_JS, &[selector], &format!( "element to click `{}`", selector ) ).await
+	}
+
+	/// Waits for an element to match the CSS selector, focuses it, and types the text into it.
+	/// Every character gets its own key events, and the text is added to what the field contains already.
+	pub async fn type_text( &self, selector: &str, text: &str ) -> Result<(), AutomationError> {
+		self.wait_until( TYPE_JS, &[selector, text], &format!( "element to type into `{}`", selector ) ).await
+	}
+
+	/// Waits for the main frame to navigate, and for the new page to have loaded.
+	/// Resolves to the address of the new page.
+	///
+	/// The navigation is only noticed from the moment that this is called, so call it before the step that navigates, and await it after:
+	/// ```ignore
+	/// let navigation = page.wait_for_navigation();
+	/// page.click( "a.next" ).await?;
+	/// navigation.await?;
+	/// ```
+	pub fn wait_for_navigation( &self ) -> Navigation {
+		let this = self.clone();
+		let events = self.window().map(|bw| bw.events() );
+
+		Navigation { future: Box::pin( async move {
+			let mut events = events?;
+			let deadline = Instant::now() + this.timeout;
+
+			let navigated = async {
+				while let Some( event ) = events.next().await {
+					if let BrowserEvent::Navigated( url ) = event {
+						return Some( url );
+					}
+				}
+				None
+			};
+
+			let url = match WithTimeout::new( navigated, this.app.sleep( this.timeout ) ).await {
+				None => return Err( AutomationError::Timeout( "navigation".to_owned() ) ),
+				Some( None ) => return Err( AutomationError::Closed ),
+				Some( Some( url ) ) => url
+			};
+
+			this.poll( LOADED_JS, &[], deadline ).await?
+				.ok_or_else(|| AutomationError::Timeout( format!( "page to load `{}`", url ) ) )?;
+			Ok( url )
+		} ) }
+	}
+
+	/// Waits for an element to match the CSS selector.
+	pub async fn wait_for_selector( &self, selector: &str ) -> Result<(), AutomationError> {
+		self.wait_until( EXISTS_JS, &[selector], &format!( "selector `{}`", selector ) ).await
+	}
+
+	/// Calls the function that `js` evaluates to until it returns true, or until the timeout has passed.
+	/// Gives `Some` once it has returned true.
+	async fn poll( &self, js: &str, args: &[&str], deadline: Instant ) -> Result<Option<()>, AutomationError> {
+		let js = call_js( js, args );
+
+		loop {
+			let done = self.window()?.eval_js_value( &js ).await;
+			match done {
+				Ok( JsValue::Bool( true ) ) => return Ok( Some(()) ),
+				Ok(_) => {},
+				// The page may be replaced while the code is running, in which case the next attempt is made on the new page
+				Err( e ) => if Instant::now() >= deadline { return Err( AutomationError::Js( e ) ) }
+			}
+
+			if Instant::now() >= deadline {
+				return Ok( None );
+			}
+			self.app.sleep( POLL_INTERVAL ).await;
+		}
+	}
+
+	async fn wait_until( &self, js: &str, args: &[&str], description: &str ) -> Result<(), AutomationError> {
+		let deadline = Instant::now() + self.timeout;
+
+		self.poll( js, args, deadline ).await?
+			.ok_or_else(|| AutomationError::Timeout( description.to_owned() ) )
+	}
+
+	/// Looks the window up again, as it may have been closed since the last step.
+	fn window( &self ) -> Result<BrowserWindowHandle, AutomationError> {
+		registry::by_id( self.window ).ok_or( AutomationError::Closed )
+	}
+}
+
+impl fmt::Display for AutomationError {
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
+		match self {
+			Self::Closed => write!( f, "the window has been closed" ),
+			Self::Js( e ) => write!( f, "unable to evaluate JavaScript: {}", e ),
+			Self::Timeout( what ) => write!( f, "timed out waiting for {}", what )
+		}
+	}
+}
+
+impl std::error::Error for AutomationError {
+	fn source( &self ) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Self::Js( e ) => Some( e ),
+			_ => None
+		}
+	}
+}
+
+impl Future for Navigation {
+	type Output = Result<String, AutomationError>;
+
+	fn poll( mut self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Self::Output> {
+		self.future.as_mut().poll( cx )
+	}
+}
+
+impl<F, T> WithTimeout<F, T> {
+
+	fn new( future: F, timeout: T ) -> Self {
+		Self {
+			future: Box::pin( future ),
+			timeout: Box::pin( timeout )
+		}
+	}
+}
+
+impl<F: Future, T: Future> Future for WithTimeout<F, T> {
+	type Output = Option<F::Output>;
+
+	fn poll( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Self::Output> {
+		let this = self.get_mut();
+
+		if let Poll::Ready( output ) = this.future.as_mut().poll( cx ) {
+			return Poll::Ready( Some( output ) );
+		}
+		this.timeout.as_mut().poll( cx ).map(|_| None )
+	}
+}
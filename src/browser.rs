@@ -3,11 +3,23 @@
 //! Keep in mind that `BrowserWindow` exposes the same methods as `BrowserWindowHandle` and `WindowHandle` does.
 //! The methods of `BrowserWindowHandle` are displayed correctly at the page of `BrowserWindow`, but the methods of `WindowHandle` are not displayed.
 //! Be sure to check them out [here](../window/struct.WindowHandle.html).
+//!
+//! # Ordering
+//! The events of a browser window are handled on the GUI thread, one at a time and in the order in which they have occurred.
+//! This is the same for all platforms and browser engines:
+//! * When a handler causes another event of the same window, like by resizing the window from within a resize handler, that event is handled once the handler has returned.
+//!   Handlers of a window are never invoked from within each other.
+//! * The result of `eval_js` is delivered in the same order as the events.
+//!   So once it resolves, the messages that the script has sent to the application before it has finished have been handled already, like the calls to APIs and to `async_handler`.
+//! * The stream of `BrowserWindowHandle::events` gives the events in that same order.
+//! * Closures that are dispatched to a browser window with the same priority are executed in the order in which they have been dispatched.
+//!   The events that have occurred in the meantime may be handled in between them.
 
 use futures_channel::oneshot;
 use std::{
 	borrow::Cow,
 	future::Future,
+	hash::{Hash, Hasher},
 	marker::PhantomData,
 	ops::Deref,
 	rc::Rc
@@ -16,6 +28,7 @@ use std::{
 use crate::application::*;
 #[cfg(feature = "threadsafe")]
 use crate::delegate::*;
+use crate::error::BrowserError;
 use crate::window::*;
 
 use browser_window_core::browser_window::{BrowserWindowExt, BrowserWindowImpl, JsEvaluationError};
@@ -27,8 +40,86 @@ use unsafe_send_sync::UnsafeSend;
 
 
 mod builder;
+pub(in crate) mod bytes;
+#[cfg(feature = "cdp")]
+mod cdp;
+mod color_scheme;
+mod content;
+mod css;
+mod device;
+mod devtools;
+mod edit_command;
+pub(in crate) mod emit;
+mod events;
+mod favicon;
+mod file_chooser;
+mod frame;
+mod geolocation;
+mod history;
+#[cfg(feature = "dev")]
+pub(in crate) mod hot_reload;
+mod input;
+mod isolation;
+pub(in crate) mod js_value;
+mod keyboard;
+pub(in crate) mod message;
+mod metrics;
+#[cfg(feature = "mock")]
+mod mock;
+mod network;
+mod offscreen;
+mod print;
+mod property;
+mod recorder;
+pub(in crate) mod registry;
+mod render_process;
+pub(in crate) mod request;
+mod response;
+mod route;
+mod scheme;
+mod scroll;
+pub(in crate) mod session;
+mod tabs;
+mod task_scope;
+mod template;
+mod watch;
 
 pub use builder::{BrowserWindowBuilder, Source};
+#[cfg(feature = "cdp")]
+pub use cdp::{Cdp, CdpError, CdpEvents};
+pub use color_scheme::ColorScheme;
+pub use css::CssHandle;
+pub use device::DeviceMetrics;
+pub use edit_command::EditCommand;
+pub use events::{BrowserEvent, BrowserEvents};
+pub use favicon::Favicon;
+pub use file_chooser::{FileChooser, FileChooserMode};
+pub use frame::{Frame, FrameId};
+pub use history::{History, HistoryEntry};
+pub use input::{KeyEvent, MouseEvent, MouseEventKind};
+pub use js_value::JsValue;
+pub use keyboard::{EditableField, VirtualKeyboard};
+pub use message::WindowMessage;
+pub use metrics::Metrics;
+#[cfg(feature = "mock")]
+pub use mock::MockPage;
+pub use network::NetworkConditions;
+pub use offscreen::{AcceleratedPaint, Cursor, Modifiers, MouseButton, Paint, Rect};
+pub use print::{Margins, PaperSize, PrintSettings};
+pub use property::BrowserProperty;
+pub use recorder::{InputEvent, InputEventKind, InteractionScript, InteractionScriptError};
+pub use render_process::TerminationReason;
+pub use request::{InterceptedRequest, RequestAction};
+pub use response::Response;
+pub use scheme::{SchemeRequest, SchemeResponse};
+pub use session::SessionState;
+pub use tabs::TabbedBrowser;
+#[cfg(feature = "threadsafe")]
+pub use property::BrowserPropertyThreaded;
+use builder::{BrowserAcceleratorHandler, BrowserUserData};
+pub use task_scope::TaskScope;
+pub use template::WindowTemplate;
+pub use watch::JsWatch;
 
 
 
@@ -88,6 +179,8 @@ unsafe impl Sync for BrowserWindowThreaded {}
 #[derive(Clone, Copy)]
 pub struct BrowserWindowHandle {
 	pub(in super) inner: BrowserWindowImpl,
+	// Cached, so that it can still be read after the browser window has been freed.
+	id: WindowId,
 	window: WindowHandle
 }
 
@@ -99,6 +192,20 @@ pub trait OwnedBrowserWindow: OwnedWindow {
 
 impl BrowserWindow {
 
+	/// Closes the browser window, and waits until it has been destroyed and its resources have been freed.
+	///
+	/// Keep in mind that a window that still has child windows is not destroyed until its children are.
+	pub async fn close_and_wait( self ) {
+		let (tx, rx) = oneshot::channel::<()>();
+		unsafe { &mut *self.handle.user_data() }.destroy_waiters.push( tx );
+
+		self.close();
+		drop( self );
+
+		// The sender is only dropped without sending if the window never gets destroyed.
+		let _ = rx.await;
+	}
+
 	fn new( handle: BrowserWindowHandle ) -> Self {
 		Self {
 			handle: handle,
@@ -149,6 +256,26 @@ impl BrowserWindowHandle {
 		ApplicationHandle::new( self.inner.window().app() )
 	}
 
+	/// Returns the identifier of this browser window.
+	/// Two handles are equal when they have the same identifier.
+	///
+	/// The identifier is assigned by _Browser Window_ when the window is created, and is never reused for another window.
+	/// It remains valid after the window has been destroyed, so it can be used in logs and to correlate events.
+	pub fn id( &self ) -> WindowId {
+		self.id
+	}
+
+	/// Returns whether the main frame is still loading its document.
+	/// See also `BrowserWindowBuilder::on_loading_progress`.
+	pub fn is_loading( &self ) -> bool {
+		unsafe { &mut *self.user_data() }.loading
+	}
+
+	/// Returns the label that has been given to the window with `BrowserWindowBuilder::label`, if any.
+	pub fn label( &self ) -> Option<String> {
+		unsafe { &mut *self.user_data() }.label.clone()
+	}
+
 	/// Executes the given javascript code and returns the output as a string.
 	/// If you don't need the result, see `exec_js`.
 	pub async fn eval_js( &self, js: &str ) -> Result<String, JsEvaluationError> {
@@ -156,10 +283,6 @@ impl BrowserWindowHandle {
 		let (tx, rx) = oneshot::channel::<Result<String, JsEvaluationError>>();
 
 		self._eval_js( js, |_, result| {
-
-			// The callback of `_eval_js` is not necessarily called from our GUI thread, so this is a quickfix to make that safe.
-			// Otherwise, the `panic` may not be propegated correctly!
-			// FIXME: Call this result callback closure form the GUI thread from within the CEF implementation.
 			if let Err(_) = tx.send( result ) {
 				panic!("Unable to send JavaScript result back")
 			}
@@ -172,24 +295,249 @@ impl BrowserWindowHandle {
 	///
 	/// # Arguments
 	/// * `on_complete` - The closure that will be called when the output is ready.
-	fn _eval_js<'a,H>( &self, js: &str, on_complete: H ) where
-		H: FnOnce( BrowserWindowHandle, Result<String, JsEvaluationError> ) + 'a
+	fn _eval_js<H>( &self, js: &str, on_complete: H ) where
+		H: FnOnce( BrowserWindowHandle, Result<String, JsEvaluationError> ) + 'static
 	{
-		let data_ptr: *mut H = Box::into_raw(
-			Box::new( on_complete )
+		let data_ptr: *mut WindowCallbackData<H> = Box::into_raw(
+			Box::new( (self.id, on_complete) )
 		);
 
 		self.inner.eval_js( js.into(), eval_js_callback::<H>, data_ptr as _ );
 	}
 
+	/// Like `eval_js`, except that the JavaScript code is executed within the given frame.
+	/// Results in an error if the frame doesn't exist (anymore).
+	pub async fn eval_js_in_frame( &self, frame: FrameId, js: &str ) -> Result<String, JsEvaluationError> {
+		let (tx, rx) = oneshot::channel::<Result<String, JsEvaluationError>>();
+
+		self._eval_js_in_frame( frame, js, |_, result| {
+			if let Err(_) = tx.send( result ) {
+				panic!("Unable to send JavaScript result back")
+			}
+		} );
+
+		rx.await.unwrap()
+	}
+
+	/// Executes the given JavaScript code within the given frame, and provides the output via a callback.
+	fn _eval_js_in_frame<H>( &self, frame: FrameId, js: &str, on_complete: H ) where
+		H: FnOnce( BrowserWindowHandle, Result<String, JsEvaluationError> ) + 'static
+	{
+		let data_ptr: *mut WindowCallbackData<H> = Box::into_raw(
+			Box::new( (self.id, on_complete) )
+		);
+
+		self.inner.eval_js_in_frame( frame.0, js, eval_js_callback::<H>, data_ptr as _ );
+	}
+
+	/// Returns the tree of frames that are currently in the browser window, with the main frame at its root.
+	/// Returns `None` if the browser doesn't have a main frame yet.
+	pub fn frame_tree( &self ) -> Option<Frame> {
+		frame::frame_tree( self.inner.frames() )
+	}
+
+	/// Returns all frames that are currently in the browser window, with the main frame first, and every frame before its subframes.
+	/// This is easier to search through than `frame_tree`, like for an embedded frame with a certain name:
+	/// ```ignore
+	/// if let Some( frame ) = bw.frames().into_iter().find(|f| f.name() == "payment" ) {
+	/// 	bw.eval_js_in_frame( frame.id(), "document.title" ).await?;
+	/// }
+	/// ```
+	/// Is empty if the browser doesn't have a main frame yet.
+	pub fn frames( &self ) -> Vec<Frame> {
+		let mut frames = Vec::new();
+		if let Some( main ) = self.frame_tree() {
+			main.collect( &mut frames );
+		}
+		frames
+	}
+
+	/// Causes an offscreen browser to paint its whole view again.
+	/// See `BrowserWindowBuilder::on_paint` for more information about offscreen browsers.
+	pub fn invalidate( &self ) {
+		self.inner.invalidate()
+	}
+
 	/// Executes the given javascript code without waiting on it to finish.
 	pub fn exec_js( &self, js: &str ) {
 		self._eval_js( js, |_,_|{} );
 	}
 
 	/// Causes the browser to navigate to the given url.
-	pub fn navigate( &self, url: &str ) {
-		self.inner.navigate( url )
+	///
+	/// Results in `BrowserError::InvalidUrl` if the url isn't absolute, in `BrowserError::WindowClosed` if the window has been closed already, and in `BrowserError::EngineError` if the browser engine refuses to navigate.
+	pub fn navigate( &self, url: &str ) -> Result<(), BrowserError> {
+		self.check_navigation( url )?;
+
+		self.inner.navigate( url )?;
+		Ok(())
+	}
+
+	/// Causes the browser to navigate to the given url, like `navigate`, and resolves once the page has finished loading, or has failed to load.
+	/// So JavaScript that is evaluated after it has resolved runs on the new page.
+	///
+	/// It resolves with the first load of the main frame that finishes after the navigation has started.
+	/// Results in the same errors as `navigate`, in `BrowserError::LoadFailed` if the page couldn't be loaded, and in `BrowserError::WindowClosed` if the window is closed before then.
+	pub async fn navigate_async( &self, url: &str ) -> Result<(), BrowserError> {
+		// The events are watched before navigating, so that the load can't be missed
+		let mut events = self.events();
+		self.navigate( url )?;
+
+		while let Some( event ) = events.next().await {
+			match event {
+				BrowserEvent::Loaded => return Ok(()),
+				BrowserEvent::LoadFailed( error ) => return Err( error ),
+				_ => {}
+			}
+		}
+		Err( BrowserError::WindowClosed )
+	}
+
+	/// Causes the given frame to navigate to the given url.
+	///
+	/// Results in the same errors as `navigate`, and in `BrowserError::FrameNotFound` if the frame doesn't exist (anymore).
+	pub fn navigate_frame( &self, frame: FrameId, url: &str ) -> Result<(), BrowserError> {
+		self.check_navigation( url )?;
+
+		if !self.inner.navigate_frame( frame.0, url ) {
+			return Err( BrowserError::FrameNotFound );
+		}
+		Ok(())
+	}
+
+	/// Loads the page of the main frame again.
+	///
+	/// After the render process of the page has terminated, the page stays blank until it is reloaded, which starts a new render process.
+	/// See `BrowserWindowBuilder::on_render_process_terminated` and `BrowserWindowBuilder::reload_on_crash`.
+	pub fn reload( &self ) {
+		self.inner.reload( false )
+	}
+
+	/// Loads the page of the main frame again, without taking anything from the cache, like pressing `Ctrl+F5` does.
+	/// So the scripts and style sheets of the page are fetched again, even when their cached copies haven't expired yet.
+	///
+	/// Resources that are loaded later on, like by `fetch`, may still come from the cache, see `clear_http_cache`.
+	pub fn reload_ignore_cache( &self ) {
+		self.inner.reload( true )
+	}
+
+	/// Checks that `url` is absolute, which means that it starts with a scheme like `https:`, and that the window still exists.
+	fn check_navigation( &self, url: &str ) -> Result<(), BrowserError> {
+		let scheme = url.split( ':' ).next().unwrap_or( "" );
+		let is_absolute = url.contains( ':' ) &&
+			scheme.chars().next().map(|c| c.is_ascii_alphabetic() ).unwrap_or( false ) &&
+			scheme.chars().all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.' );
+
+		if !is_absolute {
+			return Err( BrowserError::InvalidUrl( url.to_owned() ) );
+		}
+		if registry::by_id( self.id() ).is_none() {
+			return Err( BrowserError::WindowClosed );
+		}
+		Ok(())
+	}
+
+	/// Registers a key combination like `Ctrl+S` or `Ctrl+Shift+F5`, that invokes `handler` whenever it is pressed while the browser window has focus.
+	/// The key press is handled before the page sees it, so it also works when an input field is focused, and the page doesn't receive it.
+	/// Key combinations are written in the same way as the accelerators of menu items, see the [`menu`](../menu/index.html) module.
+	///
+	/// Registering the same key combination again replaces its handler.
+	/// Returns false if the key combination isn't valid.
+	///
+	/// *Note:* WebView2 only reports key presses with `Ctrl` or `Alt`, and keys like the function keys and `Esc`.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn register_accelerator<H>( &self, accelerator: &str, handler: H ) -> bool where
+		H: FnMut( BrowserWindowHandle ) + 'static
+	{
+		self._register_accelerator( accelerator, Box::new( handler ) )
+	}
+
+	/// Registers a key combination like `Ctrl+S` or `Ctrl+Shift+F5`, that invokes `handler` whenever it is pressed while the browser window has focus.
+	/// The key press is handled before the page sees it, so it also works when an input field is focused, and the page doesn't receive it.
+	/// Key combinations are written in the same way as the accelerators of menu items, see the [`menu`](../menu/index.html) module.
+	///
+	/// Registering the same key combination again replaces its handler.
+	/// Returns false if the key combination isn't valid.
+	///
+	/// *Note:* WebView2 only reports key presses with `Ctrl` or `Alt`, and keys like the function keys and `Esc`.
+	#[cfg(feature = "threadsafe")]
+	pub fn register_accelerator<H>( &self, accelerator: &str, handler: H ) -> bool where
+		H: FnMut( BrowserWindowHandle ) + Send + 'static
+	{
+		self._register_accelerator( accelerator, Box::new( handler ) )
+	}
+
+	/// Sends a typed character to an offscreen browser, which is what makes text appear in input fields.
+	/// Send it after the key press of the key that has produced it.
+	pub fn send_char( &self, character: char, modifiers: Modifiers ) {
+		self.inner.send_key( 2, 0, character as u32, modifiers.to_c() )
+	}
+
+	/// Lets an offscreen browser know whether or not it has the keyboard focus.
+	pub fn send_focus( &self, focused: bool ) {
+		self.inner.send_focus( focused )
+	}
+
+	/// Sends a key press or release to an offscreen browser.
+	/// `key_code` is the virtual key code as it is used on Windows, like `0x41` for the A key, on all platforms.
+	pub fn send_key( &self, key_code: u16, pressed: bool, modifiers: Modifiers ) {
+		self.inner.send_key( if pressed {0} else {1}, key_code as _, 0, modifiers.to_c() )
+	}
+
+	/// Sends a mouse button press or release to an offscreen browser, at the given position within its view.
+	/// `click_count` is 2 for the second press of a double click.
+	pub fn send_mouse_button( &self, x: i32, y: i32, button: MouseButton, pressed: bool, click_count: u32, modifiers: Modifiers ) {
+		self.inner.send_mouse_button( x, y, button.to_c(), !pressed, click_count as _, modifiers.to_c() )
+	}
+
+	/// Lets an offscreen browser know that the mouse has left its view.
+	pub fn send_mouse_leave( &self, modifiers: Modifiers ) {
+		self.inner.send_mouse_move( 0, 0, true, modifiers.to_c() )
+	}
+
+	/// Moves the mouse of an offscreen browser to the given position within its view.
+	/// The mouse buttons that are held down need to be set in `modifiers` while dragging.
+	pub fn send_mouse_move( &self, x: i32, y: i32, modifiers: Modifiers ) {
+		self.inner.send_mouse_move( x, y, false, modifiers.to_c() )
+	}
+
+	/// Scrolls an offscreen browser at the given position within its view, by the given amount of pixels.
+	pub fn send_mouse_wheel( &self, x: i32, y: i32, delta_x: i32, delta_y: i32, modifiers: Modifiers ) {
+		self.inner.send_mouse_wheel( x, y, delta_x, delta_y, modifiers.to_c() )
+	}
+
+	/// Overrides the character encoding of the pages that are loaded in the browser window, like `"windows-1252"`.
+	/// This is useful for (legacy) pages that have a missing or broken encoding declaration.
+	/// Use `None` to remove the override again.
+	/// The current page is reloaded so that the override takes effect.
+	///
+	/// With CEF, the override applies to all browser windows, and pages that correctly declare a different encoding are not affected.
+	/// WebView2 doesn't support this at all.
+	pub fn set_charset_override( &self, charset: Option<&str> ) {
+		self.inner.set_charset_override( charset.unwrap_or("") )
+	}
+
+	/// Returns the origins of all frames that are currently in the browser window, without duplicates.
+	/// These are the origins that are able to set cookies and store data from within the browser window.
+	///
+	/// The cookies of an origin can be inspected with `CookieJar::iter`, and everything an origin has stored can be removed with `clear_origin_data`.
+	pub fn origins( &self ) -> Vec<String> {
+		let mut origins = Vec::new();
+
+		if let Some( main ) = self.frame_tree() {
+			main.collect_origins( &mut origins );
+		}
+
+		origins
+	}
+
+	/// Removes all cookies and stored data of the given origin, like local storage, IndexedDB databases and cached data.
+	/// The origin should look like `https://example.com`, see also `origins`.
+	///
+	/// The data is removed asynchronously.
+	/// Returns false if the browser engine was unable to start removing the data.
+	pub fn clear_origin_data( &self, origin: &str ) -> bool {
+		self.inner.clear_origin_data( origin )
 	}
 
 	pub fn url<'a>(&'a self) -> Cow<'a, str> {
@@ -201,6 +549,34 @@ impl BrowserWindowHandle {
 			self.inner.window()
 		)
 	}
+
+	fn _register_accelerator( &self, accelerator: &str, handler: BrowserAcceleratorHandler ) -> bool {
+		let accelerators = &mut unsafe { &mut *self.user_data() }.accelerators;
+
+		let index = match accelerators.iter().position(|(a, _)| a == accelerator ) {
+			Some( index ) => index,
+			None => accelerators.len()
+		};
+
+		if !self.inner.register_accelerator( index as u32 + 1, accelerator ) {
+			return false;
+		}
+
+		if index < accelerators.len() {
+			accelerators[index].1 = Some( handler );
+		}
+		else {
+			accelerators.push( (accelerator.to_owned(), Some( handler )) );
+		}
+		true
+	}
+
+	/// Gives the data that _Browser Window_ stores with the browser window.
+	/// It may only be accessed on the GUI thread, while the browser window hasn't been destroyed yet.
+	/// Handlers may access the data as well, so a reference to it is only meant to be used right away.
+	fn user_data( &self ) -> *mut BrowserUserData {
+		self.inner.user_data() as _
+	}
 }
 
 
@@ -213,6 +589,12 @@ impl BrowserWindowThreaded {
 		ApplicationHandleThreaded::from_core_handle( self.handle.inner.window().app() )
 	}
 
+	/// Returns the identifier of this browser window.
+	/// It can be used to compare browser windows across threads.
+	pub fn id( &self ) -> WindowId {
+		self.handle.id()
+	}
+
 	/// Closes the browser.
 	pub fn close( self ) -> bool {
 		self.dispatch(|bw| {
@@ -220,6 +602,23 @@ impl BrowserWindowThreaded {
 		})
 	}
 
+	/// Closes the browser, and waits until it has been destroyed and its resources have been freed.
+	/// This is useful when windows need to be shut down in a particular order.
+	///
+	/// Keep in mind that a window that still has child windows is not destroyed until its children are.
+	/// If the runtime is not running, or exits before the window is destroyed, this resolves with `DelegateError::RuntimeNotAvailable`.
+	pub fn close_and_wait( self ) -> DispatchFuture<()> {
+		let (tx, rx) = oneshot::channel::<()>();
+
+		let dispatched = self.dispatch(move |bw| {
+			unsafe { &mut *bw.user_data() }.destroy_waiters.push( tx );
+			bw.close();
+		});
+
+		// Dropping `self` afterwards causes the window to get destroyed.
+		DispatchFuture::new( if dispatched { Some( rx ) } else { None } )
+	}
+
 	/// Executes the given closure within the GUI thread, and return the value that the closure returned.
 	/// Also see `ApplicationThreaded::delegate`.
 	///
@@ -272,16 +671,73 @@ impl BrowserWindowThreaded {
 		})
 	}
 
-	/// Executes the given closure on the GUI thread.
-	/// See also `Application::dispatch`.
-	pub fn dispatch_async<'a,C,F>( &self, func: C ) -> bool where
+	/// Executes the given closure on the GUI thread, before any closure of a lower priority that is still waiting.
+	/// See also `ApplicationHandleThreaded::dispatch_with_priority`.
+	pub fn dispatch_with_priority<'a,F>( &self, priority: DispatchPriority, func: F ) -> bool where
+		F:  FnOnce( BrowserWindowHandle ) + Send + 'a
+	{
+		let handle = UnsafeSend::new( self.handle );
+
+		self.app().dispatch_with_priority( priority, move |_| {
+			func( handle.i );
+		})
+	}
+
+	/// Executes the given closure on the GUI thread, and converts its output with `map` on the GUI thread as well.
+	/// Only the output of `map` needs to be `Send`.
+	/// See also `ApplicationHandleThreaded::dispatch_map`.
+	///
+	/// # Example
+	/// ```ignore
+	/// let title: String = bw.dispatch_map(
+	/// 	|handle| handle.window(),
+	/// 	|window| window.title().get()
+	/// ).await?;
+	/// ```
+	pub fn dispatch_map<'a,F,M,T,R>( &self, func: F, map: M ) -> DispatchFuture<R> where
+		F: FnOnce( BrowserWindowHandle ) -> T + Send + 'a,
+		M: FnOnce( T ) -> R + Send + 'a,
+		R: Send + 'static
+	{
+		let handle = UnsafeSend::new( self.handle );
+
+		self.app().dispatch_map(move |_| func( handle.i ), map )
+	}
+
+	/// Executes the given closure on the GUI thread, and keeps its output there.
+	/// The returned `GuiRef` can be sent to other threads, to use the output later on.
+	/// See also `ApplicationHandleThreaded::dispatch_ref`.
+	pub fn dispatch_ref<'a,F,T>( &self, func: F ) -> DispatchFuture<GuiRef<T>> where
+		F: FnOnce( BrowserWindowHandle ) -> T + Send + 'a,
+		T: 'static
+	{
+		let handle = UnsafeSend::new( self.handle );
+
+		self.app().dispatch_ref(move |_| func( handle.i ) )
+	}
+
+	/// Executes the given async closure on the GUI thread.
+	/// The returned future resolves with the output of the closure's future, once it has completed.
+	/// See also `ApplicationThreaded::dispatch_async`.
+	pub fn dispatch_async<'a,C,F,R>( &self, func: C ) -> DispatchFuture<R> where
 		C:  FnOnce( BrowserWindowHandle ) -> F + Send + 'a,
-		F: Future<Output=()> + 'static
+		F: Future<Output=R> + 'static,
+		R: Send + 'static
 	{
 		let handle = UnsafeSend::new( self.handle );
 
-		self.app().dispatch(move |a| {
-			a.spawn( func( handle.i ) );
+		self.app().dispatch_async(move |_| {
+			func( handle.i )
+		})
+	}
+
+	/// Causes the browser to navigate to the given url, and resolves once the page has finished loading, or has failed to load.
+	/// See `BrowserWindowHandle::navigate_async`.
+	pub fn navigate( &self, url: &str ) -> DispatchFuture<Result<(), BrowserError>> {
+		let url = url.to_owned();
+
+		self.dispatch_async(move |bw| async move {
+			bw.navigate_async( &url ).await
 		})
 	}
 
@@ -292,6 +748,15 @@ impl BrowserWindowThreaded {
 	}
 }
 
+#[cfg(feature = "threadsafe")]
+impl Drop for BrowserWindowThreaded {
+	fn drop( &mut self ) {
+		self.dispatch(|bw| {
+			bw.inner.window().drop();
+		});
+	}
+}
+
 #[cfg(feature = "threadsafe")]
 impl HasAppHandle for BrowserWindowThreaded {
 
@@ -317,14 +782,37 @@ impl OwnedBrowserWindow for BrowserWindowThreaded {
 
 
 impl BrowserWindowHandle {
+	/// Only to be used while the user data of the browser window exists, because that is where the ID is taken from.
 	fn new( inner_handle: BrowserWindowImpl ) -> Self {
+		let user_data = inner_handle.user_data() as *mut BrowserUserData;
+
+		Self::with_id( inner_handle, unsafe { (*user_data).id } )
+	}
+
+	/// For callbacks that may be invoked while the browser window is being destroyed, which carry the ID themselves.
+	fn with_id( inner_handle: BrowserWindowImpl, id: WindowId ) -> Self {
 		Self {
 			inner: inner_handle,
+			id,
 			window: WindowHandle::new( inner_handle.window() )
 		}
 	}
 }
 
+impl PartialEq for BrowserWindowHandle {
+	fn eq( &self, other: &Self ) -> bool {
+		self.id() == other.id()
+	}
+}
+
+impl Eq for BrowserWindowHandle {}
+
+impl Hash for BrowserWindowHandle {
+	fn hash<H: Hasher>( &self, state: &mut H ) {
+		self.id().hash( state );
+	}
+}
+
 impl Deref for BrowserWindowHandle {
 	type Target = WindowHandle;
 
@@ -344,13 +832,17 @@ impl HasAppHandle for BrowserWindowHandle {
 
 
 
+/// The data of a callback that the browser engine invokes once it is done, together with the ID of the browser window.
+/// The window may be in the middle of being destroyed by then, with its user data gone already, which is why the ID comes with the callback.
+type WindowCallbackData<H> = (WindowId, H);
+
+// The result is handled like an event of the browser window, so that it comes after the events that have occurred before it.
 unsafe fn eval_js_callback<H>( _handle: BrowserWindowImpl, cb_data: *mut (), result: Result<String, JsEvaluationError> ) where
-	H: FnOnce(BrowserWindowHandle, Result<String, JsEvaluationError>)
+	H: FnOnce(BrowserWindowHandle, Result<String, JsEvaluationError>) + 'static
 {
-	let data_ptr = cb_data as *mut H;
-	let data = Box::from_raw( data_ptr );
+	let (id, on_complete) = *Box::from_raw( cb_data as *mut WindowCallbackData<H> );
 
-	let handle = BrowserWindowHandle::new( _handle );
+	let handle = BrowserWindowHandle::with_id( _handle, id );
 
-	(*data)( handle, result );
-}
\ No newline at end of file
+	emit::emit( id, move || on_complete( handle, result ) );
+}
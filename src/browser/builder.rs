@@ -1,15 +1,41 @@
 use browser_window_core::*;
 use browser_window_core::browser_window::*;
+use browser_window_core::menu::MenuExt;
+use browser_window_core::prelude::{Dims2D, Pos2D};
 use browser_window_core::window::*;
 
-use crate::application::{ApplicationHandle};
+use crate::application::{self, ApplicationHandle};
 use crate::browser::*;
-use crate::window::WindowBuilder;
+use crate::error::BrowserError;
+use crate::ipc::{self, Api, ApiEntry, Commands};
+use crate::menu::{MenuBar, MenuItemEvent};
+use crate::permission::{self, Permission};
+use super::emit;
+use super::offscreen::{AcceleratedPaint, Cursor, OffscreenHandlers, Paint, Rect};
+use super::property::BrowserPropertyWatchers;
+use super::request::{self, Decider};
+use super::response;
+use super::scheme::Responder;
+#[cfg(feature = "cdp")]
+use super::cdp;
+#[cfg(feature = "dev")]
+use super::hot_reload;
+use super::{bytes, isolation, keyboard, message, recorder, registry, route, task_scope};
+use super::bytes::BrowserBytesHandler;
+use super::input::{self, BrowserKeyHandler, BrowserMouseHandler, KeyEvent, MouseEvent};
+use super::keyboard::KeyboardState;
+use super::message::{BrowserMessageHandler, BrowserPageEventHandler};
+#[cfg(feature = "cdp")]
+use crate::prop::PropertyWatchers;
+use crate::prop::Property;
+use crate::window::{Effect, SizeUnit, WindowBuilder, WindowId};
 
 use std::{
 	ops::DerefMut,
 	path::PathBuf,
 	pin::Pin,
+	ptr,
+	sync::Weak,
 	vec::Vec
 };
 
@@ -19,6 +45,7 @@ use unsafe_send_sync::UnsafeSend;
 
 
 /// The type of content to display in a browser window
+#[derive(Clone)]
 pub enum Source {
 	/// Displays the given HTML code in the browser.
 	Html( String ),
@@ -29,13 +56,118 @@ pub enum Source {
 }
 
 #[cfg(not(feature = "threadsafe"))]
-type BrowserJsInvocationHandler = Box<dyn FnMut(BrowserWindowHandle, String, Vec<String>) -> Pin<Box<dyn Future<Output=()>>>>;
+pub(in super) type BrowserAcceleratorHandler = Box<dyn FnMut(BrowserWindowHandle)>;
 #[cfg(feature = "threadsafe")]
-type BrowserJsInvocationHandler = Box<dyn FnMut(BrowserWindowHandle, String, Vec<String>) -> Pin<Box<dyn Future<Output=()>>> + Send>;
+pub(in super) type BrowserAcceleratorHandler = Box<dyn FnMut(BrowserWindowHandle) + Send>;
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserFaviconHandler = Box<dyn FnMut(BrowserWindowHandle, &Favicon)>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserFaviconHandler = Box<dyn FnMut(BrowserWindowHandle, &Favicon) + Send>;
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserFileChooserHandler = Box<dyn FnMut(BrowserWindowHandle, FileChooser)>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserFileChooserHandler = Box<dyn FnMut(BrowserWindowHandle, FileChooser) + Send>;
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserJsInvocationHandler = Box<dyn FnMut(BrowserWindowHandle, String, Vec<String>) -> Pin<Box<dyn Future<Output=()>>>>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserJsInvocationHandler = Box<dyn FnMut(BrowserWindowHandle, String, Vec<String>) -> Pin<Box<dyn Future<Output=()>>> + Send>;
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserLoadingProgressHandler = Box<dyn FnMut(BrowserWindowHandle, f64)>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserLoadingProgressHandler = Box<dyn FnMut(BrowserWindowHandle, f64) + Send>;
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserMenuHandler = Box<dyn FnMut(BrowserWindowHandle, &MenuItemEvent)>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserMenuHandler = Box<dyn FnMut(BrowserWindowHandle, &MenuItemEvent) + Send>;
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserRenderProcessTerminatedHandler = Box<dyn FnMut(BrowserWindowHandle, TerminationReason)>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserRenderProcessTerminatedHandler = Box<dyn FnMut(BrowserWindowHandle, TerminationReason) + Send>;
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserRequestHandler = Box<dyn FnMut(BrowserWindowHandle, &mut InterceptedRequest) -> RequestAction>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserRequestHandler = Box<dyn FnMut(BrowserWindowHandle, &mut InterceptedRequest) -> RequestAction + Send>;
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserResponseHandler = Box<dyn FnMut(BrowserWindowHandle, &Response)>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserResponseHandler = Box<dyn FnMut(BrowserWindowHandle, &Response) + Send>;
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserRouteHandler = Box<dyn FnMut(BrowserWindowHandle, &str)>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserRouteHandler = Box<dyn FnMut(BrowserWindowHandle, &str) + Send>;
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserScaleFactorHandler = Box<dyn FnMut(BrowserWindowHandle, f32)>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserScaleFactorHandler = Box<dyn FnMut(BrowserWindowHandle, f32) + Send>;
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserStatusTextHandler = Box<dyn FnMut(BrowserWindowHandle, &str)>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserStatusTextHandler = Box<dyn FnMut(BrowserWindowHandle, &str) + Send>;
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserSchemeHandler = Box<dyn FnMut(BrowserWindowHandle, SchemeRequest) -> SchemeResponse>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserSchemeHandler = Box<dyn FnMut(BrowserWindowHandle, SchemeRequest) -> SchemeResponse + Send>;
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserUnresponsiveHandler = Box<dyn FnMut(BrowserWindowHandle, bool)>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserUnresponsiveHandler = Box<dyn FnMut(BrowserWindowHandle, bool) + Send>;
 
 /// The data that is passed to the C FFI handler function
-struct BrowserUserData {
-	handler: BrowserJsInvocationHandler
+pub(in super) struct BrowserUserData {
+	/// The registered accelerators with their handlers, where the native ID of each accelerator is its index plus one.
+	/// A handler is taken out while it runs, so that it can register accelerators itself.
+	pub accelerators: Vec<(String, Option<BrowserAcceleratorHandler>)>,
+	pub apis: Vec<ApiEntry>,
+	pub bytes_handler: Option<BrowserBytesHandler>,
+	/// The streams of `Cdp::events`, with the event of the Chrome DevTools Protocol that each of them follows.
+	#[cfg(feature = "cdp")]
+	pub cdp_events: Vec<(String, PropertyWatchers<String>)>,
+	/// Senders that get signalled when the browser window has been destroyed.
+	pub destroy_waiters: Vec<oneshot::Sender<()>>,
+	/// Whether the favicon of the page becomes the icon of the window.
+	pub favicon_as_icon: bool,
+	pub favicon_handler: Option<BrowserFaviconHandler>,
+	pub file_chooser_handler: Option<BrowserFileChooserHandler>,
+	pub handler: BrowserJsInvocationHandler,
+	pub id: WindowId,
+	/// The token that the calls of isolated preload scripts carry, if the scripts are isolated.
+	pub isolation_token: Option<String>,
+	/// The ID of the main frame and the execution context of the world that `evaluate_isolated` has created in it, until the window navigates.
+	pub isolated_world: Option<(String, i64)>,
+	pub key_handler: Option<BrowserKeyHandler>,
+	pub keyboard: KeyboardState,
+	pub label: Option<String>,
+	/// The last position that has been reported, as some platforms report moves when only the size has changed.
+	pub last_position: Option<Pos2D>,
+	/// The handlers of the events that the page emits with `bw.emit`, with the event that each of them listens to.
+	pub listeners: Vec<(String, BrowserPageEventHandler)>,
+	/// Whether the main frame is loading, according to the progress that has been reported last.
+	pub loading: bool,
+	pub loading_progress_handler: Option<BrowserLoadingProgressHandler>,
+	pub menu_handler: Option<BrowserMenuHandler>,
+	/// The identifiers of the menu items, where the native ID of each item is its index plus one.
+	pub menu_item_ids: Vec<String>,
+	pub mouse_handler: Option<BrowserMouseHandler>,
+	pub offscreen: OffscreenHandlers,
+	/// The recording of `record_interactions` that is in progress, if any.
+	pub recording: Option<recorder::Recording>,
+	/// Whether the page is reloaded once its render process has terminated.
+	pub reload_on_crash: bool,
+	pub render_process_terminated_handler: Option<BrowserRenderProcessTerminatedHandler>,
+	/// The handlers of the intercepted requests, with the filter of the URLs that each of them handles.
+	pub request_handlers: Vec<(String, BrowserRequestHandler)>,
+	pub response_handler: Option<BrowserResponseHandler>,
+	pub route_handler: Option<BrowserRouteHandler>,
+	pub scale_factor_handler: Option<BrowserScaleFactorHandler>,
+	/// The handlers of the custom schemes, with the scheme that each of them handles.
+	pub scheme_handlers: Vec<(String, BrowserSchemeHandler)>,
+	pub status_text_handler: Option<BrowserStatusTextHandler>,
+	/// The handlers of the messages that other windows send to this one, with the event that each of them has subscribed to.
+	pub subscriptions: Vec<(String, BrowserMessageHandler)>,
+	/// The scopes of `task_scope` that may still have tasks running.
+	pub task_scopes: Vec<Weak<task_scope::ScopeState>>,
+	pub unresponsive_handler: Option<BrowserUnresponsiveHandler>,
+	pub watchers: BrowserPropertyWatchers
 }
 
 /// Used to create a [`BrowserWindow`] or [`BrowserWindowThreaded`] instance, depending on whether or not you have feature `threadsafe` enabled.
@@ -71,16 +203,101 @@ struct BrowserUserData {
 /// ```
 pub struct BrowserWindowBuilder {
 
-	dev_tools: bool,
-	handler: Option<BrowserJsInvocationHandler>,
-	source: Source,
-	window: WindowBuilder
+	pub(in super) apis: Vec<ApiEntry>,
+	pub(in super) bytes_handler: Option<BrowserBytesHandler>,
+	pub(in super) default_encoding: Option<String>,
+	pub(in super) dev_tools: bool,
+	pub(in super) favicon_as_icon: bool,
+	pub(in super) favicon_handler: Option<BrowserFaviconHandler>,
+	pub(in super) file_chooser_handler: Option<BrowserFileChooserHandler>,
+	pub(in super) handler: Option<BrowserJsInvocationHandler>,
+	pub(in super) isolate_scripts: bool,
+	pub(in super) key_handler: Option<BrowserKeyHandler>,
+	pub(in super) keyboard: KeyboardState,
+	pub(in super) label: Option<String>,
+	pub(in super) loading_progress_handler: Option<BrowserLoadingProgressHandler>,
+	pub(in super) menu_bar: Option<MenuBar>,
+	pub(in super) menu_handler: Option<BrowserMenuHandler>,
+	pub(in super) mouse_handler: Option<BrowserMouseHandler>,
+	pub(in super) offscreen: OffscreenHandlers,
+	pub(in super) permission_defaults: Vec<(Permission, bool)>,
+	pub(in super) preload_scripts: Vec<String>,
+	pub(in super) reload_on_crash: bool,
+	pub(in super) render_process_terminated_handler: Option<BrowserRenderProcessTerminatedHandler>,
+	pub(in super) request_handlers: Vec<(String, BrowserRequestHandler)>,
+	pub(in super) response_handler: Option<BrowserResponseHandler>,
+	pub(in super) route_handler: Option<BrowserRouteHandler>,
+	pub(in super) scale_factor_handler: Option<BrowserScaleFactorHandler>,
+	pub(in super) scheme_handlers: Vec<(String, BrowserSchemeHandler)>,
+	pub(in super) status_text_handler: Option<BrowserStatusTextHandler>,
+	pub(in super) source: Source,
+	pub(in super) unresponsive_handler: Option<BrowserUnresponsiveHandler>,
+	#[cfg(feature = "dev")]
+	pub(in super) watched: Vec<PathBuf>,
+	pub(in super) window: WindowBuilder
 }
 
 
 
 impl BrowserWindowBuilder {
 
+	/// Exposes the methods of `api` to JavaScript.
+	/// The type of `api` is supposed to implement `Api` with the `#[browser_window::api]` attribute.
+	/// See the [`ipc`](../ipc/index.html) module for more information.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn api<A>( &mut self, api: A ) -> &mut Self where
+		A: Api
+	{
+		self.add_api_stubs::<A>();
+		self.apis.push( ipc::api_entry( api ) );
+		self
+	}
+
+	/// Exposes the methods of `api` to JavaScript.
+	/// The type of `api` is supposed to implement `Api` with the `#[browser_window::api]` attribute.
+	/// See the [`ipc`](../ipc/index.html) module for more information.
+	#[cfg(feature = "threadsafe")]
+	pub fn api<A>( &mut self, api: A ) -> &mut Self where
+		A: Api + Send + Sync
+	{
+		self.add_api_stubs::<A>();
+		self.apis.push( ipc::api_entry( api ) );
+		self
+	}
+
+	fn add_api_stubs<A: Api>( &mut self ) {
+		self.add_bridge();
+		self.preload_scripts.push( A::js_stubs() );
+	}
+
+	fn add_bridge( &mut self ) {
+		if self.apis.len() == 0 {
+			self.preload_scripts.insert( 0, ipc::BRIDGE_JS.to_owned() );
+		}
+	}
+
+	/// Exposes the given commands to JavaScript, as methods of the global `commands` object.
+	/// The commands are functions with the `#[browser_window::command]` attribute, which are collected with `generate_handler!`.
+	/// See the [`ipc`](../ipc/index.html) module for more information.
+	///
+	/// This can be called more than once, the commands are added to the ones that are already there.
+	pub fn commands( &mut self, commands: Commands ) -> &mut Self {
+		if commands.iter().next().is_none() {
+			return self;
+		}
+
+		self.add_bridge();
+		self.preload_scripts.push( commands.js_stubs() );
+		self.apis.extend( commands.to_entries() );
+		self
+	}
+
+	/// Gives the window a label, by which it can be found with `ApplicationHandle::window_by_label`.
+	/// Labels are meant to be unique, like `"settings"` for the settings window.
+	pub fn label<L: Into<String>>( &mut self, label: L ) -> &mut Self {
+		self.label = Some( label.into() );	self
+	}
+
 	/// Configure a closure that can be invoked from within JavaScript.
 	/// The closure's second parameter specifies a command name.
 	/// The closure's third parameter specifies an array of string arguments.
@@ -109,6 +326,29 @@ impl BrowserWindowBuilder {
 		self
 	}
 
+	/// Sets the character encoding that is used for pages that don't declare their own encoding, like `"windows-1252"`.
+	/// By default, the browser engine's own default is used.
+	pub fn default_encoding( &mut self, encoding: &str ) -> &mut Self {
+		self.default_encoding = Some( encoding.to_owned() );	self
+	}
+
+	/// Allows or denies the given permission to the pages of this window, when the permission policy of the application leaves it to the browser engine.
+	/// This way a window that plays a video call can have the camera, or a window that shares the screen can skip the prompt, without a policy that applies to all windows.
+	/// Permissions that aren't given a default are left to the browser engine, which may ask the user.
+	///
+	/// *Note:* WebView2 always lets the user pick what to share when the screen is captured, even if `Permission::ScreenCapture` is allowed.
+	///
+	/// # Example
+	/// ```ignore
+	/// bwb.default_permission( Permission::Camera, true )
+	/// 	.default_permission( Permission::ScreenCapture, false );
+	/// ```
+	pub fn default_permission( &mut self, permission: Permission, allowed: bool ) -> &mut Self {
+		self.permission_defaults.retain(|(p, _)| *p != permission );
+		self.permission_defaults.push( (permission, allowed) );
+		self
+	}
+
 	/// Sets whether or not an extra window with developer tools will be opened together with this browser.
 	/// When in debug mode the default is `true`.
 	/// When in release mode the default is `false`.
@@ -116,6 +356,560 @@ impl BrowserWindowBuilder {
 		self.dev_tools = enabled;	self
 	}
 
+	/// Sets whether or not the favicon of the page becomes the icon of the window, like browsers show it in their tabs.
+	/// The icon of the window is left alone until the page has a favicon.
+	/// The default is `false`.
+	pub fn favicon_as_icon( &mut self, enabled: bool ) -> &mut Self {
+		self.favicon_as_icon = enabled;	self
+	}
+
+	/// Runs the preload scripts in isolation from the scripts of the page itself, so that the page can't tamper with them.
+	/// This is meant for pages that aren't fully trusted, like remote content.
+	///
+	/// The preload scripts, including the bridges of this crate like the one of `api`, still share the DOM with the page.
+	/// But their variables are their own, and `invoke_extern` is only available to them.
+	/// Whatever they put on `window`, like the APIs, remains available to the page, but can't be replaced by the page anymore.
+	/// So the page can only reach the application through what the preload scripts expose.
+	/// The handler of `async_handler` only receives the calls of the preload scripts then.
+	///
+	/// *Note:* The preload scripts still run in the JavaScript world of the page, and the isolation is done by the scripts themselves.
+	/// So they share the built-in objects with the page, like `Array.prototype`.
+	/// Preload scripts should avoid handing anything secret to functions that the page could have replaced.
+	/// Only the main frame runs the preload scripts, and with CEF, the other frames don't get `invoke_extern` at all.
+	pub fn isolate_scripts( &mut self, enable: bool ) -> &mut Self {
+		self.isolate_scripts = enable;	self
+	}
+
+	/// Gives the window a native menu bar.
+	/// See the [`menu`](../menu/index.html) module for more information.
+	pub fn menu( &mut self, menu_bar: MenuBar ) -> &mut Self {
+		self.menu_bar = Some( menu_bar );	self
+	}
+
+	/// Configure a closure that is invoked whenever an item of the menu bar gets activated.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn on_menu_item<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, &MenuItemEvent) + 'static
+	{
+		self.menu_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever an item of the menu bar gets activated.
+	#[cfg(feature = "threadsafe")]
+	pub fn on_menu_item<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, &MenuItemEvent) + Send + 'static
+	{
+		self.menu_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever the page changes its route without loading another document.
+	/// This is how single-page applications navigate, with `history.pushState` and `history.replaceState`, and when the user goes back or forward between those routes.
+	/// It is given the new path, with the query and the fragment, like `/settings?tab=privacy`.
+	///
+	/// Navigations that load another document are not reported here, they are given as `BrowserEvent::Navigated` by `BrowserWindowHandle::events`.
+	/// This makes it possible to keep native menus and titles in line with the route, without the page having to report it.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn on_route_changed<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, &str) + 'static
+	{
+		self.route_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever the page changes its route without loading another document.
+	/// This is how single-page applications navigate, with `history.pushState` and `history.replaceState`, and when the user goes back or forward between those routes.
+	/// It is given the new path, with the query and the fragment, like `/settings?tab=privacy`.
+	///
+	/// Navigations that load another document are not reported here, they are given as `BrowserEvent::Navigated` by `BrowserWindowHandle::events`.
+	/// This makes it possible to keep native menus and titles in line with the route, without the page having to report it.
+	#[cfg(feature = "threadsafe")]
+	pub fn on_route_changed<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, &str) + Send + 'static
+	{
+		self.route_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever the page has got another favicon, once the browser engine has downloaded it.
+	/// This can be shown in the tabs or the address bar of a browser shell, see also `favicon_as_icon`.
+	///
+	/// *Note:* WebView2 only reports favicons from version 1.0.1722 of the runtime on, and gives them as PNG images.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn on_favicon_changed<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, &Favicon) + 'static
+	{
+		self.favicon_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever the page has got another favicon, once the browser engine has downloaded it.
+	/// This can be shown in the tabs or the address bar of a browser shell, see also `favicon_as_icon`.
+	///
+	/// *Note:* WebView2 only reports favicons from version 1.0.1722 of the runtime on, and gives them as PNG images.
+	#[cfg(feature = "threadsafe")]
+	pub fn on_favicon_changed<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, &Favicon) + Send + 'static
+	{
+		self.favicon_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever the page wants the user to choose files, like when an `<input type="file">` is clicked, instead of showing the dialog of the browser engine.
+	/// The application can show a dialog of its own, or choose the files itself, which is also how automated tests can upload files.
+	/// See `FileChooser` for how it is answered.
+	///
+	/// *Note:* WebView2 has no way to answer its file dialogs, so it always shows its own dialog and never invokes this.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn on_file_chooser<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, FileChooser) + 'static
+	{
+		self.file_chooser_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever the page wants the user to choose files, like when an `<input type="file">` is clicked, instead of showing the dialog of the browser engine.
+	/// The application can show a dialog of its own, or choose the files itself, which is also how automated tests can upload files.
+	/// See `FileChooser` for how it is answered.
+	///
+	/// *Note:* WebView2 has no way to answer its file dialogs, so it always shows its own dialog and never invokes this.
+	#[cfg(feature = "threadsafe")]
+	pub fn on_file_chooser<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, FileChooser) + Send + 'static
+	{
+		self.file_chooser_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever a key is pressed or released while the browser window has the focus, before the page gets it.
+	/// Returning `true` marks the key as handled, so that the page doesn't get it and the browser engine doesn't act on it, like reloading on F5 or printing on Ctrl+P.
+	/// This makes it possible to implement shortcuts that the page can't take away, or to block those of the browser engine.
+	/// Keys are handled before accelerators, so a handled key doesn't trigger an accelerator either.
+	///
+	/// The handler has to answer right away, so keys that arrive while another event of the window is being handled are given to the page, after which the handler is still invoked with them.
+	///
+	/// *Note:* WebView2 only gives the keys that don't produce characters, like F5 or the arrow keys, and the keys that are pressed together with Ctrl or Alt.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn on_key_event<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, &KeyEvent) -> bool + 'static
+	{
+		self.key_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever a key is pressed or released while the browser window has the focus, before the page gets it.
+	/// Returning `true` marks the key as handled, so that the page doesn't get it and the browser engine doesn't act on it, like reloading on F5 or printing on Ctrl+P.
+	/// This makes it possible to implement shortcuts that the page can't take away, or to block those of the browser engine.
+	/// Keys are handled before accelerators, so a handled key doesn't trigger an accelerator either.
+	///
+	/// The handler has to answer right away, so keys that arrive while another event of the window is being handled are given to the page, after which the handler is still invoked with them.
+	///
+	/// *Note:* WebView2 only gives the keys that don't produce characters, like F5 or the arrow keys, and the keys that are pressed together with Ctrl or Alt.
+	#[cfg(feature = "threadsafe")]
+	pub fn on_key_event<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, &KeyEvent) -> bool + Send + 'static
+	{
+		self.key_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever the progress of loading the page changes, to show a progress bar or a spinner during slow loads.
+	/// It is given how far the main frame has come, from 0.0 up to 1.0 once it has loaded, see also `BrowserWindowHandle::is_loading`.
+	///
+	/// *Note:* WebView2 doesn't report the actual progress, so with it the progress only moves through a few steps between starting and finishing a load.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn on_loading_progress<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, f64) + 'static
+	{
+		self.loading_progress_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever the progress of loading the page changes, to show a progress bar or a spinner during slow loads.
+	/// It is given how far the main frame has come, from 0.0 up to 1.0 once it has loaded, see also `BrowserWindowHandle::is_loading`.
+	///
+	/// *Note:* WebView2 doesn't report the actual progress, so with it the progress only moves through a few steps between starting and finishing a load.
+	#[cfg(feature = "threadsafe")]
+	pub fn on_loading_progress<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, f64) + Send + 'static
+	{
+		self.loading_progress_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever a mouse button is pressed or released, or the wheel is turned, within the page.
+	/// This makes it possible to let a window without borders be resized from its edges, or to zoom with Ctrl+wheel in a way of the application's own.
+	/// See `MouseEvent` for what it is given.
+	///
+	/// The events are only observed, so the page still gets them afterwards.
+	/// The one exception is that turning the wheel with Ctrl held down no longer zooms the page, so that the application can decide what it does.
+	/// Clicks on the scroll bars, and clicks while the page hasn't loaded yet, aren't reported.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn on_mouse_event<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, &MouseEvent) + 'static
+	{
+		self.mouse_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever a mouse button is pressed or released, or the wheel is turned, within the page.
+	/// This makes it possible to let a window without borders be resized from its edges, or to zoom with Ctrl+wheel in a way of the application's own.
+	/// See `MouseEvent` for what it is given.
+	///
+	/// The events are only observed, so the page still gets them afterwards.
+	/// The one exception is that turning the wheel with Ctrl held down no longer zooms the page, so that the application can decide what it does.
+	/// Clicks on the scroll bars, and clicks while the page hasn't loaded yet, aren't reported.
+	#[cfg(feature = "threadsafe")]
+	pub fn on_mouse_event<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, &MouseEvent) + Send + 'static
+	{
+		self.mouse_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever the render process of the page has terminated, like when it has crashed or has run out of memory.
+	/// The page stays blank after that, until it is reloaded with `BrowserWindowHandle::reload` or navigates elsewhere.
+	/// Long-running applications like kiosks can recover by reloading it from here, or let `reload_on_crash` do so.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn on_render_process_terminated<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, TerminationReason) + 'static
+	{
+		self.render_process_terminated_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever the render process of the page has terminated, like when it has crashed or has run out of memory.
+	/// The page stays blank after that, until it is reloaded with `BrowserWindowHandle::reload` or navigates elsewhere.
+	/// Long-running applications like kiosks can recover by reloading it from here, or let `reload_on_crash` do so.
+	#[cfg(feature = "threadsafe")]
+	pub fn on_render_process_terminated<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, TerminationReason) + Send + 'static
+	{
+		self.render_process_terminated_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that decides on the requests of the page of which the URL matches the given filter, before they are sent.
+	/// In the filter, `*` matches any text, like in `https://ads.example.com/*` or `*.png`.
+	/// It is given the request, of which it can change the headers, and returns whether the request is sent, blocked, redirected or answered by the application itself.
+	///
+	/// Requests that match the filters of multiple closures are given to the one that has been configured first.
+	/// Requests of custom schemes are left to `scheme_handler`.
+	/// The closure is invoked on the GUI thread, and the request waits for it, so it shouldn't take long.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn on_request<H>( &mut self, filter: &str, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, &mut InterceptedRequest) -> RequestAction + 'static
+	{
+		self.request_handlers.push( (filter.to_owned(), Box::new( handler )) );	self
+	}
+
+	/// Configure a closure that decides on the requests of the page of which the URL matches the given filter, before they are sent.
+	/// In the filter, `*` matches any text, like in `https://ads.example.com/*` or `*.png`.
+	/// It is given the request, of which it can change the headers, and returns whether the request is sent, blocked, redirected or answered by the application itself.
+	///
+	/// Requests that match the filters of multiple closures are given to the one that has been configured first.
+	/// Requests of custom schemes are left to `scheme_handler`.
+	/// The closure is invoked on the GUI thread, and the request waits for it, so it shouldn't take long.
+	#[cfg(feature = "threadsafe")]
+	pub fn on_request<H>( &mut self, filter: &str, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, &mut InterceptedRequest) -> RequestAction + Send + 'static
+	{
+		self.request_handlers.push( (filter.to_owned(), Box::new( handler )) );	self
+	}
+
+	/// Configure a closure that is invoked whenever a response to a request of the page has arrived, like to log the traffic of an API or to notice that a session has expired.
+	/// It is given the status code, headers and MIME type of the response, but can't change them.
+	///
+	/// It is invoked once the headers have arrived, which is before the body has been loaded.
+	/// Responses that are served from the cache are included as well.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn on_response<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, &Response) + 'static
+	{
+		self.response_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever a response to a request of the page has arrived, like to log the traffic of an API or to notice that a session has expired.
+	/// It is given the status code, headers and MIME type of the response, but can't change them.
+	///
+	/// It is invoked once the headers have arrived, which is before the body has been loaded.
+	/// Responses that are served from the cache are included as well.
+	#[cfg(feature = "threadsafe")]
+	pub fn on_response<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, &Response) + Send + 'static
+	{
+		self.response_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever the scale factor of the window's display changes, or the window moves to a display with another scale factor.
+	/// It is given the new scale factor, see also `WindowHandle::scale_factor`.
+	///
+	/// The window keeps the same size in logical pixels, and the browser scales its page along.
+	/// This is useful for content that is drawn at the resolution of the display, like canvases.
+	///
+	/// *Note:* This is not invoked when CEF provides the windows itself.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn on_scale_factor_changed<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, f32) + 'static
+	{
+		self.scale_factor_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever the scale factor of the window's display changes, or the window moves to a display with another scale factor.
+	/// It is given the new scale factor, see also `WindowHandle::scale_factor`.
+	///
+	/// The window keeps the same size in logical pixels, and the browser scales its page along.
+	/// This is useful for content that is drawn at the resolution of the display, like canvases.
+	///
+	/// *Note:* This is not invoked when CEF provides the windows itself.
+	#[cfg(feature = "threadsafe")]
+	pub fn on_scale_factor_changed<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, f32) + Send + 'static
+	{
+		self.scale_factor_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever the text that browsers show in their status bar changes.
+	/// This is mostly the address of the link under the mouse cursor, so that a custom UI can show where a link leads before it is clicked.
+	/// It is given an empty string once there is nothing to show anymore.
+	///
+	/// *Note:* WebView2 only reports the status text from version 1.0.1185 of the runtime on.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn on_status_text<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, &str) + 'static
+	{
+		self.status_text_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever the text that browsers show in their status bar changes.
+	/// This is mostly the address of the link under the mouse cursor, so that a custom UI can show where a link leads before it is clicked.
+	/// It is given an empty string once there is nothing to show anymore.
+	///
+	/// *Note:* WebView2 only reports the status text from version 1.0.1185 of the runtime on.
+	#[cfg(feature = "threadsafe")]
+	pub fn on_status_text<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, &str) + Send + 'static
+	{
+		self.status_text_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever the page stops responding to input, like when its JavaScript is stuck in a loop, with `true`.
+	/// It is invoked with `false` once the page responds again.
+	/// An application can decide to give up on the page with `BrowserWindowHandle::reload`.
+	///
+	/// *Note:* WebView2 doesn't report that the page responds again.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn on_unresponsive<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, bool) + 'static
+	{
+		self.unresponsive_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever the page stops responding to input, like when its JavaScript is stuck in a loop, with `true`.
+	/// It is invoked with `false` once the page responds again.
+	/// An application can decide to give up on the page with `BrowserWindowHandle::reload`.
+	///
+	/// *Note:* WebView2 doesn't report that the page responds again.
+	#[cfg(feature = "threadsafe")]
+	pub fn on_unresponsive<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, bool) + Send + 'static
+	{
+		self.unresponsive_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever an offscreen browser has painted a new frame into a texture on the GPU.
+	/// Unlike `on_paint`, the frame doesn't need to be copied to and from the CPU, which keeps embedding the browser into a 3D application fast.
+	///
+	/// Setting this handler makes the browser paint into shared textures, in which case it only invokes the `on_paint` handler when the GPU can't be used.
+	///
+	/// *Note:* Shared textures are only supported by CEF on Windows, where they are D3D11 textures.
+	/// On other platforms, the frames are always given to the `on_paint` handler.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn on_accelerated_paint<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, &AcceleratedPaint) + 'static
+	{
+		self.offscreen.on_accelerated_paint = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever an offscreen browser has painted a new frame into a texture on the GPU.
+	/// Unlike `on_paint`, the frame doesn't need to be copied to and from the CPU, which keeps embedding the browser into a 3D application fast.
+	///
+	/// Setting this handler makes the browser paint into shared textures, in which case it only invokes the `on_paint` handler when the GPU can't be used.
+	///
+	/// *Note:* Shared textures are only supported by CEF on Windows, where they are D3D11 textures.
+	/// On other platforms, the frames are always given to the `on_paint` handler.
+	#[cfg(feature = "threadsafe")]
+	pub fn on_accelerated_paint<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, &AcceleratedPaint) + Send + 'static
+	{
+		self.offscreen.on_accelerated_paint = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked with the bytes that the page sends with `bw_send_bytes(data)`.
+	/// `data` can be an `ArrayBuffer`, a typed array like `Uint8Array`, or a `DataView`.
+	/// `bw_send_bytes` gives a promise that is resolved once the closure has been invoked.
+	///
+	/// This avoids having to encode binary data, like images or audio, as JSON.
+	/// Bytes can be sent to the page with `BrowserWindowHandle::send_bytes`.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn on_bytes<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, &[u8]) + 'static
+	{
+		self.bytes_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked with the bytes that the page sends with `bw_send_bytes(data)`.
+	/// `data` can be an `ArrayBuffer`, a typed array like `Uint8Array`, or a `DataView`.
+	/// `bw_send_bytes` gives a promise that is resolved once the closure has been invoked.
+	///
+	/// This avoids having to encode binary data, like images or audio, as JSON.
+	/// Bytes can be sent to the page with `BrowserWindowHandle::send_bytes`.
+	#[cfg(feature = "threadsafe")]
+	pub fn on_bytes<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, &[u8]) + Send + 'static
+	{
+		self.bytes_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever an offscreen browser wants to display a different mouse cursor.
+	/// See `on_paint` for more information about offscreen browsers.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn on_cursor_change<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, Cursor) + 'static
+	{
+		self.offscreen.on_cursor_change = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever an offscreen browser wants to display a different mouse cursor.
+	/// See `on_paint` for more information about offscreen browsers.
+	#[cfg(feature = "threadsafe")]
+	pub fn on_cursor_change<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, Cursor) + Send + 'static
+	{
+		self.offscreen.on_cursor_change = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever an editable field of the page gains the focus, with its place on the screen.
+	/// It is invoked with `None` when the field has lost the focus, unless it has moved to another field.
+	/// While a field has the focus, the closure is invoked again whenever the field moves, like when the page scrolls.
+	///
+	/// This allows positioning an on-screen keyboard, see also `virtual_keyboard`.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn on_field_focus<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, Option<&EditableField>) + 'static
+	{
+		self.keyboard.on_field_focus = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever an editable field of the page gains the focus, with its place on the screen.
+	/// It is invoked with `None` when the field has lost the focus, unless it has moved to another field.
+	/// While a field has the focus, the closure is invoked again whenever the field moves, like when the page scrolls.
+	///
+	/// This allows positioning an on-screen keyboard, see also `virtual_keyboard`.
+	#[cfg(feature = "threadsafe")]
+	pub fn on_field_focus<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, Option<&EditableField>) + Send + 'static
+	{
+		self.keyboard.on_field_focus = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever an offscreen browser has painted a new frame.
+	/// This allows compositing the browser into a game engine or another renderer.
+	///
+	/// Browsers are rendered offscreen when the application is started with `ApplicationSettings::headless` set, which is only supported by CEF.
+	/// Their size is the content size of their (hidden) window, and they don't receive any input from it.
+	/// Input needs to be forwarded with methods like `BrowserWindowHandle::send_mouse_move` and `BrowserWindowHandle::send_key` instead.
+	/// Popup widgets, like the list of a `<select>` element, are painted separately, see also `on_popup`.
+	/// To receive the frames as textures on the GPU instead, see `on_accelerated_paint`.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn on_paint<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, &Paint) + 'static
+	{
+		self.offscreen.on_paint = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever an offscreen browser has painted a new frame.
+	/// This allows compositing the browser into a game engine or another renderer.
+	///
+	/// Browsers are rendered offscreen when the application is started with `ApplicationSettings::headless` set, which is only supported by CEF.
+	/// Their size is the content size of their (hidden) window, and they don't receive any input from it.
+	/// Input needs to be forwarded with methods like `BrowserWindowHandle::send_mouse_move` and `BrowserWindowHandle::send_key` instead.
+	/// Popup widgets, like the list of a `<select>` element, are painted separately, see also `on_popup`.
+	/// To receive the frames as textures on the GPU instead, see `on_accelerated_paint`.
+	#[cfg(feature = "threadsafe")]
+	pub fn on_paint<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, &Paint) + Send + 'static
+	{
+		self.offscreen.on_paint = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever an offscreen browser shows, moves or hides a popup widget.
+	/// It is given the area of the popup within the view, or `None` when the popup has been hidden.
+	/// The frames of the popup are given to the paint handler, and need to be drawn on top of the view within that area.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn on_popup<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, Option<Rect>) + 'static
+	{
+		self.offscreen.on_popup = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever an offscreen browser shows, moves or hides a popup widget.
+	/// It is given the area of the popup within the view, or `None` when the popup has been hidden.
+	/// The frames of the popup are given to the paint handler, and need to be drawn on top of the view within that area.
+	#[cfg(feature = "threadsafe")]
+	pub fn on_popup<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, Option<Rect>) + Send + 'static
+	{
+		self.offscreen.on_popup = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that answers the requests of the given custom scheme, like `app` for `app://localhost/index.html`.
+	/// It is given the request, and returns the response with its status code, headers and body.
+	/// See `SchemeResponse` for the kinds of bodies that can be given.
+	///
+	/// The scheme also needs to be registered with `ApplicationSettings::custom_scheme`, otherwise the browser engine doesn't pass its requests on.
+	/// Requests of custom schemes that have no handler are answered with status 404.
+	/// The closure is invoked on the GUI thread, so it shouldn't take long.
+	/// Anything that does, like reading a large file, belongs in the body.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn scheme_handler<H>( &mut self, scheme: &str, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, SchemeRequest) -> SchemeResponse + 'static
+	{
+		self.scheme_handlers.push( (scheme.to_lowercase(), Box::new( handler )) );	self
+	}
+
+	/// Configure a closure that answers the requests of the given custom scheme, like `app` for `app://localhost/index.html`.
+	/// It is given the request, and returns the response with its status code, headers and body.
+	/// See `SchemeResponse` for the kinds of bodies that can be given.
+	///
+	/// The scheme also needs to be registered with `ApplicationSettings::custom_scheme`, otherwise the browser engine doesn't pass its requests on.
+	/// Requests of custom schemes that have no handler are answered with status 404.
+	/// The closure is invoked on the GUI thread, so it shouldn't take long.
+	/// Anything that does, like reading a large file, belongs in the body.
+	#[cfg(feature = "threadsafe")]
+	pub fn scheme_handler<H>( &mut self, scheme: &str, handler: H ) -> &mut Self where
+		H: FnMut(BrowserWindowHandle, SchemeRequest) -> SchemeResponse + Send + 'static
+	{
+		self.scheme_handlers.push( (scheme.to_lowercase(), Box::new( handler )) );	self
+	}
+
+	/// Adds JavaScript code that will be executed every time a new document has been created in the browser.
+	/// It runs before any of the page's own scripts.
+	/// Multiple scripts are executed in the order in which they were added.
+	pub fn preload_js( &mut self, js: &str ) -> &mut Self {
+		self.preload_scripts.push( js.to_owned() );	self
+	}
+
+	/// Sets whether or not the page is reloaded right away once its render process has terminated, so that the window doesn't stay blank.
+	/// The handler of `on_render_process_terminated` is still invoked before that.
+	/// The default is `false`.
+	pub fn reload_on_crash( &mut self, enabled: bool ) -> &mut Self {
+		self.reload_on_crash = enabled;	self
+	}
+
+	/// Sets the on-screen keyboard that is shown whenever an editable field of the page gains the focus, for touch screens without a hardware keyboard.
+	/// See `VirtualKeyboard` for more information.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn virtual_keyboard<K>( &mut self, keyboard: K ) -> &mut Self where
+		K: VirtualKeyboard + 'static
+	{
+		self.keyboard.keyboard = Some( Box::new( keyboard ) );	self
+	}
+
+	/// Sets the on-screen keyboard that is shown whenever an editable field of the page gains the focus, for touch screens without a hardware keyboard.
+	/// See `VirtualKeyboard` for more information.
+	#[cfg(feature = "threadsafe")]
+	pub fn virtual_keyboard<K>( &mut self, keyboard: K ) -> &mut Self where
+		K: VirtualKeyboard + Send + 'static
+	{
+		self.keyboard.keyboard = Some( Box::new( keyboard ) );	self
+	}
+
 	/*pub fn handler<H>( &mut self, mut handler: H ) -> &Self where
 		H: FnMut(BrowserWindowHandle, String, Vec<String>) + Send + 'static
 	{
@@ -126,16 +920,48 @@ impl BrowserWindowBuilder {
 	}*/
 
 	/// Creates an instance of a browser window builder.
+	/// It starts out with the default settings of `Runtime::default_window_config`, if it has been set.
 	///
 	/// # Arguments
 	/// * `source` - The content that will be displayed in the browser window.
 	pub fn new( source: Source ) -> Self {
-		Self {
+		let mut builder = Self {
+			apis: Vec::new(),
+			bytes_handler: None,
+			default_encoding: None,
 			dev_tools: false,
+			favicon_as_icon: false,
+			favicon_handler: None,
+			file_chooser_handler: None,
 			source,
 			handler: None,
+			isolate_scripts: false,
+			key_handler: None,
+			keyboard: KeyboardState::default(),
+			label: None,
+			loading_progress_handler: None,
+			menu_bar: None,
+			menu_handler: None,
+			mouse_handler: None,
+			offscreen: OffscreenHandlers::default(),
+			permission_defaults: Vec::new(),
+			preload_scripts: Vec::new(),
+			reload_on_crash: false,
+			render_process_terminated_handler: None,
+			request_handlers: Vec::new(),
+			response_handler: None,
+			route_handler: None,
+			scale_factor_handler: None,
+			scheme_handlers: Vec::new(),
+			status_text_handler: None,
+			unresponsive_handler: None,
+			#[cfg(feature = "dev")]
+			watched: Vec::new(),
 			window: WindowBuilder::new()
-		}
+		};
+
+		application::apply_default_window_config( &mut builder );
+		builder
 	}
 
 	/// Creates the browser window.
@@ -184,17 +1010,60 @@ impl BrowserWindowBuilder {
 		Ok( BrowserWindowThreaded::new( rx.await.unwrap().i ) )
 	}
 
-	fn _build<H>( self, app: ApplicationHandle, on_created: H ) where
+	fn _build<H>( mut self, app: ApplicationHandle, on_created: H ) where
 		H: FnOnce( BrowserWindowHandle )
 	{
+		// Pages only report their fields when they are needed
+		if self.keyboard.is_enabled() {
+			self.preload_scripts.insert( 0, keyboard::KEYBOARD_JS.to_owned() );
+		}
+		if self.mouse_handler.is_some() {
+			self.preload_scripts.insert( 0, input::MOUSE_JS.to_owned() );
+		}
+
 		match self {
 			Self {
+				apis,
+				bytes_handler,
 				source,
 				handler,
+				default_encoding,
 				dev_tools,
+				favicon_as_icon,
+				favicon_handler,
+				file_chooser_handler,
+				isolate_scripts,
+				key_handler,
+				keyboard,
+				label,
+				loading_progress_handler,
+				menu_bar,
+				menu_handler,
+				mouse_handler,
+				offscreen,
+				permission_defaults,
+				preload_scripts,
+				reload_on_crash,
+				render_process_terminated_handler,
+				request_handlers,
+				response_handler,
+				route_handler,
+				scale_factor_handler,
+				scheme_handlers,
+				status_text_handler,
+				unresponsive_handler,
+				#[cfg(feature = "dev")]
+				watched,
 				window
 			} => {
 
+				// The watcher of `watch` gets going once the window is there
+				#[cfg(feature = "dev")]
+				let on_created = move |handle: BrowserWindowHandle| {
+					hot_reload::watch( handle, watched );
+					on_created( handle )
+				};
+
 				// Parent
 				let parent_handle = match window.parent {
 					None => WindowImpl::default(),
@@ -228,26 +1097,92 @@ impl BrowserWindowBuilder {
 					Some( t ) => t.as_str().into()
 				};
 
+				// Menu bar
+				let mut menu_item_ids = Vec::new();
+				let menu_impl = menu_bar.as_ref().map(|m| m.to_impl( &mut menu_item_ids ) );
+
+				// Offscreen browsers only paint into shared textures if they can be handled
+				let shared_textures = offscreen.on_accelerated_paint.is_some();
+
+				let isolation_token = if isolate_scripts { Some( isolation::new_token() ) } else { None };
+
 				// Handler callback data
 				let user_data = Box::into_raw( Box::new(
 					BrowserUserData {
+						accelerators: Vec::new(),
+						apis,
+						bytes_handler,
+						#[cfg(feature = "cdp")]
+						cdp_events: Vec::new(),
+						destroy_waiters: Vec::new(),
+						favicon_as_icon,
+						favicon_handler,
+						file_chooser_handler,
 						handler: match handler {
 							Some(f) => f,
 							None => Box::new(|_,_,_| Box::pin(async {}))
-						}
+						},
+						id: WindowId::new(),
+						isolated_world: None,
+						isolation_token: isolation_token.clone(),
+						key_handler,
+						keyboard,
+						label,
+						last_position: None,
+						listeners: Vec::new(),
+						loading: true,
+						loading_progress_handler,
+						menu_handler,
+						menu_item_ids,
+						mouse_handler,
+						offscreen,
+						recording: None,
+						reload_on_crash,
+						render_process_terminated_handler,
+						request_handlers,
+						response_handler,
+						route_handler,
+						scale_factor_handler,
+						scheme_handlers,
+						status_text_handler,
+						subscriptions: Vec::new(),
+						task_scopes: Vec::new(),
+						unresponsive_handler,
+						watchers: BrowserPropertyWatchers::default()
 					}
 				) );
 				let callback_data: *mut Box<dyn FnOnce( BrowserWindowHandle )> = Box::into_raw( Box::new( Box::new(on_created ) ) );
 
 				// Convert options to FFI structs
 				let window_options = WindowOptions {
+					backdrop: Effect::to_c( window.backdrop ),
 					borders: window.borders,
+					corner_radius: window.corner_radius.map(|r| r.min( i16::MAX as u16 ) as i16 ).unwrap_or( -1 ),
 					minimizable: window.minimizable,
-					resizable: window.resizable
+					resizable: window.resizable,
+					resize_strategy: window.resize_strategy.to_c(),
+					shadow: window.shadow.map(|s| if s {1} else {2} ).unwrap_or( 0 ),
+					physical_size: window.size_unit == SizeUnit::Physical,
+					tool_window: window.tool_window,
+					menu_bar: menu_impl.as_ref().map(|m| m.as_ptr() ).unwrap_or( ptr::null() ),
+					owner: window.owner.as_ref().map(|o| o.i.inner.as_ptr() ).unwrap_or( ptr::null() ),
+					modal: window.modal,
+					native_parent: window.native_parent.unwrap_or( 0 ) as _,
+					native_parent_top: window.native_parent_top
 				};
+				// The route of the page is always followed, as it is also given to the event streams, and any page can receive messages and bytes from other windows
+				let mut preload_script = format!( "{};\n{};\n{};\n{}", route::ROUTE_JS, message::MESSAGE_JS, bytes::BYTES_JS, preload_scripts.join(";\n") );
+				if let Some( token ) = isolation_token.as_ref() {
+					preload_script = isolation::isolate( &preload_script, token );
+				}
 				let other_options = BrowserWindowOptions {
+					default_encoding: default_encoding.as_ref().map(|e| e.as_str() ).unwrap_or("").into(),
 					dev_tools: if dev_tools {1} else {0},
-					resource_path: "".into()
+					resource_path: "".into(),
+					preload_script: preload_script.as_str().into(),
+					shared_textures: if shared_textures {1} else {0},
+					isolate_scripts: if isolate_scripts {1} else {0},
+					permission_defaults: permission::defaults_to_c( &permission_defaults )
 				};
 
 				BrowserWindowImpl::new(
@@ -264,6 +1199,11 @@ impl BrowserWindowBuilder {
 					browser_window_created_callback,
 					callback_data as _
 				);
+
+				// The menu bar is only needed during the creation of the window
+				if let Some( menu ) = menu_impl {
+					menu.free();
+				}
 			}
 		}
 	}
@@ -322,18 +1262,86 @@ impl DerefMut for BrowserWindowBuilder {
 }*/
 
 unsafe fn browser_window_invoke_handler( inner_handle: BrowserWindowImpl, cmd: &str, args: Vec<String> ) {
-	
-	let data_ptr: *mut BrowserUserData = inner_handle.user_data() as _;
-	let data = &mut *data_ptr;
+	let cmd = cmd.to_owned();
 
-	match data {
-		BrowserUserData{ handler } => {
-			let outer_handle = BrowserWindowHandle::new( inner_handle );
+	emit_event( inner_handle, move |outer_handle, data| {
 
-			let future = handler( outer_handle, cmd.into(), args );
-			outer_handle.app().spawn( future );
+		// With isolated preload scripts, the page itself is not allowed to make calls
+		let (cmd, args) = match data.isolation_token.as_ref() {
+			None => (cmd, args),
+			Some( token ) => match isolation::verify( token, cmd, args ) {
+				// Calls from the page itself are dropped
+				None => return,
+				Some( call ) => call
+			}
+		};
+
+		// Calls from the JavaScript bridge are meant for the registered APIs
+		if cmd == ipc::CALL_COMMAND {
+			ipc::handle_call( &mut data.apis, outer_handle, args );
+			return;
 		}
-	}
+
+		// The route is reported by the script that follows the history of the page
+		if cmd == route::ROUTE_COMMAND {
+			let path = args.into_iter().next().unwrap_or_default();
+
+			if let Some( handler ) = data.route_handler.as_mut() {
+				handler( outer_handle, &path );
+			}
+			data.watchers.events.notify( BrowserEvent::RouteChanged( path ) );
+			return;
+		}
+
+		// Bytes from the page, sent with `bw_send_bytes`
+		if cmd == bytes::BYTES_COMMAND {
+			bytes::handle( data.bytes_handler.as_mut(), outer_handle, args );
+			return;
+		}
+
+		// The editable field that has gained or lost the focus
+		if cmd == keyboard::KEYBOARD_COMMAND {
+			keyboard::handle( outer_handle, args );
+			return;
+		}
+
+		// The clicks and the turns of the wheel, for the mouse handler
+		if cmd == input::MOUSE_COMMAND {
+			// The events come from the page, which may send anything, so the ones that make no sense are dropped
+			if let (Some( event ), Some( handler )) = (MouseEvent::from_js( &args ), data.mouse_handler.as_mut()) {
+				handler( outer_handle, &event );
+			}
+			return;
+		}
+
+		// The input events of the user, while `record_interactions` is recording them
+		if cmd == recorder::RECORD_COMMAND {
+			if let Some( recording ) = data.recording.as_mut() {
+				recording.record( &args );
+			}
+			return;
+		}
+
+		// Events from the page to its own window
+		if cmd == message::EVENT_COMMAND {
+			message::handle_event( outer_handle, args );
+			return;
+		}
+
+		// Messages from the page to other windows
+		if cmd == message::MESSAGE_COMMAND {
+			let mut args = args.into_iter();
+			let label = args.next().unwrap_or_default();
+			let event = args.next().unwrap_or_default();
+			let payload = args.next().unwrap_or_default();
+
+			outer_handle.emit_to( &label, &event, &payload );
+			return;
+		}
+
+		let future = (data.handler)( outer_handle, cmd, args );
+		outer_handle.app().spawn( future );
+	});
 }
 
 // This external C function will be given as the callback to the bw_BrowserWindow_new function, to be invoked when the browser window has been created
@@ -352,7 +1360,390 @@ unsafe fn browser_window_created_callback( inner_handle: BrowserWindowImpl, data
 	let data_ptr = data as *mut Box<dyn FnOnce( BrowserWindowHandle )>;
 	let func = Box::from_raw( data_ptr );
 
+	inner_handle.set_accelerated_paint_handler( browser_window_accelerated_paint_handler );
+	inner_handle.set_accelerator_handler( browser_window_accelerator_handler );
+	inner_handle.set_destroy_handler( browser_window_destroy_handler );
+	// Responses are observed through the DevTools protocol
+	let observe_responses = (*(inner_handle.user_data() as *mut BrowserUserData)).response_handler.is_some();
+	if cfg!(feature = "cdp") || observe_responses {
+		inner_handle.set_devtools_event_handler( browser_window_devtools_event_handler );
+	}
+	inner_handle.set_favicon_handler( browser_window_favicon_handler );
+	// The dialog of the browser engine is only replaced when the application has a file chooser handler
+	if (*(inner_handle.user_data() as *mut BrowserUserData)).file_chooser_handler.is_some() {
+		inner_handle.set_file_chooser_handler( browser_window_file_chooser_handler );
+	}
+	inner_handle.set_event_handlers( browser_window_move_handler, browser_window_focus_handler, browser_window_navigation_handler, browser_window_title_handler );
+	// Keys only wait for an answer when the application has a key handler
+	if (*(inner_handle.user_data() as *mut BrowserUserData)).key_handler.is_some() {
+		inner_handle.set_key_handler( browser_window_key_handler );
+	}
+	inner_handle.set_load_handler( browser_window_load_handler );
+	inner_handle.set_loading_progress_handler( browser_window_loading_progress_handler );
+	inner_handle.set_menu_handler( browser_window_menu_handler );
+	inner_handle.set_offscreen_handlers( browser_window_paint_handler, browser_window_popup_handler, browser_window_cursor_handler );
+	inner_handle.set_permission_handler( browser_window_permission_handler );
+	inner_handle.set_render_process_handlers( browser_window_render_process_terminated_handler, browser_window_unresponsive_handler );
+	// Requests are only held back when there are filters for them
+	let request_handlers = &(*(inner_handle.user_data() as *mut BrowserUserData)).request_handlers;
+	if request_handlers.len() > 0 {
+		let filters: Vec<&str> = request_handlers.iter().map(|(f, _)| f.as_str() ).collect();
+		inner_handle.set_request_handler( browser_window_request_handler, &filters.join( "\n" ) );
+	}
+	inner_handle.set_resize_handler( browser_window_resize_handler );
+	inner_handle.set_scale_factor_handler( browser_window_scale_factor_handler );
+	inner_handle.set_scheme_handler( browser_window_scheme_handler );
+	inner_handle.set_status_text_handler( browser_window_status_text_handler );
+
 	let outer_handle = BrowserWindowHandle::new( inner_handle );
+	registry::register( outer_handle );
+
+	// The DevTools method is called right away, before the page gets to make any requests
+	if observe_responses {
+		outer_handle.app().spawn( async move {
+			// If the browser engine can't give the responses, the handler of `on_response` is just never invoked
+			let _ = response::observe( outer_handle ).await;
+		});
+	}
 
 	func( outer_handle )
-}
\ No newline at end of file
+}
+
+/// Handles an event of the browser window with its user data, after the events that have occurred before it.
+/// See the ordering guarantees in the documentation of module `browser`.
+unsafe fn emit_event<E>( inner_handle: BrowserWindowImpl, event: E ) where
+	E: FnOnce( BrowserWindowHandle, &mut BrowserUserData ) + 'static
+{
+	// Both are obtained right away, as the event might be handled after the native handle has gone.
+	let handle = BrowserWindowHandle::new( inner_handle );
+	let data_ptr: *mut BrowserUserData = inner_handle.user_data() as _;
+	application::maintenance::note_activity();
+
+	emit::emit_named( handle.id(), std::any::type_name::<E>(), move || event( handle, &mut *data_ptr ) );
+}
+
+unsafe fn browser_window_accelerated_paint_handler( inner_handle: BrowserWindowImpl, is_popup: bool, dirty_rects: &[browser_window::Rect], shared_handle: *mut () ) {
+	let paint = AcceleratedPaint {
+		is_popup,
+		dirty_rects: dirty_rects.iter().map(|r| Rect::from( *r ) ).collect(),
+		shared_handle: shared_handle as _
+	};
+
+	emit_event( inner_handle, move |handle, data| {
+		if let Some( handler ) = data.offscreen.on_accelerated_paint.as_mut() {
+			handler( handle, &paint );
+		}
+	});
+}
+
+unsafe fn browser_window_accelerator_handler( inner_handle: BrowserWindowImpl, accelerator_id: u32 ) {
+
+	emit_event( inner_handle, move |handle, data| {
+		let index = (accelerator_id as usize).wrapping_sub( 1 );
+		let handler = data.accelerators.get_mut( index ).and_then(|(_, h)| h.take() );
+
+		if let Some( mut handler ) = handler {
+			handler( handle );
+
+			// Unless the handler has registered another one for the same accelerator
+			if let Some( (_, h) ) = data.accelerators.get_mut( index ) {
+				h.get_or_insert( handler );
+			}
+		}
+	});
+}
+
+unsafe fn browser_window_devtools_event_handler( inner_handle: BrowserWindowImpl, method: &str, params: &str ) {
+	let (method, params) = (method.to_owned(), params.to_owned());
+
+	emit_event( inner_handle, move |handle, data| {
+		if method == response::RESPONSE_EVENT {
+			if let Some( handler ) = data.response_handler.as_mut() {
+				if let Some( response ) = Response::from_cdp( &params ) {
+					handler( handle, &response );
+				}
+			}
+		}
+
+		#[cfg(feature = "cdp")]
+		cdp::notify( data, &method, &params );
+	});
+}
+
+unsafe fn browser_window_focus_handler( inner_handle: BrowserWindowImpl, focused: bool ) {
+
+	emit_event( inner_handle, move |_, data| {
+		data.watchers.events.notify( BrowserEvent::Focused( focused ) );
+	});
+}
+
+unsafe fn browser_window_menu_handler( inner_handle: BrowserWindowImpl, item_id: u32, checked: bool ) {
+
+	emit_event( inner_handle, move |handle, data| {
+		let id = match data.menu_item_ids.get( (item_id as usize).wrapping_sub( 1 ) ) {
+			None => return,
+			Some( id ) => id.clone()
+		};
+
+		if let Some( handler ) = data.menu_handler.as_mut() {
+			handler( handle, &MenuItemEvent { id, checked } );
+		}
+	});
+}
+
+unsafe fn browser_window_cursor_handler( inner_handle: BrowserWindowImpl, cursor: u8 ) {
+
+	emit_event( inner_handle, move |handle, data| {
+		if let Some( handler ) = data.offscreen.on_cursor_change.as_mut() {
+			handler( handle, Cursor::from_c( cursor ) );
+		}
+	});
+}
+
+unsafe fn browser_window_destroy_handler( inner_handle: BrowserWindowImpl ) {
+
+	emit_event( inner_handle, |handle, data| {
+		let data = Box::from_raw( data as *mut BrowserUserData );
+
+		// The events that would come after this one can't get to the user data anymore
+		emit::discard_pending( handle.id() );
+		registry::unregister( handle.id() );
+
+		if let Some( recording ) = data.recording {
+			recording.finish();
+		}
+		task_scope::cancel_all( data.task_scopes );
+
+		// The event streams end when the watchers are dropped along with the user data
+		let mut watchers = data.watchers;
+		watchers.events.notify( BrowserEvent::Closed );
+
+		for waiter in data.destroy_waiters {
+			// The receiver may have been dropped already, which is fine.
+			let _ = waiter.send(());
+		}
+	});
+}
+
+unsafe fn browser_window_move_handler( inner_handle: BrowserWindowImpl, x: i32, y: i32 ) {
+	let position = Pos2D::new( x.max( 0 ).min( u16::MAX as i32 ) as _, y.max( 0 ).min( u16::MAX as i32 ) as _ );
+
+	emit_event( inner_handle, move |_, data| {
+		if let Some( last ) = data.last_position {
+			if last.x() == position.x() && last.y() == position.y() {
+				return;
+			}
+		}
+		data.last_position = Some( position );
+
+		data.watchers.position.notify( position );
+		data.watchers.events.notify( BrowserEvent::Moved( position ) );
+	});
+}
+
+unsafe fn browser_window_favicon_handler( inner_handle: BrowserWindowImpl, data: &[u8], width: u32, height: u32 ) {
+	let favicon = Favicon::from_raw( data, width, height );
+
+	emit_event( inner_handle, move |handle, data| {
+		if data.favicon_as_icon {
+			let (icon, width, height) = favicon.to_raw();
+			handle.inner.window().set_icon( icon, width, height );
+		}
+
+		if let Some( handler ) = data.favicon_handler.as_mut() {
+			handler( handle, &favicon );
+		}
+	});
+}
+
+unsafe fn browser_window_file_chooser_handler( inner_handle: BrowserWindowImpl, chooser: FileChooserImpl, mode: u8, title: &str, default_path: &str, accept: &str ) {
+	let chooser = FileChooser::new( chooser, mode, title, default_path, accept );
+
+	emit_event( inner_handle, move |handle, data| {
+		if let Some( handler ) = data.file_chooser_handler.as_mut() {
+			handler( handle, chooser );
+		}
+	});
+}
+
+unsafe fn browser_window_key_handler( inner_handle: BrowserWindowImpl, kind: u8, key_code: u16, modifiers: u32, is_repeat: bool ) -> bool {
+	let event = KeyEvent::from_c( kind, key_code, modifiers, is_repeat );
+	let handle_key = move |handle: BrowserWindowHandle, data: &mut BrowserUserData| {
+		match data.key_handler.as_mut() {
+			None => false,
+			Some( handler ) => handler( handle, &event )
+		}
+	};
+
+	let handle = BrowserWindowHandle::new( inner_handle );
+	match emit::Emission::begin( handle.id() ) {
+		Some( emission ) => {
+			let handled = handle_key( handle, &mut *(inner_handle.user_data() as *mut BrowserUserData) );
+			emission.finish();
+			handled
+		},
+		// The key can't wait for the other event, so the page gets it anyway
+		None => {
+			emit_event( inner_handle, move |handle, data| { handle_key( handle, data ); } );
+			false
+		}
+	}
+}
+
+unsafe fn browser_window_load_handler( inner_handle: BrowserWindowImpl, error: Option<(u32, String)> ) {
+	emit_event( inner_handle, move |_, data| {
+		data.loading = false;
+		data.watchers.events.notify( match error {
+			None => BrowserEvent::Loaded,
+			Some( (code, message) ) => BrowserEvent::LoadFailed( BrowserError::LoadFailed { code, message } )
+		} );
+	});
+}
+
+unsafe fn browser_window_loading_progress_handler( inner_handle: BrowserWindowImpl, progress: f64 ) {
+	emit_event( inner_handle, move |handle, data| {
+		data.loading = progress < 1.0;
+
+		if let Some( handler ) = data.loading_progress_handler.as_mut() {
+			handler( handle, progress );
+		}
+	});
+}
+
+unsafe fn browser_window_navigation_handler( inner_handle: BrowserWindowImpl, url: &str ) {
+	let url = url.to_owned();
+
+	emit_event( inner_handle, move |_, data| {
+		data.isolated_world = None;
+		data.watchers.events.notify( BrowserEvent::Navigated( url ) );
+	});
+}
+
+unsafe fn browser_window_paint_handler( inner_handle: BrowserWindowImpl, is_popup: bool, dirty_rects: &[browser_window::Rect], pixels: &[u8], width: u32, height: u32 ) {
+	let dirty_rects: Vec<Rect> = dirty_rects.iter().map(|r| Rect::from( *r ) ).collect();
+	let paint = move |handle: BrowserWindowHandle, data: &mut BrowserUserData, pixels: &[u8]| {
+		if let Some( handler ) = data.offscreen.on_paint.as_mut() {
+			handler( handle, &Paint { is_popup, width, height, dirty_rects, pixels } );
+		}
+	};
+
+	let handle = BrowserWindowHandle::new( inner_handle );
+	match emit::Emission::begin( handle.id() ) {
+		Some( emission ) => {
+			paint( handle, &mut *(inner_handle.user_data() as *mut BrowserUserData), pixels );
+			emission.finish();
+		},
+		// The pixels are only valid during this call, so they are only copied when the frame has to wait for another event
+		None => {
+			let pixels = pixels.to_vec();
+			emit_event( inner_handle, move |handle, data| paint( handle, data, &pixels ) );
+		}
+	}
+}
+
+// This may be invoked from another thread, which is why it only looks at the permission policy and not at the user data.
+// The defaults of the window are applied by the browser window itself, to what the policy leaves open.
+unsafe fn browser_window_permission_handler( _inner_handle: BrowserWindowImpl, permission: u8, url: &str ) -> Option<bool> {
+	permission::decide( Permission::from_c( permission )?, url )
+}
+
+unsafe fn browser_window_popup_handler( inner_handle: BrowserWindowImpl, rect: Option<browser_window::Rect> ) {
+
+	emit_event( inner_handle, move |handle, data| {
+		if let Some( handler ) = data.offscreen.on_popup.as_mut() {
+			handler( handle, rect.map( Rect::from ) );
+		}
+	});
+}
+
+unsafe fn browser_window_render_process_terminated_handler( inner_handle: BrowserWindowImpl, reason: u8 ) {
+	let reason = TerminationReason::from_c( reason );
+
+	emit_event( inner_handle, move |handle, data| {
+		if let Some( handler ) = data.render_process_terminated_handler.as_mut() {
+			handler( handle, reason );
+		}
+
+		if data.reload_on_crash {
+			handle.reload();
+		}
+	});
+}
+
+unsafe fn browser_window_request_handler( inner_handle: BrowserWindowImpl, request: InterceptedRequestImpl, method: &str, url: &str, headers: &str ) {
+	let decider = Decider::new( request, headers );
+	let mut intercepted = InterceptedRequest::new( method, url, headers );
+
+	emit_event( inner_handle, move |handle, data| {
+		let action = match data.request_handlers.iter_mut().find(|(f, _)| request::url_matches( f, intercepted.url() ) ) {
+			None => RequestAction::Continue,
+			Some( (_, handler) ) => handler( handle, &mut intercepted )
+		};
+
+		decider.decide( &intercepted, action );
+	});
+}
+
+unsafe fn browser_window_resize_handler( inner_handle: BrowserWindowImpl, width: u32, height: u32 ) {
+
+	emit_event( inner_handle, move |handle, data| {
+		// Only the size of the content is given, so the size of the whole window is read from the window itself
+		let window_dimensions = handle.window().window_dimensions().get();
+
+		let content_dimensions = Dims2D::new( width.min( u16::MAX as u32 ) as _, height.min( u16::MAX as u32 ) as _ );
+
+		data.watchers.content_dimensions.notify( content_dimensions );
+		data.watchers.window_dimensions.notify( window_dimensions );
+		data.watchers.events.notify( BrowserEvent::Resized( content_dimensions ) );
+	});
+}
+
+unsafe fn browser_window_scale_factor_handler( inner_handle: BrowserWindowImpl, scale_factor: f32 ) {
+
+	emit_event( inner_handle, move |handle, data| {
+		if let Some( handler ) = data.scale_factor_handler.as_mut() {
+			handler( handle, scale_factor );
+		}
+	});
+}
+
+unsafe fn browser_window_status_text_handler( inner_handle: BrowserWindowImpl, text: &str ) {
+	let text = text.to_owned();
+
+	emit_event( inner_handle, move |handle, data| {
+		if let Some( handler ) = data.status_text_handler.as_mut() {
+			handler( handle, &text );
+		}
+	});
+}
+
+unsafe fn browser_window_scheme_handler( inner_handle: BrowserWindowImpl, request: SchemeRequestImpl, method: &str, url: &str, headers: &str, body: &[u8] ) {
+	let responder = Responder::new( request );
+	let request = SchemeRequest::new( method, url, headers, body );
+
+	emit_event( inner_handle, move |handle, data| {
+		let scheme = request.scheme();
+		let response = match data.scheme_handlers.iter_mut().find(|(s, _)| *s == scheme ) {
+			None => SchemeResponse::new( 404 ),
+			Some( (_, handler) ) => handler( handle, request )
+		};
+
+		responder.respond( response );
+	});
+}
+
+unsafe fn browser_window_title_handler( inner_handle: BrowserWindowImpl, title: &str ) {
+	let title = title.to_owned();
+
+	emit_event( inner_handle, move |_, data| {
+		data.watchers.events.notify( BrowserEvent::TitleChanged( title ) );
+	});
+}
+
+unsafe fn browser_window_unresponsive_handler( inner_handle: BrowserWindowImpl, unresponsive: bool ) {
+	emit_event( inner_handle, move |handle, data| {
+		if let Some( handler ) = data.unresponsive_handler.as_mut() {
+			handler( handle, unresponsive );
+		}
+	});
+}
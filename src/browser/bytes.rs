@@ -0,0 +1,178 @@
+use super::BrowserWindowHandle;
+#[cfg(feature = "threadsafe")]
+use super::BrowserWindowThreaded;
+use crate::ipc::js_string_literal;
+
+
+
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserBytesHandler = Box<dyn FnMut(BrowserWindowHandle, &[u8])>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserBytesHandler = Box<dyn FnMut(BrowserWindowHandle, &[u8]) + Send>;
+
+
+
+// The command that the page uses to send bytes with.
+pub(in super) const BYTES_COMMAND: &'static str = "__bw_bytes";
+
+/// The JavaScript code that lets pages send and receive bytes.
+///
+/// In JavaScript, `bw_send_bytes(data)` sends an `ArrayBuffer`, typed array or `DataView` to the handler of `BrowserWindowBuilder::on_bytes`.
+/// It gives a promise that is resolved once the handler has received them, or rejected if they didn't come through.
+/// The bytes that Rust sends with `BrowserWindowHandle::send_bytes` are dispatched on `window` as a `MessageEvent` of type `bw-bytes`, with an `ArrayBuffer` as its data.
+///
+/// The native bridge only carries strings, so the bytes are base64 encoded in between.
+/// This is done in chunks, so that large buffers don't exceed the maximum number of arguments of a function call.
+pub(in super) const BYTES_JS: &'static str = r#"(function() {
+	if (window.bw_send_bytes) return;
+	var CHUNK = 0x8000;
+	var pending = {};
+	var next_id = 0;
+	window.bw_send_bytes = function(data) {
+		var bytes = data instanceof ArrayBuffer ? new Uint8Array(data) : new Uint8Array(data.buffer, data.byteOffset, data.byteLength);
+		var binary = "";
+		for (var i = 0; i < bytes.length; i += CHUNK) {
+			binary += String.fromCharCode.apply(null, bytes.subarray(i, i + CHUNK));
+		}
+		var id = next_id++;
+		return new Promise(function(resolve, reject) {
+			pending[id] = { resolve: resolve, reject: reject };
+			invoke_extern("__bw_bytes", String(id), btoa(binary));
+		});
+	};
+	window.__bw_bytes_sent = function(id, error) {
+		var p = pending[id];
+		delete pending[id];
+		if (!p) return;
+		if (error === undefined) p.resolve(); else p.reject(new Error(error));
+	};
+	window.__bw_receive_bytes = function(encoded) {
+		var binary = atob(encoded);
+		var bytes = new Uint8Array(binary.length);
+		for (var i = 0; i < binary.length; i++) {
+			bytes[i] = binary.charCodeAt(i);
+		}
+		window.dispatchEvent(new MessageEvent("bw-bytes", { data: bytes.buffer }));
+	};
+})();
+"#;
+
+const BASE64_ALPHABET: &'static [u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+
+
+impl BrowserWindowHandle {
+
+	/// Sends the given bytes to the page, where they arrive as an `ArrayBuffer`.
+	/// They are given to the listeners of the `bw-bytes` event of `window`, in the `data` field of the event.
+	///
+	/// This avoids having to encode binary data, like images or audio, as JSON.
+	/// Pages can send bytes back with `bw_send_bytes(data)`, see `BrowserWindowBuilder::on_bytes`.
+	///
+	/// # Example
+	/// ```ignore
+	/// bw.send_bytes( &std::fs::read( "photo.png" )? );
+	/// ```
+	/// And in the page:
+	/// ```js
+	/// window.addEventListener("bw-bytes", function(e) {
+	/// 	img.src = URL.createObjectURL(new Blob([e.data], { type: "image/png" }));
+	/// });
+	/// ```
+	pub fn send_bytes( &self, bytes: &[u8] ) {
+		self.exec_js( &format!( "window.__bw_receive_bytes && window.__bw_receive_bytes(\"{}\")", base64_encode( bytes ) ) );
+	}
+}
+
+#[cfg(feature = "threadsafe")]
+impl BrowserWindowThreaded {
+
+	/// Same as `BrowserWindowHandle::send_bytes`, but sends the bytes from the GUI thread.
+	/// Returns false if the GUI thread can't be reached anymore.
+	pub fn send_bytes( &self, bytes: &[u8] ) -> bool {
+		let bytes = bytes.to_vec();
+
+		self.dispatch(move |bw| {
+			bw.send_bytes( &bytes );
+		})
+	}
+}
+
+
+
+/// Handles the bytes that the page has sent with `bw_send_bytes`, and settles the promise that it has given for them.
+pub(in super) fn handle( handler: Option<&mut BrowserBytesHandler>, bw: BrowserWindowHandle, args: Vec<String> ) {
+	let mut args = args.into_iter();
+	// Without an ID there is no promise to reject, so the error is thrown in the page instead
+	let id: u64 = match args.next().and_then(|id| id.parse().ok() ) {
+		None => {
+			bw.exec_js( &format!( "throw new Error({})", js_string_literal( "invalid bytes: no valid ID" ) ) );
+			return;
+		},
+		Some( id ) => id
+	};
+	let encoded = args.next().unwrap_or_default();
+
+	match base64_decode( &encoded ) {
+		None => bw.exec_js( &format!( "window.__bw_bytes_sent({}, {})", id, js_string_literal( "invalid bytes: not base64" ) ) ),
+		Some( bytes ) => {
+			if let Some( handler ) = handler {
+				handler( bw, &bytes );
+			}
+			bw.exec_js( &format!( "window.__bw_bytes_sent({})", id ) );
+		}
+	}
+}
+
+/// Encodes the given bytes with the standard base64 alphabet, with padding.
+pub(in crate) fn base64_encode( bytes: &[u8] ) -> String {
+	let mut encoded = String::with_capacity( (bytes.len() + 2) / 3 * 4 );
+
+	for chunk in bytes.chunks( 3 ) {
+		let b = [chunk[0], *chunk.get(1).unwrap_or( &0 ), *chunk.get(2).unwrap_or( &0 )];
+		let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+
+		for i in 0..4 {
+			if i <= chunk.len() {
+				encoded.push( BASE64_ALPHABET[(n >> (18 - 6 * i) & 0x3F) as usize] as char );
+			}
+			else {
+				encoded.push('=');
+			}
+		}
+	}
+
+	encoded
+}
+
+/// Decodes base64 with the standard alphabet, with or without padding.
+/// Returns `None` if it contains anything else.
+pub(in crate) fn base64_decode( encoded: &str ) -> Option<Vec<u8>> {
+	let encoded = encoded.trim_end_matches('=').as_bytes();
+	if encoded.len() % 4 == 1 {
+		return None;
+	}
+	let mut bytes = Vec::with_capacity( encoded.len() * 3 / 4 );
+
+	for chunk in encoded.chunks( 4 ) {
+		let mut n = 0u32;
+		for (i, c) in chunk.iter().enumerate() {
+			n |= base64_value( *c )? << (18 - 6 * i);
+		}
+
+		bytes.extend_from_slice( &n.to_be_bytes()[1..chunk.len()] );
+	}
+
+	Some( bytes )
+}
+
+fn base64_value( c: u8 ) -> Option<u32> {
+	match c {
+		b'A' ..= b'Z' => Some( (c - b'A') as u32 ),
+		b'a' ..= b'z' => Some( (c - b'a' + 26) as u32 ),
+		b'0' ..= b'9' => Some( (c - b'0' + 52) as u32 ),
+		b'+' => Some( 62 ),
+		b'/' => Some( 63 ),
+		_ => None
+	}
+}
@@ -0,0 +1,161 @@
+use browser_window_core::browser_window::{BrowserWindowExt, BrowserWindowImpl};
+use futures_channel::oneshot;
+
+use super::{BrowserUserData, BrowserWindowHandle, JsValue, WindowCallbackData};
+use super::emit;
+use crate::prop::{PropertyChanges, PropertyWatchers};
+
+use std::{error::Error, fmt};
+
+
+
+/// A client of the Chrome DevTools Protocol, for the page of a browser window.
+/// It is given by `BrowserWindowHandle::cdp`.
+///
+/// The protocol gives access to everything that the developer tools can do, like tracing, emulating devices, measuring code coverage and intercepting requests.
+/// Methods are called with `send`, and events are received with `events`.
+/// Both use the JSON of the protocol as it is, see the [documentation of the protocol](https://chromedevtools.github.io/devtools-protocol/) for the methods and events that there are.
+///
+/// **Note:** Only available with feature `cdp` enabled.
+///
+/// # Example
+/// ```ignore
+/// let cdp = bw.cdp();
+/// let mut requests = cdp.events( "Network.requestWillBeSent" );
+/// cdp.send( "Network.enable", "{}" ).await?;
+///
+/// while let Some( params ) = requests.next().await {
+/// 	println!( "Request: {}", params );
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct Cdp {
+	bw: BrowserWindowHandle
+}
+
+/// An error that a method of the Chrome DevTools Protocol has given back.
+#[derive(Clone, Debug)]
+pub struct CdpError {
+	json: String
+}
+
+/// A stream of the parameters of an event of the Chrome DevTools Protocol, as JSON objects.
+/// It ends when the window has been destroyed.
+pub type CdpEvents = PropertyChanges<String>;
+
+
+
+impl BrowserWindowHandle {
+
+	/// Gives a client of the Chrome DevTools Protocol for the page of this window.
+	/// See `Cdp` for more information.
+	///
+	/// **Note:** Only available with feature `cdp` enabled.
+	pub fn cdp( &self ) -> Cdp {
+		Cdp { bw: *self }
+	}
+}
+
+impl Cdp {
+
+	/// Gives a stream of the parameters of the given event, like `Network.requestWillBeSent`, every time that it occurs from now on.
+	///
+	/// Most events are only sent once their domain has been enabled, with a method like `Network.enable`.
+	/// So the stream is best obtained before that, so that it doesn't miss the first events.
+	pub fn events( &self, event: &str ) -> CdpEvents {
+		let data = unsafe { &mut *self.bw.user_data() };
+
+		let watchers = match data.cdp_events.iter_mut().position(|(e, _)| e == event ) {
+			Some( index ) => &mut data.cdp_events[ index ].1,
+			None => {
+				self.bw.inner.subscribe_devtools_event( event );
+				data.cdp_events.push( (event.to_owned(), PropertyWatchers::default()) );
+				&mut data.cdp_events.last_mut().unwrap().1
+			}
+		};
+		watchers.watch()
+	}
+
+	/// Calls the given method, like `Page.captureScreenshot`, with the JSON object of its parameters.
+	/// An empty string is the same as `{}`.
+	/// Gives back the JSON object of the result of the method.
+	///
+	/// If the window is destroyed before the method has finished, it results in an error.
+	pub async fn send( &self, method: &str, params: &str ) -> Result<String, CdpError> {
+		let (tx, rx) = oneshot::channel::<Result<String, CdpError>>();
+
+		self._send( method, params, |result| {
+			if let Err(_) = tx.send( result.map_err( CdpError::new ) ) {
+				panic!("Unable to send DevTools result back")
+			}
+		} );
+
+		rx.await.unwrap()
+	}
+
+	/// Calls the given method, and provides its result, or the JSON object of its error, via a callback.
+	fn _send<H>( &self, method: &str, params: &str, on_complete: H ) where
+		H: FnOnce( Result<String, String> ) + 'static
+	{
+		let data_ptr: *mut WindowCallbackData<H> = Box::into_raw(
+			Box::new( (self.bw.id(), on_complete) )
+		);
+
+		self.bw.inner.call_devtools_method( method, params, devtools_callback::<H>, data_ptr as _ );
+	}
+
+	/// The browser window that the client belongs to.
+	pub fn window( &self ) -> BrowserWindowHandle {
+		self.bw
+	}
+}
+
+impl CdpError {
+
+	pub(in crate) fn new( json: String ) -> Self {
+		Self { json }
+	}
+
+	/// The error code of the protocol, if it is given.
+	pub fn code( &self ) -> Option<i64> {
+		JsValue::from_json( &self.json )?.get( "code" )?.as_f64().map(|c| c as i64 )
+	}
+
+	/// The JSON object of the error, as the protocol has given it.
+	pub fn json( &self ) -> &str {
+		&self.json
+	}
+
+	/// The message of the error.
+	pub fn message( &self ) -> String {
+		JsValue::from_json( &self.json )
+			.and_then(|e| e.get( "message" ).and_then(|m| m.as_str() ).map(|m| m.to_owned() ) )
+			.unwrap_or_else(|| self.json.clone() )
+	}
+}
+
+impl fmt::Display for CdpError {
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
+		write!( f, "DevTools error: {}", self.message() )
+	}
+}
+
+impl Error for CdpError {}
+
+
+
+/// Gives the parameters of an event to the streams of `Cdp::events` that are waiting for it.
+pub(in super) fn notify( data: &mut BrowserUserData, method: &str, params: &str ) {
+	if let Some( (_, watchers) ) = data.cdp_events.iter_mut().find(|(e, _)| e == method ) {
+		watchers.notify( params.to_owned() );
+	}
+}
+
+// See `WindowCallbackData` for why the ID comes with the callback
+unsafe fn devtools_callback<H>( _handle: BrowserWindowImpl, cb_data: *mut (), result: Result<String, String> ) where
+	H: FnOnce( Result<String, String> ) + 'static
+{
+	let (id, on_complete) = *Box::from_raw( cb_data as *mut WindowCallbackData<H> );
+
+	emit::emit( id, move || on_complete( result ) );
+}
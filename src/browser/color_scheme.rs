@@ -0,0 +1,71 @@
+use super::BrowserWindowHandle;
+#[cfg(feature = "threadsafe")]
+use super::BrowserWindowThreaded;
+use crate::application::SystemTheme;
+use crate::error::BrowserError;
+
+#[cfg(feature = "threadsafe")]
+use crate::delegate::DispatchFuture;
+
+
+
+/// The color scheme that the page of a browser window is told that the user prefers, through the `prefers-color-scheme` media feature.
+/// It is set with `BrowserWindowHandle::set_color_scheme`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColorScheme {
+	/// The preference of the browser engine, which usually is the theme of the system.
+	Auto,
+	Light,
+	Dark
+}
+
+
+
+impl ColorScheme {
+
+	/// The color scheme as the parameters of the `Emulation.setEmulatedMedia` method of the DevTools protocol, in which an empty value removes the override.
+	pub(in crate) fn to_cdp_params( &self ) -> String {
+		let value = match self {
+			Self::Auto => "",
+			Self::Light => "light",
+			Self::Dark => "dark"
+		};
+
+		format!( "{{\"features\":[{{\"name\":\"prefers-color-scheme\",\"value\":\"{}\"}}]}}", value )
+	}
+}
+
+impl From<SystemTheme> for ColorScheme {
+	fn from( theme: SystemTheme ) -> Self {
+		match theme {
+			SystemTheme::Light => Self::Light,
+			SystemTheme::Dark => Self::Dark
+		}
+	}
+}
+
+impl BrowserWindowHandle {
+
+	/// Makes the page believe that the user prefers the given color scheme, so that its `prefers-color-scheme` media queries follow it.
+	/// Set `ColorScheme::Auto` to go back to the preference of the browser engine.
+	///
+	/// Combined with `ApplicationHandle::system_theme_changes`, this lets the HTML of the application follow the theme of the system, also where the browser engine doesn't.
+	/// The setting applies to the current page and the pages that the window navigates to afterwards.
+	/// Results in `BrowserError::DevToolsFailed` if the browser engine can't emulate the color scheme.
+	pub async fn set_color_scheme( &self, scheme: ColorScheme ) -> Result<(), BrowserError> {
+		self.call_devtools( "Emulation.setEmulatedMedia", &scheme.to_cdp_params() ).await
+			.map_err( BrowserError::DevToolsFailed )?;
+		Ok(())
+	}
+}
+
+#[cfg(feature = "threadsafe")]
+impl BrowserWindowThreaded {
+
+	/// See `BrowserWindowHandle::set_color_scheme`.
+	pub fn set_color_scheme( &self, scheme: ColorScheme ) -> DispatchFuture<Result<(), BrowserError>> {
+		self.dispatch_async(move |bw| async move {
+			bw.set_color_scheme( scheme ).await
+		})
+	}
+}
@@ -0,0 +1,76 @@
+use super::BrowserWindowHandle;
+#[cfg(feature = "threadsafe")]
+use super::BrowserWindowThreaded;
+use crate::error::BrowserError;
+
+#[cfg(feature = "threadsafe")]
+use crate::delegate::DispatchFuture;
+
+
+
+// Follows the focus into the frames of the same origin, and takes the selection of a text field if that has the focus, which `getSelection` leaves out.
+const SELECTED_TEXT_JS: &'static str = "(function() {
+	var doc = document;
+	var element = doc.activeElement;
+	while (element && (element.tagName == 'IFRAME' || element.tagName == 'FRAME') && element.contentDocument) {
+		doc = element.contentDocument;
+		element = doc.activeElement;
+	}
+	if (element && (element.tagName == 'INPUT' || element.tagName == 'TEXTAREA') && typeof element.selectionStart == 'number')
+		return element.value.substring(element.selectionStart, element.selectionEnd);
+	return doc.getSelection().toString();
+})()";
+
+
+
+impl BrowserWindowHandle {
+
+	/// Gives the HTML of the document of the main frame, as it is at the moment.
+	/// That includes the changes that scripts have made to it, unlike the source that has been loaded.
+	///
+	/// The document is serialized by the DevTools protocol of the browser engine, so it doesn't depend on the page, and works even while the scripts of the page are busy.
+	/// Results in `BrowserError::DevToolsFailed` if the browser engine can't give the document.
+	pub async fn page_source( &self ) -> Result<String, BrowserError> {
+		let document = self.call_devtools( "DOM.getDocument", "{\"depth\":0}" ).await
+			.map_err( BrowserError::DevToolsFailed )?;
+		let node_id = document.get( "root" ).and_then(|r| r.get( "nodeId" ) ).and_then(|id| id.as_f64() )
+			.ok_or_else(|| BrowserError::DevToolsFailed( "the document has no root node".into() ) )?;
+
+		let html = self.call_devtools( "DOM.getOuterHTML", &format!( "{{\"nodeId\":{}}}", node_id as i64 ) ).await
+			.map_err( BrowserError::DevToolsFailed )?;
+		html.get( "outerHTML" ).and_then(|h| h.as_str() ).map(|h| h.to_owned() )
+			.ok_or_else(|| BrowserError::DevToolsFailed( "the browser engine didn't give back the HTML".into() ) )
+	}
+
+	/// Gives the text that the user has selected, in the frame that has the focus.
+	/// If a text field has the focus, this is the text that is selected in there.
+	/// Is empty if nothing is selected.
+	///
+	/// The text is taken with the DevTools protocol, by JavaScript that runs in a world of its own.
+	/// So the page can't get in the way by replacing `getSelection`, but it still has to respond, as the world shares its thread.
+	/// Results in `BrowserError::DevToolsFailed` if the browser engine can't give the text.
+	pub async fn selected_text( &self ) -> Result<String, BrowserError> {
+		let text = self.evaluate_isolated( SELECTED_TEXT_JS ).await
+			.map_err( BrowserError::DevToolsFailed )?;
+
+		Ok( text.as_str().unwrap_or( "" ).to_owned() )
+	}
+}
+
+#[cfg(feature = "threadsafe")]
+impl BrowserWindowThreaded {
+
+	/// See `BrowserWindowHandle::page_source`.
+	pub fn page_source( &self ) -> DispatchFuture<Result<String, BrowserError>> {
+		self.dispatch_async(|bw| async move {
+			bw.page_source().await
+		})
+	}
+
+	/// See `BrowserWindowHandle::selected_text`.
+	pub fn selected_text( &self ) -> DispatchFuture<Result<String, BrowserError>> {
+		self.dispatch_async(|bw| async move {
+			bw.selected_text().await
+		})
+	}
+}
@@ -0,0 +1,108 @@
+use super::{registry, BrowserWindowBuilder, BrowserWindowHandle};
+#[cfg(feature = "threadsafe")]
+use super::BrowserWindowThreaded;
+use crate::ipc;
+use crate::window::WindowId;
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+
+
+/// A style sheet that has been inserted into the page of a browser window with `BrowserWindowHandle::insert_css`, which can be removed again.
+///
+/// Dropping the handle leaves the style sheet in place.
+///
+/// # Example
+/// ```ignore
+/// let dark = bw.insert_css( "body { background: #222; color: #eee; }" );
+/// // ...
+/// dark.remove();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CssHandle {
+	id: u64,
+	window: WindowId
+}
+
+
+
+/// The number that the next inserted style sheet is known by, which is unique for the application.
+static NEXT_ID: AtomicU64 = AtomicU64::new( 1 );
+
+
+
+impl CssHandle {
+
+	fn new( window: WindowId ) -> Self {
+		Self {
+			id: NEXT_ID.fetch_add( 1, Ordering::Relaxed ),
+			window
+		}
+	}
+
+	/// Removes the style sheet from the page again.
+	/// Nothing happens if the page has navigated elsewhere in the meantime, as the style sheet is gone already, or if the window has been destroyed.
+	///
+	/// This needs to be done on the GUI thread, like in `BrowserWindowThreaded::dispatch`.
+	pub fn remove( self ) {
+		if let Some( bw ) = registry::by_id( self.window ) {
+			bw.exec_js( &format!( "(function() {{ var s = document.getElementById(\"__bw_css_{}\"); if (s) s.remove(); }})()", self.id ) );
+		}
+	}
+}
+
+impl BrowserWindowBuilder {
+
+	/// Adds CSS that is applied to every document that is loaded in the browser, like for theming or branding pages whose source can't be changed.
+	/// Multiple style sheets are applied in the order in which they were added, after the style sheets of the page itself.
+	///
+	/// The style sheet is inserted once the document has been parsed, so the page may briefly be shown without it.
+	pub fn preload_css( &mut self, css: &str ) -> &mut Self {
+		self.preload_js( &format!( r#"(function() {{
+	function insert() {{
+		var s = document.createElement("style");
+		s.textContent = {};
+		(document.head || document.documentElement).appendChild(s);
+	}}
+	if (document.readyState === "loading") document.addEventListener("DOMContentLoaded", insert);
+	else insert();
+}})();"#, ipc::js_string_literal( css ) ) )
+	}
+}
+
+impl BrowserWindowHandle {
+
+	/// Inserts the given CSS into the page that is shown, after its own style sheets, and gives a handle to remove it again.
+	/// It only applies to the current page, use `BrowserWindowBuilder::preload_css` for CSS that is applied to every page.
+	pub fn insert_css( &self, css: &str ) -> CssHandle {
+		let handle = CssHandle::new( self.id() );
+		self.exec_js( &insert_js( handle.id, css ) );
+		handle
+	}
+}
+
+#[cfg(feature = "threadsafe")]
+impl BrowserWindowThreaded {
+
+	/// Same as `BrowserWindowHandle::insert_css`, but inserts it from the GUI thread.
+	/// If the GUI thread can't be reached anymore, the CSS is not inserted.
+	pub fn insert_css( &self, css: &str ) -> CssHandle {
+		let handle = CssHandle::new( self.id() );
+		let js = insert_js( handle.id, css );
+
+		self.dispatch(move |bw| bw.exec_js( &js ) );
+		handle
+	}
+}
+
+
+
+/// The JavaScript code that inserts the style sheet into the page, with an ID that `CssHandle::remove` can find it by.
+fn insert_js( id: u64, css: &str ) -> String {
+	format!( r#"(function() {{
+	var s = document.createElement("style");
+	s.id = "__bw_css_{}";
+	s.textContent = {};
+	(document.head || document.documentElement).appendChild(s);
+}})()"#, id, ipc::js_string_literal( css ) )
+}
@@ -0,0 +1,97 @@
+use super::BrowserWindowHandle;
+#[cfg(feature = "threadsafe")]
+use super::BrowserWindowThreaded;
+use crate::error::BrowserError;
+
+#[cfg(feature = "threadsafe")]
+use crate::delegate::DispatchFuture;
+
+
+
+/// The screen of a device that the page of a browser window is made to believe it is shown on, by `BrowserWindowHandle::emulate_device`.
+/// This is meant for previewing responsive layouts, like in the tooling windows of an application.
+///
+/// # Example
+/// ```ignore
+/// // A phone in portrait orientation
+/// bw.emulate_device( &DeviceMetrics {
+/// 	width: 390,
+/// 	height: 844,
+/// 	device_pixel_ratio: 3.0,
+/// 	mobile: true,
+/// 	touch: true
+/// } ).await?;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DeviceMetrics {
+	/// The width of the viewport, in CSS pixels.
+	pub width: u32,
+	/// The height of the viewport, in CSS pixels.
+	pub height: u32,
+	/// The number of physical pixels per CSS pixel, which is what `window.devicePixelRatio` gives.
+	pub device_pixel_ratio: f64,
+	/// Whether the page is laid out like on a mobile device, where the `<meta name="viewport">` tag is honored and scroll bars overlay the page.
+	pub mobile: bool,
+	/// Whether the page gets touch events, and whether `(pointer: coarse)` media queries match.
+	pub touch: bool
+}
+
+
+
+impl DeviceMetrics {
+
+	/// The metrics as the parameters of the `Emulation.setDeviceMetricsOverride` method of the DevTools protocol.
+	pub(in crate) fn to_cdp_params( &self ) -> String {
+		format!(
+			"{{\"width\":{},\"height\":{},\"deviceScaleFactor\":{},\"mobile\":{}}}",
+			self.width, self.height, self.device_pixel_ratio, self.mobile
+		)
+	}
+}
+
+impl BrowserWindowHandle {
+
+	/// Stops emulating a device, so that the page is shown on the actual screen again.
+	/// Results in `BrowserError::DevToolsFailed` if the browser engine can't stop the emulation.
+	pub async fn clear_device_emulation( &self ) -> Result<(), BrowserError> {
+		self.call_devtools( "Emulation.clearDeviceMetricsOverride", "{}" ).await
+			.map_err( BrowserError::DevToolsFailed )?;
+		self.call_devtools( "Emulation.setTouchEmulationEnabled", "{\"enabled\":false}" ).await
+			.map_err( BrowserError::DevToolsFailed )?;
+		Ok(())
+	}
+
+	/// Makes the page believe that it is shown on the screen of the given device, from now on, to preview how a responsive layout looks on it.
+	/// The page is laid out with the size of the device, which is drawn within the window, scaled down if it doesn't fit.
+	/// Use `clear_device_emulation` to go back to the actual screen.
+	///
+	/// The emulation lasts across navigations.
+	/// Results in `BrowserError::DevToolsFailed` if the browser engine can't emulate the device.
+	pub async fn emulate_device( &self, metrics: &DeviceMetrics ) -> Result<(), BrowserError> {
+		self.call_devtools( "Emulation.setDeviceMetricsOverride", &metrics.to_cdp_params() ).await
+			.map_err( BrowserError::DevToolsFailed )?;
+
+		let touch = if metrics.touch { "{\"enabled\":true,\"maxTouchPoints\":5}" } else { "{\"enabled\":false}" };
+		self.call_devtools( "Emulation.setTouchEmulationEnabled", touch ).await
+			.map_err( BrowserError::DevToolsFailed )?;
+		Ok(())
+	}
+}
+
+#[cfg(feature = "threadsafe")]
+impl BrowserWindowThreaded {
+
+	/// See `BrowserWindowHandle::clear_device_emulation`.
+	pub fn clear_device_emulation( &self ) -> DispatchFuture<Result<(), BrowserError>> {
+		self.dispatch_async(|bw| async move {
+			bw.clear_device_emulation().await
+		})
+	}
+
+	/// See `BrowserWindowHandle::emulate_device`.
+	pub fn emulate_device( &self, metrics: DeviceMetrics ) -> DispatchFuture<Result<(), BrowserError>> {
+		self.dispatch_async(move |bw| async move {
+			bw.emulate_device( &metrics ).await
+		})
+	}
+}
@@ -0,0 +1,94 @@
+use browser_window_core::browser_window::{BrowserWindowExt, BrowserWindowImpl};
+use futures_channel::oneshot;
+
+use super::{BrowserWindowHandle, JsValue, WindowCallbackData};
+use super::{emit, registry};
+
+
+
+impl BrowserWindowHandle {
+
+	/// Calls a method of the DevTools protocol, for the features that are built on top of it.
+	/// Unlike `Cdp::send`, this doesn't need feature `cdp`.
+	///
+	/// Gives back the result of the method, or the message of its error.
+	pub(in super) async fn call_devtools( &self, method: &str, params: &str ) -> Result<JsValue, String> {
+		let (tx, rx) = oneshot::channel::<Result<String, String>>();
+
+		let data_ptr = Box::into_raw( Box::new( (self.id(), tx) ) );
+		self.inner.call_devtools_method( method, params, devtools_callback, data_ptr as _ );
+
+		match rx.await {
+			Err(_) => Err( "the window has been destroyed".into() ),
+			Ok( Err( json ) ) => Err(
+				JsValue::from_json( &json )
+					.and_then(|e| e.get( "message" ).and_then(|m| m.as_str() ).map(|m| m.to_owned() ) )
+					.unwrap_or( json )
+			),
+			Ok( Ok( json ) ) => JsValue::from_json( &json ).ok_or( json )
+		}
+	}
+
+	/// Evaluates a JavaScript expression in the main frame, in a world of its own, with the DevTools protocol.
+	/// The world shares the DOM with the page, but not its scripts, so the built-in functions are the original ones, whatever the page has done to them.
+	/// The world is created once for each page, and used again until the window navigates.
+	///
+	/// Gives back the value of the expression, or the message of its error.
+	pub(in super) async fn evaluate_isolated( &self, expression: &str ) -> Result<JsValue, String> {
+		let result = match unsafe { &mut *self.user_data() }.isolated_world.clone() {
+			None => self.evaluate_in_new_world( expression ).await?,
+			// The page may have been replaced before the navigation has been reported, which leaves the world behind
+			Some( cached ) => match self.evaluate_in_world( cached.1, expression ).await {
+				Ok( result ) => result,
+				Err(_) => {
+					let stale = Some( cached );
+					self.cache_isolated_world(|world| if *world == stale { *world = None } );
+					self.evaluate_in_new_world( expression ).await?
+				}
+			}
+		};
+
+		if let Some( text ) = result.get( "exceptionDetails" ).and_then(|e| e.get( "text" ) ).and_then(|t| t.as_str() ) {
+			return Err( text.to_owned() );
+		}
+		Ok( result.get( "result" ).and_then(|r| r.get( "value" ) ).cloned().unwrap_or( JsValue::Null ) )
+	}
+
+	async fn evaluate_in_new_world( &self, expression: &str ) -> Result<JsValue, String> {
+		let tree = self.call_devtools( "Page.getFrameTree", "{}" ).await?;
+		let frame_id = tree.get( "frameTree" ).and_then(|t| t.get( "frame" ) ).and_then(|f| f.get( "id" ) ).and_then(|id| id.as_str() )
+			.ok_or( "the browser engine didn't give back the main frame" )?
+			.to_owned();
+
+		let params = format!( "{{\"frameId\":{},\"worldName\":\"browser-window\"}}", JsValue::from( frame_id.as_str() ) );
+		let world = self.call_devtools( "Page.createIsolatedWorld", &params ).await?;
+		let context_id = world.get( "executionContextId" ).and_then(|id| id.as_f64() )
+			.ok_or( "the browser engine didn't give back the isolated world" )? as i64;
+
+		self.cache_isolated_world(|world| *world = Some( (frame_id, context_id) ) );
+		self.evaluate_in_world( context_id, expression ).await
+	}
+
+	async fn evaluate_in_world( &self, context_id: i64, expression: &str ) -> Result<JsValue, String> {
+		let params = format!( "{{\"expression\":{},\"contextId\":{},\"returnByValue\":true}}", JsValue::from( expression ), context_id );
+		self.call_devtools( "Runtime.evaluate", &params ).await
+	}
+
+	/// Updates the isolated world that is kept for the page, unless the window has been destroyed during the calls that came before.
+	fn cache_isolated_world<F>( &self, update: F ) where
+		F: FnOnce( &mut Option<(String, i64)> )
+	{
+		if let Some( bw ) = registry::by_id( self.id() ) {
+			update( &mut unsafe { &mut *bw.user_data() }.isolated_world );
+		}
+	}
+}
+
+
+
+// See `WindowCallbackData` for why the ID comes with the callback
+unsafe fn devtools_callback( _handle: BrowserWindowImpl, cb_data: *mut (), result: Result<String, String> ) {
+	let (id, tx) = *Box::from_raw( cb_data as *mut WindowCallbackData<oneshot::Sender<Result<String, String>>> );
+
+	emit::emit( id, move || { let _ = tx.send( result ); } );
+}
@@ -0,0 +1,60 @@
+use browser_window_core::browser_window::BrowserWindowExt;
+
+use super::BrowserWindowHandle;
+
+
+
+/// The standard editing commands, that are executed by `BrowserWindowHandle::exec_command`.
+/// They do the same as their usual keyboard shortcuts, so they can be given to the items of a native menu.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EditCommand {
+	Undo,
+	Redo,
+	Cut,
+	Copy,
+	Paste,
+	/// Deletes the selection, without putting it on the clipboard.
+	Delete,
+	SelectAll
+}
+
+
+
+impl EditCommand {
+
+	#[cfg(feature = "mock")]
+	pub(in super) fn from_c( command: u8 ) -> Self {
+		match command {
+			0 => Self::Undo,
+			1 => Self::Redo,
+			2 => Self::Cut,
+			3 => Self::Copy,
+			4 => Self::Paste,
+			5 => Self::Delete,
+			_ => Self::SelectAll
+		}
+	}
+
+	fn to_c( &self ) -> u8 {
+		match self {
+			Self::Undo => 0,
+			Self::Redo => 1,
+			Self::Cut => 2,
+			Self::Copy => 3,
+			Self::Paste => 4,
+			Self::Delete => 5,
+			Self::SelectAll => 6
+		}
+	}
+}
+
+impl BrowserWindowHandle {
+
+	/// Executes the given editing command in the frame that has the focus, or in the main frame if none has.
+	/// It applies to the element that has the focus there, like a text field, or to the document otherwise.
+	///
+	/// The command goes through the browser engine, so `Paste` works without the page having access to the clipboard.
+	pub fn exec_command( &self, command: EditCommand ) {
+		self.inner.exec_command( command.to_c() )
+	}
+}
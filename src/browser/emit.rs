@@ -0,0 +1,103 @@
+use std::{
+	any,
+	cell::RefCell,
+	collections::{HashMap, VecDeque},
+	mem
+};
+
+use crate::application::trace;
+use crate::window::WindowId;
+
+
+
+type PendingEvent = Box<dyn FnOnce()>;
+
+/// An event of a window that is being handled.
+/// While it exists, the other events of the same window wait until it has finished.
+pub(in crate) struct Emission {
+	window: WindowId
+}
+
+
+
+thread_local! {
+	// Events are only handled on the GUI thread, so every window that is handling an event has its waiting events here.
+	static PENDING: RefCell<HashMap<WindowId, VecDeque<PendingEvent>>> = RefCell::new( HashMap::new() );
+}
+
+
+
+impl Emission {
+
+	/// Starts handling an event of the given window.
+	/// Returns `None` if another event of the window is being handled already, in which case the event should be deferred with `emit`.
+	pub fn begin( window: WindowId ) -> Option<Self> {
+		PENDING.with(|pending| {
+			let mut pending = pending.borrow_mut();
+
+			if pending.contains_key( &window ) {
+				return None;
+			}
+			pending.insert( window, VecDeque::new() );
+			Some( Self { window } )
+		})
+	}
+
+	/// Handles the events that have occurred in the meantime, in the order in which they have occurred.
+	pub fn finish( self ) {
+		// The borrow is released before each event, so that the event can emit events itself.
+		while let Some( event ) = PENDING.with(|pending| {
+			pending.borrow_mut().get_mut( &self.window ).and_then(|events| events.pop_front() )
+		}) {
+			event();
+		}
+	}
+}
+
+impl Drop for Emission {
+	fn drop( &mut self ) {
+		// Only when a handler has panicked, are there still events left to drop.
+		let left = PENDING.with(|pending| pending.borrow_mut().remove( &self.window ) );
+		drop( left );
+	}
+}
+
+
+
+/// Handles an event of a window right away, or once the event that is being handled for the window, and the ones before it, have finished.
+/// So the events of a window are handled one at a time, in the order in which they occur, even when an event occurs while a handler is still running.
+pub(in crate) fn emit<E>( window: WindowId, event: E ) where
+	E: FnOnce() + 'static
+{
+	emit_named( window, any::type_name::<E>(), event );
+}
+
+/// Like `emit`, but with the name of the handler that the event is traced by, see `ApplicationHandle::start_gui_trace`.
+pub(in crate) fn emit_named<E>( window: WindowId, name: &'static str, event: E ) where
+	E: FnOnce() + 'static
+{
+	let event = move || trace::event( window, name, event );
+
+	match Emission::begin( window ) {
+		None => PENDING.with(|pending| {
+			if let Some( events ) = pending.borrow_mut().get_mut( &window ) {
+				events.push_back( Box::new( event ) );
+			}
+		}),
+		Some( emission ) => {
+			event();
+			emission.finish();
+		}
+	}
+}
+
+/// Drops the events that are waiting for the given window, without handling them.
+/// Used when the window is destroyed, after which its events can't be handled anymore.
+pub(in crate) fn discard_pending( window: WindowId ) {
+	let discarded = PENDING.with(|pending| {
+		pending.borrow_mut().get_mut( &window ).map(|events| mem::take( events ) )
+	});
+
+	// Dropping the events may drop senders that wake up futures, which is done outside of the borrow.
+	drop( discarded );
+}
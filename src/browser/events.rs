@@ -0,0 +1,78 @@
+use super::BrowserWindowHandle;
+#[cfg(feature = "threadsafe")]
+use super::BrowserWindowThreaded;
+
+use crate::error::BrowserError;
+use crate::prelude::*;
+
+
+
+/// Something that has happened to a browser window, as it is given by the stream of `BrowserWindowHandle::events`.
+#[derive(Clone, Debug)]
+pub enum BrowserEvent {
+	/// The window has moved, to the given position of its top left corner.
+	Moved( Pos2D ),
+	/// The window has been resized, to the given size of its content.
+	Resized( Dims2D ),
+	/// The window has gained (`true`) or lost (`false`) the focus.
+	Focused( bool ),
+	/// The main frame has navigated to the given address.
+	Navigated( String ),
+	/// The main frame has finished loading its document.
+	Loaded,
+	/// The main frame couldn't load its document, which is given as `BrowserError::LoadFailed`.
+	LoadFailed( BrowserError ),
+	/// The page has changed its route without loading another document, like single-page applications do with `history.pushState`.
+	/// It is given the new path, with the query and the fragment, see also `BrowserWindowBuilder::on_route_changed`.
+	RouteChanged( String ),
+	/// The title of the page has changed.
+	/// This is the title of the document, which is not necessarily the title of the window.
+	TitleChanged( String ),
+	/// The window has been closed.
+	/// This is the last event of the stream, after which it ends.
+	Closed
+}
+
+/// A stream of the events of a browser window, in the order in which they occur.
+/// It ends after `BrowserEvent::Closed`.
+///
+/// # Example
+/// ```ignore
+/// let mut events = bw.events();
+///
+/// while let Some( event ) = events.next().await {
+/// 	match event {
+/// 		BrowserEvent::Navigated( url ) => println!( "Now at {}", url ),
+/// 		BrowserEvent::Closed => println!( "Closed" ),
+/// 		_ => {}
+/// 	}
+/// }
+/// ```
+pub type BrowserEvents = PropertyChanges<BrowserEvent>;
+
+
+
+impl BrowserWindowHandle {
+
+	/// Gives a stream of everything that happens to the window from now on.
+	/// See `BrowserEvent` for the events that are reported.
+	pub fn events( &self ) -> BrowserEvents {
+		unsafe { &mut *self.user_data() }.watchers.events.watch()
+	}
+}
+
+#[cfg(feature = "threadsafe")]
+impl BrowserWindowThreaded {
+
+	/// See `BrowserWindowHandle::events`.
+	/// The stream starts once the GUI thread gets to it, so events that happen before that are missed.
+	pub fn events( &self ) -> BrowserEvents {
+		let (sender, events) = PropertyChanges::new();
+
+		// If the window can't be reached anymore, the sender is dropped, which ends the stream right away.
+		self.dispatch(move |bw| {
+			unsafe { &mut *bw.user_data() }.watchers.events.add( sender );
+		});
+		events
+	}
+}
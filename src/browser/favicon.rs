@@ -0,0 +1,37 @@
+/// The favicon of a page, as it is given by `BrowserWindowBuilder::on_favicon_changed`.
+///
+/// CEF decodes the icon, while WebView2 gives the PNG image that it has converted the icon to.
+#[derive(Clone, Debug)]
+pub enum Favicon {
+	/// An image in the PNG format.
+	Png( Vec<u8> ),
+	/// The pixels of the icon, row by row, with 4 bytes for each pixel in the RGBA format.
+	Rgba {
+		width: u32,
+		height: u32,
+		pixels: Vec<u8>
+	}
+}
+
+
+
+impl Favicon {
+
+	/// A width and height of 0 mean that the data is a PNG image.
+	pub(in super) fn from_raw( data: &[u8], width: u32, height: u32 ) -> Self {
+		if width == 0 || height == 0 {
+			Self::Png( data.to_vec() )
+		}
+		else {
+			Self::Rgba { width, height, pixels: data.to_vec() }
+		}
+	}
+
+	/// The data with its width and height, in the way that `WindowExt::set_icon` takes them.
+	pub(in super) fn to_raw( &self ) -> (&[u8], u32, u32) {
+		match self {
+			Self::Png( data ) => (data, 0, 0),
+			Self::Rgba { width, height, pixels } => (pixels, *width, *height)
+		}
+	}
+}
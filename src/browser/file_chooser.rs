@@ -0,0 +1,121 @@
+use browser_window_core::browser_window::{FileChooserExt, FileChooserImpl};
+
+use std::path::{Path, PathBuf};
+
+
+
+/// What the page wants the user to choose with a `FileChooser`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileChooserMode {
+	/// A single file to open, like for `<input type="file">`.
+	Open,
+	/// Any number of files to open, like for `<input type="file" multiple>`.
+	OpenMultiple,
+	/// A folder, like for `<input type="file" webkitdirectory>`.
+	OpenFolder,
+	/// Where to save a file.
+	Save
+}
+
+/// A request of the page to choose files, as it is given to the handler of `BrowserWindowBuilder::on_file_chooser`.
+///
+/// It is answered with `select` or `cancel`, which can be done later on and from another thread, like once a dialog of the application itself has been closed.
+/// If it gets dropped without having been answered, it is cancelled, as if the user had closed the dialog.
+///
+/// # Example
+/// ```ignore
+/// bwb.on_file_chooser(|_, chooser| {
+/// 	chooser.select( &["/home/user/picture.png"] );
+/// });
+/// ```
+pub struct FileChooser {
+	accept: Vec<String>,
+	chooser: Option<FileChooserImpl>,
+	default_path: PathBuf,
+	mode: FileChooserMode,
+	title: String
+}
+
+
+
+impl FileChooser {
+
+	/// `accept` contains a line for every accepted type of file.
+	pub(in super) fn new( chooser: FileChooserImpl, mode: u8, title: &str, default_path: &str, accept: &str ) -> Self {
+		Self {
+			accept: accept.lines().filter(|a| *a != "" ).map(|a| a.to_owned() ).collect(),
+			chooser: Some( chooser ),
+			default_path: PathBuf::from( default_path ),
+			mode: FileChooserMode::from_c( mode ),
+			title: title.to_owned()
+		}
+	}
+
+	/// The types of files that the page accepts, like `.png` or `image/*`, as they are given in the `accept` attribute of the input field.
+	/// It is empty if any file is accepted.
+	pub fn accept( &self ) -> &[String] {
+		&self.accept
+	}
+
+	/// Cancels the file chooser, as if the user had closed the dialog without choosing anything.
+	pub fn cancel( self ) {}
+
+	/// The path that the dialog would start at, or the name that the file would be saved under.
+	/// It is empty if the page doesn't suggest one.
+	pub fn default_path( &self ) -> &Path {
+		&self.default_path
+	}
+
+	pub fn mode( &self ) -> FileChooserMode {
+		self.mode
+	}
+
+	/// Answers the file chooser with the given paths, as if the user had chosen them.
+	/// Only the first path is used unless the mode is `FileChooserMode::OpenMultiple`, and no paths cancel the file chooser.
+	pub fn select<P>( mut self, paths: &[P] ) where
+		P: AsRef<Path>
+	{
+		let paths: Vec<String> = paths.iter().map(|p| p.as_ref().to_string_lossy().into_owned() ).collect();
+		let count = if self.mode == FileChooserMode::OpenMultiple { paths.len() } else { paths.len().min( 1 ) };
+		let paths: Vec<&str> = paths[ ..count ].iter().map(|p| p.as_str() ).collect();
+
+		if let Some( chooser ) = self.chooser.take() {
+			chooser.select( &paths );
+		}
+	}
+
+	/// The title that the dialog would have, which is empty for the default title.
+	pub fn title( &self ) -> &str {
+		&self.title
+	}
+}
+
+impl Drop for FileChooser {
+	fn drop( &mut self ) {
+		if let Some( chooser ) = self.chooser.take() {
+			chooser.select( &[] );
+		}
+	}
+}
+
+impl FileChooserMode {
+
+	fn from_c( mode: u8 ) -> Self {
+		match mode {
+			1 => Self::OpenMultiple,
+			2 => Self::OpenFolder,
+			3 => Self::Save,
+			_ => Self::Open
+		}
+	}
+
+	#[cfg(feature = "mock")]
+	pub(in super) fn to_c( &self ) -> u8 {
+		match self {
+			Self::Open => 0,
+			Self::OpenMultiple => 1,
+			Self::OpenFolder => 2,
+			Self::Save => 3
+		}
+	}
+}
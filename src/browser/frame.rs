@@ -0,0 +1,123 @@
+use browser_window_core::browser_window::FrameInfo;
+
+
+
+/// Identifies a frame within a browser window.
+/// It can be used to target a frame with `eval_js_in_frame` and `navigate_frame`.
+/// The identifiers of the frames are given by `BrowserWindowHandle::frames` and `frame_tree`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FrameId( pub(in super) i64 );
+
+/// A frame of a browser window, together with all of its subframes.
+/// This is a snapshot, it doesn't change when the page changes its frames.
+#[derive(Clone, Debug)]
+pub struct Frame {
+	id: FrameId,
+	name: String,
+	url: String,
+	is_main: bool,
+	children: Vec<Frame>
+}
+
+
+
+impl Frame {
+
+	/// The frames that are embedded within this frame.
+	pub fn children( &self ) -> &[Frame] {
+		&self.children
+	}
+
+	pub fn id( &self ) -> FrameId {
+		self.id
+	}
+
+	/// Whether or not this is the main frame of the browser window.
+	pub fn is_main( &self ) -> bool {
+		self.is_main
+	}
+
+	/// The name of the frame, as set by the `name` attribute of its element.
+	/// Is empty if it has no name.
+	pub fn name( &self ) -> &str {
+		&self.name
+	}
+
+	/// The origin of the frame's URL, like `https://example.com:8080`.
+	/// Is `None` for URLs that don't have a host, like `about:blank` and `data:` URLs.
+	pub fn origin( &self ) -> Option<String> {
+		url_origin( &self.url )
+	}
+
+	pub fn url( &self ) -> &str {
+		&self.url
+	}
+
+	/// Adds this frame and all of its subframes to `frames`, every frame before its subframes.
+	pub(in super) fn collect( &self, frames: &mut Vec<Frame> ) {
+		frames.push( self.clone() );
+
+		for child in &self.children {
+			child.collect( frames );
+		}
+	}
+
+	/// Adds the origins of this frame and all of its subframes to `origins`, if they are not in there yet.
+	pub(in super) fn collect_origins( &self, origins: &mut Vec<String> ) {
+		if let Some( origin ) = self.origin() {
+			if !origins.contains( &origin ) {
+				origins.push( origin );
+			}
+		}
+
+		for child in &self.children {
+			child.collect_origins( origins );
+		}
+	}
+
+	/// Constructs the tree of the frame with the given `info`, taking its children out of `frames`.
+	fn from_info( info: FrameInfo, frames: &mut Vec<FrameInfo> ) -> Self {
+		let mut children = Vec::new();
+
+		while let Some( index ) = frames.iter().position(|f| f.parent_id == Some( info.id ) ) {
+			let child = frames.remove( index );
+			children.push( Self::from_info( child, frames ) );
+		}
+
+		Self {
+			id: FrameId( info.id ),
+			name: info.name,
+			url: info.url,
+			is_main: info.is_main,
+			children
+		}
+	}
+}
+
+
+
+/// Builds the tree of frames, with the main frame at the root.
+/// Returns `None` if there is no main frame.
+pub(in super) fn frame_tree( mut frames: Vec<FrameInfo> ) -> Option<Frame> {
+	let index = frames.iter().position(|f| f.is_main )?;
+	let main = frames.remove( index );
+
+	Some( Frame::from_info( main, &mut frames ) )
+}
+
+/// Gives the origin of the given URL: its scheme, host and port (if any).
+fn url_origin( url: &str ) -> Option<String> {
+	let (scheme, rest) = url.split_once("://")?;
+	let authority = rest.split(|c| c == '/' || c == '?' || c == '#').next().unwrap_or("");
+	// Leave out any credentials
+	let host = match authority.rsplit_once('@') {
+		None => authority,
+		Some( (_, host) ) => host
+	};
+
+	if scheme.is_empty() || host.is_empty() {
+		return None;
+	}
+
+	Some( format!( "{}://{}", scheme.to_lowercase(), host.to_lowercase() ) )
+}
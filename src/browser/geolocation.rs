@@ -0,0 +1,53 @@
+use super::BrowserWindowHandle;
+#[cfg(feature = "threadsafe")]
+use super::BrowserWindowThreaded;
+use crate::error::BrowserError;
+
+#[cfg(feature = "threadsafe")]
+use crate::delegate::DispatchFuture;
+
+
+
+impl BrowserWindowHandle {
+
+	/// Lets the page find itself at its actual location again, after `set_geolocation_override`.
+	/// Results in `BrowserError::DevToolsFailed` if the browser engine can't remove the override.
+	pub async fn clear_geolocation_override( &self ) -> Result<(), BrowserError> {
+		self.call_devtools( "Emulation.clearGeolocationOverride", "{}" ).await
+			.map_err( BrowserError::DevToolsFailed )?;
+		Ok(())
+	}
+
+	/// Makes the page find itself at the given location, from now on, with the Geolocation API.
+	/// This is meant for testing pages that depend on the location, and for kiosks that are at a fixed place without a way to find it.
+	///
+	/// `latitude` and `longitude` are in degrees, and `accuracy` is how far off the location may be, in meters.
+	/// The page still needs to be allowed to use the location, which the browser engine may ask the user for.
+	/// The override lasts across navigations.
+	/// Results in `BrowserError::DevToolsFailed` if the browser engine can't override the location.
+	pub async fn set_geolocation_override( &self, latitude: f64, longitude: f64, accuracy: f64 ) -> Result<(), BrowserError> {
+		let params = format!( "{{\"latitude\":{},\"longitude\":{},\"accuracy\":{}}}", latitude, longitude, accuracy );
+
+		self.call_devtools( "Emulation.setGeolocationOverride", &params ).await
+			.map_err( BrowserError::DevToolsFailed )?;
+		Ok(())
+	}
+}
+
+#[cfg(feature = "threadsafe")]
+impl BrowserWindowThreaded {
+
+	/// See `BrowserWindowHandle::clear_geolocation_override`.
+	pub fn clear_geolocation_override( &self ) -> DispatchFuture<Result<(), BrowserError>> {
+		self.dispatch_async(|bw| async move {
+			bw.clear_geolocation_override().await
+		})
+	}
+
+	/// See `BrowserWindowHandle::set_geolocation_override`.
+	pub fn set_geolocation_override( &self, latitude: f64, longitude: f64, accuracy: f64 ) -> DispatchFuture<Result<(), BrowserError>> {
+		self.dispatch_async(move |bw| async move {
+			bw.set_geolocation_override( latitude, longitude, accuracy ).await
+		})
+	}
+}
@@ -0,0 +1,129 @@
+use super::{BrowserWindowHandle, JsValue};
+#[cfg(feature = "threadsafe")]
+use super::BrowserWindowThreaded;
+use crate::error::BrowserError;
+
+#[cfg(feature = "threadsafe")]
+use crate::delegate::DispatchFuture;
+
+
+
+/// The back and forward history of the main frame of a browser window, as it is given by `BrowserWindowHandle::history`.
+///
+/// # Example
+/// ```ignore
+/// let history = bw.history().await?;
+/// for entry in history.back_entries().iter().rev() {
+/// 	println!( "{}: {}", entry.index, entry.title );
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct History {
+	/// The index of the entry of the page that is shown at the moment.
+	pub current: usize,
+	/// All entries, from the oldest one to the newest one.
+	pub entries: Vec<HistoryEntry>
+}
+
+/// A page in the history of a browser window.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HistoryEntry {
+	/// The index of the entry within `History::entries`, which is what `BrowserWindowHandle::go_to_history_index` takes.
+	pub index: usize,
+	/// The title that the page had, which is empty if it didn't have any.
+	pub title: String,
+	pub url: String
+}
+
+
+
+impl History {
+
+	/// The entries that going back leads to, from the oldest one to the one right before the current one.
+	pub fn back_entries( &self ) -> &[HistoryEntry] {
+		&self.entries[ ..self.current.min( self.entries.len() ) ]
+	}
+
+	pub fn can_go_back( &self ) -> bool {
+		self.current > 0
+	}
+
+	pub fn can_go_forward( &self ) -> bool {
+		self.current + 1 < self.entries.len()
+	}
+
+	/// The entries that going forward leads to, from the one right after the current one to the newest one.
+	pub fn forward_entries( &self ) -> &[HistoryEntry] {
+		&self.entries[ (self.current + 1).min( self.entries.len() ).. ]
+	}
+
+	/// Takes the history from the result of the `Page.getNavigationHistory` method of the DevTools protocol.
+	/// Also gives the ID that the protocol has for every entry.
+	pub(in crate) fn from_cdp( result: &JsValue ) -> Option<(Self, Vec<i64>)> {
+		let current = result.get( "currentIndex" )?.as_f64()? as usize;
+		let mut entries = Vec::new();
+		let mut ids = Vec::new();
+
+		let mut index = 0;
+		while let Some( entry ) = result.get( "entries" )?.get( &index.to_string() ) {
+			let string = |name: &str| entry.get( name ).and_then(|v| v.as_str() ).unwrap_or( "" ).to_owned();
+
+			ids.push( entry.get( "id" )?.as_f64()? as i64 );
+			entries.push( HistoryEntry { index, title: string( "title" ), url: string( "url" ) } );
+			index += 1;
+		}
+
+		Some( (Self { current, entries }, ids) )
+	}
+}
+
+impl BrowserWindowHandle {
+
+	/// Navigates the main frame to the entry of its history at the given index, like going back or forward a number of times at once.
+	/// The index is that of `HistoryEntry::index`, as it has been given by `history`.
+	///
+	/// Resolves once the navigation has started, not when the page has loaded.
+	/// Results in `BrowserError::HistoryEntryNotFound` if the history has no entry at the index, which can happen when it has changed in the meantime.
+	pub async fn go_to_history_index( &self, index: usize ) -> Result<(), BrowserError> {
+		let (_, ids) = self._history().await?;
+		let id = ids.get( index ).ok_or( BrowserError::HistoryEntryNotFound( index ) )?;
+
+		self.call_devtools( "Page.navigateToHistoryEntry", &format!( "{{\"entryId\":{}}}", id ) ).await
+			.map_err( BrowserError::DevToolsFailed )?;
+		Ok(())
+	}
+
+	/// Gives the back and forward history of the main frame, to show it in a history menu or in breadcrumbs.
+	/// See `History` for more information.
+	///
+	/// Results in `BrowserError::DevToolsFailed` if the browser engine can't give the history.
+	pub async fn history( &self ) -> Result<History, BrowserError> {
+		Ok( self._history().await?.0 )
+	}
+
+	async fn _history( &self ) -> Result<(History, Vec<i64>), BrowserError> {
+		let result = self.call_devtools( "Page.getNavigationHistory", "{}" ).await
+			.map_err( BrowserError::DevToolsFailed )?;
+
+		History::from_cdp( &result )
+			.ok_or_else(|| BrowserError::DevToolsFailed( "the browser engine didn't give back the history".into() ) )
+	}
+}
+
+#[cfg(feature = "threadsafe")]
+impl BrowserWindowThreaded {
+
+	/// See `BrowserWindowHandle::go_to_history_index`.
+	pub fn go_to_history_index( &self, index: usize ) -> DispatchFuture<Result<(), BrowserError>> {
+		self.dispatch_async(move |bw| async move {
+			bw.go_to_history_index( index ).await
+		})
+	}
+
+	/// See `BrowserWindowHandle::history`.
+	pub fn history( &self ) -> DispatchFuture<Result<History, BrowserError>> {
+		self.dispatch_async(|bw| async move {
+			bw.history().await
+		})
+	}
+}
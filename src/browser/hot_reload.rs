@@ -0,0 +1,137 @@
+use super::{BrowserWindowBuilder, BrowserWindowHandle};
+use super::js_value::call_js;
+
+use std::{
+	collections::HashMap,
+	fs,
+	mem,
+	path::{Path, PathBuf},
+	sync::{Arc, Mutex},
+	thread,
+	time::{Duration, SystemTime}
+};
+
+
+
+/// How often the watched directories are checked for changes.
+const SCAN_INTERVAL: Duration = Duration::from_millis( 250 );
+
+/// Replaces the style sheets of the page that belong to the changed files, given as lines of paths.
+/// If any of the files is not a style sheet that the page uses, the page is reloaded instead.
+const HOT_RELOAD_JS: &'static str = r#"function(changed) {
+	var links = Array.prototype.slice.call(document.querySelectorAll('link[rel="stylesheet"]'));
+	var swapped = changed.split("\n").every(function(path) {
+		var name = path.split("/").pop();
+		if (!/\.css$/i.test(name)) return false;
+		var matching = links.filter(function(link) { return link.href.split(/[?#]/)[0].split("/").pop() === name; });
+		matching.forEach(function(link) {
+			var url = new URL(link.href);
+			url.searchParams.set("bw-reload", Date.now());
+			link.href = url.toString();
+		});
+		return matching.length > 0;
+	});
+	if (!swapped) location.reload();
+}"#;
+
+
+
+impl BrowserWindowBuilder {
+
+	/// Watches the given directory, like the one with the HTML, JavaScript and CSS files of the page, and reloads the page whenever something in it changes.
+	/// When only style sheets have changed, they are swapped in without reloading the page, so that it keeps its state.
+	/// This can be called more than once, to watch several directories.
+	///
+	/// The directory is checked for changes a few times per second, for as long as the window is open.
+	/// This is meant to shorten the loop of editing the page and seeing the result, it's not something to ship an application with.
+	///
+	/// **Note:** Only available with feature `dev` enabled.
+	pub fn watch<P: Into<PathBuf>>( &mut self, path: P ) -> &mut Self {
+		self.watched.push( path.into() );	self
+	}
+}
+
+
+
+/// Starts watching the given directories for the window, until it is closed.
+pub(in super) fn watch( bw: BrowserWindowHandle, directories: Vec<PathBuf> ) {
+	if directories.is_empty() {
+		return;
+	}
+
+	// The scanner stops once the window has let go of the changes
+	let changes = Arc::new( Mutex::new( Vec::new() ) );
+	let weak_changes = Arc::downgrade( &changes );
+	thread::spawn( move || {
+		let mut snapshots: Vec<HashMap<PathBuf, SystemTime>> = directories.iter().map(|d| snapshot( d ) ).collect();
+
+		loop {
+			thread::sleep( SCAN_INTERVAL );
+			let changes = match weak_changes.upgrade() {
+				None => return,
+				Some( c ) => c
+			};
+
+			for (directory, old) in directories.iter().zip( snapshots.iter_mut() ) {
+				let new = snapshot( directory );
+				let changed = changed_paths( directory, old, &new );
+				*old = new;
+
+				changes.lock().unwrap().extend( changed );
+			}
+		}
+	});
+
+	bw.task_scope().spawn( async move {
+		loop {
+			bw.app().sleep( SCAN_INTERVAL ).await;
+
+			let changed = mem::take( &mut *changes.lock().unwrap() );
+			if changed.len() > 0 {
+				bw.exec_js( &call_js( HOT_RELOAD_JS, &[changed.join("\n").as_str()] ) );
+			}
+		}
+	});
+}
+
+/// The paths, relative to `directory` and with forward slashes, of the files that have been added, modified or removed between the two snapshots.
+pub(in crate) fn changed_paths( directory: &Path, old: &HashMap<PathBuf, SystemTime>, new: &HashMap<PathBuf, SystemTime> ) -> Vec<String> {
+	let modified = new.iter().filter(|(path, time)| old.get( *path ) != Some( *time ) ).map(|(path, _)| path );
+	let removed = old.keys().filter(|path| !new.contains_key( *path ) );
+
+	let mut changed: Vec<String> = modified.chain( removed ).map(|path| {
+		path.strip_prefix( directory ).unwrap_or( path ).to_string_lossy().replace( '\\', "/" )
+	}).collect();
+	changed.sort();
+	changed
+}
+
+/// The modification times of all files within the given directory and its subdirectories.
+pub(in crate) fn snapshot( directory: &Path ) -> HashMap<PathBuf, SystemTime> {
+	let mut files = HashMap::new();
+	let mut directories = vec![directory.to_owned()];
+
+	while let Some( directory ) = directories.pop() {
+		// Files may disappear while they are being looked at, which is picked up by the next snapshot
+		let entries = match fs::read_dir( &directory ) {
+			Err(_) => continue,
+			Ok( e ) => e
+		};
+
+		for entry in entries.filter_map(|e| e.ok() ) {
+			let metadata = match entry.metadata() {
+				Err(_) => continue,
+				Ok( m ) => m
+			};
+
+			if metadata.is_dir() {
+				directories.push( entry.path() );
+			}
+			else if let Ok( modified ) = metadata.modified() {
+				files.insert( entry.path(), modified );
+			}
+		}
+	}
+
+	files
+}
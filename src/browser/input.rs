@@ -0,0 +1,147 @@
+use super::{BrowserWindowHandle, Modifiers, MouseButton};
+
+
+
+/// A key that has been pressed or released while a browser window had focus, as it is given to the handler of `BrowserWindowBuilder::on_key_event`.
+///
+/// # Example
+/// ```ignore
+/// // Ctrl+P
+/// if event.pressed && event.key_code == 0x50 && event.modifiers.ctrl { /* ... */ }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeyEvent {
+	/// The virtual key code as it is used on Windows, like `0x41` for the A key or `0x74` for F5, on all platforms.
+	pub key_code: u16,
+	/// The modifier keys that are held down.
+	/// The mouse buttons are never set.
+	pub modifiers: Modifiers,
+	/// Whether the key has been pressed, or released otherwise.
+	pub pressed: bool,
+	/// Whether the key is pressed again because it is being held down.
+	pub repeat: bool
+}
+
+/// A click or a turn of the wheel within the view of a browser window, as it is given to the handler of `BrowserWindowBuilder::on_mouse_event`.
+///
+/// # Example
+/// ```ignore
+/// // Ctrl+wheel
+/// if let MouseEventKind::Wheel { delta_y, .. } = event.kind {
+/// 	if event.modifiers.ctrl { /* ... */ }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MouseEvent {
+	pub kind: MouseEventKind,
+	/// The modifier keys and the mouse buttons that are held down.
+	pub modifiers: Modifiers,
+	/// The position of the mouse within the view of the browser window, in physical pixels.
+	pub x: i32,
+	pub y: i32
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MouseEventKind {
+	/// A mouse button has been pressed.
+	/// `click_count` is 2 for the second press of a double click, and so on.
+	Pressed { button: MouseButton, click_count: u32 },
+	/// A mouse button has been released.
+	Released { button: MouseButton },
+	/// The wheel has been turned, by the amount of CSS pixels that it would scroll the page with.
+	/// Positive values scroll to the right and down.
+	Wheel { delta_x: f64, delta_y: f64 }
+}
+
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserKeyHandler = Box<dyn FnMut(BrowserWindowHandle, &KeyEvent) -> bool>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserKeyHandler = Box<dyn FnMut(BrowserWindowHandle, &KeyEvent) -> bool + Send>;
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserMouseHandler = Box<dyn FnMut(BrowserWindowHandle, &MouseEvent)>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserMouseHandler = Box<dyn FnMut(BrowserWindowHandle, &MouseEvent) + Send>;
+
+
+
+// The command that the page uses to report the mouse events with.
+pub(in super) const MOUSE_COMMAND: &'static str = "__bw_mouse";
+
+/// The JavaScript code that reports the clicks and the turns of the wheel of the page.
+///
+/// The events are caught before the page gets them, so the page can't stop them from being reported.
+/// Turning the wheel while Ctrl is held doesn't zoom the page, so that the application can decide what it does.
+pub(in super) const MOUSE_JS: &'static str = r#"(function() {
+	if (window.__bw_mouse) return;
+	window.__bw_mouse = true;
+
+	function report(kind, e, button, count, dx, dy) {
+		var r = window.devicePixelRatio;
+		var keys = (e.ctrlKey ? 1 : 0) | (e.shiftKey ? 2 : 0) | (e.altKey ? 4 : 0) | (e.metaKey ? 8 : 0);
+		invoke_extern("__bw_mouse", kind, String(Math.round(e.clientX * r)), String(Math.round(e.clientY * r)), String(button), String(count), String(dx), String(dy), String(keys), String(e.buttons));
+	}
+
+	window.addEventListener("mousedown", function(e) { report("down", e, e.button, e.detail, 0, 0); }, true);
+	window.addEventListener("mouseup", function(e) { report("up", e, e.button, e.detail, 0, 0); }, true);
+	window.addEventListener("wheel", function(e) {
+		// Lines and pages are converted to pixels, like the browser engines do when they scroll
+		var unit = e.deltaMode === 1 ? 40 : e.deltaMode === 2 ? window.innerHeight : 1;
+		report("wheel", e, 0, 0, e.deltaX * unit, e.deltaY * unit);
+		if (e.ctrlKey) e.preventDefault();
+	}, { capture: true, passive: false });
+})();
+"#;
+
+
+
+impl KeyEvent {
+
+	pub(in super) fn from_c( kind: u8, key_code: u16, modifiers: u32, is_repeat: bool ) -> Self {
+		Self {
+			key_code,
+			modifiers: Modifiers::from_c( modifiers ),
+			pressed: kind == 0,
+			repeat: is_repeat
+		}
+	}
+}
+
+impl MouseEvent {
+
+	/// Takes the event from the arguments that `MOUSE_JS` reports it with.
+	pub(in super) fn from_js( args: &[String] ) -> Option<Self> {
+		let number = |i: usize| -> Option<f64> { args.get( i )?.parse().ok() };
+		let button = match number( 3 )? as u8 {
+			1 => MouseButton::Middle,
+			2 => MouseButton::Right,
+			_ => MouseButton::Left
+		};
+
+		let kind = match args.get( 0 )?.as_str() {
+			"down" => MouseEventKind::Pressed { button, click_count: number( 4 )? as u32 },
+			"up" => MouseEventKind::Released { button },
+			"wheel" => MouseEventKind::Wheel { delta_x: number( 5 )?, delta_y: number( 6 )? },
+			_ => return None
+		};
+
+		// The keys are flags of the page, and the buttons are those of `MouseEvent.buttons`
+		let keys = number( 7 )? as u32;
+		let buttons = number( 8 )? as u32;
+		let modifiers = Modifiers {
+			ctrl: keys & 1 != 0,
+			shift: keys & 2 != 0,
+			alt: keys & 4 != 0,
+			meta: keys & 8 != 0,
+			left_button: buttons & 1 != 0,
+			middle_button: buttons & 4 != 0,
+			right_button: buttons & 2 != 0
+		};
+
+		Some( Self {
+			kind,
+			modifiers,
+			x: number( 1 )? as i32,
+			y: number( 2 )? as i32
+		} )
+	}
+}
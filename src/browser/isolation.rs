@@ -0,0 +1,103 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::SystemTime;
+
+use super::BrowserWindowHandle;
+
+
+
+/// The JavaScript code that precedes the preload scripts when they are isolated.
+///
+/// It takes `invoke_extern` away from the page, and gives the scripts a version of it that is only in their scope.
+/// Every call of that version carries the token, by which the calls of the scripts can be told apart from the calls of the page.
+/// The built-in functions that are needed for this are taken before the page can replace them.
+const ISOLATION_START: &'static str = r#"(function() {
+	var token = "__BW_TOKEN__";
+	var native = window.invoke_extern;
+	var apply = Reflect.apply, slice = Array.prototype.slice, concat = Array.prototype.concat;
+	var define = Object.defineProperty, describe = Object.getOwnPropertyDescriptor, names = Object.getOwnPropertyNames, freeze = Object.freeze;
+	var before = names(window);
+	function isolated(token) {
+		return function() { return apply(native, undefined, apply(concat, [token], [apply(slice, arguments, [])])); };
+	}
+	var invoke_extern = isolated(token);
+	delete window.invoke_extern;
+	(function() {
+"#;
+
+/// The JavaScript code that follows the preload scripts when they are isolated.
+///
+/// Whatever the scripts have put on `window` can't be replaced or removed by the page anymore, and the objects of the IPC bridge are frozen.
+/// `__bw_isolated` lets the scripts that are executed later on from Rust use the token.
+const ISOLATION_END: &'static str = r#"
+	})();
+	function deep_freeze(object) {
+		freeze(object);
+		names(object).forEach(function(name) {
+			var value = object[name];
+			if (value && typeof value === "object" && !Object.isFrozen(value)) deep_freeze(value);
+		});
+	}
+	if (window.__bw) deep_freeze(window.__bw);
+	names(window).forEach(function(name) {
+		if (before.indexOf(name) >= 0) return;
+		var descriptor = describe(window, name);
+		if (!descriptor.configurable) return;
+		descriptor.configurable = false;
+		if ("value" in descriptor) descriptor.writable = false;
+		define(window, name, descriptor);
+	});
+	define(window, "__bw_isolated", { value: isolated });
+})();
+"#;
+
+
+
+impl BrowserWindowHandle {
+
+	/// Executes JavaScript code that has been written by this crate, and that may call `invoke_extern`.
+	/// When the scripts of the window are isolated, the code gets the `invoke_extern` of the preload scripts.
+	pub(in super) fn exec_internal_js( &self, js: &str ) {
+		match unsafe { &mut *self.user_data() }.isolation_token.as_ref() {
+			None => self.exec_js( js ),
+			Some( token ) => {
+				let js = format!( "(function(invoke_extern) {{\n{}\n}})(window.__bw_isolated(\"{}\"))", js, token );
+				self.exec_js( &js )
+			}
+		}
+	}
+}
+
+
+
+/// Wraps the given preload script, so that it runs in isolation with the given token.
+pub(in super) fn isolate( script: &str, token: &str ) -> String {
+	format!( "{}{}{}", ISOLATION_START.replace( "__BW_TOKEN__", token ), script, ISOLATION_END )
+}
+
+/// Generates a token that can't be guessed by the page.
+pub(in super) fn new_token() -> String {
+	let nanos = SystemTime::now().duration_since( SystemTime::UNIX_EPOCH ).map(|d| d.as_nanos() ).unwrap_or( 0 );
+	let mut token = "__bw_".to_owned();
+
+	// Every `RandomState` has keys of its own, which are seeded with randomness of the system
+	for i in 0..2 {
+		let mut hasher = RandomState::new().build_hasher();
+		hasher.write_u128( nanos );
+		hasher.write_usize( i );
+		token += &format!( "{:016x}", hasher.finish() );
+	}
+	token
+}
+
+/// Checks that a call has come from the preload scripts, and takes the token off of it.
+/// Returns `None` if the call has come from the page itself.
+pub(in super) fn verify( token: &str, cmd: String, args: Vec<String> ) -> Option<(String, Vec<String>)> {
+	if cmd != token {
+		return None;
+	}
+
+	let mut args = args.into_iter();
+	let cmd = args.next()?;
+	Some( (cmd, args.collect()) )
+}
@@ -0,0 +1,309 @@
+use super::BrowserWindowHandle;
+
+use crate::ipc;
+
+use browser_window_core::browser_window::JsEvaluationError;
+use std::{
+	collections::BTreeMap,
+	fmt,
+	iter::Peekable,
+	str::Chars
+};
+
+
+
+/// A JavaScript value, as it is given by `BrowserWindowHandle::eval_js_value`.
+///
+/// `undefined`, and values that can't be represented in JSON like functions, become `Null`.
+/// And so do the numbers `NaN` and `Infinity`.
+/// It displays as JavaScript code that creates the value again.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsValue {
+	Null,
+	Bool( bool ),
+	Number( f64 ),
+	String( String ),
+	Array( Vec<JsValue> ),
+	Object( BTreeMap<String, JsValue> )
+}
+
+
+
+// Converts the completion value of the script to JSON in the page, so that the type of the value isn't lost on the way.
+// The script is evaluated with an indirect `eval`, so that it runs in the global scope, just like it does with `eval_js`.
+const TO_JSON_JS: &'static str = "(function(value) { var json = JSON.stringify(value); return json === undefined ? \"null\" : json; })((0, eval)";
+
+
+
+impl JsValue {
+
+	/// Gives the value of the boolean, if it is one.
+	pub fn as_bool( &self ) -> Option<bool> {
+		match self { Self::Bool( b ) => Some( *b ), _ => None }
+	}
+
+	/// Gives the value of the number, if it is one.
+	pub fn as_f64( &self ) -> Option<f64> {
+		match self { Self::Number( n ) => Some( *n ), _ => None }
+	}
+
+	/// Gives the contents of the string, if it is one.
+	pub fn as_str( &self ) -> Option<&str> {
+		match self { Self::String( s ) => Some( s ), _ => None }
+	}
+
+	/// Gives the property of the object with the given name, or the element of the array at the given index.
+	pub fn get( &self, key: &str ) -> Option<&JsValue> {
+		match self {
+			Self::Object( properties ) => properties.get( key ),
+			Self::Array( elements ) => key.parse::<usize>().ok().and_then(|i| elements.get( i ) ),
+			_ => None
+		}
+	}
+
+	pub fn is_null( &self ) -> bool {
+		*self == Self::Null
+	}
+
+	/// Parses the JSON text that the page has produced.
+	pub(in crate) fn from_json( json: &str ) -> Option<Self> {
+		let mut chars = json.chars().peekable();
+
+		let value = parse_value( &mut chars )?;
+		skip_whitespace( &mut chars );
+		if chars.next().is_some() {
+			return None;
+		}
+		Some( value )
+	}
+}
+
+impl fmt::Display for JsValue {
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
+		match self {
+			Self::Null => write!( f, "null" ),
+			Self::Bool( b ) => write!( f, "{}", b ),
+			Self::Number( n ) if n.is_nan() => write!( f, "NaN" ),
+			Self::Number( n ) if n.is_infinite() => write!( f, "{}Infinity", if *n < 0.0 {"-"} else {""} ),
+			Self::Number( n ) => write!( f, "{}", n ),
+			Self::String( s ) => write!( f, "{}", ipc::js_string_literal( s ) ),
+			Self::Array( elements ) => {
+				write!( f, "[" )?;
+				for (i, element) in elements.iter().enumerate() {
+					if i > 0 { write!( f, "," )? }
+					write!( f, "{}", element )?;
+				}
+				write!( f, "]" )
+			},
+			Self::Object( properties ) => {
+				write!( f, "{{" )?;
+				for (i, (name, value)) in properties.iter().enumerate() {
+					if i > 0 { write!( f, "," )? }
+					write!( f, "{}:{}", ipc::js_string_literal( name ), value )?;
+				}
+				write!( f, "}}" )
+			}
+		}
+	}
+}
+
+macro_rules! impl_from_number {
+	( $( $type:ty ),* ) => { $(
+		impl From<$type> for JsValue {
+			fn from( number: $type ) -> Self { Self::Number( number as f64 ) }
+		}
+	)* };
+}
+
+impl_from_number!( f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize );
+
+impl From<bool> for JsValue {
+	fn from( b: bool ) -> Self { Self::Bool( b ) }
+}
+
+impl From<&str> for JsValue {
+	fn from( s: &str ) -> Self { Self::String( s.to_owned() ) }
+}
+
+impl From<String> for JsValue {
+	fn from( s: String ) -> Self { Self::String( s ) }
+}
+
+impl From<&String> for JsValue {
+	fn from( s: &String ) -> Self { Self::String( s.clone() ) }
+}
+
+impl<T: Into<JsValue>> From<Option<T>> for JsValue {
+	fn from( option: Option<T> ) -> Self {
+		option.map( Into::into ).unwrap_or( Self::Null )
+	}
+}
+
+impl<T: Into<JsValue>> From<Vec<T>> for JsValue {
+	fn from( elements: Vec<T> ) -> Self {
+		Self::Array( elements.into_iter().map( Into::into ).collect() )
+	}
+}
+
+impl BrowserWindowHandle {
+
+	/// Like `eval_js`, but gives the result as a `JsValue`, so that booleans, numbers and strings can be told apart.
+	/// Arrays and objects are given with all their contents.
+	///
+	/// # Example
+	/// ```ignore
+	/// let checked = bw.eval_js_value( "document.getElementById('remember').checked" ).await?;
+	///
+	/// if checked.as_bool() == Some( true ) {
+	/// 	// ...
+	/// }
+	/// ```
+	pub async fn eval_js_value( &self, js: &str ) -> Result<JsValue, JsEvaluationError> {
+		let json = self.eval_js( &format!( "{}({}))", TO_JSON_JS, ipc::js_string_literal( js ) ) ).await?;
+
+		// The page could have replaced `JSON.stringify`, in which case the text is all there is
+		Ok( JsValue::from_json( &json ).unwrap_or( JsValue::String( json ) ) )
+	}
+
+	/// Calls the JavaScript function that `js` evaluates to with the given arguments, and gives the result in the same way as `eval_js` does.
+	/// The arguments are written into the call as JavaScript literals, so strings that come from the user can't break out of them.
+	/// Use `JsValue` as the type of the arguments to mix several types.
+	///
+	/// # Example
+	/// ```ignore
+	/// bw.eval_js_with_args( "(name, count) => greet(name, count)", &[JsValue::from( user_name ), JsValue::from( 3 )] ).await?;
+	/// ```
+	pub async fn eval_js_with_args<V>( &self, js: &str, args: &[V] ) -> Result<String, JsEvaluationError> where
+		V: Clone + Into<JsValue>
+	{
+		self.eval_js( &call_js( js, args ) ).await
+	}
+}
+
+
+
+fn parse_value( chars: &mut Peekable<Chars> ) -> Option<JsValue> {
+	skip_whitespace( chars );
+
+	match *chars.peek()? {
+		'n' => parse_word( chars, "null", JsValue::Null ),
+		't' => parse_word( chars, "true", JsValue::Bool( true ) ),
+		'f' => parse_word( chars, "false", JsValue::Bool( false ) ),
+		'"' => parse_string( chars ).map( JsValue::String ),
+		'[' => {
+			chars.next();
+			let mut elements = Vec::new();
+
+			skip_whitespace( chars );
+			if chars.peek() == Some( &']' ) {
+				chars.next();
+				return Some( JsValue::Array( elements ) );
+			}
+			loop {
+				elements.push( parse_value( chars )? );
+				skip_whitespace( chars );
+				match chars.next()? {
+					',' => continue,
+					']' => return Some( JsValue::Array( elements ) ),
+					_ => return None
+				}
+			}
+		},
+		'{' => {
+			chars.next();
+			let mut properties = BTreeMap::new();
+
+			skip_whitespace( chars );
+			if chars.peek() == Some( &'}' ) {
+				chars.next();
+				return Some( JsValue::Object( properties ) );
+			}
+			loop {
+				skip_whitespace( chars );
+				let name = parse_string( chars )?;
+				skip_whitespace( chars );
+				if chars.next()? != ':' { return None }
+				properties.insert( name, parse_value( chars )? );
+				skip_whitespace( chars );
+				match chars.next()? {
+					',' => continue,
+					'}' => return Some( JsValue::Object( properties ) ),
+					_ => return None
+				}
+			}
+		},
+		_ => {
+			let mut number = String::new();
+			while let Some( &c ) = chars.peek() {
+				if !( c.is_ascii_digit() || c == '-' || c == '+' || c == '.' || c == 'e' || c == 'E' ) { break }
+				number.push( c );
+				chars.next();
+			}
+			number.parse().ok().map( JsValue::Number )
+		}
+	}
+}
+
+fn parse_string( chars: &mut Peekable<Chars> ) -> Option<String> {
+	if chars.next()? != '"' { return None }
+	let mut string = String::new();
+
+	loop {
+		match chars.next()? {
+			'"' => return Some( string ),
+			'\\' => match chars.next()? {
+				'b' => string.push('\u{8}'),
+				'f' => string.push('\u{c}'),
+				'n' => string.push('\n'),
+				'r' => string.push('\r'),
+				't' => string.push('\t'),
+				'u' => {
+					let unit = parse_hex4( chars )?;
+
+					// Characters outside of the basic multilingual plane are written as a surrogate pair
+					if (0xD800..0xDC00).contains( &unit ) {
+						if chars.next()? != '\\' || chars.next()? != 'u' { return None }
+						let low = parse_hex4( chars )?;
+						let code = 0x10000 + ((unit - 0xD800) << 10) + (low.checked_sub( 0xDC00 )? & 0x3FF);
+						string.push( std::char::from_u32( code )? );
+					}
+					else {
+						string.push( std::char::from_u32( unit ).unwrap_or('\u{FFFD}') );
+					}
+				},
+				c => string.push( c )
+			},
+			c => string.push( c )
+		}
+	}
+}
+
+fn parse_hex4( chars: &mut Peekable<Chars> ) -> Option<u32> {
+	let mut value = 0;
+	for _ in 0..4 {
+		value = value * 16 + chars.next()?.to_digit( 16 )?;
+	}
+	Some( value )
+}
+
+fn parse_word( chars: &mut Peekable<Chars>, word: &str, value: JsValue ) -> Option<JsValue> {
+	for expected in word.chars() {
+		if chars.next()? != expected { return None }
+	}
+	Some( value )
+}
+
+fn skip_whitespace( chars: &mut Peekable<Chars> ) {
+	while chars.peek().map(|c| c.is_whitespace() ).unwrap_or( false ) {
+		chars.next();
+	}
+}
+
+/// Writes the call of the function that `js` evaluates to, with the arguments as JavaScript literals.
+pub(in crate) fn call_js<V>( js: &str, args: &[V] ) -> String where
+	V: Clone + Into<JsValue>
+{
+	let args: Vec<String> = args.iter().map(|a| a.clone().into().to_string() ).collect();
+
+	format!( "({})({})", js, args.join(",") )
+}
@@ -0,0 +1,237 @@
+use super::BrowserWindowHandle;
+#[cfg(feature = "threadsafe")]
+use super::BrowserWindowThreaded;
+use crate::prop::Property;
+
+
+
+/// An editable field of a page that has gained the focus, like an `<input>`, a `<textarea>` or an element with `contenteditable`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EditableField {
+	/// The position of the top left corner of the field on the screen, in the same units as the position of the window.
+	pub position: (i32, i32),
+	/// The size of the field on the screen, in the same units as the size of the window.
+	pub size: (u32, u32),
+	/// The kind of input that the field expects, which can help to choose a layout for the keyboard.
+	/// This is the value of the `inputmode` attribute if it has one, like `numeric`, `decimal`, `tel`, `email`, `url` or `search`.
+	/// Otherwise, it is derived from the type of the field, and is `text` for most fields.
+	///
+	/// A field with `inputmode="none"` provides its own way of input, so it doesn't show the keyboard automatically.
+	pub input_mode: String
+}
+
+/// An on-screen keyboard, for touch screens that don't have a hardware keyboard, like those of kiosks.
+/// The keyboard itself is supplied by the application, as a window of its own or as a keyboard of the system.
+///
+/// Once it is set with `BrowserWindowBuilder::virtual_keyboard`, it is shown whenever an editable field gains the focus, and hidden when the field loses it.
+/// It can also be shown and hidden at will, with `BrowserWindowHandle::show_virtual_keyboard` and `hide_virtual_keyboard`.
+///
+/// A keyboard that is a window of its own should not take the focus away from the browser window when it is clicked, like tool windows on Windows.
+/// Otherwise, the field loses the focus to the keyboard.
+pub trait VirtualKeyboard {
+	/// Shows the keyboard for the given field, preferably in a place where it doesn't cover the field.
+	/// This is also called while the keyboard is shown, when the focus moves to another field or when the field moves because the page scrolls.
+	fn show( &mut self, bw: BrowserWindowHandle, field: &EditableField );
+	/// Hides the keyboard.
+	fn hide( &mut self, bw: BrowserWindowHandle );
+}
+
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserFieldFocusHandler = Box<dyn FnMut(BrowserWindowHandle, Option<&EditableField>)>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserFieldFocusHandler = Box<dyn FnMut(BrowserWindowHandle, Option<&EditableField>) + Send>;
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserVirtualKeyboard = Box<dyn VirtualKeyboard>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserVirtualKeyboard = Box<dyn VirtualKeyboard + Send>;
+
+/// The virtual keyboard of a browser window, with the field that it is for.
+#[derive(Default)]
+pub(in super) struct KeyboardState {
+	/// The field that has the focus, if any.
+	pub focused: Option<EditableField>,
+	pub keyboard: Option<BrowserVirtualKeyboard>,
+	pub on_field_focus: Option<BrowserFieldFocusHandler>,
+	pub visible: bool
+}
+
+
+
+// The command that the page uses to report the focus of editable fields with.
+pub(in super) const KEYBOARD_COMMAND: &'static str = "__bw_keyboard";
+
+/// The JavaScript code that reports which editable field has the focus, and where it is.
+///
+/// The field is reported again when it moves, because the page scrolls or the window is resized.
+/// When the window itself loses the focus, like when the on-screen keyboard is clicked, the field is not reported to have lost it.
+pub(in super) const KEYBOARD_JS: &'static str = r#"(function() {
+	if (window.__bw_keyboard) return;
+	window.__bw_keyboard = true;
+	var NOT_EDITABLE = ["button", "checkbox", "color", "file", "hidden", "image", "radio", "range", "reset", "submit"];
+	var current = null;
+	var scheduled = false;
+
+	function editable(el) {
+		if (!el || el.disabled || el.readOnly) return false;
+		if (el.isContentEditable || el.tagName === "TEXTAREA") return true;
+		return el.tagName === "INPUT" && NOT_EDITABLE.indexOf(el.type) < 0;
+	}
+	function input_mode(el) {
+		if (el.inputMode) return el.inputMode;
+		switch (el.type) {
+			case "number": return "decimal";
+			case "email": case "tel": case "url": case "search": return el.type;
+			default: return "text";
+		}
+	}
+	function report() {
+		scheduled = false;
+		if (!current) return;
+		var r = current.getBoundingClientRect();
+		invoke_extern("__bw_keyboard", "focus", String(r.left), String(r.top), String(r.width), String(r.height), String(window.devicePixelRatio), input_mode(current));
+	}
+	function schedule() {
+		if (current && !scheduled) {
+			scheduled = true;
+			requestAnimationFrame(report);
+		}
+	}
+
+	document.addEventListener("focusin", function(e) {
+		if (editable(e.target)) { current = e.target; report(); }
+	}, true);
+	document.addEventListener("focusout", function() {
+		// The focus may move to another field right away, and the window may only have lost the focus as a whole
+		setTimeout(function() {
+			if (!current || !document.hasFocus()) return;
+			if (editable(document.activeElement)) return;
+			current = null;
+			invoke_extern("__bw_keyboard", "blur");
+		}, 0);
+	}, true);
+	window.addEventListener("scroll", schedule, true);
+	window.addEventListener("resize", schedule);
+})();
+"#;
+
+
+
+impl KeyboardState {
+
+	/// Whether the page needs to report its fields.
+	pub fn is_enabled( &self ) -> bool {
+		self.keyboard.is_some() || self.on_field_focus.is_some()
+	}
+}
+
+impl BrowserWindowHandle {
+
+	/// Gives the editable field that has the focus, if there is one.
+	/// This is only known when a virtual keyboard, or a handler for `BrowserWindowBuilder::on_field_focus` has been set.
+	pub fn focused_field( &self ) -> Option<EditableField> {
+		unsafe { &mut *self.user_data() }.keyboard.focused.clone()
+	}
+
+	/// Hides the virtual keyboard of `BrowserWindowBuilder::virtual_keyboard`, if it is shown.
+	/// It is shown again when another field gains the focus.
+	pub fn hide_virtual_keyboard( &self ) {
+		let state = &mut unsafe { &mut *self.user_data() }.keyboard;
+		if !state.visible {
+			return;
+		}
+		state.visible = false;
+
+		// The keyboard may call back into this window, so it isn't borrowed from the user data in the meantime
+		if let Some( mut keyboard ) = state.keyboard.take() {
+			keyboard.hide( *self );
+			unsafe { &mut *self.user_data() }.keyboard.keyboard = Some( keyboard );
+		}
+	}
+
+	/// Shows the virtual keyboard of `BrowserWindowBuilder::virtual_keyboard` for the field that has the focus, even if it has `inputmode="none"`.
+	/// Returns false if there is no virtual keyboard, or if no field has the focus.
+	pub fn show_virtual_keyboard( &self ) -> bool {
+		let state = &mut unsafe { &mut *self.user_data() }.keyboard;
+		let field = match state.focused.clone() {
+			None => return false,
+			Some( f ) => f
+		};
+
+		match state.keyboard.take() {
+			None => false,
+			Some( mut keyboard ) => {
+				state.visible = true;
+				keyboard.show( *self, &field );
+				unsafe { &mut *self.user_data() }.keyboard.keyboard = Some( keyboard );
+				true
+			}
+		}
+	}
+}
+
+#[cfg(feature = "threadsafe")]
+impl BrowserWindowThreaded {
+
+	/// Same as `BrowserWindowHandle::hide_virtual_keyboard`, but hides it from the GUI thread.
+	/// Returns false if the GUI thread can't be reached anymore.
+	pub fn hide_virtual_keyboard( &self ) -> bool {
+		self.dispatch(|bw| bw.hide_virtual_keyboard() )
+	}
+
+	/// Same as `BrowserWindowHandle::show_virtual_keyboard`, but shows it from the GUI thread.
+	/// Returns false if the GUI thread can't be reached anymore.
+	pub fn show_virtual_keyboard( &self ) -> bool {
+		self.dispatch(|bw| { bw.show_virtual_keyboard(); } )
+	}
+}
+
+
+
+/// Handles the focus of an editable field that the page has reported.
+pub(in super) fn handle( bw: BrowserWindowHandle, args: Vec<String> ) {
+	let field = match args.get(0).map(|a| a.as_str() ) {
+		// A focus that can't be placed on the screen is ignored
+		Some("focus") => match field_on_screen( bw, &args[1..] ) {
+			None => return,
+			Some( field ) => Some( field )
+		},
+		_ => None
+	};
+	unsafe { &mut *bw.user_data() }.keyboard.focused = field.clone();
+
+	if let Some( mut handler ) = unsafe { &mut *bw.user_data() }.keyboard.on_field_focus.take() {
+		handler( bw, field.as_ref() );
+		unsafe { &mut *bw.user_data() }.keyboard.on_field_focus = Some( handler );
+	}
+
+	match field {
+		Some( f ) if f.input_mode != "none" => { bw.show_virtual_keyboard(); },
+		_ => bw.hide_virtual_keyboard()
+	}
+}
+
+/// Converts the rectangle of a field within the page, in CSS pixels, to one on the screen.
+/// The content area is assumed to be centered horizontally within the borders of the window, and at the bottom of it.
+fn field_on_screen( bw: BrowserWindowHandle, args: &[String] ) -> Option<EditableField> {
+	let numbers: Vec<f64> = args.iter().take(5).map(|a| a.parse().ok() ).collect::<Option<_>>()?;
+	if numbers.len() < 5 {
+		return None;
+	}
+
+	let window = bw.window();
+	let position = window.position().get();
+	let outer = window.window_dimensions().get();
+	let content = window.content_dimensions().get();
+	let border = outer.width().saturating_sub( content.width() ) / 2;
+	let left = position.x() as f64 + border as f64;
+	let top = position.y() as f64 + outer.height().saturating_sub( content.height() ).saturating_sub( border ) as f64;
+
+	// The position of a window is in physical pixels on Windows, and in logical pixels elsewhere
+	let scale = if cfg!(windows) { numbers[4] } else { numbers[4] / window.scale_factor() as f64 };
+
+	Some( EditableField {
+		position: ((left + numbers[0] * scale).round() as i32, (top + numbers[1] * scale).round() as i32),
+		size: ((numbers[2] * scale).round() as u32, (numbers[3] * scale).round() as u32),
+		input_mode: args.get(5).cloned().unwrap_or_else(|| "text".to_owned() )
+	} )
+}
@@ -0,0 +1,240 @@
+use super::{emit, registry, BrowserWindowHandle};
+#[cfg(feature = "threadsafe")]
+use super::BrowserWindowThreaded;
+
+use crate::ipc;
+
+
+
+/// A message that a browser window has sent to another one with `BrowserWindowHandle::emit_to`.
+#[derive(Clone, Debug)]
+pub struct WindowMessage {
+	/// The name of the event, by which the message is subscribed to.
+	pub event: String,
+	/// The content of the message, which is any string, although JSON is a good fit.
+	pub payload: String,
+	/// The label of the window that has sent the message, if it has one.
+	pub from: Option<String>
+}
+
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserMessageHandler = Box<dyn FnMut(BrowserWindowHandle, &WindowMessage)>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserMessageHandler = Box<dyn FnMut(BrowserWindowHandle, &WindowMessage) + Send>;
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserPageEventHandler = Box<dyn FnMut(BrowserWindowHandle, &str)>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserPageEventHandler = Box<dyn FnMut(BrowserWindowHandle, &str) + Send>;
+
+
+
+// The command that the page uses to send messages to other windows with.
+pub(in super) const MESSAGE_COMMAND: &'static str = "__bw_emit";
+// The command that the page uses to emit events to its own window with.
+pub(in super) const EVENT_COMMAND: &'static str = "__bw_event";
+
+/// The JavaScript code that lets pages send messages to other windows, and subscribe to the messages that are sent to them.
+///
+/// In JavaScript, `bw_emit_to(label, event, payload)` sends a message, and `bw_subscribe(event, callback)` invokes `callback` with the payload and the label of the sender for every message of that event.
+///
+/// It also defines the global `bw` object, for the events between the page and its own window.
+/// `bw.emit(event, payload)` emits an event to the handlers of `BrowserWindowHandle::listen`.
+/// `bw.listen(event, callback)` invokes `callback` with the payload of every event that `BrowserWindowHandle::emit` emits, and gives back a function that stops listening.
+pub(in super) const MESSAGE_JS: &'static str = r#"(function() {
+	if (window.bw_subscribe) return;
+	var subscriptions = {};
+	var listeners = {};
+	window.bw = window.bw || {};
+	window.bw.listen = function(event, callback) {
+		var list = (listeners[event] = listeners[event] || []);
+		list.push(callback);
+		return function() {
+			var index = list.indexOf(callback);
+			if (index >= 0) list.splice(index, 1);
+		};
+	};
+	window.bw.emit = function(event, payload) {
+		invoke_extern("__bw_event", String(event), payload === undefined ? "" : String(payload));
+	};
+	window.__bw_event = function(event, payload) {
+		// Listeners may stop listening while the event is being delivered
+		(listeners[event] || []).slice().forEach(function(callback) { callback(payload); });
+	};
+	window.bw_subscribe = function(event, callback) {
+		(subscriptions[event] = subscriptions[event] || []).push(callback);
+	};
+	window.bw_emit_to = function(label, event, payload) {
+		invoke_extern("__bw_emit", String(label), String(event), payload === undefined ? "" : String(payload));
+	};
+	window.__bw_deliver = function(event, payload, from) {
+		(subscriptions[event] || []).forEach(function(callback) { callback(payload, from); });
+	};
+})();
+"#;
+
+
+
+impl BrowserWindowHandle {
+
+	/// Emits an event to the page of this window, where it is given to the callbacks that have been registered with `bw.listen(event, callback)`.
+	/// Together with `listen`, this forms a simple way of publishing events between the application and its pages.
+	/// Use `emit_to` to send messages to other windows instead.
+	///
+	/// The events are not remembered, so a page that is loaded afterwards doesn't get them.
+	///
+	/// # Example
+	/// ```ignore
+	/// bw.emit( "download-progress", "0.5" );
+	/// ```
+	/// And in the page:
+	/// ```js
+	/// let stop = bw.listen("download-progress", function(payload) { progress.value = Number(payload); });
+	/// ```
+	pub fn emit( &self, event: &str, payload: &str ) {
+		self.exec_js( &format!(
+			"window.__bw_event && window.__bw_event({}, {})",
+			ipc::js_string_literal( event ),
+			ipc::js_string_literal( payload )
+		) );
+	}
+
+	/// Invokes `handler` with the payload of every event of `event` that the page of this window emits with `bw.emit(event, payload)`.
+	/// A payload that is left out is given as an empty string.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn listen<H>( &self, event: &str, handler: H ) where
+		H: FnMut( BrowserWindowHandle, &str ) + 'static
+	{
+		unsafe { &mut *self.user_data() }.listeners.push( (event.to_owned(), Box::new( handler )) );
+	}
+
+	/// Invokes `handler` with the payload of every event of `event` that the page of this window emits with `bw.emit(event, payload)`.
+	/// A payload that is left out is given as an empty string.
+	#[cfg(feature = "threadsafe")]
+	pub fn listen<H>( &self, event: &str, handler: H ) where
+		H: FnMut( BrowserWindowHandle, &str ) + Send + 'static
+	{
+		unsafe { &mut *self.user_data() }.listeners.push( (event.to_owned(), Box::new( handler )) );
+	}
+
+	/// Sends a message to the window with the given label, see `BrowserWindowBuilder::label`.
+	/// The message is given to the handlers that the window has subscribed to `event` with, and to the callbacks that its page has subscribed with `bw_subscribe(event, callback)`.
+	/// Returns false if there is no window with that label.
+	///
+	/// Pages can send messages too, with `bw_emit_to(label, event, payload)`.
+	/// With feature `threadsafe`, messages can also be sent to sidecars by their label, see the `sidecar` module.
+	///
+	/// # Example
+	/// ```ignore
+	/// bw.emit_to( "settings", "theme-changed", r#"{"dark":true}"# );
+	/// ```
+	/// And in the page of the settings window:
+	/// ```js
+	/// bw_subscribe("theme-changed", function(payload, from) { applyTheme(JSON.parse(payload)); });
+	/// ```
+	pub fn emit_to( &self, label: &str, event: &str, payload: &str ) -> bool {
+		match registry::by_label( label ) {
+			#[cfg(not(feature = "threadsafe"))]
+			None => false,
+			#[cfg(feature = "threadsafe")]
+			None => crate::sidecar::emit_to( label, &WindowMessage {
+				event: event.to_owned(),
+				payload: payload.to_owned(),
+				from: self.label()
+			} ),
+			Some( target ) => {
+				deliver( target, WindowMessage {
+					event: event.to_owned(),
+					payload: payload.to_owned(),
+					from: self.label()
+				} );
+				true
+			}
+		}
+	}
+
+	/// Invokes `handler` for every message of `event` that is sent to this window with `emit_to`.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn subscribe<H>( &self, event: &str, handler: H ) where
+		H: FnMut( BrowserWindowHandle, &WindowMessage ) + 'static
+	{
+		unsafe { &mut *self.user_data() }.subscriptions.push( (event.to_owned(), Box::new( handler )) );
+	}
+
+	/// Invokes `handler` for every message of `event` that is sent to this window with `emit_to`.
+	#[cfg(feature = "threadsafe")]
+	pub fn subscribe<H>( &self, event: &str, handler: H ) where
+		H: FnMut( BrowserWindowHandle, &WindowMessage ) + Send + 'static
+	{
+		unsafe { &mut *self.user_data() }.subscriptions.push( (event.to_owned(), Box::new( handler )) );
+	}
+}
+
+#[cfg(feature = "threadsafe")]
+impl BrowserWindowThreaded {
+
+	/// Same as `BrowserWindowHandle::emit`, but emits the event from the GUI thread.
+	/// Returns false if the GUI thread can't be reached anymore.
+	pub fn emit( &self, event: &str, payload: &str ) -> bool {
+		let (event, payload) = (event.to_owned(), payload.to_owned());
+
+		self.dispatch(move |bw| {
+			bw.emit( &event, &payload );
+		})
+	}
+
+	/// Same as `BrowserWindowHandle::listen`, but registers the handler from the GUI thread.
+	/// Returns false if the GUI thread can't be reached anymore.
+	pub fn listen<H>( &self, event: &str, handler: H ) -> bool where
+		H: FnMut( BrowserWindowHandle, &str ) + Send + 'static
+	{
+		let event = event.to_owned();
+
+		self.dispatch(move |bw| {
+			bw.listen( &event, handler );
+		})
+	}
+
+	/// Same as `BrowserWindowHandle::emit_to`, but sends the message from the GUI thread.
+	/// Returns false if the GUI thread can't be reached anymore.
+	/// If there is no window with the given label, the message is dropped.
+	pub fn emit_to( &self, label: &str, event: &str, payload: &str ) -> bool {
+		let (label, event, payload) = (label.to_owned(), event.to_owned(), payload.to_owned());
+
+		self.dispatch(move |bw| {
+			bw.emit_to( &label, &event, &payload );
+		})
+	}
+}
+
+
+
+/// Handles an event that the page has emitted with `bw.emit`.
+pub(in super) fn handle_event( bw: BrowserWindowHandle, args: Vec<String> ) {
+	let mut args = args.into_iter();
+	let event = args.next().unwrap_or_default();
+	let payload = args.next().unwrap_or_default();
+
+	for (e, handler) in unsafe { &mut *bw.user_data() }.listeners.iter_mut() {
+		if *e == event {
+			handler( bw, &payload );
+		}
+	}
+}
+
+/// Gives the message to the Rust handlers and to the page of the target window, in between the other events of that window.
+pub(in crate) fn deliver( target: BrowserWindowHandle, message: WindowMessage ) {
+	emit::emit( target.id(), move || {
+		for (event, handler) in unsafe { &mut *target.user_data() }.subscriptions.iter_mut() {
+			if *event == message.event {
+				handler( target, &message );
+			}
+		}
+
+		target.exec_js( &format!(
+			"window.__bw_deliver && window.__bw_deliver({}, {}, {})",
+			ipc::js_string_literal( &message.event ),
+			ipc::js_string_literal( &message.payload ),
+			message.from.as_ref().map(|f| ipc::js_string_literal( f ) ).unwrap_or( "null".to_owned() )
+		) );
+	});
+}
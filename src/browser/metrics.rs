@@ -0,0 +1,74 @@
+use super::BrowserWindowHandle;
+#[cfg(feature = "threadsafe")]
+use super::BrowserWindowThreaded;
+
+#[cfg(feature = "threadsafe")]
+use crate::delegate::DispatchFuture;
+
+use browser_window_core::browser_window::JsEvaluationError;
+
+
+
+/// The resources that the page of a browser window uses, as they are given by `BrowserWindowHandle::metrics`.
+/// The amounts of memory are in bytes.
+///
+/// A field is `None` if the browser engine doesn't expose it.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Metrics {
+	/// The memory that the JavaScript engine has reserved for the heap of the page.
+	///
+	/// Neither CEF nor WebView2 tell how much memory the render process of a single window uses, so this is the closest there is.
+	/// Most of what a long-lived page leaks ends up in here.
+	pub memory_footprint: Option<u64>,
+	/// The part of the JavaScript heap that is actually in use.
+	pub js_heap_size: Option<u64>,
+	/// The number of nodes in the document of the main frame, including text nodes and comments.
+	pub dom_nodes: Option<u64>
+}
+
+
+
+// `performance.memory` is only there in Chromium based browsers, and is left out otherwise.
+const METRICS_JS: &'static str = "(function() {
+	var memory = window.performance && performance.memory;
+	var walker = document.createTreeWalker(document, NodeFilter.SHOW_ALL);
+	var nodes = 1;
+	while (walker.nextNode()) nodes++;
+	return {
+		memory: memory ? memory.totalJSHeapSize : null,
+		heap: memory ? memory.usedJSHeapSize : null,
+		nodes: nodes
+	};
+})()";
+
+
+
+impl BrowserWindowHandle {
+
+	/// Measures the resources that the page uses at the moment, so that the usage of long-lived windows can be monitored and reported.
+	/// See `Metrics` for what is measured.
+	///
+	/// The measurements are taken by JavaScript in the main frame, so they fail in the same way as `eval_js` does.
+	/// Counting the nodes of a large document takes a moment, so this isn't meant to be called many times a second.
+	pub async fn metrics( &self ) -> Result<Metrics, JsEvaluationError> {
+		let value = self.eval_js_value( METRICS_JS ).await?;
+		let get = |name: &str| value.get( name ).and_then(|v| v.as_f64() ).filter(|v| *v >= 0.0 ).map(|v| v as u64 );
+
+		Ok( Metrics {
+			memory_footprint: get( "memory" ),
+			js_heap_size: get( "heap" ),
+			dom_nodes: get( "nodes" )
+		} )
+	}
+}
+
+#[cfg(feature = "threadsafe")]
+impl BrowserWindowThreaded {
+
+	/// See `BrowserWindowHandle::metrics`.
+	pub fn metrics( &self ) -> DispatchFuture<Result<Metrics, JsEvaluationError>> {
+		self.dispatch_async(|bw| async move {
+			bw.metrics().await
+		})
+	}
+}
@@ -0,0 +1,173 @@
+use super::{BrowserWindowHandle, EditCommand, Favicon, FileChooserMode, InterceptedRequest, JsValue, Modifiers, TerminationReason};
+
+use std::path::PathBuf;
+
+
+
+/// The page of a browser window, as it is modelled by the mock backend.
+/// It is given by `BrowserWindowHandle::mock_page`.
+///
+/// The page has no DOM and runs no JavaScript.
+/// Instead, it records the addresses that it navigates to and the JavaScript that is evaluated in it, and gives that JavaScript the results that `on_eval` decides on.
+/// In the same way, it records the methods of the DevTools protocol that the features of the window call, and gives them the results that `on_devtools` decides on.
+/// The handlers of the browser window are still invoked from the event loop of the application, like with a browser engine, so the code around the window can be tested as it is.
+///
+/// **Note:** Only available with feature `mock` enabled.
+///
+/// # Example
+/// ```ignore
+/// let page = bw.mock_page();
+/// page.on_eval(|js| if js == "document.title" { Ok( "\"Inbox\"".into() ) } else { Ok( "undefined".into() ) } );
+///
+/// assert_eq!( bw.eval_js( "document.title" ).await.unwrap(), "\"Inbox\"" );
+/// assert_eq!( page.evaluated(), vec!["document.title"] );
+/// ```
+#[derive(Clone, Copy)]
+pub struct MockPage {
+	bw: BrowserWindowHandle
+}
+
+
+
+impl BrowserWindowHandle {
+
+	/// Gives the page model of this window, to script and inspect it from tests.
+	/// See `MockPage` for more information.
+	///
+	/// **Note:** Only available with feature `mock` enabled.
+	pub fn mock_page( &self ) -> MockPage {
+		MockPage { bw: *self }
+	}
+}
+
+impl MockPage {
+
+	/// The paths that the file choosers of the page have been answered with so far, in order.
+	/// A file chooser that has been cancelled has no paths.
+	pub fn chosen_files( &self ) -> Vec<Vec<PathBuf>> {
+		self.bw.inner.chosen_files().into_iter().map(|paths| paths.into_iter().map( PathBuf::from ).collect() ).collect()
+	}
+
+	/// The editing commands that have been executed in the page so far, in order.
+	pub fn commands( &self ) -> Vec<EditCommand> {
+		self.bw.inner.commands().into_iter().map( EditCommand::from_c ).collect()
+	}
+
+	/// All the methods of the DevTools protocol that have been called for the page so far, with their parameters, in order.
+	pub fn devtools_calls( &self ) -> Vec<(String, JsValue)> {
+		self.bw.inner.devtools_calls().into_iter()
+			.map(|(method, params)| (method, JsValue::from_json( &params ).unwrap_or( JsValue::Null )) )
+			.collect()
+	}
+
+	/// All the JavaScript code that has been evaluated or executed in the page so far, in order.
+	pub fn evaluated( &self ) -> Vec<String> {
+		self.bw.inner.evaluated()
+	}
+
+	/// Invokes the handler of the browser window, as if the page had called `invoke_extern` with the given command and arguments.
+	pub fn invoke( &self, cmd: &str, args: &[&str] ) {
+		self.bw.inner.invoke( cmd, args.iter().map(|a| (*a).to_owned() ).collect() );
+	}
+
+	/// The virtual key codes of the keys that the page has got so far, because the key handler hasn't handled them, in order.
+	pub fn keys( &self ) -> Vec<u16> {
+		self.bw.inner.keys()
+	}
+
+	/// All the addresses that the page has navigated to so far, in order.
+	/// This starts with the address that the window has been created with, unless it has been created with HTML.
+	pub fn navigations( &self ) -> Vec<String> {
+		self.bw.inner.navigations()
+	}
+
+	/// Sets the handler that decides the results of the methods of the DevTools protocol that are called for the page from now on.
+	/// It is given the method and its parameters, and returns the JSON of the result, or the message of an error.
+	/// Without one, all methods fail, as if the browser engine had no DevTools.
+	///
+	/// # Example
+	/// ```ignore
+	/// page.on_devtools(|method, _| match method {
+	/// 	"Page.getLayoutMetrics" => Ok( r#"{"cssLayoutViewport":{"pageX":0,"pageY":200}}"#.into() ),
+	/// 	_ => Err( "not supported".into() )
+	/// } );
+	/// ```
+	pub fn on_devtools<H>( &self, mut handler: H ) where
+		H: FnMut( &str, &JsValue ) -> Result<String, String> + 'static
+	{
+		self.bw.inner.set_devtools_handler( Box::new( move |method, params| {
+			handler( method, &JsValue::from_json( params ).unwrap_or( JsValue::Null ) )
+		} ) );
+	}
+
+	/// Sets the handler that decides the results of the JavaScript code that is evaluated in the page from now on.
+	/// It is given the code, and returns the result as a browser engine would give it back, like `true` or `"text"` with the quotes, or the message of a JavaScript error.
+	/// Without one, all code evaluates to `undefined`.
+	pub fn on_eval<H>( &self, handler: H ) where
+		H: FnMut( &str ) -> Result<String, String> + 'static
+	{
+		self.bw.inner.set_eval_handler( Box::new( handler ) );
+	}
+
+	/// Opens a file chooser, as if an `<input type="file">` that accepts the given types of files had been clicked.
+	/// It is given to the handler of `BrowserWindowBuilder::on_file_chooser`, and cancelled right away if there is none.
+	pub fn open_file_chooser( &self, mode: FileChooserMode, accept: &[&str] ) {
+		self.bw.inner.open_file_chooser( mode.to_c(), &accept.join( "\n" ) );
+	}
+
+	/// Presses and releases the given key, as if the user had typed it while the window had the focus.
+	/// Both are given to the handler of `BrowserWindowBuilder::on_key_event` first, and the page only gets the key if the press hasn't been handled.
+	pub fn press_key( &self, key_code: u16, modifiers: Modifiers ) {
+		self.bw.inner.press_key( key_code, modifiers.to_c() );
+	}
+
+	/// The number of times that the page has been reloaded, like by `BrowserWindowHandle::reload`.
+	pub fn reloads( &self ) -> usize {
+		self.bw.inner.reloads()
+	}
+
+	/// Lets the page make a request with the given method, URL and headers, as if it had loaded a resource or called `fetch`.
+	/// It is given to the handlers of `BrowserWindowBuilder::on_request`, and sent to the network right away if there are none.
+	pub fn request( &self, method: &str, url: &str, headers: &[(&str, &str)] ) {
+		let headers: String = headers.iter().map(|(n, v)| format!( "{}: {}\r\n", n, v ) ).collect();
+		self.bw.inner.request( method, url, &headers );
+	}
+
+	/// The requests that the page has sent to the network so far, in order, as they have been sent after their handlers have changed or redirected them.
+	/// Requests that have been blocked or answered by the application are not among them.
+	pub fn sent_requests( &self ) -> Vec<InterceptedRequest> {
+		self.bw.inner.sent_requests().into_iter().map(|(method, url, headers)| InterceptedRequest::new( &method, &url, &headers ) ).collect()
+	}
+
+	/// Changes the favicon of the page, as if the browser engine had downloaded another one.
+	pub fn set_favicon( &self, favicon: &Favicon ) {
+		let (data, width, height) = favicon.to_raw();
+		self.bw.inner.set_page_favicon( data, width, height );
+	}
+
+	/// Changes the text of the status bar, as if the mouse cursor had moved onto a link with the given address.
+	/// An empty text is as if it had moved off the link again.
+	pub fn set_status_text( &self, text: &str ) {
+		self.bw.inner.set_page_status_text( text );
+	}
+
+	/// Changes the title of the page, as if the page had set `document.title`.
+	pub fn set_title( &self, title: &str ) {
+		self.bw.inner.set_page_title( title );
+	}
+
+	/// Lets the page stop responding to input, as if its JavaScript had got stuck in a loop, or lets it respond again.
+	pub fn set_unresponsive( &self, unresponsive: bool ) {
+		self.bw.inner.set_page_unresponsive( unresponsive );
+	}
+
+	/// Lets the render process of the page terminate for the given reason, as if it had crashed.
+	pub fn terminate( &self, reason: TerminationReason ) {
+		self.bw.inner.terminate_render_process( reason.to_c() );
+	}
+
+	/// The browser window that the page belongs to.
+	pub fn window( &self ) -> BrowserWindowHandle {
+		self.bw
+	}
+}
@@ -0,0 +1,108 @@
+use super::BrowserWindowHandle;
+#[cfg(feature = "threadsafe")]
+use super::BrowserWindowThreaded;
+use crate::error::BrowserError;
+
+#[cfg(feature = "threadsafe")]
+use crate::delegate::DispatchFuture;
+
+use std::time::Duration;
+
+
+
+/// The conditions of the network that the page of a browser window is made to experience, by `BrowserWindowHandle::set_network_conditions`.
+/// This is meant for testing how a page copes with a slow or lost connection, without having to change the network itself.
+///
+/// The default conditions are those of the actual network.
+///
+/// # Example
+/// ```ignore
+/// // A slow mobile connection
+/// bw.set_network_conditions( &NetworkConditions {
+/// 	latency: Duration::from_millis( 400 ),
+/// 	download_throughput: Some( 50_000 ),
+/// 	upload_throughput: Some( 20_000 ),
+/// 	..Default::default()
+/// } ).await?;
+///
+/// bw.set_network_conditions( &NetworkConditions::offline() ).await?;
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NetworkConditions {
+	/// Whether every request fails, as if there were no connection at all.
+	/// `navigator.onLine` is `false` in the page while this is set.
+	pub offline: bool,
+	/// The time that is added to every request, before its response starts to arrive.
+	pub latency: Duration,
+	/// The most bytes per second that are received, or `None` for as many as the network gives.
+	pub download_throughput: Option<u64>,
+	/// The most bytes per second that are sent, or `None` for as many as the network takes.
+	pub upload_throughput: Option<u64>
+}
+
+
+
+impl NetworkConditions {
+
+	/// The conditions of having no connection at all.
+	pub fn offline() -> Self {
+		Self { offline: true, ..Default::default() }
+	}
+
+	/// The conditions as the parameters of the `Network.emulateNetworkConditions` method of the DevTools protocol, in which -1 disables a limit.
+	pub(in crate) fn to_cdp_params( &self ) -> String {
+		let throughput = |t: Option<u64>| t.map(|t| t as i64 ).unwrap_or( -1 );
+
+		format!(
+			"{{\"offline\":{},\"latency\":{},\"downloadThroughput\":{},\"uploadThroughput\":{}}}",
+			self.offline, self.latency.as_millis(), throughput( self.download_throughput ), throughput( self.upload_throughput )
+		)
+	}
+}
+
+impl BrowserWindowHandle {
+
+	/// Makes the page experience the given conditions of the network, from now on, like being offline or having a slow connection.
+	/// Set `NetworkConditions::default()` to go back to the actual network.
+	///
+	/// Only the requests of this window are affected, and connections that are already open, like those of WebSockets, are left as they are.
+	/// Results in `BrowserError::DevToolsFailed` if the browser engine can't emulate the conditions.
+	pub async fn set_network_conditions( &self, conditions: &NetworkConditions ) -> Result<(), BrowserError> {
+		// The conditions only apply once the network domain is enabled
+		self.call_devtools( "Network.enable", "{}" ).await
+			.map_err( BrowserError::DevToolsFailed )?;
+		self.call_devtools( "Network.emulateNetworkConditions", &conditions.to_cdp_params() ).await
+			.map_err( BrowserError::DevToolsFailed )?;
+		Ok(())
+	}
+
+	/// Removes everything from the HTTP cache of the browser engine, so that every resource is fetched again the next time it is loaded.
+	/// See also `reload_ignore_cache`.
+	///
+	/// The cache is shared by all browser windows of the application, so it is cleared for all of them.
+	/// Cookies and the data that pages have stored are kept, see `clear_origin_data` for those.
+	/// Results in `BrowserError::DevToolsFailed` if the browser engine can't clear the cache.
+	pub async fn clear_http_cache( &self ) -> Result<(), BrowserError> {
+		self.call_devtools( "Network.clearBrowserCache", "{}" ).await
+			.map_err( BrowserError::DevToolsFailed )?;
+		Ok(())
+	}
+}
+
+#[cfg(feature = "threadsafe")]
+impl BrowserWindowThreaded {
+
+	/// See `BrowserWindowHandle::clear_http_cache`.
+	pub fn clear_http_cache( &self ) -> DispatchFuture<Result<(), BrowserError>> {
+		self.dispatch_async(|bw| async move {
+			bw.clear_http_cache().await
+		})
+	}
+
+	/// See `BrowserWindowHandle::set_network_conditions`.
+	pub fn set_network_conditions( &self, conditions: NetworkConditions ) -> DispatchFuture<Result<(), BrowserError>> {
+		self.dispatch_async(|bw| async move {
+			bw.set_network_conditions( &conditions ).await
+		})
+	}
+}
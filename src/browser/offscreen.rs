@@ -0,0 +1,225 @@
+use super::BrowserWindowHandle;
+
+use browser_window_core::browser_window::{self, Rect as RectImpl};
+use std::os::raw::c_void;
+
+
+
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserAcceleratedPaintHandler = Box<dyn FnMut(BrowserWindowHandle, &AcceleratedPaint)>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserAcceleratedPaintHandler = Box<dyn FnMut(BrowserWindowHandle, &AcceleratedPaint) + Send>;
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserCursorHandler = Box<dyn FnMut(BrowserWindowHandle, Cursor)>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserCursorHandler = Box<dyn FnMut(BrowserWindowHandle, Cursor) + Send>;
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserPaintHandler = Box<dyn FnMut(BrowserWindowHandle, &Paint)>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserPaintHandler = Box<dyn FnMut(BrowserWindowHandle, &Paint) + Send>;
+#[cfg(not(feature = "threadsafe"))]
+pub(in super) type BrowserPopupHandler = Box<dyn FnMut(BrowserWindowHandle, Option<Rect>)>;
+#[cfg(feature = "threadsafe")]
+pub(in super) type BrowserPopupHandler = Box<dyn FnMut(BrowserWindowHandle, Option<Rect>) + Send>;
+
+/// The handlers of an offscreen browser window.
+#[derive(Default)]
+pub(in super) struct OffscreenHandlers {
+	pub on_accelerated_paint: Option<BrowserAcceleratedPaintHandler>,
+	pub on_cursor_change: Option<BrowserCursorHandler>,
+	pub on_paint: Option<BrowserPaintHandler>,
+	pub on_popup: Option<BrowserPopupHandler>
+}
+
+/// A frame that an offscreen browser has painted into a texture on the GPU, which it shares with the application.
+pub struct AcceleratedPaint {
+	pub(in super) is_popup: bool,
+	pub(in super) dirty_rects: Vec<Rect>,
+	pub(in super) shared_handle: *mut c_void
+}
+
+/// The mouse cursor that an offscreen browser wants to have displayed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cursor {
+	Default,
+	/// The hand that is displayed over links.
+	Pointer,
+	Text,
+	Wait,
+	/// An arrow with an hourglass or spinner, while the page is busy but can still be used.
+	Progress,
+	Crosshair,
+	Move,
+	NotAllowed,
+	Help,
+	ResizeEastWest,
+	ResizeNorthSouth,
+	ResizeNortheastSouthwest,
+	ResizeNorthwestSoutheast,
+	Grab,
+	Grabbing,
+	/// No cursor should be displayed at all.
+	Hidden
+}
+
+/// Which modifier keys and mouse buttons are held down, like for the input that is sent to offscreen browsers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Modifiers {
+	pub ctrl: bool,
+	pub shift: bool,
+	pub alt: bool,
+	/// The Windows key, or the command key on macOS.
+	pub meta: bool,
+	pub left_button: bool,
+	pub middle_button: bool,
+	pub right_button: bool
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MouseButton {
+	Left,
+	Middle,
+	Right
+}
+
+/// A frame that an offscreen browser has painted.
+pub struct Paint<'a> {
+	pub(in super) is_popup: bool,
+	pub(in super) width: u32,
+	pub(in super) height: u32,
+	pub(in super) dirty_rects: Vec<Rect>,
+	pub(in super) pixels: &'a [u8]
+}
+
+/// A rectangular area in pixels, like one within the view of a browser window, or the area of a display.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rect {
+	pub x: i32,
+	pub y: i32,
+	pub width: u32,
+	pub height: u32
+}
+
+
+
+impl AcceleratedPaint {
+
+	/// The areas that have changed since the previous frame.
+	pub fn dirty_rects( &self ) -> &[Rect] {
+		&self.dirty_rects
+	}
+
+	/// Whether or not this is a frame of a popup widget, see also `Paint::is_popup`.
+	pub fn is_popup( &self ) -> bool {
+		self.is_popup
+	}
+
+	/// The shared `HANDLE` of the D3D11 texture that contains the frame, in the BGRA format.
+	/// It can be opened on the application's own device with `ID3D11Device::OpenSharedResource`.
+	///
+	/// The browser keeps painting into the same texture, so its contents should be copied during the handler, rather than kept around.
+	pub fn shared_handle( &self ) -> *mut c_void {
+		self.shared_handle
+	}
+}
+
+impl Cursor {
+	pub(in super) fn from_c( cursor: u8 ) -> Self {
+		match cursor {
+			1 => Self::Pointer,
+			2 => Self::Text,
+			3 => Self::Wait,
+			4 => Self::Progress,
+			5 => Self::Crosshair,
+			6 => Self::Move,
+			7 => Self::NotAllowed,
+			8 => Self::Help,
+			9 => Self::ResizeEastWest,
+			10 => Self::ResizeNorthSouth,
+			11 => Self::ResizeNortheastSouthwest,
+			12 => Self::ResizeNorthwestSoutheast,
+			13 => Self::Grab,
+			14 => Self::Grabbing,
+			15 => Self::Hidden,
+			_ => Self::Default
+		}
+	}
+}
+
+impl Modifiers {
+	pub(in super) fn from_c( flags: u32 ) -> Self {
+		Self {
+			ctrl: flags & browser_window::MODIFIER_CTRL != 0,
+			shift: flags & browser_window::MODIFIER_SHIFT != 0,
+			alt: flags & browser_window::MODIFIER_ALT != 0,
+			meta: flags & browser_window::MODIFIER_SUPER != 0,
+			left_button: flags & browser_window::MODIFIER_LEFT_BUTTON != 0,
+			middle_button: flags & browser_window::MODIFIER_MIDDLE_BUTTON != 0,
+			right_button: flags & browser_window::MODIFIER_RIGHT_BUTTON != 0
+		}
+	}
+
+	pub(in super) fn to_c( &self ) -> u32 {
+		let flags = [
+			(self.ctrl, browser_window::MODIFIER_CTRL),
+			(self.shift, browser_window::MODIFIER_SHIFT),
+			(self.alt, browser_window::MODIFIER_ALT),
+			(self.meta, browser_window::MODIFIER_SUPER),
+			(self.left_button, browser_window::MODIFIER_LEFT_BUTTON),
+			(self.middle_button, browser_window::MODIFIER_MIDDLE_BUTTON),
+			(self.right_button, browser_window::MODIFIER_RIGHT_BUTTON)
+		];
+
+		flags.iter().filter(|(set, _)| *set ).fold( 0, |c, (_, flag)| c | flag )
+	}
+}
+
+impl MouseButton {
+	pub(in super) fn to_c( &self ) -> u8 {
+		match self {
+			Self::Left => 0,
+			Self::Middle => 1,
+			Self::Right => 2
+		}
+	}
+}
+
+impl<'a> Paint<'a> {
+
+	/// The areas that have changed since the previous frame.
+	/// Only these need to be uploaded again when the frame is kept in a texture.
+	pub fn dirty_rects( &self ) -> &[Rect] {
+		&self.dirty_rects
+	}
+
+	pub fn height( &self ) -> u32 {
+		self.height
+	}
+
+	/// Whether or not this is a frame of a popup widget, like the list of a `<select>` element.
+	/// Popup frames need to be drawn on top of the view, at the rect given to the popup handler.
+	pub fn is_popup( &self ) -> bool {
+		self.is_popup
+	}
+
+	/// The pixels of the frame in the BGRA format, row by row from the top, without any padding.
+	/// Their alpha is premultiplied.
+	pub fn pixels( &self ) -> &[u8] {
+		self.pixels
+	}
+
+	pub fn width( &self ) -> u32 {
+		self.width
+	}
+}
+
+impl From<RectImpl> for Rect {
+	fn from( rect: RectImpl ) -> Self {
+		Self {
+			x: rect.x,
+			y: rect.y,
+			width: rect.width.max( 0 ) as _,
+			height: rect.height.max( 0 ) as _
+		}
+	}
+}
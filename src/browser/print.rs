@@ -0,0 +1,206 @@
+use super::BrowserWindowHandle;
+#[cfg(feature = "threadsafe")]
+use super::BrowserWindowThreaded;
+use super::bytes;
+use crate::error::BrowserError;
+use crate::ipc::js_string_literal;
+
+#[cfg(feature = "threadsafe")]
+use crate::delegate::DispatchFuture;
+
+use std::ops::RangeInclusive;
+
+
+
+/// The sizes of paper that a page can be printed on.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PaperSize {
+	A3,
+	A4,
+	A5,
+	Letter,
+	Legal,
+	Tabloid,
+	/// A size of paper with the given width and height, in millimetres.
+	Custom { width: f64, height: f64 }
+}
+
+/// The margins around the content of a printed page, in millimetres.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Margins {
+	pub top: f64,
+	pub right: f64,
+	pub bottom: f64,
+	pub left: f64
+}
+
+/// How the page of a browser window is printed, by `BrowserWindowHandle::print_to_pdf` and `BrowserWindowHandle::print`.
+///
+/// The settings are given to the browser engine as they are, instead of being taken from the printer or the locale of the system.
+/// So a page printed with the same settings comes out the same on every machine.
+///
+/// # Example
+/// ```ignore
+/// let settings = PrintSettings {
+/// 	paper_size: PaperSize::Letter,
+/// 	landscape: true,
+/// 	footer: Some( "<div style=\"font-size: 8px\"><span class=\"pageNumber\"></span></div>".into() ),
+/// 	page_ranges: vec![1..=3],
+/// 	..Default::default()
+/// };
+/// let pdf = bw.print_to_pdf( &settings ).await?;
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrintSettings {
+	/// A4 by default.
+	pub paper_size: PaperSize,
+	/// 10 millimetres on every side by default.
+	pub margins: Margins,
+	/// Whether the paper is turned sideways.
+	pub landscape: bool,
+	/// The HTML that is printed at the top of every page.
+	/// Elements with the classes `date`, `title`, `url`, `pageNumber` and `totalPages` get the respective values put into them.
+	///
+	/// The header is printed inside the top margin, so that margin needs to be large enough to fit it.
+	pub header: Option<String>,
+	/// The HTML that is printed at the bottom of every page, in the same way as `header`.
+	pub footer: Option<String>,
+	/// The factor by which the content is scaled, between 0.1 and 2.
+	/// 1 by default.
+	pub scale: f64,
+	/// The pages to print, counting from 1.
+	/// All pages are printed if there are none.
+	pub page_ranges: Vec<RangeInclusive<u32>>,
+	/// Whether the backgrounds of elements are printed, like their colors and images.
+	pub print_background: bool
+}
+
+
+
+impl PaperSize {
+
+	/// The width and height of the paper in portrait, in millimetres.
+	pub fn dimensions( &self ) -> (f64, f64) {
+		match self {
+			Self::A3 => (297.0, 420.0),
+			Self::A4 => (210.0, 297.0),
+			Self::A5 => (148.0, 210.0),
+			Self::Letter => (215.9, 279.4),
+			Self::Legal => (215.9, 355.6),
+			Self::Tabloid => (279.4, 431.8),
+			Self::Custom { width, height } => (*width, *height)
+		}
+	}
+}
+
+impl Default for PaperSize {
+	fn default() -> Self { Self::A4 }
+}
+
+impl Margins {
+
+	/// The same margin on every side.
+	pub fn uniform( margin: f64 ) -> Self {
+		Self { top: margin, right: margin, bottom: margin, left: margin }
+	}
+}
+
+impl Default for Margins {
+	fn default() -> Self { Self::uniform( 10.0 ) }
+}
+
+impl PrintSettings {
+
+	/// The settings as the parameters of the `Page.printToPDF` method of the DevTools protocol, which measures in inches.
+	pub(in crate) fn to_cdp_params( &self ) -> String {
+		let inches = |mm: f64| mm / 25.4;
+		let (width, height) = self.paper_size.dimensions();
+		let mut params = format!(
+			"{{\"paperWidth\":{},\"paperHeight\":{},\"marginTop\":{},\"marginRight\":{},\"marginBottom\":{},\"marginLeft\":{},\"landscape\":{},\"scale\":{},\"printBackground\":{},\"preferCSSPageSize\":false",
+			inches( width ), inches( height ),
+			inches( self.margins.top ), inches( self.margins.right ), inches( self.margins.bottom ), inches( self.margins.left ),
+			self.landscape, self.scale, self.print_background
+		);
+
+		if self.header.is_some() || self.footer.is_some() {
+			// A template that isn't given is replaced by the default of the browser engine, which is the date and title, so it is left empty instead.
+			let template = |t: &Option<String>| js_string_literal( t.as_deref().unwrap_or( "<span></span>" ) );
+			params += &format!( ",\"displayHeaderFooter\":true,\"headerTemplate\":{},\"footerTemplate\":{}", template( &self.header ), template( &self.footer ) );
+		}
+
+		if self.page_ranges.len() > 0 {
+			let ranges: Vec<String> = self.page_ranges.iter().map(|r| format!( "{}-{}", r.start(), r.end() ) ).collect();
+			params += &format!( ",\"pageRanges\":{}", js_string_literal( &ranges.join( "," ) ) );
+		}
+
+		params.push('}');
+		params
+	}
+
+	/// The `@page` rule of CSS that lays out the paper in the same way, as far as CSS is able to.
+	fn to_page_css( &self ) -> String {
+		let (width, height) = self.paper_size.dimensions();
+		let (width, height) = if self.landscape { (height, width) } else { (width, height) };
+
+		format!(
+			"@page {{ size: {}mm {}mm; margin: {}mm {}mm {}mm {}mm; }}",
+			width, height, self.margins.top, self.margins.right, self.margins.bottom, self.margins.left
+		)
+	}
+}
+
+impl Default for PrintSettings {
+	fn default() -> Self {
+		Self {
+			paper_size: PaperSize::default(),
+			margins: Margins::default(),
+			landscape: false,
+			header: None,
+			footer: None,
+			scale: 1.0,
+			page_ranges: Vec::new(),
+			print_background: false
+		}
+	}
+}
+
+impl BrowserWindowHandle {
+
+	/// Opens the print dialog of the browser engine for the page, with the paper size, margins and orientation of the given settings already selected.
+	///
+	/// Only the settings that CSS can express are applied, so the header, footer, scale and page ranges are left to the user.
+	/// Use `print_to_pdf` to have all of them applied.
+	pub fn print( &self, settings: &PrintSettings ) {
+		self.exec_js( &format!( "(function() {{
+			var style = document.createElement('style');
+			style.textContent = {};
+			document.head.appendChild(style);
+			window.print();
+			style.remove();
+		}})()", js_string_literal( &settings.to_page_css() ) ) );
+	}
+
+	/// Prints the page to a PDF document, with the given settings, and gives back the document.
+	///
+	/// The page is printed as it is for the `print` media type of CSS.
+	/// Results in `BrowserError::PrintFailed` if the browser engine can't print the page, like when the window has been closed in the meantime.
+	pub async fn print_to_pdf( &self, settings: &PrintSettings ) -> Result<Vec<u8>, BrowserError> {
+		let result = self.call_devtools( "Page.printToPDF", &settings.to_cdp_params() ).await
+			.map_err( BrowserError::PrintFailed )?;
+
+		result.get( "data" ).and_then(|d| d.as_str() ).and_then( bytes::base64_decode )
+			.ok_or_else(|| BrowserError::PrintFailed( "the browser engine didn't give back a document".into() ) )
+	}
+}
+
+#[cfg(feature = "threadsafe")]
+impl BrowserWindowThreaded {
+
+	/// See `BrowserWindowHandle::print_to_pdf`.
+	pub fn print_to_pdf( &self, settings: PrintSettings ) -> DispatchFuture<Result<Vec<u8>, BrowserError>> {
+		self.dispatch_async(|bw| async move {
+			bw.print_to_pdf( &settings ).await
+		})
+	}
+}
+
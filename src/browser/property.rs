@@ -0,0 +1,256 @@
+use super::BrowserWindowHandle;
+#[cfg(feature = "threadsafe")]
+use super::{BrowserDelegateFuture, BrowserWindowThreaded};
+
+use crate::prelude::*;
+use crate::prop::PropertyWatchers;
+use super::events::BrowserEvent;
+
+
+
+/// The streams that follow the properties and the events of a browser window.
+#[derive(Default)]
+pub(in super) struct BrowserPropertyWatchers {
+	pub content_dimensions: PropertyWatchers<Dims2D>,
+	pub events: PropertyWatchers<BrowserEvent>,
+	pub opacity: PropertyWatchers<u8>,
+	pub position: PropertyWatchers<Pos2D>,
+	pub title: PropertyWatchers<String>,
+	pub visible: PropertyWatchers<bool>,
+	pub window_dimensions: PropertyWatchers<Dims2D>,
+	pub zoom: PropertyWatchers<f64>
+}
+
+type WatchersFn<G> = fn( &mut BrowserPropertyWatchers ) -> &mut PropertyWatchers<G>;
+
+/// A property of a browser window, which can be followed with `changes`.
+///
+/// The streams are notified whenever the property is set through a `BrowserProperty` or `BrowserPropertyThreaded`.
+/// The size and the position of the window are also reported when the user resizes or moves it.
+/// Other changes from outside, like the page changing its title, are not reported, although some of them are available from `BrowserWindowHandle::events`.
+pub struct BrowserProperty<'a,G,S> {
+	handle: &'a BrowserWindowHandle,
+	get: fn( &BrowserWindowHandle ) -> G,
+	set: fn( &BrowserWindowHandle, S ),
+	watchers: WatchersFn<G>
+}
+
+/// **Note:** Only available with feature `threadsafe` enabled.
+///
+/// A property of a browser window that is accessed from another thread than the GUI thread.
+/// `get` and `set` return futures that resolve once they have been carried out on the GUI thread.
+/// See also `BrowserProperty`.
+#[cfg(feature = "threadsafe")]
+pub struct BrowserPropertyThreaded<'a,G,S> {
+	handle: &'a BrowserWindowThreaded,
+	get: fn( &BrowserWindowHandle ) -> G,
+	set: fn( &BrowserWindowHandle, S ),
+	watchers: WatchersFn<G>
+}
+
+
+
+impl<'a,G,S> BrowserProperty<'a,G,S> where G: Clone {
+
+	/// Gives a stream of the new values of the property, every time it changes.
+	pub fn changes( &self ) -> PropertyChanges<G> {
+		(self.watchers)( &mut unsafe { &mut *self.handle.user_data() }.watchers ).watch()
+	}
+}
+
+impl<'a,G,S> Property<G,S> for BrowserProperty<'a,G,S> where G: Clone {
+
+	fn get( &self ) -> G {
+		(self.get)( self.handle )
+	}
+
+	fn set( &self, value: S ) {
+		(self.set)( self.handle, value );
+
+		// The value is read back, because the platform may not have taken it as it is
+		let new_value = self.get();
+		(self.watchers)( &mut unsafe { &mut *self.handle.user_data() }.watchers ).notify( new_value );
+	}
+}
+
+#[cfg(feature = "threadsafe")]
+impl<'a,G,S> BrowserPropertyThreaded<'a,G,S> where
+	G: Clone + Send + 'static,
+	S: Send + 'static
+{
+	/// Gives a stream of the new values of the property, every time it changes.
+	/// The stream starts following the property once the GUI thread gets to it, so changes that happen before that are missed.
+	pub fn changes( &self ) -> PropertyChanges<G> {
+		let (sender, changes) = PropertyChanges::new();
+		let watchers = self.watchers;
+
+		// If the window can't be reached anymore, the sender is dropped, which ends the stream right away.
+		self.handle.dispatch(move |bw| {
+			watchers( &mut unsafe { &mut *bw.user_data() }.watchers ).add( sender );
+		});
+		changes
+	}
+
+	/// Reads the property on the GUI thread.
+	pub fn get( &self ) -> BrowserDelegateFuture<'static,G> {
+		let get = self.get;
+
+		self.handle.delegate(move |bw| get( &bw ) )
+	}
+
+	/// Changes the property on the GUI thread.
+	pub fn set( &self, value: S ) -> BrowserDelegateFuture<'static,()> {
+		let set = self.set;
+
+		self.handle.delegate(move |bw| set( &bw, value ) )
+	}
+}
+
+
+
+impl BrowserWindowHandle {
+
+	/// Gets or sets the width and height of the content of the window.
+	pub fn content_dimensions( &self ) -> BrowserProperty<'_,Dims2D,Dims2D> {
+		BrowserProperty {
+			handle: self,
+			get: |bw| bw.window().content_dimensions().get(),
+			set: |bw, size| bw.window().content_dimensions().set( size ),
+			watchers: |w| &mut w.content_dimensions
+		}
+	}
+
+	/// Gets or sets the opacity of the window, see `WindowHandle::opacity`.
+	pub fn opacity( &self ) -> BrowserProperty<'_,u8,u8> {
+		BrowserProperty {
+			handle: self,
+			get: |bw| bw.window().opacity().get(),
+			set: |bw, opacity| bw.window().opacity().set( opacity ),
+			watchers: |w| &mut w.opacity
+		}
+	}
+
+	/// Gets or sets the position of the window.
+	pub fn position( &self ) -> BrowserProperty<'_,Pos2D,Pos2D> {
+		BrowserProperty {
+			handle: self,
+			get: |bw| bw.window().position().get(),
+			set: |bw, position| bw.window().position().set( position ),
+			watchers: |w| &mut w.position
+		}
+	}
+
+	/// Gets or sets the title of the window.
+	pub fn title<'a>( &'a self ) -> BrowserProperty<'a,String,&'a str> {
+		BrowserProperty {
+			handle: self,
+			get: |bw| bw.window().title().get(),
+			set: |bw, title| bw.window().title().set( title ),
+			watchers: |w| &mut w.title
+		}
+	}
+
+	/// Gets or sets whether or not the window is visible.
+	pub fn visible( &self ) -> BrowserProperty<'_,bool,bool> {
+		BrowserProperty {
+			handle: self,
+			get: |bw| bw.window().visible().get(),
+			set: |bw, visible| bw.window().visible().set( visible ),
+			watchers: |w| &mut w.visible
+		}
+	}
+
+	/// Gets or sets the size of the window, including its border and titlebar.
+	pub fn window_dimensions( &self ) -> BrowserProperty<'_,Dims2D,Dims2D> {
+		BrowserProperty {
+			handle: self,
+			get: |bw| bw.window().window_dimensions().get(),
+			set: |bw, size| bw.window().window_dimensions().set( size ),
+			watchers: |w| &mut w.window_dimensions
+		}
+	}
+
+	/// Gets or sets the zoom factor of the page, where 1.0 is 100%.
+	pub fn zoom( &self ) -> BrowserProperty<'_,f64,f64> {
+		BrowserProperty {
+			handle: self,
+			get: |bw| bw.inner.get_zoom(),
+			set: |bw, factor| bw.inner.set_zoom( factor ),
+			watchers: |w| &mut w.zoom
+		}
+	}
+}
+
+#[cfg(feature = "threadsafe")]
+impl BrowserWindowThreaded {
+
+	/// See `BrowserWindowHandle::content_dimensions`.
+	pub fn content_dimensions( &self ) -> BrowserPropertyThreaded<'_,Dims2D,Dims2D> {
+		BrowserPropertyThreaded {
+			handle: self,
+			get: |bw| bw.content_dimensions().get(),
+			set: |bw, size| bw.content_dimensions().set( size ),
+			watchers: |w| &mut w.content_dimensions
+		}
+	}
+
+	/// See `BrowserWindowHandle::opacity`.
+	pub fn opacity( &self ) -> BrowserPropertyThreaded<'_,u8,u8> {
+		BrowserPropertyThreaded {
+			handle: self,
+			get: |bw| bw.opacity().get(),
+			set: |bw, opacity| bw.opacity().set( opacity ),
+			watchers: |w| &mut w.opacity
+		}
+	}
+
+	/// See `BrowserWindowHandle::position`.
+	pub fn position( &self ) -> BrowserPropertyThreaded<'_,Pos2D,Pos2D> {
+		BrowserPropertyThreaded {
+			handle: self,
+			get: |bw| bw.position().get(),
+			set: |bw, position| bw.position().set( position ),
+			watchers: |w| &mut w.position
+		}
+	}
+
+	/// See `BrowserWindowHandle::title`.
+	pub fn title( &self ) -> BrowserPropertyThreaded<'_,String,String> {
+		BrowserPropertyThreaded {
+			handle: self,
+			get: |bw| bw.title().get(),
+			set: |bw, title| bw.title().set( &title ),
+			watchers: |w| &mut w.title
+		}
+	}
+
+	/// See `BrowserWindowHandle::visible`.
+	pub fn visible( &self ) -> BrowserPropertyThreaded<'_,bool,bool> {
+		BrowserPropertyThreaded {
+			handle: self,
+			get: |bw| bw.visible().get(),
+			set: |bw, visible| bw.visible().set( visible ),
+			watchers: |w| &mut w.visible
+		}
+	}
+
+	/// See `BrowserWindowHandle::window_dimensions`.
+	pub fn window_dimensions( &self ) -> BrowserPropertyThreaded<'_,Dims2D,Dims2D> {
+		BrowserPropertyThreaded {
+			handle: self,
+			get: |bw| bw.window_dimensions().get(),
+			set: |bw, size| bw.window_dimensions().set( size ),
+			watchers: |w| &mut w.window_dimensions
+		}
+	}
+
+	/// See `BrowserWindowHandle::zoom`.
+	pub fn zoom( &self ) -> BrowserPropertyThreaded<'_,f64,f64> {
+		BrowserPropertyThreaded {
+			handle: self,
+			get: |bw| bw.zoom().get(),
+			set: |bw, factor| bw.zoom().set( factor ),
+			watchers: |w| &mut w.zoom
+		}
+	}
+}
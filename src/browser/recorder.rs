@@ -0,0 +1,300 @@
+use super::{registry, BrowserWindowHandle};
+
+use futures_channel::oneshot;
+use std::{
+	fmt,
+	str::FromStr,
+	sync::atomic::{AtomicU64, Ordering},
+	time::Duration
+};
+
+
+
+/// An input event of the user, as it is recorded by `BrowserWindowHandle::record_interactions`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InputEvent {
+	/// The time at which the event has occurred, since the start of the recording.
+	pub time: Duration,
+	pub kind: InputEventKind
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputEventKind {
+	/// A click at the given position in the viewport, in CSS pixels, with the given mouse button: 0 for the main button, 1 for the middle one and 2 for the secondary one.
+	Click { x: f64, y: f64, button: u8 },
+	/// A pressed key, as the `key` property of the JavaScript `KeyboardEvent`, like `a`, `Enter` or `ArrowLeft`.
+	Key( String ),
+	/// The page has been scrolled to the given offset, in CSS pixels.
+	Scroll { x: f64, y: f64 }
+}
+
+/// The input events that have been recorded from a page, which can be replayed with `BrowserWindowHandle::replay`.
+///
+/// A script is serialized with `to_string`, and parsed again with `parse`.
+/// Every event is written on its own line, starting with its time in milliseconds:
+/// ```text
+/// 1200 click 120.5 48 0
+/// 1850 key H
+/// 1900 key i
+/// 3020 scroll 0 640
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InteractionScript {
+	/// The events in the order in which they have occurred.
+	pub events: Vec<InputEvent>
+}
+
+/// The error of parsing an `InteractionScript`, which tells the line (starting at 1) that isn't a valid event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InteractionScriptError {
+	pub line: usize
+}
+
+/// A recording that is in progress.
+pub(in super) struct Recording {
+	events: Vec<InputEvent>,
+	done: oneshot::Sender<InteractionScript>,
+	id: u64
+}
+
+
+
+// Every recording gets its own identifier, so that the timer of a recording that has already ended doesn't end another one.
+static NEXT_RECORDING_ID: AtomicU64 = AtomicU64::new( 0 );
+
+// The command that the recording script reports the input events with.
+pub(in super) const RECORD_COMMAND: &'static str = "__bw_record";
+
+// The JavaScript code that listens for the input events of the user, until `__bw_record_stop` is called.
+// Events that have been dispatched by a script, like the ones of a replay, are not trusted and are left out.
+const RECORD_JS: &'static str = r#"(function() {
+	if (window.__bw_record_stop) window.__bw_record_stop();
+	var start = performance.now();
+	var last_scroll = null;
+	function time() { return String(Math.round(performance.now() - start)); }
+	function onClick(e) {
+		if (e.isTrusted) invoke_extern("__bw_record", time(), "click", String(e.clientX), String(e.clientY), String(e.button));
+	}
+	function onKey(e) {
+		if (e.isTrusted) invoke_extern("__bw_record", time(), "key", e.key);
+	}
+	function onScroll(e) {
+		var position = window.scrollX + " " + window.scrollY;
+		if (!e.isTrusted || position === last_scroll) return;
+		last_scroll = position;
+		invoke_extern("__bw_record", time(), "scroll", String(window.scrollX), String(window.scrollY));
+	}
+	document.addEventListener("click", onClick, true);
+	document.addEventListener("auxclick", onClick, true);
+	document.addEventListener("keydown", onKey, true);
+	window.addEventListener("scroll", onScroll, true);
+	window.__bw_record_stop = function() {
+		document.removeEventListener("click", onClick, true);
+		document.removeEventListener("auxclick", onClick, true);
+		document.removeEventListener("keydown", onKey, true);
+		window.removeEventListener("scroll", onScroll, true);
+		delete window.__bw_record_stop;
+	};
+})();
+"#;
+
+// The JavaScript code that dispatches the events of a script, which is given to it as `events`.
+// Synthetic key presses don't type anything, so printable keys are inserted into the focused field.
+const REPLAY_JS: &'static str = r#"(function(events) {
+	events.forEach(function(event) {
+		setTimeout(function() {
+			if (event[1] === "click") {
+				var target = document.elementFromPoint(event[2], event[3]) || document.body;
+				var options = { bubbles: true, cancelable: true, view: window, clientX: event[2], clientY: event[3], button: event[4] };
+				target.dispatchEvent(new MouseEvent("mousedown", options));
+				target.dispatchEvent(new MouseEvent("mouseup", options));
+				target.dispatchEvent(new MouseEvent(event[4] === 0 ? "click" : "auxclick", options));
+				if (event[4] === 0 && target.focus) target.focus();
+			}
+			else if (event[1] === "key") {
+				var target = document.activeElement || document.body;
+				var options = { bubbles: true, cancelable: true, key: event[2] };
+				var proceed = target.dispatchEvent(new KeyboardEvent("keydown", options));
+				if (proceed && event[2].length === 1 && (target.isContentEditable || target.tagName === "INPUT" || target.tagName === "TEXTAREA"))
+					document.execCommand("insertText", false, event[2]);
+				target.dispatchEvent(new KeyboardEvent("keyup", options));
+			}
+			else if (event[1] === "scroll") {
+				window.scrollTo(event[2], event[3]);
+			}
+		}, event[0]);
+	});
+})"#;
+
+
+
+impl InteractionScript {
+
+	/// The time of the last event, which is how long it takes to replay the script.
+	pub fn duration( &self ) -> Duration {
+		self.events.last().map(|e| e.time ).unwrap_or_default()
+	}
+}
+
+impl fmt::Display for InteractionScript {
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
+		for event in &self.events {
+			let time = event.time.as_millis();
+
+			// The key is the rest of the line, because it can be a space.
+			match &event.kind {
+				InputEventKind::Click { x, y, button } => writeln!( f, "{} click {} {} {}", time, x, y, button )?,
+				InputEventKind::Key( key ) => writeln!( f, "{} key {}", time, key )?,
+				InputEventKind::Scroll { x, y } => writeln!( f, "{} scroll {} {}", time, x, y )?
+			}
+		}
+		Ok(())
+	}
+}
+
+impl FromStr for InteractionScript {
+	type Err = InteractionScriptError;
+
+	fn from_str( string: &str ) -> Result<Self, Self::Err> {
+		let mut events = Vec::new();
+
+		for (index, line) in string.lines().enumerate() {
+			if line.trim().is_empty() { continue }
+
+			let event = parse_line( line ).ok_or( InteractionScriptError { line: index + 1 } )?;
+			events.push( event );
+		}
+		Ok( Self { events } )
+	}
+}
+
+impl fmt::Display for InteractionScriptError {
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
+		write!( f, "invalid input event at line {}", self.line )
+	}
+}
+
+impl std::error::Error for InteractionScriptError {}
+
+impl Recording {
+
+	/// Gives the recorded events to the one that is waiting for them.
+	pub fn finish( self ) {
+		// The future may have been dropped already, which is fine.
+		let _ = self.done.send( InteractionScript { events: self.events } );
+	}
+
+	/// Adds an event that the recording script has reported.
+	pub fn record( &mut self, args: &[String] ) {
+		let params: Vec<&str> = args.iter().map(|a| a.as_str() ).collect();
+
+		if let Some( event ) = parse_params( &params ) {
+			self.events.push( event );
+		}
+	}
+}
+
+impl BrowserWindowHandle {
+
+	/// Records the clicks, key presses and scrolls of the user in the page, for the given duration.
+	/// Gives the recorded events once the time is up, or once the window has been closed.
+	/// Starting another recording ends the one that is in progress.
+	///
+	/// Only the events of the current page are recorded, so the recording stops early when the window navigates to another page.
+	///
+	/// # Example
+	/// ```ignore
+	/// let script = bw.record_interactions( Duration::from_secs( 30 ) ).await;
+	/// std::fs::write( "bug-report.txt", script.to_string() )?;
+	/// ```
+	pub async fn record_interactions( &self, duration: Duration ) -> InteractionScript {
+		let (tx, rx) = oneshot::channel::<InteractionScript>();
+
+		if let Some( recording ) = unsafe { &mut *self.user_data() }.recording.take() {
+			recording.finish();
+		}
+		let id = NEXT_RECORDING_ID.fetch_add( 1, Ordering::Relaxed );
+
+		unsafe { &mut *self.user_data() }.recording = Some( Recording {
+			events: Vec::new(),
+			done: tx,
+			id
+		} );
+		self.exec_internal_js( RECORD_JS );
+
+		// The window may be gone by the time the recording is over
+		let window_id = self.id();
+		self.app().set_timeout( duration, move |_| {
+			if let Some( bw ) = registry::by_id( window_id ) {
+				if unsafe { &mut *bw.user_data() }.recording.as_ref().map(|r| r.id ) == Some( id ) {
+					bw.exec_js( "window.__bw_record_stop && window.__bw_record_stop()" );
+					unsafe { &mut *bw.user_data() }.recording.take().unwrap().finish();
+				}
+			}
+		});
+
+		rx.await.unwrap_or_default()
+	}
+
+	/// Injects the events of the given script into the page, at the same pace at which they have been recorded.
+	/// Resolves once the last event has been injected.
+	///
+	/// The events are dispatched by JavaScript, so they are not trusted, like the events of the user are.
+	/// Pages that check `Event.isTrusted`, and the browser's own behavior that needs a gesture of the user, like opening popups, react differently to them.
+	pub async fn replay( &self, script: &InteractionScript ) {
+		let events: Vec<String> = script.events.iter().map(|event| {
+			let time = event.time.as_millis();
+
+			match &event.kind {
+				InputEventKind::Click { x, y, button } => format!( "[{},\"click\",{},{},{}]", time, x, y, button ),
+				InputEventKind::Key( key ) => format!( "[{},\"key\",{}]", time, crate::ipc::js_string_literal( key ) ),
+				InputEventKind::Scroll { x, y } => format!( "[{},\"scroll\",{},{}]", time, x, y )
+			}
+		}).collect();
+
+		self.exec_js( &format!( "{}([{}])", REPLAY_JS, events.join(",") ) );
+		self.app().sleep( script.duration() ).await;
+	}
+}
+
+
+
+fn parse_line( line: &str ) -> Option<InputEvent> {
+	let mut parts = line.splitn( 3, ' ' );
+	let time = parts.next()?;
+	let kind = parts.next()?;
+	let rest = parts.next().unwrap_or("");
+
+	// Everything after the kind is the key, while the other kinds have numbers separated by spaces
+	if kind == "key" {
+		parse_params( &[time, kind, rest] )
+	}
+	else {
+		let mut params = vec![time, kind];
+		params.extend( rest.split_whitespace() );
+		parse_params( &params )
+	}
+}
+
+fn parse_params( params: &[&str] ) -> Option<InputEvent> {
+	let time = Duration::from_millis( params.get(0)?.parse().ok()? );
+	let number = |i: usize| -> Option<f64> {
+		params.get( i )?.parse::<f64>().ok().filter(|n| n.is_finite() )
+	};
+
+	let kind = match *params.get(1)? {
+		"click" if params.len() == 5 => InputEventKind::Click {
+			x: number(2)?,
+			y: number(3)?,
+			button: params[4].parse().ok()?
+		},
+		"key" if params.len() == 3 && params[2].len() > 0 => InputEventKind::Key( params[2].to_owned() ),
+		"scroll" if params.len() == 4 => InputEventKind::Scroll {
+			x: number(2)?,
+			y: number(3)?
+		},
+		_ => return None
+	};
+
+	Some( InputEvent { time, kind } )
+}
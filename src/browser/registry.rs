@@ -0,0 +1,38 @@
+use std::cell::RefCell;
+
+use super::BrowserWindowHandle;
+use crate::window::WindowId;
+
+
+
+thread_local! {
+	// Browser windows only exist on the GUI thread, so that is where they are kept track of, in the order in which they have been created.
+	static WINDOWS: RefCell<Vec<BrowserWindowHandle>> = RefCell::new( Vec::new() );
+}
+
+
+
+/// Keeps track of a browser window that has just been created.
+pub(in crate) fn register( handle: BrowserWindowHandle ) {
+	WINDOWS.with(|windows| windows.borrow_mut().push( handle ) );
+}
+
+/// Forgets a browser window that is being destroyed.
+pub(in crate) fn unregister( id: WindowId ) {
+	WINDOWS.with(|windows| windows.borrow_mut().retain(|w| w.id() != id ) );
+}
+
+/// Gives all browser windows that haven't been destroyed.
+pub(in crate) fn all() -> Vec<BrowserWindowHandle> {
+	WINDOWS.with(|windows| windows.borrow().clone() )
+}
+
+/// Gives the browser window with the given label, or the first one if there are several.
+pub(in crate) fn by_label( label: &str ) -> Option<BrowserWindowHandle> {
+	all().into_iter().find(|w| unsafe { &mut *w.user_data() }.label.as_deref() == Some( label ) )
+}
+
+/// Gives the browser window with the given identifier, if it hasn't been destroyed.
+pub(in crate) fn by_id( id: WindowId ) -> Option<BrowserWindowHandle> {
+	WINDOWS.with(|windows| windows.borrow().iter().find(|w| w.id() == id ).copied() )
+}
@@ -0,0 +1,36 @@
+/// Why the render process of a page has terminated, as it is given by `BrowserWindowBuilder::on_render_process_terminated`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TerminationReason {
+	/// The process has exited with an error, or for a reason that the browser engine doesn't tell.
+	Abnormal,
+	/// The process has been killed, like by the task manager of the system.
+	Killed,
+	/// The process has crashed.
+	Crashed,
+	/// The process has run out of memory.
+	OutOfMemory
+}
+
+
+
+impl TerminationReason {
+
+	pub(in super) fn from_c( reason: u8 ) -> Self {
+		match reason {
+			1 => Self::Killed,
+			2 => Self::Crashed,
+			3 => Self::OutOfMemory,
+			_ => Self::Abnormal
+		}
+	}
+
+	#[cfg(feature = "mock")]
+	pub(in super) fn to_c( &self ) -> u8 {
+		match self {
+			Self::Abnormal => 0,
+			Self::Killed => 1,
+			Self::Crashed => 2,
+			Self::OutOfMemory => 3
+		}
+	}
+}
@@ -0,0 +1,153 @@
+use browser_window_core::browser_window::{InterceptedRequestExt, InterceptedRequestImpl};
+
+use super::SchemeResponse;
+use super::scheme::Responder;
+
+
+
+/// A request of the page that has been intercepted before it is sent, as it is given to the handler of `BrowserWindowBuilder::on_request`.
+///
+/// Its headers can be changed before it is sent on with `RequestAction::Continue`.
+pub struct InterceptedRequest {
+	headers: Vec<(String, String)>,
+	method: String,
+	url: String
+}
+
+/// What happens to an intercepted request, as decided by the handler of `BrowserWindowBuilder::on_request`.
+pub enum RequestAction {
+	/// Fails the request, as if the network had refused it.
+	///
+	/// *Note:* WebView2 can't fail requests, so it answers them with status 403 instead.
+	Block,
+	/// Sends the request on, with the headers that it has by then.
+	Continue,
+	/// Sends the request to the given URL instead.
+	///
+	/// *Note:* With WebView2, the page doesn't get to know about this, so its address stays the one that it has requested.
+	Redirect( String ),
+	/// Answers the request with the given response, without sending it.
+	Respond( SchemeResponse )
+}
+
+/// Decides on an intercepted request.
+/// If it gets dropped without having decided, like when the window is destroyed before the request could be handled, the request is sent on as it is.
+pub(in super) struct Decider {
+	headers: String,
+	request: Option<InterceptedRequestImpl>
+}
+
+
+
+impl InterceptedRequest {
+
+	/// `headers` contains a `Name: value` line for every header.
+	pub(in crate) fn new( method: &str, url: &str, headers: &str ) -> Self {
+		Self {
+			headers: headers.lines().filter_map(|line| {
+				let (name, value) = line.split_at( line.find( ':' )? );
+				Some( (name.trim().to_owned(), value[1..].trim().to_owned()) )
+			}).collect(),
+			method: method.to_owned(),
+			url: url.to_owned()
+		}
+	}
+
+	/// The value of the header with the given name, which is case-insensitive.
+	pub fn header( &self, name: &str ) -> Option<&str> {
+		self.headers.iter().find(|(n, _)| n.eq_ignore_ascii_case( name ) ).map(|(_, v)| v.as_str() )
+	}
+
+	/// All headers of the request, with their names and values.
+	pub fn headers( &self ) -> &[(String, String)] {
+		&self.headers
+	}
+
+	/// The method of the request, like `GET` or `POST`.
+	pub fn method( &self ) -> &str {
+		&self.method
+	}
+
+	/// Removes the header with the given name, which is case-insensitive, so that it isn't sent.
+	pub fn remove_header( &mut self, name: &str ) {
+		self.headers.retain(|(n, _)| !n.eq_ignore_ascii_case( name ) );
+	}
+
+	/// Sets the header with the given name, replacing the value that it may have already.
+	pub fn set_header<N: Into<String>, V: Into<String>>( &mut self, name: N, value: V ) {
+		let name = name.into();
+		self.remove_header( &name );
+		self.headers.push( (name, value.into()) );
+	}
+
+	/// The full URL of the request.
+	pub fn url( &self ) -> &str {
+		&self.url
+	}
+
+	/// The headers in the form that the browser engine takes them in, with a `Name: value` line for every header.
+	fn header_lines( &self ) -> String {
+		let mut lines = String::new();
+		for (name, value) in &self.headers {
+			lines += &format!( "{}: {}\r\n", name, value );
+		}
+		lines
+	}
+}
+
+impl Decider {
+
+	pub(in super) fn new( request: InterceptedRequestImpl, headers: &str ) -> Self {
+		Self {
+			headers: headers.to_owned(),
+			request: Some( request )
+		}
+	}
+
+	/// Applies the given action to the request, taking its headers from `intercepted`.
+	pub(in super) fn decide( mut self, intercepted: &InterceptedRequest, action: RequestAction ) {
+		let request = self.request.take().unwrap();
+
+		match action {
+			RequestAction::Block => request.block(),
+			RequestAction::Continue => request.proceed( &intercepted.header_lines() ),
+			RequestAction::Redirect( url ) => request.redirect( &url ),
+			RequestAction::Respond( response ) => Responder::new( request.respond() ).respond( response )
+		}
+	}
+}
+
+impl Drop for Decider {
+	fn drop( &mut self ) {
+		if let Some( request ) = self.request.take() {
+			request.proceed( &self.headers );
+		}
+	}
+}
+
+
+
+/// Whether the URL matches the given filter, in which `*` matches any text, including none.
+pub(in crate) fn url_matches( filter: &str, url: &str ) -> bool {
+	let mut parts = filter.split( '*' );
+	// Without a `*`, the filter needs to be the whole URL
+	let first = parts.next().unwrap_or( "" );
+	if !url.starts_with( first ) {
+		return false;
+	}
+
+	let mut rest = &url[ first.len().. ];
+	let parts: Vec<&str> = parts.collect();
+	match parts.split_last() {
+		None => rest.len() == 0,
+		Some( (last, middle) ) => {
+			for part in middle {
+				match rest.find( part ) {
+					None => return false,
+					Some( i ) => rest = &rest[ i + part.len().. ]
+				}
+			}
+			rest.ends_with( last )
+		}
+	}
+}
@@ -0,0 +1,96 @@
+use browser_window_core::browser_window::BrowserWindowExt;
+
+use super::{BrowserWindowHandle, JsValue};
+use crate::error::BrowserError;
+
+
+
+/// The response to a request of the page, as it is given to the handler of `BrowserWindowBuilder::on_response`.
+///
+/// It only describes the response, as the page gets it in the same way whether it is observed or not.
+pub struct Response {
+	headers: Vec<(String, String)>,
+	mime_type: String,
+	status: u16,
+	status_text: String,
+	url: String
+}
+
+
+
+/// The event of the DevTools protocol that responses are taken from.
+pub(in super) const RESPONSE_EVENT: &'static str = "Network.responseReceived";
+
+
+
+impl Response {
+
+	/// Takes the response from the parameters of the `Network.responseReceived` event of the DevTools protocol.
+	pub(in crate) fn from_cdp( params: &str ) -> Option<Self> {
+		let params = JsValue::from_json( params )?;
+		let response = params.get( "response" )?;
+		let string = |name: &str| response.get( name ).and_then(|v| v.as_str() ).unwrap_or( "" ).to_owned();
+
+		// Headers that occur more than once are joined by newlines
+		let mut headers = Vec::new();
+		if let Some( JsValue::Object( fields ) ) = response.get( "headers" ) {
+			for (name, value) in fields {
+				for value in value.as_str().unwrap_or( "" ).split( '\n' ) {
+					headers.push( (name.clone(), value.to_owned()) );
+				}
+			}
+		}
+
+		Some( Self {
+			headers,
+			mime_type: string( "mimeType" ),
+			status: response.get( "status" )?.as_f64()? as u16,
+			status_text: string( "statusText" ),
+			url: string( "url" )
+		} )
+	}
+
+	/// The value of the header with the given name, which is case-insensitive.
+	/// If the header occurs more than once, this is its first value.
+	pub fn header( &self, name: &str ) -> Option<&str> {
+		self.headers.iter().find(|(n, _)| n.eq_ignore_ascii_case( name ) ).map(|(_, v)| v.as_str() )
+	}
+
+	/// All headers of the response, with their names and values.
+	pub fn headers( &self ) -> &[(String, String)] {
+		&self.headers
+	}
+
+	/// The MIME type of the body, like `application/json`, as the browser engine has determined it.
+	pub fn mime_type( &self ) -> &str {
+		&self.mime_type
+	}
+
+	/// The status code of the response, like 200 or 401.
+	pub fn status( &self ) -> u16 {
+		self.status
+	}
+
+	/// The text that comes with the status code, like `OK`.
+	/// It is empty for HTTP/2 and later, as those don't have one.
+	pub fn status_text( &self ) -> &str {
+		&self.status_text
+	}
+
+	/// The URL that the response is for, which is where the request has ended up after any redirects.
+	pub fn url( &self ) -> &str {
+		&self.url
+	}
+}
+
+
+
+/// Lets the DevTools event handler of the browser window receive the responses of the page.
+/// Fails if the browser engine can't give them.
+pub(in super) async fn observe( bw: BrowserWindowHandle ) -> Result<(), BrowserError> {
+	bw.inner.subscribe_devtools_event( RESPONSE_EVENT );
+	// The network domain keeps being enabled for as long as the page exists
+	bw.call_devtools( "Network.enable", "{}" ).await
+		.map_err( BrowserError::DevToolsFailed )?;
+	Ok(())
+}
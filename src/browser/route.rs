@@ -0,0 +1,26 @@
+// The command that the page uses to report its route with.
+pub(in super) const ROUTE_COMMAND: &'static str = "__bw_route";
+
+/// The JavaScript code that reports the navigations that don't load another document, like the ones of single-page applications.
+/// These are done with `history.pushState` and `history.replaceState`, and with the back and forward buttons in between them.
+/// The route is only reported when it has actually changed.
+pub(in super) const ROUTE_JS: &'static str = r#"(function() {
+	if (window.__bw_route) return;
+	var current = location.pathname + location.search + location.hash;
+	window.__bw_route = function() {
+		var route = location.pathname + location.search + location.hash;
+		if (route === current) return;
+		current = route;
+		invoke_extern("__bw_route", route);
+	};
+	["pushState", "replaceState"].forEach(function(name) {
+		var original = history[name];
+		history[name] = function() {
+			var result = original.apply(this, arguments);
+			window.__bw_route();
+			return result;
+		};
+	});
+	window.addEventListener("popstate", function() { window.__bw_route(); });
+})();
+"#;
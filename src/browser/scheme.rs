@@ -0,0 +1,301 @@
+use browser_window_core::browser_window::{SchemeRequestExt, SchemeRequestImpl};
+use futures_core::Stream;
+use futures_io::AsyncRead;
+
+use std::{
+	io::{self, Read},
+	pin::Pin,
+	sync::Arc,
+	task::{Context, Poll, Wake, Waker},
+	thread
+};
+
+
+
+/// How much of a body is read at once, when it comes from a reader.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+
+
+/// A request of a custom scheme, as given to the handler of `BrowserWindowBuilder::scheme_handler`.
+pub struct SchemeRequest {
+	body: Vec<u8>,
+	headers: Vec<(String, String)>,
+	method: String,
+	url: String
+}
+
+/// The response to a request of a custom scheme.
+///
+/// Besides a body that is held in memory, the body can be read from a reader, an async reader or a stream.
+/// These are read on a thread of their own, only as fast as the browser engine can take them in.
+/// So large files and endless streams, like the ones of server-sent events, don't need to be kept in memory all at once.
+///
+/// # Example
+/// ```ignore
+/// bwb.scheme_handler( "app", |_, request| {
+/// 	match File::open( assets.join( &request.path()[1..] ) ) {
+/// 		Err(_) => SchemeResponse::new( 404 ),
+/// 		Ok( file ) => SchemeResponse::new( 200 )
+/// 			.header( "Content-Type", "video/mp4" )
+/// 			.reader( file )
+/// 	}
+/// });
+/// ```
+pub struct SchemeResponse {
+	body: Body,
+	headers: Vec<(String, String)>,
+	status: u16
+}
+
+/// Writes a body with the given function, until it returns false.
+/// Fails if the body couldn't be read to the end.
+type BodyPump = Box<dyn FnOnce( &mut dyn FnMut( &[u8] ) -> bool ) -> io::Result<()> + Send>;
+
+enum Body {
+	Bytes( Vec<u8> ),
+	Pump( BodyPump )
+}
+
+/// Answers a request of a custom scheme.
+/// If it gets dropped without having answered it, like when the window is destroyed before the request could be handled, it is answered with status 500.
+pub(in super) struct Responder {
+	request: Option<SchemeRequestImpl>
+}
+
+/// Wakes up the thread that pumps a body, when its reader or stream is ready.
+struct ThreadWaker( thread::Thread );
+
+
+
+impl SchemeRequest {
+
+	/// `headers` contains a `Name: value` line for every header.
+	pub(in crate) fn new( method: &str, url: &str, headers: &str, body: &[u8] ) -> Self {
+		Self {
+			body: body.to_vec(),
+			headers: headers.lines().filter_map(|line| {
+				let (name, value) = line.split_at( line.find( ':' )? );
+				Some( (name.trim().to_owned(), value[1..].trim().to_owned()) )
+			}).collect(),
+			method: method.to_owned(),
+			url: url.to_owned()
+		}
+	}
+
+	/// The body of the request, like the data of a `POST` request.
+	/// Uploaded files are not included.
+	pub fn body( &self ) -> &[u8] {
+		&self.body
+	}
+
+	/// The value of the header with the given name, which is case-insensitive.
+	pub fn header( &self, name: &str ) -> Option<&str> {
+		self.headers.iter().find(|(n, _)| n.eq_ignore_ascii_case( name ) ).map(|(_, v)| v.as_str() )
+	}
+
+	/// All headers of the request, with their names and values.
+	pub fn headers( &self ) -> &[(String, String)] {
+		&self.headers
+	}
+
+	/// The method of the request, like `GET` or `POST`.
+	pub fn method( &self ) -> &str {
+		&self.method
+	}
+
+	/// The path of the URL, without the query and the fragment, like `/index.html`.
+	pub fn path( &self ) -> &str {
+		let rest = match self.url.find( "://" ) {
+			None => &self.url[ self.url.find( ':' ).map(|i| i + 1 ).unwrap_or( 0 ).. ],
+			Some( i ) => {
+				let rest = &self.url[ i + 3.. ];
+				&rest[ rest.find( '/' ).unwrap_or( rest.len() ).. ]
+			}
+		};
+
+		let end = rest.find(|c| c == '?' || c == '#' ).unwrap_or( rest.len() );
+		if end == 0 { "/" } else { &rest[ ..end ] }
+	}
+
+	/// The scheme of the URL, always in lowercase, like `app`.
+	pub fn scheme( &self ) -> String {
+		self.url[ ..self.url.find( ':' ).unwrap_or( 0 ) ].to_lowercase()
+	}
+
+	/// The full URL of the request.
+	pub fn url( &self ) -> &str {
+		&self.url
+	}
+}
+
+impl SchemeResponse {
+
+	/// Creates a response with the given status code, and an empty body.
+	pub fn new( status: u16 ) -> Self {
+		Self {
+			body: Body::Bytes( Vec::new() ),
+			headers: Vec::new(),
+			status
+		}
+	}
+
+	/// Reads the body from the given async reader.
+	/// If the reader fails, so does the request, instead of ending the body early.
+	pub fn async_reader<R>( mut self, mut reader: R ) -> Self where
+		R: AsyncRead + Send + Unpin + 'static
+	{
+		self.body = Body::Pump( Box::new( move |write| {
+			let mut buffer = vec![0u8; CHUNK_SIZE];
+
+			loop {
+				match block_on(|cx| Pin::new( &mut reader ).poll_read( cx, &mut buffer ) )? {
+					0 => return Ok(()),
+					count => if !write( &buffer[ ..count ] ) { return Ok(()) }
+				}
+			}
+		}) );
+		self
+	}
+
+	/// Sets the body of the response.
+	/// Unless it has been given already, the `Content-Length` header is set for it.
+	pub fn body<B: Into<Vec<u8>>>( mut self, body: B ) -> Self {
+		self.body = Body::Bytes( body.into() );
+		self
+	}
+
+	/// Adds a header to the response, like `Content-Type`.
+	pub fn header<N: Into<String>, V: Into<String>>( mut self, name: N, value: V ) -> Self {
+		self.headers.push( (name.into(), value.into()) );
+		self
+	}
+
+	/// Reads the body from the given reader, like a file.
+	/// If the reader fails, so does the request, instead of ending the body early.
+	pub fn reader<R>( mut self, mut reader: R ) -> Self where
+		R: Read + Send + 'static
+	{
+		self.body = Body::Pump( Box::new( move |write| {
+			let mut buffer = vec![0u8; CHUNK_SIZE];
+
+			loop {
+				match reader.read( &mut buffer ) {
+					Ok( 0 ) => return Ok(()),
+					Ok( count ) => if !write( &buffer[ ..count ] ) { return Ok(()) },
+					Err( e ) if e.kind() == io::ErrorKind::Interrupted => {},
+					Err( e ) => return Err( e )
+				}
+			}
+		}) );
+		self
+	}
+
+	/// The status code of the response.
+	pub fn status( &self ) -> u16 {
+		self.status
+	}
+
+	/// Takes the body from the chunks of the given stream, which are sent to the page as soon as they are there.
+	/// The body ends when the stream ends.
+	pub fn stream<S, B>( mut self, mut stream: S ) -> Self where
+		S: Stream<Item=B> + Send + Unpin + 'static,
+		B: AsRef<[u8]>
+	{
+		self.body = Body::Pump( Box::new( move |write| {
+			while let Some( chunk ) = block_on(|cx| Pin::new( &mut stream ).poll_next( cx ) ) {
+				if !write( chunk.as_ref() ) {
+					break;
+				}
+			}
+			Ok(())
+		}) );
+		self
+	}
+
+	/// The headers in the form that the browser engine takes them in, with a `Name: value` line for every header.
+	pub(in crate) fn header_lines( &self ) -> String {
+		let mut lines = String::new();
+		for (name, value) in &self.headers {
+			lines += &format!( "{}: {}\r\n", name, value );
+		}
+
+		if let Body::Bytes( bytes ) = &self.body {
+			if !self.headers.iter().any(|(n, _)| n.eq_ignore_ascii_case( "Content-Length" ) ) {
+				lines += &format!( "Content-Length: {}\r\n", bytes.len() );
+			}
+		}
+		lines
+	}
+
+	/// Writes the whole body with the given function, until it returns false.
+	/// This blocks for as long as the reader or stream of the body takes.
+	/// Fails if the reader of the body fails.
+	pub(in crate) fn write_body( self, write: &mut dyn FnMut( &[u8] ) -> bool ) -> io::Result<()> {
+		match self.body {
+			Body::Bytes( bytes ) => { if bytes.len() > 0 { write( &bytes ); } Ok(()) },
+			Body::Pump( pump ) => pump( write )
+		}
+	}
+}
+
+impl Responder {
+
+	pub(in super) fn new( request: SchemeRequestImpl ) -> Self {
+		Self { request: Some( request ) }
+	}
+
+	/// Answers the request with the given response.
+	pub(in super) fn respond( mut self, response: SchemeResponse ) {
+		let request = self.request.take().unwrap();
+		request.respond( response.status, &response.header_lines() );
+
+		match response.body {
+			// A body that is already in memory is written all at once, which never blocks
+			Body::Bytes(_) => {
+				let _ = response.write_body( &mut |data| request.write( data ) );
+				request.finish();
+			},
+			// A body that can't be read to the end fails the request, so that the page doesn't take the part that it got for all of it
+			Body::Pump(_) => {
+				thread::spawn( move || {
+					match response.write_body( &mut |data| request.write( data ) ) {
+						Err(_) => request.abort(),
+						Ok(()) => request.finish()
+					}
+				});
+			}
+		}
+	}
+}
+
+impl Drop for Responder {
+	fn drop( &mut self ) {
+		if let Some( request ) = self.request.take() {
+			request.finish();
+		}
+	}
+}
+
+impl Wake for ThreadWaker {
+	fn wake( self: Arc<Self> ) {
+		self.0.unpark();
+	}
+}
+
+
+
+/// Polls until the result is ready, parking the current thread in between.
+fn block_on<T, P>( mut poll: P ) -> T where
+	P: FnMut( &mut Context ) -> Poll<T>
+{
+	let waker = Waker::from( Arc::new( ThreadWaker( thread::current() ) ) );
+	let mut cx = Context::from_waker( &waker );
+
+	loop {
+		match poll( &mut cx ) {
+			Poll::Ready( result ) => return result,
+			Poll::Pending => thread::park()
+		}
+	}
+}
@@ -0,0 +1,79 @@
+use super::{BrowserWindowHandle, JsValue};
+#[cfg(feature = "threadsafe")]
+use super::BrowserWindowThreaded;
+use crate::error::BrowserError;
+
+#[cfg(feature = "threadsafe")]
+use crate::delegate::DispatchFuture;
+
+
+
+impl BrowserWindowHandle {
+
+	/// Scrolls the page of the main frame by the given amount of CSS pixels, horizontally and vertically.
+	/// Positive values scroll to the right and down.
+	///
+	/// See `scroll_to` for more information.
+	pub async fn scroll_by( &self, delta_x: f64, delta_y: f64 ) -> Result<(), BrowserError> {
+		self.scroll( "scrollBy", delta_x, delta_y ).await
+	}
+
+	/// Gives how far the page of the main frame is scrolled, horizontally and vertically, in CSS pixels.
+	/// This can be stored to restore the reading position later with `scroll_to`, or to keep panes next to each other in sync.
+	///
+	/// The position is taken from the layout of the browser engine, so it is known even when the page doesn't respond to JavaScript.
+	/// Results in `BrowserError::DevToolsFailed` if the browser engine can't give it.
+	pub async fn scroll_position( &self ) -> Result<(f64, f64), BrowserError> {
+		let result = self.call_devtools( "Page.getLayoutMetrics", "{}" ).await
+			.map_err( BrowserError::DevToolsFailed )?;
+
+		// Older engines only have the viewport that is deprecated in newer ones
+		let viewport = result.get( "cssLayoutViewport" ).or_else(|| result.get( "layoutViewport" ) );
+		viewport.and_then(|v| Some( (v.get( "pageX" )?.as_f64()?, v.get( "pageY" )?.as_f64()?) ) )
+			.ok_or_else(|| BrowserError::DevToolsFailed( "the browser engine didn't give back the layout of the page".into() ) )
+	}
+
+	/// Scrolls the page of the main frame to the given position, in CSS pixels from its top left corner.
+	/// A position beyond the end of the page scrolls as far as it goes.
+	///
+	/// The page is scrolled right away, even if it prefers to scroll smoothly with the `scroll-behavior` style.
+	/// This is done with the DevTools protocol, by JavaScript that runs in a world of its own.
+	/// So a page that replaces `window.scrollTo` doesn't get in the way, but it still has to respond, as the world shares its thread.
+	/// Results in `BrowserError::DevToolsFailed` if the browser engine can't scroll the page.
+	pub async fn scroll_to( &self, x: f64, y: f64 ) -> Result<(), BrowserError> {
+		self.scroll( "scrollTo", x, y ).await
+	}
+
+	async fn scroll( &self, method: &str, x: f64, y: f64 ) -> Result<(), BrowserError> {
+		let expression = format!( "window.{}({{left: {}, top: {}, behavior: \"instant\"}})", method, JsValue::from( x ), JsValue::from( y ) );
+
+		self.evaluate_isolated( &expression ).await
+			.map_err( BrowserError::DevToolsFailed )?;
+		Ok(())
+	}
+}
+
+#[cfg(feature = "threadsafe")]
+impl BrowserWindowThreaded {
+
+	/// See `BrowserWindowHandle::scroll_by`.
+	pub fn scroll_by( &self, delta_x: f64, delta_y: f64 ) -> DispatchFuture<Result<(), BrowserError>> {
+		self.dispatch_async(move |bw| async move {
+			bw.scroll_by( delta_x, delta_y ).await
+		})
+	}
+
+	/// See `BrowserWindowHandle::scroll_position`.
+	pub fn scroll_position( &self ) -> DispatchFuture<Result<(f64, f64), BrowserError>> {
+		self.dispatch_async(|bw| async move {
+			bw.scroll_position().await
+		})
+	}
+
+	/// See `BrowserWindowHandle::scroll_to`.
+	pub fn scroll_to( &self, x: f64, y: f64 ) -> DispatchFuture<Result<(), BrowserError>> {
+		self.dispatch_async(move |bw| async move {
+			bw.scroll_to( x, y ).await
+		})
+	}
+}
@@ -0,0 +1,155 @@
+use super::{BrowserWindowBuilder, BrowserWindowHandle, History, HistoryEntry, JsValue, Source};
+#[cfg(feature = "threadsafe")]
+use super::BrowserWindowThreaded;
+use crate::error::BrowserError;
+
+#[cfg(feature = "threadsafe")]
+use crate::delegate::DispatchFuture;
+
+use std::collections::BTreeMap;
+
+
+
+/// Where the user was in a browser window, as it is given by `BrowserWindowHandle::save_session`, to be restored with `BrowserWindowBuilder::restore_session`.
+///
+/// It can be stored as JSON with `to_json`, like in a file, and read back with `from_json` after the application has been restarted.
+///
+/// # Example
+/// ```ignore
+/// // Before quitting
+/// fs::write( "session.json", bw.save_session().await?.to_json() )?;
+///
+/// // After starting again
+/// let mut bwb = BrowserWindowBuilder::new( Source::Url( "https://example.com/".into() ) );
+/// if let Some( state ) = fs::read_to_string( "session.json" ).ok().and_then(|json| SessionState::from_json( &json ) ) {
+/// 	bwb.restore_session( &state );
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SessionState {
+	/// The back and forward history of the main frame.
+	pub history: History,
+	/// How far the page was scrolled, horizontally and vertically, in CSS pixels.
+	pub scroll: (f64, f64)
+}
+
+
+
+/// The version of the JSON of a session state, which is increased whenever it changes in an incompatible way.
+const FORMAT_VERSION: f64 = 1.0;
+
+
+
+impl SessionState {
+
+	/// Reads the state back from the JSON that `to_json` has given.
+	/// Returns `None` if the JSON is broken, or has been written by an incompatible version.
+	pub fn from_json( json: &str ) -> Option<Self> {
+		let json = JsValue::from_json( json )?;
+		if json.get( "version" )?.as_f64()? != FORMAT_VERSION {
+			return None;
+		}
+
+		let mut entries = Vec::new();
+		while let Some( entry ) = json.get( "entries" )?.get( &entries.len().to_string() ) {
+			entries.push( HistoryEntry {
+				index: entries.len(),
+				title: entry.get( "title" )?.as_str()?.to_owned(),
+				url: entry.get( "url" )?.as_str()?.to_owned()
+			} );
+		}
+		let scroll = json.get( "scroll" )?;
+
+		Some( Self {
+			history: History {
+				current: json.get( "current" )?.as_f64()? as usize,
+				entries
+			},
+			scroll: (scroll.get( "0" )?.as_f64()?, scroll.get( "1" )?.as_f64()?)
+		} )
+	}
+
+	/// The address of the page that was shown, if there was one.
+	pub fn url( &self ) -> Option<&str> {
+		self.history.entries.get( self.history.current ).map(|e| e.url.as_str() )
+	}
+
+	/// Gives the state as JSON.
+	pub fn to_json( &self ) -> String {
+		let entries = self.history.entries.iter().map(|entry| {
+			let mut properties = BTreeMap::new();
+			properties.insert( "title".to_owned(), JsValue::from( &entry.title ) );
+			properties.insert( "url".to_owned(), JsValue::from( &entry.url ) );
+			JsValue::Object( properties )
+		}).collect();
+
+		let mut properties = BTreeMap::new();
+		properties.insert( "version".to_owned(), JsValue::from( FORMAT_VERSION ) );
+		properties.insert( "current".to_owned(), JsValue::from( self.history.current ) );
+		properties.insert( "entries".to_owned(), JsValue::Array( entries ) );
+		properties.insert( "scroll".to_owned(), JsValue::Array( vec![JsValue::from( self.scroll.0 ), JsValue::from( self.scroll.1 )] ) );
+		JsValue::Object( properties ).to_string()
+	}
+}
+
+impl BrowserWindowBuilder {
+
+	/// Lets the browser window start where the user was when the session has been saved with `BrowserWindowHandle::save_session`.
+	/// The page that was shown is loaded instead of the source of the builder, and scrolled to where it was once it has loaded.
+	///
+	/// The browser engines can't be given a history, so the pages before and after it are not in the history of the window.
+	/// They are still in `SessionState::history`, for a history menu of the application itself.
+	pub fn restore_session( &mut self, state: &SessionState ) -> &mut Self {
+		if let Some( url ) = state.url() {
+			self.source = Source::Url( url.to_owned() );
+		}
+
+		self.preload_js( &scroll_once_js( state.scroll ) )
+	}
+}
+
+impl BrowserWindowHandle {
+
+	/// Saves where the user is in the browser window, which is the history of the main frame and how far its page is scrolled.
+	/// See `SessionState` for more information.
+	///
+	/// The history is taken with the DevTools protocol, so this results in `BrowserError::DevToolsFailed` if the browser engine can't give it.
+	/// A page that doesn't respond is saved as not having been scrolled.
+	pub async fn save_session( &self ) -> Result<SessionState, BrowserError> {
+		let history = self.history().await?;
+
+		let scroll = match self.eval_js_value( "[window.scrollX, window.scrollY]" ).await {
+			Ok( JsValue::Array( s ) ) if s.len() == 2 => (s[0].as_f64().unwrap_or( 0.0 ), s[1].as_f64().unwrap_or( 0.0 )),
+			_ => (0.0, 0.0)
+		};
+
+		Ok( SessionState { history, scroll } )
+	}
+}
+
+#[cfg(feature = "threadsafe")]
+impl BrowserWindowThreaded {
+
+	/// See `BrowserWindowHandle::save_session`.
+	pub fn save_session( &self ) -> DispatchFuture<Result<SessionState, BrowserError>> {
+		self.dispatch_async(|bw| async move {
+			bw.save_session().await
+		})
+	}
+}
+
+
+
+/// A preload script that scrolls the first document of the window to the given position, once it has loaded.
+/// Reloads and later navigations are left as they are.
+pub(in crate) fn scroll_once_js( scroll: (f64, f64) ) -> String {
+	format!( r#"(function() {{
+	if (window.__bw_restored) return;
+	try {{
+		if (sessionStorage.getItem("__bw_restored")) return;
+		sessionStorage.setItem("__bw_restored", "1");
+	}} catch (e) {{}}
+	window.__bw_restored = true;
+	window.addEventListener("load", function() {{ window.scrollTo({}, {}); }}, {{ once: true }});
+}})();"#, JsValue::from( scroll.0 ), JsValue::from( scroll.1 ) )
+}
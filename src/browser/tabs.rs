@@ -0,0 +1,136 @@
+use browser_window_core::window::WindowExt;
+
+use super::{BrowserWindow, BrowserWindowBuilder};
+
+
+
+/// A native window that holds several browser views, of which one is shown at a time, like the tabs of a web browser.
+/// This way a tabbed application doesn't need a window of its own for every document.
+///
+/// The window itself is a browser window, the host, whose page is shown in a strip at the top of it.
+/// That page is where the tab bar goes, and it can switch between the tabs through a handler, like with `select`.
+/// The tabs are browser windows that are embedded in the host below the strip, and they follow its size.
+///
+/// *Note:* Embedding is only supported on Windows for now.
+/// On other platforms every tab becomes a window of its own, of which only the selected one is visible.
+///
+/// # Example
+/// ```ignore
+/// let mut host = BrowserWindowBuilder::new( Source::File( "tab-bar.html".into() ) );
+/// host.size( 1024, 768 );
+/// let mut tabs = TabbedBrowser::new( host.build( app ).await, 40 );
+/// tabs.host().show();
+///
+/// tabs.add_tab( BrowserWindowBuilder::new( Source::Url( "https://example.com/".into() ) ) ).await;
+/// tabs.add_tab( BrowserWindowBuilder::new( Source::Url( "https://example.org/".into() ) ) ).await;
+/// tabs.select( 0 );
+/// ```
+pub struct TabbedBrowser {
+	active: Option<usize>,
+	host: BrowserWindow,
+	strip_height: u16,
+	tabs: Vec<BrowserWindow>
+}
+
+
+
+impl TabbedBrowser {
+
+	/// The index of the tab that is shown, or `None` if there are no tabs.
+	pub fn active( &self ) -> Option<usize> {
+		self.active
+	}
+
+	/// The tab that is shown, or `None` if there are no tabs.
+	pub fn active_tab( &self ) -> Option<&BrowserWindow> {
+		self.active.map(|i| &self.tabs[ i ] )
+	}
+
+	/// Creates a browser window from the given builder as a new tab after the others, and selects it.
+	/// Returns the index of the new tab.
+	///
+	/// The decorations of the builder, like its title bar and size, are of no use for a tab, so they are ignored.
+	pub async fn add_tab( &mut self, mut builder: BrowserWindowBuilder ) -> usize {
+		builder.native_parent = self.host.window().inner.get_native_handle()
+			.filter(|h| h.kind == 1 && !h.window.is_null() )
+			.map(|h| h.window as usize );
+		builder.native_parent_top = self.strip_height;
+
+		let tab = builder.build( self.host.app() ).await;
+		self.tabs.push( tab );
+
+		let index = self.tabs.len() - 1;
+		self.select( index );
+		index
+	}
+
+	/// Closes the tab at the given index, after which the tabs after it move up by one.
+	/// If it was the selected tab, the tab that takes its place is selected, or the one before it if it was the last one.
+	///
+	/// Returns `false` if there is no tab at the index.
+	pub fn close_tab( &mut self, index: usize ) -> bool {
+		if index >= self.tabs.len() {
+			return false;
+		}
+
+		let tab = self.tabs.remove( index );
+		tab.close();
+		drop( tab );
+
+		self.active = match self.active {
+			_ if self.tabs.is_empty() => None,
+			Some( active ) if active > index => Some( active - 1 ),
+			Some( active ) if active == index => {
+				let next = index.min( self.tabs.len() - 1 );
+				self.tabs[ next ].window().show();
+				Some( next )
+			},
+			other => other
+		};
+		true
+	}
+
+	/// The browser window that the tabs are embedded in, whose page is shown above them.
+	pub fn host( &self ) -> &BrowserWindow {
+		&self.host
+	}
+
+	/// Uses the given browser window as the host of the tabs.
+	/// Its page is shown in a strip at the top that is `strip_height` physical pixels high, and the tabs take up the rest of it.
+	pub fn new( host: BrowserWindow, strip_height: u16 ) -> Self {
+		Self {
+			active: None,
+			host,
+			strip_height,
+			tabs: Vec::new()
+		}
+	}
+
+	/// Shows the tab at the given index, and hides the one that was shown before.
+	///
+	/// Returns `false` if there is no tab at the index.
+	pub fn select( &mut self, index: usize ) -> bool {
+		let tab = match self.tabs.get( index ) {
+			None => return false,
+			Some( t ) => t
+		};
+
+		// The new tab is shown first, so that the host doesn't show through in between
+		tab.window().show();
+		if let Some( active ) = self.active.filter(|a| *a != index ) {
+			self.tabs[ active ].window().hide();
+		}
+		self.active = Some( index );
+		true
+	}
+
+	/// The tab at the given index, if there is one.
+	pub fn tab( &self, index: usize ) -> Option<&BrowserWindow> {
+		self.tabs.get( index )
+	}
+
+	/// All tabs, in their order.
+	pub fn tabs( &self ) -> &[BrowserWindow] {
+		&self.tabs
+	}
+}
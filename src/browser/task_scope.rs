@@ -0,0 +1,153 @@
+use std::{
+	collections::HashMap,
+	future::Future,
+	pin::Pin,
+	sync::{
+		Arc,
+		Mutex,
+		Weak,
+		atomic::{AtomicBool, AtomicU64, Ordering}
+	},
+	task::{Context, Poll, Waker}
+};
+
+use super::BrowserWindowHandle;
+
+
+
+/// A scope for the tasks that belong to a browser window, which are cancelled when the window is closed.
+///
+/// Tasks that outlive their window would otherwise keep running, and may try to use the window after the user has closed it, like by evaluating JavaScript in it.
+/// A task of a scope is stopped at the first point where it waits, once the window has been closed or the scope has been cancelled.
+/// It is dropped then, so that anything it holds is freed.
+///
+/// # Example
+/// ```ignore
+/// let scope = bw.task_scope();
+/// scope.spawn( async move {
+/// 	loop {
+/// 		let status = fetch_status().await;
+/// 		bw.exec_js( &format!( "showStatus({:?})", status ) );
+/// 		app.sleep( Duration::from_secs( 5 ) ).await;
+/// 	}
+/// });
+/// ```
+#[derive(Clone)]
+pub struct TaskScope {
+	bw: BrowserWindowHandle,
+	state: Arc<ScopeState>
+}
+
+/// What the tasks of a scope share, so that they can be woken up when it is cancelled.
+#[derive(Default)]
+pub(in super) struct ScopeState {
+	cancelled: AtomicBool,
+	next_task: AtomicU64,
+	wakers: Mutex<HashMap<u64, Waker>>
+}
+
+/// A future of a task scope, which stops as soon as the scope is cancelled.
+struct Scoped<F> {
+	future: Pin<Box<F>>,
+	id: u64,
+	state: Arc<ScopeState>
+}
+
+
+
+impl BrowserWindowHandle {
+
+	/// Creates a new scope for tasks that belong to this window.
+	/// The tasks that are spawned with it are cancelled when the window is closed.
+	/// See `TaskScope` for more information.
+	pub fn task_scope( &self ) -> TaskScope {
+		let state = Arc::new( ScopeState::default() );
+
+		let scopes = &mut unsafe { &mut *self.user_data() }.task_scopes;
+		scopes.retain(|s| s.strong_count() > 0 );
+		scopes.push( Arc::downgrade( &state ) );
+
+		TaskScope {
+			bw: *self,
+			state
+		}
+	}
+}
+
+impl TaskScope {
+
+	/// Cancels all tasks of the scope, and the ones that are spawned with it from now on.
+	/// The other scopes of the window are not affected.
+	pub fn cancel( &self ) {
+		self.state.cancel();
+	}
+
+	/// Whether the scope has been cancelled, either with `cancel` or by closing the window.
+	pub fn is_cancelled( &self ) -> bool {
+		self.state.cancelled.load( Ordering::Acquire )
+	}
+
+	/// Spawns the given future on the GUI thread, like `ApplicationHandle::spawn` does, but stops it once the scope is cancelled.
+	/// If it has been cancelled already, the future isn't run at all.
+	pub fn spawn<F>( &self, future: F ) where
+		F: Future<Output=()> + 'static
+	{
+		if self.is_cancelled() {
+			return;
+		}
+
+		self.bw.app().spawn( Scoped {
+			future: Box::pin( future ),
+			id: self.state.next_task.fetch_add( 1, Ordering::Relaxed ),
+			state: self.state.clone()
+		} );
+	}
+
+	/// The browser window that the scope belongs to.
+	pub fn window( &self ) -> BrowserWindowHandle {
+		self.bw
+	}
+}
+
+impl ScopeState {
+
+	fn cancel( &self ) {
+		self.cancelled.store( true, Ordering::Release );
+
+		// The tasks are woken up, so that they get dropped
+		let wakers: Vec<Waker> = self.wakers.lock().unwrap().drain().map(|(_, w)| w ).collect();
+		for waker in wakers {
+			waker.wake();
+		}
+	}
+}
+
+impl<F> Future for Scoped<F> where
+	F: Future<Output=()>
+{
+	type Output = ();
+
+	fn poll( mut self: Pin<&mut Self>, cx: &mut Context ) -> Poll<()> {
+		if self.state.cancelled.load( Ordering::Acquire ) {
+			return Poll::Ready(());
+		}
+		self.state.wakers.lock().unwrap().insert( self.id, cx.waker().clone() );
+
+		self.future.as_mut().poll( cx )
+	}
+}
+
+impl<F> Drop for Scoped<F> {
+	fn drop( &mut self ) {
+		self.state.wakers.lock().unwrap().remove( &self.id );
+	}
+}
+
+
+
+/// Cancels the task scopes of a window that is being destroyed.
+pub(in super) fn cancel_all( scopes: Vec<Weak<ScopeState>> ) {
+	for scope in scopes.iter().filter_map(|s| s.upgrade() ) {
+		scope.cancel();
+	}
+}
@@ -0,0 +1,248 @@
+use crate::browser::*;
+use crate::window::{WindowBuilder, WindowEvents};
+
+use std::future::Future;
+#[cfg(not(feature = "threadsafe"))]
+use std::{cell::RefCell, rc::Rc};
+#[cfg(feature = "threadsafe")]
+use std::sync::{Arc, Mutex};
+
+use unsafe_send_sync::UnsafeSend;
+
+use super::builder::{BrowserFileChooserHandler, BrowserJsInvocationHandler, BrowserMenuHandler, BrowserRequestHandler, BrowserResponseHandler, BrowserSchemeHandler};
+use super::bytes::BrowserBytesHandler;
+use super::input::{BrowserKeyHandler, BrowserMouseHandler};
+use super::keyboard::{BrowserFieldFocusHandler, BrowserVirtualKeyboard, KeyboardState};
+use super::offscreen::OffscreenHandlers;
+use crate::menu::MenuItemEvent;
+
+
+
+/// A handler of the template, that is shared by all windows that are created with it.
+#[cfg(not(feature = "threadsafe"))]
+type Shared<T> = Rc<RefCell<T>>;
+#[cfg(feature = "threadsafe")]
+type Shared<T> = Arc<Mutex<T>>;
+
+/// A configuration from which many identical browser windows can be created.
+///
+/// The settings, preload scripts, APIs and the handlers of the `BrowserWindowBuilder` it is created from are shared by all windows that are created with it.
+/// So the API instances and the handler closures are not copied for each window.
+/// This includes the handlers of `on_request` and of the custom schemes, like the one of `assets`, so every window is filtered and served in the same way.
+/// The handlers of `on_key_event`, `on_mouse_event`, `on_bytes`, `on_file_chooser` and `on_response`, and the virtual keyboard with its `on_field_focus` handler, are shared as well.
+///
+/// The handlers of the events of a window itself are not part of the template, as they are usually about that one window.
+/// These are the ones of `on_favicon_changed`, `on_loading_progress`, `on_render_process_terminated`, `on_route_changed`, `on_scale_factor_changed`, `on_status_text` and `on_unresponsive`, and the handlers of offscreen browsers.
+/// They need to be registered on the builder obtained from `builder` instead.
+/// The same goes for the label, as every window is supposed to have a label of its own.
+///
+/// # Example
+/// ```ignore
+/// let mut bwb = BrowserWindowBuilder::new( Source::File( "document.html".into() ) );
+/// bwb.preload_js( "window.isDocument = true;" );
+/// let template = WindowTemplate::new( bwb );
+///
+/// for _ in 0..10 {
+/// 	template.build( app ).await;
+/// }
+/// ```
+pub struct WindowTemplate {
+	builder: BrowserWindowBuilder,
+	bytes_handler: Option<Shared<BrowserBytesHandler>>,
+	field_focus_handler: Option<Shared<BrowserFieldFocusHandler>>,
+	file_chooser_handler: Option<Shared<BrowserFileChooserHandler>>,
+	handler: Option<Shared<BrowserJsInvocationHandler>>,
+	key_handler: Option<Shared<BrowserKeyHandler>>,
+	menu_handler: Option<Shared<BrowserMenuHandler>>,
+	mouse_handler: Option<Shared<BrowserMouseHandler>>,
+	request_handlers: Vec<(String, Shared<BrowserRequestHandler>)>,
+	response_handler: Option<Shared<BrowserResponseHandler>>,
+	scheme_handlers: Vec<(String, Shared<BrowserSchemeHandler>)>,
+	virtual_keyboard: Option<Shared<BrowserVirtualKeyboard>>
+}
+
+/// The virtual keyboard of a template, as it is given to every window that is created with it.
+struct SharedKeyboard( Shared<BrowserVirtualKeyboard> );
+
+
+
+impl WindowTemplate {
+
+	/// Creates a template from a configured browser window builder.
+	pub fn new( mut builder: BrowserWindowBuilder ) -> Self {
+		let bytes_handler = builder.bytes_handler.take().map( share );
+		let field_focus_handler = builder.keyboard.on_field_focus.take().map( share );
+		let file_chooser_handler = builder.file_chooser_handler.take().map( share );
+		let handler = builder.handler.take().map( share );
+		let key_handler = builder.key_handler.take().map( share );
+		let menu_handler = builder.menu_handler.take().map( share );
+		let mouse_handler = builder.mouse_handler.take().map( share );
+		let request_handlers = builder.request_handlers.drain(..).map(|(f, h)| (f, share( h )) ).collect();
+		let response_handler = builder.response_handler.take().map( share );
+		let scheme_handlers = builder.scheme_handlers.drain(..).map(|(s, h)| (s, share( h )) ).collect();
+		let virtual_keyboard = builder.keyboard.keyboard.take().map( share );
+
+		Self {
+			builder,
+			bytes_handler,
+			field_focus_handler,
+			file_chooser_handler,
+			handler,
+			key_handler,
+			menu_handler,
+			mouse_handler,
+			request_handlers,
+			response_handler,
+			scheme_handlers,
+			virtual_keyboard
+		}
+	}
+
+	/// Creates a browser window builder that is configured in the same way as the template.
+	/// Use this if a window needs a few additional settings.
+	pub fn builder( &self ) -> BrowserWindowBuilder {
+		let window = &self.builder.window;
+
+		BrowserWindowBuilder {
+			apis: self.builder.apis.clone(),
+			bytes_handler: self.bytes_handler.clone().map(|h| -> BrowserBytesHandler {
+				Box::new(move |handle, bytes: &[u8]| with( &h, |h| h( handle, bytes ) ) )
+			}),
+			default_encoding: self.builder.default_encoding.clone(),
+			dev_tools: self.builder.dev_tools,
+			favicon_as_icon: self.builder.favicon_as_icon,
+			favicon_handler: None,
+			file_chooser_handler: self.file_chooser_handler.clone().map(|h| -> BrowserFileChooserHandler {
+				Box::new(move |handle, chooser| with( &h, |h| h( handle, chooser ) ) )
+			}),
+			handler: self.handler.clone().map(|h| -> BrowserJsInvocationHandler {
+				Box::new(move |handle, cmd, args| with( &h, |h| h( handle, cmd, args ) ) )
+			}),
+			isolate_scripts: self.builder.isolate_scripts,
+			key_handler: self.key_handler.clone().map(|h| -> BrowserKeyHandler {
+				Box::new(move |handle, event: &KeyEvent| with( &h, |h| h( handle, event ) ) )
+			}),
+			keyboard: KeyboardState {
+				keyboard: self.virtual_keyboard.clone().map(|k| -> BrowserVirtualKeyboard { Box::new( SharedKeyboard( k ) ) } ),
+				on_field_focus: self.field_focus_handler.clone().map(|h| -> BrowserFieldFocusHandler {
+					Box::new(move |handle, field: Option<&EditableField>| with( &h, |h| h( handle, field ) ) )
+				}),
+				..KeyboardState::default()
+			},
+			label: None,
+			loading_progress_handler: None,
+			menu_bar: self.builder.menu_bar.clone(),
+			menu_handler: self.menu_handler.clone().map(|h| -> BrowserMenuHandler {
+				Box::new(move |handle, event: &MenuItemEvent| with( &h, |h| h( handle, event ) ) )
+			}),
+			mouse_handler: self.mouse_handler.clone().map(|h| -> BrowserMouseHandler {
+				Box::new(move |handle, event: &MouseEvent| with( &h, |h| h( handle, event ) ) )
+			}),
+			offscreen: OffscreenHandlers::default(),
+			permission_defaults: self.builder.permission_defaults.clone(),
+			preload_scripts: self.builder.preload_scripts.clone(),
+			reload_on_crash: self.builder.reload_on_crash,
+			render_process_terminated_handler: None,
+			request_handlers: self.request_handlers.iter().map(|(f, h)| {
+				let h = h.clone();
+				let handler: BrowserRequestHandler = Box::new(move |handle, request: &mut InterceptedRequest| with( &h, |h| h( handle, request ) ) );
+				(f.clone(), handler)
+			}).collect(),
+			response_handler: self.response_handler.clone().map(|h| -> BrowserResponseHandler {
+				Box::new(move |handle, response: &Response| with( &h, |h| h( handle, response ) ) )
+			}),
+			route_handler: None,
+			scale_factor_handler: None,
+			status_text_handler: None,
+			scheme_handlers: self.scheme_handlers.iter().map(|(s, h)| {
+				let h = h.clone();
+				let handler: BrowserSchemeHandler = Box::new(move |handle, request| with( &h, |h| h( handle, request ) ) );
+				(s.clone(), handler)
+			}).collect(),
+			source: self.builder.source.clone(),
+			unresponsive_handler: None,
+			#[cfg(feature = "dev")]
+			watched: self.builder.watched.clone(),
+			window: WindowBuilder {
+				backdrop: window.backdrop,
+				borders: window.borders,
+				corner_radius: window.corner_radius,
+				events: Box::new( WindowEvents::default() ),
+				height: window.height,
+				minimizable: window.minimizable,
+				modal: window.modal,
+				// Every embedded window needs a window of its own to be embedded in
+				native_parent: None,
+				native_parent_top: 0,
+				owner: window.owner.as_ref().map(|o| UnsafeSend::new( o.i ) ),
+				parent: window.parent.as_ref().map(|p| UnsafeSend::new( p.i ) ),
+				resizable: window.resizable,
+				resize_strategy: window.resize_strategy,
+				shadow: window.shadow,
+				size_unit: window.size_unit,
+				title: window.title.clone(),
+				tool_window: window.tool_window,
+				width: window.width
+			}
+		}
+	}
+
+	/// Creates a new browser window from the template.
+	///
+	/// # Arguments
+	/// * `app` - An application handle that this browser window can spawn into
+	pub fn build( &self, app: ApplicationHandle ) -> impl Future<Output=BrowserWindow> {
+		self.builder().build( app )
+	}
+
+	/// Creates a new browser window from the template.
+	/// See also `BrowserWindowBuilder::build_threaded`.
+	///
+	/// # Arguments
+	/// * `app` - An (thread-safe) application handle.
+	#[cfg(feature = "threadsafe")]
+	pub fn build_threaded( &self, app: ApplicationHandleThreaded ) -> impl Future<Output=Result<BrowserWindowThreaded, DelegateError>> {
+		self.builder().build_threaded( app )
+	}
+}
+
+impl From<BrowserWindowBuilder> for WindowTemplate {
+	fn from( builder: BrowserWindowBuilder ) -> Self {
+		Self::new( builder )
+	}
+}
+
+impl VirtualKeyboard for SharedKeyboard {
+
+	fn show( &mut self, bw: BrowserWindowHandle, field: &EditableField ) {
+		with( &self.0, |k| k.show( bw, field ) )
+	}
+
+	fn hide( &mut self, bw: BrowserWindowHandle ) {
+		with( &self.0, |k| k.hide( bw ) )
+	}
+}
+
+
+
+#[cfg(not(feature = "threadsafe"))]
+fn share<T>( handler: T ) -> Shared<T> {
+	Rc::new( RefCell::new( handler ) )
+}
+
+#[cfg(feature = "threadsafe")]
+fn share<T>( handler: T ) -> Shared<T> {
+	Arc::new( Mutex::new( handler ) )
+}
+
+/// Calls `f` with the shared handler.
+#[cfg(not(feature = "threadsafe"))]
+fn with<T, R>( handler: &Shared<T>, f: impl FnOnce( &mut T ) -> R ) -> R {
+	f( &mut *handler.borrow_mut() )
+}
+
+/// Calls `f` with the shared handler.
+#[cfg(feature = "threadsafe")]
+fn with<T, R>( handler: &Shared<T>, f: impl FnOnce( &mut T ) -> R ) -> R {
+	f( &mut *handler.lock().unwrap() )
+}
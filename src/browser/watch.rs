@@ -0,0 +1,94 @@
+use super::{registry, BrowserWindowHandle};
+#[cfg(feature = "threadsafe")]
+use super::BrowserWindowThreaded;
+
+use crate::application::ApplicationHandle;
+use crate::prelude::*;
+use crate::window::WindowId;
+
+use browser_window_core::browser_window::JsEvaluationError;
+use futures_channel::mpsc;
+use std::time::Duration;
+
+
+
+/// A stream of the results of a JavaScript expression that is evaluated periodically, see `BrowserWindowHandle::watch_js`.
+/// It ends when the window is destroyed.
+pub type JsWatch = PropertyChanges<Result<String, JsEvaluationError>>;
+
+type JsWatchSender = mpsc::UnboundedSender<Result<String, JsEvaluationError>>;
+
+
+
+impl BrowserWindowHandle {
+
+	/// Evaluates the given JavaScript expression every `interval`, and gives a stream of its results.
+	/// The first result comes right away, and the interval starts counting after the previous result has come in.
+	///
+	/// The expression isn't evaluated while the window is hidden, so nothing is done for dashboards that nobody is looking at.
+	/// Dropping the stream stops the evaluations.
+	///
+	/// # Example
+	/// ```ignore
+	/// let mut unread = bw.watch_js( "document.querySelectorAll('.unread').length", Duration::from_secs( 5 ) );
+	///
+	/// while let Some( count ) = unread.next().await {
+	/// 	if let Ok( count ) = count {
+	/// 		tray.set_tooltip( &format!( "{} unread messages", count ) );
+	/// 	}
+	/// }
+	/// ```
+	pub fn watch_js( &self, expression: &str, interval: Duration ) -> JsWatch {
+		let (sender, results) = PropertyChanges::new();
+
+		evaluate( self.app(), self.id(), expression.to_owned(), interval, sender );
+		results
+	}
+}
+
+#[cfg(feature = "threadsafe")]
+impl BrowserWindowThreaded {
+
+	/// See `BrowserWindowHandle::watch_js`.
+	/// If the GUI thread can't be reached anymore, the stream ends right away.
+	pub fn watch_js( &self, expression: &str, interval: Duration ) -> JsWatch {
+		let (sender, results) = PropertyChanges::new();
+		let expression = expression.to_owned();
+
+		self.dispatch(move |bw| {
+			evaluate( bw.app(), bw.id(), expression, interval, sender );
+		});
+		results
+	}
+}
+
+
+
+/// Evaluates the expression, unless the window is hidden, and schedules the next evaluation afterwards.
+/// The window is looked up every time, because it may have been destroyed in the meantime, which ends the stream as the sender is dropped.
+fn evaluate( app: ApplicationHandle, window: WindowId, expression: String, interval: Duration, sender: JsWatchSender ) {
+	if sender.is_closed() { return }
+
+	let bw = match registry::by_id( window ) {
+		None => return,
+		Some( bw ) => bw
+	};
+
+	if !bw.inner.window().is_visible() {
+		schedule( app, window, expression, interval, sender );
+		return;
+	}
+
+	let js = expression.clone();
+	bw._eval_js( &js, move |_, result| {
+		if sender.unbounded_send( result ).is_ok() {
+			schedule( app, window, expression, interval, sender );
+		}
+	});
+}
+
+fn schedule( app: ApplicationHandle, window: WindowId, expression: String, interval: Duration, sender: JsWatchSender ) {
+	app.dispatch_delayed(move |app| {
+		evaluate( app, window, expression, interval, sender );
+	}, interval );
+}
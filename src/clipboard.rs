@@ -0,0 +1,176 @@
+//! This module contains the types to read from and write to the system's clipboard.
+//!
+//! The clipboard is obtained with `ApplicationHandle::clipboard`, or with `ApplicationHandleThreaded::clipboard` from other threads.
+//! This allows the handlers of IPC messages to implement copy and paste, without having to rely on `document.execCommand`.
+//!
+//! Besides text and images, the clipboard can hold HTML fragments and lists of files.
+//! Files that are put on it can be pasted in the file manager of the system, and files that are copied in there can be read from it.
+//!
+//! *Note:* The clipboard is not supported when CEF provides the windows itself, which is when feature `gtk` is disabled on other platforms than Windows.
+//! It always appears to be empty then.
+//!
+//! # Example
+//! ```ignore
+//! let clipboard = app.clipboard();
+//! clipboard.write_text( "Hello world!" );
+//!
+//! if let Some( text ) = clipboard.read_text() {
+//! 	println!( "The clipboard contains: {}", text );
+//! }
+//! ```
+
+use crate::application::ApplicationHandle;
+#[cfg(feature = "threadsafe")]
+use crate::application::ApplicationHandleThreaded;
+#[cfg(feature = "threadsafe")]
+use crate::DelegateError;
+
+use browser_window_core::prelude::*;
+use std::path::{Path, PathBuf};
+
+
+
+/// The system's clipboard.
+/// Can only be used on the GUI thread.
+#[derive(Clone, Copy)]
+pub struct Clipboard {
+	app: ApplicationHandle
+}
+
+/// **Note:** Only available with feature `threadsafe` enabled.
+///
+/// A handle to the system's clipboard that can be used from any thread.
+/// Its methods are performed on the GUI thread, and resolve once they are done.
+#[cfg(feature = "threadsafe")]
+#[derive(Clone, Copy)]
+pub struct ClipboardThreaded {
+	app: ApplicationHandleThreaded
+}
+
+/// An image that is read from or written to the clipboard.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClipboardImage {
+	pub width: u32,
+	pub height: u32,
+	/// The pixels in the RGBA format, row by row from the top, without any padding.
+	/// Their alpha is not premultiplied.
+	pub pixels: Vec<u8>
+}
+
+
+
+impl Clipboard {
+
+	pub(in crate) fn new( app: ApplicationHandle ) -> Self {
+		Self { app }
+	}
+
+	/// Gives the paths of the files that are on the clipboard, like the ones that have been copied in a file manager.
+	/// Returns `None` if the clipboard doesn't contain any files.
+	pub fn read_files( &self ) -> Option<Vec<PathBuf>> {
+		let paths = self.app.inner.read_clipboard_files()?;
+
+		Some( paths.into_iter().map( PathBuf::from ).collect() )
+	}
+
+	/// Gives the HTML fragment that is on the clipboard, like the one that a browser puts on it when a part of a page is copied.
+	/// Returns `None` if the clipboard doesn't contain any HTML.
+	pub fn read_html( &self ) -> Option<String> {
+		self.app.inner.read_clipboard_html()
+	}
+
+	/// Gives the image that is on the clipboard.
+	/// Returns `None` if the clipboard doesn't contain an image.
+	pub fn read_image( &self ) -> Option<ClipboardImage> {
+		let (width, height, pixels) = self.app.inner.read_clipboard_image()?;
+
+		Some( ClipboardImage { width, height, pixels } )
+	}
+
+	/// Gives the text that is on the clipboard.
+	/// Returns `None` if the clipboard doesn't contain any text.
+	pub fn read_text( &self ) -> Option<String> {
+		self.app.inner.read_clipboard_text()
+	}
+
+	/// Replaces the contents of the clipboard with the given files, so that they can be pasted in a file manager.
+	/// Returns false if the clipboard couldn't be written to.
+	///
+	/// *Note:* Paths that aren't valid unicode are converted lossily.
+	pub fn write_files<P: AsRef<Path>>( &self, paths: &[P] ) -> bool {
+		let paths: Vec<String> = paths.iter().map(|p| p.as_ref().to_string_lossy().into_owned() ).collect();
+
+		self.app.inner.write_clipboard_files( &paths )
+	}
+
+	/// Replaces the contents of the clipboard with the given HTML fragment.
+	/// Returns false if the clipboard couldn't be written to.
+	pub fn write_html( &self, html: &str ) -> bool {
+		self.app.inner.write_clipboard_html( html )
+	}
+
+	/// Replaces the contents of the clipboard with the given image.
+	/// Returns false if the clipboard couldn't be written to.
+	///
+	/// # Panics
+	/// Panics if the number of pixels doesn't match the width and height of the image.
+	pub fn write_image( &self, image: &ClipboardImage ) -> bool {
+		assert!( image.pixels.len() == image.width as usize * image.height as usize * 4, "the pixels don't match the size of the image" );
+
+		self.app.inner.write_clipboard_image( &image.pixels, image.width, image.height )
+	}
+
+	/// Replaces the contents of the clipboard with the given text.
+	/// Returns false if the clipboard couldn't be written to.
+	pub fn write_text( &self, text: &str ) -> bool {
+		self.app.inner.write_clipboard_text( text )
+	}
+}
+
+#[cfg(feature = "threadsafe")]
+impl ClipboardThreaded {
+
+	pub(in crate) fn new( app: ApplicationHandleThreaded ) -> Self {
+		Self { app }
+	}
+
+	/// Same as `Clipboard::read_files`.
+	pub async fn read_files( &self ) -> Result<Option<Vec<PathBuf>>, DelegateError> {
+		self.app.delegate(|app| app.clipboard().read_files() ).await
+	}
+
+	/// Same as `Clipboard::read_html`.
+	pub async fn read_html( &self ) -> Result<Option<String>, DelegateError> {
+		self.app.delegate(|app| app.clipboard().read_html() ).await
+	}
+
+	/// Same as `Clipboard::read_image`.
+	pub async fn read_image( &self ) -> Result<Option<ClipboardImage>, DelegateError> {
+		self.app.delegate(|app| app.clipboard().read_image() ).await
+	}
+
+	/// Same as `Clipboard::read_text`.
+	pub async fn read_text( &self ) -> Result<Option<String>, DelegateError> {
+		self.app.delegate(|app| app.clipboard().read_text() ).await
+	}
+
+	/// Same as `Clipboard::write_files`.
+	pub async fn write_files( &self, paths: Vec<PathBuf> ) -> Result<bool, DelegateError> {
+		self.app.delegate(move |app| app.clipboard().write_files( &paths ) ).await
+	}
+
+	/// Same as `Clipboard::write_html`.
+	pub async fn write_html( &self, html: &str ) -> Result<bool, DelegateError> {
+		self.app.delegate(|app| app.clipboard().write_html( html ) ).await
+	}
+
+	/// Same as `Clipboard::write_image`.
+	pub async fn write_image( &self, image: ClipboardImage ) -> Result<bool, DelegateError> {
+		self.app.delegate(move |app| app.clipboard().write_image( &image ) ).await
+	}
+
+	/// Same as `Clipboard::write_text`.
+	pub async fn write_text( &self, text: &str ) -> Result<bool, DelegateError> {
+		self.app.delegate(|app| app.clipboard().write_text( text ) ).await
+	}
+}
@@ -1,4 +1,5 @@
-use super::application::{ApplicationHandle, HasAppHandle};
+use super::application::{ApplicationHandle, ApplicationHandleThreaded, DispatchPriority, HasAppHandle, queue};
+use std::any;
 use std::boxed::Box;
 use std::future::Future;
 use std::mem;
@@ -14,6 +15,8 @@ use std::task::{
 use std::panic::{AssertUnwindSafe, catch_unwind};
 
 use browser_window_core::application::*;
+use futures_channel::oneshot;
+use unsafe_send_sync::UnsafeSend;
 
 
 
@@ -77,6 +80,38 @@ pub struct DelegateFutureFuture<'a,R> where
 unsafe impl<'a,R> Send for DelegateFutureFuture<'a,R> where R: Send {}
 impl<'a,R> Unpin for DelegateFutureFuture<'a,R> where R: Send {}
 
+/// This future resolves with the output of a future that has been dispatched to the GUI thread.
+///
+/// Unlike `DelegateFuture` and `DelegateFutureFuture`, the work has already been dispatched when this future is constructed.
+/// So dropping it without awaiting it does not prevent the work from being done.
+pub struct DispatchFuture<R> {
+	receiver: Option<oneshot::Receiver<R>>
+}
+
+/// A value that is kept on the GUI thread, while it can be referred to from any thread.
+/// It is obtained with `dispatch_ref`, and is meant for values that can't be sent to other threads, like handles and `Rc`s.
+///
+/// The value can only be reached by dispatching closures to the GUI thread with `with`.
+/// Once the `GuiRef` is dropped, the value is dropped on the GUI thread as well.
+/// If the runtime isn't running anymore by then, the value is leaked instead.
+///
+/// # Example
+/// ```ignore
+/// // The `Rc` never leaves the GUI thread
+/// let counter = app.dispatch_ref(|_| Rc::new( Cell::new( 0 ) ) ).await?;
+///
+/// counter.with(|c| c.set( c.get() + 1 ) ).await?;
+/// let count = counter.with(|c| c.get() ).await?;
+/// ```
+pub struct GuiRef<T> where T: 'static {
+	app_handle: ApplicationHandle,
+	value: *mut T
+}
+/// # Safety
+/// The value is only ever accessed and dropped through closures that are dispatched to the GUI thread.
+unsafe impl<T> Send for GuiRef<T> {}
+unsafe impl<T> Sync for GuiRef<T> {}
+
 /// This is not a future but the inner part that of `DelegateFutureFuture` that needs to have mutable reference.
 struct DelegateFutureInner<'a,R> where R: Send {
 	result: Option<Result<R, DelegateError>>,
@@ -138,9 +173,9 @@ impl<'a,H,R> Future for DelegateFuture<'a,H,R> where
 			};
 
 			let succeeded = {
-				let data_ptr = Box::into_raw( Box::new( data ) );
+				let data_ptr = UnsafeSend::new( Box::into_raw( Box::new( data ) ) as *mut () );
 
-				app_inner.dispatch( delegate_handler::<H,R>, data_ptr as _ )
+				queue::dispatch_raw( app_inner, DispatchPriority::Normal, any::type_name::<H>(), delegate_handler::<H,R>, data_ptr )
 			};
 
 			// cbw_Application_dispatch fails when there is now runtime that is running
@@ -164,6 +199,71 @@ impl<'a,H,R> Future for DelegateFuture<'a,H,R> where
 	}
 }
 
+impl<T> GuiRef<T> {
+
+	/// Should only be called on the GUI thread.
+	pub(in super) fn new( app_handle: ApplicationHandle, value: T ) -> Self {
+		Self {
+			app_handle,
+			value: Box::into_raw( Box::new( value ) )
+		}
+	}
+
+	/// Executes the given closure on the GUI thread with the value, and gives back its output.
+	/// Closures are executed in the order in which they have been dispatched.
+	pub fn with<F,R>( &self, func: F ) -> DispatchFuture<R> where
+		F: FnOnce( &mut T ) -> R + Send + 'static,
+		R: Send + 'static
+	{
+		let value = UnsafeSend::new( self.value );
+		let (tx, rx) = oneshot::channel::<R>();
+
+		let dispatched = ApplicationHandleThreaded::from( self.app_handle ).dispatch(move |_| {
+			// The value is only dropped by a closure that is dispatched after this one.
+			let _ = tx.send( func( unsafe { &mut *value.i } ) );
+		});
+
+		DispatchFuture::new( if dispatched { Some( rx ) } else { None } )
+	}
+}
+
+impl<T> Drop for GuiRef<T> {
+	fn drop( &mut self ) {
+		let value = UnsafeSend::new( self.value );
+
+		ApplicationHandleThreaded::from( self.app_handle ).dispatch(move |_| {
+			drop( unsafe { Box::from_raw( value.i ) } );
+		});
+	}
+}
+
+impl<R> DispatchFuture<R> {
+
+	/// # Arguments
+	/// * `receiver` - The receiver that receives the output, or `None` if dispatching has failed.
+	pub(in super) fn new( receiver: Option<oneshot::Receiver<R>> ) -> Self {
+		Self {
+			receiver
+		}
+	}
+}
+
+impl<R> Future for DispatchFuture<R> {
+	type Output = Result<R, DelegateError>;
+
+	fn poll( mut self: Pin<&mut Self>, cx: &mut Context ) -> Poll<Self::Output> {
+		match &mut self.receiver {
+			None => Poll::Ready( Err( DelegateError::RuntimeNotAvailable ) ),
+			Some( receiver ) => match Pin::new( receiver ).poll( cx ) {
+				Poll::Pending => Poll::Pending,
+				Poll::Ready( Ok( output ) ) => Poll::Ready( Ok( output ) ),
+				// The dispatched future got dropped before it finished, which happens when the runtime exits.
+				Poll::Ready( Err( _ ) ) => Poll::Ready( Err( DelegateError::RuntimeNotAvailable ) )
+			}
+		}
+	}
+}
+
 #[cfg(feature = "threadsafe")]
 impl<'a,R> DelegateFutureFuture<'a,R> where R: Send {
 
@@ -192,12 +292,12 @@ impl<'a,R> Future for DelegateFutureFuture<'a,R> where R: Send {
 
 			let succeeded = unsafe {
 
-				let data_ptr = Box::into_raw(Box::new(DelegateFutureData {
+				let data_ptr = UnsafeSend::new( Box::into_raw(Box::new(DelegateFutureData {
 					inner: &mut self.inner,
 					waker: ctx.waker().clone(),
-				}));
+				})) as *mut () );
 
-				app_inner.dispatch( delegate_async_handler::<R>, data_ptr as _ )
+				queue::dispatch_raw( app_inner, DispatchPriority::Normal, "delegate_async", delegate_async_handler::<R>, data_ptr )
 			};
 
 			// cbw_Application_dispatch fails when there is now runtime that is running
@@ -0,0 +1,119 @@
+//! **Note:** Only available with feature `demos` enabled.
+//!
+//! This module contains small demos that can be started from any application, to quickly check whether _Browser Window_ works on a platform.
+//! Each demo is a configured `BrowserWindowBuilder`, so it can be adjusted before it is built, and it can be built into windows of integration tests.
+//!
+//! The demos are instrumented: their pages keep track of what has worked in a global `demo` object, which can be read with `eval_js`.
+//!
+//! # Example
+//! ```ignore
+//! let bw = browser_window::demos::ipc_demo().build( app ).await;
+//!
+//! app.sleep( Duration::from_secs( 1 ) ).await;
+//! let round_trips: u32 = bw.eval_js( "demo.roundTrips" ).await.unwrap().parse().unwrap();
+//! assert!( round_trips > 0 );
+//! ```
+
+use crate::browser::{BrowserWindowBuilder, Source};
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+
+
+// The styling that all demo pages share.
+const STYLE: &'static str = "body { font-family: sans-serif; margin: 2em; } .ok { color: green; }";
+
+static WINDOW_COUNT: AtomicUsize = AtomicUsize::new( 0 );
+
+
+
+/// A window that sends messages to the application, which sends them right back.
+///
+/// Once the page has loaded, it sends `ping` with a number to the application, for which the application calls `demo.pong` with the same number.
+/// `demo.roundTrips` counts the messages that have come back, and `demo.lastError` contains the description of anything that went wrong, or `null`.
+/// The button on the page sends another message.
+pub fn ipc_demo() -> BrowserWindowBuilder {
+	let html = format!( r#"<!DOCTYPE html>
+<html>
+<head><title>IPC demo</title><style>{}</style></head>
+<body>
+	<h1>IPC demo</h1>
+	<p>Round trips: <span id="count">0</span></p>
+	<p id="error"></p>
+	<button onclick="demo.ping()">Ping</button>
+	<script>
+		window.demo = {{
+			roundTrips: 0,
+			lastError: null,
+			sent: 0,
+			ping: function() {{
+				demo.sent += 1;
+				invoke_extern( "ping", String( demo.sent ) );
+			}},
+			pong: function( n ) {{
+				if ( n !== demo.roundTrips + 1 )
+					demo.lastError = "expected pong " + (demo.roundTrips + 1) + ", got " + n;
+				demo.roundTrips += 1;
+				document.getElementById( "count" ).textContent = demo.roundTrips;
+				document.getElementById( "count" ).className = "ok";
+				document.getElementById( "error" ).textContent = demo.lastError || "";
+			}}
+		}};
+		demo.ping();
+	</script>
+</body>
+</html>"#, STYLE );
+
+	let mut builder = BrowserWindowBuilder::new( Source::Html( html ) );
+	builder.async_handler(|bw, cmd, args| async move {
+		if cmd == "ping" {
+			let n: u32 = args.get( 0 ).and_then(|a| a.parse().ok() ).unwrap_or( 0 );
+			bw.exec_js( &format!( "demo.pong({})", n ) );
+		}
+	});
+	builder.title( "IPC demo" );
+	builder.size( 480, 320 );
+	builder
+}
+
+/// A window with a button that opens another window of the same kind.
+///
+/// Every window gets a number, which is available from `demo.windowNumber`, and is displayed in its title.
+/// `demo.opened` counts the windows that have been opened from that window.
+pub fn multi_window_demo() -> BrowserWindowBuilder {
+	let number = WINDOW_COUNT.fetch_add( 1, Ordering::Relaxed ) + 1;
+	let html = format!( r#"<!DOCTYPE html>
+<html>
+<head><title>Window {number}</title><style>{style}</style></head>
+<body>
+	<h1>Window {number}</h1>
+	<p>Windows opened from here: <span id="opened">0</span></p>
+	<button onclick="demo.open()">Open another window</button>
+	<script>
+		window.demo = {{
+			windowNumber: {number},
+			opened: 0,
+			open: function() {{
+				invoke_extern( "open" );
+			}},
+			onOpened: function() {{
+				demo.opened += 1;
+				document.getElementById( "opened" ).textContent = demo.opened;
+			}}
+		}};
+	</script>
+</body>
+</html>"#, number = number, style = STYLE );
+
+	let mut builder = BrowserWindowBuilder::new( Source::Html( html ) );
+	builder.async_handler(|bw, cmd, _| async move {
+		if cmd == "open" {
+			// The new window stays open after its handle has been dropped, until the user closes it.
+			let _ = multi_window_demo().build( bw.app() ).await;
+			bw.exec_js( "demo.onOpened()" );
+		}
+	});
+	builder.title( format!( "Window {}", number ) );
+	builder.size( 480, 320 );
+	builder
+}
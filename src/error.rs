@@ -6,29 +6,58 @@ use std::fmt;
 
 
 
-#[derive(Debug)]
-pub enum Error {
-	Cbw(CbwError)
+/// The ways in which an operation of _Browser Window_ can fail.
+/// Match on it to handle the kinds of failure differently, instead of looking at the message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BrowserError {
+	/// A method of the DevTools protocol of the browser engine, that the operation is built on, has given an error with the given message.
+	DevToolsFailed( String ),
+	/// The browser engine or the platform has given an error.
+	/// `code` is the error code of `browser-window-c`, and `message` describes it.
+	EngineError { code: u32, message: String },
+	/// The frame doesn't exist (anymore).
+	FrameNotFound,
+	/// The history of the page has no entry at the given index (anymore).
+	HistoryEntryNotFound( usize ),
+	/// The address isn't an absolute URL, like `https://example.com/`, so it can't be navigated to.
+	InvalidUrl( String ),
+	/// The page couldn't be loaded, like when the host can't be reached.
+	/// `code` is the error code of the browser engine, and `message` describes it.
+	LoadFailed { code: u32, message: String },
+	/// The page couldn't be printed, for the reason that is given.
+	PrintFailed( String ),
+	/// The window has been closed, and can't be used anymore.
+	WindowClosed
 }
 
-pub type Result<T> = std::result::Result<T, Error>;
+/// The name that `BrowserError` used to have.
+pub type Error = BrowserError;
 
+pub type Result<T> = std::result::Result<T, BrowserError>;
 
 
-impl fmt::Display for Error {
+
+impl fmt::Display for BrowserError {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		match self {
-			Self::Cbw(e) => write!(f, "c(bw) error: {}", e)
+			Self::DevToolsFailed(message) => write!(f, "DevTools error: {}", message),
+			Self::EngineError { code, message } => write!(f, "browser engine error {}: {}", code, message),
+			Self::FrameNotFound => write!(f, "the frame doesn't exist"),
+			Self::HistoryEntryNotFound(index) => write!(f, "the history has no entry at index {}", index),
+			Self::InvalidUrl(url) => write!(f, "not an absolute URL: {}", url),
+			Self::LoadFailed { code, message } => write!(f, "unable to load the page ({}): {}", code, message),
+			Self::PrintFailed(message) => write!(f, "unable to print the page: {}", message),
+			Self::WindowClosed => write!(f, "the window has been closed")
 		}
 	}
 }
 
-impl std::error::Error for Error {
+impl std::error::Error for BrowserError {
 	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> { None }
 }
 
-impl From<CbwError> for Error {
+impl From<CbwError> for BrowserError {
 	fn from(e: CbwError) -> Self {
-		Self::Cbw(e)
+		Self::EngineError { code: e.code(), message: e.message() }
 	}
-}
\ No newline at end of file
+}
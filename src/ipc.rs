@@ -0,0 +1,348 @@
+//! This module contains the plumbing that exposes Rust APIs to JavaScript.
+//!
+//! With feature `macros` enabled, an impl block can be annotated with `#[browser_window::api]`.
+//! Every `pub` method that takes `&self` will then be available from within JavaScript as a method of a global object.
+//! The arguments are deserialized with `serde`, and the (serialized) return value is given back to JavaScript through a promise.
+//! If a method returns a `Result`, the promise gets rejected with the error's message when it is an `Err`.
+//!
+//! # Example
+//! ```ignore
+//! use browser_window::browser::*;
+//!
+//! struct Storage;
+//!
+//! #[browser_window::api("storage")]
+//! impl Storage {
+//! 	pub async fn read(&self, key: String) -> Option<String> { /* ... */ }
+//! 	pub async fn write(&self, key: String, value: String) -> Result<(), std::io::Error> { /* ... */ }
+//! }
+//!
+//! let mut bwb = BrowserWindowBuilder::new( Source::File( "index.html".into() ) );
+//! bwb.api( Storage );
+//! ```
+//! Then in JavaScript:
+//! ```js
+//! let value = await storage.read("key");
+//! ```
+//!
+//! # Codecs
+//! The arguments and return values are encoded as JSON by default.
+//! For UIs that exchange a lot of messages, feature `rmp` provides the `MessagePack` codec, which is more compact and cheaper to encode:
+//! ```ignore
+//! #[browser_window::api("storage", codec = browser_window::ipc::MessagePack)]
+//! impl Storage { /* ... */ }
+//! ```
+//! The codec is chosen per API, and doesn't change anything for the JavaScript code that calls it.
+//! Other codecs can be plugged in by implementing the `Codec` trait.
+//!
+//! # Commands
+//! Functions that don't belong to any object can be exposed as commands instead.
+//! They are annotated with `#[browser_window::command]`, which takes the same arguments as the `api` attribute, and collected with `generate_handler!`:
+//! ```ignore
+//! use browser_window::{command, generate_handler};
+//!
+//! #[command]
+//! async fn get_user( id: u32 ) -> Option<User> { /* ... */ }
+//!
+//! #[command("saveFile")]
+//! fn save_file( bw: BrowserWindowHandle, path: String, contents: String ) -> Result<(), std::io::Error> { /* ... */ }
+//!
+//! bwb.commands( generate_handler![get_user, save_file] );
+//! ```
+//! The commands become methods of the global `commands` object:
+//! ```js
+//! let user = await commands.get_user(42);
+//! await commands.saveFile("notes.txt", user.notes);
+//! ```
+//! Commands are invoked on the GUI thread, just like the methods of an API.
+//! So they can use the `BrowserWindowHandle` of the window that calls them, and asynchronous commands don't block the GUI while they are waiting.
+
+#[cfg(feature = "macros")]
+mod codec;
+
+use crate::browser::BrowserWindowHandle;
+
+use std::{
+	future::Future,
+	pin::Pin,
+	sync::Arc
+};
+#[cfg(not(feature = "threadsafe"))]
+use std::rc::Rc;
+
+#[cfg(feature = "macros")]
+pub use codec::{Codec, Json};
+#[cfg(feature = "rmp")]
+pub use codec::MessagePack;
+
+// The name of the global JavaScript object that holds the commands.
+const COMMANDS_NAME: &'static str = "commands";
+
+
+
+/// The future returned by `Api::invoke`.
+/// It outputs either the result encoded with the codec of the API, or an error message.
+pub type ApiFuture = Pin<Box<dyn Future<Output=Result<String, String>>>>;
+
+/// A collection of methods that can be called from JavaScript.
+///
+/// Implement this with the `#[browser_window::api]` attribute instead of doing it manually.
+pub trait Api: 'static {
+	/// The name of the global JavaScript object that holds the methods.
+	fn js_name() -> &'static str;
+	/// The JavaScript code that defines the global object and its methods.
+	fn js_stubs() -> String;
+	/// The name of the codec that the arguments and the return values are encoded with.
+	/// The JavaScript side of the codec needs to be registered by `js_stubs`.
+	fn codec_name() -> &'static str { "json" }
+	/// Invokes the method with the given `method` name and encoded `args`.
+	/// Returns `None` if there is no such method.
+	fn invoke( this: Arc<Self>, handle: BrowserWindowHandle, method: &str, args: Vec<String> ) -> Option<ApiFuture>;
+}
+
+// The invoker is reference counted so that the same API instance can be shared by multiple windows.
+#[cfg(not(feature = "threadsafe"))]
+pub(in crate) type ApiInvoker = Rc<dyn Fn( BrowserWindowHandle, &str, Vec<String> ) -> Option<ApiFuture>>;
+#[cfg(feature = "threadsafe")]
+pub(in crate) type ApiInvoker = Arc<dyn Fn( BrowserWindowHandle, &str, Vec<String> ) -> Option<ApiFuture> + Send + Sync>;
+
+/// A function that can be called from JavaScript.
+///
+/// Commands are generated with the `#[browser_window::command]` attribute, and collected with `generate_handler!`, instead of being constructed manually.
+#[derive(Clone, Copy)]
+pub struct Command {
+	name: &'static str,
+	codec: &'static str,
+	codec_js: &'static str,
+	invoke: fn( BrowserWindowHandle, Vec<String> ) -> ApiFuture
+}
+
+/// A set of commands, as given by `generate_handler!`.
+/// See `BrowserWindowBuilder::commands`.
+#[derive(Clone, Default)]
+pub struct Commands( Vec<Command> );
+
+/// A type-erased `Api` implementation, as it is stored by the browser window.
+#[derive(Clone)]
+pub(in crate) struct ApiEntry {
+	pub name: &'static str,
+	pub codec: &'static str,
+	pub invoke: ApiInvoker
+}
+
+// The command that the JavaScript bridge uses to call API methods with.
+pub(in crate) const CALL_COMMAND: &'static str = "__bw_call";
+
+/// The JavaScript code that needs to be loaded before any of the API stubs.
+/// It keeps track of the pending promises, and lets Rust resolve them.
+/// It also keeps the codecs, and which API uses which one.
+pub(in crate) const BRIDGE_JS: &'static str = r#"(function() {
+	if (window.__bw) return;
+	var pending = {};
+	var next_id = 0;
+	// Commands have a codec of their own, APIs have one for all of their methods
+	window.__bw = {
+		codecs: {
+			json: { encode: JSON.stringify, decode: JSON.parse }
+		},
+		api_codecs: {},
+		call: function(cmd, args) {
+			var id = next_id++;
+			var codec = window.__bw.codecs[window.__bw.api_codecs[cmd] || window.__bw.api_codecs[cmd.split(".")[0]] || "json"];
+			return new Promise(function(resolve, reject) {
+				pending[id] = { resolve: resolve, reject: reject };
+				var encoded_args = args.map(function(a) { return codec.encode(a === undefined ? null : a); });
+				invoke_extern.apply(null, ["__bw_call", String(id), cmd].concat(encoded_args));
+			});
+		},
+		resolve: function(id, ok, value, codec) {
+			var p = pending[id];
+			delete pending[id];
+			if (!p) return;
+			if (!ok) { p.reject(new Error(value)); return; }
+			try { p.resolve(codec ? window.__bw.codecs[codec].decode(value) : value); }
+			catch (e) { p.reject(e); }
+		}
+	};
+})();
+"#;
+
+
+
+#[doc(hidden)]
+#[cfg(feature = "macros")]
+pub mod __private {
+	pub use serde_json;
+}
+
+
+
+#[cfg(not(feature = "threadsafe"))]
+pub(in crate) fn api_entry<A>( api: A ) -> ApiEntry where
+	A: Api
+{
+	let api = Arc::new( api );
+
+	ApiEntry {
+		name: A::js_name(),
+		codec: A::codec_name(),
+		invoke: Rc::new(move |handle, method, args| A::invoke( api.clone(), handle, method, args ))
+	}
+}
+
+#[cfg(feature = "threadsafe")]
+pub(in crate) fn api_entry<A>( api: A ) -> ApiEntry where
+	A: Api + Send + Sync
+{
+	let api = Arc::new( api );
+
+	ApiEntry {
+		name: A::js_name(),
+		codec: A::codec_name(),
+		invoke: Arc::new(move |handle, method, args| A::invoke( api.clone(), handle, method, args ))
+	}
+}
+
+impl Command {
+
+	#[doc(hidden)]
+	pub fn new( name: &'static str, codec: &'static str, codec_js: &'static str, invoke: fn( BrowserWindowHandle, Vec<String> ) -> ApiFuture ) -> Self {
+		Self { name, codec, codec_js, invoke }
+	}
+
+	/// The name by which the command is called from JavaScript.
+	pub fn name( &self ) -> &'static str {
+		self.name
+	}
+}
+
+impl Commands {
+
+	/// Adds the commands of `other` to these commands.
+	pub fn extend( &mut self, other: Commands ) {
+		self.0.extend( other.0 );
+	}
+
+	/// Gives the commands that are in the set.
+	pub fn iter( &self ) -> impl Iterator<Item=&Command> {
+		self.0.iter()
+	}
+
+	/// The JavaScript code that defines the commands as methods of the global `commands` object, after it has registered their codecs.
+	pub(in crate) fn js_stubs( &self ) -> String {
+		let mut js = format!( "window[{:?}] = window[{:?}] || {{}};\n", COMMANDS_NAME, COMMANDS_NAME );
+		let mut codecs: Vec<&str> = Vec::new();
+
+		for command in &self.0 {
+			let qualified_name = format!( "{}.{}", COMMANDS_NAME, command.name );
+
+			if !codecs.contains( &command.codec ) {
+				codecs.push( command.codec );
+				js += command.codec_js;
+			}
+			js += &format!(
+				"window.__bw.api_codecs[{:?}] = {:?};\nwindow[{:?}][{:?}] = function() {{ return window.__bw.call({:?}, Array.prototype.slice.call(arguments)); }};\n",
+				qualified_name, command.codec, COMMANDS_NAME, command.name, qualified_name
+			);
+		}
+		js
+	}
+
+	/// Gives an entry for every command, so that they are called just like the methods of APIs are.
+	pub(in crate) fn to_entries( &self ) -> Vec<ApiEntry> {
+		self.0.iter().map(|command| {
+			let command = *command;
+			let invoke = move |handle: BrowserWindowHandle, method: &str, args: Vec<String>| {
+				if method == command.name { Some( (command.invoke)( handle, args ) ) } else { None }
+			};
+
+			ApiEntry {
+				name: COMMANDS_NAME,
+				codec: command.codec,
+				#[cfg(not(feature = "threadsafe"))]
+				invoke: Rc::new( invoke ),
+				#[cfg(feature = "threadsafe")]
+				invoke: Arc::new( invoke )
+			}
+		}).collect()
+	}
+}
+
+impl From<Vec<Command>> for Commands {
+	fn from( commands: Vec<Command> ) -> Self {
+		Self( commands )
+	}
+}
+
+
+
+/// Handles a call that the JavaScript bridge has made, and resolves its promise when done.
+///
+/// # Arguments
+/// * `args` - The arguments of the `__bw_call` command: the promise ID, the qualified method name, and the encoded arguments.
+pub(in crate) fn handle_call( apis: &[ApiEntry], handle: BrowserWindowHandle, mut args: Vec<String> ) {
+	// Without an ID there is no promise to reject, so the error is thrown in the page instead
+	let id: u64 = match args.first().and_then(|id| id.parse().ok() ) {
+		None => {
+			handle.exec_js( &format!( "throw new Error({})", js_string_literal( "invalid API call: no valid call ID" ) ) );
+			return;
+		},
+		Some( id ) => id
+	};
+	if args.len() < 2 {
+		resolve( handle, id, "json", Err( "invalid API call: no method name".into() ) );
+		return;
+	}
+	let call_args = args.split_off( 2 );
+	let qualified_name = &args[1];
+
+	let call = match qualified_name.split_once('.') {
+		None => None,
+		Some( (api_name, method) ) => apis.iter()
+			.filter(|api| api.name == api_name)
+			.find_map(|api| (api.invoke)( handle, method, call_args.clone() ).map(|f| (api.codec, f) ))
+	};
+
+	match call {
+		None => resolve( handle, id, "json", Err( format!("unknown method: {}", qualified_name) ) ),
+		Some( (codec, f) ) => handle.app().spawn( async move {
+			let result = f.await;
+			resolve( handle, id, codec, result );
+		} )
+	}
+}
+
+fn resolve( handle: BrowserWindowHandle, id: u64, codec: &str, result: Result<String, String> ) {
+	let js = match result {
+		// JSON is valid JavaScript already, so it doesn't need to be decoded
+		Ok( json ) if codec == "json" => format!( "window.__bw.resolve({}, true, {})", id, json ),
+		Ok( encoded ) => format!( "window.__bw.resolve({}, true, {}, {})", id, js_string_literal( &encoded ), js_string_literal( codec ) ),
+		Err( msg ) => format!( "window.__bw.resolve({}, false, {})", id, js_string_literal( &msg ) )
+	};
+
+	handle.exec_js( &js );
+}
+
+/// Converts the given string into a JavaScript string literal.
+pub(in crate) fn js_string_literal( string: &str ) -> String {
+	let mut literal = String::with_capacity( string.len() + 2 );
+	literal.push('"');
+
+	for c in string.chars() {
+		match c {
+			'"' => literal.push_str("\\\""),
+			'\\' => literal.push_str("\\\\"),
+			'\n' => literal.push_str("\\n"),
+			'\r' => literal.push_str("\\r"),
+			'\t' => literal.push_str("\\t"),
+			// These are valid in JSON, but terminate lines in older JavaScript engines.
+			'\u{2028}' => literal.push_str("\\u2028"),
+			'\u{2029}' => literal.push_str("\\u2029"),
+			c if (c as u32) < 0x20 => literal.push_str( &format!("\\u{:04x}", c as u32) ),
+			c => literal.push(c)
+		}
+	}
+
+	literal.push('"');
+	literal
+}
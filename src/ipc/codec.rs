@@ -0,0 +1,247 @@
+use serde::{de::DeserializeOwned, Serialize};
+#[cfg(feature = "rmp")]
+use crate::browser::bytes::{base64_decode, base64_encode};
+
+
+
+/// The way in which the arguments and return values of API methods are encoded on their way between JavaScript and Rust.
+///
+/// An API uses JSON by default.
+/// Another codec can be chosen with the `codec` argument of the attribute, like `#[browser_window::api("storage", codec = MessagePack)]`.
+///
+/// A codec consists of a Rust side and a JavaScript side, which need to agree on the encoding.
+/// The native bridge only carries strings, so binary encodings need to be converted to text, like `MessagePack` does with base64.
+pub trait Codec: 'static {
+	/// The name by which the JavaScript side of the codec is registered.
+	fn name() -> &'static str;
+	/// The JavaScript code that registers the codec with the bridge, as `window.__bw.codecs[name] = { encode: ..., decode: ... }`.
+	/// `encode` takes any value and gives its encoding as a string, and `decode` does the opposite.
+	fn js() -> &'static str;
+	/// Decodes an argument that the page has passed to an API method.
+	/// `encoded` is `None` if the argument has been left out, which is treated like `null`.
+	fn decode<T: DeserializeOwned>( encoded: Option<&str> ) -> Result<T, String>;
+	/// Encodes the value that an API method returns, so that the page can decode it.
+	fn encode<T: Serialize + ?Sized>( value: &T ) -> Result<String, String>;
+}
+
+/// The codec that is used by default, which is JSON.
+/// Its JavaScript side is part of the bridge itself.
+pub struct Json;
+
+/// A codec that uses [MessagePack](https://msgpack.org/), which is more compact and quicker to encode than JSON, for chatty UIs.
+/// The encoded messages are base64 encoded, because the native bridge only carries strings.
+///
+/// Structs are encoded as maps, so that they appear as objects in JavaScript.
+/// Integers that don't fit into 53 bits lose precision in JavaScript, like they do with JSON.
+/// Byte buffers like `serde_bytes::ByteBuf` appear as a `Uint8Array`, and typed arrays can be passed for them.
+///
+/// *Note:* Only available with feature `rmp` enabled.
+#[cfg(feature = "rmp")]
+pub struct MessagePack;
+
+
+
+#[cfg(feature = "rmp")]
+const MESSAGE_PACK_JS: &'static str = r#"(function() {
+	if (window.__bw.codecs.msgpack) return;
+	var CHUNK = 0x8000;
+
+	function encode(value, out) {
+		if (value === null || value === undefined) out.push(0xc0);
+		else if (value === false) out.push(0xc2);
+		else if (value === true) out.push(0xc3);
+		else if (typeof value === "number") {
+			if (Number.isSafeInteger(value)) {
+				if (value >= 0) {
+					if (value < 0x80) out.push(value);
+					else if (value < 0x100) out.push(0xcc, value);
+					else if (value < 0x10000) out.push(0xcd, value >> 8, value & 0xff);
+					else if (value < 0x100000000) out.push(0xce, value >>> 24, (value >> 16) & 0xff, (value >> 8) & 0xff, value & 0xff);
+					else uint64(0xcf, value, out);
+				}
+				else {
+					if (value >= -0x20) out.push(value & 0xff);
+					else if (value >= -0x80) out.push(0xd0, value & 0xff);
+					else if (value >= -0x8000) out.push(0xd1, (value >> 8) & 0xff, value & 0xff);
+					else if (value >= -0x80000000) out.push(0xd2, (value >> 24) & 0xff, (value >> 16) & 0xff, (value >> 8) & 0xff, value & 0xff);
+					else uint64(0xd3, value, out);
+				}
+			}
+			else {
+				var view = new DataView(new ArrayBuffer(8));
+				view.setFloat64(0, value);
+				out.push(0xcb);
+				for (var i = 0; i < 8; i++) out.push(view.getUint8(i));
+			}
+		}
+		else if (typeof value === "string") {
+			var utf8 = new TextEncoder().encode(value);
+			if (utf8.length < 0x20) out.push(0xa0 | utf8.length);
+			else header(0xd9, utf8.length, out);
+			push_bytes(utf8, out);
+		}
+		else if (value instanceof ArrayBuffer || ArrayBuffer.isView(value)) {
+			var bytes = value instanceof ArrayBuffer ? new Uint8Array(value) : new Uint8Array(value.buffer, value.byteOffset, value.byteLength);
+			header(0xc4, bytes.length, out);
+			push_bytes(bytes, out);
+		}
+		else if (Array.isArray(value)) {
+			if (value.length < 0x10) out.push(0x90 | value.length);
+			else header(0xdc, value.length, out, true);
+			value.forEach(function(item) { encode(item, out); });
+		}
+		else if (typeof value.toJSON === "function") encode(value.toJSON(), out);
+		else {
+			var keys = Object.keys(value).filter(function(k) { return value[k] !== undefined; });
+			if (keys.length < 0x10) out.push(0x80 | keys.length);
+			else header(0xde, keys.length, out, true);
+			keys.forEach(function(k) { encode(k, out); encode(value[k], out); });
+		}
+	}
+
+	// Writes the 8, 16 or 32 bit length that follows one of the given markers.
+	// Arrays and maps have no 8 bit length, so their first marker is the 16 bit one.
+	function header(marker, length, out, no8) {
+		if (!no8 && length < 0x100) out.push(marker, length);
+		else if (length < 0x10000) out.push(marker + (no8 ? 0 : 1), length >> 8, length & 0xff);
+		else out.push(marker + (no8 ? 1 : 2), length >>> 24, (length >> 16) & 0xff, (length >> 8) & 0xff, length & 0xff);
+	}
+
+	function uint64(marker, value, out) {
+		var view = new DataView(new ArrayBuffer(8));
+		view.setBigInt64(0, BigInt(value));
+		out.push(marker);
+		for (var i = 0; i < 8; i++) out.push(view.getUint8(i));
+	}
+
+	function push_bytes(bytes, out) {
+		for (var i = 0; i < bytes.length; i++) out.push(bytes[i]);
+	}
+
+	function decode(view, pos) {
+		var m = view.getUint8(pos.i++);
+		if (m < 0x80) return m;
+		if (m < 0x90) return map(view, pos, m & 0x0f);
+		if (m < 0xa0) return array(view, pos, m & 0x0f);
+		if (m < 0xc0) return str(view, pos, m & 0x1f);
+		if (m >= 0xe0) return m - 0x100;
+		switch (m) {
+			case 0xc0: return null;
+			case 0xc2: return false;
+			case 0xc3: return true;
+			case 0xc4: return bin(view, pos, read(view, pos, 1));
+			case 0xc5: return bin(view, pos, read(view, pos, 2));
+			case 0xc6: return bin(view, pos, read(view, pos, 4));
+			case 0xca: pos.i += 4; return view.getFloat32(pos.i - 4);
+			case 0xcb: pos.i += 8; return view.getFloat64(pos.i - 8);
+			case 0xcc: return read(view, pos, 1);
+			case 0xcd: return read(view, pos, 2);
+			case 0xce: return read(view, pos, 4);
+			case 0xcf: pos.i += 8; return Number(view.getBigUint64(pos.i - 8));
+			case 0xd0: pos.i += 1; return view.getInt8(pos.i - 1);
+			case 0xd1: pos.i += 2; return view.getInt16(pos.i - 2);
+			case 0xd2: pos.i += 4; return view.getInt32(pos.i - 4);
+			case 0xd3: pos.i += 8; return Number(view.getBigInt64(pos.i - 8));
+			case 0xd9: return str(view, pos, read(view, pos, 1));
+			case 0xda: return str(view, pos, read(view, pos, 2));
+			case 0xdb: return str(view, pos, read(view, pos, 4));
+			case 0xdc: return array(view, pos, read(view, pos, 2));
+			case 0xdd: return array(view, pos, read(view, pos, 4));
+			case 0xde: return map(view, pos, read(view, pos, 2));
+			case 0xdf: return map(view, pos, read(view, pos, 4));
+		}
+		throw new Error("unsupported MessagePack type: " + m);
+	}
+
+	function read(view, pos, size) {
+		pos.i += size;
+		if (size === 1) return view.getUint8(pos.i - 1);
+		if (size === 2) return view.getUint16(pos.i - 2);
+		return view.getUint32(pos.i - 4);
+	}
+
+	function bin(view, pos, length) {
+		pos.i += length;
+		return new Uint8Array(view.buffer.slice(pos.i - length, pos.i));
+	}
+
+	function str(view, pos, length) {
+		pos.i += length;
+		return new TextDecoder().decode(new Uint8Array(view.buffer, pos.i - length, length));
+	}
+
+	function array(view, pos, length) {
+		var result = [];
+		for (var i = 0; i < length; i++) result.push(decode(view, pos));
+		return result;
+	}
+
+	function map(view, pos, length) {
+		var result = {};
+		for (var i = 0; i < length; i++) {
+			var key = decode(view, pos);
+			result[key] = decode(view, pos);
+		}
+		return result;
+	}
+
+	window.__bw.codecs.msgpack = {
+		encode: function(value) {
+			var out = [];
+			encode(value, out);
+			var binary = "";
+			for (var i = 0; i < out.length; i += CHUNK) {
+				binary += String.fromCharCode.apply(null, out.slice(i, i + CHUNK));
+			}
+			return btoa(binary);
+		},
+		decode: function(encoded) {
+			var binary = atob(encoded);
+			var bytes = new Uint8Array(binary.length);
+			for (var i = 0; i < binary.length; i++) bytes[i] = binary.charCodeAt(i);
+			return decode(new DataView(bytes.buffer), { i: 0 });
+		}
+	};
+})();
+"#;
+
+
+
+impl Codec for Json {
+
+	fn name() -> &'static str { "json" }
+
+	fn js() -> &'static str { "" }
+
+	fn decode<T: DeserializeOwned>( encoded: Option<&str> ) -> Result<T, String> {
+		serde_json::from_str( encoded.unwrap_or( "null" ) ).map_err(|e| e.to_string() )
+	}
+
+	fn encode<T: Serialize + ?Sized>( value: &T ) -> Result<String, String> {
+		serde_json::to_string( value ).map_err(|e| e.to_string() )
+	}
+}
+
+#[cfg(feature = "rmp")]
+impl Codec for MessagePack {
+
+	fn name() -> &'static str { "msgpack" }
+
+	fn js() -> &'static str { MESSAGE_PACK_JS }
+
+	fn decode<T: DeserializeOwned>( encoded: Option<&str> ) -> Result<T, String> {
+		// A single nil marker
+		let bytes = match encoded {
+			None => vec![0xc0],
+			Some( e ) => base64_decode( e ).ok_or( "invalid base64".to_owned() )?
+		};
+
+		rmp_serde::from_slice( &bytes ).map_err(|e| e.to_string() )
+	}
+
+	fn encode<T: Serialize + ?Sized>( value: &T ) -> Result<String, String> {
+		let bytes = rmp_serde::to_vec_named( value ).map_err(|e| e.to_string() )?;
+
+		Ok( base64_encode( &bytes ) )
+	}
+}
@@ -15,6 +15,42 @@
 //! 
 //! # Thread safety
 //! To use the threadsafe version of _Browser Window_, enable feature `threadsafe`.
+//! This also enables the [`sidecar`](sidecar/index.html) module, which lets helper processes exchange messages with the pages.
+//!
+//! # Macros
+//! To expose Rust methods and functions to JavaScript with the `#[browser_window::api]` and `#[browser_window::command]` attributes, enable feature `macros`.
+//! See the [`ipc`](ipc/index.html) module.
+//! Feature `rmp` adds MessagePack as a more compact alternative to JSON for the arguments and return values of those methods.
+//!
+//! # Assets
+//! To serve files that are embedded into the executable over a custom scheme, enable feature `assets`.
+//! See the [`assets`](assets/index.html) module.
+//!
+//! # Development
+//! To have pages reload by themselves whenever their files change, enable feature `dev`.
+//! See `BrowserWindowBuilder::watch`.
+//!
+//! To call the methods of the Chrome DevTools Protocol and receive its events, enable feature `cdp`.
+//! See `BrowserWindowHandle::cdp`.
+//!
+//! # Testing
+//! To write integration tests that click and type in the page like the user would, see the [`automation`](automation/index.html) module.
+//!
+//! To unit test the code around your windows without a display or a browser engine, like in CI, enable feature `mock`.
+//! It replaces the browser engine by a page model that runs no JavaScript, but records the navigations and the JavaScript that is evaluated, and gives back the results that you decide on.
+//! See `BrowserWindowHandle::mock_page`.
+//!
+//! # Crash recovery
+//! To write snapshots of the open windows and of your own state, and to restore them after a crash, enable feature `recovery`.
+//! See `ApplicationHandle::write_recovery_snapshot` and `Runtime::recover_from` in the [`application`](application/index.html) module.
+//!
+//! # Interoperability
+//! To hand windows over to libraries that are built on [raw-window-handle](https://docs.rs/raw-window-handle/0.5), like wry, tao and winit, enable feature `raw-window-handle`.
+//! Windows and browser windows then implement `HasRawWindowHandle` and `HasRawDisplayHandle`.
+//! The other way around, `WindowBuilder::embed_in` puts a browser window inside a window of such a library.
+//!
+//! # Demos
+//! To check whether everything works on a platform, enable feature `demos`, and build one of the windows of the [`demos`](demos/index.html) module.
 
 
 #[macro_use]
@@ -23,11 +59,23 @@ mod prop;
 mod tests;
 
 pub mod application;
+#[cfg(feature = "assets")]
+pub mod assets;
+pub mod automation;
 pub mod browser;
+pub mod clipboard;
 pub mod cookie;
+#[cfg(feature = "demos")]
+pub mod demos;
 pub mod error;
 pub mod event;
+pub mod ipc;
+pub mod menu;
+pub mod permission;
 pub mod prelude;
+#[cfg(feature = "threadsafe")]
+pub mod sidecar;
+pub mod tray_icon;
 pub mod window;
 
 
@@ -38,6 +86,11 @@ mod delegate;
 pub use delegate::{
 	DelegateError,
 	DelegateFuture,
-	DelegateFutureFuture
+	DelegateFutureFuture,
+	DispatchFuture,
+	GuiRef
 };
-pub use prop::Property;
+pub use prop::{Property, PropertyChanges, PropertyChangesNext};
+
+#[cfg(feature = "macros")]
+pub use browser_window_macros::{api, command, generate_handler};
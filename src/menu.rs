@@ -0,0 +1,204 @@
+//! This module contains the types to give browser windows a native menu bar.
+//!
+//! A `MenuBar` holds `Menu`s, which in turn hold `MenuItem`s, separators and submenus.
+//! Every item has an identifier, which is passed to the handler set with `BrowserWindowBuilder::on_menu_item` when the item gets activated.
+//! A `Menu` can also be used as the context menu of a tray icon, see the [`tray_icon`](../tray_icon/index.html) module.
+//!
+//! Labels can mark a mnemonic with `&`, like `&File`, and `&&` gives a literal `&`.
+//! Accelerators are written like `Ctrl+S` or `Ctrl+Shift+F5`.
+//!
+//! *Note:* Menu bars are not supported when CEF provides the windows itself, which is when feature `gtk` is disabled on other platforms than Windows.
+//!
+//! # Example
+//! ```ignore
+//! use browser_window::{browser::*, menu::*};
+//!
+//! let menu_bar = MenuBar::new()
+//! 	.menu( Menu::new("&File")
+//! 		.item( MenuItem::new( "open", "&Open..." ).accelerator("Ctrl+O") )
+//! 		.separator()
+//! 		.item( MenuItem::new( "quit", "&Quit" ) )
+//! 	)
+//! 	.menu( Menu::new("&View")
+//! 		.item( MenuItem::new( "sidebar", "&Sidebar" ).checkbox( true ) )
+//! 	);
+//!
+//! let mut bwb = BrowserWindowBuilder::new( Source::File( "index.html".into() ) );
+//! bwb.menu( menu_bar );
+//! bwb.on_menu_item(|handle, event| {
+//! 	if event.id() == "quit" {
+//! 		handle.close();
+//! 	}
+//! });
+//! ```
+
+use browser_window_core::menu::{MenuExt, MenuImpl};
+
+
+
+/// The menu bar at the top of a window.
+#[derive(Clone, Default)]
+pub struct MenuBar {
+	menus: Vec<Menu>
+}
+
+/// A menu with items, which can be put on a menu bar or in another menu.
+#[derive(Clone)]
+pub struct Menu {
+	label: String,
+	entries: Vec<MenuEntry>
+}
+
+/// An item of a menu, which can be activated by the user.
+#[derive(Clone)]
+pub struct MenuItem {
+	id: String,
+	label: String,
+	accelerator: Option<String>,
+	checkable: bool,
+	checked: bool
+}
+
+/// Describes the activation of a menu item.
+#[derive(Clone, Debug)]
+pub struct MenuItemEvent {
+	pub(in crate) id: String,
+	pub(in crate) checked: bool
+}
+
+#[derive(Clone)]
+enum MenuEntry {
+	Item( MenuItem ),
+	Separator,
+	Submenu( Menu )
+}
+
+
+
+impl MenuBar {
+
+	/// Adds a menu to the bar.
+	pub fn menu( mut self, menu: Menu ) -> Self {
+		self.menus.push( menu );
+		self
+	}
+
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Creates the native menu for this menu bar.
+	/// The identifiers of all its items are put in `item_ids`, so that the native item ID of an item is its index plus one.
+	pub(in crate) fn to_impl( &self, item_ids: &mut Vec<String> ) -> MenuImpl {
+		let handle = MenuImpl::new();
+
+		for menu in &self.menus {
+			handle.add_submenu( &menu.label, menu.to_impl( item_ids ) );
+		}
+
+		handle
+	}
+}
+
+impl Menu {
+
+	/// Adds an item to the menu.
+	pub fn item( mut self, item: MenuItem ) -> Self {
+		self.entries.push( MenuEntry::Item( item ) );
+		self
+	}
+
+	/// Creates an empty menu with the given label.
+	pub fn new<S: Into<String>>( label: S ) -> Self {
+		Self {
+			label: label.into(),
+			entries: Vec::new()
+		}
+	}
+
+	/// Adds a separator line to the menu.
+	pub fn separator( mut self ) -> Self {
+		self.entries.push( MenuEntry::Separator );
+		self
+	}
+
+	/// Adds a menu that unfolds when its entry in this menu is hovered.
+	pub fn submenu( mut self, menu: Menu ) -> Self {
+		self.entries.push( MenuEntry::Submenu( menu ) );
+		self
+	}
+
+	/// Creates the native menu for this menu, in the same way as `MenuBar::to_impl` does.
+	pub(in crate) fn to_impl( &self, item_ids: &mut Vec<String> ) -> MenuImpl {
+		let handle = MenuImpl::new();
+
+		for entry in &self.entries {
+			match entry {
+				MenuEntry::Item( item ) => {
+					item_ids.push( item.id.clone() );
+
+					handle.add_item(
+						item_ids.len() as u32,
+						&item.label,
+						item.accelerator.as_deref().unwrap_or(""),
+						item.checkable,
+						item.checked
+					);
+				},
+				MenuEntry::Separator => handle.add_separator(),
+				MenuEntry::Submenu( menu ) => handle.add_submenu( &menu.label, menu.to_impl( item_ids ) )
+			}
+		}
+
+		handle
+	}
+}
+
+impl MenuItem {
+
+	/// Sets the key combination that activates the item, like `Ctrl+S`.
+	/// It is also displayed next to the label.
+	pub fn accelerator<S: Into<String>>( mut self, accelerator: S ) -> Self {
+		self.accelerator = Some( accelerator.into() );
+		self
+	}
+
+	/// Makes the item a checkbox, which toggles its check mark every time it gets activated.
+	///
+	/// # Arguments
+	/// * `checked` - Whether or not the item starts out checked.
+	pub fn checkbox( mut self, checked: bool ) -> Self {
+		self.checkable = true;
+		self.checked = checked;
+		self
+	}
+
+	/// Creates a menu item.
+	///
+	/// # Arguments
+	/// * `id` - The identifier that is given to the menu handler when the item gets activated.
+	/// * `label` - The text of the item.
+	pub fn new<I: Into<String>, L: Into<String>>( id: I, label: L ) -> Self {
+		Self {
+			id: id.into(),
+			label: label.into(),
+			accelerator: None,
+			checkable: false,
+			checked: false
+		}
+	}
+}
+
+impl MenuItemEvent {
+
+	/// The identifier of the item that has been activated.
+	pub fn id( &self ) -> &str {
+		&self.id
+	}
+
+	/// Whether or not the item is checked now.
+	/// Is always false for items that aren't checkboxes.
+	pub fn is_checked( &self ) -> bool {
+		self.checked
+	}
+}
@@ -0,0 +1,311 @@
+//! A policy that decides which permissions pages get, for all browser windows at once.
+//!
+//! The policy can be loaded from a file, so that the capabilities of the browser can be managed without recompiling the application.
+//! It consists of sections that start with the origin they apply to, followed by the permissions that are allowed or denied for it:
+//! ```text
+//! # Applies to every origin that isn't listed below
+//! [*]
+//! popups = deny
+//! downloads = deny
+//!
+//! # Applies to example.com and all of its subdomains, with any scheme
+//! [*.example.com]
+//! downloads = allow
+//!
+//! [https://intranet.example.com]
+//! camera = allow
+//! clipboard = allow
+//! downloads = ask
+//! ```
+//! The permissions are `camera` (which includes the microphone), `clipboard`, `popups`, `downloads` and `screen_capture`.
+//! They can be set to `allow`, `deny`, or `ask`, which leaves it to the browser engine.
+//! The browser engine may ask the user, or use its own default.
+//! Permissions that aren't mentioned for an origin are also left to the browser engine.
+//! What the policy leaves to the browser engine can still be decided per window, with `BrowserWindowBuilder::default_permission`.
+//!
+//! For an origin, the section of that exact origin is looked at first, then the one of the closest domain, and finally `[*]`.
+//! The first section that mentions the permission decides it.
+//!
+//! # Example
+//! ```ignore
+//! app.load_permission_policy( "/etc/my-app/permissions.conf" )?;
+//!
+//! // Later on, after the file has been changed:
+//! app.reload_permission_policy()?;
+//! ```
+//!
+//! *Note:* WebView2 only reports downloads from version 1.0.902 on.
+//! CEF cancels all downloads unless they are allowed by the policy.
+//! WebView2 only reports screen capture from version 1.0.2420 on, and always lets the user pick what to share, even when it is allowed.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::RwLock;
+
+use lazy_static::lazy_static;
+
+use crate::application::ApplicationHandle;
+
+
+
+/// A capability that a page can ask for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Permission {
+	/// Using the camera or the microphone.
+	Camera,
+	/// Reading from the clipboard.
+	Clipboard,
+	/// Opening new windows, with `window.open` or with links that have a target.
+	Popups,
+	/// Downloading files.
+	Downloads,
+	/// Capturing the screen, a window, or the audio of the system, with `getDisplayMedia`.
+	ScreenCapture
+}
+
+/// A set of rules that decide which permissions are granted to which origins.
+/// See the module documentation for its format.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PermissionPolicy {
+	sections: Vec<PolicySection>
+}
+
+/// An error that occurs when a permission policy can't be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PermissionPolicyError {
+	/// The number of the line that is invalid, starting from 1.
+	pub line: usize
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct PolicySection {
+	origin: String,
+	rules: Vec<(Permission, Option<bool>)>
+}
+
+/// The policy that is in use, with the file that it has been loaded from, if any.
+#[derive(Default)]
+struct ActivePolicy {
+	policy: PermissionPolicy,
+	path: Option<PathBuf>
+}
+
+
+
+lazy_static! {
+	// Browser engines may ask for a permission on another thread than the GUI thread, and need the answer right away.
+	static ref POLICY: RwLock<ActivePolicy> = RwLock::new( ActivePolicy::default() );
+}
+
+
+
+impl Permission {
+
+	/// The name of the permission as it is used in policy files.
+	pub fn name( &self ) -> &'static str {
+		match self {
+			Self::Camera => "camera",
+			Self::Clipboard => "clipboard",
+			Self::Popups => "popups",
+			Self::Downloads => "downloads",
+			Self::ScreenCapture => "screen_capture"
+		}
+	}
+
+	pub(in crate) fn from_c( permission: u8 ) -> Option<Self> {
+		match permission {
+			0 => Some( Self::Camera ),
+			1 => Some( Self::Clipboard ),
+			2 => Some( Self::Popups ),
+			3 => Some( Self::Downloads ),
+			4 => Some( Self::ScreenCapture ),
+			_ => None
+		}
+	}
+
+	fn from_name( name: &str ) -> Option<Self> {
+		[Self::Camera, Self::Clipboard, Self::Popups, Self::Downloads, Self::ScreenCapture].iter().copied().find(|p| p.name() == name )
+	}
+
+	pub(in crate) fn to_c( &self ) -> u8 {
+		match self {
+			Self::Camera => 0,
+			Self::Clipboard => 1,
+			Self::Popups => 2,
+			Self::Downloads => 3,
+			Self::ScreenCapture => 4
+		}
+	}
+}
+
+impl fmt::Display for Permission {
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
+		write!( f, "{}", self.name() )
+	}
+}
+
+impl PermissionPolicy {
+
+	/// Decides whether the page at the given address gets the given permission.
+	/// Returns `None` if it is left to the browser engine.
+	pub fn decide( &self, permission: Permission, url: &str ) -> Option<bool> {
+		let origin = origin_of( url );
+		let host = host_of( &origin );
+
+		// The exact origin, then the domains from the closest one up, and then all origins
+		let mut candidates = vec![origin.clone()];
+		let mut domain = host;
+		loop {
+			candidates.push( format!( "*.{}", domain ) );
+			match domain.find('.') {
+				None => break,
+				Some( i ) => domain = &domain[i+1..]
+			}
+		}
+		candidates.push( "*".to_owned() );
+
+		candidates.iter()
+			.filter_map(|c| self.sections.iter().find(|s| s.origin == *c ) )
+			.find_map(|s| s.rules.iter().find(|r| r.0 == permission ).map(|r| r.1 ) )
+			.flatten()
+	}
+
+	/// Reads the policy from the file at `path`.
+	/// If the file isn't valid, an error of kind `io::ErrorKind::InvalidData` is returned, with a `PermissionPolicyError` inside.
+	pub fn load<P: AsRef<Path>>( path: P ) -> io::Result<Self> {
+		fs::read_to_string( path )?.parse().map_err(|e| io::Error::new( io::ErrorKind::InvalidData, e ) )
+	}
+}
+
+impl FromStr for PermissionPolicy {
+	type Err = PermissionPolicyError;
+
+	fn from_str( string: &str ) -> Result<Self, Self::Err> {
+		let mut sections: Vec<PolicySection> = Vec::new();
+
+		for (index, line) in string.lines().enumerate() {
+			let line = line.trim();
+			if line.is_empty() || line.starts_with('#') { continue }
+
+			let error = PermissionPolicyError { line: index + 1 };
+
+			if line.starts_with('[') {
+				if !line.ends_with(']') || line.len() < 3 {
+					return Err( error );
+				}
+				let origin = line[1..line.len()-1].trim();
+				sections.push( PolicySection { origin: normalize_origin( origin ), rules: Vec::new() } );
+			}
+			else {
+				let (name, value) = line.split_once('=').ok_or( error.clone() )?;
+				let permission = Permission::from_name( name.trim() ).ok_or( error.clone() )?;
+				let decision = match value.trim() {
+					"allow" => Some( true ),
+					"deny" => Some( false ),
+					"ask" => None,
+					_ => return Err( error )
+				};
+
+				// Rules need to be in a section
+				sections.last_mut().ok_or( error )?.rules.push( (permission, decision) );
+			}
+		}
+
+		Ok( Self { sections } )
+	}
+}
+
+impl fmt::Display for PermissionPolicyError {
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
+		write!( f, "invalid permission policy at line {}", self.line )
+	}
+}
+
+impl std::error::Error for PermissionPolicyError {}
+
+
+
+impl ApplicationHandle {
+
+	/// Reads the permission policy from the file at `path`, and applies it to all browser windows from now on.
+	/// The file is remembered, so that `reload_permission_policy` can read it again.
+	/// See the `permission` module for the format of the file.
+	///
+	/// If the file can't be read or is invalid, the policy that was in use stays in use.
+	pub fn load_permission_policy<P: AsRef<Path>>( &self, path: P ) -> io::Result<()> {
+		let policy = PermissionPolicy::load( path.as_ref() )?;
+
+		let mut active = POLICY.write().unwrap();
+		active.policy = policy;
+		active.path = Some( path.as_ref().to_owned() );
+		Ok(())
+	}
+
+	/// Reads the file of `load_permission_policy` again, so that changes to it take effect without restarting the application.
+	/// Does nothing if the policy hasn't been loaded from a file.
+	///
+	/// If the file can't be read or is invalid, the policy that was in use stays in use.
+	pub fn reload_permission_policy( &self ) -> io::Result<()> {
+		let path = POLICY.read().unwrap().path.clone();
+
+		match path {
+			None => Ok(()),
+			Some( p ) => self.load_permission_policy( p )
+		}
+	}
+
+	/// Applies the given permission policy to all browser windows from now on.
+	/// This also forgets the file that a previous policy was loaded from.
+	pub fn set_permission_policy( &self, policy: PermissionPolicy ) {
+		let mut active = POLICY.write().unwrap();
+		active.policy = policy;
+		active.path = None;
+	}
+}
+
+
+
+/// Decides the permission for the page at `url` with the policy that is in use.
+pub(in crate) fn decide( permission: Permission, url: &str ) -> Option<bool> {
+	POLICY.read().unwrap().policy.decide( permission, url )
+}
+
+/// Gives the decisions of a browser window for the permissions that the policy leaves open, as the browser engine takes them.
+/// There is one for every permission, which is 1 to allow it, 0 to deny it, or -1 to leave it to the browser engine.
+pub(in crate) fn defaults_to_c( defaults: &[(Permission, bool)] ) -> [i8; 5] {
+	let mut c_defaults = [-1; 5];
+	for (permission, allowed) in defaults {
+		c_defaults[ permission.to_c() as usize ] = if *allowed {1} else {0};
+	}
+	c_defaults
+}
+
+/// Gives the origin of the given address, which is its scheme, host and port.
+/// Addresses without a host, like `about:blank`, give `null`.
+fn origin_of( url: &str ) -> String {
+	let (scheme, rest) = match url.split_once( "://" ) {
+		None => return "null".to_owned(),
+		Some( parts ) => parts
+	};
+	let authority = rest.split(|c| c == '/' || c == '?' || c == '#' ).next().unwrap_or("");
+	let authority = authority.rsplit('@').next().unwrap_or("");
+
+	normalize_origin( &format!( "{}://{}", scheme, authority ) )
+}
+
+fn host_of( origin: &str ) -> &str {
+	let authority = origin.split_once( "://" ).map(|(_, a)| a ).unwrap_or("");
+
+	// IPv6 addresses contain colons too
+	if authority.starts_with('[') {
+		return authority.split(']').next().map(|h| &h[1..] ).unwrap_or("");
+	}
+	authority.split(':').next().unwrap_or("")
+}
+
+fn normalize_origin( origin: &str ) -> String {
+	origin.trim_end_matches('/').to_lowercase()
+}
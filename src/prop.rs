@@ -41,6 +41,27 @@
 //! string.push_str("something");
 //! my_struct.my_property().set( string );
 //! ```
+//!
+//! # Browser windows
+//! The properties of browser windows (`BrowserProperty`) can also be followed: `changes` gives a `PropertyChanges` stream of its new values.
+//! With feature `threadsafe`, `BrowserWindowThreaded` has the same properties (`BrowserPropertyThreaded`), of which `get` and `set` return futures that resolve once the GUI thread has carried them out.
+//! ```ignore
+//! bw.title().set( "Loading..." ).await?;
+//! let size = bw.content_dimensions().get().await?;
+//!
+//! let mut changes = bw.zoom().changes();
+//! while let Some( zoom ) = changes.next().await {
+//! 	println!( "Zoomed to {}%", zoom * 100.0 );
+//! }
+//! ```
+
+use futures_channel::mpsc;
+use futures_core::stream::Stream;
+use std::{
+	future::Future,
+	pin::Pin,
+	task::{Context, Poll}
+};
 
 /// A property is something that has a setter and a getter.
 // The setters are immutable.
@@ -50,6 +71,85 @@ pub trait Property<G,S> {
 	fn set( &self, value: S );
 }
 
+/// A stream of the values that a property gets, every time it has changed.
+/// It ends when the object that the property belongs to has been destroyed.
+///
+/// It implements `futures_core::Stream`, and `next` waits for the next value without needing any other crate.
+pub struct PropertyChanges<T> {
+	receiver: mpsc::UnboundedReceiver<T>
+}
+
+/// The future returned by `PropertyChanges::next`.
+pub struct PropertyChangesNext<'a,T> {
+	changes: &'a mut PropertyChanges<T>
+}
+
+/// The senders of all `PropertyChanges` streams that follow a property.
+pub(in crate) struct PropertyWatchers<T> {
+	senders: Vec<mpsc::UnboundedSender<T>>
+}
+
+
+
+impl<T> PropertyChanges<T> {
+
+	/// Creates a stream, together with the sender that feeds it.
+	pub(in crate) fn new() -> (mpsc::UnboundedSender<T>, Self) {
+		let (sender, receiver) = mpsc::unbounded();
+
+		(sender, Self { receiver })
+	}
+
+	/// Waits for the next value of the property.
+	/// Resolves to `None` when the stream has ended.
+	pub fn next( &mut self ) -> PropertyChangesNext<'_,T> {
+		PropertyChangesNext { changes: self }
+	}
+}
+
+impl<T> Stream for PropertyChanges<T> {
+	type Item = T;
+
+	fn poll_next( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Option<T>> {
+		Pin::new( &mut self.get_mut().receiver ).poll_next( cx )
+	}
+}
+
+impl<'a,T> Future for PropertyChangesNext<'a,T> {
+	type Output = Option<T>;
+
+	fn poll( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Option<T>> {
+		Pin::new( &mut *self.get_mut().changes ).poll_next( cx )
+	}
+}
+
+impl<T> PropertyWatchers<T> where T: Clone {
+
+	pub fn add( &mut self, sender: mpsc::UnboundedSender<T> ) {
+		self.senders.push( sender );
+	}
+
+	/// Sends the new value to all streams, and forgets the ones that have been dropped.
+	pub fn notify( &mut self, value: T ) {
+		self.senders.retain(|s| s.unbounded_send( value.clone() ).is_ok() );
+	}
+
+	/// Creates a new stream that follows the property.
+	pub fn watch( &mut self ) -> PropertyChanges<T> {
+		let (sender, changes) = PropertyChanges::new();
+		self.add( sender );
+		changes
+	}
+}
+
+impl<T> Default for PropertyWatchers<T> {
+	fn default() -> Self {
+		Self {
+			senders: Vec::new()
+		}
+	}
+}
+
 
 
 #[doc(hidden)]
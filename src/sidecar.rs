@@ -0,0 +1,324 @@
+//! This module lets helper processes, written in any language, exchange messages with the pages of the browser windows.
+//! Such a process is called a sidecar, and it talks to the application over its standard input and output.
+//!
+//! **Note:** Only available with feature `threadsafe` enabled.
+//!
+//! A sidecar has a label, just like a browser window can have, see `BrowserWindowBuilder::label`.
+//! Messages that are sent to that label, with `BrowserWindowHandle::emit_to` or with `bw_emit_to(label, event, payload)` in JavaScript, are written to the sidecar's standard input.
+//! And the sidecar sends messages to windows by writing them to its standard output.
+//!
+//! # Protocol
+//! Every message is one line of UTF-8 text, with its fields separated by tabs.
+//! Tabs, newlines, carriage returns and backslashes within the fields are escaped as `\t`, `\n`, `\r` and `\\`.
+//!
+//! The sidecar receives `<from>\t<event>\t<payload>`, where `<from>` is the label of the window that has sent the message.
+//! It is empty for messages that have been sent with `Sidecar::send`, and for windows without a label.
+//!
+//! The sidecar sends `<to>\t<event>\t<payload>`, where `<to>` is the label of the window that the message is for, or `*` for all windows.
+//! The messages arrive in the same way as the ones of other windows do, with the label of the sidecar as their sender.
+//! Lines that aren't messages are ignored.
+//!
+//! # Example
+//! ```ignore
+//! let sidecar = SidecarBuilder::new( "python3" )
+//! 	.arg( "helper.py" )
+//! 	.label( "helper" )
+//! 	.spawn( app.into_threaded() )?;
+//! ```
+//! With `helper.py`:
+//! ```python
+//! import sys
+//! for line in sys.stdin:
+//! 	sender, event, payload = line.rstrip("\n").split("\t")
+//! 	if event == "ping":
+//! 		print(sender + "\tpong\t" + payload, flush=True)
+//! ```
+//! And in the page of the window that has label `main`:
+//! ```js
+//! bw_subscribe("pong", function(payload, from) { console.log(from + " says " + payload); });
+//! bw_emit_to("helper", "ping", "hello");
+//! ```
+
+use crate::application::ApplicationHandleThreaded;
+use crate::browser::{message, registry, BrowserWindowHandle, WindowMessage};
+
+use lazy_static::lazy_static;
+use std::{
+	ffi::OsString,
+	io::{self, BufRead, BufReader, Write},
+	process::{Child, ChildStdin, Command, Stdio},
+	sync::{
+		Arc, Mutex, Weak,
+		atomic::{AtomicBool, Ordering}
+	},
+	thread,
+	time::Duration
+};
+
+
+
+/// Used to start a sidecar.
+pub struct SidecarBuilder {
+	args: Vec<OsString>,
+	label: String,
+	program: OsString,
+	restart: bool
+}
+
+/// A running sidecar.
+/// Dropping it, or calling `stop`, terminates the process.
+pub struct Sidecar {
+	shared: Arc<SidecarShared>
+}
+
+struct SidecarShared {
+	child: Mutex<Option<Child>>,
+	label: String,
+	stdin: Mutex<Option<ChildStdin>>,
+	stopped: AtomicBool
+}
+
+
+
+lazy_static! {
+	// The sidecars that are running, so that messages can be sent to them by their label.
+	static ref SIDECARS: Mutex<Vec<Weak<SidecarShared>>> = Mutex::new( Vec::new() );
+}
+
+// How long to wait before starting a sidecar again, after it has crashed.
+const RESTART_DELAY: Duration = Duration::from_secs( 1 );
+
+
+
+impl SidecarBuilder {
+
+	/// Adds an argument that is passed to the program.
+	pub fn arg<S: Into<OsString>>( mut self, arg: S ) -> Self {
+		self.args.push( arg.into() );
+		self
+	}
+
+	/// Sets the label by which windows send messages to the sidecar.
+	/// Default is the name of the program.
+	pub fn label<S: Into<String>>( mut self, label: S ) -> Self {
+		self.label = label.into();
+		self
+	}
+
+	pub fn new<S: Into<OsString>>( program: S ) -> Self {
+		let program = program.into();
+
+		Self {
+			args: Vec::new(),
+			label: program.to_string_lossy().into_owned(),
+			program,
+			restart: true
+		}
+	}
+
+	/// Sets whether or not the sidecar is started again when it exits with a failure, like when it crashes.
+	/// It isn't started again if it exits successfully.
+	/// Default is true.
+	pub fn restart( mut self, value: bool ) -> Self {
+		self.restart = value;
+		self
+	}
+
+	/// Starts the sidecar.
+	/// The messages that it sends are delivered to the windows of `app`.
+	/// Fails if the program couldn't be started.
+	pub fn spawn( self, app: ApplicationHandleThreaded ) -> io::Result<Sidecar> {
+		let shared = Arc::new( SidecarShared {
+			child: Mutex::new( None ),
+			label: self.label.clone(),
+			stdin: Mutex::new( None ),
+			stopped: AtomicBool::new( false )
+		} );
+
+		let stdout = shared.start( &self )?;
+		SIDECARS.lock().unwrap().push( Arc::downgrade( &shared ) );
+
+		let shared2 = shared.clone();
+		thread::spawn(move || supervise( self, app, shared2, stdout ) );
+
+		Ok( Sidecar { shared } )
+	}
+}
+
+impl Sidecar {
+
+	/// Whether or not the process is running.
+	/// This is false for a little while after it has crashed, until it has been started again.
+	pub fn is_running( &self ) -> bool {
+		match self.shared.child.lock().unwrap().as_mut() {
+			None => false,
+			Some( child ) => child.try_wait().map(|status| status.is_none() ).unwrap_or( false )
+		}
+	}
+
+	/// The label of the sidecar.
+	pub fn label( &self ) -> &str {
+		&self.shared.label
+	}
+
+	/// Sends a message to the sidecar, with an empty sender.
+	/// Returns false if it couldn't be written to the sidecar, like when it isn't running.
+	pub fn send( &self, event: &str, payload: &str ) -> bool {
+		self.shared.write( "", event, payload )
+	}
+
+	/// Terminates the process, without starting it again.
+	pub fn stop( &self ) {
+		self.shared.stop();
+	}
+}
+
+impl Drop for Sidecar {
+	fn drop( &mut self ) {
+		self.shared.stop();
+	}
+}
+
+impl SidecarShared {
+
+	/// Starts the process, and gives its standard output.
+	fn start( &self, builder: &SidecarBuilder ) -> io::Result<BufReader<std::process::ChildStdout>> {
+		let mut child = Command::new( &builder.program )
+			.args( &builder.args )
+			.stdin( Stdio::piped() )
+			.stdout( Stdio::piped() )
+			.stderr( Stdio::inherit() )
+			.spawn()?;
+
+		let stdout = BufReader::new( child.stdout.take().unwrap() );
+		*self.stdin.lock().unwrap() = child.stdin.take();
+		*self.child.lock().unwrap() = Some( child );
+		Ok( stdout )
+	}
+
+	fn stop( &self ) {
+		self.stopped.store( true, Ordering::SeqCst );
+
+		// Closing the standard input is enough for well-behaved sidecars, but they are killed anyway.
+		self.stdin.lock().unwrap().take();
+		if let Some( child ) = self.child.lock().unwrap().as_mut() {
+			let _ = child.kill();
+		}
+	}
+
+	fn write( &self, from: &str, event: &str, payload: &str ) -> bool {
+		let line = format!( "{}\t{}\t{}\n", escape( from ), escape( event ), escape( payload ) );
+
+		match self.stdin.lock().unwrap().as_mut() {
+			None => false,
+			Some( stdin ) => stdin.write_all( line.as_bytes() ).and_then(|_| stdin.flush() ).is_ok()
+		}
+	}
+}
+
+
+
+/// Sends a message from a window to the sidecar with the given label.
+/// Returns false if there is no such sidecar.
+pub(in crate) fn emit_to( label: &str, message: &WindowMessage ) -> bool {
+	let sidecar = SIDECARS.lock().unwrap().iter()
+		.filter_map(|s| s.upgrade() )
+		.find(|s| s.label == label );
+
+	match sidecar {
+		None => false,
+		Some( sidecar ) => {
+			sidecar.write( message.from.as_deref().unwrap_or(""), &message.event, &message.payload );
+			true
+		}
+	}
+}
+
+/// Delivers the messages of the sidecar, and starts it again whenever it crashes.
+/// This runs on a thread of its own.
+fn supervise( builder: SidecarBuilder, app: ApplicationHandleThreaded, shared: Arc<SidecarShared>, mut stdout: BufReader<std::process::ChildStdout> ) {
+	loop {
+		let mut line = String::new();
+		while stdout.read_line( &mut line ).map(|n| n > 0 ).unwrap_or( false ) {
+			if let Some( (to, message) ) = parse_line( &line, &shared.label ) {
+				app.dispatch(move |_app| deliver( &to, message ) );
+			}
+			line.clear();
+		}
+
+		// The standard output closes when the process exits.
+		// The process is taken out, so that `stop` doesn't have to wait for it.
+		let child = shared.child.lock().unwrap().take();
+		let status = child.and_then(|mut c| c.wait().ok() );
+		shared.stdin.lock().unwrap().take();
+
+		let crashed = status.map(|s| !s.success() ).unwrap_or( true );
+		if !builder.restart || !crashed || shared.stopped.load( Ordering::SeqCst ) {
+			break;
+		}
+
+		thread::sleep( RESTART_DELAY );
+		if shared.stopped.load( Ordering::SeqCst ) { break }
+
+		stdout = match shared.start( &builder ) {
+			Err(_) => break,
+			Ok( stdout ) => stdout
+		};
+	}
+
+	// Sidecars that are gone can't receive messages anymore
+	SIDECARS.lock().unwrap().retain(|s| s.upgrade().map(|s| !Arc::ptr_eq( &s, &shared ) ).unwrap_or( false ) );
+}
+
+fn deliver( to: &str, message: WindowMessage ) {
+	let targets: Vec<BrowserWindowHandle> = if to == "*" {
+		registry::all()
+	}
+	else {
+		registry::by_label( to ).into_iter().collect()
+	};
+
+	for target in targets {
+		message::deliver( target, message.clone() );
+	}
+}
+
+fn escape( field: &str ) -> String {
+	field.replace( '\\', "\\\\" ).replace( '\t', "\\t" ).replace( '\n', "\\n" ).replace( '\r', "\\r" )
+}
+
+fn parse_line( line: &str, label: &str ) -> Option<(String, WindowMessage)> {
+	let fields: Vec<String> = line.trim_end_matches(|c| c == '\n' || c == '\r' ).split( '\t' ).map( unescape ).collect();
+	if fields.len() != 3 || fields[0].is_empty() {
+		return None;
+	}
+
+	let mut fields = fields.into_iter();
+	let to = fields.next().unwrap();
+	Some( (to, WindowMessage {
+		event: fields.next().unwrap(),
+		payload: fields.next().unwrap(),
+		from: Some( label.to_owned() )
+	}) )
+}
+
+fn unescape( field: &str ) -> String {
+	let mut result = String::with_capacity( field.len() );
+	let mut chars = field.chars();
+
+	while let Some( c ) = chars.next() {
+		if c != '\\' {
+			result.push( c );
+			continue;
+		}
+
+		match chars.next() {
+			Some('t') => result.push('\t'),
+			Some('n') => result.push('\n'),
+			Some('r') => result.push('\r'),
+			Some( other ) => result.push( other ),
+			None => result.push('\\')
+		}
+	}
+	result
+}
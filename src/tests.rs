@@ -1,13 +1,24 @@
 use crate::application::*;
 use crate::browser::*;
+use crate::browser::emit;
 use crate::cookie::*;
+use crate::ipc;
 use crate::prelude::*;
+use crate::prop::PropertyWatchers;
+use crate::window::WindowId;
 
 use std::{
+	cell::{Cell, RefCell},
 	env,
+	io,
+	rc::Rc,
 	time::{Duration, SystemTime, UNIX_EPOCH}
 };
 
+#[cfg(feature = "threadsafe")]
+use futures_channel::oneshot;
+#[cfg(feature = "threadsafe")]
+use std::sync::{Arc, Mutex};
 #[cfg(feature = "threadsafe")]
 use tokio;
 
@@ -19,11 +30,17 @@ fn tests() {
 
 	let settings = ApplicationSettings {
 		engine_seperate_executable_path: Some(exec_path),
-		resource_dir: None
+		headless: true,
+		resource_dir: None,
+		..Default::default()
 	};
 
 	let app = Application::initialize(&settings).expect("unable to initialize application");
 
+	let engine_info = app.engine_info();
+	assert!(engine_info.version().is_some());
+	assert!(engine_info.supports(Feature::Headless));
+
 	// Instead of marking each test with #[test], there is one actual test that runs all different 'test' functions.
 	// This is because the Browser Window application can only be initialized once.
 	// Also, because `Application` is not `Send`, we can not use it acros multiple tests because they are ran in parallel.
@@ -46,6 +63,34 @@ fn threaded_tests(application: &Application) {
 		tokio_runtime.spawn(async move{
 
 			// TODO: run tests here...
+			let value = app.dispatch_async(|_handle| async move { 1 + 1 }).await.unwrap();
+			assert!(value == 2);
+
+			// Values that aren't `Send` stay on the GUI thread
+			let length = app.dispatch_map(|_handle| Rc::new("text".to_owned()), |text| text.len()).await.unwrap();
+			assert!(length == 4);
+
+			let counter = app.dispatch_ref(|_handle| Rc::new(Cell::new(1))).await.unwrap();
+			counter.with(|c| c.set(c.get() + 1)).await.unwrap();
+			assert!(counter.with(|c| c.get()).await.unwrap() == 2);
+
+			// On the GUI thread itself, the closure is executed right away instead of waiting for itself
+			let blocking = app.delegate(|handle| handle.into_threaded().dispatch_blocking(|_| 3).unwrap()).await.unwrap();
+			assert!(blocking == 3);
+
+			// Closures of a higher priority are executed first
+			let order = Arc::new(Mutex::new(Vec::new()));
+			let order2 = order.clone();
+			app.delegate(move |handle| {
+				let app = handle.into_threaded();
+				let (low, high) = (order2.clone(), order2);
+				app.dispatch_with_priority(DispatchPriority::Low, move |_| low.lock().unwrap().push(1));
+				app.dispatch_with_priority(DispatchPriority::High, move |_| high.lock().unwrap().push(2));
+			}).await.unwrap();
+			let (tx, rx) = oneshot::channel();
+			app.dispatch_with_priority(DispatchPriority::Low, move |_| { let _ = tx.send(()); });
+			rx.await.unwrap();
+			assert!(*order.lock().unwrap() == vec![2, 1]);
 
 			app.exit(0);
 		});
@@ -59,9 +104,25 @@ fn async_tests(application: &Application) {
 	let exit_code = runtime.run_async(|app| async move {
 		let bw = async_basic(app).await;
 		async_cookies(app).await;
+		async_timers(app).await;
+		async_monitors(app);
+		async_events(app).await;
+		// The tabs are checked by their visibility, which is what the mock backend keeps track of
+		#[cfg(feature = "mock")]
+		async_tabs(app).await;
+		// The page model of the mock backend runs no JavaScript to automate
+		#[cfg(not(feature = "mock"))]
+		async_automation(app).await;
+		#[cfg(feature = "mock")]
+		async_mock_page(app).await;
+		#[cfg(feature = "mock")]
+		async_mock_template(app).await;
+		// The demos need their JavaScript to run, like the automation does
+		#[cfg(all(feature = "demos", not(feature = "mock")))]
+		async_demos(app).await;
 		//async_correct_parent_cleanup(app).await;
 
-		bw.close();
+		bw.close_and_wait().await;
 	});
 
 	assert!(exit_code == 0);
@@ -70,7 +131,363 @@ fn async_tests(application: &Application) {
 async fn async_basic(app: ApplicationHandle) -> BrowserWindow {
 	let mut bwb = BrowserWindowBuilder::new( Source::Url("https://www.duckduckgo.com/".into()) );
 	bwb.title("Basic Async Test");
-	return bwb.build( app ).await;
+	let bw = bwb.build( app ).await;
+
+	assert!(bw.navigate("www.duckduckgo.com") == Err(crate::error::BrowserError::InvalidUrl("www.duckduckgo.com".into())));
+	return bw;
+}
+
+/// Checks if the displays that are given make sense.
+fn async_monitors(app: ApplicationHandle) {
+	let monitors = app.monitors();
+
+	assert!(monitors.iter().filter(|m| m.is_primary).count() <= 1);
+	for monitor in &monitors {
+		assert!(monitor.scale_factor > 0.0);
+		assert!(monitor.work_area.width <= monitor.bounds.width);
+		assert!(monitor.work_area.height <= monitor.bounds.height);
+	}
+}
+
+/// Checks if the event stream of a window ends with the window being closed, and if the window can be found and sent messages by its label in the meantime.
+async fn async_events(app: ApplicationHandle) {
+	let mut bwb = BrowserWindowBuilder::new( Source::Html("<title>Events</title>".into()) );
+	bwb.label("events");
+	let bw = bwb.build( app ).await;
+	let mut events = bw.events();
+
+	assert!(app.window_by_label("events").map(|w| w.id()) == Some(bw.id()));
+	assert!(app.windows().iter().any(|w| w.id() == bw.id()));
+
+	// A window can send messages to itself as well
+	let received = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+	let received2 = received.clone();
+	bw.subscribe("ping", move |_, message| {
+		received2.store(message.payload == "1" && message.from.as_deref() == Some("events"), std::sync::atomic::Ordering::Relaxed);
+	});
+	assert!(bw.emit_to("events", "ping", "1"));
+	assert!(!bw.emit_to("nonexistent", "ping", "1"));
+	assert!(received.load(std::sync::atomic::Ordering::Relaxed));
+
+	bw.close_and_wait().await;
+	assert!(app.window_by_label("events").is_none());
+
+	let mut last = None;
+	while let Some(event) = events.next().await {
+		last = Some(event);
+	}
+	assert!(matches!(last, Some(BrowserEvent::Closed)));
+}
+
+/// Checks if the page of a window can be driven with `Automation`.
+#[cfg(not(feature = "mock"))]
+async fn async_automation(app: ApplicationHandle) {
+	use crate::automation::{Automation, AutomationError};
+
+	let html = r#"<input id="name"><button onclick="document.title = 'Hello ' + document.getElementById('name').value">Greet</button>"#;
+	let bw = BrowserWindowBuilder::new( Source::Html(html.into()) ).build( app ).await;
+	let page = Automation::new(*bw).timeout(Duration::from_secs(2));
+
+	page.wait_for_selector("button").await.unwrap();
+	page.type_text("#name", "world").await.unwrap();
+	page.click("button").await.unwrap();
+	assert!(bw.eval_js_value("document.title").await.unwrap().as_str() == Some("Hello world"));
+	assert!(matches!(page.click("#missing").await, Err(AutomationError::Timeout(_))));
+
+	// Exceptions come with their name
+	let error = bw.eval_js("null.property").await.unwrap_err();
+	assert!(error.name() == Some("TypeError"));
+	assert!(error.line() == Some(1));
+
+	bw.close_and_wait().await;
+	assert!(matches!(page.wait_for_selector("button").await, Err(AutomationError::Closed)));
+}
+
+#[cfg(feature = "mock")]
+/// Checks if only the selected tab of a tabbed browser is shown, and if closing tabs selects another one.
+async fn async_tabs(app: ApplicationHandle) {
+	let host = BrowserWindowBuilder::new( Source::Html("<div id=\"tab-bar\"></div>".into()) ).build( app ).await;
+	let mut tabs = TabbedBrowser::new(host, 40);
+	assert!(tabs.active().is_none());
+
+	tabs.add_tab( BrowserWindowBuilder::new( Source::Url("https://example.com/".into()) ) ).await;
+	assert!(tabs.add_tab( BrowserWindowBuilder::new( Source::Url("https://example.org/".into()) ) ).await == 1);
+	assert!(tabs.active() == Some(1));
+	assert!(!tabs.tabs()[0].window().visible().get());
+	assert!(tabs.active_tab().unwrap().window().visible().get());
+
+	assert!(tabs.select(0));
+	assert!(!tabs.select(2));
+	assert!(!tabs.tabs()[1].window().visible().get());
+
+	// The tab that takes the place of the closed one is selected
+	assert!(tabs.close_tab(0));
+	assert!(tabs.active() == Some(0));
+	assert!(tabs.tab(0).unwrap().window().visible().get());
+	assert!(!tabs.close_tab(1));
+	assert!(tabs.close_tab(0));
+	assert!(tabs.active().is_none());
+}
+
+#[cfg(feature = "mock")]
+async fn async_mock_page(app: ApplicationHandle) {
+	let favicon_width = std::sync::Arc::new(std::sync::atomic::AtomicU32::new(0));
+	let mut bwb = BrowserWindowBuilder::new( Source::Url("https://example.com/".into()) );
+	let width = favicon_width.clone();
+	bwb.favicon_as_icon(true);
+	bwb.on_favicon_changed(move |_, favicon| if let Favicon::Rgba { width: w, .. } = favicon {
+		width.store(*w, std::sync::atomic::Ordering::SeqCst);
+	});
+	let status_text = std::sync::Arc::new(std::sync::Mutex::new(String::new()));
+	let text = status_text.clone();
+	bwb.on_status_text(move |_, t| *text.lock().unwrap() = t.to_owned());
+	let unresponsive = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+	let flag = unresponsive.clone();
+	bwb.on_unresponsive(move |_, u| flag.store(u, std::sync::atomic::Ordering::SeqCst));
+	bwb.reload_on_crash(true);
+	bwb.on_file_chooser(|_, chooser| if chooser.accept() == ["image/*"] {
+		chooser.select(&["/tmp/picture.png", "/tmp/other.png"]);
+	});
+	bwb.on_request("https://ads.example.com/*", |_, _| RequestAction::Block);
+	bwb.on_request("*/api/*", |_, request| {
+		request.set_header("Authorization", "Bearer token");
+		RequestAction::Continue
+	});
+	bwb.on_request("*.png", |_, _| RequestAction::Redirect("https://cdn.example.com/placeholder.png".into()));
+	bwb.on_key_event(|_, e| e.key_code == 0x74 || (e.modifiers.ctrl && e.key_code == 0x50));
+	let mouse_events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+	let events = mouse_events.clone();
+	bwb.on_mouse_event(move |_, e| events.lock().unwrap().push(*e));
+	let received_bytes = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+	let received = received_bytes.clone();
+	bwb.on_bytes(move |_, bytes| received.lock().unwrap().extend_from_slice(bytes));
+	let bw = bwb.build( app ).await;
+	let page = bw.mock_page();
+
+	page.on_eval(|js| if js == "fail()" { Err("ReferenceError: fail is not defined".into()) } else { Ok("42".into()) });
+	assert!(bw.eval_js("answer()").await.unwrap() == "42");
+	assert!(bw.eval_js("fail()").await.is_err());
+	assert!(page.evaluated() == vec!["answer()", "fail()"]);
+
+	page.on_eval(|_| Ok(r#"{"memory":null,"heap":1048576,"nodes":12}"#.into()));
+	let metrics = bw.metrics().await.unwrap();
+	assert!(metrics.memory_footprint.is_none());
+	assert!(metrics.js_heap_size == Some(1048576));
+	assert!(metrics.dom_nodes == Some(12));
+	// Without DevTools, the features that are built on it fail
+	assert!(matches!(bw.page_source().await, Err(crate::error::BrowserError::DevToolsFailed(_))));
+
+	page.on_devtools(|method, params| match method {
+		"DOM.getDocument" => Ok(r#"{"root":{"nodeId":7}}"#.into()),
+		"DOM.getOuterHTML" if params.get("nodeId").and_then(|id| id.as_f64()) == Some(7.0) => Ok(r#"{"outerHTML":"<html><body></body></html>"}"#.into()),
+		"Page.getFrameTree" => Ok(r#"{"frameTree":{"frame":{"id":"MAIN"}}}"#.into()),
+		"Page.createIsolatedWorld" => Ok(r#"{"executionContextId":3}"#.into()),
+		"Runtime.evaluate" => Ok(r#"{"result":{"type":"string","value":"selected"}}"#.into()),
+		"Page.getNavigationHistory" => Ok(r#"{"currentIndex":1,"entries":[{"id":4,"url":"https://example.com/","title":"Home"},{"id":9,"url":"https://example.com/next","title":""}]}"#.into()),
+		"Page.getLayoutMetrics" => Ok(r#"{"cssLayoutViewport":{"pageX":0,"pageY":200}}"#.into()),
+		"Page.printToPDF" => Err("Printing is not available".into()),
+		_ => Ok("{}".into())
+	});
+	// The calls that the last feature has made, with their parameters
+	let recent = |n: usize| { let calls = page.devtools_calls(); calls[calls.len() - n..].to_vec() };
+	let number = |params: &JsValue, name: &str| params.get(name).and_then(|v| v.as_f64());
+
+	assert!(matches!(bw.print_to_pdf(&PrintSettings::default()).await, Err(crate::error::BrowserError::PrintFailed(_))));
+	assert!(bw.page_source().await.unwrap() == "<html><body></body></html>");
+	assert!(recent(2).iter().map(|c| c.0.as_str()).collect::<Vec<_>>() == vec!["DOM.getDocument", "DOM.getOuterHTML"]);
+
+	// The selection is taken in a world of its own
+	assert!(bw.selected_text().await.unwrap() == "selected");
+	let calls = recent(3);
+	assert!(calls.iter().map(|c| c.0.as_str()).collect::<Vec<_>>() == vec!["Page.getFrameTree", "Page.createIsolatedWorld", "Runtime.evaluate"]);
+	assert!(calls[1].1.get("frameId").and_then(|id| id.as_str()) == Some("MAIN"));
+	assert!(number(&calls[2].1, "contextId") == Some(3.0));
+	assert!(calls[2].1.get("expression").and_then(|e| e.as_str()).unwrap().contains("getSelection()"));
+
+	let conditions = NetworkConditions { latency: Duration::from_millis(400), download_throughput: Some(50_000), ..Default::default() };
+	bw.set_network_conditions(&conditions).await.unwrap();
+	let calls = recent(2);
+	assert!(calls[0].0 == "Network.enable" && calls[1].0 == "Network.emulateNetworkConditions");
+	assert!(calls[1].1.get("offline").and_then(|o| o.as_bool()) == Some(false));
+	assert!(number(&calls[1].1, "latency") == Some(400.0));
+	assert!(number(&calls[1].1, "downloadThroughput") == Some(50_000.0));
+	assert!(number(&calls[1].1, "uploadThroughput") == Some(-1.0));
+	bw.clear_http_cache().await.unwrap();
+	assert!(recent(1)[0].0 == "Network.clearBrowserCache");
+
+	let history = bw.history().await.unwrap();
+	assert!(history.current == 1 && history.can_go_back() && !history.can_go_forward());
+	assert!(history.back_entries() == [HistoryEntry { index: 0, title: "Home".into(), url: "https://example.com/".into() }]);
+	bw.go_to_history_index(0).await.unwrap();
+	let calls = recent(1);
+	assert!(calls[0].0 == "Page.navigateToHistoryEntry" && number(&calls[0].1, "entryId") == Some(4.0));
+	assert!(matches!(bw.go_to_history_index(5).await, Err(crate::error::BrowserError::HistoryEntryNotFound(5))));
+
+	page.on_eval(|_| Ok("[0,200]".into()));
+	let session = bw.save_session().await.unwrap();
+	assert!(session.history == history && session.scroll == (0.0, 200.0));
+	assert!(SessionState::from_json(&session.to_json()) == Some(session.clone()));
+
+	assert!(bw.scroll_position().await.unwrap() == (0.0, 200.0));
+	bw.scroll_to(0.0, 200.0).await.unwrap();
+	// The world of the selection is used again, until the window navigates
+	let calls = recent(2);
+	assert!(calls[0].0 == "Page.getLayoutMetrics");
+	assert!(calls[1].0 == "Runtime.evaluate" && number(&calls[1].1, "contextId") == Some(3.0));
+	assert!(calls[1].1.get("expression").and_then(|e| e.as_str()).unwrap().starts_with("window.scrollTo({left: 0, top: 200"));
+	bw.navigate_async("https://example.com/top").await.unwrap();
+	bw.scroll_by(0.0, 100.0).await.unwrap();
+	assert!(recent(3).iter().map(|c| c.0.as_str()).collect::<Vec<_>>() == vec!["Page.getFrameTree", "Page.createIsolatedWorld", "Runtime.evaluate"]);
+
+	bw.set_color_scheme(ColorScheme::Dark).await.unwrap();
+	let calls = recent(1);
+	assert!(calls[0].0 == "Emulation.setEmulatedMedia");
+	assert!(calls[0].1.get("features").and_then(|f| f.get("0")).and_then(|f| f.get("value")).and_then(|v| v.as_str()) == Some("dark"));
+
+	let phone = DeviceMetrics { width: 390, height: 844, device_pixel_ratio: 3.0, mobile: true, touch: true };
+	assert!(phone.to_cdp_params() == r#"{"width":390,"height":844,"deviceScaleFactor":3,"mobile":true}"#);
+	bw.emulate_device(&phone).await.unwrap();
+	let calls = recent(2);
+	assert!(calls[0].0 == "Emulation.setDeviceMetricsOverride" && number(&calls[0].1, "width") == Some(390.0));
+	assert!(calls[1].0 == "Emulation.setTouchEmulationEnabled" && calls[1].1.get("enabled").and_then(|e| e.as_bool()) == Some(true));
+	bw.clear_device_emulation().await.unwrap();
+	let calls = recent(2);
+	assert!(calls[0].0 == "Emulation.clearDeviceMetricsOverride");
+	assert!(calls[1].1.get("enabled").and_then(|e| e.as_bool()) == Some(false));
+
+	bw.set_geolocation_override(52.37, 4.89, 10.0).await.unwrap();
+	let calls = recent(1);
+	assert!(calls[0].0 == "Emulation.setGeolocationOverride");
+	assert!(number(&calls[0].1, "latitude") == Some(52.37) && number(&calls[0].1, "longitude") == Some(4.89) && number(&calls[0].1, "accuracy") == Some(10.0));
+	bw.clear_geolocation_override().await.unwrap();
+	assert!(recent(1)[0].0 == "Emulation.clearGeolocationOverride");
+
+	assert!(app.system_theme() == Some(SystemTheme::Light));
+	let mut themes = app.system_theme_changes();
+	app.inner.set_system_theme(browser_window_core::application::THEME_DARK);
+	assert!(themes.next().await == Some(SystemTheme::Dark));
+	assert!(app.system_theme() == Some(SystemTheme::Dark));
+
+	let css = bw.insert_css("body { color: red; }");
+	css.remove();
+	assert!(page.evaluated().iter().filter(|js| js.contains("__bw_css_")).count() == 2);
+
+	bw.exec_command(EditCommand::SelectAll);
+	bw.exec_command(EditCommand::Copy);
+	assert!(page.commands() == vec![EditCommand::SelectAll, EditCommand::Copy]);
+
+	bw.navigate("https://example.com/next").unwrap();
+	assert!(page.navigations() == vec!["https://example.com/", "https://example.com/top", "https://example.com/next"]);
+	bw.navigate_async("https://example.com/last").await.unwrap();
+	assert!(bw.url() == "https://example.com/last");
+	assert!(!bw.is_loading());
+
+	page.set_favicon(&Favicon::Rgba { width: 2, height: 1, pixels: vec![255; 8] });
+	app.sleep(Duration::from_millis(10)).await;
+	assert!(favicon_width.load(std::sync::atomic::Ordering::SeqCst) == 2);
+	page.set_status_text("https://example.com/link");
+	app.sleep(Duration::from_millis(10)).await;
+	assert!(*status_text.lock().unwrap() == "https://example.com/link");
+	page.set_unresponsive(true);
+	app.sleep(Duration::from_millis(10)).await;
+	assert!(unresponsive.load(std::sync::atomic::Ordering::SeqCst));
+	page.terminate(TerminationReason::Crashed);
+	app.sleep(Duration::from_millis(10)).await;
+	assert!(page.reloads() == 1);
+	bw.reload_ignore_cache();
+	assert!(page.reloads() == 2);
+	page.open_file_chooser(FileChooserMode::Open, &["image/*"]);
+	page.open_file_chooser(FileChooserMode::Open, &[".txt"]);
+	app.sleep(Duration::from_millis(10)).await;
+	assert!(page.chosen_files() == vec![vec![std::path::PathBuf::from("/tmp/picture.png")], vec![]]);
+	page.request("GET", "https://ads.example.com/banner.js", &[]);
+	page.request("POST", "https://example.com/api/items", &[("Authorization", "none"), ("Accept", "*/*")]);
+	page.request("GET", "https://example.com/logo.png", &[]);
+	page.request("GET", "https://example.com/style.css", &[]);
+	app.sleep(Duration::from_millis(10)).await;
+	let sent = page.sent_requests();
+	assert!(sent.iter().map(|r| r.url()).collect::<Vec<_>>() == vec!["https://example.com/api/items", "https://cdn.example.com/placeholder.png", "https://example.com/style.css"]);
+	assert!(sent[0].method() == "POST");
+	assert!(sent[0].header("authorization") == Some("Bearer token"));
+	assert!(sent[0].headers().len() == 2);
+	page.press_key(0x74, Modifiers::default());
+	page.press_key(0x50, Modifiers { ctrl: true, ..Default::default() });
+	page.press_key(0x50, Modifiers::default());
+	app.sleep(Duration::from_millis(10)).await;
+	assert!(page.keys() == vec![0x50]);
+	page.invoke("__bw_mouse", &["down", "10", "20", "2", "1", "0", "0", "0", "2"]);
+	page.invoke("__bw_mouse", &["wheel", "10", "20", "0", "0", "0", "-120", "1", "0"]);
+	page.invoke("__bw_mouse", &["jump", "10", "20"]);
+	app.sleep(Duration::from_millis(10)).await;
+	let events = mouse_events.lock().unwrap().clone();
+	assert!(events.len() == 2);
+	assert!(events[0].kind == MouseEventKind::Pressed { button: MouseButton::Right, click_count: 1 });
+	assert!(events[0].modifiers.right_button && (events[0].x, events[0].y) == (10, 20));
+	assert!(events[1].kind == MouseEventKind::Wheel { delta_x: 0.0, delta_y: -120.0 });
+	assert!(events[1].modifiers.ctrl);
+	// The promise of the bytes is rejected when they aren't base64
+	page.invoke("__bw_bytes", &["0", "aGk="]);
+	page.invoke("__bw_bytes", &["1", "h!"]);
+	app.sleep(Duration::from_millis(10)).await;
+	assert!(*received_bytes.lock().unwrap() == b"hi");
+	let evaluated = page.evaluated();
+	assert!(evaluated[evaluated.len() - 2] == "window.__bw_bytes_sent(0)");
+	assert!(evaluated[evaluated.len() - 1].starts_with("window.__bw_bytes_sent(1, "));
+	// Invalid API calls get an error back in the page
+	page.invoke("__bw_call", &["7"]);
+	page.invoke("__bw_call", &["call"]);
+	app.sleep(Duration::from_millis(10)).await;
+	let evaluated = page.evaluated();
+	assert!(evaluated.iter().any(|js| js.starts_with("window.__bw.resolve(7, false, ")));
+	assert!(evaluated.last().unwrap().starts_with("throw new Error("));
+
+	bw.close_and_wait().await;
+}
+
+#[cfg(feature = "mock")]
+/// Checks if the handlers of a template are shared by all windows that are created with it.
+async fn async_mock_template(app: ApplicationHandle) {
+	let mut bwb = BrowserWindowBuilder::new( Source::Url("https://example.com/".into()) );
+	bwb.on_request("https://ads.example.com/*", |_, _| RequestAction::Block);
+	bwb.on_key_event(|_, e| e.key_code == 0x74);
+	bwb.on_file_chooser(|_, chooser| chooser.select(&["/tmp/picture.png"]));
+	let template = WindowTemplate::new(bwb);
+
+	for _ in 0..2 {
+		let bw = template.build( app ).await;
+		let page = bw.mock_page();
+		page.request("GET", "https://ads.example.com/banner.js", &[]);
+		page.request("GET", "https://example.com/style.css", &[]);
+		app.sleep(Duration::from_millis(10)).await;
+		assert!(page.sent_requests().iter().map(|r| r.url()).collect::<Vec<_>>() == vec!["https://example.com/style.css"]);
+		page.press_key(0x74, Modifiers::default());
+		page.press_key(0x50, Modifiers::default());
+		page.open_file_chooser(FileChooserMode::Open, &["image/*"]);
+		app.sleep(Duration::from_millis(10)).await;
+		assert!(page.keys() == vec![0x50]);
+		assert!(page.chosen_files() == vec![vec![std::path::PathBuf::from("/tmp/picture.png")]]);
+
+		bw.close_and_wait().await;
+	}
+}
+
+/// Checks if messages make it from JavaScript to Rust and back.
+#[cfg(all(feature = "demos", not(feature = "mock")))]
+async fn async_demos(app: ApplicationHandle) {
+	let bw = crate::demos::ipc_demo().build(app).await;
+
+	let mut round_trips = 0;
+	for _ in 0..50 {
+		app.sleep(Duration::from_millis(100)).await;
+		round_trips = bw.eval_js("demo.roundTrips").await.unwrap().parse().unwrap_or(0);
+		if round_trips > 0 {
+			break;
+		}
+	}
+	assert!(round_trips == 1);
+	assert!(bw.eval_js("String(demo.lastError)").await.unwrap() == "null");
+
+	bw.close_and_wait().await;
 }
 
 async fn async_cookies(app: ApplicationHandle) {
@@ -110,6 +527,27 @@ async fn async_cookies(app: ApplicationHandle) {
 }
 
 
+async fn async_timers(app: ApplicationHandle) {
+	let fired = Rc::new(Cell::new(0));
+
+	// A cancelled timeout should never fire
+	let fired2 = fired.clone();
+	let timeout = app.set_timeout(Duration::from_millis(10), move |_| fired2.set(fired2.get() + 100));
+	timeout.cancel();
+
+	// An interval should keep firing until cancelled
+	let fired2 = fired.clone();
+	let interval = app.set_interval(Duration::from_millis(10), move |_| fired2.set(fired2.get() + 1));
+	app.sleep(Duration::from_millis(200)).await;
+	interval.cancel();
+	let count = fired.get();
+	assert!(count > 0 && count < 100);
+
+	app.sleep(Duration::from_millis(50)).await;
+	assert!(fired.get() == count);
+}
+
+
 
 #[test]
 /// Checking if all cookie methods work correctly.
@@ -139,6 +577,414 @@ fn cookie() {
 		assert!((now.duration_since(UNIX_EPOCH).unwrap() - cookie.creation_time().duration_since(UNIX_EPOCH).unwrap()) < Duration::from_millis(1));
 }
 
+#[test]
+/// Checks if strings get escaped properly before they get embedded in JavaScript code.
+fn js_string_literal() {
+	assert!(ipc::js_string_literal("plain") == "\"plain\"");
+	assert!(ipc::js_string_literal("\"quoted\"") == "\"\\\"quoted\\\"\"");
+	assert!(ipc::js_string_literal("back\\slash") == "\"back\\\\slash\"");
+	assert!(ipc::js_string_literal("new\nline\u{2028}") == "\"new\\nline\\u2028\"");
+	assert!(ipc::js_string_literal("\u{1}") == "\"\\u0001\"");
+}
+
+#[test]
+/// Checks if interaction scripts survive being written and parsed again, keys with spaces included.
+fn interaction_script() {
+	let script = InteractionScript { events: vec![
+		InputEvent { time: Duration::from_millis(1200), kind: InputEventKind::Click { x: 120.5, y: 48.0, button: 0 } },
+		InputEvent { time: Duration::from_millis(1850), kind: InputEventKind::Key(" ".into()) },
+		InputEvent { time: Duration::from_millis(3020), kind: InputEventKind::Scroll { x: 0.0, y: 640.0 } }
+	] };
+
+	let text = script.to_string();
+	assert!(text.lines().next() == Some("1200 click 120.5 48 0"));
+	assert!(text.parse::<InteractionScript>() == Ok(script));
+	assert!("10 click 1 2\n".parse::<InteractionScript>() == Err(InteractionScriptError { line: 1 }));
+}
+
+#[test]
+/// Checks if the JSON that pages give is parsed into the right values, and if those display as JavaScript again.
+fn js_value() {
+	let value = JsValue::from_json(r#"{"a": [1, 2.5e1, -3], "b": true, "c": null, "d": "line\n\ud83d\ude00"}"#).unwrap();
+
+	assert!(value.get("a").and_then(|a| a.get("1")).and_then(|n| n.as_f64()) == Some(25.0));
+	assert!(value.get("b").and_then(|b| b.as_bool()) == Some(true));
+	assert!(value.get("c").map(|c| c.is_null()) == Some(true));
+	assert!(value.get("d").and_then(|d| d.as_str()) == Some("line\n\u{1F600}"));
+	assert!(value.to_string() == "{\"a\":[1,25,-3],\"b\":true,\"c\":null,\"d\":\"line\\n\u{1F600}\"}");
+	assert!(JsValue::from_json("[1,]").is_none());
+
+	// Arguments can't break out of their literals
+	let js = crate::browser::js_value::call_js("greet", &["'); alert('", "\"x\""]);
+	assert!(js == "(greet)(\"'); alert('\",\"\\\"x\\\"\")");
+}
+
+#[test]
+/// Checks if bytes survive being encoded as base64 and decoded again, for every amount of padding.
+fn base64() {
+	use crate::browser::bytes::{base64_decode, base64_encode};
+
+	assert!(base64_encode(b"bytes") == "Ynl0ZXM=");
+	assert!(base64_encode(b"") == "");
+	for len in 0..4 {
+		let bytes: Vec<u8> = (0..=255u8).cycle().take(300 + len).collect();
+		assert!(base64_decode(&base64_encode(&bytes)) == Some(bytes));
+	}
+	assert!(base64_decode("Ynl0ZXM") == Some(b"bytes".to_vec()));
+	assert!(base64_decode("Ynl0*XM=").is_none());
+}
+
+#[test]
+#[cfg(feature = "rmp")]
+/// Checks if values survive the MessagePack codec, and if left out arguments are decoded as null.
+fn message_pack_codec() {
+	use ipc::{Codec, MessagePack};
+
+	let value = (42u32, "text".to_owned(), vec![1.5f64, -2.0], Some(true));
+	let encoded = MessagePack::encode(&value).unwrap();
+	assert!(MessagePack::decode::<(u32, String, Vec<f64>, Option<bool>)>(Some(&encoded)) == Ok(value));
+	assert!(MessagePack::decode::<Option<u32>>(None) == Ok(None));
+	assert!(MessagePack::decode::<u32>(Some("not base64!")).is_err());
+}
+
+#[test]
+/// Checks if a directory is pruned down to its limit, without removing more files than allowed in one go.
+fn prune_directory() {
+	use crate::application::maintenance::prune_directory;
+
+	let dir = std::env::temp_dir().join(format!("bw-prune-{}", std::process::id()));
+	let _ = std::fs::remove_dir_all(&dir);
+	std::fs::create_dir_all(dir.join("sub")).unwrap();
+	for i in 0..6 {
+		let path = if i % 2 == 0 { dir.join(i.to_string()) } else { dir.join("sub").join(i.to_string()) };
+		std::fs::write(path, [0u8; 100]).unwrap();
+	}
+
+	assert!(prune_directory(&dir, 250, 2).unwrap() == 2);
+	assert!(prune_directory(&dir, 250, 10).unwrap() == 2);
+	assert!(prune_directory(&dir, 250, 10).unwrap() == 0);
+	assert!(prune_directory(&dir.join("missing"), 0, 10).unwrap() == 0);
+	std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+/// Checks if the most specific section of a permission policy decides, and if invalid lines are reported.
+fn permission_policy() {
+	use crate::permission::{Permission, PermissionPolicy, PermissionPolicyError};
+
+	let policy: PermissionPolicy = "
+		# Defaults
+		[*]
+		popups = deny
+		downloads = deny
+
+		[*.example.com]
+		downloads = allow
+
+		[https://intranet.example.com]
+		camera = allow
+		downloads = ask
+	".parse().unwrap();
+
+	assert!(policy.decide(Permission::Camera, "https://intranet.example.com/call?id=1") == Some(true));
+	assert!(policy.decide(Permission::Camera, "https://www.example.com/") == None);
+	assert!(policy.decide(Permission::Downloads, "http://shop.EXAMPLE.com:8080/file.zip") == Some(true));
+	assert!(policy.decide(Permission::Downloads, "https://intranet.example.com/") == None);
+	assert!(policy.decide(Permission::Downloads, "https://example.org/") == Some(false));
+	assert!(policy.decide(Permission::Popups, "about:blank") == Some(false));
+
+	assert!("camera = allow".parse::<PermissionPolicy>() == Err(PermissionPolicyError { line: 1 }));
+	assert!("[*]\nmicrophone = allow".parse::<PermissionPolicy>() == Err(PermissionPolicyError { line: 2 }));
+}
+
+#[test]
+/// Checks if screen capture can be decided by a policy, and if the defaults of a window are given to the browser engine in the order of its permissions.
+fn permission_defaults() {
+	use crate::permission::{self, Permission, PermissionPolicy};
+
+	let policy: PermissionPolicy = "[*]\nscreen_capture = deny".parse().unwrap();
+	assert!(policy.decide(Permission::ScreenCapture, "https://meet.example.com/") == Some(false));
+	assert!(Permission::from_c(Permission::ScreenCapture.to_c()) == Some(Permission::ScreenCapture));
+
+	assert!(permission::defaults_to_c(&[]) == [-1; 5]);
+	assert!(permission::defaults_to_c(&[(Permission::Camera, true), (Permission::ScreenCapture, false)]) == [1, -1, -1, -1, 0]);
+}
+
+#[test]
+/// Checks if GUI traces survive being written and parsed again, and if they are replayed in the order in which they have started.
+fn gui_trace() {
+	let window = WindowId::new();
+	let entry = |id, name: &str, start| TraceEntry {
+		id,
+		kind: TraceEntryKind::Event(window),
+		name: name.into(),
+		thread: "main".into(),
+		start: Duration::from_micros(start),
+		duration: Duration::from_micros(5)
+	};
+	let trace = GuiTrace { entries: vec![entry(1, "resize", 300), entry(0, "move", 100), entry(2, "some::closure::{{closure}}", 200)] };
+
+	let text = trace.to_string();
+	assert!(text.lines().next() == Some(format!("300 5 event 1 main {} resize", window).as_str()));
+	assert!(text.parse::<GuiTrace>() == Ok(trace.clone()));
+	assert!("10 0 event 1 main nowhere move\n".parse::<GuiTrace>() == Err(GuiTraceError { line: 1 }));
+
+	let order = RefCell::new(Vec::new());
+	let replayed = TraceReplay::new(&trace)
+		.on("move", |e| order.borrow_mut().push(e.id))
+		.on("resize", |e| order.borrow_mut().push(e.id))
+		.run();
+	assert!(replayed == 2);
+	assert!(*order.borrow() == vec![0, 1]);
+}
+
+#[test]
+#[cfg(feature = "dev")]
+/// Checks if the watcher of `BrowserWindowBuilder::watch` finds the files that have changed.
+fn hot_reload() {
+	use crate::browser::hot_reload::{changed_paths, snapshot};
+
+	let dir = std::env::temp_dir().join(format!("bw-watch-{}", std::process::id()));
+	let _ = std::fs::remove_dir_all(&dir);
+	std::fs::create_dir_all(dir.join("css")).unwrap();
+	std::fs::write(dir.join("index.html"), "").unwrap();
+	std::fs::write(dir.join("css").join("app.css"), "").unwrap();
+
+	let old = snapshot(&dir);
+	assert!(old.len() == 2);
+
+	let mut new = old.clone();
+	new.remove(&dir.join("index.html"));
+	new.insert(dir.join("css").join("app.css"), UNIX_EPOCH);
+	new.insert(dir.join("app.js"), UNIX_EPOCH);
+	assert!(changed_paths(&dir, &old, &new) == vec!["app.js", "css/app.css", "index.html"]);
+	assert!(changed_paths(&dir, &new, &new).is_empty());
+	std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+#[cfg(feature = "cdp")]
+/// Checks if the errors of the Chrome DevTools Protocol give their message and code.
+fn cdp_error() {
+	let error = CdpError::new(r#"{"code":-32601,"message":"'Foo.bar' wasn't found"}"#.to_owned());
+	assert!(error.code() == Some(-32601));
+	assert!(error.message() == "'Foo.bar' wasn't found");
+
+	let error = CdpError::new("not json".to_owned());
+	assert!(error.code() == None);
+	assert!(error.message() == "not json");
+}
+
+#[test]
+/// Checks if window identifiers are never handed out twice.
+fn window_id() {
+	let a = WindowId::new();
+	let b = WindowId::new();
+
+	assert!(a != b);
+	assert!(a < b);
+	assert!(a.to_string().starts_with("window-"));
+}
+
+#[test]
+/// Checks if events that occur while another event of their window is being handled wait for it, without changing their order.
+fn event_ordering() {
+	let window = WindowId::new();
+	let other = WindowId::new();
+	let log = Rc::new(RefCell::new(Vec::new()));
+
+	let l = log.clone();
+	emit::emit(window, move || {
+		l.borrow_mut().push(1);
+
+		let l2 = l.clone();
+		emit::emit(window, move || {
+			l2.borrow_mut().push(3);
+
+			// The events that are still waiting when the window gets destroyed are never handled
+			let l3 = l2.clone();
+			emit::emit(window, move || l3.borrow_mut().push(5));
+			emit::discard_pending(window);
+		});
+		let l2 = l.clone();
+		emit::emit(window, move || l2.borrow_mut().push(4));
+
+		// The events of other windows don't need to wait
+		let l2 = l.clone();
+		emit::emit(other, move || l2.borrow_mut().push(2));
+	});
+
+	assert!(*log.borrow() == vec![1, 2, 3]);
+}
+
+#[test]
+/// Checks if property streams receive every new value, and end when the property is gone.
+fn property_changes() {
+	let mut watchers = PropertyWatchers::default();
+	let mut changes = watchers.watch();
+	drop(watchers.watch());
+
+	watchers.notify(1u8);
+	watchers.notify(2);
+	drop(watchers);
+
+	let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+	runtime.block_on(async {
+		assert!(changes.next().await == Some(1));
+		assert!(changes.next().await == Some(2));
+		assert!(changes.next().await == None);
+	});
+}
+
+#[test]
+/// Checks if the history is taken from the DevTools protocol, and split around the current entry.
+fn history() {
+	let result = JsValue::from_json(r#"{"currentIndex":1,"entries":[{"id":3,"url":"https://example.com/","title":"Home"},{"id":5,"url":"https://example.com/a","title":"A"},{"id":8,"url":"https://example.com/b","title":""}]}"#).unwrap();
+	let (history, ids) = History::from_cdp(&result).unwrap();
+	assert!(ids == vec![3, 5, 8]);
+	assert!(history.current == 1);
+	assert!(history.back_entries() == &[HistoryEntry { index: 0, title: "Home".into(), url: "https://example.com/".into() }]);
+	assert!(history.forward_entries().len() == 1 && history.forward_entries()[0].url == "https://example.com/b");
+	assert!(history.can_go_back() && history.can_go_forward());
+
+	let (history, _) = History::from_cdp(&JsValue::from_json(r#"{"currentIndex":0,"entries":[{"id":1,"url":"about:blank","title":""}]}"#).unwrap()).unwrap();
+	assert!(!history.can_go_back() && !history.can_go_forward());
+}
+
+#[test]
+/// Checks if network conditions are given to the DevTools protocol in milliseconds, with -1 for no limit.
+fn network_conditions() {
+	assert!(NetworkConditions::default().to_cdp_params() == "{\"offline\":false,\"latency\":0,\"downloadThroughput\":-1,\"uploadThroughput\":-1}");
+
+	let conditions = NetworkConditions { latency: Duration::from_millis(400), download_throughput: Some(50_000), ..NetworkConditions::offline() };
+	assert!(conditions.to_cdp_params() == "{\"offline\":true,\"latency\":400,\"downloadThroughput\":50000,\"uploadThroughput\":-1}");
+}
+
+#[test]
+/// Checks if print settings are given to the DevTools protocol in inches, and without the default header and footer.
+fn print_settings() {
+	let params = PrintSettings::default().to_cdp_params();
+	assert!(params.starts_with("{\"paperWidth\":8.267716535433072,\"paperHeight\":11.692913385826772,"));
+	assert!(!params.contains("displayHeaderFooter"));
+
+	let settings = PrintSettings {
+		paper_size: PaperSize::Custom { width: 25.4, height: 50.8 },
+		margins: Margins::uniform(0.0),
+		footer: Some("<span class=\"pageNumber\"></span>".into()),
+		page_ranges: vec![1..=3, 5..=5],
+		..Default::default()
+	};
+	let params = JsValue::from_json(&settings.to_cdp_params()).unwrap();
+	assert!(params.get("paperHeight").and_then(|h| h.as_f64()) == Some(2.0));
+	assert!(params.get("marginTop").and_then(|m| m.as_f64()) == Some(0.0));
+	assert!(params.get("headerTemplate").and_then(|h| h.as_str()) == Some("<span></span>"));
+	assert!(params.get("footerTemplate").and_then(|f| f.as_str()) == Some("<span class=\"pageNumber\"></span>"));
+	assert!(params.get("pageRanges").and_then(|r| r.as_str()) == Some("1-3,5-5"));
+}
+
+#[test]
+/// Checks if the filters of intercepted requests match URLs with their wildcards.
+fn request_filters() {
+	use crate::browser::request::url_matches;
+
+	assert!(url_matches("https://example.com/", "https://example.com/"));
+	assert!(!url_matches("https://example.com/", "https://example.com/index.html"));
+	assert!(url_matches("https://example.com/*", "https://example.com/"));
+	assert!(url_matches("*.png", "https://example.com/logo.png"));
+	assert!(!url_matches("*.png", "https://example.com/logo.png?v=2"));
+	assert!(url_matches("*://*/api/*", "https://example.com/api/items"));
+	assert!(!url_matches("*/api/*", "https://example.com/apis"));
+	assert!(url_matches("*a*a", "aa"));
+	assert!(!url_matches("*a*a", "a"));
+}
+
+#[test]
+/// Checks if responses are taken from the events of the DevTools protocol, with every value of repeated headers.
+fn response_event() {
+	let response = Response::from_cdp(r#"{"requestId":"1.2","type":"Fetch","response":{"url":"https://example.com/api/me","status":401,"statusText":"Unauthorized","headers":{"Content-Type":"application/json","Set-Cookie":"a=1\nb=2"},"mimeType":"application/json"}}"#).unwrap();
+	assert!(response.status() == 401);
+	assert!(response.status_text() == "Unauthorized");
+	assert!(response.url() == "https://example.com/api/me");
+	assert!(response.mime_type() == "application/json");
+	assert!(response.header("content-type") == Some("application/json"));
+	assert!(response.headers().iter().filter(|(n, _)| n == "Set-Cookie").count() == 2);
+	assert!(Response::from_cdp(r#"{"requestId":"1.2"}"#).is_none());
+}
+
+#[test]
+/// Checks if session states come back the same from their JSON, and if JSON of another version is refused.
+fn session_state() {
+	let state = SessionState {
+		history: History {
+			current: 1,
+			entries: vec![
+				HistoryEntry { index: 0, title: "Home \"quoted\"".into(), url: "https://example.com/".into() },
+				HistoryEntry { index: 1, title: "".into(), url: "https://example.com/a?b=c".into() }
+			]
+		},
+		scroll: (0.0, 1250.5)
+	};
+	assert!(SessionState::from_json(&state.to_json()) == Some(state.clone()));
+	assert!(state.url() == Some("https://example.com/a?b=c"));
+	assert!(SessionState::from_json(&state.to_json().replace("\"version\":1", "\"version\":2")).is_none());
+}
+
+#[test]
+/// Checks if scheme requests are parsed, and the bodies of scheme responses are written in chunks.
+fn scheme_response() {
+	let request = SchemeRequest::new("GET", "app://localhost/assets/app.js?v=2#top", "Accept: */*\r\nRange: bytes=0-", &[]);
+	assert!(request.scheme() == "app");
+	assert!(request.path() == "/assets/app.js");
+	assert!(request.header("range") == Some("bytes=0-"));
+	assert!(SchemeRequest::new("GET", "app://localhost", "", &[]).path() == "/");
+
+	let response = SchemeResponse::new(200).header("Content-Type", "text/plain").body("hello");
+	assert!(response.header_lines() == "Content-Type: text/plain\r\nContent-Length: 5\r\n");
+
+	let mut body = Vec::new();
+	SchemeResponse::new(200).async_reader(&b"streamed"[..]).write_body(&mut |data| { body.extend_from_slice(data); true }).unwrap();
+	assert!(body == b"streamed");
+
+	// The body stops being read once the request has been cancelled
+	let mut chunks = 0;
+	SchemeResponse::new(200).reader(io::repeat(0)).write_body(&mut |_| { chunks += 1; chunks < 3 }).unwrap();
+	assert!(chunks == 3);
+
+	// A reader that fails, fails the body with it
+	struct Broken;
+	impl io::Read for Broken {
+		fn read(&mut self, _: &mut [u8]) -> io::Result<usize> { Err(io::ErrorKind::BrokenPipe.into()) }
+	}
+	assert!(SchemeResponse::new(200).reader(Broken).write_body(&mut |_| true).is_err());
+}
+
+#[test]
+#[cfg(feature = "assets")]
+/// Checks if embedded assets are served with their MIME types and ETags, and if routes fall back to `index.html`.
+fn assets() {
+	use crate::assets::{self, Assets};
+	use include_dir::{Dir, DirEntry, File};
+
+	static DIST: Dir = Dir::new("", &[
+		DirEntry::File(File::new("index.html", b"<html></html>")),
+		DirEntry::Dir(Dir::new("js", &[DirEntry::File(File::new("js/app.js", b"main()"))]))
+	]);
+	let assets = Assets::new(&DIST);
+	let get = |url: &str, headers: &str| assets.respond(&SchemeRequest::new("GET", url, headers, &[]));
+
+	assert!(assets::mime_type("js/app.js") == "text/javascript; charset=utf-8");
+	assert!(assets::mime_type("LICENSE") == "application/octet-stream");
+
+	let response = get("app://localhost/js/app.js", "");
+	assert!(response.status() == 200);
+	let etag = response.header_lines().lines().find(|l| l.starts_with("ETag: ")).unwrap()[6..].to_owned();
+	assert!(get("app://localhost/js/app.js", &format!("If-None-Match: {}", etag)).status() == 304);
+
+	assert!(get("app://localhost/settings/privacy", "").status() == 200);
+	assert!(get("app://localhost/missing.png", "").status() == 404);
+	assert!(Assets::new(&DIST).fallback(None).respond(&SchemeRequest::new("GET", "app://localhost/settings", "", &[])).status() == 404);
+}
+
 /// Closes a parent window before closing its child window, to see if the child window handle still is valid and doesn't cause any memory issues.
 async fn async_correct_parent_cleanup(app: ApplicationHandle) {
 
@@ -0,0 +1,257 @@
+//! This module contains the types to put an icon in the system tray, or the notification area of the taskbar.
+//!
+//! A tray icon keeps the application running for as long as it is there, just like a window does.
+//! This allows an application to hide its windows and stay in the tray, until it is exited from the tray icon's context menu.
+//!
+//! *Note:* Tray icons are not supported when CEF provides the windows itself, which is when feature `gtk` is disabled on other platforms than Windows.
+//!
+//! # Example
+//! ```ignore
+//! use browser_window::{menu::*, tray_icon::*};
+//!
+//! let menu = Menu::new("")
+//! 	.item( MenuItem::new( "show", "&Show" ) )
+//! 	.separator()
+//! 	.item( MenuItem::new( "quit", "&Quit" ) );
+//!
+//! let mut builder = TrayIconBuilder::new();
+//! builder.icon( "icon.ico" ).tooltip( "My App" ).menu( menu );
+//! builder.on_click(move |_| window.show() );
+//! builder.on_menu_item(move |icon, event| {
+//! 	if event.id() == "quit" {
+//! 		icon.app().exit( 0 );
+//! 	}
+//! });
+//! let tray_icon = builder.build( app );
+//! ```
+
+use crate::application::ApplicationHandle;
+use crate::menu::{Menu, MenuItemEvent};
+
+use browser_window_core::prelude::*;
+use std::{
+	cell::RefCell,
+	path::PathBuf
+};
+
+
+
+#[cfg(not(feature = "threadsafe"))]
+type TrayIconClickHandler = Box<dyn FnMut(TrayIconHandle)>;
+#[cfg(feature = "threadsafe")]
+type TrayIconClickHandler = Box<dyn FnMut(TrayIconHandle) + Send>;
+#[cfg(not(feature = "threadsafe"))]
+type TrayIconMenuHandler = Box<dyn FnMut(TrayIconHandle, &MenuItemEvent)>;
+#[cfg(feature = "threadsafe")]
+type TrayIconMenuHandler = Box<dyn FnMut(TrayIconHandle, &MenuItemEvent) + Send>;
+
+/// Used to put an icon in the system tray.
+#[derive(Default)]
+pub struct TrayIconBuilder {
+	click_handler: Option<TrayIconClickHandler>,
+	icon: Option<PathBuf>,
+	menu: Option<Menu>,
+	menu_handler: Option<TrayIconMenuHandler>,
+	tooltip: Option<String>
+}
+
+/// A handle to an icon in the system tray.
+/// The icon stays there until `remove` is called.
+pub struct TrayIconHandle {
+	app: ApplicationHandle,
+	inner: TrayIconImpl
+}
+
+struct TrayIconUserData {
+	app: ApplicationHandle,
+	click_handler: Option<TrayIconClickHandler>,
+	menu_handler: Option<TrayIconMenuHandler>,
+	/// The identifiers of the menu items, where the native ID of each item is its index plus one.
+	menu_item_ids: Vec<String>
+}
+
+
+
+thread_local! {
+	/// The user data of the tray icons that haven't been removed yet, so that a tray icon is only freed once.
+	static TRAY_ICONS: RefCell<Vec<usize>> = const { RefCell::new( Vec::new() ) };
+}
+
+
+
+impl TrayIconBuilder {
+
+	/// Puts the icon in the system tray.
+	///
+	/// # Arguments
+	/// * `app` - The application that the icon keeps running.
+	pub fn build( self, app: ApplicationHandle ) -> TrayIconHandle {
+		let icon_path = self.icon.as_ref().map(|p| p.to_string_lossy().into_owned() ).unwrap_or_default();
+		let tooltip = self.tooltip.as_deref().unwrap_or("");
+
+		let mut menu_item_ids = Vec::new();
+		let menu_impl = self.menu.as_ref().map(|m| m.to_impl( &mut menu_item_ids ) );
+
+		let user_data = Box::into_raw( Box::new( TrayIconUserData {
+			app,
+			click_handler: self.click_handler,
+			menu_handler: self.menu_handler,
+			menu_item_ids
+		} ) );
+
+		let inner = TrayIconImpl::new(
+			app.inner,
+			&icon_path,
+			tooltip,
+			menu_impl,
+			ffi_click_handler,
+			ffi_menu_handler,
+			user_data as _
+		);
+		TRAY_ICONS.with(|icons| icons.borrow_mut().push( user_data as usize ) );
+
+		// The native menu has been copied by now
+		if let Some( menu ) = menu_impl {
+			menu.free();
+		}
+
+		TrayIconHandle {
+			app,
+			inner
+		}
+	}
+
+	/// Sets the image file of the icon.
+	/// On Windows, this needs to be an `.ico` file.
+	/// If it isn't set, or if it can't be loaded, the default application icon is used.
+	pub fn icon<P: Into<PathBuf>>( &mut self, path: P ) -> &mut Self {
+		self.icon = Some( path.into() );	self
+	}
+
+	/// Sets the context menu, which is shown when the icon is right-clicked.
+	/// The label of the menu itself is not used.
+	pub fn menu( &mut self, menu: Menu ) -> &mut Self {
+		self.menu = Some( menu );	self
+	}
+
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Configure a closure that is invoked whenever the icon is clicked on with the left mouse button.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn on_click<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(TrayIconHandle) + 'static
+	{
+		self.click_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever the icon is clicked on with the left mouse button.
+	#[cfg(feature = "threadsafe")]
+	pub fn on_click<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(TrayIconHandle) + Send + 'static
+	{
+		self.click_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever an item of the context menu gets activated.
+	#[cfg(not(feature = "threadsafe"))]
+	pub fn on_menu_item<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(TrayIconHandle, &MenuItemEvent) + 'static
+	{
+		self.menu_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Configure a closure that is invoked whenever an item of the context menu gets activated.
+	#[cfg(feature = "threadsafe")]
+	pub fn on_menu_item<H>( &mut self, handler: H ) -> &mut Self where
+		H: FnMut(TrayIconHandle, &MenuItemEvent) + Send + 'static
+	{
+		self.menu_handler = Some( Box::new( handler ) );	self
+	}
+
+	/// Sets the text that is shown when hovering over the icon.
+	pub fn tooltip<S: Into<String>>( &mut self, tooltip: S ) -> &mut Self {
+		self.tooltip = Some( tooltip.into() );	self
+	}
+}
+
+impl TrayIconHandle {
+
+	pub fn app( &self ) -> ApplicationHandle {
+		self.app
+	}
+
+	/// Removes the icon from the system tray.
+	/// If there are no windows and no other tray icons left, the application exits.
+	///
+	/// The icon is freed once the handler that is running, if any, has returned.
+	/// Its handlers are not invoked anymore after this, and removing it again with another handle does nothing.
+	pub fn remove( self ) {
+		let user_data = self.inner.user_data() as usize;
+
+		let was_there = TRAY_ICONS.with(|icons| {
+			let mut icons = icons.borrow_mut();
+			let count = icons.len();
+			icons.retain(|i| *i != user_data );
+			icons.len() != count
+		});
+		if was_there {
+			let data = Box::into_raw( Box::new( self.inner ) );
+			self.app.inner.dispatch( ffi_remove_handler, data as _ );
+		}
+	}
+
+	/// Replaces the image of the icon.
+	/// See `TrayIconBuilder::icon` for the requirements of the file.
+	pub fn set_icon<P: Into<PathBuf>>( &self, path: P ) {
+		self.inner.set_icon( &path.into().to_string_lossy() );
+	}
+
+	pub fn set_tooltip( &self, tooltip: &str ) {
+		self.inner.set_tooltip( tooltip );
+	}
+}
+
+
+
+unsafe fn ffi_click_handler( icon: TrayIconImpl, data: *mut () ) {
+	if !is_there( data ) {
+		return;
+	}
+	let user_data = &mut *(data as *mut TrayIconUserData);
+
+	if let Some( handler ) = user_data.click_handler.as_mut() {
+		handler( TrayIconHandle { app: user_data.app, inner: icon } );
+	}
+}
+
+unsafe fn ffi_menu_handler( icon: TrayIconImpl, data: *mut (), item_id: u32, checked: bool ) {
+	if !is_there( data ) {
+		return;
+	}
+	let user_data = &mut *(data as *mut TrayIconUserData);
+
+	let id = match user_data.menu_item_ids.get( (item_id as usize).wrapping_sub( 1 ) ) {
+		None => return,
+		Some( id ) => id.clone()
+	};
+
+	if let Some( handler ) = user_data.menu_handler.as_mut() {
+		let event = MenuItemEvent { id, checked };
+		handler( TrayIconHandle { app: user_data.app, inner: icon }, &event );
+	}
+}
+
+unsafe fn ffi_remove_handler( _app: ApplicationImpl, data: *mut () ) {
+	let icon = *Box::from_raw( data as *mut TrayIconImpl );
+	let user_data = icon.user_data() as *mut TrayIconUserData;
+
+	icon.free();
+	drop( Box::from_raw( user_data ) );
+}
+
+/// Whether the tray icon with the given user data hasn't been removed yet.
+fn is_there( user_data: *mut () ) -> bool {
+	TRAY_ICONS.with(|icons| icons.borrow().contains( &(user_data as usize) ) )
+}
@@ -1,18 +1,78 @@
 //! This module contains all window related functionality.
 
 mod builder;
+mod drag;
+#[cfg(feature = "raw-window-handle")]
+mod interop;
+mod message_box;
 
 use super::prelude::*;
+use super::application::Monitor;
 use super::event::Event;
 
+use std::{
+	fmt,
+	str::FromStr,
+	sync::atomic::{AtomicU64, Ordering}
+};
+
 
 
 pub use builder::WindowBuilder;
+pub use drag::DragData;
+pub use message_box::{MessageBoxKind, MessageBoxOptions};
 
 
 
 pub type StandardWindowEvent = Event<'static, WindowHandle>;
 
+/// A material that the operating system draws behind the content of a window.
+/// For it to be visible, the page needs a transparent background, e.g. `body { background: transparent; }`.
+///
+/// When an effect is not supported, the system falls back to the closest one that is, or to no effect at all.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Effect {
+	/// The opaque material of Windows 11, which is tinted with the desktop wallpaper.
+	Mica,
+	/// A translucent, blurred material.
+	Acrylic,
+	/// The translucent material of macOS.
+	/// Falls back to `Acrylic` on Windows.
+	Vibrancy
+}
+
+/// How the content of a window keeps up with the window while the user is resizing it.
+/// Without any coordination, the browser lags a frame or more behind the window's frame, and the area it doesn't cover yet shows garbage.
+///
+/// *Note:* This only has an effect on Windows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResizeStrategy {
+	/// Leaves it up to the platform.
+	Default,
+	/// Resizes the browser and waits for it to be repainted and presented, before the window resizes any further.
+	/// This keeps the content in step with the frame, at the cost of a less smooth resize when the page takes long to lay out.
+	Synchronous,
+	/// Fills the area that the browser doesn't cover yet with the background color of the system, until it catches up.
+	Letterbox
+}
+
+/// The unit in which the size of a window is given to the builder.
+///
+/// Logical pixels are scaled by the scale factor of the display that the window ends up on, so that the window looks just as big on every display.
+/// Physical pixels are the actual pixels of the display.
+/// See also `WindowHandle::scale_factor`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SizeUnit {
+	Logical,
+	Physical
+}
+
+/// An identifier that is unique for every window that has been created during the lifetime of the process.
+/// Unlike handles, it can be compared, hashed and be sent to other threads.
+/// It stays meaningful after the window has been destroyed, and it displays as `window-<number>`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WindowId( u64 );
+
 /// A handle that exposes all windowing functionality.
 #[derive(Clone, Copy)]
 pub struct WindowHandle {
@@ -37,11 +97,42 @@ pub trait OwnedWindow {
 
 
 
+impl WindowId {
+
+	/// Allocates a new identifier, which has never been given out before.
+	pub(in crate) fn new() -> Self {
+		static NEXT_ID: AtomicU64 = AtomicU64::new( 1 );
+
+		Self( NEXT_ID.fetch_add( 1, Ordering::Relaxed ) )
+	}
+}
+
+impl fmt::Display for WindowId {
+
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result {
+		write!( f, "window-{}", self.0 )
+	}
+}
+
+impl FromStr for WindowId {
+	type Err = ();
+
+	/// Parses an identifier in the way that it is displayed, like `window-3`.
+	fn from_str( string: &str ) -> Result<Self, Self::Err> {
+		let number = string.strip_prefix( "window-" ).ok_or(())?;
+
+		number.parse().map( Self ).map_err(|_| () )
+	}
+}
+
+
+
 impl WindowHandle {
 	impl_prop!{ pub content_dimensions: ContentDimensions }
 	impl_prop!{ pub opacity: Opacity }
 	impl_prop!{ pub position: Position }
 	impl_prop!{ pub title: Title }
+	impl_prop!{ pub visible: Visible }
 	impl_prop!{ pub window_dimensions: WindowDimensions }
 
 	/// Destroys the window.
@@ -50,6 +141,12 @@ impl WindowHandle {
 		// The window will be dropped because ownership of `self` is taken.
 	}
 
+	/// Gives the display that contains the largest part of the window.
+	/// Returns `None` if the window isn't on any display, like before it has been shown on GTK.
+	pub fn current_monitor( &self ) -> Option<Monitor> {
+		self.inner.get_monitor().map( Monitor::from )
+	}
+
 	/// Make the window invisible to the user.
 	pub fn hide( &self ) {
 		self.inner.hide()
@@ -61,6 +158,46 @@ impl WindowHandle {
 		}
 	}
 
+	/// The number of physical pixels that make up one logical pixel on the display that the window is on.
+	/// This is 1.0 on displays that aren't scaled, and 2.0 on displays that are scaled to 200%.
+	///
+	/// On Windows, the sizes and positions of a window that are read and set after it has been created are in physical pixels.
+	/// On GTK and with CEF's own windows, they are in logical pixels.
+	/// GTK only scales by whole numbers, so fractional scaling of the desktop is not reflected in the scale factor.
+	pub fn scale_factor( &self ) -> f32 {
+		self.inner.get_scale_factor()
+	}
+
+	/// Marks the area of the custom maximize button, for windows without borders that have a titlebar made in HTML.
+	/// The position is relative to the content area of the window, and `None` removes the area again.
+	///
+	/// On Windows 11, hovering over the area shows the snap layouts, just like it does for a normal maximize button.
+	/// Clicking the area maximizes or restores the window.
+	/// Because the area is covered by the native window, the page doesn't receive mouse events for it anymore.
+	///
+	/// This feature only works on Windows.
+	///
+	/// # Example
+	/// The page can report the area of its button through a handler:
+	/// ```ignore
+	/// bwb.async_handler(|handle, cmd, args| async move {
+	/// 	if cmd == "maximize-button" {
+	/// 		let n: Vec<u16> = args.iter().map(|a| a.parse().unwrap() ).collect();
+	/// 		handle.set_maximize_button_area( Some( (Pos2D::new( n[0], n[1] ), Dims2D::new( n[2], n[3] )) ) );
+	/// 	}
+	/// });
+	/// ```
+	/// ```js
+	/// let r = document.getElementById("maximize").getBoundingClientRect();
+	/// invoke_extern("maximize-button", ...[r.x, r.y, r.width, r.height].map(n => String(Math.round(n))));
+	/// ```
+	pub fn set_maximize_button_area( &self, area: Option<(Pos2D, Dims2D)> ) {
+		match area {
+			None => self.inner.set_maximize_button_area( Pos2D::new( 0, 0 ), Dims2D::new( 0, 0 ) ),
+			Some( (position, size) ) => self.inner.set_maximize_button_area( position, size )
+		}
+	}
+
 	/// Make the window visible to the user.
 	pub fn show( &self ) {
 		self.inner.show()
@@ -102,6 +239,13 @@ prop!{ /// Gets or sets the title of the window.
 	}
 }
 
+prop! { /// Gets or sets whether or not the window is visible, like `show` and `hide` do.
+	Visible<bool>( this: WindowHandle ) {
+		get => this.inner.is_visible(),
+		set(val) => if val { this.show() } else { this.hide() }
+	}
+}
+
 prop! { /// Gets or sets the current window size including its border and titlebar.
 	WindowDimensions<Dims2D>( this: WindowHandle ) {
 		get => this.inner.get_window_dimensions().into(),
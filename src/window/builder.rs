@@ -3,7 +3,8 @@ use crate::window::*;
 
 use browser_window_core::prelude::*;
 use std::{
-	future::Future
+	future::Future,
+	ptr
 };
 use unsafe_send_sync::UnsafeSend;
 
@@ -65,13 +66,25 @@ macro_rules! def_event {
 
 /// Exposes functionality related to constructing a window.
 pub struct WindowBuilder {
+	pub(in crate) backdrop: Option<Effect>,
 	pub(in crate) borders: bool,
+	pub(in crate) corner_radius: Option<u16>,
 	pub(in crate) events: Box<WindowEvents>,
 	pub(in crate) height: Option<u32>,
 	pub(in crate) minimizable: bool,
+	pub(in crate) modal: bool,
+	/// The address of the native window of another toolkit that the window is embedded in, if any.
+	pub(in crate) native_parent: Option<usize>,
+	/// The height in physical pixels of the area at the top of the native parent that the window leaves uncovered.
+	pub(in crate) native_parent_top: u16,
+	pub(in crate) owner: Option<UnsafeSend<WindowHandle>>,
 	pub(in crate) parent: Option<UnsafeSend<WindowHandle>>,
 	pub(in crate) resizable: bool,
+	pub(in crate) resize_strategy: ResizeStrategy,
+	pub(in crate) shadow: Option<bool>,
+	pub(in crate) size_unit: SizeUnit,
 	pub(in crate) title: Option<String>,
+	pub(in crate) tool_window: bool,
 	pub(in crate) width: Option<u32>
 }
 
@@ -81,6 +94,31 @@ struct WindowUserData {
 
 
 
+impl Effect {
+
+	/// The value of the effect as it is known by the C code.
+	pub(in crate) fn to_c( effect: Option<Self> ) -> u8 {
+		match effect {
+			None => 0,
+			Some( Effect::Mica ) => 1,
+			Some( Effect::Acrylic ) => 2,
+			Some( Effect::Vibrancy ) => 3
+		}
+	}
+}
+
+impl ResizeStrategy {
+
+	/// The value of the strategy as it is known by the C code.
+	pub(in crate) fn to_c( &self ) -> u8 {
+		match self {
+			Self::Default => 0,
+			Self::Synchronous => 1,
+			Self::Letterbox => 2
+		}
+	}
+}
+
 impl WindowBuilder {
 
 	def_event!{ /// Invoked whenever the window closes, whether it was closed by the user or programmatically.
@@ -93,12 +131,35 @@ impl WindowBuilder {
 		WindowResizeEventArgs, on_resize, on_resize_async
 	}
 
+	/// Sets the material that is drawn behind the content of the window.
+	/// The browser's page is given a transparent background, so that the backdrop shows through wherever the page has no background of its own.
+	///
+	/// The effect degrades gracefully: on versions of Windows before Windows 11, and on other platforms, the window just looks like it normally does.
+	/// Changing the opacity of a window with a backdrop removes the backdrop.
+	///
+	/// *Note:* CEF doesn't support transparent pages in windows, so the backdrop is only visible around the page with the `cef` feature.
+	pub fn backdrop( &mut self, effect: Effect ) -> &mut Self {
+		self.backdrop = Some( effect );	self
+	}
+
 	/// Sets whether or not the window has borders.
 	/// Default is true.
 	pub fn borders( &mut self, value: bool ) -> &mut Self {
 		self.borders = value;	self
 	}
 
+	/// Sets the radius of the corners of the window, in pixels.
+	/// This is mostly useful for frameless windows, whose corners otherwise look different on every platform.
+	/// A radius of 0 gives square corners.
+	///
+	/// On Windows 11, the system rounds the corners itself with one of its own radiuses, whichever comes closest.
+	/// On older versions of Windows and on GTK, the window is cut into shape, which also cuts off its drop shadow.
+	///
+	/// *Note:* This has no effect when CEF provides the windows itself.
+	pub fn corner_radius( &mut self, radius: u16 ) -> &mut Self {
+		self.corner_radius = Some( radius );	self
+	}
+
 	// TODO: Create a Window struct that can be created with this method.
 	fn build( self, app: ApplicationHandle ) {
 
@@ -110,9 +171,20 @@ impl WindowBuilder {
 
 		// Convert options to the FFI struct
 		let window_options = cbw_WindowOptions {
+			backdrop: Effect::to_c( self.backdrop ),
 			borders: self.borders,
+			corner_radius: self.corner_radius.map(|r| r.min( i16::MAX as u16 ) as i16 ).unwrap_or( -1 ),
 			minimizable: self.minimizable,
-			resizable: self.resizable
+			resizable: self.resizable,
+			resize_strategy: self.resize_strategy.to_c(),
+			shadow: self.shadow.map(|s| if s {1} else {2} ).unwrap_or( 0 ),
+			physical_size: self.size_unit == SizeUnit::Physical,
+			tool_window: self.tool_window,
+			menu_bar: ptr::null(),
+			owner: self.owner.as_ref().map(|o| o.inner.as_ptr() ).unwrap_or( ptr::null() ),
+			modal: self.modal,
+			native_parent: self.native_parent.unwrap_or( 0 ) as _,
+			native_parent_top: self.native_parent_top
 		};
 
 		// Put event data into a user data pointer
@@ -149,6 +221,27 @@ impl WindowBuilder {
 		self.minimizable = value;	self
 	}
 
+	/// Makes the window block all interaction with its owner for as long as it is visible, like a dialog that needs to be answered first.
+	/// This only has an effect when the window has an owner, see `owner`.
+	///
+	/// *Note:* On GTK, a modal window blocks the other windows of the application as well.
+	/// Modal windows are not supported yet when CEF provides the windows itself.
+	pub fn modal( &mut self, value: bool ) -> &mut Self {
+		self.modal = value;	self
+	}
+
+	/// Makes the window a secondary window of the given one, like a dialog or a tool palette.
+	/// It stays above its owner, and it is centered on its owner when it is created.
+	/// This could be a reference to a `Browser` or `BrowserThreaded` handle.
+	///
+	/// Unlike with `parent`, the window isn't closed along with its owner.
+	pub fn owner<W>( &mut self, bw: &W ) -> &mut Self where
+		W: OwnedWindow
+	{
+		self.owner = Some( UnsafeSend::new( bw.window_handle() ) );
+		self
+	}
+
 	/// Configure a parent window.
 	/// When a parent window closes, this browser window will close as well.
 	/// This could be a reference to a `Browser` or `BrowserThreaded` handle.
@@ -161,24 +254,52 @@ impl WindowBuilder {
 
 	pub fn new() -> Self {
 		Self {
+			backdrop: None,
 			borders: true,
+			corner_radius: None,
 			height: None,
 			minimizable: true,
+			modal: false,
+			native_parent: None,
+			native_parent_top: 0,
+			owner: None,
 			parent: None,
 			resizable: true,
+			resize_strategy: ResizeStrategy::Default,
+			shadow: None,
+			size_unit: SizeUnit::Physical,
 			title: None,
+			tool_window: false,
 			width: None,
 			events: Box::new( WindowEvents::default() )
 		}
 	}
 
-	/// Sets the width and height of the browser window
+	/// Sets whether or not the window has a drop shadow.
+	/// Frameless windows on Windows otherwise have no shadow.
+	///
+	/// *Note:* This only has an effect on Windows, elsewhere the window manager decides whether windows have a shadow.
+	pub fn shadow( &mut self, value: bool ) -> &mut Self {
+		self.shadow = Some( value );	self
+	}
+
+	/// Sets the width and height of the browser window.
+	/// They are in the unit set with `size_unit`, which is physical pixels by default.
 	pub fn size( &mut self, width: u32, height: u32 ) -> &mut Self {
 		self.width = Some( width );
 		self.height = Some( height );
 		self
 	}
 
+	/// Sets the unit of the width and height that are given with `size`, `width` and `height`.
+	/// Default is `SizeUnit::Physical`, which are the actual pixels of the display.
+	/// `SizeUnit::Logical` makes the window just as big on every display, whatever its scale factor is.
+	///
+	/// This only applies to the size with which the window is created.
+	pub fn size_unit( &mut self, unit: SizeUnit ) -> &mut Self {
+		self.size_unit = unit;	self
+	}
+
 	/// Sets the title of the window.
 	pub fn title<S: Into<String>>( &mut self, title: S ) -> &mut Self {
 		self.title = Some( title.into() );
@@ -186,6 +307,15 @@ impl WindowBuilder {
 	}
 
 
+	/// Makes the window a tool window: a small utility window, like a palette or an inspector, that accompanies a main window.
+	/// It has a thin titlebar (on Windows), and doesn't appear in the taskbar or when alt-tabbing.
+	/// Default is false.
+	///
+	/// *Note:* When CEF provides the windows itself, this is only supported on Windows.
+	pub fn tool_window( &mut self, value: bool ) -> &mut Self {
+		self.tool_window = value;	self
+	}
+
 	/// Sets the width that the browser window will be created with initially.
 	pub fn width( &mut self, width: u32 ) -> &mut Self {
 		self.width = Some( width );
@@ -197,4 +327,10 @@ impl WindowBuilder {
 	pub fn resizable( &mut self, resizable: bool ) -> &mut Self {
 		self.resizable = resizable;	self
 	}
+
+	/// Sets how the content keeps up with the window while it is being resized.
+	/// Default is `ResizeStrategy::Default`.
+	pub fn resize_strategy( &mut self, strategy: ResizeStrategy ) -> &mut Self {
+		self.resize_strategy = strategy;	self
+	}
 }
\ No newline at end of file
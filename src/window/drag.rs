@@ -0,0 +1,70 @@
+use super::WindowHandle;
+
+use browser_window_core::prelude::*;
+use std::path::PathBuf;
+
+
+
+/// The files and text that are carried along by a drag that starts in a window.
+/// See `WindowHandle::start_drag`.
+#[derive(Clone, Debug, Default)]
+pub struct DragData {
+	files: Vec<PathBuf>,
+	text: Option<String>
+}
+
+
+
+impl DragData {
+
+	/// Adds a file to the data.
+	/// The path needs to be absolute.
+	pub fn file<P: Into<PathBuf>>( mut self, path: P ) -> Self {
+		self.files.push( path.into() );
+		self
+	}
+
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Sets the text that is dropped into destinations that accept text.
+	pub fn text<S: Into<String>>( mut self, text: S ) -> Self {
+		self.text = Some( text.into() );
+		self
+	}
+}
+
+impl WindowHandle {
+
+	/// Starts a native drag, which carries the given files or text out of the window to wherever the user drops them, like the file explorer or another application.
+	/// Returns whether or not the drag could be started.
+	///
+	/// The drag follows the mouse button that is held down, so this should be called while the user is pressing it.
+	/// Typically, the page asks for it from its `dragstart` event, which it then cancels so that the page's own drag doesn't get in the way.
+	/// On Windows, this only returns after the data has been dropped.
+	///
+	/// *Note:* This is not supported yet when CEF provides the windows itself.
+	///
+	/// # Example
+	/// ```ignore
+	/// bwb.async_handler(|handle, cmd, args| async move {
+	/// 	if cmd == "drag-file" {
+	/// 		handle.start_drag( &DragData::new().file( &args[0] ) );
+	/// 	}
+	/// });
+	/// ```
+	/// ```js
+	/// element.addEventListener("dragstart", e => {
+	/// 	e.preventDefault();
+	/// 	invoke_extern("drag-file", "/home/user/report.pdf");
+	/// });
+	/// ```
+	pub fn start_drag( &self, data: &DragData ) -> bool {
+		// The C code works with UTF-8 paths
+		let files: Vec<String> = data.files.iter().map(|f| f.to_string_lossy().into_owned() ).collect();
+		let file_refs: Vec<&str> = files.iter().map(|f| f.as_str() ).collect();
+
+		self.inner.start_drag( &file_refs, data.text.as_deref().unwrap_or("") )
+	}
+}
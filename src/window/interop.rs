@@ -0,0 +1,97 @@
+use raw_window_handle::*;
+
+use super::{WindowBuilder, WindowHandle};
+use crate::browser::BrowserWindowHandle;
+use browser_window_core::prelude::*;
+
+
+
+unsafe impl HasRawWindowHandle for WindowHandle {
+
+	/// Gives the native window handle.
+	/// If the window doesn't have one yet, an empty handle of the kind of the platform is given.
+	fn raw_window_handle( &self ) -> RawWindowHandle {
+		match self.inner.get_native_handle() {
+			Some( h ) if h.kind == 1 => {
+				let mut handle = Win32WindowHandle::empty();
+				handle.hwnd = h.window;
+				handle.hinstance = h.display;
+				RawWindowHandle::Win32( handle )
+			},
+			Some( h ) if h.kind == 2 => {
+				let mut handle = XlibWindowHandle::empty();
+				handle.window = h.xid;
+				RawWindowHandle::Xlib( handle )
+			},
+			Some( h ) if h.kind == 3 => {
+				let mut handle = WaylandWindowHandle::empty();
+				handle.surface = h.window;
+				RawWindowHandle::Wayland( handle )
+			},
+			_ => if cfg!(windows) {
+				RawWindowHandle::Win32( Win32WindowHandle::empty() )
+			} else {
+				RawWindowHandle::Xlib( XlibWindowHandle::empty() )
+			}
+		}
+	}
+}
+
+unsafe impl HasRawDisplayHandle for WindowHandle {
+
+	fn raw_display_handle( &self ) -> RawDisplayHandle {
+		match self.inner.get_native_handle() {
+			Some( h ) if h.kind == 2 => {
+				let mut handle = XlibDisplayHandle::empty();
+				handle.display = h.display;
+				RawDisplayHandle::Xlib( handle )
+			},
+			Some( h ) if h.kind == 3 => {
+				let mut handle = WaylandDisplayHandle::empty();
+				handle.display = h.display;
+				RawDisplayHandle::Wayland( handle )
+			},
+			Some( h ) if h.kind == 1 => RawDisplayHandle::Windows( WindowsDisplayHandle::empty() ),
+			_ => if cfg!(windows) {
+				RawDisplayHandle::Windows( WindowsDisplayHandle::empty() )
+			} else {
+				RawDisplayHandle::Xlib( XlibDisplayHandle::empty() )
+			}
+		}
+	}
+}
+
+unsafe impl HasRawWindowHandle for BrowserWindowHandle {
+
+	/// Gives the native handle of the window that contains the browser.
+	fn raw_window_handle( &self ) -> RawWindowHandle {
+		self.window().raw_window_handle()
+	}
+}
+
+unsafe impl HasRawDisplayHandle for BrowserWindowHandle {
+
+	fn raw_display_handle( &self ) -> RawDisplayHandle {
+		self.window().raw_display_handle()
+	}
+}
+
+
+
+impl WindowBuilder {
+
+	/// Embeds the window into the given window of another toolkit, like one of tao or winit.
+	/// The window then fills the client area of the other window, follows its size, and has no decorations of its own.
+	///
+	/// The other window needs to stay alive for as long as this window exists.
+	///
+	/// *Note:* Only supported on Windows for now.
+	/// On other platforms this is ignored, and the window becomes a window of its own.
+	pub fn embed_in<W: HasRawWindowHandle>( &mut self, window: &W ) -> &mut Self {
+		self.native_parent = match window.raw_window_handle() {
+			RawWindowHandle::Win32( h ) if !h.hwnd.is_null() => Some( h.hwnd as usize ),
+			_ => None
+		};
+		self
+	}
+}
@@ -0,0 +1,107 @@
+use super::WindowHandle;
+
+use browser_window_core::prelude::*;
+use futures_channel::oneshot;
+
+
+
+/// The icon of a message box, which tells the user what kind of message it is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MessageBoxKind {
+	Info,
+	Warning,
+	Error,
+	Question
+}
+
+/// The contents of a message box, as it is shown by `WindowHandle::message_box`.
+///
+/// # Example
+/// ```ignore
+/// let options = MessageBoxOptions::new( MessageBoxKind::Question, "Unsaved changes", "Do you want to save your changes?" )
+/// 	.button( "Save" )
+/// 	.button( "Don't save" )
+/// 	.button( "Cancel" );
+/// ```
+#[derive(Clone, Debug)]
+pub struct MessageBoxOptions {
+	kind: MessageBoxKind,
+	title: String,
+	message: String,
+	buttons: Vec<String>
+}
+
+type MessageBoxHandler = Box<dyn FnOnce( Option<usize> )>;
+
+
+
+impl MessageBoxOptions {
+
+	/// Adds a button with the given caption.
+	/// Buttons are shown in the order in which they are added.
+	/// Without any buttons, the message box only has an OK button.
+	pub fn button<S: Into<String>>( mut self, caption: S ) -> Self {
+		self.buttons.push( caption.into() );
+		self
+	}
+
+	pub fn new<T: Into<String>, M: Into<String>>( kind: MessageBoxKind, title: T, message: M ) -> Self {
+		Self {
+			kind,
+			title: title.into(),
+			message: message.into(),
+			buttons: Vec::new()
+		}
+	}
+}
+
+impl WindowHandle {
+
+	/// Shows a native message box that is modal to the window, and waits for the user to close it.
+	/// Gives the index of the button that has been chosen, or `None` if the message box has been dismissed without choosing a button, like with the escape key.
+	/// Without any buttons, the OK button has index 0.
+	///
+	/// *Note:* On Windows, the buttons only get their own captions when version 6 of the common controls is enabled with a manifest.
+	/// Otherwise, a message box with one, two or three buttons gets OK, OK and Cancel, or Yes, No and Cancel.
+	/// Message boxes are not supported yet when CEF provides the windows itself, in which case this gives `None` right away.
+	///
+	/// # Example
+	/// ```ignore
+	/// let options = MessageBoxOptions::new( MessageBoxKind::Warning, "Delete file", "This can't be undone." )
+	/// 	.button( "Delete" )
+	/// 	.button( "Cancel" );
+	///
+	/// if bw.message_box( options ).await == Some( 0 ) {
+	/// 	// Delete the file ...
+	/// }
+	/// ```
+	pub async fn message_box( &self, options: MessageBoxOptions ) -> Option<usize> {
+		let (tx, rx) = oneshot::channel::<Option<usize>>();
+
+		let kind = match options.kind {
+			MessageBoxKind::Info => 0,
+			MessageBoxKind::Warning => 1,
+			MessageBoxKind::Error => 2,
+			MessageBoxKind::Question => 3
+		};
+		let buttons: Vec<&str> = options.buttons.iter().map(|b| b.as_str() ).collect();
+
+		let handler: MessageBoxHandler = Box::new( move |button| {
+			let _ = tx.send( button );
+		} );
+		let data_ptr = Box::into_raw( Box::new( handler ) );
+
+		self.inner.show_message_box( kind, &options.title, &options.message, &buttons, message_box_handler, data_ptr as _ );
+
+		// The message box is dismissed if the window is destroyed before it has been closed
+		rx.await.unwrap_or( None )
+	}
+}
+
+
+
+unsafe fn message_box_handler( _window: WindowImpl, button: Option<usize>, data: *mut () ) {
+	let handler = Box::from_raw( data as *mut MessageBoxHandler );
+
+	handler( button );
+}